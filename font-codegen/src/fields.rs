@@ -241,23 +241,52 @@ impl Fields {
             .flat_map(move |fld| fld.from_obj_ref_stmt(in_record))
     }
 
+    /// Generate the match arms of a traversal `get_field` implementation.
+    ///
+    /// For tables, each field is annotated with its byte range. For
+    /// zerocopy records the range relative to the start of the record is
+    /// known statically; other records do not report ranges.
     pub(crate) fn iter_field_traversal_match_arms(
         &self,
         in_record: bool,
+        zerocopy_record: bool,
     ) -> impl Iterator<Item = TokenStream> + '_ {
         let pass_data = in_record.then(|| quote!(_data));
+        // the offset of each field from the start of a zerocopy record
+        let mut record_offsets = Vec::with_capacity(self.fields.len());
+        let mut sizes = Vec::new();
+        for fld in &self.fields {
+            record_offsets.push(match sizes.as_slice() {
+                [] => quote!(0),
+                sizes => quote!( #( #sizes )+* ),
+            });
+            if zerocopy_record {
+                let typ = fld.typ.cooked_type_tokens();
+                sizes.push(quote!(#typ::RAW_BYTE_LEN));
+            }
+        }
         self.fields
             .iter()
-            .filter(|fld| fld.has_getter())
+            .zip(record_offsets)
+            .filter(|(fld, _)| fld.has_getter())
             .enumerate()
-            .map(move |(i, fld)| {
+            .map(move |(i, (fld, record_offset))| {
                 let condition = fld
                     .attrs
                     .since_version
                     .as_ref()
                     .map(|v| quote!(if version.compatible(#v)));
                 let rhs = traversal_arm_for_field(fld, in_record, pass_data.as_ref());
-                quote!( #i #condition => Some(#rhs) )
+                let position = if !in_record {
+                    let range_fn = fld.shape_byte_range_fn_name();
+                    Some(quote!(.with_bytes(self.data, self.shape.#range_fn())))
+                } else if zerocopy_record {
+                    let typ = fld.typ.cooked_type_tokens();
+                    Some(quote!(.with_record_range(#record_offset, #typ::RAW_BYTE_LEN)))
+                } else {
+                    None
+                };
+                quote!( #i #condition => Some(#rhs #position) )
             })
     }
 }
@@ -287,6 +316,9 @@ fn traversal_arm_for_field(
     let name_str = &fld.name.to_string();
     let name = &fld.name;
     let maybe_unwrap = fld.attrs.since_version.is_some().then(|| quote!(.unwrap()));
+    // the getters for versioned nullable offsets already flatten the version
+    // check and the null check into a single `Option`
+    let maybe_unwrap_getter = (!fld.is_nullable()).then(|| maybe_unwrap.clone()).flatten();
     if let Some(traverse_with) = &fld.attrs.traverse_with {
         let traverse_fn = &traverse_with.attr;
         if traverse_fn == "skip" {
@@ -308,7 +340,7 @@ fn traversal_arm_for_field(
                     #name_str,
                     traversal::FieldType::offset_to_array_of_records(
                         self.#name()#maybe_unwrap,
-                        self.#getter(#pass_data)#maybe_unwrap,
+                        self.#getter(#pass_data)#maybe_unwrap_getter,
                         stringify!(#typ),
                         #offset_data,
                     )
@@ -320,7 +352,7 @@ fn traversal_arm_for_field(
                 OffsetTarget::Array(_) => quote!(offset_to_array_of_scalars),
             };
             let getter = fld.offset_getter_name();
-            quote!(Field::new(#name_str, FieldType::#constructor_name(self.#name()#maybe_unwrap, self.#getter(#pass_data)#maybe_unwrap)))
+            quote!(Field::new(#name_str, FieldType::#constructor_name(self.#name()#maybe_unwrap, self.#getter(#pass_data)#maybe_unwrap_getter)))
         }
         FieldType::Scalar { .. } => quote!(Field::new(#name_str, self.#name()#maybe_unwrap)),
 
@@ -388,6 +420,14 @@ fn traversal_arm_for_field(
             // in a record we return things by value, so clone
             let maybe_clone = in_record.then(|| quote!(.clone()));
             let typ_str = arr.raw_inner_type().to_string();
+            // value records are located using the format they were read with
+            if typ_str == "ValueRecord" && !in_record {
+                let format = value_record_format(fld);
+                return quote!(Field::new(
+                    #name_str,
+                    ValueRecord::traverse_array(self.#name() #maybe_unwrap, #data, #format)
+                ));
+            }
             quote!(Field::new(
                     #name_str,
                     traversal::FieldType::computed_array(
@@ -407,7 +447,19 @@ fn traversal_arm_for_field(
             let offset_data = pass_data
                 .cloned()
                 .unwrap_or_else(|| fld.offset_getter_data_src());
-            quote!(Field::new(#name_str, self.#name() #maybe_unwrap .traversal_type(#offset_data)))
+            // records do not keep the arguments they were read with, so the
+            // format of a nested value record is not available
+            if in_record {
+                return quote!(Field::new(#name_str, traversal::FieldType::Record(self.#name() #maybe_unwrap .clone().traverse(#offset_data))));
+            }
+            let format = value_record_format(fld);
+            quote!(Field::new(#name_str, self.#name() #maybe_unwrap .traversal_type(#offset_data, #format)))
+        }
+        FieldType::Struct { .. } if !in_record => {
+            let offset_data = pass_data
+                .cloned()
+                .unwrap_or_else(|| fld.offset_getter_data_src());
+            quote!(Field::new(#name_str, traversal::FieldType::Record(self.#name() #maybe_unwrap .clone().traverse(#offset_data))))
         }
         FieldType::Struct { .. } => {
             quote!(compile_error!(concat!("another weird type: ", #name_str)))
@@ -416,6 +468,14 @@ fn traversal_arm_for_field(
     }
 }
 
+/// Returns the expression for the format of a value record field in a table.
+fn value_record_format(fld: &Field) -> TokenStream {
+    match &fld.attrs.read_with_args {
+        Some(args) => args.to_tokens_for_table_getter(),
+        None => quote!(compile_error!("ValueRecord requires a format")),
+    }
+}
+
 fn check_resolution(phase: Phase, field_type: &FieldType) -> syn::Result<()> {
     if let Phase::Parse = phase {
         return Ok(());
@@ -585,6 +645,9 @@ impl Field {
             FieldType::Offset { typ, .. } | FieldType::Scalar { typ } => {
                 quote!(#typ::RAW_BYTE_LEN)
             }
+            FieldType::Struct { typ } if !self.has_computed_len() => {
+                quote!(#typ::RAW_BYTE_LEN)
+            }
             FieldType::Struct { .. }
             | FieldType::Array { .. }
             | FieldType::ComputedArray { .. }
@@ -702,6 +765,13 @@ impl Field {
             quote!(VarLenArray::read(self.data.split_off(range.start).unwrap()).unwrap())
         } else if is_array {
             quote!(self.data.read_array(range).unwrap())
+        } else if let FieldType::Struct { typ } = &self.typ {
+            // fixed size records are returned by reference
+            return_type = quote!(&'a #typ);
+            if is_versioned {
+                return_type = quote!(Option<#return_type>);
+            }
+            quote!(self.data.read_ref_at(range.start).unwrap())
         } else {
             quote!(self.data.read_at(range.start).unwrap())
         };
@@ -929,6 +999,9 @@ impl Field {
             && !self.validate_at_parse()
             && !self.is_version_dependent()
         {
+            if let FieldType::Struct { typ } = &self.typ {
+                return quote!( cursor.advance_by(#typ::RAW_BYTE_LEN); );
+            }
             let typ = self.typ.cooked_type_tokens();
             return quote!( cursor.advance::<#typ>(); );
         }
@@ -943,6 +1016,17 @@ impl Field {
             let len_field_name = self.shape_byte_len_field_name();
 
             match &self.attrs.since_version {
+                // computing the length may fail, so only do so if the field is present
+                Some(version) if self.has_fallible_len() => quote! {
+                    let #len_field_name = if version.compatible(#version) {
+                        Some(#len_expr)
+                    } else {
+                        None
+                    };
+                    if let Some(value) = #len_field_name {
+                        cursor.advance_by(value);
+                    }
+                },
                 Some(version) => quote! {
                     let #len_field_name = version.compatible(#version).then_some(#len_expr);
                     if let Some(value) = #len_field_name {
@@ -979,6 +1063,14 @@ impl Field {
         }
     }
 
+    /// True if computing the length of this field can fail.
+    fn has_fallible_len(&self) -> bool {
+        matches!(
+            self.typ,
+            FieldType::Struct { .. } | FieldType::ComputedArray(_)
+        )
+    }
+
     /// The computed length of this field, if it is not a scalar/offset
     fn computed_len_expr(&self) -> Option<TokenStream> {
         if !self.has_computed_len() {
@@ -993,27 +1085,39 @@ impl Field {
             .map(FieldReadArgs::to_tokens_for_validation);
 
         if let FieldType::Struct { typ } = &self.typ {
-            return Some(quote!( <#typ as ComputeSize>::compute_size(&#read_args)));
+            return Some(quote!( <#typ as ComputeSize>::compute_size(&#read_args)?));
         }
         if let FieldType::PendingResolution { .. } = &self.typ {
             panic!("Should have resolved {self:?}")
         }
         let len_expr = match self.attrs.count.as_deref() {
-            Some(Count::All(_)) => quote!(cursor.remaining_bytes()),
+            Some(Count::All(_)) => match &self.typ {
+                // trailing bytes that do not make up a complete element are
+                // ignored, so that reading the array later cannot fail
+                FieldType::Array { inner_typ } if !inner_typ.is_u8() => {
+                    let inner_typ = inner_typ.cooked_type_tokens();
+                    quote!(cursor.remaining_bytes() / #inner_typ::RAW_BYTE_LEN * #inner_typ::RAW_BYTE_LEN)
+                }
+                _ => quote!(cursor.remaining_bytes()),
+            },
             Some(other) => {
                 let count_expr = other.count_expr();
-                let size_expr = match &self.typ {
+                match &self.typ {
                     FieldType::Array { inner_typ } => {
                         let inner_typ = inner_typ.cooked_type_tokens();
-                        quote!( #inner_typ::RAW_BYTE_LEN )
+                        quote!( #count_expr * #inner_typ::RAW_BYTE_LEN )
                     }
+                    // item sizes are read from the font, so may be large
                     FieldType::ComputedArray(array) => {
                         let inner = array.raw_inner_type();
-                        quote!( <#inner as ComputeSize>::compute_size(&#read_args) )
+                        quote! {
+                            (#count_expr)
+                                .checked_mul(<#inner as ComputeSize>::compute_size(&#read_args)?)
+                                .ok_or(ReadError::OutOfBounds)?
+                        }
                     }
                     _ => unreachable!("count not valid here"),
-                };
-                quote!(  #count_expr * #size_expr )
+                }
             }
             None => quote!(compile_error!("missing count attribute?")),
         };
@@ -1295,6 +1399,10 @@ impl Field {
 }
 
 impl FieldType {
+    fn is_u8(&self) -> bool {
+        matches!(self, FieldType::Scalar { typ } if typ == "u8")
+    }
+
     /// 'cooked', as in now 'raw', i.e no 'BigEndian' wrapper
     pub(crate) fn cooked_type_tokens(&self) -> &syn::Ident {
         match &self {
@@ -1374,6 +1482,9 @@ pub(crate) fn remove_offset_from_field_name(name: &str) -> Cow<str> {
         let temp = name.trim_end_matches("_offsets");
         // hacky attempt to respect pluralization rules. we can update this
         // as we encounter actual tables, instead of trying to be systematic
+        if let Some(stem) = temp.strip_suffix("priority") {
+            return Cow::Owned(format!("{stem}priorities"));
+        }
         let suffix = if temp.ends_with("attach") {
             "es"
         } else if temp.ends_with("data") {
@@ -277,6 +277,8 @@ pub(crate) enum CountTransform {
     Add,
     /// requires exactly three args, defined as ($arg1 + $arg2) * $arg3
     AddMul,
+    /// requires exactly two args, defined as $arg1 * $arg2
+    Mul,
     /// requires exactly one arg. defined as $arg1 / 2
     Half,
     DeltaValueCount,
@@ -1368,6 +1370,7 @@ static TRANSFORM_IDENTS: &[(CountTransform, &str)] = &[
     (CountTransform::Sub, "subtract"),
     (CountTransform::Add, "add"),
     (CountTransform::AddMul, "add_multiply"),
+    (CountTransform::Mul, "multiply"),
     (CountTransform::Half, "half"),
     (CountTransform::DeltaValueCount, "delta_value_count"),
     (CountTransform::DeltaSetIndexData, "delta_set_index_data"),
@@ -1400,6 +1403,7 @@ impl CountTransform {
             CountTransform::Sub => 2,
             CountTransform::Add => 2,
             CountTransform::AddMul => 3,
+            CountTransform::Mul => 2,
             CountTransform::Half => 1,
             CountTransform::DeltaValueCount => 3,
             CountTransform::DeltaSetIndexData => 2,
@@ -1525,6 +1529,9 @@ impl Count {
                 (CountTransform::AddMul, [a, b, c]) => {
                     quote!(transforms::add_multiply(#a, #b, #c))
                 }
+                (CountTransform::Mul, [a, b]) => {
+                    quote!(transforms::multiply(#a, #b))
+                }
                 (CountTransform::Half, [a]) => {
                     quote!(transforms::half(#a))
                 }
@@ -95,9 +95,10 @@ fn generate_read_with_args(item: &Record) -> TokenStream {
         }
 
         impl ComputeSize for #name #anon_lifetime {
-            fn compute_size(args: &#args_type) -> usize {
+            fn compute_size(args: &#args_type) -> Result<usize, ReadError> {
                 let #destructure_pattern = *args;
-                #( #field_size_expr )+*
+                let size = #( #field_size_expr )+*;
+                Ok(size)
             }
         }
 
@@ -129,7 +130,9 @@ fn generate_traversal(item: &Record) -> syn::Result<TokenStream> {
     let name = &item.name;
     let name_str = name.to_string();
     let lifetime = &item.lifetime;
-    let field_arms = item.fields.iter_field_traversal_match_arms(true);
+    let field_arms = item
+        .fields
+        .iter_field_traversal_match_arms(true, item.is_zerocopy());
 
     Ok(quote! {
         #[cfg(feature = "traversal")]
@@ -142,6 +145,7 @@ fn generate_traversal(item: &Record) -> syn::Result<TokenStream> {
                         _ => None,
                     }),
                     data,
+                    bytes: None,
                 }
             }
         }
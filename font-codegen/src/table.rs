@@ -209,6 +209,7 @@ pub(crate) fn generate_group(item: &GenericGroup) -> syn::Result<TokenStream> {
 
     Ok(quote! {
         #( #docs)*
+        #[derive(Clone)]
         pub enum #name <'a> {
             #( #variant_decls, )*
         }
@@ -258,7 +259,7 @@ fn generate_debug(item: &Table) -> syn::Result<TokenStream> {
         let name = &fld.name;
         quote!(let version = self.#name();)
     });
-    let field_arms = item.fields.iter_field_traversal_match_arms(false);
+    let field_arms = item.fields.iter_field_traversal_match_arms(false, false);
     let attrs = item.fields.fields.is_empty().then(|| {
         quote! {
             #[allow(unused_variables)]
@@ -673,6 +674,7 @@ pub(crate) fn generate_format_group(item: &TableFormat) -> syn::Result<TokenStre
 
     Ok(quote! {
         #( #docs )*
+        #[derive(Clone)]
         pub enum #name<'a> {
             #( #variants ),*
         }
@@ -0,0 +1,165 @@
+//! Printing disassembled CFF and CFF2 charstrings.
+
+use std::ops::Range;
+
+use font_types::GlyphId;
+use read_fonts::{
+    tables::{
+        postscript::{
+            charstring::{self, Instruction},
+            dict, BlendState, Error, FdSelect, Index,
+        },
+        variations::ItemVariationStore,
+    },
+    FontData, FontRead, FontRef, TableProvider,
+};
+
+/// Print the charstring for a glyph, one instruction per line.
+///
+/// Blends are decoded at the default location.
+pub fn print_charstring(
+    font: &FontRef,
+    glyph_id: GlyphId,
+    inline_subrs: bool,
+) -> Result<(), String> {
+    let source = CharstringSource::new(font).map_err(|e| e.to_string())?;
+    source
+        .print(glyph_id, inline_subrs)
+        .map_err(|e| format!("failed to decode charstring for glyph {glyph_id}: {e}"))
+}
+
+/// The parts of a CFF or CFF2 table needed to locate and decode a charstring.
+struct CharstringSource<'a> {
+    is_cff2: bool,
+    table_data: &'a [u8],
+    global_subrs: Index<'a>,
+    charstrings: Option<Index<'a>>,
+    font_dicts: Option<Index<'a>>,
+    fd_select: Option<FdSelect<'a>>,
+    private_dict_range: Option<Range<usize>>,
+    var_store: Option<ItemVariationStore<'a>>,
+}
+
+impl<'a> CharstringSource<'a> {
+    fn new(font: &FontRef<'a>) -> Result<Self, Error> {
+        let (is_cff2, table_data, top_dict_data, global_subrs) = if let Ok(cff2) = font.cff2() {
+            let table_data = cff2.offset_data().as_bytes();
+            (
+                true,
+                table_data,
+                cff2.top_dict_data(),
+                cff2.global_subrs().into(),
+            )
+        } else {
+            let cff = font.cff()?;
+            let table_data = cff.offset_data().as_bytes();
+            let top_dict_data = cff.top_dicts().get(0)?;
+            (false, table_data, top_dict_data, cff.global_subrs().into())
+        };
+        let mut source = Self {
+            is_cff2,
+            table_data,
+            global_subrs,
+            charstrings: None,
+            font_dicts: None,
+            fd_select: None,
+            private_dict_range: None,
+            var_store: None,
+        };
+        let data_at = |offset: usize| table_data.get(offset..).unwrap_or_default();
+        for entry in dict::entries(top_dict_data, None) {
+            match entry? {
+                dict::Entry::CharstringsOffset(offset) => {
+                    source.charstrings = Some(Index::new(data_at(offset), is_cff2)?);
+                }
+                dict::Entry::FdArrayOffset(offset) => {
+                    source.font_dicts = Some(Index::new(data_at(offset), is_cff2)?);
+                }
+                dict::Entry::FdSelectOffset(offset) => {
+                    source.fd_select = Some(FdSelect::read(FontData::new(data_at(offset)))?);
+                }
+                dict::Entry::PrivateDictRange(range) => source.private_dict_range = Some(range),
+                dict::Entry::VariationStoreOffset(offset) if is_cff2 => {
+                    // IVS is preceded by a 2 byte length
+                    source.var_store = Some(ItemVariationStore::read(FontData::new(data_at(
+                        offset + 2,
+                    )))?);
+                }
+                _ => {}
+            }
+        }
+        Ok(source)
+    }
+
+    fn blend_state(&self, store_index: u16) -> Result<Option<BlendState<'a>>, Error> {
+        self.var_store
+            .clone()
+            .map(|store| BlendState::new(store, &[], store_index))
+            .transpose()
+    }
+
+    fn print(&self, glyph_id: GlyphId, inline_subrs: bool) -> Result<(), Error> {
+        let charstring_data = self
+            .charstrings
+            .as_ref()
+            .ok_or(Error::MissingCharstrings)?
+            .get(glyph_id.to_u16() as usize)?;
+        let fd_index = self
+            .fd_select
+            .as_ref()
+            .and_then(|select| select.font_index(glyph_id))
+            .unwrap_or(0);
+        let private_dict_range = match &self.font_dicts {
+            Some(font_dicts) => {
+                let mut range = None;
+                for entry in dict::entries(font_dicts.get(fd_index as usize)?, None) {
+                    if let dict::Entry::PrivateDictRange(r) = entry? {
+                        range = Some(r);
+                    }
+                }
+                range
+            }
+            None => self.private_dict_range.clone(),
+        };
+        let mut subrs = None;
+        let mut store_index = 0;
+        if let Some(range) = private_dict_range {
+            let private_dict_data = self.table_data.get(range.clone()).unwrap_or_default();
+            for entry in dict::entries(private_dict_data, self.blend_state(0)?) {
+                match entry? {
+                    // Subrs offset is relative to the private DICT
+                    dict::Entry::SubrsOffset(offset) => {
+                        let subrs_data = self
+                            .table_data
+                            .get(range.start + offset..)
+                            .unwrap_or_default();
+                        subrs = Some(Index::new(subrs_data, self.is_cff2)?);
+                    }
+                    dict::Entry::VariationStoreIndex(index) => store_index = index,
+                    _ => {}
+                }
+            }
+        }
+        println!(
+            "glyph {glyph_id}: {} bytes, font dict {fd_index}",
+            charstring_data.len()
+        );
+        let mut disassembler = charstring::disassemble(
+            charstring_data,
+            self.global_subrs.clone(),
+            subrs,
+            self.blend_state(store_index)?,
+        );
+        if inline_subrs {
+            disassembler = disassembler.inline_subroutines();
+        }
+        for instruction in disassembler {
+            let instruction = instruction?;
+            match &instruction {
+                Instruction::Call { index, .. } => println!("  {instruction}  # subr {index}"),
+                _ => println!("  {instruction}"),
+            }
+        }
+        Ok(())
+    }
+}
@@ -5,18 +5,23 @@
 
 use std::{collections::HashSet, str::FromStr};
 
-use font_types::Tag;
-use read_fonts::{traversal::SomeTable, FileRef, FontRef, ReadError, TableProvider, TopLevelTable};
-
+use font_types::{GlyphId, Tag};
+use read_fonts::{
+    checksum::{self, ChecksumError},
+    traversal::SomeTable,
+    FileRef, FontRef, ReadError,
+};
+
+mod charstring;
 mod print;
 mod query;
 
 use print::PrettyPrinter;
-use query::Query;
+use query::{BytePosition, Query};
 
 fn main() -> Result<(), Error> {
     let args = flags::Args::from_env().map_err(|e| Error(e.to_string()))?;
-    let bytes = std::fs::read(&args.input).unwrap();
+    let bytes = decode_web_font(std::fs::read(&args.input).unwrap());
     let font = FileRef::new(&bytes)
         .unwrap()
         .fonts()
@@ -32,30 +37,67 @@ fn main() -> Result<(), Error> {
         return query::print_query(&font, query).map_err(Error);
     }
 
+    if let Some(glyph_id) = args.glyph {
+        return charstring::print_charstring(&font, GlyphId::new(glyph_id), args.inline_subrs)
+            .map_err(Error);
+    }
+
+    if let Some(pos) = args.at {
+        return query::print_location(&font, &bytes, pos).map_err(Error);
+    }
+
     let filter = TableFilter::from_args(&args)?;
-    print_tables(&font, &filter);
+    let offsets = args.offsets.then_some(bytes.as_slice());
+    print_tables(&font, &filter, offsets);
     Ok(())
 }
 
+/// Converts WOFF and WOFF2 files to sfnt, and returns other data unchanged.
+fn decode_web_font(bytes: Vec<u8>) -> Vec<u8> {
+    let decoded = match FileRef::new(&bytes) {
+        Ok(FileRef::Woff(woff)) => Some(woff.to_sfnt()),
+        Ok(FileRef::Woff2(woff)) => Some(woff.to_sfnt()),
+        _ => None,
+    };
+    match decoded {
+        Some(sfnt) => sfnt.unwrap(),
+        None => bytes,
+    }
+}
+
 fn list_tables(font: &FontRef) {
-    println!("Tag  Offset  Length  Checksum");
-    println!("-------------------------------");
+    println!("Tag  Offset  Length  Checksum  Mismatch");
+    println!("-----------------------------------------");
 
     let offset_pad = get_offset_width(font);
+    let mismatches = checksum::verify_table_records(font).collect::<Vec<_>>();
 
     for record in font.table_directory.table_records() {
+        let mismatch = mismatches.iter().find_map(|error| match *error {
+            ChecksumError::OutOfBounds(tag) if tag == record.tag() => {
+                Some("out of bounds".to_string())
+            }
+            ChecksumError::Mismatch { tag, actual, .. } if tag == record.tag() => {
+                Some(format!("0x{actual:08X}"))
+            }
+            _ => None,
+        });
         println!(
-            "{0} 0x{1:02$X} {3:8} 0x{4:08X} ",
+            "{0} 0x{1:02$X} {3:8} 0x{4:08X} {5}",
             record.tag(),
             record.offset(),
             offset_pad,
             record.length(),
-            record.checksum()
+            record.checksum(),
+            mismatch.unwrap_or_default(),
         );
     }
+    if let Err(error) = checksum::verify_checksum_adjustment(font) {
+        println!("\n{error}");
+    }
 }
 
-fn print_tables(font: &FontRef, filter: &TableFilter) {
+fn print_tables(font: &FontRef, filter: &TableFilter, offsets: Option<&[u8]>) {
     let mut printed = HashSet::new();
     for tag in font
         .table_directory
@@ -65,7 +107,7 @@ fn print_tables(font: &FontRef, filter: &TableFilter) {
         .filter(|tag| filter.should_print(*tag))
     {
         printed.insert(tag);
-        print_table(font, tag)
+        print_table(font, tag, offsets)
     }
 
     if let TableFilter::Include(to_print) = filter {
@@ -101,33 +143,12 @@ fn get_some_table<'a>(
     font: &FontRef<'a>,
     tag: Tag,
 ) -> Result<Box<dyn SomeTable<'a> + 'a>, ReadError> {
-    use read_fonts::tables;
-    match tag {
-        tables::gpos::Gpos::TAG => font.gpos().map(|x| Box::new(x) as _),
-        tables::gsub::Gsub::TAG => font.gsub().map(|x| Box::new(x) as _),
-        tables::cmap::Cmap::TAG => font.cmap().map(|x| Box::new(x) as _),
-        tables::fvar::Fvar::TAG => font.fvar().map(|x| Box::new(x) as _),
-        tables::avar::Avar::TAG => font.avar().map(|x| Box::new(x) as _),
-        tables::gdef::Gdef::TAG => font.gdef().map(|x| Box::new(x) as _),
-        tables::glyf::Glyf::TAG => font.glyf().map(|x| Box::new(x) as _),
-        tables::head::Head::TAG => font.head().map(|x| Box::new(x) as _),
-        tables::hhea::Hhea::TAG => font.hhea().map(|x| Box::new(x) as _),
-        tables::hmtx::Hmtx::TAG => font.hmtx().map(|x| Box::new(x) as _),
-        tables::loca::Loca::TAG => font.loca(None).map(|x| Box::new(x) as _),
-        tables::maxp::Maxp::TAG => font.maxp().map(|x| Box::new(x) as _),
-        tables::name::Name::TAG => font.name().map(|x| Box::new(x) as _),
-        tables::post::Post::TAG => font.post().map(|x| Box::new(x) as _),
-        tables::colr::Colr::TAG => font.colr().map(|x| Box::new(x) as _),
-        tables::stat::Stat::TAG => font.stat().map(|x| Box::new(x) as _),
-        tables::vhea::Vhea::TAG => font.vhea().map(|x| Box::new(x) as _),
-        tables::vmtx::Vmtx::TAG => font.vmtx().map(|x| Box::new(x) as _),
-        _ => Err(ReadError::TableIsMissing(tag)),
-    }
+    read_fonts::traversal::some_table(font, tag)
 }
 
-fn print_table(font: &FontRef, tag: Tag) {
+fn print_table(font: &FontRef, tag: Tag, offsets: Option<&[u8]>) {
     match get_some_table(font, tag) {
-        Ok(table) => fancy_print_table(&table).unwrap(),
+        Ok(table) => fancy_print_table(&table, offsets).unwrap(),
         Err(err) => println!("{tag}: Error '{err}'"),
     }
 }
@@ -190,15 +211,22 @@ impl std::fmt::Display for Error {
 
 impl std::error::Error for Error {}
 
-fn fancy_print_table<'a>(table: &(dyn SomeTable<'a> + 'a)) -> std::io::Result<()> {
+/// Print a table, optionally annotating fields with their offsets in `font_data`.
+fn fancy_print_table<'a>(
+    table: &(dyn SomeTable<'a> + 'a),
+    font_data: Option<&[u8]>,
+) -> std::io::Result<()> {
     let stdout = std::io::stdout();
     let mut locked = stdout.lock();
     let mut formatter = PrettyPrinter::new(&mut locked);
+    if let Some(font_data) = font_data {
+        formatter = formatter.with_offsets(font_data);
+    }
     formatter.print_root_table(table)
 }
 
 mod flags {
-    use super::Query;
+    use super::{BytePosition, Query};
     use std::path::PathBuf;
 
     xflags::xflags! {
@@ -208,6 +236,14 @@ mod flags {
             optional -i, --index index: u32
             optional -l, --list
             optional -q, --query query: Query
+            /// Print the field containing the byte at this position in the file.
+            optional -a, --at position: BytePosition
+            /// Annotate printed fields with their position in the file.
+            optional -o, --offsets
+            /// Print the disassembled CFF or CFF2 charstring for this glyph id.
+            optional -g, --glyph glyph: u16
+            /// Expand subroutine calls when printing a charstring.
+            optional --inline-subrs
             optional -t, --tables include: String
             optional -x, --exclude exclude: String
         }
@@ -4,8 +4,8 @@ use std::io::Write;
 
 use ansi_term::{Color, Style};
 use read_fonts::traversal::{
-    ArrayOffset, FieldType, OffsetType, ResolvedOffset, SomeArray, SomeString, SomeTable,
-    StringOffset,
+    subslice_range, ArrayOffset, FieldType, OffsetType, ResolvedOffset, SomeArray, SomeString,
+    SomeTable, StringOffset,
 };
 
 static MANY_SPACES: [u8; 200] = [0x20; 200];
@@ -20,6 +20,8 @@ pub struct PrettyPrinter<'a> {
     is_tty: bool,
     cur_array_item: Option<usize>,
     indent_size: usize,
+    // if set, fields are annotated with their position in this data
+    font_data: Option<&'a [u8]>,
     writer: &'a mut (dyn std::io::Write + 'a),
 }
 
@@ -51,10 +53,17 @@ impl<'a> PrettyPrinter<'a> {
             cur_array_item: None,
             is_tty: atty::is(atty::Stream::Stdout),
             indent_size: 2,
+            font_data: None,
             writer,
         }
     }
 
+    /// Annotate printed fields with their offset from the start of `font_data`.
+    pub fn with_offsets(mut self, font_data: &'a [u8]) -> Self {
+        self.font_data = Some(font_data);
+        self
+    }
+
     pub fn print_table_header(&mut self) -> std::io::Result<()> {
         writeln!(
             self,
@@ -131,6 +140,7 @@ impl<'a> PrettyPrinter<'a> {
                 self.print_newline()?;
             }
             self.print_indent()?;
+            self.print_position(field.bytes())?;
             self.print_with_style(Color::Cyan.into(), |this| write!(this, "{}", field.name))?;
             write!(self, ": ")?;
             self.print_field(&field.value)?;
@@ -148,8 +158,9 @@ impl<'a> PrettyPrinter<'a> {
                 if i != 0 {
                     this.print_newline()?;
                 }
-                if is_scalar(&item) {
+                if is_scalar(&item) || is_offset(&item) {
                     this.print_indent()?;
+                    this.print_position(array.get_bytes(i))?;
                 }
                 this.print_field(&item)?;
             }
@@ -301,6 +312,18 @@ impl<'a> PrettyPrinter<'a> {
         }
     }
 
+    fn print_position(&mut self, bytes: Option<&[u8]>) -> std::io::Result<()> {
+        let Some(font_data) = self.font_data else {
+            return Ok(());
+        };
+        match bytes.and_then(|bytes| subslice_range(font_data, bytes)) {
+            Some(range) => self.print_with_style(Color::Fixed(243).into(), |this| {
+                write!(this, "0x{:06X} ", range.start)
+            }),
+            None => write!(self, "{:9}", ""),
+        }
+    }
+
     fn print_current_array_pos(&mut self) -> std::io::Result<()> {
         if let Some(idx) = self.cur_array_item.take() {
             let padding = ARRAY_POS_WIDTH.saturating_sub(self.line_pos);
@@ -337,6 +360,13 @@ impl<'a> PrettyPrinter<'a> {
     }
 }
 
+fn is_offset(field_type: &FieldType) -> bool {
+    matches!(
+        field_type,
+        FieldType::ResolvedOffset(_) | FieldType::BareOffset(_)
+    )
+}
+
 fn is_scalar(field_type: &FieldType) -> bool {
     matches!(
         field_type,
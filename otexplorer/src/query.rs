@@ -1,13 +1,18 @@
 //! querying paths within fonts
 
-use std::{borrow::Cow, fmt::Write, str::FromStr};
+use std::{borrow::Cow, fmt::Write, ops::Range, str::FromStr};
 
 use font_types::Tag;
 use read_fonts::{
-    traversal::{Field, FieldType, ResolvedOffset, SomeTable},
+    traversal::{
+        subslice_range, ArrayOffset, Field, FieldType, ResolvedOffset, SomeArray, SomeTable,
+    },
     FontRef,
 };
 
+// the maximum number of fields visited when searching for a byte position
+const MAX_LOCATE_STEPS: usize = 1 << 20;
+
 #[derive(Clone, Debug)]
 pub struct Query {
     tag: Tag,
@@ -38,12 +43,160 @@ pub fn print_query(font: &FontRef, query: &Query) -> Result<(), String> {
         }
         Some((QueryElement::Index(_), _)) => Err("tables cannot be indexed".into()),
         None => {
-            super::fancy_print_table(&table).unwrap();
+            super::fancy_print_table(&table, None).unwrap();
             Ok(())
         }
     }
 }
 
+/// A position in the font file, parsed from a decimal or `0x`-prefixed hex string.
+#[derive(Clone, Copy, Debug)]
+pub struct BytePosition(usize);
+
+/// Print the path to, and the value of, the field containing the byte at `pos`.
+///
+/// `font_data` must be the data of the entire file that `font` was read from.
+pub fn print_location(font: &FontRef, font_data: &[u8], pos: BytePosition) -> Result<(), String> {
+    let pos = pos.0;
+    let records = font.table_directory.table_records();
+    let Some(record) = records.iter().find(|record| {
+        let start = record.offset() as usize;
+        (start..start + record.length() as usize).contains(&pos)
+    }) else {
+        let directory = font.table_directory.offset_data().as_bytes();
+        let directory_len = 12 + records.len() * 16;
+        return match subslice_range(font_data, directory) {
+            Some(range) if (range.start..range.start + directory_len).contains(&pos) => {
+                println!("0x{pos:X} is in the table directory");
+                Ok(())
+            }
+            _ => Err(format!("0x{pos:X} is not in any table")),
+        };
+    };
+    let tag = record.tag();
+    println!(
+        "0x{pos:X} is in '{tag}', at offset 0x{:X} from the start of the table",
+        pos - record.offset() as usize
+    );
+    let table = super::get_some_table(font, tag).map_err(|err| err.to_string())?;
+    let mut locator = Locator {
+        font_data,
+        pos,
+        path: Vec::new(),
+        steps: 0,
+    };
+    let Some((target, range)) = locator.find_in_table(&table) else {
+        let reason = if locator.steps > MAX_LOCATE_STEPS {
+            "search limit exceeded"
+        } else {
+            "it may be padding or unreferenced data"
+        };
+        return Err(format!("no field found at 0x{pos:X} ({reason})"));
+    };
+    let mut path = tag.to_string();
+    for element in &locator.path {
+        match element {
+            QueryElement::Field(name) => write!(&mut path, ".{name}").unwrap(),
+            QueryElement::Index(idx) => write!(&mut path, ".{idx}").unwrap(),
+        }
+    }
+    println!("path  {path}");
+    println!(
+        "found {} at 0x{:X}..0x{:X}",
+        field_type_name(&target),
+        range.start,
+        range.end
+    );
+    println!();
+    print_field(target).map_err(|e| format!("print failed: '{e}'"))
+}
+
+/// State for a depth-first search for the field containing a byte.
+struct Locator<'b> {
+    font_data: &'b [u8],
+    pos: usize,
+    path: Vec<QueryElement>,
+    steps: usize,
+}
+
+impl Locator<'_> {
+    fn find_in_table<'a>(
+        &mut self,
+        table: &(dyn SomeTable<'a> + 'a),
+    ) -> Option<(FieldType<'a>, Range<usize>)> {
+        for field in table.iter() {
+            self.path.push(QueryElement::Field(field.name.to_string()));
+            let range = field.byte_range(self.font_data);
+            if let Some(found) = self.find_in_value(field.value, range) {
+                return Some(found);
+            }
+            self.path.pop();
+        }
+        None
+    }
+
+    fn find_in_value<'a>(
+        &mut self,
+        value: FieldType<'a>,
+        range: Option<Range<usize>>,
+    ) -> Option<(FieldType<'a>, Range<usize>)> {
+        self.steps += 1;
+        if self.steps > MAX_LOCATE_STEPS {
+            return None;
+        }
+        if let Some(range) = range.filter(|range| range.contains(&self.pos)) {
+            // a compound value may contain a more specific match
+            let found = match &value {
+                FieldType::Record(record) => self.find_in_table(record),
+                FieldType::Array(array) => self.find_in_array(array.as_ref()),
+                _ => None,
+            };
+            return found.or_else(|| Some((without_target(value), range)));
+        }
+        match value {
+            FieldType::ResolvedOffset(ResolvedOffset {
+                target: Ok(table), ..
+            }) => self.find_in_table(&table),
+            FieldType::ArrayOffset(ArrayOffset {
+                target: Ok(array), ..
+            }) => self.find_in_array(array.as_ref()),
+            FieldType::Record(record) => self.find_in_table(&record),
+            FieldType::Array(array) => self.find_in_array(array.as_ref()),
+            _ => None,
+        }
+    }
+
+    fn find_in_array<'a>(
+        &mut self,
+        array: &(dyn SomeArray<'a> + 'a),
+    ) -> Option<(FieldType<'a>, Range<usize>)> {
+        for (i, item) in array.iter().enumerate() {
+            self.path.push(QueryElement::Index(i as u32));
+            let range = array
+                .get_bytes(i)
+                .and_then(|bytes| subslice_range(self.font_data, bytes));
+            if let Some(found) = self.find_in_value(item, range) {
+                return Some(found);
+            }
+            self.path.pop();
+            if self.steps > MAX_LOCATE_STEPS {
+                return None;
+            }
+        }
+        None
+    }
+}
+
+/// When the byte is in an offset, we only want to print the offset itself.
+fn without_target(value: FieldType) -> FieldType {
+    match value {
+        FieldType::ResolvedOffset(ResolvedOffset { offset, .. })
+        | FieldType::ArrayOffset(ArrayOffset { offset, .. }) => FieldType::BareOffset(offset),
+        FieldType::StringOffset(string) => FieldType::BareOffset(string.offset),
+        value => value,
+    }
+}
+
 fn get_field<'a>(table: &(dyn SomeTable<'a> + 'a), name: &str) -> Result<Field<'a>, String> {
     let mut result = None;
     for field in table.iter() {
@@ -185,6 +338,20 @@ fn field_type_name(field_type: &FieldType) -> Cow<'static, str> {
     }
 }
 
+impl FromStr for BytePosition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => usize::from_str_radix(hex, 16),
+            None => s.parse(),
+        };
+        parsed
+            .map(BytePosition)
+            .map_err(|_| format!("invalid byte position '{s}'"))
+    }
+}
+
 impl FromStr for Query {
     type Err = String;
 
@@ -218,4 +385,11 @@ mod tests {
     fn fuzzy() {
         assert!(!ascii_fuzzy_match("off", "lookup_flag"));
     }
+
+    #[test]
+    fn byte_position() {
+        assert_eq!("0x1F".parse::<BytePosition>().unwrap().0, 31);
+        assert_eq!("31".parse::<BytePosition>().unwrap().0, 31);
+        assert!("0xZZ".parse::<BytePosition>().is_err());
+    }
 }
@@ -0,0 +1,28 @@
+//! Compares repeated table access through `FontRef` and `CachedFontRef`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use read_fonts::{CachedFontRef, FontRef, TableProvider};
+
+/// Reads the tables needed to load a single TrueType glyph.
+fn read_glyph_tables<'a>(font: &impl TableProvider<'a>) {
+    black_box(font.maxp().unwrap());
+    black_box(font.hmtx().unwrap());
+    black_box(font.glyf().unwrap());
+    black_box(font.gvar().unwrap());
+    black_box(font.loca(None).unwrap());
+}
+
+fn table_access(c: &mut Criterion) {
+    let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+    let cached = CachedFontRef::new(font.clone());
+    let mut group = c.benchmark_group("table_access");
+    group.bench_function("font_ref", |b| b.iter(|| read_glyph_tables(&font)));
+    group.bench_function("cached_font_ref", |b| b.iter(|| read_glyph_tables(&cached)));
+    group.bench_function("cached_font_ref_new", |b| {
+        b.iter(|| CachedFontRef::new(black_box(font.clone())))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, table_access);
+criterion_main!(benches);
@@ -100,19 +100,37 @@ impl<'a> SomeTable<'a> for TableDirectory<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("sfnt_version", self.sfnt_version())),
-            1usize => Some(Field::new("num_tables", self.num_tables())),
-            2usize => Some(Field::new("search_range", self.search_range())),
-            3usize => Some(Field::new("entry_selector", self.entry_selector())),
-            4usize => Some(Field::new("range_shift", self.range_shift())),
-            5usize => Some(Field::new(
-                "table_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(TableRecord),
-                    self.table_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("sfnt_version", self.sfnt_version())
+                    .with_bytes(self.data, self.shape.sfnt_version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_tables", self.num_tables())
+                    .with_bytes(self.data, self.shape.num_tables_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("search_range", self.search_range())
+                    .with_bytes(self.data, self.shape.search_range_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("entry_selector", self.entry_selector())
+                    .with_bytes(self.data, self.shape.entry_selector_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("range_shift", self.range_shift())
+                    .with_bytes(self.data, self.shape.range_shift_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "table_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(TableRecord),
+                        self.table_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.table_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -180,13 +198,27 @@ impl<'a> SomeRecord<'a> for TableRecord {
         RecordResolver {
             name: "TableRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("tag", self.tag())),
-                1usize => Some(Field::new("checksum", self.checksum())),
-                2usize => Some(Field::new("offset", self.offset())),
-                3usize => Some(Field::new("length", self.length())),
+                0usize => {
+                    Some(Field::new("tag", self.tag()).with_record_range(0, Tag::RAW_BYTE_LEN))
+                }
+                1usize => Some(
+                    Field::new("checksum", self.checksum())
+                        .with_record_range(Tag::RAW_BYTE_LEN, u32::RAW_BYTE_LEN),
+                ),
+                2usize => {
+                    Some(Field::new("offset", self.offset()).with_record_range(
+                        Tag::RAW_BYTE_LEN + u32::RAW_BYTE_LEN,
+                        u32::RAW_BYTE_LEN,
+                    ))
+                }
+                3usize => Some(Field::new("length", self.length()).with_record_range(
+                    Tag::RAW_BYTE_LEN + u32::RAW_BYTE_LEN + u32::RAW_BYTE_LEN,
+                    u32::RAW_BYTE_LEN,
+                )),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -319,22 +351,34 @@ impl<'a> SomeTable<'a> for TTCHeader<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("ttc_tag", self.ttc_tag())),
-            1usize => Some(Field::new("version", self.version())),
-            2usize => Some(Field::new("num_fonts", self.num_fonts())),
-            3usize => Some(Field::new(
-                "table_directory_offsets",
-                self.table_directory_offsets(),
-            )),
-            4usize if version.compatible((2, 0)) => {
-                Some(Field::new("dsig_tag", self.dsig_tag().unwrap()))
-            }
-            5usize if version.compatible((2, 0)) => {
-                Some(Field::new("dsig_length", self.dsig_length().unwrap()))
-            }
-            6usize if version.compatible((2, 0)) => {
-                Some(Field::new("dsig_offset", self.dsig_offset().unwrap()))
-            }
+            0usize => Some(
+                Field::new("ttc_tag", self.ttc_tag())
+                    .with_bytes(self.data, self.shape.ttc_tag_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("num_fonts", self.num_fonts())
+                    .with_bytes(self.data, self.shape.num_fonts_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("table_directory_offsets", self.table_directory_offsets())
+                    .with_bytes(self.data, self.shape.table_directory_offsets_byte_range()),
+            ),
+            4usize if version.compatible((2, 0)) => Some(
+                Field::new("dsig_tag", self.dsig_tag().unwrap())
+                    .with_bytes(self.data, self.shape.dsig_tag_byte_range()),
+            ),
+            5usize if version.compatible((2, 0)) => Some(
+                Field::new("dsig_length", self.dsig_length().unwrap())
+                    .with_bytes(self.data, self.shape.dsig_length_byte_range()),
+            ),
+            6usize if version.compatible((2, 0)) => Some(
+                Field::new("dsig_offset", self.dsig_offset().unwrap())
+                    .with_bytes(self.data, self.shape.dsig_offset_byte_range()),
+            ),
             _ => None,
         }
     }
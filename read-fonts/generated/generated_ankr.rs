@@ -0,0 +1,255 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [anchor point](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ankr.html) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct AnkrMarker {}
+
+impl AnkrMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn flags_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn lookup_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.flags_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn glyph_data_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.lookup_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl TopLevelTable for Ankr<'_> {
+    /// `ankr`
+    const TAG: Tag = Tag::new(b"ankr");
+}
+
+impl<'a> FontRead<'a> for Ankr<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.finish(AnkrMarker {})
+    }
+}
+
+/// The [anchor point](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ankr.html) table.
+pub type Ankr<'a> = TableRef<'a, AnkrMarker>;
+
+impl<'a> Ankr<'a> {
+    /// Version number (set to zero).
+    pub fn version(&self) -> u16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Flags (currently unused; set to zero).
+    pub fn flags(&self) -> u16 {
+        let range = self.shape.flags_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to the table's lookup table; currently this is always `0x0000000C`.
+    ///
+    /// Lookup values are two byte offsets into the glyph data table.
+    pub fn lookup_table_offset(&self) -> Offset32 {
+        let range = self.shape.lookup_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`lookup_table_offset`][Self::lookup_table_offset].
+    pub fn lookup_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.lookup_table_offset().resolve(data)
+    }
+
+    /// Offset to the glyph data table.
+    pub fn glyph_data_table_offset(&self) -> u32 {
+        let range = self.shape.glyph_data_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Ankr<'a> {
+    fn type_name(&self) -> &str {
+        "Ankr"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "lookup_table_offset",
+                    FieldType::offset(self.lookup_table_offset(), self.lookup_table()),
+                )
+                .with_bytes(self.data, self.shape.lookup_table_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("glyph_data_table_offset", self.glyph_data_table_offset())
+                    .with_bytes(self.data, self.shape.glyph_data_table_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Ankr<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The anchor points of a single glyph.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct GlyphDataEntryMarker {
+    anchor_points_byte_len: usize,
+}
+
+impl GlyphDataEntryMarker {
+    fn num_points_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn anchor_points_byte_range(&self) -> Range<usize> {
+        let start = self.num_points_byte_range().end;
+        start..start + self.anchor_points_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for GlyphDataEntry<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let num_points: u32 = cursor.read()?;
+        let anchor_points_byte_len = num_points as usize * AnchorPoint::RAW_BYTE_LEN;
+        cursor.advance_by(anchor_points_byte_len);
+        cursor.finish(GlyphDataEntryMarker {
+            anchor_points_byte_len,
+        })
+    }
+}
+
+/// The anchor points of a single glyph.
+pub type GlyphDataEntry<'a> = TableRef<'a, GlyphDataEntryMarker>;
+
+impl<'a> GlyphDataEntry<'a> {
+    /// Number of anchor points for this glyph.
+    pub fn num_points(&self) -> u32 {
+        let range = self.shape.num_points_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Individual anchor points.
+    pub fn anchor_points(&self) -> &'a [AnchorPoint] {
+        let range = self.shape.anchor_points_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for GlyphDataEntry<'a> {
+    fn type_name(&self) -> &str {
+        "GlyphDataEntry"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("num_points", self.num_points())
+                    .with_bytes(self.data, self.shape.num_points_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "anchor_points",
+                    traversal::FieldType::array_of_records(
+                        stringify!(AnchorPoint),
+                        self.anchor_points(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.anchor_points_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for GlyphDataEntry<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Individual anchor point.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct AnchorPoint {
+    /// X coordinate of this anchor point.
+    pub x: BigEndian<i16>,
+    /// Y coordinate of this anchor point.
+    pub y: BigEndian<i16>,
+}
+
+impl AnchorPoint {
+    /// X coordinate of this anchor point.
+    pub fn x(&self) -> i16 {
+        self.x.get()
+    }
+
+    /// Y coordinate of this anchor point.
+    pub fn y(&self) -> i16 {
+        self.y.get()
+    }
+}
+
+impl FixedSize for AnchorPoint {
+    const RAW_BYTE_LEN: usize = i16::RAW_BYTE_LEN + i16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for AnchorPoint {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for AnchorPoint {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for AnchorPoint {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "AnchorPoint",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(Field::new("x", self.x()).with_record_range(0, i16::RAW_BYTE_LEN)),
+                1usize => Some(
+                    Field::new("y", self.y())
+                        .with_record_range(i16::RAW_BYTE_LEN, i16::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
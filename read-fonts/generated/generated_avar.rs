@@ -81,16 +81,25 @@ impl<'a> SomeTable<'a> for Avar<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("axis_count", self.axis_count())),
-            2usize => Some(Field::new(
-                "axis_segment_maps",
-                traversal::FieldType::var_array(
-                    "SegmentMaps",
-                    self.axis_segment_maps(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("axis_count", self.axis_count())
+                    .with_bytes(self.data, self.shape.axis_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "axis_segment_maps",
+                    traversal::FieldType::var_array(
+                        "SegmentMaps",
+                        self.axis_segment_maps(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.axis_segment_maps_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -142,6 +151,7 @@ impl<'a> SomeRecord<'a> for SegmentMaps<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -186,11 +196,18 @@ impl<'a> SomeRecord<'a> for AxisValueMap {
         RecordResolver {
             name: "AxisValueMap",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("from_coordinate", self.from_coordinate())),
-                1usize => Some(Field::new("to_coordinate", self.to_coordinate())),
+                0usize => Some(
+                    Field::new("from_coordinate", self.from_coordinate())
+                        .with_record_range(0, F2Dot14::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("to_coordinate", self.to_coordinate())
+                        .with_record_range(F2Dot14::RAW_BYTE_LEN, F2Dot14::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
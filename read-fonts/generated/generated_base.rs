@@ -110,22 +110,31 @@ impl<'a> SomeTable<'a> for Base<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "horiz_axis_offset",
-                FieldType::offset(self.horiz_axis_offset(), self.horiz_axis()),
-            )),
-            2usize => Some(Field::new(
-                "vert_axis_offset",
-                FieldType::offset(self.vert_axis_offset(), self.vert_axis()),
-            )),
-            3usize if version.compatible((1, 1)) => Some(Field::new(
-                "item_var_store_offset",
-                FieldType::offset(
-                    self.item_var_store_offset().unwrap(),
-                    self.item_var_store().unwrap(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "horiz_axis_offset",
+                    FieldType::offset(self.horiz_axis_offset(), self.horiz_axis()),
+                )
+                .with_bytes(self.data, self.shape.horiz_axis_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "vert_axis_offset",
+                    FieldType::offset(self.vert_axis_offset(), self.vert_axis()),
+                )
+                .with_bytes(self.data, self.shape.vert_axis_offset_byte_range()),
+            ),
+            3usize if version.compatible((1, 1)) => Some(
+                Field::new(
+                    "item_var_store_offset",
+                    FieldType::offset(self.item_var_store_offset().unwrap(), self.item_var_store()),
+                )
+                .with_bytes(self.data, self.shape.item_var_store_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -200,14 +209,20 @@ impl<'a> SomeTable<'a> for Axis<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "base_tag_list_offset",
-                FieldType::offset(self.base_tag_list_offset(), self.base_tag_list()),
-            )),
-            1usize => Some(Field::new(
-                "base_script_list_offset",
-                FieldType::offset(self.base_script_list_offset(), self.base_script_list()),
-            )),
+            0usize => Some(
+                Field::new(
+                    "base_tag_list_offset",
+                    FieldType::offset(self.base_tag_list_offset(), self.base_tag_list()),
+                )
+                .with_bytes(self.data, self.shape.base_tag_list_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "base_script_list_offset",
+                    FieldType::offset(self.base_script_list_offset(), self.base_script_list()),
+                )
+                .with_bytes(self.data, self.shape.base_script_list_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -276,8 +291,14 @@ impl<'a> SomeTable<'a> for BaseTagList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("base_tag_count", self.base_tag_count())),
-            1usize => Some(Field::new("baseline_tags", self.baseline_tags())),
+            0usize => Some(
+                Field::new("base_tag_count", self.base_tag_count())
+                    .with_bytes(self.data, self.shape.base_tag_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("baseline_tags", self.baseline_tags())
+                    .with_bytes(self.data, self.shape.baseline_tags_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -346,15 +367,21 @@ impl<'a> SomeTable<'a> for BaseScriptList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("base_script_count", self.base_script_count())),
-            1usize => Some(Field::new(
-                "base_script_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(BaseScriptRecord),
-                    self.base_script_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("base_script_count", self.base_script_count())
+                    .with_bytes(self.data, self.shape.base_script_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "base_script_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(BaseScriptRecord),
+                        self.base_script_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.base_script_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -412,14 +439,21 @@ impl<'a> SomeRecord<'a> for BaseScriptRecord {
         RecordResolver {
             name: "BaseScriptRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("base_script_tag", self.base_script_tag())),
-                1usize => Some(Field::new(
-                    "base_script_offset",
-                    FieldType::offset(self.base_script_offset(), self.base_script(_data)),
-                )),
+                0usize => Some(
+                    Field::new("base_script_tag", self.base_script_tag())
+                        .with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "base_script_offset",
+                        FieldType::offset(self.base_script_offset(), self.base_script(_data)),
+                    )
+                    .with_record_range(Tag::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -514,26 +548,35 @@ impl<'a> SomeTable<'a> for BaseScript<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "base_values_offset",
-                FieldType::offset(self.base_values_offset(), self.base_values()),
-            )),
-            1usize => Some(Field::new(
-                "default_min_max_offset",
-                FieldType::offset(self.default_min_max_offset(), self.default_min_max()),
-            )),
-            2usize => Some(Field::new(
-                "base_lang_sys_count",
-                self.base_lang_sys_count(),
-            )),
-            3usize => Some(Field::new(
-                "base_lang_sys_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(BaseLangSysRecord),
-                    self.base_lang_sys_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new(
+                    "base_values_offset",
+                    FieldType::offset(self.base_values_offset(), self.base_values()),
+                )
+                .with_bytes(self.data, self.shape.base_values_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "default_min_max_offset",
+                    FieldType::offset(self.default_min_max_offset(), self.default_min_max()),
+                )
+                .with_bytes(self.data, self.shape.default_min_max_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("base_lang_sys_count", self.base_lang_sys_count())
+                    .with_bytes(self.data, self.shape.base_lang_sys_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "base_lang_sys_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(BaseLangSysRecord),
+                        self.base_lang_sys_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.base_lang_sys_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -591,14 +634,21 @@ impl<'a> SomeRecord<'a> for BaseLangSysRecord {
         RecordResolver {
             name: "BaseLangSysRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("base_lang_sys_tag", self.base_lang_sys_tag())),
-                1usize => Some(Field::new(
-                    "min_max_offset",
-                    FieldType::offset(self.min_max_offset(), self.min_max(_data)),
-                )),
+                0usize => Some(
+                    Field::new("base_lang_sys_tag", self.base_lang_sys_tag())
+                        .with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "min_max_offset",
+                        FieldType::offset(self.min_max_offset(), self.min_max(_data)),
+                    )
+                    .with_record_range(Tag::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -680,25 +730,31 @@ impl<'a> SomeTable<'a> for BaseValues<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "default_baseline_index",
-                self.default_baseline_index(),
-            )),
-            1usize => Some(Field::new("base_coord_count", self.base_coord_count())),
-            2usize => Some({
-                let data = self.data;
-                Field::new(
-                    "base_coord_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<BaseCoord>(),
-                        self.base_coord_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<BaseCoord>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("default_baseline_index", self.default_baseline_index())
+                    .with_bytes(self.data, self.shape.default_baseline_index_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("base_coord_count", self.base_coord_count())
+                    .with_bytes(self.data, self.shape.base_coord_count_byte_range()),
+            ),
+            2usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "base_coord_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<BaseCoord>(),
+                            self.base_coord_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<BaseCoord>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.base_coord_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -803,23 +859,35 @@ impl<'a> SomeTable<'a> for MinMax<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "min_coord_offset",
-                FieldType::offset(self.min_coord_offset(), self.min_coord()),
-            )),
-            1usize => Some(Field::new(
-                "max_coord_offset",
-                FieldType::offset(self.max_coord_offset(), self.max_coord()),
-            )),
-            2usize => Some(Field::new("feat_min_max_count", self.feat_min_max_count())),
-            3usize => Some(Field::new(
-                "feat_min_max_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(FeatMinMaxRecord),
-                    self.feat_min_max_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new(
+                    "min_coord_offset",
+                    FieldType::offset(self.min_coord_offset(), self.min_coord()),
+                )
+                .with_bytes(self.data, self.shape.min_coord_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "max_coord_offset",
+                    FieldType::offset(self.max_coord_offset(), self.max_coord()),
+                )
+                .with_bytes(self.data, self.shape.max_coord_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("feat_min_max_count", self.feat_min_max_count())
+                    .with_bytes(self.data, self.shape.feat_min_max_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "feat_min_max_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(FeatMinMaxRecord),
+                        self.feat_min_max_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.feat_min_max_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -895,22 +963,36 @@ impl<'a> SomeRecord<'a> for FeatMinMaxRecord {
         RecordResolver {
             name: "FeatMinMaxRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("feature_table_tag", self.feature_table_tag())),
-                1usize => Some(Field::new(
-                    "min_coord_offset",
-                    FieldType::offset(self.min_coord_offset(), self.min_coord(_data)),
-                )),
-                2usize => Some(Field::new(
-                    "max_coord_offset",
-                    FieldType::offset(self.max_coord_offset(), self.max_coord(_data)),
-                )),
+                0usize => Some(
+                    Field::new("feature_table_tag", self.feature_table_tag())
+                        .with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "min_coord_offset",
+                        FieldType::offset(self.min_coord_offset(), self.min_coord(_data)),
+                    )
+                    .with_record_range(Tag::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new(
+                        "max_coord_offset",
+                        FieldType::offset(self.max_coord_offset(), self.max_coord(_data)),
+                    )
+                    .with_record_range(
+                        Tag::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN,
+                        Offset16::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
 
+#[derive(Clone)]
 pub enum BaseCoord<'a> {
     Format1(BaseCoordFormat1<'a>),
     Format2(BaseCoordFormat2<'a>),
@@ -1010,8 +1092,14 @@ impl<'a> SomeTable<'a> for BaseCoordFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("base_coord_format", self.base_coord_format())),
-            1usize => Some(Field::new("coordinate", self.coordinate())),
+            0usize => Some(
+                Field::new("base_coord_format", self.base_coord_format())
+                    .with_bytes(self.data, self.shape.base_coord_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coordinate", self.coordinate())
+                    .with_bytes(self.data, self.shape.coordinate_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1099,10 +1187,22 @@ impl<'a> SomeTable<'a> for BaseCoordFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("base_coord_format", self.base_coord_format())),
-            1usize => Some(Field::new("coordinate", self.coordinate())),
-            2usize => Some(Field::new("reference_glyph", self.reference_glyph())),
-            3usize => Some(Field::new("base_coord_point", self.base_coord_point())),
+            0usize => Some(
+                Field::new("base_coord_format", self.base_coord_format())
+                    .with_bytes(self.data, self.shape.base_coord_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coordinate", self.coordinate())
+                    .with_bytes(self.data, self.shape.coordinate_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("reference_glyph", self.reference_glyph())
+                    .with_bytes(self.data, self.shape.reference_glyph_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("base_coord_point", self.base_coord_point())
+                    .with_bytes(self.data, self.shape.base_coord_point_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1187,12 +1287,21 @@ impl<'a> SomeTable<'a> for BaseCoordFormat3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("base_coord_format", self.base_coord_format())),
-            1usize => Some(Field::new("coordinate", self.coordinate())),
-            2usize => Some(Field::new(
-                "device_offset",
-                FieldType::offset(self.device_offset(), self.device()),
-            )),
+            0usize => Some(
+                Field::new("base_coord_format", self.base_coord_format())
+                    .with_bytes(self.data, self.shape.base_coord_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coordinate", self.coordinate())
+                    .with_bytes(self.data, self.shape.coordinate_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "device_offset",
+                    FieldType::offset(self.device_offset(), self.device()),
+                )
+                .with_bytes(self.data, self.shape.device_offset_byte_range()),
+            ),
             _ => None,
         }
     }
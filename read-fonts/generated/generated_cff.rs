@@ -106,12 +106,30 @@ impl<'a> SomeTable<'a> for CffHeader<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("major", self.major())),
-            1usize => Some(Field::new("minor", self.minor())),
-            2usize => Some(Field::new("hdr_size", self.hdr_size())),
-            3usize => Some(Field::new("off_size", self.off_size())),
-            4usize => Some(Field::new("_padding", self._padding())),
-            5usize => Some(Field::new("trailing_data", self.trailing_data())),
+            0usize => Some(
+                Field::new("major", self.major())
+                    .with_bytes(self.data, self.shape.major_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("minor", self.minor())
+                    .with_bytes(self.data, self.shape.minor_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("hdr_size", self.hdr_size())
+                    .with_bytes(self.data, self.shape.hdr_size_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("off_size", self.off_size())
+                    .with_bytes(self.data, self.shape.off_size_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("_padding", self._padding())
+                    .with_bytes(self.data, self.shape._padding_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("trailing_data", self.trailing_data())
+                    .with_bytes(self.data, self.shape.trailing_data_byte_range()),
+            ),
             _ => None,
         }
     }
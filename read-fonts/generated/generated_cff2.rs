@@ -120,13 +120,34 @@ impl<'a> SomeTable<'a> for Cff2Header<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("major_version", self.major_version())),
-            1usize => Some(Field::new("minor_version", self.minor_version())),
-            2usize => Some(Field::new("header_size", self.header_size())),
-            3usize => Some(Field::new("top_dict_length", self.top_dict_length())),
-            4usize => Some(Field::new("_padding", self._padding())),
-            5usize => Some(Field::new("top_dict_data", self.top_dict_data())),
-            6usize => Some(Field::new("trailing_data", self.trailing_data())),
+            0usize => Some(
+                Field::new("major_version", self.major_version())
+                    .with_bytes(self.data, self.shape.major_version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("minor_version", self.minor_version())
+                    .with_bytes(self.data, self.shape.minor_version_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("header_size", self.header_size())
+                    .with_bytes(self.data, self.shape.header_size_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("top_dict_length", self.top_dict_length())
+                    .with_bytes(self.data, self.shape.top_dict_length_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("_padding", self._padding())
+                    .with_bytes(self.data, self.shape._padding_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("top_dict_data", self.top_dict_data())
+                    .with_bytes(self.data, self.shape.top_dict_data_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("trailing_data", self.trailing_data())
+                    .with_bytes(self.data, self.shape.trailing_data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -74,16 +74,25 @@ impl<'a> SomeTable<'a> for Cmap<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("num_tables", self.num_tables())),
-            2usize => Some(Field::new(
-                "encoding_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(EncodingRecord),
-                    self.encoding_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_tables", self.num_tables())
+                    .with_bytes(self.data, self.shape.num_tables_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "encoding_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(EncodingRecord),
+                        self.encoding_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.encoding_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -151,15 +160,28 @@ impl<'a> SomeRecord<'a> for EncodingRecord {
         RecordResolver {
             name: "EncodingRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("platform_id", self.platform_id())),
-                1usize => Some(Field::new("encoding_id", self.encoding_id())),
-                2usize => Some(Field::new(
-                    "subtable_offset",
-                    FieldType::offset(self.subtable_offset(), self.subtable(_data)),
-                )),
+                0usize => Some(
+                    Field::new("platform_id", self.platform_id())
+                        .with_record_range(0, PlatformId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("encoding_id", self.encoding_id())
+                        .with_record_range(PlatformId::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new(
+                        "subtable_offset",
+                        FieldType::offset(self.subtable_offset(), self.subtable(_data)),
+                    )
+                    .with_record_range(
+                        PlatformId::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                        Offset32::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -213,6 +235,7 @@ impl<'a> From<PlatformId> for FieldType<'a> {
 }
 
 /// The different cmap subtable formats.
+#[derive(Clone)]
 pub enum CmapSubtable<'a> {
     Format0(Cmap0<'a>),
     Format2(Cmap2<'a>),
@@ -358,10 +381,22 @@ impl<'a> SomeTable<'a> for Cmap0<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("glyph_id_array", self.glyph_id_array())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("glyph_id_array", self.glyph_id_array())
+                    .with_bytes(self.data, self.shape.glyph_id_array_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -456,10 +491,22 @@ impl<'a> SomeTable<'a> for Cmap2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("sub_header_keys", self.sub_header_keys())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("sub_header_keys", self.sub_header_keys())
+                    .with_bytes(self.data, self.shape.sub_header_keys_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -527,13 +574,30 @@ impl<'a> SomeRecord<'a> for SubHeader {
         RecordResolver {
             name: "SubHeader",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("first_code", self.first_code())),
-                1usize => Some(Field::new("entry_count", self.entry_count())),
-                2usize => Some(Field::new("id_delta", self.id_delta())),
-                3usize => Some(Field::new("id_range_offset", self.id_range_offset())),
+                0usize => Some(
+                    Field::new("first_code", self.first_code())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("entry_count", self.entry_count())
+                        .with_record_range(u16::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => {
+                    Some(Field::new("id_delta", self.id_delta()).with_record_range(
+                        u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                        i16::RAW_BYTE_LEN,
+                    ))
+                }
+                3usize => Some(
+                    Field::new("id_range_offset", self.id_range_offset()).with_record_range(
+                        u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN + i16::RAW_BYTE_LEN,
+                        u16::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -627,7 +691,8 @@ impl<'a> FontRead<'a> for Cmap4<'a> {
         cursor.advance_by(id_delta_byte_len);
         let id_range_offsets_byte_len = transforms::half(seg_count_x2) * u16::RAW_BYTE_LEN;
         cursor.advance_by(id_range_offsets_byte_len);
-        let glyph_id_array_byte_len = cursor.remaining_bytes();
+        let glyph_id_array_byte_len =
+            cursor.remaining_bytes() / u16::RAW_BYTE_LEN * u16::RAW_BYTE_LEN;
         cursor.advance_by(glyph_id_array_byte_len);
         cursor.finish(Cmap4Marker {
             end_code_byte_len,
@@ -728,18 +793,54 @@ impl<'a> SomeTable<'a> for Cmap4<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("seg_count_x2", self.seg_count_x2())),
-            4usize => Some(Field::new("search_range", self.search_range())),
-            5usize => Some(Field::new("entry_selector", self.entry_selector())),
-            6usize => Some(Field::new("range_shift", self.range_shift())),
-            7usize => Some(Field::new("end_code", self.end_code())),
-            8usize => Some(Field::new("start_code", self.start_code())),
-            9usize => Some(Field::new("id_delta", self.id_delta())),
-            10usize => Some(Field::new("id_range_offsets", self.id_range_offsets())),
-            11usize => Some(Field::new("glyph_id_array", self.glyph_id_array())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("seg_count_x2", self.seg_count_x2())
+                    .with_bytes(self.data, self.shape.seg_count_x2_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("search_range", self.search_range())
+                    .with_bytes(self.data, self.shape.search_range_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("entry_selector", self.entry_selector())
+                    .with_bytes(self.data, self.shape.entry_selector_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("range_shift", self.range_shift())
+                    .with_bytes(self.data, self.shape.range_shift_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("end_code", self.end_code())
+                    .with_bytes(self.data, self.shape.end_code_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("start_code", self.start_code())
+                    .with_bytes(self.data, self.shape.start_code_byte_range()),
+            ),
+            9usize => Some(
+                Field::new("id_delta", self.id_delta())
+                    .with_bytes(self.data, self.shape.id_delta_byte_range()),
+            ),
+            10usize => Some(
+                Field::new("id_range_offsets", self.id_range_offsets())
+                    .with_bytes(self.data, self.shape.id_range_offsets_byte_range()),
+            ),
+            11usize => Some(
+                Field::new("glyph_id_array", self.glyph_id_array())
+                    .with_bytes(self.data, self.shape.glyph_id_array_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -855,12 +956,30 @@ impl<'a> SomeTable<'a> for Cmap6<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("first_code", self.first_code())),
-            4usize => Some(Field::new("entry_count", self.entry_count())),
-            5usize => Some(Field::new("glyph_id_array", self.glyph_id_array())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("first_code", self.first_code())
+                    .with_bytes(self.data, self.shape.first_code_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("entry_count", self.entry_count())
+                    .with_bytes(self.data, self.shape.entry_count_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("glyph_id_array", self.glyph_id_array())
+                    .with_bytes(self.data, self.shape.glyph_id_array_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -986,19 +1105,36 @@ impl<'a> SomeTable<'a> for Cmap8<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("is32", self.is32())),
-            4usize => Some(Field::new("num_groups", self.num_groups())),
-            5usize => Some(Field::new(
-                "groups",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequentialMapGroup),
-                    self.groups(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("is32", self.is32()).with_bytes(self.data, self.shape.is32_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("num_groups", self.num_groups())
+                    .with_bytes(self.data, self.shape.num_groups_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "groups",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequentialMapGroup),
+                        self.groups(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.groups_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1066,12 +1202,24 @@ impl<'a> SomeRecord<'a> for SequentialMapGroup {
         RecordResolver {
             name: "SequentialMapGroup",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("start_char_code", self.start_char_code())),
-                1usize => Some(Field::new("end_char_code", self.end_char_code())),
-                2usize => Some(Field::new("start_glyph_id", self.start_glyph_id())),
+                0usize => Some(
+                    Field::new("start_char_code", self.start_char_code())
+                        .with_record_range(0, u32::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("end_char_code", self.end_char_code())
+                        .with_record_range(u32::RAW_BYTE_LEN, u32::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("start_glyph_id", self.start_glyph_id()).with_record_range(
+                        u32::RAW_BYTE_LEN + u32::RAW_BYTE_LEN,
+                        u32::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1127,7 +1275,8 @@ impl<'a> FontRead<'a> for Cmap10<'a> {
         cursor.advance::<u32>();
         cursor.advance::<u32>();
         cursor.advance::<u32>();
-        let glyph_id_array_byte_len = cursor.remaining_bytes();
+        let glyph_id_array_byte_len =
+            cursor.remaining_bytes() / u16::RAW_BYTE_LEN * u16::RAW_BYTE_LEN;
         cursor.advance_by(glyph_id_array_byte_len);
         cursor.finish(Cmap10Marker {
             glyph_id_array_byte_len,
@@ -1184,12 +1333,30 @@ impl<'a> SomeTable<'a> for Cmap10<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("start_char_code", self.start_char_code())),
-            4usize => Some(Field::new("num_chars", self.num_chars())),
-            5usize => Some(Field::new("glyph_id_array", self.glyph_id_array())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("start_char_code", self.start_char_code())
+                    .with_bytes(self.data, self.shape.start_char_code_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("num_chars", self.num_chars())
+                    .with_bytes(self.data, self.shape.num_chars_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("glyph_id_array", self.glyph_id_array())
+                    .with_bytes(self.data, self.shape.glyph_id_array_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1297,18 +1464,33 @@ impl<'a> SomeTable<'a> for Cmap12<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("num_groups", self.num_groups())),
-            4usize => Some(Field::new(
-                "groups",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequentialMapGroup),
-                    self.groups(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("num_groups", self.num_groups())
+                    .with_bytes(self.data, self.shape.num_groups_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "groups",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequentialMapGroup),
+                        self.groups(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.groups_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1416,18 +1598,33 @@ impl<'a> SomeTable<'a> for Cmap13<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new("language", self.language())),
-            3usize => Some(Field::new("num_groups", self.num_groups())),
-            4usize => Some(Field::new(
-                "groups",
-                traversal::FieldType::array_of_records(
-                    stringify!(ConstantMapGroup),
-                    self.groups(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("language", self.language())
+                    .with_bytes(self.data, self.shape.language_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("num_groups", self.num_groups())
+                    .with_bytes(self.data, self.shape.num_groups_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "groups",
+                    traversal::FieldType::array_of_records(
+                        stringify!(ConstantMapGroup),
+                        self.groups(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.groups_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1489,12 +1686,24 @@ impl<'a> SomeRecord<'a> for ConstantMapGroup {
         RecordResolver {
             name: "ConstantMapGroup",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("start_char_code", self.start_char_code())),
-                1usize => Some(Field::new("end_char_code", self.end_char_code())),
-                2usize => Some(Field::new("glyph_id", self.glyph_id())),
+                0usize => Some(
+                    Field::new("start_char_code", self.start_char_code())
+                        .with_record_range(0, u32::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("end_char_code", self.end_char_code())
+                        .with_record_range(u32::RAW_BYTE_LEN, u32::RAW_BYTE_LEN),
+                ),
+                2usize => {
+                    Some(Field::new("glyph_id", self.glyph_id()).with_record_range(
+                        u32::RAW_BYTE_LEN + u32::RAW_BYTE_LEN,
+                        u32::RAW_BYTE_LEN,
+                    ))
+                }
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1580,20 +1789,29 @@ impl<'a> SomeTable<'a> for Cmap14<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("length", self.length())),
-            2usize => Some(Field::new(
-                "num_var_selector_records",
-                self.num_var_selector_records(),
-            )),
-            3usize => Some(Field::new(
-                "var_selector",
-                traversal::FieldType::array_of_records(
-                    stringify!(VariationSelector),
-                    self.var_selector(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("num_var_selector_records", self.num_var_selector_records())
+                    .with_bytes(self.data, self.shape.num_var_selector_records_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "var_selector",
+                    traversal::FieldType::array_of_records(
+                        stringify!(VariationSelector),
+                        self.var_selector(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.var_selector_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1671,18 +1889,34 @@ impl<'a> SomeRecord<'a> for VariationSelector {
         RecordResolver {
             name: "VariationSelector",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("var_selector", self.var_selector())),
-                1usize => Some(Field::new(
-                    "default_uvs_offset",
-                    FieldType::offset(self.default_uvs_offset(), self.default_uvs(_data)),
-                )),
-                2usize => Some(Field::new(
-                    "non_default_uvs_offset",
-                    FieldType::offset(self.non_default_uvs_offset(), self.non_default_uvs(_data)),
-                )),
+                0usize => Some(
+                    Field::new("var_selector", self.var_selector())
+                        .with_record_range(0, Uint24::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "default_uvs_offset",
+                        FieldType::offset(self.default_uvs_offset(), self.default_uvs(_data)),
+                    )
+                    .with_record_range(Uint24::RAW_BYTE_LEN, Offset32::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new(
+                        "non_default_uvs_offset",
+                        FieldType::offset(
+                            self.non_default_uvs_offset(),
+                            self.non_default_uvs(_data),
+                        ),
+                    )
+                    .with_record_range(
+                        Uint24::RAW_BYTE_LEN + Offset32::RAW_BYTE_LEN,
+                        Offset32::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1739,18 +1973,21 @@ impl<'a> SomeTable<'a> for DefaultUvs<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "num_unicode_value_ranges",
-                self.num_unicode_value_ranges(),
-            )),
-            1usize => Some(Field::new(
-                "ranges",
-                traversal::FieldType::array_of_records(
-                    stringify!(UnicodeRange),
-                    self.ranges(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("num_unicode_value_ranges", self.num_unicode_value_ranges())
+                    .with_bytes(self.data, self.shape.num_unicode_value_ranges_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "ranges",
+                    traversal::FieldType::array_of_records(
+                        stringify!(UnicodeRange),
+                        self.ranges(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.ranges_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1815,15 +2052,21 @@ impl<'a> SomeTable<'a> for NonDefaultUvs<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("num_uvs_mappings", self.num_uvs_mappings())),
-            1usize => Some(Field::new(
-                "uvs_mapping",
-                traversal::FieldType::array_of_records(
-                    stringify!(UvsMapping),
-                    self.uvs_mapping(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("num_uvs_mappings", self.num_uvs_mappings())
+                    .with_bytes(self.data, self.shape.num_uvs_mappings_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "uvs_mapping",
+                    traversal::FieldType::array_of_records(
+                        stringify!(UvsMapping),
+                        self.uvs_mapping(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.uvs_mapping_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1876,11 +2119,18 @@ impl<'a> SomeRecord<'a> for UvsMapping {
         RecordResolver {
             name: "UvsMapping",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("unicode_value", self.unicode_value())),
-                1usize => Some(Field::new("glyph_id", self.glyph_id())),
+                0usize => Some(
+                    Field::new("unicode_value", self.unicode_value())
+                        .with_record_range(0, Uint24::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("glyph_id", self.glyph_id())
+                        .with_record_range(Uint24::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1925,14 +2175,18 @@ impl<'a> SomeRecord<'a> for UnicodeRange {
         RecordResolver {
             name: "UnicodeRange",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new(
-                    "start_unicode_value",
-                    self.start_unicode_value(),
-                )),
-                1usize => Some(Field::new("additional_count", self.additional_count())),
+                0usize => Some(
+                    Field::new("start_unicode_value", self.start_unicode_value())
+                        .with_record_range(0, Uint24::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("additional_count", self.additional_count())
+                        .with_record_range(Uint24::RAW_BYTE_LEN, u8::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -226,62 +226,86 @@ impl<'a> SomeTable<'a> for Colr<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "num_base_glyph_records",
-                self.num_base_glyph_records(),
-            )),
-            2usize => Some(Field::new(
-                "base_glyph_records_offset",
-                traversal::FieldType::offset_to_array_of_records(
-                    self.base_glyph_records_offset(),
-                    self.base_glyph_records(),
-                    stringify!(BaseGlyph),
-                    self.offset_data(),
-                ),
-            )),
-            3usize => Some(Field::new(
-                "layer_records_offset",
-                traversal::FieldType::offset_to_array_of_records(
-                    self.layer_records_offset(),
-                    self.layer_records(),
-                    stringify!(Layer),
-                    self.offset_data(),
-                ),
-            )),
-            4usize => Some(Field::new("num_layer_records", self.num_layer_records())),
-            5usize if version.compatible(1) => Some(Field::new(
-                "base_glyph_list_offset",
-                FieldType::offset(
-                    self.base_glyph_list_offset().unwrap(),
-                    self.base_glyph_list().unwrap(),
-                ),
-            )),
-            6usize if version.compatible(1) => Some(Field::new(
-                "layer_list_offset",
-                FieldType::offset(
-                    self.layer_list_offset().unwrap(),
-                    self.layer_list().unwrap(),
-                ),
-            )),
-            7usize if version.compatible(1) => Some(Field::new(
-                "clip_list_offset",
-                FieldType::offset(self.clip_list_offset().unwrap(), self.clip_list().unwrap()),
-            )),
-            8usize if version.compatible(1) => Some(Field::new(
-                "var_index_map_offset",
-                FieldType::offset(
-                    self.var_index_map_offset().unwrap(),
-                    self.var_index_map().unwrap(),
-                ),
-            )),
-            9usize if version.compatible(1) => Some(Field::new(
-                "item_variation_store_offset",
-                FieldType::offset(
-                    self.item_variation_store_offset().unwrap(),
-                    self.item_variation_store().unwrap(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_base_glyph_records", self.num_base_glyph_records())
+                    .with_bytes(self.data, self.shape.num_base_glyph_records_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "base_glyph_records_offset",
+                    traversal::FieldType::offset_to_array_of_records(
+                        self.base_glyph_records_offset(),
+                        self.base_glyph_records(),
+                        stringify!(BaseGlyph),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.base_glyph_records_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "layer_records_offset",
+                    traversal::FieldType::offset_to_array_of_records(
+                        self.layer_records_offset(),
+                        self.layer_records(),
+                        stringify!(Layer),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.layer_records_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("num_layer_records", self.num_layer_records())
+                    .with_bytes(self.data, self.shape.num_layer_records_byte_range()),
+            ),
+            5usize if version.compatible(1) => Some(
+                Field::new(
+                    "base_glyph_list_offset",
+                    FieldType::offset(
+                        self.base_glyph_list_offset().unwrap(),
+                        self.base_glyph_list(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.base_glyph_list_offset_byte_range()),
+            ),
+            6usize if version.compatible(1) => Some(
+                Field::new(
+                    "layer_list_offset",
+                    FieldType::offset(self.layer_list_offset().unwrap(), self.layer_list()),
+                )
+                .with_bytes(self.data, self.shape.layer_list_offset_byte_range()),
+            ),
+            7usize if version.compatible(1) => Some(
+                Field::new(
+                    "clip_list_offset",
+                    FieldType::offset(self.clip_list_offset().unwrap(), self.clip_list()),
+                )
+                .with_bytes(self.data, self.shape.clip_list_offset_byte_range()),
+            ),
+            8usize if version.compatible(1) => Some(
+                Field::new(
+                    "var_index_map_offset",
+                    FieldType::offset(self.var_index_map_offset().unwrap(), self.var_index_map()),
+                )
+                .with_bytes(self.data, self.shape.var_index_map_offset_byte_range()),
+            ),
+            9usize if version.compatible(1) => Some(
+                Field::new(
+                    "item_variation_store_offset",
+                    FieldType::offset(
+                        self.item_variation_store_offset().unwrap(),
+                        self.item_variation_store(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.item_variation_store_offset_byte_range(),
                 ),
-            )),
+            ),
             _ => None,
         }
     }
@@ -341,12 +365,24 @@ impl<'a> SomeRecord<'a> for BaseGlyph {
         RecordResolver {
             name: "BaseGlyph",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("glyph_id", self.glyph_id())),
-                1usize => Some(Field::new("first_layer_index", self.first_layer_index())),
-                2usize => Some(Field::new("num_layers", self.num_layers())),
+                0usize => Some(
+                    Field::new("glyph_id", self.glyph_id())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("first_layer_index", self.first_layer_index())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("num_layers", self.num_layers()).with_record_range(
+                        GlyphId::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                        u16::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -391,11 +427,18 @@ impl<'a> SomeRecord<'a> for Layer {
         RecordResolver {
             name: "Layer",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("glyph_id", self.glyph_id())),
-                1usize => Some(Field::new("palette_index", self.palette_index())),
+                0usize => Some(
+                    Field::new("glyph_id", self.glyph_id())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("palette_index", self.palette_index())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -453,18 +496,27 @@ impl<'a> SomeTable<'a> for BaseGlyphList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "num_base_glyph_paint_records",
-                self.num_base_glyph_paint_records(),
-            )),
-            1usize => Some(Field::new(
-                "base_glyph_paint_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(BaseGlyphPaint),
-                    self.base_glyph_paint_records(),
-                    self.offset_data(),
+            0usize => Some(
+                Field::new(
+                    "num_base_glyph_paint_records",
+                    self.num_base_glyph_paint_records(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.num_base_glyph_paint_records_byte_range(),
                 ),
-            )),
+            ),
+            1usize => Some(
+                Field::new(
+                    "base_glyph_paint_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(BaseGlyphPaint),
+                        self.base_glyph_paint_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.base_glyph_paint_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -522,14 +574,21 @@ impl<'a> SomeRecord<'a> for BaseGlyphPaint {
         RecordResolver {
             name: "BaseGlyphPaint",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("glyph_id", self.glyph_id())),
-                1usize => Some(Field::new(
-                    "paint_offset",
-                    FieldType::offset(self.paint_offset(), self.paint(_data)),
-                )),
+                0usize => Some(
+                    Field::new("glyph_id", self.glyph_id())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "paint_offset",
+                        FieldType::offset(self.paint_offset(), self.paint(_data)),
+                    )
+                    .with_record_range(GlyphId::RAW_BYTE_LEN, Offset32::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -594,21 +653,27 @@ impl<'a> SomeTable<'a> for LayerList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("num_layers", self.num_layers())),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "paint_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<Paint>(),
-                        self.paint_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<Paint>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("num_layers", self.num_layers())
+                    .with_bytes(self.data, self.shape.num_layers_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "paint_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<Paint>(),
+                            self.paint_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<Paint>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.paint_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -684,16 +749,25 @@ impl<'a> SomeTable<'a> for ClipList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("num_clips", self.num_clips())),
-            2usize => Some(Field::new(
-                "clips",
-                traversal::FieldType::array_of_records(
-                    stringify!(Clip),
-                    self.clips(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_clips", self.num_clips())
+                    .with_bytes(self.data, self.shape.num_clips_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "clips",
+                    traversal::FieldType::array_of_records(
+                        stringify!(Clip),
+                        self.clips(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.clips_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -759,20 +833,34 @@ impl<'a> SomeRecord<'a> for Clip {
         RecordResolver {
             name: "Clip",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("start_glyph_id", self.start_glyph_id())),
-                1usize => Some(Field::new("end_glyph_id", self.end_glyph_id())),
-                2usize => Some(Field::new(
-                    "clip_box_offset",
-                    FieldType::offset(self.clip_box_offset(), self.clip_box(_data)),
-                )),
+                0usize => Some(
+                    Field::new("start_glyph_id", self.start_glyph_id())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("end_glyph_id", self.end_glyph_id())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, GlyphId::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new(
+                        "clip_box_offset",
+                        FieldType::offset(self.clip_box_offset(), self.clip_box(_data)),
+                    )
+                    .with_record_range(
+                        GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN,
+                        Offset24::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
 
 /// [ClipBox](https://learn.microsoft.com/en-us/typography/opentype/spec/colr#baseglyphlist-layerlist-and-cliplist) table
+#[derive(Clone)]
 pub enum ClipBox<'a> {
     Format1(ClipBoxFormat1<'a>),
     Format2(ClipBoxFormat2<'a>),
@@ -902,11 +990,26 @@ impl<'a> SomeTable<'a> for ClipBoxFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("x_min", self.x_min())),
-            2usize => Some(Field::new("y_min", self.y_min())),
-            3usize => Some(Field::new("x_max", self.x_max())),
-            4usize => Some(Field::new("y_max", self.y_max())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_min", self.x_min())
+                    .with_bytes(self.data, self.shape.x_min_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("y_min", self.y_min())
+                    .with_bytes(self.data, self.shape.y_min_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("x_max", self.x_max())
+                    .with_bytes(self.data, self.shape.x_max_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("y_max", self.y_max())
+                    .with_bytes(self.data, self.shape.y_max_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1016,12 +1119,30 @@ impl<'a> SomeTable<'a> for ClipBoxFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("x_min", self.x_min())),
-            2usize => Some(Field::new("y_min", self.y_min())),
-            3usize => Some(Field::new("x_max", self.x_max())),
-            4usize => Some(Field::new("y_max", self.y_max())),
-            5usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_min", self.x_min())
+                    .with_bytes(self.data, self.shape.x_min_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("y_min", self.y_min())
+                    .with_bytes(self.data, self.shape.y_min_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("x_max", self.x_max())
+                    .with_bytes(self.data, self.shape.x_max_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("y_max", self.y_max())
+                    .with_bytes(self.data, self.shape.y_max_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1074,11 +1195,18 @@ impl<'a> SomeRecord<'a> for ColorIndex {
         RecordResolver {
             name: "ColorIndex",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("palette_index", self.palette_index())),
-                1usize => Some(Field::new("alpha", self.alpha())),
+                0usize => Some(
+                    Field::new("palette_index", self.palette_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("alpha", self.alpha())
+                        .with_record_range(u16::RAW_BYTE_LEN, F2Dot14::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1130,12 +1258,24 @@ impl<'a> SomeRecord<'a> for VarColorIndex {
         RecordResolver {
             name: "VarColorIndex",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("palette_index", self.palette_index())),
-                1usize => Some(Field::new("alpha", self.alpha())),
-                2usize => Some(Field::new("var_index_base", self.var_index_base())),
+                0usize => Some(
+                    Field::new("palette_index", self.palette_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("alpha", self.alpha())
+                        .with_record_range(u16::RAW_BYTE_LEN, F2Dot14::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("var_index_base", self.var_index_base()).with_record_range(
+                        u16::RAW_BYTE_LEN + F2Dot14::RAW_BYTE_LEN,
+                        u32::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1187,12 +1327,22 @@ impl<'a> SomeRecord<'a> for ColorStop {
         RecordResolver {
             name: "ColorStop",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("stop_offset", self.stop_offset())),
-                1usize => Some(Field::new("palette_index", self.palette_index())),
-                2usize => Some(Field::new("alpha", self.alpha())),
+                0usize => Some(
+                    Field::new("stop_offset", self.stop_offset())
+                        .with_record_range(0, F2Dot14::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("palette_index", self.palette_index())
+                        .with_record_range(F2Dot14::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(Field::new("alpha", self.alpha()).with_record_range(
+                    F2Dot14::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                    F2Dot14::RAW_BYTE_LEN,
+                )),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1252,13 +1402,28 @@ impl<'a> SomeRecord<'a> for VarColorStop {
         RecordResolver {
             name: "VarColorStop",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("stop_offset", self.stop_offset())),
-                1usize => Some(Field::new("palette_index", self.palette_index())),
-                2usize => Some(Field::new("alpha", self.alpha())),
-                3usize => Some(Field::new("var_index_base", self.var_index_base())),
+                0usize => Some(
+                    Field::new("stop_offset", self.stop_offset())
+                        .with_record_range(0, F2Dot14::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("palette_index", self.palette_index())
+                        .with_record_range(F2Dot14::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(Field::new("alpha", self.alpha()).with_record_range(
+                    F2Dot14::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                    F2Dot14::RAW_BYTE_LEN,
+                )),
+                3usize => Some(
+                    Field::new("var_index_base", self.var_index_base()).with_record_range(
+                        F2Dot14::RAW_BYTE_LEN + u16::RAW_BYTE_LEN + F2Dot14::RAW_BYTE_LEN,
+                        u32::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1327,16 +1492,25 @@ impl<'a> SomeTable<'a> for ColorLine<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("extend", self.extend())),
-            1usize => Some(Field::new("num_stops", self.num_stops())),
-            2usize => Some(Field::new(
-                "color_stops",
-                traversal::FieldType::array_of_records(
-                    stringify!(ColorStop),
-                    self.color_stops(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("extend", self.extend())
+                    .with_bytes(self.data, self.shape.extend_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_stops", self.num_stops())
+                    .with_bytes(self.data, self.shape.num_stops_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "color_stops",
+                    traversal::FieldType::array_of_records(
+                        stringify!(ColorStop),
+                        self.color_stops(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.color_stops_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1414,16 +1588,25 @@ impl<'a> SomeTable<'a> for VarColorLine<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("extend", self.extend())),
-            1usize => Some(Field::new("num_stops", self.num_stops())),
-            2usize => Some(Field::new(
-                "color_stops",
-                traversal::FieldType::array_of_records(
-                    stringify!(VarColorStop),
-                    self.color_stops(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("extend", self.extend())
+                    .with_bytes(self.data, self.shape.extend_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_stops", self.num_stops())
+                    .with_bytes(self.data, self.shape.num_stops_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "color_stops",
+                    traversal::FieldType::array_of_records(
+                        stringify!(VarColorStop),
+                        self.color_stops(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.color_stops_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1481,6 +1664,7 @@ impl<'a> From<Extend> for FieldType<'a> {
 }
 
 /// [Paint](https://learn.microsoft.com/en-us/typography/opentype/spec/colr#paint-tables) tables
+#[derive(Clone)]
 pub enum Paint<'a> {
     ColrLayers(PaintColrLayers<'a>),
     Solid(PaintSolid<'a>),
@@ -1700,9 +1884,18 @@ impl<'a> SomeTable<'a> for PaintColrLayers<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("num_layers", self.num_layers())),
-            2usize => Some(Field::new("first_layer_index", self.first_layer_index())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_layers", self.num_layers())
+                    .with_bytes(self.data, self.shape.num_layers_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("first_layer_index", self.first_layer_index())
+                    .with_bytes(self.data, self.shape.first_layer_index_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1779,9 +1972,18 @@ impl<'a> SomeTable<'a> for PaintSolid<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("palette_index", self.palette_index())),
-            2usize => Some(Field::new("alpha", self.alpha())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("palette_index", self.palette_index())
+                    .with_bytes(self.data, self.shape.palette_index_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("alpha", self.alpha())
+                    .with_bytes(self.data, self.shape.alpha_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1869,10 +2071,22 @@ impl<'a> SomeTable<'a> for PaintVarSolid<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("palette_index", self.palette_index())),
-            2usize => Some(Field::new("alpha", self.alpha())),
-            3usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("palette_index", self.palette_index())
+                    .with_bytes(self.data, self.shape.palette_index_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("alpha", self.alpha())
+                    .with_bytes(self.data, self.shape.alpha_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2010,17 +2224,35 @@ impl<'a> SomeTable<'a> for PaintLinearGradient<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "color_line_offset",
-                FieldType::offset(self.color_line_offset(), self.color_line()),
-            )),
-            2usize => Some(Field::new("x0", self.x0())),
-            3usize => Some(Field::new("y0", self.y0())),
-            4usize => Some(Field::new("x1", self.x1())),
-            5usize => Some(Field::new("y1", self.y1())),
-            6usize => Some(Field::new("x2", self.x2())),
-            7usize => Some(Field::new("y2", self.y2())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "color_line_offset",
+                    FieldType::offset(self.color_line_offset(), self.color_line()),
+                )
+                .with_bytes(self.data, self.shape.color_line_offset_byte_range()),
+            ),
+            2usize => {
+                Some(Field::new("x0", self.x0()).with_bytes(self.data, self.shape.x0_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("y0", self.y0()).with_bytes(self.data, self.shape.y0_byte_range()))
+            }
+            4usize => {
+                Some(Field::new("x1", self.x1()).with_bytes(self.data, self.shape.x1_byte_range()))
+            }
+            5usize => {
+                Some(Field::new("y1", self.y1()).with_bytes(self.data, self.shape.y1_byte_range()))
+            }
+            6usize => {
+                Some(Field::new("x2", self.x2()).with_bytes(self.data, self.shape.x2_byte_range()))
+            }
+            7usize => {
+                Some(Field::new("y2", self.y2()).with_bytes(self.data, self.shape.y2_byte_range()))
+            }
             _ => None,
         }
     }
@@ -2175,18 +2407,39 @@ impl<'a> SomeTable<'a> for PaintVarLinearGradient<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "color_line_offset",
-                FieldType::offset(self.color_line_offset(), self.color_line()),
-            )),
-            2usize => Some(Field::new("x0", self.x0())),
-            3usize => Some(Field::new("y0", self.y0())),
-            4usize => Some(Field::new("x1", self.x1())),
-            5usize => Some(Field::new("y1", self.y1())),
-            6usize => Some(Field::new("x2", self.x2())),
-            7usize => Some(Field::new("y2", self.y2())),
-            8usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "color_line_offset",
+                    FieldType::offset(self.color_line_offset(), self.color_line()),
+                )
+                .with_bytes(self.data, self.shape.color_line_offset_byte_range()),
+            ),
+            2usize => {
+                Some(Field::new("x0", self.x0()).with_bytes(self.data, self.shape.x0_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("y0", self.y0()).with_bytes(self.data, self.shape.y0_byte_range()))
+            }
+            4usize => {
+                Some(Field::new("x1", self.x1()).with_bytes(self.data, self.shape.x1_byte_range()))
+            }
+            5usize => {
+                Some(Field::new("y1", self.y1()).with_bytes(self.data, self.shape.y1_byte_range()))
+            }
+            6usize => {
+                Some(Field::new("x2", self.x2()).with_bytes(self.data, self.shape.x2_byte_range()))
+            }
+            7usize => {
+                Some(Field::new("y2", self.y2()).with_bytes(self.data, self.shape.y2_byte_range()))
+            }
+            8usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2324,17 +2577,37 @@ impl<'a> SomeTable<'a> for PaintRadialGradient<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "color_line_offset",
-                FieldType::offset(self.color_line_offset(), self.color_line()),
-            )),
-            2usize => Some(Field::new("x0", self.x0())),
-            3usize => Some(Field::new("y0", self.y0())),
-            4usize => Some(Field::new("radius0", self.radius0())),
-            5usize => Some(Field::new("x1", self.x1())),
-            6usize => Some(Field::new("y1", self.y1())),
-            7usize => Some(Field::new("radius1", self.radius1())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "color_line_offset",
+                    FieldType::offset(self.color_line_offset(), self.color_line()),
+                )
+                .with_bytes(self.data, self.shape.color_line_offset_byte_range()),
+            ),
+            2usize => {
+                Some(Field::new("x0", self.x0()).with_bytes(self.data, self.shape.x0_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("y0", self.y0()).with_bytes(self.data, self.shape.y0_byte_range()))
+            }
+            4usize => Some(
+                Field::new("radius0", self.radius0())
+                    .with_bytes(self.data, self.shape.radius0_byte_range()),
+            ),
+            5usize => {
+                Some(Field::new("x1", self.x1()).with_bytes(self.data, self.shape.x1_byte_range()))
+            }
+            6usize => {
+                Some(Field::new("y1", self.y1()).with_bytes(self.data, self.shape.y1_byte_range()))
+            }
+            7usize => Some(
+                Field::new("radius1", self.radius1())
+                    .with_bytes(self.data, self.shape.radius1_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2487,18 +2760,41 @@ impl<'a> SomeTable<'a> for PaintVarRadialGradient<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "color_line_offset",
-                FieldType::offset(self.color_line_offset(), self.color_line()),
-            )),
-            2usize => Some(Field::new("x0", self.x0())),
-            3usize => Some(Field::new("y0", self.y0())),
-            4usize => Some(Field::new("radius0", self.radius0())),
-            5usize => Some(Field::new("x1", self.x1())),
-            6usize => Some(Field::new("y1", self.y1())),
-            7usize => Some(Field::new("radius1", self.radius1())),
-            8usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "color_line_offset",
+                    FieldType::offset(self.color_line_offset(), self.color_line()),
+                )
+                .with_bytes(self.data, self.shape.color_line_offset_byte_range()),
+            ),
+            2usize => {
+                Some(Field::new("x0", self.x0()).with_bytes(self.data, self.shape.x0_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("y0", self.y0()).with_bytes(self.data, self.shape.y0_byte_range()))
+            }
+            4usize => Some(
+                Field::new("radius0", self.radius0())
+                    .with_bytes(self.data, self.shape.radius0_byte_range()),
+            ),
+            5usize => {
+                Some(Field::new("x1", self.x1()).with_bytes(self.data, self.shape.x1_byte_range()))
+            }
+            6usize => {
+                Some(Field::new("y1", self.y1()).with_bytes(self.data, self.shape.y1_byte_range()))
+            }
+            7usize => Some(
+                Field::new("radius1", self.radius1())
+                    .with_bytes(self.data, self.shape.radius1_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2616,15 +2912,33 @@ impl<'a> SomeTable<'a> for PaintSweepGradient<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "color_line_offset",
-                FieldType::offset(self.color_line_offset(), self.color_line()),
-            )),
-            2usize => Some(Field::new("center_x", self.center_x())),
-            3usize => Some(Field::new("center_y", self.center_y())),
-            4usize => Some(Field::new("start_angle", self.start_angle())),
-            5usize => Some(Field::new("end_angle", self.end_angle())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "color_line_offset",
+                    FieldType::offset(self.color_line_offset(), self.color_line()),
+                )
+                .with_bytes(self.data, self.shape.color_line_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("start_angle", self.start_angle())
+                    .with_bytes(self.data, self.shape.start_angle_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("end_angle", self.end_angle())
+                    .with_bytes(self.data, self.shape.end_angle_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2755,16 +3069,37 @@ impl<'a> SomeTable<'a> for PaintVarSweepGradient<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "color_line_offset",
-                FieldType::offset(self.color_line_offset(), self.color_line()),
-            )),
-            2usize => Some(Field::new("center_x", self.center_x())),
-            3usize => Some(Field::new("center_y", self.center_y())),
-            4usize => Some(Field::new("start_angle", self.start_angle())),
-            5usize => Some(Field::new("end_angle", self.end_angle())),
-            6usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "color_line_offset",
+                    FieldType::offset(self.color_line_offset(), self.color_line()),
+                )
+                .with_bytes(self.data, self.shape.color_line_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("start_angle", self.start_angle())
+                    .with_bytes(self.data, self.shape.start_angle_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("end_angle", self.end_angle())
+                    .with_bytes(self.data, self.shape.end_angle_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2847,12 +3182,21 @@ impl<'a> SomeTable<'a> for PaintGlyph<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("glyph_id", self.glyph_id())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("glyph_id", self.glyph_id())
+                    .with_bytes(self.data, self.shape.glyph_id_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2918,8 +3262,14 @@ impl<'a> SomeTable<'a> for PaintColrGlyph<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("glyph_id", self.glyph_id())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("glyph_id", self.glyph_id())
+                    .with_bytes(self.data, self.shape.glyph_id_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3008,15 +3358,24 @@ impl<'a> SomeTable<'a> for PaintTransform<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new(
-                "transform_offset",
-                FieldType::offset(self.transform_offset(), self.transform()),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "transform_offset",
+                    FieldType::offset(self.transform_offset(), self.transform()),
+                )
+                .with_bytes(self.data, self.shape.transform_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3105,15 +3464,24 @@ impl<'a> SomeTable<'a> for PaintVarTransform<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new(
-                "transform_offset",
-                FieldType::offset(self.transform_offset(), self.transform()),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "transform_offset",
+                    FieldType::offset(self.transform_offset(), self.transform()),
+                )
+                .with_bytes(self.data, self.shape.transform_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3219,12 +3587,24 @@ impl<'a> SomeTable<'a> for Affine2x3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("xx", self.xx())),
-            1usize => Some(Field::new("yx", self.yx())),
-            2usize => Some(Field::new("xy", self.xy())),
-            3usize => Some(Field::new("yy", self.yy())),
-            4usize => Some(Field::new("dx", self.dx())),
-            5usize => Some(Field::new("dy", self.dy())),
+            0usize => {
+                Some(Field::new("xx", self.xx()).with_bytes(self.data, self.shape.xx_byte_range()))
+            }
+            1usize => {
+                Some(Field::new("yx", self.yx()).with_bytes(self.data, self.shape.yx_byte_range()))
+            }
+            2usize => {
+                Some(Field::new("xy", self.xy()).with_bytes(self.data, self.shape.xy_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("yy", self.yy()).with_bytes(self.data, self.shape.yy_byte_range()))
+            }
+            4usize => {
+                Some(Field::new("dx", self.dx()).with_bytes(self.data, self.shape.dx_byte_range()))
+            }
+            5usize => {
+                Some(Field::new("dy", self.dy()).with_bytes(self.data, self.shape.dy_byte_range()))
+            }
             _ => None,
         }
     }
@@ -3345,13 +3725,28 @@ impl<'a> SomeTable<'a> for VarAffine2x3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("xx", self.xx())),
-            1usize => Some(Field::new("yx", self.yx())),
-            2usize => Some(Field::new("xy", self.xy())),
-            3usize => Some(Field::new("yy", self.yy())),
-            4usize => Some(Field::new("dx", self.dx())),
-            5usize => Some(Field::new("dy", self.dy())),
-            6usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => {
+                Some(Field::new("xx", self.xx()).with_bytes(self.data, self.shape.xx_byte_range()))
+            }
+            1usize => {
+                Some(Field::new("yx", self.yx()).with_bytes(self.data, self.shape.yx_byte_range()))
+            }
+            2usize => {
+                Some(Field::new("xy", self.xy()).with_bytes(self.data, self.shape.xy_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("yy", self.yy()).with_bytes(self.data, self.shape.yy_byte_range()))
+            }
+            4usize => {
+                Some(Field::new("dx", self.dx()).with_bytes(self.data, self.shape.dx_byte_range()))
+            }
+            5usize => {
+                Some(Field::new("dy", self.dy()).with_bytes(self.data, self.shape.dy_byte_range()))
+            }
+            6usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3445,13 +3840,23 @@ impl<'a> SomeTable<'a> for PaintTranslate<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("dx", self.dx())),
-            3usize => Some(Field::new("dy", self.dy())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => {
+                Some(Field::new("dx", self.dx()).with_bytes(self.data, self.shape.dx_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("dy", self.dy()).with_bytes(self.data, self.shape.dy_byte_range()))
+            }
             _ => None,
         }
     }
@@ -3556,14 +3961,27 @@ impl<'a> SomeTable<'a> for PaintVarTranslate<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("dx", self.dx())),
-            3usize => Some(Field::new("dy", self.dy())),
-            4usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => {
+                Some(Field::new("dx", self.dx()).with_bytes(self.data, self.shape.dx_byte_range()))
+            }
+            3usize => {
+                Some(Field::new("dy", self.dy()).with_bytes(self.data, self.shape.dy_byte_range()))
+            }
+            4usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3657,13 +4075,25 @@ impl<'a> SomeTable<'a> for PaintScale<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale_x", self.scale_x())),
-            3usize => Some(Field::new("scale_y", self.scale_y())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale_x", self.scale_x())
+                    .with_bytes(self.data, self.shape.scale_x_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("scale_y", self.scale_y())
+                    .with_bytes(self.data, self.shape.scale_y_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3770,14 +4200,29 @@ impl<'a> SomeTable<'a> for PaintVarScale<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale_x", self.scale_x())),
-            3usize => Some(Field::new("scale_y", self.scale_y())),
-            4usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale_x", self.scale_x())
+                    .with_bytes(self.data, self.shape.scale_x_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("scale_y", self.scale_y())
+                    .with_bytes(self.data, self.shape.scale_y_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3893,15 +4338,33 @@ impl<'a> SomeTable<'a> for PaintScaleAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale_x", self.scale_x())),
-            3usize => Some(Field::new("scale_y", self.scale_y())),
-            4usize => Some(Field::new("center_x", self.center_x())),
-            5usize => Some(Field::new("center_y", self.center_y())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale_x", self.scale_x())
+                    .with_bytes(self.data, self.shape.scale_x_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("scale_y", self.scale_y())
+                    .with_bytes(self.data, self.shape.scale_y_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4032,16 +4495,37 @@ impl<'a> SomeTable<'a> for PaintVarScaleAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale_x", self.scale_x())),
-            3usize => Some(Field::new("scale_y", self.scale_y())),
-            4usize => Some(Field::new("center_x", self.center_x())),
-            5usize => Some(Field::new("center_y", self.center_y())),
-            6usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale_x", self.scale_x())
+                    .with_bytes(self.data, self.shape.scale_x_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("scale_y", self.scale_y())
+                    .with_bytes(self.data, self.shape.scale_y_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4124,12 +4608,21 @@ impl<'a> SomeTable<'a> for PaintScaleUniform<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale", self.scale())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale", self.scale())
+                    .with_bytes(self.data, self.shape.scale_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4224,13 +4717,25 @@ impl<'a> SomeTable<'a> for PaintVarScaleUniform<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale", self.scale())),
-            3usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale", self.scale())
+                    .with_bytes(self.data, self.shape.scale_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4335,14 +4840,29 @@ impl<'a> SomeTable<'a> for PaintScaleUniformAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale", self.scale())),
-            3usize => Some(Field::new("center_x", self.center_x())),
-            4usize => Some(Field::new("center_y", self.center_y())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale", self.scale())
+                    .with_bytes(self.data, self.shape.scale_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4462,15 +4982,33 @@ impl<'a> SomeTable<'a> for PaintVarScaleUniformAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("scale", self.scale())),
-            3usize => Some(Field::new("center_x", self.center_x())),
-            4usize => Some(Field::new("center_y", self.center_y())),
-            5usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scale", self.scale())
+                    .with_bytes(self.data, self.shape.scale_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4554,12 +5092,21 @@ impl<'a> SomeTable<'a> for PaintRotate<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("angle", self.angle())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("angle", self.angle())
+                    .with_bytes(self.data, self.shape.angle_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4654,13 +5201,25 @@ impl<'a> SomeTable<'a> for PaintVarRotate<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("angle", self.angle())),
-            3usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("angle", self.angle())
+                    .with_bytes(self.data, self.shape.angle_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4766,14 +5325,29 @@ impl<'a> SomeTable<'a> for PaintRotateAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("angle", self.angle())),
-            3usize => Some(Field::new("center_x", self.center_x())),
-            4usize => Some(Field::new("center_y", self.center_y())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("angle", self.angle())
+                    .with_bytes(self.data, self.shape.angle_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4892,15 +5466,33 @@ impl<'a> SomeTable<'a> for PaintVarRotateAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("angle", self.angle())),
-            3usize => Some(Field::new("center_x", self.center_x())),
-            4usize => Some(Field::new("center_y", self.center_y())),
-            5usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("angle", self.angle())
+                    .with_bytes(self.data, self.shape.angle_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4996,13 +5588,25 @@ impl<'a> SomeTable<'a> for PaintSkew<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("x_skew_angle", self.x_skew_angle())),
-            3usize => Some(Field::new("y_skew_angle", self.y_skew_angle())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("x_skew_angle", self.x_skew_angle())
+                    .with_bytes(self.data, self.shape.x_skew_angle_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("y_skew_angle", self.y_skew_angle())
+                    .with_bytes(self.data, self.shape.y_skew_angle_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -5111,14 +5715,29 @@ impl<'a> SomeTable<'a> for PaintVarSkew<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("x_skew_angle", self.x_skew_angle())),
-            3usize => Some(Field::new("y_skew_angle", self.y_skew_angle())),
-            4usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("x_skew_angle", self.x_skew_angle())
+                    .with_bytes(self.data, self.shape.x_skew_angle_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("y_skew_angle", self.y_skew_angle())
+                    .with_bytes(self.data, self.shape.y_skew_angle_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -5236,15 +5855,33 @@ impl<'a> SomeTable<'a> for PaintSkewAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("x_skew_angle", self.x_skew_angle())),
-            3usize => Some(Field::new("y_skew_angle", self.y_skew_angle())),
-            4usize => Some(Field::new("center_x", self.center_x())),
-            5usize => Some(Field::new("center_y", self.center_y())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("x_skew_angle", self.x_skew_angle())
+                    .with_bytes(self.data, self.shape.x_skew_angle_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("y_skew_angle", self.y_skew_angle())
+                    .with_bytes(self.data, self.shape.y_skew_angle_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -5377,16 +6014,37 @@ impl<'a> SomeTable<'a> for PaintVarSkewAroundCenter<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "paint_offset",
-                FieldType::offset(self.paint_offset(), self.paint()),
-            )),
-            2usize => Some(Field::new("x_skew_angle", self.x_skew_angle())),
-            3usize => Some(Field::new("y_skew_angle", self.y_skew_angle())),
-            4usize => Some(Field::new("center_x", self.center_x())),
-            5usize => Some(Field::new("center_y", self.center_y())),
-            6usize => Some(Field::new("var_index_base", self.var_index_base())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "paint_offset",
+                    FieldType::offset(self.paint_offset(), self.paint()),
+                )
+                .with_bytes(self.data, self.shape.paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("x_skew_angle", self.x_skew_angle())
+                    .with_bytes(self.data, self.shape.x_skew_angle_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("y_skew_angle", self.y_skew_angle())
+                    .with_bytes(self.data, self.shape.y_skew_angle_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("center_x", self.center_x())
+                    .with_bytes(self.data, self.shape.center_x_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("center_y", self.center_y())
+                    .with_bytes(self.data, self.shape.center_y_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("var_index_base", self.var_index_base())
+                    .with_bytes(self.data, self.shape.var_index_base_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -5486,16 +6144,28 @@ impl<'a> SomeTable<'a> for PaintComposite<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "source_paint_offset",
-                FieldType::offset(self.source_paint_offset(), self.source_paint()),
-            )),
-            2usize => Some(Field::new("composite_mode", self.composite_mode())),
-            3usize => Some(Field::new(
-                "backdrop_paint_offset",
-                FieldType::offset(self.backdrop_paint_offset(), self.backdrop_paint()),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "source_paint_offset",
+                    FieldType::offset(self.source_paint_offset(), self.source_paint()),
+                )
+                .with_bytes(self.data, self.shape.source_paint_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("composite_mode", self.composite_mode())
+                    .with_bytes(self.data, self.shape.composite_mode_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "backdrop_paint_offset",
+                    FieldType::offset(self.backdrop_paint_offset(), self.backdrop_paint()),
+                )
+                .with_bytes(self.data, self.shape.backdrop_paint_offset_byte_range()),
+            ),
             _ => None,
         }
     }
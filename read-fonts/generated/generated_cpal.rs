@@ -212,47 +212,80 @@ impl<'a> SomeTable<'a> for Cpal<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "num_palette_entries",
-                self.num_palette_entries(),
-            )),
-            2usize => Some(Field::new("num_palettes", self.num_palettes())),
-            3usize => Some(Field::new("num_color_records", self.num_color_records())),
-            4usize => Some(Field::new(
-                "color_records_array_offset",
-                traversal::FieldType::offset_to_array_of_records(
-                    self.color_records_array_offset(),
-                    self.color_records_array(),
-                    stringify!(ColorRecord),
-                    self.offset_data(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_palette_entries", self.num_palette_entries())
+                    .with_bytes(self.data, self.shape.num_palette_entries_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("num_palettes", self.num_palettes())
+                    .with_bytes(self.data, self.shape.num_palettes_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("num_color_records", self.num_color_records())
+                    .with_bytes(self.data, self.shape.num_color_records_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "color_records_array_offset",
+                    traversal::FieldType::offset_to_array_of_records(
+                        self.color_records_array_offset(),
+                        self.color_records_array(),
+                        stringify!(ColorRecord),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.color_records_array_offset_byte_range(),
                 ),
-            )),
-            5usize => Some(Field::new(
-                "color_record_indices",
-                self.color_record_indices(),
-            )),
-            6usize if version.compatible(1) => Some(Field::new(
-                "palette_types_array_offset",
-                FieldType::offset_to_array_of_scalars(
-                    self.palette_types_array_offset().unwrap(),
-                    self.palette_types_array().unwrap(),
+            ),
+            5usize => Some(
+                Field::new("color_record_indices", self.color_record_indices())
+                    .with_bytes(self.data, self.shape.color_record_indices_byte_range()),
+            ),
+            6usize if version.compatible(1) => Some(
+                Field::new(
+                    "palette_types_array_offset",
+                    FieldType::offset_to_array_of_scalars(
+                        self.palette_types_array_offset().unwrap(),
+                        self.palette_types_array(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.palette_types_array_offset_byte_range(),
                 ),
-            )),
-            7usize if version.compatible(1) => Some(Field::new(
-                "palette_labels_array_offset",
-                FieldType::offset_to_array_of_scalars(
-                    self.palette_labels_array_offset().unwrap(),
-                    self.palette_labels_array().unwrap(),
+            ),
+            7usize if version.compatible(1) => Some(
+                Field::new(
+                    "palette_labels_array_offset",
+                    FieldType::offset_to_array_of_scalars(
+                        self.palette_labels_array_offset().unwrap(),
+                        self.palette_labels_array(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.palette_labels_array_offset_byte_range(),
                 ),
-            )),
-            8usize if version.compatible(1) => Some(Field::new(
-                "palette_entry_labels_array_offset",
-                FieldType::offset_to_array_of_scalars(
-                    self.palette_entry_labels_array_offset().unwrap(),
-                    self.palette_entry_labels_array().unwrap(),
+            ),
+            8usize if version.compatible(1) => Some(
+                Field::new(
+                    "palette_entry_labels_array_offset",
+                    FieldType::offset_to_array_of_scalars(
+                        self.palette_entry_labels_array_offset().unwrap(),
+                        self.palette_entry_labels_array(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.palette_entry_labels_array_offset_byte_range(),
                 ),
-            )),
+            ),
             _ => None,
         }
     }
@@ -320,13 +353,25 @@ impl<'a> SomeRecord<'a> for ColorRecord {
         RecordResolver {
             name: "ColorRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("blue", self.blue())),
-                1usize => Some(Field::new("green", self.green())),
-                2usize => Some(Field::new("red", self.red())),
-                3usize => Some(Field::new("alpha", self.alpha())),
+                0usize => {
+                    Some(Field::new("blue", self.blue()).with_record_range(0, u8::RAW_BYTE_LEN))
+                }
+                1usize => Some(
+                    Field::new("green", self.green())
+                        .with_record_range(u8::RAW_BYTE_LEN, u8::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("red", self.red())
+                        .with_record_range(u8::RAW_BYTE_LEN + u8::RAW_BYTE_LEN, u8::RAW_BYTE_LEN),
+                ),
+                3usize => Some(Field::new("alpha", self.alpha()).with_record_range(
+                    u8::RAW_BYTE_LEN + u8::RAW_BYTE_LEN + u8::RAW_BYTE_LEN,
+                    u8::RAW_BYTE_LEN,
+                )),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
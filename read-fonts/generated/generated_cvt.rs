@@ -0,0 +1,68 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [cvt (Control Value Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvt) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct CvtMarker {
+    values_byte_len: usize,
+}
+
+impl CvtMarker {
+    fn values_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.values_byte_len
+    }
+}
+
+impl TopLevelTable for Cvt<'_> {
+    /// `cvt `
+    const TAG: Tag = Tag::new(b"cvt ");
+}
+
+impl<'a> FontRead<'a> for Cvt<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let values_byte_len = cursor.remaining_bytes() / i16::RAW_BYTE_LEN * i16::RAW_BYTE_LEN;
+        cursor.advance_by(values_byte_len);
+        cursor.finish(CvtMarker { values_byte_len })
+    }
+}
+
+/// The [cvt (Control Value Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvt) table.
+pub type Cvt<'a> = TableRef<'a, CvtMarker>;
+
+impl<'a> Cvt<'a> {
+    /// List of values referenceable by instructions, in font units.
+    pub fn values(&self) -> &'a [BigEndian<i16>] {
+        let range = self.shape.values_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Cvt<'a> {
+    fn type_name(&self) -> &str {
+        "Cvt"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("values", self.values())
+                    .with_bytes(self.data, self.shape.values_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Cvt<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
@@ -122,18 +122,39 @@ impl<'a> SomeTable<'a> for Fvar<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "axis_instance_arrays_offset",
-                FieldType::offset(
-                    self.axis_instance_arrays_offset(),
-                    self.axis_instance_arrays(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "axis_instance_arrays_offset",
+                    FieldType::offset(
+                        self.axis_instance_arrays_offset(),
+                        self.axis_instance_arrays(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.axis_instance_arrays_offset_byte_range(),
                 ),
-            )),
-            2usize => Some(Field::new("axis_count", self.axis_count())),
-            3usize => Some(Field::new("axis_size", self.axis_size())),
-            4usize => Some(Field::new("instance_count", self.instance_count())),
-            5usize => Some(Field::new("instance_size", self.instance_size())),
+            ),
+            2usize => Some(
+                Field::new("axis_count", self.axis_count())
+                    .with_bytes(self.data, self.shape.axis_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("axis_size", self.axis_size())
+                    .with_bytes(self.data, self.shape.axis_size_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("instance_count", self.instance_count())
+                    .with_bytes(self.data, self.shape.instance_count_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("instance_size", self.instance_size())
+                    .with_bytes(self.data, self.shape.instance_size_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -177,8 +198,12 @@ impl<'a> FontReadWithArgs<'a> for AxisInstanceArrays<'a> {
         let mut cursor = data.cursor();
         let axes_byte_len = axis_count as usize * VariationAxisRecord::RAW_BYTE_LEN;
         cursor.advance_by(axes_byte_len);
-        let instances_byte_len = instance_count as usize
-            * <InstanceRecord as ComputeSize>::compute_size(&(axis_count, instance_size));
+        let instances_byte_len = (instance_count as usize)
+            .checked_mul(<InstanceRecord as ComputeSize>::compute_size(&(
+                axis_count,
+                instance_size,
+            ))?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(instances_byte_len);
         cursor.finish(AxisInstanceArraysMarker {
             axis_count,
@@ -239,22 +264,28 @@ impl<'a> SomeTable<'a> for AxisInstanceArrays<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "axes",
-                traversal::FieldType::array_of_records(
-                    stringify!(VariationAxisRecord),
-                    self.axes(),
-                    self.offset_data(),
-                ),
-            )),
-            1usize => Some(Field::new(
-                "instances",
-                traversal::FieldType::computed_array(
-                    "InstanceRecord",
-                    self.instances(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new(
+                    "axes",
+                    traversal::FieldType::array_of_records(
+                        stringify!(VariationAxisRecord),
+                        self.axes(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.axes_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "instances",
+                    traversal::FieldType::computed_array(
+                        "InstanceRecord",
+                        self.instances(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.instances_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -340,15 +371,44 @@ impl<'a> SomeRecord<'a> for VariationAxisRecord {
         RecordResolver {
             name: "VariationAxisRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("axis_tag", self.axis_tag())),
-                1usize => Some(Field::new("min_value", self.min_value())),
-                2usize => Some(Field::new("default_value", self.default_value())),
-                3usize => Some(Field::new("max_value", self.max_value())),
-                4usize => Some(Field::new("flags", self.flags())),
-                5usize => Some(Field::new("axis_name_id", self.axis_name_id())),
+                0usize => Some(
+                    Field::new("axis_tag", self.axis_tag()).with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("min_value", self.min_value())
+                        .with_record_range(Tag::RAW_BYTE_LEN, Fixed::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("default_value", self.default_value()).with_record_range(
+                        Tag::RAW_BYTE_LEN + Fixed::RAW_BYTE_LEN,
+                        Fixed::RAW_BYTE_LEN,
+                    ),
+                ),
+                3usize => Some(Field::new("max_value", self.max_value()).with_record_range(
+                    Tag::RAW_BYTE_LEN + Fixed::RAW_BYTE_LEN + Fixed::RAW_BYTE_LEN,
+                    Fixed::RAW_BYTE_LEN,
+                )),
+                4usize => Some(Field::new("flags", self.flags()).with_record_range(
+                    Tag::RAW_BYTE_LEN
+                        + Fixed::RAW_BYTE_LEN
+                        + Fixed::RAW_BYTE_LEN
+                        + Fixed::RAW_BYTE_LEN,
+                    u16::RAW_BYTE_LEN,
+                )),
+                5usize => Some(
+                    Field::new("axis_name_id", self.axis_name_id()).with_record_range(
+                        Tag::RAW_BYTE_LEN
+                            + Fixed::RAW_BYTE_LEN
+                            + Fixed::RAW_BYTE_LEN
+                            + Fixed::RAW_BYTE_LEN
+                            + u16::RAW_BYTE_LEN,
+                        NameId::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
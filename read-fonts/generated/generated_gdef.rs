@@ -175,40 +175,64 @@ impl<'a> SomeTable<'a> for Gdef<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "glyph_class_def_offset",
-                FieldType::offset(self.glyph_class_def_offset(), self.glyph_class_def()),
-            )),
-            2usize => Some(Field::new(
-                "attach_list_offset",
-                FieldType::offset(self.attach_list_offset(), self.attach_list()),
-            )),
-            3usize => Some(Field::new(
-                "lig_caret_list_offset",
-                FieldType::offset(self.lig_caret_list_offset(), self.lig_caret_list()),
-            )),
-            4usize => Some(Field::new(
-                "mark_attach_class_def_offset",
-                FieldType::offset(
-                    self.mark_attach_class_def_offset(),
-                    self.mark_attach_class_def(),
-                ),
-            )),
-            5usize if version.compatible((1, 2)) => Some(Field::new(
-                "mark_glyph_sets_def_offset",
-                FieldType::offset(
-                    self.mark_glyph_sets_def_offset().unwrap(),
-                    self.mark_glyph_sets_def().unwrap(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "glyph_class_def_offset",
+                    FieldType::offset(self.glyph_class_def_offset(), self.glyph_class_def()),
+                )
+                .with_bytes(self.data, self.shape.glyph_class_def_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "attach_list_offset",
+                    FieldType::offset(self.attach_list_offset(), self.attach_list()),
+                )
+                .with_bytes(self.data, self.shape.attach_list_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "lig_caret_list_offset",
+                    FieldType::offset(self.lig_caret_list_offset(), self.lig_caret_list()),
+                )
+                .with_bytes(self.data, self.shape.lig_caret_list_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "mark_attach_class_def_offset",
+                    FieldType::offset(
+                        self.mark_attach_class_def_offset(),
+                        self.mark_attach_class_def(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.mark_attach_class_def_offset_byte_range(),
                 ),
-            )),
-            6usize if version.compatible((1, 3)) => Some(Field::new(
-                "item_var_store_offset",
-                FieldType::offset(
-                    self.item_var_store_offset().unwrap(),
-                    self.item_var_store().unwrap(),
+            ),
+            5usize if version.compatible((1, 2)) => Some(
+                Field::new(
+                    "mark_glyph_sets_def_offset",
+                    FieldType::offset(
+                        self.mark_glyph_sets_def_offset().unwrap(),
+                        self.mark_glyph_sets_def(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.mark_glyph_sets_def_offset_byte_range(),
                 ),
-            )),
+            ),
+            6usize if version.compatible((1, 3)) => Some(
+                Field::new(
+                    "item_var_store_offset",
+                    FieldType::offset(self.item_var_store_offset().unwrap(), self.item_var_store()),
+                )
+                .with_bytes(self.data, self.shape.item_var_store_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -346,25 +370,34 @@ impl<'a> SomeTable<'a> for AttachList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            1usize => Some(Field::new("glyph_count", self.glyph_count())),
-            2usize => Some({
-                let data = self.data;
+            0usize => Some(
                 Field::new(
-                    "attach_point_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<AttachPoint>(),
-                        self.attach_point_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<AttachPoint>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            2usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "attach_point_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<AttachPoint>(),
+                            self.attach_point_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<AttachPoint>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.attach_point_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -431,8 +464,14 @@ impl<'a> SomeTable<'a> for AttachPoint<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("point_count", self.point_count())),
-            1usize => Some(Field::new("point_indices", self.point_indices())),
+            0usize => Some(
+                Field::new("point_count", self.point_count())
+                    .with_bytes(self.data, self.shape.point_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("point_indices", self.point_indices())
+                    .with_bytes(self.data, self.shape.point_indices_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -524,25 +563,34 @@ impl<'a> SomeTable<'a> for LigCaretList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            1usize => Some(Field::new("lig_glyph_count", self.lig_glyph_count())),
-            2usize => Some({
-                let data = self.data;
+            0usize => Some(
                 Field::new(
-                    "lig_glyph_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<LigGlyph>(),
-                        self.lig_glyph_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<LigGlyph>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("lig_glyph_count", self.lig_glyph_count())
+                    .with_bytes(self.data, self.shape.lig_glyph_count_byte_range()),
+            ),
+            2usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "lig_glyph_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<LigGlyph>(),
+                            self.lig_glyph_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<LigGlyph>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.lig_glyph_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -617,21 +665,27 @@ impl<'a> SomeTable<'a> for LigGlyph<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("caret_count", self.caret_count())),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "caret_value_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CaretValue>(),
-                        self.caret_value_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CaretValue>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("caret_count", self.caret_count())
+                    .with_bytes(self.data, self.shape.caret_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "caret_value_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CaretValue>(),
+                            self.caret_value_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CaretValue>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.caret_value_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -645,6 +699,7 @@ impl<'a> std::fmt::Debug for LigGlyph<'a> {
 }
 
 /// [Caret Value Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#caret-value-tables)
+#[derive(Clone)]
 pub enum CaretValue<'a> {
     Format1(CaretValueFormat1<'a>),
     Format2(CaretValueFormat2<'a>),
@@ -744,8 +799,14 @@ impl<'a> SomeTable<'a> for CaretValueFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("caret_value_format", self.caret_value_format())),
-            1usize => Some(Field::new("coordinate", self.coordinate())),
+            0usize => Some(
+                Field::new("caret_value_format", self.caret_value_format())
+                    .with_bytes(self.data, self.shape.caret_value_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coordinate", self.coordinate())
+                    .with_bytes(self.data, self.shape.coordinate_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -811,11 +872,14 @@ impl<'a> SomeTable<'a> for CaretValueFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("caret_value_format", self.caret_value_format())),
-            1usize => Some(Field::new(
-                "caret_value_point_index",
-                self.caret_value_point_index(),
-            )),
+            0usize => Some(
+                Field::new("caret_value_format", self.caret_value_format())
+                    .with_bytes(self.data, self.shape.caret_value_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("caret_value_point_index", self.caret_value_point_index())
+                    .with_bytes(self.data, self.shape.caret_value_point_index_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -900,12 +964,21 @@ impl<'a> SomeTable<'a> for CaretValueFormat3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("caret_value_format", self.caret_value_format())),
-            1usize => Some(Field::new("coordinate", self.coordinate())),
-            2usize => Some(Field::new(
-                "device_offset",
-                FieldType::offset(self.device_offset(), self.device()),
-            )),
+            0usize => Some(
+                Field::new("caret_value_format", self.caret_value_format())
+                    .with_bytes(self.data, self.shape.caret_value_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coordinate", self.coordinate())
+                    .with_bytes(self.data, self.shape.coordinate_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "device_offset",
+                    FieldType::offset(self.device_offset(), self.device()),
+                )
+                .with_bytes(self.data, self.shape.device_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -995,25 +1068,31 @@ impl<'a> SomeTable<'a> for MarkGlyphSets<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "mark_glyph_set_count",
-                self.mark_glyph_set_count(),
-            )),
-            2usize => Some({
-                let data = self.data;
-                Field::new(
-                    "coverage_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CoverageTable>(),
-                        self.coverage_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CoverageTable>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("mark_glyph_set_count", self.mark_glyph_set_count())
+                    .with_bytes(self.data, self.shape.mark_glyph_set_count_byte_range()),
+            ),
+            2usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "coverage_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CoverageTable>(),
+                            self.coverage_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CoverageTable>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.coverage_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -193,18 +193,42 @@ impl<'a> SomeTable<'a> for SimpleGlyph<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("number_of_contours", self.number_of_contours())),
-            1usize => Some(Field::new("x_min", self.x_min())),
-            2usize => Some(Field::new("y_min", self.y_min())),
-            3usize => Some(Field::new("x_max", self.x_max())),
-            4usize => Some(Field::new("y_max", self.y_max())),
-            5usize => Some(Field::new(
-                "end_pts_of_contours",
-                self.end_pts_of_contours(),
-            )),
-            6usize => Some(Field::new("instruction_length", self.instruction_length())),
-            7usize => Some(Field::new("instructions", self.instructions())),
-            8usize => Some(Field::new("glyph_data", self.glyph_data())),
+            0usize => Some(
+                Field::new("number_of_contours", self.number_of_contours())
+                    .with_bytes(self.data, self.shape.number_of_contours_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_min", self.x_min())
+                    .with_bytes(self.data, self.shape.x_min_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("y_min", self.y_min())
+                    .with_bytes(self.data, self.shape.y_min_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("x_max", self.x_max())
+                    .with_bytes(self.data, self.shape.x_max_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("y_max", self.y_max())
+                    .with_bytes(self.data, self.shape.y_max_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("end_pts_of_contours", self.end_pts_of_contours())
+                    .with_bytes(self.data, self.shape.end_pts_of_contours_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("instruction_length", self.instruction_length())
+                    .with_bytes(self.data, self.shape.instruction_length_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("instructions", self.instructions())
+                    .with_bytes(self.data, self.shape.instructions_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("glyph_data", self.glyph_data())
+                    .with_bytes(self.data, self.shape.glyph_data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -706,12 +730,30 @@ impl<'a> SomeTable<'a> for CompositeGlyph<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("number_of_contours", self.number_of_contours())),
-            1usize => Some(Field::new("x_min", self.x_min())),
-            2usize => Some(Field::new("y_min", self.y_min())),
-            3usize => Some(Field::new("x_max", self.x_max())),
-            4usize => Some(Field::new("y_max", self.y_max())),
-            5usize => Some(Field::new("component_data", self.component_data())),
+            0usize => Some(
+                Field::new("number_of_contours", self.number_of_contours())
+                    .with_bytes(self.data, self.shape.number_of_contours_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_min", self.x_min())
+                    .with_bytes(self.data, self.shape.x_min_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("y_min", self.y_min())
+                    .with_bytes(self.data, self.shape.y_min_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("x_max", self.x_max())
+                    .with_bytes(self.data, self.shape.x_max_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("y_max", self.y_max())
+                    .with_bytes(self.data, self.shape.y_max_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("component_data", self.component_data())
+                    .with_bytes(self.data, self.shape.component_data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1102,6 +1144,7 @@ impl<'a> From<CompositeGlyphFlags> for FieldType<'a> {
 }
 
 /// Simple or composite glyph.
+#[derive(Clone)]
 pub enum Glyph<'a> {
     Simple(SimpleGlyph<'a>),
     Composite(CompositeGlyph<'a>),
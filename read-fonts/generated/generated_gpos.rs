@@ -128,26 +128,41 @@ impl<'a> SomeTable<'a> for Gpos<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "script_list_offset",
-                FieldType::offset(self.script_list_offset(), self.script_list()),
-            )),
-            2usize => Some(Field::new(
-                "feature_list_offset",
-                FieldType::offset(self.feature_list_offset(), self.feature_list()),
-            )),
-            3usize => Some(Field::new(
-                "lookup_list_offset",
-                FieldType::offset(self.lookup_list_offset(), self.lookup_list()),
-            )),
-            4usize if version.compatible((1, 1)) => Some(Field::new(
-                "feature_variations_offset",
-                FieldType::offset(
-                    self.feature_variations_offset().unwrap(),
-                    self.feature_variations().unwrap(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "script_list_offset",
+                    FieldType::offset(self.script_list_offset(), self.script_list()),
+                )
+                .with_bytes(self.data, self.shape.script_list_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "feature_list_offset",
+                    FieldType::offset(self.feature_list_offset(), self.feature_list()),
+                )
+                .with_bytes(self.data, self.shape.feature_list_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "lookup_list_offset",
+                    FieldType::offset(self.lookup_list_offset(), self.lookup_list()),
+                )
+                .with_bytes(self.data, self.shape.lookup_list_offset_byte_range()),
+            ),
+            4usize if version.compatible((1, 1)) => Some(
+                Field::new(
+                    "feature_variations_offset",
+                    FieldType::offset(
+                        self.feature_variations_offset().unwrap(),
+                        self.feature_variations(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.feature_variations_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -161,6 +176,7 @@ impl<'a> std::fmt::Debug for Gpos<'a> {
 }
 
 /// A [GPOS Lookup](https://learn.microsoft.com/en-us/typography/opentype/spec/gpos#gsubLookupTypeEnum) subtable.
+#[derive(Clone)]
 pub enum PositionLookup<'a> {
     Single(Lookup<'a, SinglePos<'a>>),
     Pair(Lookup<'a, PairPos<'a>>),
@@ -561,6 +577,7 @@ impl<'a> From<ValueFormat> for FieldType<'a> {
 
 /// [Anchor Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#anchor-tables)
 /// position one glyph with respect to another.
+#[derive(Clone)]
 pub enum AnchorTable<'a> {
     Format1(AnchorFormat1<'a>),
     Format2(AnchorFormat2<'a>),
@@ -671,9 +688,18 @@ impl<'a> SomeTable<'a> for AnchorFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("anchor_format", self.anchor_format())),
-            1usize => Some(Field::new("x_coordinate", self.x_coordinate())),
-            2usize => Some(Field::new("y_coordinate", self.y_coordinate())),
+            0usize => Some(
+                Field::new("anchor_format", self.anchor_format())
+                    .with_bytes(self.data, self.shape.anchor_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_coordinate", self.x_coordinate())
+                    .with_bytes(self.data, self.shape.x_coordinate_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("y_coordinate", self.y_coordinate())
+                    .with_bytes(self.data, self.shape.y_coordinate_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -761,10 +787,22 @@ impl<'a> SomeTable<'a> for AnchorFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("anchor_format", self.anchor_format())),
-            1usize => Some(Field::new("x_coordinate", self.x_coordinate())),
-            2usize => Some(Field::new("y_coordinate", self.y_coordinate())),
-            3usize => Some(Field::new("anchor_point", self.anchor_point())),
+            0usize => Some(
+                Field::new("anchor_format", self.anchor_format())
+                    .with_bytes(self.data, self.shape.anchor_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_coordinate", self.x_coordinate())
+                    .with_bytes(self.data, self.shape.x_coordinate_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("y_coordinate", self.y_coordinate())
+                    .with_bytes(self.data, self.shape.y_coordinate_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("anchor_point", self.anchor_point())
+                    .with_bytes(self.data, self.shape.anchor_point_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -879,17 +917,32 @@ impl<'a> SomeTable<'a> for AnchorFormat3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("anchor_format", self.anchor_format())),
-            1usize => Some(Field::new("x_coordinate", self.x_coordinate())),
-            2usize => Some(Field::new("y_coordinate", self.y_coordinate())),
-            3usize => Some(Field::new(
-                "x_device_offset",
-                FieldType::offset(self.x_device_offset(), self.x_device()),
-            )),
-            4usize => Some(Field::new(
-                "y_device_offset",
-                FieldType::offset(self.y_device_offset(), self.y_device()),
-            )),
+            0usize => Some(
+                Field::new("anchor_format", self.anchor_format())
+                    .with_bytes(self.data, self.shape.anchor_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_coordinate", self.x_coordinate())
+                    .with_bytes(self.data, self.shape.x_coordinate_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("y_coordinate", self.y_coordinate())
+                    .with_bytes(self.data, self.shape.y_coordinate_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "x_device_offset",
+                    FieldType::offset(self.x_device_offset(), self.x_device()),
+                )
+                .with_bytes(self.data, self.shape.x_device_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "y_device_offset",
+                    FieldType::offset(self.y_device_offset(), self.y_device()),
+                )
+                .with_bytes(self.data, self.shape.y_device_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -957,15 +1010,21 @@ impl<'a> SomeTable<'a> for MarkArray<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("mark_count", self.mark_count())),
-            1usize => Some(Field::new(
-                "mark_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(MarkRecord),
-                    self.mark_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("mark_count", self.mark_count())
+                    .with_bytes(self.data, self.shape.mark_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "mark_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(MarkRecord),
+                        self.mark_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.mark_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1023,19 +1082,27 @@ impl<'a> SomeRecord<'a> for MarkRecord {
         RecordResolver {
             name: "MarkRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("mark_class", self.mark_class())),
-                1usize => Some(Field::new(
-                    "mark_anchor_offset",
-                    FieldType::offset(self.mark_anchor_offset(), self.mark_anchor(_data)),
-                )),
+                0usize => Some(
+                    Field::new("mark_class", self.mark_class())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "mark_anchor_offset",
+                        FieldType::offset(self.mark_anchor_offset(), self.mark_anchor(_data)),
+                    )
+                    .with_record_range(u16::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
 
 /// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-1-single-adjustment-positioning-subtable): Single Adjustment Positioning Subtable
+#[derive(Clone)]
 pub enum SinglePos<'a> {
     Format1(SinglePosFormat1<'a>),
     Format2(SinglePosFormat2<'a>),
@@ -1115,7 +1182,7 @@ impl<'a> FontRead<'a> for SinglePosFormat1<'a> {
         cursor.advance::<u16>();
         cursor.advance::<Offset16>();
         let value_format: ValueFormat = cursor.read()?;
-        let value_record_byte_len = <ValueRecord as ComputeSize>::compute_size(&value_format);
+        let value_record_byte_len = <ValueRecord as ComputeSize>::compute_size(&value_format)?;
         cursor.advance_by(value_record_byte_len);
         cursor.finish(SinglePosFormat1Marker {
             value_record_byte_len,
@@ -1168,16 +1235,29 @@ impl<'a> SomeTable<'a> for SinglePosFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("value_format", self.value_format())),
-            3usize => Some(Field::new(
-                "value_record",
-                self.value_record().traversal_type(self.offset_data()),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
+                )
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("value_format", self.value_format())
+                    .with_bytes(self.data, self.shape.value_format_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "value_record",
+                    self.value_record()
+                        .traversal_type(self.offset_data(), self.value_format()),
+                )
+                .with_bytes(self.data, self.shape.value_record_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1231,8 +1311,9 @@ impl<'a> FontRead<'a> for SinglePosFormat2<'a> {
         cursor.advance::<Offset16>();
         let value_format: ValueFormat = cursor.read()?;
         let value_count: u16 = cursor.read()?;
-        let value_records_byte_len =
-            value_count as usize * <ValueRecord as ComputeSize>::compute_size(&value_format);
+        let value_records_byte_len = (value_count as usize)
+            .checked_mul(<ValueRecord as ComputeSize>::compute_size(&value_format)?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(value_records_byte_len);
         cursor.finish(SinglePosFormat2Marker {
             value_records_byte_len,
@@ -1291,21 +1372,36 @@ impl<'a> SomeTable<'a> for SinglePosFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("value_format", self.value_format())),
-            3usize => Some(Field::new("value_count", self.value_count())),
-            4usize => Some(Field::new(
-                "value_records",
-                traversal::FieldType::computed_array(
-                    "ValueRecord",
-                    self.value_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
+                )
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("value_format", self.value_format())
+                    .with_bytes(self.data, self.shape.value_format_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("value_count", self.value_count())
+                    .with_bytes(self.data, self.shape.value_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "value_records",
+                    ValueRecord::traverse_array(
+                        self.value_records(),
+                        self.offset_data(),
+                        self.value_format(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.value_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1319,6 +1415,7 @@ impl<'a> std::fmt::Debug for SinglePosFormat2<'a> {
 }
 
 /// [Lookup Type 1](https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#lookup-type-1-single-adjustment-positioning-subtable): Single Adjustment Positioning Subtable
+#[derive(Clone)]
 pub enum PairPos<'a> {
     Format1(PairPosFormat1<'a>),
     Format2(PairPosFormat2<'a>),
@@ -1481,29 +1578,47 @@ impl<'a> SomeTable<'a> for PairPosFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("value_format1", self.value_format1())),
-            3usize => Some(Field::new("value_format2", self.value_format2())),
-            4usize => Some(Field::new("pair_set_count", self.pair_set_count())),
-            5usize => Some({
-                let data = self.data;
-                let args = (self.value_format1(), self.value_format2());
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "pair_set_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<PairSet>(),
-                        self.pair_set_offsets(),
-                        move |off| {
-                            let target = off.get().resolve_with_args::<PairSet>(data, &args);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("value_format1", self.value_format1())
+                    .with_bytes(self.data, self.shape.value_format1_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("value_format2", self.value_format2())
+                    .with_bytes(self.data, self.shape.value_format2_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("pair_set_count", self.pair_set_count())
+                    .with_bytes(self.data, self.shape.pair_set_count_byte_range()),
+            ),
+            5usize => Some(
+                {
+                    let data = self.data;
+                    let args = (self.value_format1(), self.value_format2());
+                    Field::new(
+                        "pair_set_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<PairSet>(),
+                            self.pair_set_offsets(),
+                            move |off| {
+                                let target = off.get().resolve_with_args::<PairSet>(data, &args);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.pair_set_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1548,8 +1663,12 @@ impl<'a> FontReadWithArgs<'a> for PairSet<'a> {
         let (value_format1, value_format2) = *args;
         let mut cursor = data.cursor();
         let pair_value_count: u16 = cursor.read()?;
-        let pair_value_records_byte_len = pair_value_count as usize
-            * <PairValueRecord as ComputeSize>::compute_size(&(value_format1, value_format2));
+        let pair_value_records_byte_len = (pair_value_count as usize)
+            .checked_mul(<PairValueRecord as ComputeSize>::compute_size(&(
+                value_format1,
+                value_format2,
+            ))?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(pair_value_records_byte_len);
         cursor.finish(PairSetMarker {
             value_format1,
@@ -1609,15 +1728,21 @@ impl<'a> SomeTable<'a> for PairSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pair_value_count", self.pair_value_count())),
-            1usize => Some(Field::new(
-                "pair_value_records",
-                traversal::FieldType::computed_array(
-                    "PairValueRecord",
-                    self.pair_value_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("pair_value_count", self.pair_value_count())
+                    .with_bytes(self.data, self.shape.pair_value_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "pair_value_records",
+                    traversal::FieldType::computed_array(
+                        "PairValueRecord",
+                        self.pair_value_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.pair_value_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1665,11 +1790,12 @@ impl ReadArgs for PairValueRecord {
 }
 
 impl ComputeSize for PairValueRecord {
-    fn compute_size(args: &(ValueFormat, ValueFormat)) -> usize {
+    fn compute_size(args: &(ValueFormat, ValueFormat)) -> Result<usize, ReadError> {
         let (value_format1, value_format2) = *args;
-        GlyphId::RAW_BYTE_LEN
-            + <ValueRecord as ComputeSize>::compute_size(&value_format1)
-            + <ValueRecord as ComputeSize>::compute_size(&value_format2)
+        let size = GlyphId::RAW_BYTE_LEN
+            + <ValueRecord as ComputeSize>::compute_size(&value_format1)?
+            + <ValueRecord as ComputeSize>::compute_size(&value_format2)?;
+        Ok(size)
     }
 }
 
@@ -1712,15 +1838,16 @@ impl<'a> SomeRecord<'a> for PairValueRecord {
                 0usize => Some(Field::new("second_glyph", self.second_glyph())),
                 1usize => Some(Field::new(
                     "value_record1",
-                    self.value_record1().traversal_type(_data),
+                    traversal::FieldType::Record(self.value_record1().clone().traverse(_data)),
                 )),
                 2usize => Some(Field::new(
                     "value_record2",
-                    self.value_record2().traversal_type(_data),
+                    traversal::FieldType::Record(self.value_record2().clone().traverse(_data)),
                 )),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1786,12 +1913,13 @@ impl<'a> FontRead<'a> for PairPosFormat2<'a> {
         cursor.advance::<Offset16>();
         let class1_count: u16 = cursor.read()?;
         let class2_count: u16 = cursor.read()?;
-        let class1_records_byte_len = class1_count as usize
-            * <Class1Record as ComputeSize>::compute_size(&(
+        let class1_records_byte_len = (class1_count as usize)
+            .checked_mul(<Class1Record as ComputeSize>::compute_size(&(
                 class2_count,
                 value_format1,
                 value_format2,
-            ));
+            ))?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(class1_records_byte_len);
         cursor.finish(PairPosFormat2Marker {
             class1_records_byte_len,
@@ -1896,31 +2024,58 @@ impl<'a> SomeTable<'a> for PairPosFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("value_format1", self.value_format1())),
-            3usize => Some(Field::new("value_format2", self.value_format2())),
-            4usize => Some(Field::new(
-                "class_def1_offset",
-                FieldType::offset(self.class_def1_offset(), self.class_def1()),
-            )),
-            5usize => Some(Field::new(
-                "class_def2_offset",
-                FieldType::offset(self.class_def2_offset(), self.class_def2()),
-            )),
-            6usize => Some(Field::new("class1_count", self.class1_count())),
-            7usize => Some(Field::new("class2_count", self.class2_count())),
-            8usize => Some(Field::new(
-                "class1_records",
-                traversal::FieldType::computed_array(
-                    "Class1Record",
-                    self.class1_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
+                )
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("value_format1", self.value_format1())
+                    .with_bytes(self.data, self.shape.value_format1_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("value_format2", self.value_format2())
+                    .with_bytes(self.data, self.shape.value_format2_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "class_def1_offset",
+                    FieldType::offset(self.class_def1_offset(), self.class_def1()),
+                )
+                .with_bytes(self.data, self.shape.class_def1_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "class_def2_offset",
+                    FieldType::offset(self.class_def2_offset(), self.class_def2()),
+                )
+                .with_bytes(self.data, self.shape.class_def2_offset_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("class1_count", self.class1_count())
+                    .with_bytes(self.data, self.shape.class1_count_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("class2_count", self.class2_count())
+                    .with_bytes(self.data, self.shape.class2_count_byte_range()),
+            ),
+            8usize => Some(
+                Field::new(
+                    "class1_records",
+                    traversal::FieldType::computed_array(
+                        "Class1Record",
+                        self.class1_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.class1_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1952,10 +2107,15 @@ impl ReadArgs for Class1Record<'_> {
 }
 
 impl ComputeSize for Class1Record<'_> {
-    fn compute_size(args: &(u16, ValueFormat, ValueFormat)) -> usize {
+    fn compute_size(args: &(u16, ValueFormat, ValueFormat)) -> Result<usize, ReadError> {
         let (class2_count, value_format1, value_format2) = *args;
-        class2_count as usize
-            * <Class2Record as ComputeSize>::compute_size(&(value_format1, value_format2))
+        let size = (class2_count as usize)
+            .checked_mul(<Class2Record as ComputeSize>::compute_size(&(
+                value_format1,
+                value_format2,
+            ))?)
+            .ok_or(ReadError::OutOfBounds)?;
+        Ok(size)
     }
 }
 
@@ -2006,6 +2166,7 @@ impl<'a> SomeRecord<'a> for Class1Record<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -2036,10 +2197,11 @@ impl ReadArgs for Class2Record {
 }
 
 impl ComputeSize for Class2Record {
-    fn compute_size(args: &(ValueFormat, ValueFormat)) -> usize {
+    fn compute_size(args: &(ValueFormat, ValueFormat)) -> Result<usize, ReadError> {
         let (value_format1, value_format2) = *args;
-        <ValueRecord as ComputeSize>::compute_size(&value_format1)
-            + <ValueRecord as ComputeSize>::compute_size(&value_format2)
+        let size = <ValueRecord as ComputeSize>::compute_size(&value_format1)?
+            + <ValueRecord as ComputeSize>::compute_size(&value_format2)?;
+        Ok(size)
     }
 }
 
@@ -2080,15 +2242,16 @@ impl<'a> SomeRecord<'a> for Class2Record {
             get_field: Box::new(move |idx, _data| match idx {
                 0usize => Some(Field::new(
                     "value_record1",
-                    self.value_record1().traversal_type(_data),
+                    traversal::FieldType::Record(self.value_record1().clone().traverse(_data)),
                 )),
                 1usize => Some(Field::new(
                     "value_record2",
-                    self.value_record2().traversal_type(_data),
+                    traversal::FieldType::Record(self.value_record2().clone().traverse(_data)),
                 )),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -2179,20 +2342,32 @@ impl<'a> SomeTable<'a> for CursivePosFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("entry_exit_count", self.entry_exit_count())),
-            3usize => Some(Field::new(
-                "entry_exit_record",
-                traversal::FieldType::array_of_records(
-                    stringify!(EntryExitRecord),
-                    self.entry_exit_record(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
+                )
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("entry_exit_count", self.entry_exit_count())
+                    .with_bytes(self.data, self.shape.entry_exit_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "entry_exit_record",
+                    traversal::FieldType::array_of_records(
+                        stringify!(EntryExitRecord),
+                        self.entry_exit_record(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.entry_exit_record_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2265,17 +2440,24 @@ impl<'a> SomeRecord<'a> for EntryExitRecord {
         RecordResolver {
             name: "EntryExitRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new(
-                    "entry_anchor_offset",
-                    FieldType::offset(self.entry_anchor_offset(), self.entry_anchor(_data)),
-                )),
-                1usize => Some(Field::new(
-                    "exit_anchor_offset",
-                    FieldType::offset(self.exit_anchor_offset(), self.exit_anchor(_data)),
-                )),
+                0usize => Some(
+                    Field::new(
+                        "entry_anchor_offset",
+                        FieldType::offset(self.entry_anchor_offset(), self.entry_anchor(_data)),
+                    )
+                    .with_record_range(0, Offset16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "exit_anchor_offset",
+                        FieldType::offset(self.exit_anchor_offset(), self.exit_anchor(_data)),
+                    )
+                    .with_record_range(Offset16::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -2406,24 +2588,42 @@ impl<'a> SomeTable<'a> for MarkBasePosFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "mark_coverage_offset",
-                FieldType::offset(self.mark_coverage_offset(), self.mark_coverage()),
-            )),
-            2usize => Some(Field::new(
-                "base_coverage_offset",
-                FieldType::offset(self.base_coverage_offset(), self.base_coverage()),
-            )),
-            3usize => Some(Field::new("mark_class_count", self.mark_class_count())),
-            4usize => Some(Field::new(
-                "mark_array_offset",
-                FieldType::offset(self.mark_array_offset(), self.mark_array()),
-            )),
-            5usize => Some(Field::new(
-                "base_array_offset",
-                FieldType::offset(self.base_array_offset(), self.base_array()),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "mark_coverage_offset",
+                    FieldType::offset(self.mark_coverage_offset(), self.mark_coverage()),
+                )
+                .with_bytes(self.data, self.shape.mark_coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "base_coverage_offset",
+                    FieldType::offset(self.base_coverage_offset(), self.base_coverage()),
+                )
+                .with_bytes(self.data, self.shape.base_coverage_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("mark_class_count", self.mark_class_count())
+                    .with_bytes(self.data, self.shape.mark_class_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "mark_array_offset",
+                    FieldType::offset(self.mark_array_offset(), self.mark_array()),
+                )
+                .with_bytes(self.data, self.shape.mark_array_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "base_array_offset",
+                    FieldType::offset(self.base_array_offset(), self.base_array()),
+                )
+                .with_bytes(self.data, self.shape.base_array_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2464,8 +2664,11 @@ impl<'a> FontReadWithArgs<'a> for BaseArray<'a> {
         let mark_class_count = *args;
         let mut cursor = data.cursor();
         let base_count: u16 = cursor.read()?;
-        let base_records_byte_len =
-            base_count as usize * <BaseRecord as ComputeSize>::compute_size(&mark_class_count);
+        let base_records_byte_len = (base_count as usize)
+            .checked_mul(<BaseRecord as ComputeSize>::compute_size(
+                &mark_class_count,
+            )?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(base_records_byte_len);
         cursor.finish(BaseArrayMarker {
             mark_class_count,
@@ -2515,15 +2718,21 @@ impl<'a> SomeTable<'a> for BaseArray<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("base_count", self.base_count())),
-            1usize => Some(Field::new(
-                "base_records",
-                traversal::FieldType::computed_array(
-                    "BaseRecord",
-                    self.base_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("base_count", self.base_count())
+                    .with_bytes(self.data, self.shape.base_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "base_records",
+                    traversal::FieldType::computed_array(
+                        "BaseRecord",
+                        self.base_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.base_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2568,9 +2777,10 @@ impl ReadArgs for BaseRecord<'_> {
 }
 
 impl ComputeSize for BaseRecord<'_> {
-    fn compute_size(args: &u16) -> usize {
+    fn compute_size(args: &u16) -> Result<usize, ReadError> {
         let mark_class_count = *args;
-        mark_class_count as usize * Offset16::RAW_BYTE_LEN
+        let size = mark_class_count as usize * Offset16::RAW_BYTE_LEN;
+        Ok(size)
     }
 }
 
@@ -2617,6 +2827,7 @@ impl<'a> SomeRecord<'a> for BaseRecord<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -2747,24 +2958,42 @@ impl<'a> SomeTable<'a> for MarkLigPosFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "mark_coverage_offset",
-                FieldType::offset(self.mark_coverage_offset(), self.mark_coverage()),
-            )),
-            2usize => Some(Field::new(
-                "ligature_coverage_offset",
-                FieldType::offset(self.ligature_coverage_offset(), self.ligature_coverage()),
-            )),
-            3usize => Some(Field::new("mark_class_count", self.mark_class_count())),
-            4usize => Some(Field::new(
-                "mark_array_offset",
-                FieldType::offset(self.mark_array_offset(), self.mark_array()),
-            )),
-            5usize => Some(Field::new(
-                "ligature_array_offset",
-                FieldType::offset(self.ligature_array_offset(), self.ligature_array()),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "mark_coverage_offset",
+                    FieldType::offset(self.mark_coverage_offset(), self.mark_coverage()),
+                )
+                .with_bytes(self.data, self.shape.mark_coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "ligature_coverage_offset",
+                    FieldType::offset(self.ligature_coverage_offset(), self.ligature_coverage()),
+                )
+                .with_bytes(self.data, self.shape.ligature_coverage_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("mark_class_count", self.mark_class_count())
+                    .with_bytes(self.data, self.shape.mark_class_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "mark_array_offset",
+                    FieldType::offset(self.mark_array_offset(), self.mark_array()),
+                )
+                .with_bytes(self.data, self.shape.mark_array_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "ligature_array_offset",
+                    FieldType::offset(self.ligature_array_offset(), self.ligature_array()),
+                )
+                .with_bytes(self.data, self.shape.ligature_array_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2863,22 +3092,29 @@ impl<'a> SomeTable<'a> for LigatureArray<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("ligature_count", self.ligature_count())),
-            1usize => Some({
-                let data = self.data;
-                let args = self.mark_class_count();
-                Field::new(
-                    "ligature_attach_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<LigatureAttach>(),
-                        self.ligature_attach_offsets(),
-                        move |off| {
-                            let target = off.get().resolve_with_args::<LigatureAttach>(data, &args);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("ligature_count", self.ligature_count())
+                    .with_bytes(self.data, self.shape.ligature_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    let args = self.mark_class_count();
+                    Field::new(
+                        "ligature_attach_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<LigatureAttach>(),
+                            self.ligature_attach_offsets(),
+                            move |off| {
+                                let target =
+                                    off.get().resolve_with_args::<LigatureAttach>(data, &args);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.ligature_attach_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2919,8 +3155,11 @@ impl<'a> FontReadWithArgs<'a> for LigatureAttach<'a> {
         let mark_class_count = *args;
         let mut cursor = data.cursor();
         let component_count: u16 = cursor.read()?;
-        let component_records_byte_len = component_count as usize
-            * <ComponentRecord as ComputeSize>::compute_size(&mark_class_count);
+        let component_records_byte_len = (component_count as usize)
+            .checked_mul(<ComponentRecord as ComputeSize>::compute_size(
+                &mark_class_count,
+            )?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(component_records_byte_len);
         cursor.finish(LigatureAttachMarker {
             mark_class_count,
@@ -2970,15 +3209,21 @@ impl<'a> SomeTable<'a> for LigatureAttach<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("component_count", self.component_count())),
-            1usize => Some(Field::new(
-                "component_records",
-                traversal::FieldType::computed_array(
-                    "ComponentRecord",
-                    self.component_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("component_count", self.component_count())
+                    .with_bytes(self.data, self.shape.component_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "component_records",
+                    traversal::FieldType::computed_array(
+                        "ComponentRecord",
+                        self.component_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.component_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3023,9 +3268,10 @@ impl ReadArgs for ComponentRecord<'_> {
 }
 
 impl ComputeSize for ComponentRecord<'_> {
-    fn compute_size(args: &u16) -> usize {
+    fn compute_size(args: &u16) -> Result<usize, ReadError> {
         let mark_class_count = *args;
-        mark_class_count as usize * Offset16::RAW_BYTE_LEN
+        let size = mark_class_count as usize * Offset16::RAW_BYTE_LEN;
+        Ok(size)
     }
 }
 
@@ -3072,6 +3318,7 @@ impl<'a> SomeRecord<'a> for ComponentRecord<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -3202,24 +3449,42 @@ impl<'a> SomeTable<'a> for MarkMarkPosFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "mark1_coverage_offset",
-                FieldType::offset(self.mark1_coverage_offset(), self.mark1_coverage()),
-            )),
-            2usize => Some(Field::new(
-                "mark2_coverage_offset",
-                FieldType::offset(self.mark2_coverage_offset(), self.mark2_coverage()),
-            )),
-            3usize => Some(Field::new("mark_class_count", self.mark_class_count())),
-            4usize => Some(Field::new(
-                "mark1_array_offset",
-                FieldType::offset(self.mark1_array_offset(), self.mark1_array()),
-            )),
-            5usize => Some(Field::new(
-                "mark2_array_offset",
-                FieldType::offset(self.mark2_array_offset(), self.mark2_array()),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "mark1_coverage_offset",
+                    FieldType::offset(self.mark1_coverage_offset(), self.mark1_coverage()),
+                )
+                .with_bytes(self.data, self.shape.mark1_coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "mark2_coverage_offset",
+                    FieldType::offset(self.mark2_coverage_offset(), self.mark2_coverage()),
+                )
+                .with_bytes(self.data, self.shape.mark2_coverage_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("mark_class_count", self.mark_class_count())
+                    .with_bytes(self.data, self.shape.mark_class_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "mark1_array_offset",
+                    FieldType::offset(self.mark1_array_offset(), self.mark1_array()),
+                )
+                .with_bytes(self.data, self.shape.mark1_array_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "mark2_array_offset",
+                    FieldType::offset(self.mark2_array_offset(), self.mark2_array()),
+                )
+                .with_bytes(self.data, self.shape.mark2_array_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3260,8 +3525,11 @@ impl<'a> FontReadWithArgs<'a> for Mark2Array<'a> {
         let mark_class_count = *args;
         let mut cursor = data.cursor();
         let mark2_count: u16 = cursor.read()?;
-        let mark2_records_byte_len =
-            mark2_count as usize * <Mark2Record as ComputeSize>::compute_size(&mark_class_count);
+        let mark2_records_byte_len = (mark2_count as usize)
+            .checked_mul(<Mark2Record as ComputeSize>::compute_size(
+                &mark_class_count,
+            )?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(mark2_records_byte_len);
         cursor.finish(Mark2ArrayMarker {
             mark_class_count,
@@ -3311,15 +3579,21 @@ impl<'a> SomeTable<'a> for Mark2Array<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("mark2_count", self.mark2_count())),
-            1usize => Some(Field::new(
-                "mark2_records",
-                traversal::FieldType::computed_array(
-                    "Mark2Record",
-                    self.mark2_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("mark2_count", self.mark2_count())
+                    .with_bytes(self.data, self.shape.mark2_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "mark2_records",
+                    traversal::FieldType::computed_array(
+                        "Mark2Record",
+                        self.mark2_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.mark2_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3364,9 +3638,10 @@ impl ReadArgs for Mark2Record<'_> {
 }
 
 impl ComputeSize for Mark2Record<'_> {
-    fn compute_size(args: &u16) -> usize {
+    fn compute_size(args: &u16) -> Result<usize, ReadError> {
         let mark_class_count = *args;
-        mark_class_count as usize * Offset16::RAW_BYTE_LEN
+        let size = mark_class_count as usize * Offset16::RAW_BYTE_LEN;
+        Ok(size)
     }
 }
 
@@ -3413,6 +3688,7 @@ impl<'a> SomeRecord<'a> for Mark2Record<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -3520,15 +3796,21 @@ impl<'a, T: FontRead<'a> + SomeTable<'a> + 'a> SomeTable<'a> for ExtensionPosFor
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("pos_format", self.pos_format())),
-            1usize => Some(Field::new(
-                "extension_lookup_type",
-                self.extension_lookup_type(),
-            )),
-            2usize => Some(Field::new(
-                "extension_offset",
-                FieldType::offset(self.extension_offset(), self.extension()),
-            )),
+            0usize => Some(
+                Field::new("pos_format", self.pos_format())
+                    .with_bytes(self.data, self.shape.pos_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("extension_lookup_type", self.extension_lookup_type())
+                    .with_bytes(self.data, self.shape.extension_lookup_type_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "extension_offset",
+                    FieldType::offset(self.extension_offset(), self.extension()),
+                )
+                .with_bytes(self.data, self.shape.extension_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3542,6 +3824,7 @@ impl<'a, T: FontRead<'a> + SomeTable<'a> + 'a> std::fmt::Debug for ExtensionPosF
 }
 
 /// A [GPOS Extension Positioning](https://learn.microsoft.com/en-us/typography/opentype/spec/gpos#lookuptype-9-extension-positioning) subtable
+#[derive(Clone)]
 pub enum ExtensionSubtable<'a> {
     Single(ExtensionPosFormat1<'a, SinglePos<'a>>),
     Pair(ExtensionPosFormat1<'a, PairPos<'a>>),
@@ -128,26 +128,41 @@ impl<'a> SomeTable<'a> for Gsub<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "script_list_offset",
-                FieldType::offset(self.script_list_offset(), self.script_list()),
-            )),
-            2usize => Some(Field::new(
-                "feature_list_offset",
-                FieldType::offset(self.feature_list_offset(), self.feature_list()),
-            )),
-            3usize => Some(Field::new(
-                "lookup_list_offset",
-                FieldType::offset(self.lookup_list_offset(), self.lookup_list()),
-            )),
-            4usize if version.compatible((1, 1)) => Some(Field::new(
-                "feature_variations_offset",
-                FieldType::offset(
-                    self.feature_variations_offset().unwrap(),
-                    self.feature_variations().unwrap(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "script_list_offset",
+                    FieldType::offset(self.script_list_offset(), self.script_list()),
+                )
+                .with_bytes(self.data, self.shape.script_list_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "feature_list_offset",
+                    FieldType::offset(self.feature_list_offset(), self.feature_list()),
+                )
+                .with_bytes(self.data, self.shape.feature_list_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "lookup_list_offset",
+                    FieldType::offset(self.lookup_list_offset(), self.lookup_list()),
+                )
+                .with_bytes(self.data, self.shape.lookup_list_offset_byte_range()),
+            ),
+            4usize if version.compatible((1, 1)) => Some(
+                Field::new(
+                    "feature_variations_offset",
+                    FieldType::offset(
+                        self.feature_variations_offset().unwrap(),
+                        self.feature_variations(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.feature_variations_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -161,6 +176,7 @@ impl<'a> std::fmt::Debug for Gsub<'a> {
 }
 
 /// A [GSUB Lookup](https://learn.microsoft.com/en-us/typography/opentype/spec/gsub#gsubLookupTypeEnum) subtable.
+#[derive(Clone)]
 pub enum SubstitutionLookup<'a> {
     Single(Lookup<'a, SingleSubst<'a>>),
     Multiple(Lookup<'a, MultipleSubstFormat1<'a>>),
@@ -216,6 +232,7 @@ impl<'a> SomeTable<'a> for SubstitutionLookup<'a> {
 }
 
 /// LookupType 1: [Single Substitution](https://learn.microsoft.com/en-us/typography/opentype/spec/gsub#lookuptype-1-single-substitution-subtable) Subtable
+#[derive(Clone)]
 pub enum SingleSubst<'a> {
     Format1(SingleSubstFormat1<'a>),
     Format2(SingleSubstFormat2<'a>),
@@ -330,12 +347,21 @@ impl<'a> SomeTable<'a> for SingleSubstFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("subst_format", self.subst_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("delta_glyph_id", self.delta_glyph_id())),
+            0usize => Some(
+                Field::new("subst_format", self.subst_format())
+                    .with_bytes(self.data, self.shape.subst_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
+                )
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("delta_glyph_id", self.delta_glyph_id())
+                    .with_bytes(self.data, self.shape.delta_glyph_id_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -435,16 +461,25 @@ impl<'a> SomeTable<'a> for SingleSubstFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("subst_format", self.subst_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("glyph_count", self.glyph_count())),
-            3usize => Some(Field::new(
-                "substitute_glyph_ids",
-                self.substitute_glyph_ids(),
-            )),
+            0usize => Some(
+                Field::new("subst_format", self.subst_format())
+                    .with_bytes(self.data, self.shape.subst_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
+                )
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("substitute_glyph_ids", self.substitute_glyph_ids())
+                    .with_bytes(self.data, self.shape.substitute_glyph_ids_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -552,26 +587,38 @@ impl<'a> SomeTable<'a> for MultipleSubstFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("subst_format", self.subst_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("sequence_count", self.sequence_count())),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("subst_format", self.subst_format())
+                    .with_bytes(self.data, self.shape.subst_format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "sequence_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<Sequence>(),
-                        self.sequence_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<Sequence>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("sequence_count", self.sequence_count())
+                    .with_bytes(self.data, self.shape.sequence_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "sequence_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<Sequence>(),
+                            self.sequence_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<Sequence>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.sequence_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -639,11 +686,14 @@ impl<'a> SomeTable<'a> for Sequence<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("glyph_count", self.glyph_count())),
-            1usize => Some(Field::new(
-                "substitute_glyph_ids",
-                self.substitute_glyph_ids(),
-            )),
+            0usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("substitute_glyph_ids", self.substitute_glyph_ids())
+                    .with_bytes(self.data, self.shape.substitute_glyph_ids_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -751,29 +801,38 @@ impl<'a> SomeTable<'a> for AlternateSubstFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("subst_format", self.subst_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new(
-                "alternate_set_count",
-                self.alternate_set_count(),
-            )),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("subst_format", self.subst_format())
+                    .with_bytes(self.data, self.shape.subst_format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "alternate_set_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<AlternateSet>(),
-                        self.alternate_set_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<AlternateSet>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("alternate_set_count", self.alternate_set_count())
+                    .with_bytes(self.data, self.shape.alternate_set_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "alternate_set_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<AlternateSet>(),
+                            self.alternate_set_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<AlternateSet>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.alternate_set_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -840,11 +899,14 @@ impl<'a> SomeTable<'a> for AlternateSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("glyph_count", self.glyph_count())),
-            1usize => Some(Field::new(
-                "alternate_glyph_ids",
-                self.alternate_glyph_ids(),
-            )),
+            0usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("alternate_glyph_ids", self.alternate_glyph_ids())
+                    .with_bytes(self.data, self.shape.alternate_glyph_ids_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -952,26 +1014,38 @@ impl<'a> SomeTable<'a> for LigatureSubstFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("subst_format", self.subst_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("ligature_set_count", self.ligature_set_count())),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("subst_format", self.subst_format())
+                    .with_bytes(self.data, self.shape.subst_format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "ligature_set_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<LigatureSet>(),
-                        self.ligature_set_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<LigatureSet>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("ligature_set_count", self.ligature_set_count())
+                    .with_bytes(self.data, self.shape.ligature_set_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "ligature_set_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<LigatureSet>(),
+                            self.ligature_set_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<LigatureSet>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.ligature_set_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1046,21 +1120,27 @@ impl<'a> SomeTable<'a> for LigatureSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("ligature_count", self.ligature_count())),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "ligature_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<Ligature>(),
-                        self.ligature_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<Ligature>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("ligature_count", self.ligature_count())
+                    .with_bytes(self.data, self.shape.ligature_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "ligature_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<Ligature>(),
+                            self.ligature_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<Ligature>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.ligature_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1140,12 +1220,18 @@ impl<'a> SomeTable<'a> for Ligature<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("ligature_glyph", self.ligature_glyph())),
-            1usize => Some(Field::new("component_count", self.component_count())),
-            2usize => Some(Field::new(
-                "component_glyph_ids",
-                self.component_glyph_ids(),
-            )),
+            0usize => Some(
+                Field::new("ligature_glyph", self.ligature_glyph())
+                    .with_bytes(self.data, self.shape.ligature_glyph_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("component_count", self.component_count())
+                    .with_bytes(self.data, self.shape.component_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("component_glyph_ids", self.component_glyph_ids())
+                    .with_bytes(self.data, self.shape.component_glyph_ids_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1261,15 +1347,21 @@ impl<'a, T: FontRead<'a> + SomeTable<'a> + 'a> SomeTable<'a> for ExtensionSubstF
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("subst_format", self.subst_format())),
-            1usize => Some(Field::new(
-                "extension_lookup_type",
-                self.extension_lookup_type(),
-            )),
-            2usize => Some(Field::new(
-                "extension_offset",
-                FieldType::offset(self.extension_offset(), self.extension()),
-            )),
+            0usize => Some(
+                Field::new("subst_format", self.subst_format())
+                    .with_bytes(self.data, self.shape.subst_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("extension_lookup_type", self.extension_lookup_type())
+                    .with_bytes(self.data, self.shape.extension_lookup_type_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "extension_offset",
+                    FieldType::offset(self.extension_offset(), self.extension()),
+                )
+                .with_bytes(self.data, self.shape.extension_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1283,6 +1375,7 @@ impl<'a, T: FontRead<'a> + SomeTable<'a> + 'a> std::fmt::Debug for ExtensionSubs
 }
 
 /// A [GSUB Extension Substitution](https://learn.microsoft.com/en-us/typography/opentype/spec/gsub#ES) subtable
+#[derive(Clone)]
 pub enum ExtensionSubtable<'a> {
     Single(ExtensionSubstFormat1<'a, SingleSubst<'a>>),
     Multiple(ExtensionSubstFormat1<'a, MultipleSubstFormat1<'a>>),
@@ -1489,52 +1582,73 @@ impl<'a> SomeTable<'a> for ReverseChainSingleSubstFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("subst_format", self.subst_format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new(
-                "backtrack_glyph_count",
-                self.backtrack_glyph_count(),
-            )),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("subst_format", self.subst_format())
+                    .with_bytes(self.data, self.shape.subst_format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "backtrack_coverage_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CoverageTable>(),
-                        self.backtrack_coverage_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CoverageTable>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
-            4usize => Some(Field::new(
-                "lookahead_glyph_count",
-                self.lookahead_glyph_count(),
-            )),
-            5usize => Some({
-                let data = self.data;
-                Field::new(
-                    "lookahead_coverage_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CoverageTable>(),
-                        self.lookahead_coverage_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CoverageTable>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
-            6usize => Some(Field::new("glyph_count", self.glyph_count())),
-            7usize => Some(Field::new(
-                "substitute_glyph_ids",
-                self.substitute_glyph_ids(),
-            )),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("backtrack_glyph_count", self.backtrack_glyph_count())
+                    .with_bytes(self.data, self.shape.backtrack_glyph_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "backtrack_coverage_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CoverageTable>(),
+                            self.backtrack_coverage_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CoverageTable>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.backtrack_coverage_offsets_byte_range(),
+                ),
+            ),
+            4usize => Some(
+                Field::new("lookahead_glyph_count", self.lookahead_glyph_count())
+                    .with_bytes(self.data, self.shape.lookahead_glyph_count_byte_range()),
+            ),
+            5usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "lookahead_coverage_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CoverageTable>(),
+                            self.lookahead_coverage_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CoverageTable>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.lookahead_coverage_offsets_byte_range(),
+                ),
+            ),
+            6usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("substitute_glyph_ids", self.substitute_glyph_ids())
+                    .with_bytes(self.data, self.shape.substitute_glyph_ids_byte_range()),
+            ),
             _ => None,
         }
     }
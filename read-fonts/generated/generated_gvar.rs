@@ -62,8 +62,9 @@ impl<'a> FontRead<'a> for Gvar<'a> {
         let glyph_count: u16 = cursor.read()?;
         let flags: GvarFlags = cursor.read()?;
         cursor.advance::<u32>();
-        let glyph_variation_data_offsets_byte_len =
-            transforms::add(glyph_count, 1_usize) * <U16Or32 as ComputeSize>::compute_size(&flags);
+        let glyph_variation_data_offsets_byte_len = (transforms::add(glyph_count, 1_usize))
+            .checked_mul(<U16Or32 as ComputeSize>::compute_size(&flags)?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(glyph_variation_data_offsets_byte_len);
         cursor.finish(GvarMarker {
             glyph_variation_data_offsets_byte_len,
@@ -147,23 +148,53 @@ impl<'a> SomeTable<'a> for Gvar<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("axis_count", self.axis_count())),
-            2usize => Some(Field::new("shared_tuple_count", self.shared_tuple_count())),
-            3usize => Some(Field::new(
-                "shared_tuples_offset",
-                FieldType::offset(self.shared_tuples_offset(), self.shared_tuples()),
-            )),
-            4usize => Some(Field::new("glyph_count", self.glyph_count())),
-            5usize => Some(Field::new("flags", self.flags())),
-            6usize => Some(Field::new(
-                "glyph_variation_data_array_offset",
-                self.glyph_variation_data_array_offset(),
-            )),
-            7usize => Some(Field::new(
-                "glyph_variation_data_offsets",
-                traversal::FieldType::Unknown,
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("axis_count", self.axis_count())
+                    .with_bytes(self.data, self.shape.axis_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("shared_tuple_count", self.shared_tuple_count())
+                    .with_bytes(self.data, self.shape.shared_tuple_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "shared_tuples_offset",
+                    FieldType::offset(self.shared_tuples_offset(), self.shared_tuples()),
+                )
+                .with_bytes(self.data, self.shape.shared_tuples_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            6usize => Some(
+                Field::new(
+                    "glyph_variation_data_array_offset",
+                    self.glyph_variation_data_array_offset(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.glyph_variation_data_array_offset_byte_range(),
+                ),
+            ),
+            7usize => Some(
+                Field::new(
+                    "glyph_variation_data_offsets",
+                    traversal::FieldType::Unknown,
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.glyph_variation_data_offsets_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
@@ -498,8 +529,9 @@ impl<'a> FontReadWithArgs<'a> for SharedTuples<'a> {
     fn read_with_args(data: FontData<'a>, args: &(u16, u16)) -> Result<Self, ReadError> {
         let (shared_tuple_count, axis_count) = *args;
         let mut cursor = data.cursor();
-        let tuples_byte_len =
-            shared_tuple_count as usize * <Tuple as ComputeSize>::compute_size(&axis_count);
+        let tuples_byte_len = (shared_tuple_count as usize)
+            .checked_mul(<Tuple as ComputeSize>::compute_size(&axis_count)?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(tuples_byte_len);
         cursor.finish(SharedTuplesMarker {
             axis_count,
@@ -544,10 +576,17 @@ impl<'a> SomeTable<'a> for SharedTuples<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "tuples",
-                traversal::FieldType::computed_array("Tuple", self.tuples(), self.offset_data()),
-            )),
+            0usize => Some(
+                Field::new(
+                    "tuples",
+                    traversal::FieldType::computed_array(
+                        "Tuple",
+                        self.tuples(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.tuples_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -635,18 +674,18 @@ impl<'a> SomeTable<'a> for GlyphVariationDataHeader<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "tuple_variation_count",
-                traversal::FieldType::Unknown,
-            )),
-            1usize => Some(Field::new(
-                "serialized_data_offset",
-                traversal::FieldType::Unknown,
-            )),
-            2usize => Some(Field::new(
-                "tuple_variation_headers",
-                traversal::FieldType::Unknown,
-            )),
+            0usize => Some(
+                Field::new("tuple_variation_count", traversal::FieldType::Unknown)
+                    .with_bytes(self.data, self.shape.tuple_variation_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("serialized_data_offset", traversal::FieldType::Unknown)
+                    .with_bytes(self.data, self.shape.serialized_data_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("tuple_variation_headers", traversal::FieldType::Unknown)
+                    .with_bytes(self.data, self.shape.tuple_variation_headers_byte_range()),
+            ),
             _ => None,
         }
     }
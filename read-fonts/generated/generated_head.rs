@@ -567,32 +567,74 @@ impl<'a> SomeTable<'a> for Head<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("font_revision", self.font_revision())),
-            2usize => Some(Field::new(
-                "checksum_adjustment",
-                self.checksum_adjustment(),
-            )),
-            3usize => Some(Field::new("magic_number", self.magic_number())),
-            4usize => Some(Field::new("flags", self.flags())),
-            5usize => Some(Field::new("units_per_em", self.units_per_em())),
-            6usize => Some(Field::new("created", self.created())),
-            7usize => Some(Field::new("modified", self.modified())),
-            8usize => Some(Field::new("x_min", self.x_min())),
-            9usize => Some(Field::new("y_min", self.y_min())),
-            10usize => Some(Field::new("x_max", self.x_max())),
-            11usize => Some(Field::new("y_max", self.y_max())),
-            12usize => Some(Field::new("mac_style", self.mac_style())),
-            13usize => Some(Field::new("lowest_rec_ppem", self.lowest_rec_ppem())),
-            14usize => Some(Field::new(
-                "font_direction_hint",
-                self.font_direction_hint(),
-            )),
-            15usize => Some(Field::new(
-                "index_to_loc_format",
-                self.index_to_loc_format(),
-            )),
-            16usize => Some(Field::new("glyph_data_format", self.glyph_data_format())),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("font_revision", self.font_revision())
+                    .with_bytes(self.data, self.shape.font_revision_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("checksum_adjustment", self.checksum_adjustment())
+                    .with_bytes(self.data, self.shape.checksum_adjustment_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("magic_number", self.magic_number())
+                    .with_bytes(self.data, self.shape.magic_number_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("units_per_em", self.units_per_em())
+                    .with_bytes(self.data, self.shape.units_per_em_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("created", self.created())
+                    .with_bytes(self.data, self.shape.created_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("modified", self.modified())
+                    .with_bytes(self.data, self.shape.modified_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("x_min", self.x_min())
+                    .with_bytes(self.data, self.shape.x_min_byte_range()),
+            ),
+            9usize => Some(
+                Field::new("y_min", self.y_min())
+                    .with_bytes(self.data, self.shape.y_min_byte_range()),
+            ),
+            10usize => Some(
+                Field::new("x_max", self.x_max())
+                    .with_bytes(self.data, self.shape.x_max_byte_range()),
+            ),
+            11usize => Some(
+                Field::new("y_max", self.y_max())
+                    .with_bytes(self.data, self.shape.y_max_byte_range()),
+            ),
+            12usize => Some(
+                Field::new("mac_style", self.mac_style())
+                    .with_bytes(self.data, self.shape.mac_style_byte_range()),
+            ),
+            13usize => Some(
+                Field::new("lowest_rec_ppem", self.lowest_rec_ppem())
+                    .with_bytes(self.data, self.shape.lowest_rec_ppem_byte_range()),
+            ),
+            14usize => Some(
+                Field::new("font_direction_hint", self.font_direction_hint())
+                    .with_bytes(self.data, self.shape.font_direction_hint_byte_range()),
+            ),
+            15usize => Some(
+                Field::new("index_to_loc_format", self.index_to_loc_format())
+                    .with_bytes(self.data, self.shape.index_to_loc_format_byte_range()),
+            ),
+            16usize => Some(
+                Field::new("glyph_data_format", self.glyph_data_format())
+                    .with_bytes(self.data, self.shape.glyph_data_format_byte_range()),
+            ),
             _ => None,
         }
     }
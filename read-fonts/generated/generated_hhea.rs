@@ -206,28 +206,58 @@ impl<'a> SomeTable<'a> for Hhea<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("ascender", self.ascender())),
-            2usize => Some(Field::new("descender", self.descender())),
-            3usize => Some(Field::new("line_gap", self.line_gap())),
-            4usize => Some(Field::new("advance_width_max", self.advance_width_max())),
-            5usize => Some(Field::new(
-                "min_left_side_bearing",
-                self.min_left_side_bearing(),
-            )),
-            6usize => Some(Field::new(
-                "min_right_side_bearing",
-                self.min_right_side_bearing(),
-            )),
-            7usize => Some(Field::new("x_max_extent", self.x_max_extent())),
-            8usize => Some(Field::new("caret_slope_rise", self.caret_slope_rise())),
-            9usize => Some(Field::new("caret_slope_run", self.caret_slope_run())),
-            10usize => Some(Field::new("caret_offset", self.caret_offset())),
-            11usize => Some(Field::new("metric_data_format", self.metric_data_format())),
-            12usize => Some(Field::new(
-                "number_of_long_metrics",
-                self.number_of_long_metrics(),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("ascender", self.ascender())
+                    .with_bytes(self.data, self.shape.ascender_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("descender", self.descender())
+                    .with_bytes(self.data, self.shape.descender_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("line_gap", self.line_gap())
+                    .with_bytes(self.data, self.shape.line_gap_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("advance_width_max", self.advance_width_max())
+                    .with_bytes(self.data, self.shape.advance_width_max_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("min_left_side_bearing", self.min_left_side_bearing())
+                    .with_bytes(self.data, self.shape.min_left_side_bearing_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("min_right_side_bearing", self.min_right_side_bearing())
+                    .with_bytes(self.data, self.shape.min_right_side_bearing_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("x_max_extent", self.x_max_extent())
+                    .with_bytes(self.data, self.shape.x_max_extent_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("caret_slope_rise", self.caret_slope_rise())
+                    .with_bytes(self.data, self.shape.caret_slope_rise_byte_range()),
+            ),
+            9usize => Some(
+                Field::new("caret_slope_run", self.caret_slope_run())
+                    .with_bytes(self.data, self.shape.caret_slope_run_byte_range()),
+            ),
+            10usize => Some(
+                Field::new("caret_offset", self.caret_offset())
+                    .with_bytes(self.data, self.shape.caret_offset_byte_range()),
+            ),
+            11usize => Some(
+                Field::new("metric_data_format", self.metric_data_format())
+                    .with_bytes(self.data, self.shape.metric_data_format_byte_range()),
+            ),
+            12usize => Some(
+                Field::new("number_of_long_metrics", self.number_of_long_metrics())
+                    .with_bytes(self.data, self.shape.number_of_long_metrics_byte_range()),
+            ),
             _ => None,
         }
     }
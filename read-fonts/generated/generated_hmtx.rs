@@ -90,15 +90,21 @@ impl<'a> SomeTable<'a> for Hmtx<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "h_metrics",
-                traversal::FieldType::array_of_records(
-                    stringify!(LongMetric),
-                    self.h_metrics(),
-                    self.offset_data(),
-                ),
-            )),
-            1usize => Some(Field::new("left_side_bearings", self.left_side_bearings())),
+            0usize => Some(
+                Field::new(
+                    "h_metrics",
+                    traversal::FieldType::array_of_records(
+                        stringify!(LongMetric),
+                        self.h_metrics(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.h_metrics_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("left_side_bearings", self.left_side_bearings())
+                    .with_bytes(self.data, self.shape.left_side_bearings_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -150,11 +156,17 @@ impl<'a> SomeRecord<'a> for LongMetric {
         RecordResolver {
             name: "LongMetric",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("advance", self.advance())),
-                1usize => Some(Field::new("side_bearing", self.side_bearing())),
+                0usize => Some(
+                    Field::new("advance", self.advance()).with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("side_bearing", self.side_bearing())
+                        .with_record_range(u16::RAW_BYTE_LEN, i16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
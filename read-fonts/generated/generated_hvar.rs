@@ -117,29 +117,50 @@ impl<'a> SomeTable<'a> for Hvar<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "item_variation_store_offset",
-                FieldType::offset(
-                    self.item_variation_store_offset(),
-                    self.item_variation_store(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "item_variation_store_offset",
+                    FieldType::offset(
+                        self.item_variation_store_offset(),
+                        self.item_variation_store(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.item_variation_store_offset_byte_range(),
                 ),
-            )),
-            2usize => Some(Field::new(
-                "advance_width_mapping_offset",
-                FieldType::offset(
-                    self.advance_width_mapping_offset(),
-                    self.advance_width_mapping(),
+            ),
+            2usize => Some(
+                Field::new(
+                    "advance_width_mapping_offset",
+                    FieldType::offset(
+                        self.advance_width_mapping_offset(),
+                        self.advance_width_mapping(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.advance_width_mapping_offset_byte_range(),
                 ),
-            )),
-            3usize => Some(Field::new(
-                "lsb_mapping_offset",
-                FieldType::offset(self.lsb_mapping_offset(), self.lsb_mapping()),
-            )),
-            4usize => Some(Field::new(
-                "rsb_mapping_offset",
-                FieldType::offset(self.rsb_mapping_offset(), self.rsb_mapping()),
-            )),
+            ),
+            3usize => Some(
+                Field::new(
+                    "lsb_mapping_offset",
+                    FieldType::offset(self.lsb_mapping_offset(), self.lsb_mapping()),
+                )
+                .with_bytes(self.data, self.shape.lsb_mapping_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "rsb_mapping_offset",
+                    FieldType::offset(self.rsb_mapping_offset(), self.rsb_mapping()),
+                )
+                .with_bytes(self.data, self.shape.rsb_mapping_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -0,0 +1,1273 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [kern (Kerning)](https://learn.microsoft.com/en-us/typography/opentype/spec/kern) table.
+///
+/// This is either the OpenType version of the table or the version defined
+/// by [Apple](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html).
+#[derive(Clone)]
+pub enum Kern<'a> {
+    Ot(OtKern<'a>),
+    Aat(AatKern<'a>),
+}
+
+impl<'a> FontRead<'a> for Kern<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let format: u16 = data.read_at(0usize)?;
+        match format {
+            format if format == 0 => Ok(Self::Ot(FontRead::read(data)?)),
+            format if format == 1 => Ok(Self::Aat(FontRead::read(data)?)),
+            other => Err(ReadError::InvalidFormat(other.into())),
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> Kern<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Ot(table) => table,
+            Self::Aat(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Kern<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.dyn_inner().fmt(f)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Kern<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+/// The OpenType [kern](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#kerning-table-header) table header.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct OtKernMarker {
+    subtable_data_byte_len: usize,
+}
+
+impl OtKernMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn n_tables_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn subtable_data_byte_range(&self) -> Range<usize> {
+        let start = self.n_tables_byte_range().end;
+        start..start + self.subtable_data_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for OtKern<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        let subtable_data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(subtable_data_byte_len);
+        cursor.finish(OtKernMarker {
+            subtable_data_byte_len,
+        })
+    }
+}
+
+/// The OpenType [kern](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#kerning-table-header) table header.
+pub type OtKern<'a> = TableRef<'a, OtKernMarker>;
+
+impl<'a> OtKern<'a> {
+    /// Table version number—set to 0.
+    pub fn version(&self) -> u16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of subtables in the kerning table.
+    pub fn n_tables(&self) -> u16 {
+        let range = self.shape.n_tables_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Data for subtables, immediately following the header.
+    pub fn subtable_data(&self) -> &'a [u8] {
+        let range = self.shape.subtable_data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for OtKern<'a> {
+    fn type_name(&self) -> &str {
+        "OtKern"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_tables", self.n_tables())
+                    .with_bytes(self.data, self.shape.n_tables_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("subtable_data", self.traverse_subtables())
+                    .with_bytes(self.data, self.shape.subtable_data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for OtKern<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The Apple [kern](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) table header.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct AatKernMarker {
+    subtable_data_byte_len: usize,
+}
+
+impl AatKernMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + MajorMinor::RAW_BYTE_LEN
+    }
+    fn n_tables_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn subtable_data_byte_range(&self) -> Range<usize> {
+        let start = self.n_tables_byte_range().end;
+        start..start + self.subtable_data_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for AatKern<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<MajorMinor>();
+        cursor.advance::<u32>();
+        let subtable_data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(subtable_data_byte_len);
+        cursor.finish(AatKernMarker {
+            subtable_data_byte_len,
+        })
+    }
+}
+
+/// The Apple [kern](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) table header.
+pub type AatKern<'a> = TableRef<'a, AatKernMarker>;
+
+impl<'a> AatKern<'a> {
+    /// The version number of the kerning table (0x00010000 for the current version).
+    pub fn version(&self) -> MajorMinor {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of subtables included in the kerning table.
+    pub fn n_tables(&self) -> u32 {
+        let range = self.shape.n_tables_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Data for subtables, immediately following the header.
+    pub fn subtable_data(&self) -> &'a [u8] {
+        let range = self.shape.subtable_data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for AatKern<'a> {
+    fn type_name(&self) -> &str {
+        "AatKern"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_tables", self.n_tables())
+                    .with_bytes(self.data, self.shape.n_tables_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("subtable_data", self.traverse_subtables())
+                    .with_bytes(self.data, self.shape.subtable_data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for AatKern<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A [subtable](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#kerning-subtables) in an OpenType `kern` table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct OtSubtableMarker {
+    data_byte_len: usize,
+}
+
+impl OtSubtableMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn length_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn coverage_byte_range(&self) -> Range<usize> {
+        let start = self.length_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn data_byte_range(&self) -> Range<usize> {
+        let start = self.coverage_byte_range().end;
+        start..start + self.data_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for OtSubtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        let data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(data_byte_len);
+        cursor.finish(OtSubtableMarker { data_byte_len })
+    }
+}
+
+/// A [subtable](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#kerning-subtables) in an OpenType `kern` table.
+pub type OtSubtable<'a> = TableRef<'a, OtSubtableMarker>;
+
+impl<'a> OtSubtable<'a> {
+    /// Kern subtable version number—set to 0.
+    pub fn version(&self) -> u16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The length of this subtable in bytes, including this header.
+    pub fn length(&self) -> u16 {
+        let range = self.shape.length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// What type of information is contained in this table. The format
+    /// of the subtable is in the high byte.
+    pub fn coverage(&self) -> u16 {
+        let range = self.shape.coverage_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Subtable specific data.
+    pub fn data(&self) -> &'a [u8] {
+        let range = self.shape.data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for OtSubtable<'a> {
+    fn type_name(&self) -> &str {
+        "OtSubtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("coverage", self.coverage())
+                    .with_bytes(self.data, self.shape.coverage_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("data", self.traverse_kind())
+                    .with_bytes(self.data, self.shape.data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for OtSubtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A [subtable](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) in an Apple `kern` table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct AatSubtableMarker {
+    data_byte_len: usize,
+}
+
+impl AatSubtableMarker {
+    fn length_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn coverage_byte_range(&self) -> Range<usize> {
+        let start = self.length_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn tuple_index_byte_range(&self) -> Range<usize> {
+        let start = self.coverage_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn data_byte_range(&self) -> Range<usize> {
+        let start = self.tuple_index_byte_range().end;
+        start..start + self.data_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for AatSubtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        let data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(data_byte_len);
+        cursor.finish(AatSubtableMarker { data_byte_len })
+    }
+}
+
+/// A [subtable](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) in an Apple `kern` table.
+pub type AatSubtable<'a> = TableRef<'a, AatSubtableMarker>;
+
+impl<'a> AatSubtable<'a> {
+    /// The length of this subtable in bytes, including this header.
+    pub fn length(&self) -> u32 {
+        let range = self.shape.length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Circumstances under which this table is used. The format of the
+    /// subtable is in the low byte.
+    pub fn coverage(&self) -> u16 {
+        let range = self.shape.coverage_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The tuple index (used for variations fonts). This value specifies
+    /// which tuple this subtable covers.
+    pub fn tuple_index(&self) -> u16 {
+        let range = self.shape.tuple_index_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Subtable specific data.
+    pub fn data(&self) -> &'a [u8] {
+        let range = self.shape.data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for AatSubtable<'a> {
+    fn type_name(&self) -> &str {
+        "AatSubtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coverage", self.coverage())
+                    .with_bytes(self.data, self.shape.coverage_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("tuple_index", self.tuple_index())
+                    .with_bytes(self.data, self.shape.tuple_index_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("data", self.traverse_kind())
+                    .with_bytes(self.data, self.shape.data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for AatSubtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [format 0](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#format-0) kern subtable: an ordered list of kerning pairs.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable0Marker {
+    pairs_byte_len: usize,
+}
+
+impl Subtable0Marker {
+    fn n_pairs_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn search_range_byte_range(&self) -> Range<usize> {
+        let start = self.n_pairs_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn entry_selector_byte_range(&self) -> Range<usize> {
+        let start = self.search_range_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn range_shift_byte_range(&self) -> Range<usize> {
+        let start = self.entry_selector_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn pairs_byte_range(&self) -> Range<usize> {
+        let start = self.range_shift_byte_range().end;
+        start..start + self.pairs_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable0<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let n_pairs: u16 = cursor.read()?;
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        let pairs_byte_len = n_pairs as usize * Subtable0Pair::RAW_BYTE_LEN;
+        cursor.advance_by(pairs_byte_len);
+        cursor.finish(Subtable0Marker { pairs_byte_len })
+    }
+}
+
+/// The [format 0](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#format-0) kern subtable: an ordered list of kerning pairs.
+pub type Subtable0<'a> = TableRef<'a, Subtable0Marker>;
+
+impl<'a> Subtable0<'a> {
+    /// The number of kerning pairs in this subtable.
+    pub fn n_pairs(&self) -> u16 {
+        let range = self.shape.n_pairs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The largest power of two less than or equal to the value of nPairs,
+    /// multiplied by the size in bytes of an entry in the subtable.
+    pub fn search_range(&self) -> u16 {
+        let range = self.shape.search_range_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// This is calculated as log2 of the largest power of two less than or
+    /// equal to the value of nPairs.
+    pub fn entry_selector(&self) -> u16 {
+        let range = self.shape.entry_selector_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The value of nPairs minus the largest power of two less than or
+    /// equal to nPairs.
+    pub fn range_shift(&self) -> u16 {
+        let range = self.shape.range_shift_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Kerning records, sorted by the left and right glyph identifiers.
+    pub fn pairs(&self) -> &'a [Subtable0Pair] {
+        let range = self.shape.pairs_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable0<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable0"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_pairs", self.n_pairs())
+                    .with_bytes(self.data, self.shape.n_pairs_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("search_range", self.search_range())
+                    .with_bytes(self.data, self.shape.search_range_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("entry_selector", self.entry_selector())
+                    .with_bytes(self.data, self.shape.entry_selector_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("range_shift", self.range_shift())
+                    .with_bytes(self.data, self.shape.range_shift_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "pairs",
+                    traversal::FieldType::array_of_records(
+                        stringify!(Subtable0Pair),
+                        self.pairs(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.pairs_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable0<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A kerning pair in a format 0 subtable.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct Subtable0Pair {
+    /// The glyph index for the lefthand glyph in the kerning pair.
+    pub left: BigEndian<GlyphId>,
+    /// The glyph index for the righthand glyph in the kerning pair.
+    pub right: BigEndian<GlyphId>,
+    /// The kerning value for the above pair, in design units.
+    pub value: BigEndian<FWord>,
+}
+
+impl Subtable0Pair {
+    /// The glyph index for the lefthand glyph in the kerning pair.
+    pub fn left(&self) -> GlyphId {
+        self.left.get()
+    }
+
+    /// The glyph index for the righthand glyph in the kerning pair.
+    pub fn right(&self) -> GlyphId {
+        self.right.get()
+    }
+
+    /// The kerning value for the above pair, in design units.
+    pub fn value(&self) -> FWord {
+        self.value.get()
+    }
+}
+
+impl FixedSize for Subtable0Pair {
+    const RAW_BYTE_LEN: usize = GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN + FWord::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for Subtable0Pair {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for Subtable0Pair {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for Subtable0Pair {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "Subtable0Pair",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("left", self.left()).with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("right", self.right())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, GlyphId::RAW_BYTE_LEN),
+                ),
+                2usize => Some(Field::new("value", self.value()).with_record_range(
+                    GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN,
+                    FWord::RAW_BYTE_LEN,
+                )),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// The [format 1](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) kern subtable: a state table for contextual kerning.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable1Marker {}
+
+impl Subtable1Marker {
+    fn n_classes_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.n_classes_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn state_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.class_table_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn entry_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.state_array_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn value_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.entry_table_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable1<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.finish(Subtable1Marker {})
+    }
+}
+
+/// The [format 1](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) kern subtable: a state table for contextual kerning.
+pub type Subtable1<'a> = TableRef<'a, Subtable1Marker>;
+
+impl<'a> Subtable1<'a> {
+    /// Number of classes, which is the number of 1-byte entries in a
+    /// state array row.
+    pub fn n_classes(&self) -> u16 {
+        let range = self.shape.n_classes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the beginning of the state table to the class subtable.
+    pub fn class_table_offset(&self) -> Offset16 {
+        let range = self.shape.class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`class_table_offset`][Self::class_table_offset].
+    pub fn class_table(&self) -> Result<ClassSubtable<'a>, ReadError> {
+        let data = self.data;
+        self.class_table_offset().resolve(data)
+    }
+
+    /// Offset from the beginning of the state table to the state array.
+    pub fn state_array_offset(&self) -> u16 {
+        let range = self.shape.state_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the beginning of the state table to the entry subtable.
+    pub fn entry_table_offset(&self) -> u16 {
+        let range = self.shape.entry_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the beginning of the state table to the kerning value
+    /// table.
+    pub fn value_table_offset(&self) -> u16 {
+        let range = self.shape.value_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable1<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable1"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_classes", self.n_classes())
+                    .with_bytes(self.data, self.shape.n_classes_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "class_table_offset",
+                    FieldType::offset(self.class_table_offset(), self.class_table()),
+                )
+                .with_bytes(self.data, self.shape.class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("state_array_offset", self.state_array_offset())
+                    .with_bytes(self.data, self.shape.state_array_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("entry_table_offset", self.entry_table_offset())
+                    .with_bytes(self.data, self.shape.entry_table_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("value_table_offset", self.value_table_offset())
+                    .with_bytes(self.data, self.shape.value_table_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable1<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Maps glyphs to classes in a format 1 subtable.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct ClassSubtableMarker {
+    class_array_byte_len: usize,
+}
+
+impl ClassSubtableMarker {
+    fn first_glyph_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + GlyphId::RAW_BYTE_LEN
+    }
+    fn n_glyphs_byte_range(&self) -> Range<usize> {
+        let start = self.first_glyph_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn class_array_byte_range(&self) -> Range<usize> {
+        let start = self.n_glyphs_byte_range().end;
+        start..start + self.class_array_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for ClassSubtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<GlyphId>();
+        let n_glyphs: u16 = cursor.read()?;
+        let class_array_byte_len = n_glyphs as usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(class_array_byte_len);
+        cursor.finish(ClassSubtableMarker {
+            class_array_byte_len,
+        })
+    }
+}
+
+/// Maps glyphs to classes in a format 1 subtable.
+pub type ClassSubtable<'a> = TableRef<'a, ClassSubtableMarker>;
+
+impl<'a> ClassSubtable<'a> {
+    /// Glyph index of the first glyph in the class array.
+    pub fn first_glyph(&self) -> GlyphId {
+        let range = self.shape.first_glyph_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of glyphs in the class array.
+    pub fn n_glyphs(&self) -> u16 {
+        let range = self.shape.n_glyphs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The class of each glyph, beginning with the first glyph.
+    pub fn class_array(&self) -> &'a [u8] {
+        let range = self.shape.class_array_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for ClassSubtable<'a> {
+    fn type_name(&self) -> &str {
+        "ClassSubtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("first_glyph", self.first_glyph())
+                    .with_bytes(self.data, self.shape.first_glyph_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_glyphs", self.n_glyphs())
+                    .with_bytes(self.data, self.shape.n_glyphs_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("class_array", self.class_array())
+                    .with_bytes(self.data, self.shape.class_array_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for ClassSubtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [format 2](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#format-2) kern subtable: a two-dimensional array of kerning values.
+///
+/// Offsets in this subtable are relative to the start of the enclosing
+/// subtable header, so this is read from the data of the full subtable
+/// with the length of the header as an argument.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable2Marker {
+    header_byte_len: usize,
+}
+
+impl Subtable2Marker {
+    fn header_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.header_byte_len
+    }
+    fn row_width_byte_range(&self) -> Range<usize> {
+        let start = self.header_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn left_class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.row_width_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn right_class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.left_class_table_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.right_class_table_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+}
+
+impl ReadArgs for Subtable2<'_> {
+    type Args = u16;
+}
+
+impl<'a> FontReadWithArgs<'a> for Subtable2<'a> {
+    fn read_with_args(data: FontData<'a>, args: &u16) -> Result<Self, ReadError> {
+        let header_len = *args;
+        let mut cursor = data.cursor();
+        let header_byte_len = header_len as usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(header_byte_len);
+        cursor.advance::<u16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<u16>();
+        cursor.finish(Subtable2Marker { header_byte_len })
+    }
+}
+
+impl<'a> Subtable2<'a> {
+    /// A constructor that requires additional arguments.
+    ///
+    /// This type requires some external state in order to be
+    /// parsed.
+    pub fn read(data: FontData<'a>, header_len: u16) -> Result<Self, ReadError> {
+        let args = header_len;
+        Self::read_with_args(data, &args)
+    }
+}
+
+/// The [format 2](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#format-2) kern subtable: a two-dimensional array of kerning values.
+///
+/// Offsets in this subtable are relative to the start of the enclosing
+/// subtable header, so this is read from the data of the full subtable
+/// with the length of the header as an argument.
+pub type Subtable2<'a> = TableRef<'a, Subtable2Marker>;
+
+impl<'a> Subtable2<'a> {
+    /// The width, in bytes, of a row in the table.
+    pub fn row_width(&self) -> u16 {
+        let range = self.shape.row_width_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from beginning of this subtable to the left-hand class table.
+    pub fn left_class_table_offset(&self) -> Offset16 {
+        let range = self.shape.left_class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`left_class_table_offset`][Self::left_class_table_offset].
+    pub fn left_class_table(&self) -> Result<Subtable2ClassTable<'a>, ReadError> {
+        let data = self.data;
+        self.left_class_table_offset().resolve(data)
+    }
+
+    /// Offset from beginning of this subtable to the right-hand class table.
+    pub fn right_class_table_offset(&self) -> Offset16 {
+        let range = self.shape.right_class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`right_class_table_offset`][Self::right_class_table_offset].
+    pub fn right_class_table(&self) -> Result<Subtable2ClassTable<'a>, ReadError> {
+        let data = self.data;
+        self.right_class_table_offset().resolve(data)
+    }
+
+    /// Offset from beginning of this subtable to the start of the kerning
+    /// array.
+    pub fn array_offset(&self) -> u16 {
+        let range = self.shape.array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable2<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable2"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("row_width", self.row_width())
+                    .with_bytes(self.data, self.shape.row_width_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "left_class_table_offset",
+                    FieldType::offset(self.left_class_table_offset(), self.left_class_table()),
+                )
+                .with_bytes(self.data, self.shape.left_class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "right_class_table_offset",
+                    FieldType::offset(self.right_class_table_offset(), self.right_class_table()),
+                )
+                .with_bytes(self.data, self.shape.right_class_table_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("array_offset", self.array_offset())
+                    .with_bytes(self.data, self.shape.array_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable2<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Class table for a format 2 kern subtable.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable2ClassTableMarker {
+    offsets_byte_len: usize,
+}
+
+impl Subtable2ClassTableMarker {
+    fn first_glyph_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + GlyphId::RAW_BYTE_LEN
+    }
+    fn n_glyphs_byte_range(&self) -> Range<usize> {
+        let start = self.first_glyph_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn offsets_byte_range(&self) -> Range<usize> {
+        let start = self.n_glyphs_byte_range().end;
+        start..start + self.offsets_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable2ClassTable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<GlyphId>();
+        let n_glyphs: u16 = cursor.read()?;
+        let offsets_byte_len = n_glyphs as usize * u16::RAW_BYTE_LEN;
+        cursor.advance_by(offsets_byte_len);
+        cursor.finish(Subtable2ClassTableMarker { offsets_byte_len })
+    }
+}
+
+/// Class table for a format 2 kern subtable.
+pub type Subtable2ClassTable<'a> = TableRef<'a, Subtable2ClassTableMarker>;
+
+impl<'a> Subtable2ClassTable<'a> {
+    /// First glyph in class range.
+    pub fn first_glyph(&self) -> GlyphId {
+        let range = self.shape.first_glyph_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of glyph in class range.
+    pub fn n_glyphs(&self) -> u16 {
+        let range = self.shape.n_glyphs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The class values, which are byte offsets from the beginning of the
+    /// subtable to a row or column of the kerning array.
+    pub fn offsets(&self) -> &'a [BigEndian<u16>] {
+        let range = self.shape.offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable2ClassTable<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable2ClassTable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("first_glyph", self.first_glyph())
+                    .with_bytes(self.data, self.shape.first_glyph_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_glyphs", self.n_glyphs())
+                    .with_bytes(self.data, self.shape.n_glyphs_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("offsets", self.offsets())
+                    .with_bytes(self.data, self.shape.offsets_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable2ClassTable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [format 3](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) kern subtable: a compact two-dimensional array of kerning values indexed by glyph class.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable3Marker {
+    kern_value_byte_len: usize,
+    left_class_byte_len: usize,
+    right_class_byte_len: usize,
+    kern_index_byte_len: usize,
+}
+
+impl Subtable3Marker {
+    fn glyph_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn kern_value_count_byte_range(&self) -> Range<usize> {
+        let start = self.glyph_count_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn left_class_count_byte_range(&self) -> Range<usize> {
+        let start = self.kern_value_count_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn right_class_count_byte_range(&self) -> Range<usize> {
+        let start = self.left_class_count_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn flags_byte_range(&self) -> Range<usize> {
+        let start = self.right_class_count_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn kern_value_byte_range(&self) -> Range<usize> {
+        let start = self.flags_byte_range().end;
+        start..start + self.kern_value_byte_len
+    }
+    fn left_class_byte_range(&self) -> Range<usize> {
+        let start = self.kern_value_byte_range().end;
+        start..start + self.left_class_byte_len
+    }
+    fn right_class_byte_range(&self) -> Range<usize> {
+        let start = self.left_class_byte_range().end;
+        start..start + self.right_class_byte_len
+    }
+    fn kern_index_byte_range(&self) -> Range<usize> {
+        let start = self.right_class_byte_range().end;
+        start..start + self.kern_index_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable3<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let glyph_count: u16 = cursor.read()?;
+        let kern_value_count: u8 = cursor.read()?;
+        let left_class_count: u8 = cursor.read()?;
+        let right_class_count: u8 = cursor.read()?;
+        cursor.advance::<u8>();
+        let kern_value_byte_len = kern_value_count as usize * FWord::RAW_BYTE_LEN;
+        cursor.advance_by(kern_value_byte_len);
+        let left_class_byte_len = glyph_count as usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(left_class_byte_len);
+        let right_class_byte_len = glyph_count as usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(right_class_byte_len);
+        let kern_index_byte_len =
+            transforms::multiply(left_class_count, right_class_count) * u8::RAW_BYTE_LEN;
+        cursor.advance_by(kern_index_byte_len);
+        cursor.finish(Subtable3Marker {
+            kern_value_byte_len,
+            left_class_byte_len,
+            right_class_byte_len,
+            kern_index_byte_len,
+        })
+    }
+}
+
+/// The [format 3](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) kern subtable: a compact two-dimensional array of kerning values indexed by glyph class.
+pub type Subtable3<'a> = TableRef<'a, Subtable3Marker>;
+
+impl<'a> Subtable3<'a> {
+    /// The number of glyphs in this font.
+    pub fn glyph_count(&self) -> u16 {
+        let range = self.shape.glyph_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of kerning values.
+    pub fn kern_value_count(&self) -> u8 {
+        let range = self.shape.kern_value_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of left-hand classes.
+    pub fn left_class_count(&self) -> u8 {
+        let range = self.shape.left_class_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of right-hand classes.
+    pub fn right_class_count(&self) -> u8 {
+        let range = self.shape.right_class_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Set to zero (reserved for future use).
+    pub fn flags(&self) -> u8 {
+        let range = self.shape.flags_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The kerning values.
+    pub fn kern_value(&self) -> &'a [BigEndian<FWord>] {
+        let range = self.shape.kern_value_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// The left-hand classes.
+    pub fn left_class(&self) -> &'a [u8] {
+        let range = self.shape.left_class_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// The right-hand classes.
+    pub fn right_class(&self) -> &'a [u8] {
+        let range = self.shape.right_class_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// The indices into the kernValue array.
+    pub fn kern_index(&self) -> &'a [u8] {
+        let range = self.shape.kern_index_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable3<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable3"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("kern_value_count", self.kern_value_count())
+                    .with_bytes(self.data, self.shape.kern_value_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("left_class_count", self.left_class_count())
+                    .with_bytes(self.data, self.shape.left_class_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("right_class_count", self.right_class_count())
+                    .with_bytes(self.data, self.shape.right_class_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("kern_value", self.kern_value())
+                    .with_bytes(self.data, self.shape.kern_value_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("left_class", self.left_class())
+                    .with_bytes(self.data, self.shape.left_class_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("right_class", self.right_class())
+                    .with_bytes(self.data, self.shape.right_class_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("kern_index", self.kern_index())
+                    .with_bytes(self.data, self.shape.kern_index_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable3<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
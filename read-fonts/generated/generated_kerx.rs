@@ -0,0 +1,1104 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [kerx (Extended Kerning)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kerx.html) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct KerxMarker {
+    subtable_data_byte_len: usize,
+}
+
+impl KerxMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn padding_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn n_tables_byte_range(&self) -> Range<usize> {
+        let start = self.padding_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn subtable_data_byte_range(&self) -> Range<usize> {
+        let start = self.n_tables_byte_range().end;
+        start..start + self.subtable_data_byte_len
+    }
+}
+
+impl TopLevelTable for Kerx<'_> {
+    /// `kerx`
+    const TAG: Tag = Tag::new(b"kerx");
+}
+
+impl<'a> FontRead<'a> for Kerx<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        let subtable_data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(subtable_data_byte_len);
+        cursor.finish(KerxMarker {
+            subtable_data_byte_len,
+        })
+    }
+}
+
+/// The [kerx (Extended Kerning)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kerx.html) table.
+pub type Kerx<'a> = TableRef<'a, KerxMarker>;
+
+impl<'a> Kerx<'a> {
+    /// The version number of the extended kerning table (currently 2, 3,
+    /// or 4).
+    pub fn version(&self) -> u16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of subtables included in the extended kerning table.
+    pub fn n_tables(&self) -> u32 {
+        let range = self.shape.n_tables_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Data for subtables, immediately following the header.
+    pub fn subtable_data(&self) -> &'a [u8] {
+        let range = self.shape.subtable_data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Kerx<'a> {
+    fn type_name(&self) -> &str {
+        "Kerx"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_tables", self.n_tables())
+                    .with_bytes(self.data, self.shape.n_tables_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("subtable_data", self.traverse_subtables())
+                    .with_bytes(self.data, self.shape.subtable_data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Kerx<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A subtable in a `kerx` table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct SubtableMarker {
+    data_byte_len: usize,
+}
+
+impl SubtableMarker {
+    fn length_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn coverage_byte_range(&self) -> Range<usize> {
+        let start = self.length_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn tuple_count_byte_range(&self) -> Range<usize> {
+        let start = self.coverage_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn data_byte_range(&self) -> Range<usize> {
+        let start = self.tuple_count_byte_range().end;
+        start..start + self.data_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(data_byte_len);
+        cursor.finish(SubtableMarker { data_byte_len })
+    }
+}
+
+/// A subtable in a `kerx` table.
+pub type Subtable<'a> = TableRef<'a, SubtableMarker>;
+
+impl<'a> Subtable<'a> {
+    /// The length of this subtable in bytes, including this header.
+    pub fn length(&self) -> u32 {
+        let range = self.shape.length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Circumstances under which this table is used. The format of the
+    /// subtable is in the low byte.
+    pub fn coverage(&self) -> u32 {
+        let range = self.shape.coverage_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The tuple count. This value is only used with variation fonts and
+    /// should be 0 for all other fonts.
+    pub fn tuple_count(&self) -> u32 {
+        let range = self.shape.tuple_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Subtable specific data.
+    pub fn data(&self) -> &'a [u8] {
+        let range = self.shape.data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coverage", self.coverage())
+                    .with_bytes(self.data, self.shape.coverage_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("tuple_count", self.tuple_count())
+                    .with_bytes(self.data, self.shape.tuple_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("data", self.traverse_kind())
+                    .with_bytes(self.data, self.shape.data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The format 0 `kerx` subtable: an ordered list of kerning pairs.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable0Marker {
+    pairs_byte_len: usize,
+}
+
+impl Subtable0Marker {
+    fn n_pairs_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn search_range_byte_range(&self) -> Range<usize> {
+        let start = self.n_pairs_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn entry_selector_byte_range(&self) -> Range<usize> {
+        let start = self.search_range_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn range_shift_byte_range(&self) -> Range<usize> {
+        let start = self.entry_selector_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn pairs_byte_range(&self) -> Range<usize> {
+        let start = self.range_shift_byte_range().end;
+        start..start + self.pairs_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable0<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let n_pairs: u32 = cursor.read()?;
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let pairs_byte_len = n_pairs as usize * Subtable0Pair::RAW_BYTE_LEN;
+        cursor.advance_by(pairs_byte_len);
+        cursor.finish(Subtable0Marker { pairs_byte_len })
+    }
+}
+
+/// The format 0 `kerx` subtable: an ordered list of kerning pairs.
+pub type Subtable0<'a> = TableRef<'a, Subtable0Marker>;
+
+impl<'a> Subtable0<'a> {
+    /// The number of kerning pairs in this subtable.
+    pub fn n_pairs(&self) -> u32 {
+        let range = self.shape.n_pairs_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The largest power of two less than or equal to the value of nPairs,
+    /// multiplied by the size in bytes of an entry in the subtable.
+    pub fn search_range(&self) -> u32 {
+        let range = self.shape.search_range_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// This is calculated as log2 of the largest power of two less than or
+    /// equal to the value of nPairs.
+    pub fn entry_selector(&self) -> u32 {
+        let range = self.shape.entry_selector_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The value of nPairs minus the largest power of two less than or
+    /// equal to nPairs.
+    pub fn range_shift(&self) -> u32 {
+        let range = self.shape.range_shift_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Kerning records, sorted by the left and right glyph identifiers.
+    pub fn pairs(&self) -> &'a [Subtable0Pair] {
+        let range = self.shape.pairs_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable0<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable0"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_pairs", self.n_pairs())
+                    .with_bytes(self.data, self.shape.n_pairs_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("search_range", self.search_range())
+                    .with_bytes(self.data, self.shape.search_range_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("entry_selector", self.entry_selector())
+                    .with_bytes(self.data, self.shape.entry_selector_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("range_shift", self.range_shift())
+                    .with_bytes(self.data, self.shape.range_shift_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "pairs",
+                    traversal::FieldType::array_of_records(
+                        stringify!(Subtable0Pair),
+                        self.pairs(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.pairs_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable0<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A kerning pair in a format 0 subtable.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct Subtable0Pair {
+    /// The glyph index for the lefthand glyph in the kerning pair.
+    pub left: BigEndian<GlyphId>,
+    /// The glyph index for the righthand glyph in the kerning pair.
+    pub right: BigEndian<GlyphId>,
+    /// The kerning value for the above pair, in design units.
+    pub value: BigEndian<FWord>,
+}
+
+impl Subtable0Pair {
+    /// The glyph index for the lefthand glyph in the kerning pair.
+    pub fn left(&self) -> GlyphId {
+        self.left.get()
+    }
+
+    /// The glyph index for the righthand glyph in the kerning pair.
+    pub fn right(&self) -> GlyphId {
+        self.right.get()
+    }
+
+    /// The kerning value for the above pair, in design units.
+    pub fn value(&self) -> FWord {
+        self.value.get()
+    }
+}
+
+impl FixedSize for Subtable0Pair {
+    const RAW_BYTE_LEN: usize = GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN + FWord::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for Subtable0Pair {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for Subtable0Pair {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for Subtable0Pair {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "Subtable0Pair",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("left", self.left()).with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("right", self.right())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, GlyphId::RAW_BYTE_LEN),
+                ),
+                2usize => Some(Field::new("value", self.value()).with_record_range(
+                    GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN,
+                    FWord::RAW_BYTE_LEN,
+                )),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// The format 1 `kerx` subtable: a state table for contextual kerning.
+///
+/// The first four fields are the header of the extended state table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable1Marker {}
+
+impl Subtable1Marker {
+    fn n_classes_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.n_classes_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn state_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.class_table_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn entry_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.state_array_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn value_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.entry_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable1<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.finish(Subtable1Marker {})
+    }
+}
+
+/// The format 1 `kerx` subtable: a state table for contextual kerning.
+///
+/// The first four fields are the header of the extended state table.
+pub type Subtable1<'a> = TableRef<'a, Subtable1Marker>;
+
+impl<'a> Subtable1<'a> {
+    /// Number of classes in the state table.
+    pub fn n_classes(&self) -> u32 {
+        let range = self.shape.n_classes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of the state table to the class table.
+    pub fn class_table_offset(&self) -> Offset32 {
+        let range = self.shape.class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`class_table_offset`][Self::class_table_offset].
+    pub fn class_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.class_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of the state table to the state array.
+    pub fn state_array_offset(&self) -> Offset32 {
+        let range = self.shape.state_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`state_array_offset`][Self::state_array_offset].
+    pub fn state_array(&self) -> Result<RawWords<'a>, ReadError> {
+        let data = self.data;
+        self.state_array_offset().resolve(data)
+    }
+
+    /// Offset from the start of the state table to the entry table.
+    pub fn entry_table_offset(&self) -> Offset32 {
+        let range = self.shape.entry_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`entry_table_offset`][Self::entry_table_offset].
+    pub fn entry_table(&self) -> Result<RawBytes<'a>, ReadError> {
+        let data = self.data;
+        self.entry_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of the state table to the kerning values.
+    pub fn value_table_offset(&self) -> u32 {
+        let range = self.shape.value_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable1<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable1"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_classes", self.n_classes())
+                    .with_bytes(self.data, self.shape.n_classes_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "class_table_offset",
+                    FieldType::offset(self.class_table_offset(), self.class_table()),
+                )
+                .with_bytes(self.data, self.shape.class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "state_array_offset",
+                    FieldType::offset(self.state_array_offset(), self.state_array()),
+                )
+                .with_bytes(self.data, self.shape.state_array_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "entry_table_offset",
+                    FieldType::offset(self.entry_table_offset(), self.entry_table()),
+                )
+                .with_bytes(self.data, self.shape.entry_table_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("value_table_offset", self.value_table_offset())
+                    .with_bytes(self.data, self.shape.value_table_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable1<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Entry data for a format 1 subtable.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct Subtable1EntryData {
+    /// Index into the kerning value array for this entry (use 0xFFFF for
+    /// none).
+    pub value_index: BigEndian<u16>,
+}
+
+impl Subtable1EntryData {
+    /// Index into the kerning value array for this entry (use 0xFFFF for
+    /// none).
+    pub fn value_index(&self) -> u16 {
+        self.value_index.get()
+    }
+}
+
+impl FixedSize for Subtable1EntryData {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for Subtable1EntryData {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for Subtable1EntryData {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for Subtable1EntryData {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "Subtable1EntryData",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("value_index", self.value_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// The format 2 `kerx` subtable: a two-dimensional array of kerning values.
+///
+/// Offsets in this subtable are relative to the start of the subtable
+/// header, so this is read from the data of the full subtable.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable2Marker {
+    header_byte_len: usize,
+}
+
+impl Subtable2Marker {
+    fn header_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.header_byte_len
+    }
+    fn row_width_byte_range(&self) -> Range<usize> {
+        let start = self.header_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn left_class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.row_width_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn right_class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.left_class_table_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.right_class_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable2<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let header_byte_len = 12_usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(header_byte_len);
+        cursor.advance::<u32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.finish(Subtable2Marker { header_byte_len })
+    }
+}
+
+/// The format 2 `kerx` subtable: a two-dimensional array of kerning values.
+///
+/// Offsets in this subtable are relative to the start of the subtable
+/// header, so this is read from the data of the full subtable.
+pub type Subtable2<'a> = TableRef<'a, Subtable2Marker>;
+
+impl<'a> Subtable2<'a> {
+    /// The number of bytes in each row of the kerning value array.
+    pub fn row_width(&self) -> u32 {
+        let range = self.shape.row_width_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from beginning of this subtable to the left-hand class table.
+    pub fn left_class_table_offset(&self) -> Offset32 {
+        let range = self.shape.left_class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`left_class_table_offset`][Self::left_class_table_offset].
+    pub fn left_class_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.left_class_table_offset().resolve(data)
+    }
+
+    /// Offset from beginning of this subtable to the right-hand class table.
+    pub fn right_class_table_offset(&self) -> Offset32 {
+        let range = self.shape.right_class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`right_class_table_offset`][Self::right_class_table_offset].
+    pub fn right_class_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.right_class_table_offset().resolve(data)
+    }
+
+    /// Offset from beginning of this subtable to the start of the kerning
+    /// array.
+    pub fn array_offset(&self) -> u32 {
+        let range = self.shape.array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable2<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable2"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("row_width", self.row_width())
+                    .with_bytes(self.data, self.shape.row_width_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "left_class_table_offset",
+                    FieldType::offset(self.left_class_table_offset(), self.left_class_table()),
+                )
+                .with_bytes(self.data, self.shape.left_class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "right_class_table_offset",
+                    FieldType::offset(self.right_class_table_offset(), self.right_class_table()),
+                )
+                .with_bytes(self.data, self.shape.right_class_table_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("array_offset", self.array_offset())
+                    .with_bytes(self.data, self.shape.array_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable2<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The format 4 `kerx` subtable: a state table for attaching glyphs using
+/// control or anchor points.
+///
+/// The first four fields are the header of the extended state table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable4Marker {}
+
+impl Subtable4Marker {
+    fn n_classes_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.n_classes_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn state_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.class_table_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn entry_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.state_array_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn flags_byte_range(&self) -> Range<usize> {
+        let start = self.entry_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable4<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.finish(Subtable4Marker {})
+    }
+}
+
+/// The format 4 `kerx` subtable: a state table for attaching glyphs using
+/// control or anchor points.
+///
+/// The first four fields are the header of the extended state table.
+pub type Subtable4<'a> = TableRef<'a, Subtable4Marker>;
+
+impl<'a> Subtable4<'a> {
+    /// Number of classes in the state table.
+    pub fn n_classes(&self) -> u32 {
+        let range = self.shape.n_classes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of the state table to the class table.
+    pub fn class_table_offset(&self) -> Offset32 {
+        let range = self.shape.class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`class_table_offset`][Self::class_table_offset].
+    pub fn class_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.class_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of the state table to the state array.
+    pub fn state_array_offset(&self) -> Offset32 {
+        let range = self.shape.state_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`state_array_offset`][Self::state_array_offset].
+    pub fn state_array(&self) -> Result<RawWords<'a>, ReadError> {
+        let data = self.data;
+        self.state_array_offset().resolve(data)
+    }
+
+    /// Offset from the start of the state table to the entry table.
+    pub fn entry_table_offset(&self) -> Offset32 {
+        let range = self.shape.entry_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`entry_table_offset`][Self::entry_table_offset].
+    pub fn entry_table(&self) -> Result<RawBytes<'a>, ReadError> {
+        let data = self.data;
+        self.entry_table_offset().resolve(data)
+    }
+
+    /// The action type in the high two bits and the offset from the start
+    /// of the state table to the control point actions in the low 24 bits.
+    pub fn flags(&self) -> u32 {
+        let range = self.shape.flags_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable4<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable4"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_classes", self.n_classes())
+                    .with_bytes(self.data, self.shape.n_classes_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "class_table_offset",
+                    FieldType::offset(self.class_table_offset(), self.class_table()),
+                )
+                .with_bytes(self.data, self.shape.class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "state_array_offset",
+                    FieldType::offset(self.state_array_offset(), self.state_array()),
+                )
+                .with_bytes(self.data, self.shape.state_array_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "entry_table_offset",
+                    FieldType::offset(self.entry_table_offset(), self.entry_table()),
+                )
+                .with_bytes(self.data, self.shape.entry_table_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable4<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Entry data for a format 4 subtable.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct Subtable4EntryData {
+    /// Index into the control point action table for this entry (use
+    /// 0xFFFF for none).
+    pub ankr_action_index: BigEndian<u16>,
+}
+
+impl Subtable4EntryData {
+    /// Index into the control point action table for this entry (use
+    /// 0xFFFF for none).
+    pub fn ankr_action_index(&self) -> u16 {
+        self.ankr_action_index.get()
+    }
+}
+
+impl FixedSize for Subtable4EntryData {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for Subtable4EntryData {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for Subtable4EntryData {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for Subtable4EntryData {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "Subtable4EntryData",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("ankr_action_index", self.ankr_action_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// The format 6 `kerx` subtable: a compact two-dimensional array of kerning
+/// values indexed by glyph.
+///
+/// Offsets in this subtable are relative to the start of the subtable
+/// header, so this is read from the data of the full subtable.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Subtable6Marker {
+    header_byte_len: usize,
+}
+
+impl Subtable6Marker {
+    fn header_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.header_byte_len
+    }
+    fn flags_byte_range(&self) -> Range<usize> {
+        let start = self.header_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn row_count_byte_range(&self) -> Range<usize> {
+        let start = self.flags_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn column_count_byte_range(&self) -> Range<usize> {
+        let start = self.row_count_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn row_index_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.column_count_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn column_index_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.row_index_table_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn kerning_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.column_index_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn kerning_vector_offset_byte_range(&self) -> Range<usize> {
+        let start = self.kerning_array_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable6<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let header_byte_len = 12_usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(header_byte_len);
+        cursor.advance::<u32>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.finish(Subtable6Marker { header_byte_len })
+    }
+}
+
+/// The format 6 `kerx` subtable: a compact two-dimensional array of kerning
+/// values indexed by glyph.
+///
+/// Offsets in this subtable are relative to the start of the subtable
+/// header, so this is read from the data of the full subtable.
+pub type Subtable6<'a> = TableRef<'a, Subtable6Marker>;
+
+impl<'a> Subtable6<'a> {
+    /// Flags for this subtable. The low bit is set if the lookup and
+    /// kerning values are 32-bit.
+    pub fn flags(&self) -> u32 {
+        let range = self.shape.flags_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of rows in the kerning value array.
+    pub fn row_count(&self) -> u16 {
+        let range = self.shape.row_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of columns in the kerning value array.
+    pub fn column_count(&self) -> u16 {
+        let range = self.shape.column_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from beginning of this subtable to the row index lookup
+    /// table.
+    pub fn row_index_table_offset(&self) -> Offset32 {
+        let range = self.shape.row_index_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`row_index_table_offset`][Self::row_index_table_offset].
+    pub fn row_index_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.row_index_table_offset().resolve(data)
+    }
+
+    /// Offset from beginning of this subtable to the column index lookup
+    /// table.
+    pub fn column_index_table_offset(&self) -> Offset32 {
+        let range = self.shape.column_index_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`column_index_table_offset`][Self::column_index_table_offset].
+    pub fn column_index_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.column_index_table_offset().resolve(data)
+    }
+
+    /// Offset from beginning of this subtable to the kerning array.
+    pub fn kerning_array_offset(&self) -> u32 {
+        let range = self.shape.kerning_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from beginning of this subtable to the kerning vectors, for
+    /// variation fonts.
+    pub fn kerning_vector_offset(&self) -> u32 {
+        let range = self.shape.kerning_vector_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable6<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable6"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("row_count", self.row_count())
+                    .with_bytes(self.data, self.shape.row_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("column_count", self.column_count())
+                    .with_bytes(self.data, self.shape.column_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "row_index_table_offset",
+                    FieldType::offset(self.row_index_table_offset(), self.row_index_table()),
+                )
+                .with_bytes(self.data, self.shape.row_index_table_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "column_index_table_offset",
+                    FieldType::offset(self.column_index_table_offset(), self.column_index_table()),
+                )
+                .with_bytes(self.data, self.shape.column_index_table_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("kerning_array_offset", self.kerning_array_offset())
+                    .with_bytes(self.data, self.shape.kerning_array_offset_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("kerning_vector_offset", self.kerning_vector_offset())
+                    .with_bytes(self.data, self.shape.kerning_vector_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable6<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
@@ -59,15 +59,21 @@ impl<'a> SomeTable<'a> for ScriptList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("script_count", self.script_count())),
-            1usize => Some(Field::new(
-                "script_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(ScriptRecord),
-                    self.script_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("script_count", self.script_count())
+                    .with_bytes(self.data, self.shape.script_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "script_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(ScriptRecord),
+                        self.script_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.script_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -125,14 +131,21 @@ impl<'a> SomeRecord<'a> for ScriptRecord {
         RecordResolver {
             name: "ScriptRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("script_tag", self.script_tag())),
-                1usize => Some(Field::new(
-                    "script_offset",
-                    FieldType::offset(self.script_offset(), self.script(_data)),
-                )),
+                0usize => Some(
+                    Field::new("script_tag", self.script_tag())
+                        .with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "script_offset",
+                        FieldType::offset(self.script_offset(), self.script(_data)),
+                    )
+                    .with_record_range(Tag::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -210,19 +223,28 @@ impl<'a> SomeTable<'a> for Script<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "default_lang_sys_offset",
-                FieldType::offset(self.default_lang_sys_offset(), self.default_lang_sys()),
-            )),
-            1usize => Some(Field::new("lang_sys_count", self.lang_sys_count())),
-            2usize => Some(Field::new(
-                "lang_sys_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(LangSysRecord),
-                    self.lang_sys_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new(
+                    "default_lang_sys_offset",
+                    FieldType::offset(self.default_lang_sys_offset(), self.default_lang_sys()),
+                )
+                .with_bytes(self.data, self.shape.default_lang_sys_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("lang_sys_count", self.lang_sys_count())
+                    .with_bytes(self.data, self.shape.lang_sys_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "lang_sys_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(LangSysRecord),
+                        self.lang_sys_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.lang_sys_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -279,14 +301,21 @@ impl<'a> SomeRecord<'a> for LangSysRecord {
         RecordResolver {
             name: "LangSysRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("lang_sys_tag", self.lang_sys_tag())),
-                1usize => Some(Field::new(
-                    "lang_sys_offset",
-                    FieldType::offset(self.lang_sys_offset(), self.lang_sys(_data)),
-                )),
+                0usize => Some(
+                    Field::new("lang_sys_tag", self.lang_sys_tag())
+                        .with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "lang_sys_offset",
+                        FieldType::offset(self.lang_sys_offset(), self.lang_sys(_data)),
+                    )
+                    .with_record_range(Tag::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -363,15 +392,18 @@ impl<'a> SomeTable<'a> for LangSys<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "required_feature_index",
-                self.required_feature_index(),
-            )),
-            1usize => Some(Field::new(
-                "feature_index_count",
-                self.feature_index_count(),
-            )),
-            2usize => Some(Field::new("feature_indices", self.feature_indices())),
+            0usize => Some(
+                Field::new("required_feature_index", self.required_feature_index())
+                    .with_bytes(self.data, self.shape.required_feature_index_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("feature_index_count", self.feature_index_count())
+                    .with_bytes(self.data, self.shape.feature_index_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("feature_indices", self.feature_indices())
+                    .with_bytes(self.data, self.shape.feature_indices_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -439,15 +471,21 @@ impl<'a> SomeTable<'a> for FeatureList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("feature_count", self.feature_count())),
-            1usize => Some(Field::new(
-                "feature_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(FeatureRecord),
-                    self.feature_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("feature_count", self.feature_count())
+                    .with_bytes(self.data, self.shape.feature_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "feature_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(FeatureRecord),
+                        self.feature_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.feature_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -506,14 +544,21 @@ impl<'a> SomeRecord<'a> for FeatureRecord {
         RecordResolver {
             name: "FeatureRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("feature_tag", self.feature_tag())),
-                1usize => Some(Field::new(
-                    "feature_offset",
-                    FieldType::offset(self.feature_offset(), self.feature(_data)),
-                )),
+                0usize => Some(
+                    Field::new("feature_tag", self.feature_tag())
+                        .with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "feature_offset",
+                        FieldType::offset(self.feature_offset(), self.feature(_data)),
+                    )
+                    .with_record_range(Tag::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -613,15 +658,21 @@ impl<'a> SomeTable<'a> for Feature<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "feature_params_offset",
-                FieldType::offset(self.feature_params_offset(), self.feature_params()),
-            )),
-            1usize => Some(Field::new("lookup_index_count", self.lookup_index_count())),
-            2usize => Some(Field::new(
-                "lookup_list_indices",
-                self.lookup_list_indices(),
-            )),
+            0usize => Some(
+                Field::new(
+                    "feature_params_offset",
+                    FieldType::offset(self.feature_params_offset(), self.feature_params()),
+                )
+                .with_bytes(self.data, self.shape.feature_params_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("lookup_index_count", self.lookup_index_count())
+                    .with_bytes(self.data, self.shape.lookup_index_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("lookup_list_indices", self.lookup_list_indices())
+                    .with_bytes(self.data, self.shape.lookup_list_indices_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -726,21 +777,27 @@ impl<'a, T: FontRead<'a> + SomeTable<'a> + 'a> SomeTable<'a> for LookupList<'a,
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("lookup_count", self.lookup_count())),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "lookup_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<T>(),
-                        self.lookup_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<T>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("lookup_count", self.lookup_count())
+                    .with_bytes(self.data, self.shape.lookup_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "lookup_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<T>(),
+                            self.lookup_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<T>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.lookup_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -880,24 +937,39 @@ impl<'a, T: FontRead<'a> + SomeTable<'a> + 'a> SomeTable<'a> for Lookup<'a, T> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("lookup_type", self.lookup_type())),
-            1usize => Some(Field::new("lookup_flag", self.traverse_lookup_flag())),
-            2usize => Some(Field::new("sub_table_count", self.sub_table_count())),
-            3usize => Some({
-                let data = self.data;
-                Field::new(
-                    "subtable_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<T>(),
-                        self.subtable_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<T>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
-            4usize => Some(Field::new("mark_filtering_set", self.mark_filtering_set())),
+            0usize => Some(
+                Field::new("lookup_type", self.lookup_type())
+                    .with_bytes(self.data, self.shape.lookup_type_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("lookup_flag", self.traverse_lookup_flag())
+                    .with_bytes(self.data, self.shape.lookup_flag_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("sub_table_count", self.sub_table_count())
+                    .with_bytes(self.data, self.shape.sub_table_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "subtable_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<T>(),
+                            self.subtable_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<T>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.subtable_offsets_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("mark_filtering_set", self.mark_filtering_set())
+                    .with_bytes(self.data, self.shape.mark_filtering_set_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -979,9 +1051,18 @@ impl<'a> SomeTable<'a> for CoverageFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("coverage_format", self.coverage_format())),
-            1usize => Some(Field::new("glyph_count", self.glyph_count())),
-            2usize => Some(Field::new("glyph_array", self.glyph_array())),
+            0usize => Some(
+                Field::new("coverage_format", self.coverage_format())
+                    .with_bytes(self.data, self.shape.coverage_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("glyph_array", self.glyph_array())
+                    .with_bytes(self.data, self.shape.glyph_array_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1063,16 +1144,25 @@ impl<'a> SomeTable<'a> for CoverageFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("coverage_format", self.coverage_format())),
-            1usize => Some(Field::new("range_count", self.range_count())),
-            2usize => Some(Field::new(
-                "range_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(RangeRecord),
-                    self.range_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("coverage_format", self.coverage_format())
+                    .with_bytes(self.data, self.shape.coverage_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("range_count", self.range_count())
+                    .with_bytes(self.data, self.shape.range_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "range_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(RangeRecord),
+                        self.range_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.range_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1132,20 +1222,31 @@ impl<'a> SomeRecord<'a> for RangeRecord {
         RecordResolver {
             name: "RangeRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("start_glyph_id", self.start_glyph_id())),
-                1usize => Some(Field::new("end_glyph_id", self.end_glyph_id())),
-                2usize => Some(Field::new(
-                    "start_coverage_index",
-                    self.start_coverage_index(),
-                )),
+                0usize => Some(
+                    Field::new("start_glyph_id", self.start_glyph_id())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("end_glyph_id", self.end_glyph_id())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, GlyphId::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("start_coverage_index", self.start_coverage_index())
+                        .with_record_range(
+                            GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN,
+                            u16::RAW_BYTE_LEN,
+                        ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
 
 /// [Coverage Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#coverage-table)
+#[derive(Clone)]
 pub enum CoverageTable<'a> {
     Format1(CoverageFormat1<'a>),
     Format2(CoverageFormat2<'a>),
@@ -1269,10 +1370,22 @@ impl<'a> SomeTable<'a> for ClassDefFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("class_format", self.class_format())),
-            1usize => Some(Field::new("start_glyph_id", self.start_glyph_id())),
-            2usize => Some(Field::new("glyph_count", self.glyph_count())),
-            3usize => Some(Field::new("class_value_array", self.class_value_array())),
+            0usize => Some(
+                Field::new("class_format", self.class_format())
+                    .with_bytes(self.data, self.shape.class_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("start_glyph_id", self.start_glyph_id())
+                    .with_bytes(self.data, self.shape.start_glyph_id_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("class_value_array", self.class_value_array())
+                    .with_bytes(self.data, self.shape.class_value_array_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1355,16 +1468,25 @@ impl<'a> SomeTable<'a> for ClassDefFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("class_format", self.class_format())),
-            1usize => Some(Field::new("class_range_count", self.class_range_count())),
-            2usize => Some(Field::new(
-                "class_range_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(ClassRangeRecord),
-                    self.class_range_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("class_format", self.class_format())
+                    .with_bytes(self.data, self.shape.class_format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("class_range_count", self.class_range_count())
+                    .with_bytes(self.data, self.shape.class_range_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "class_range_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(ClassRangeRecord),
+                        self.class_range_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.class_range_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1424,17 +1546,28 @@ impl<'a> SomeRecord<'a> for ClassRangeRecord {
         RecordResolver {
             name: "ClassRangeRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("start_glyph_id", self.start_glyph_id())),
-                1usize => Some(Field::new("end_glyph_id", self.end_glyph_id())),
-                2usize => Some(Field::new("class", self.class())),
+                0usize => Some(
+                    Field::new("start_glyph_id", self.start_glyph_id())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("end_glyph_id", self.end_glyph_id())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, GlyphId::RAW_BYTE_LEN),
+                ),
+                2usize => Some(Field::new("class", self.class()).with_record_range(
+                    GlyphId::RAW_BYTE_LEN + GlyphId::RAW_BYTE_LEN,
+                    u16::RAW_BYTE_LEN,
+                )),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
 
 /// A [Class Definition Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#class-definition-table)
+#[derive(Clone)]
 pub enum ClassDef<'a> {
     Format1(ClassDefFormat1<'a>),
     Format2(ClassDefFormat2<'a>),
@@ -1518,11 +1651,18 @@ impl<'a> SomeRecord<'a> for SequenceLookupRecord {
         RecordResolver {
             name: "SequenceLookupRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("sequence_index", self.sequence_index())),
-                1usize => Some(Field::new("lookup_list_index", self.lookup_list_index())),
+                0usize => Some(
+                    Field::new("sequence_index", self.sequence_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("lookup_list_index", self.lookup_list_index())
+                        .with_record_range(u16::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1622,26 +1762,38 @@ impl<'a> SomeTable<'a> for SequenceContextFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new("seq_rule_set_count", self.seq_rule_set_count())),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "seq_rule_set_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<SequenceRuleSet>(),
-                        self.seq_rule_set_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<SequenceRuleSet>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("seq_rule_set_count", self.seq_rule_set_count())
+                    .with_bytes(self.data, self.shape.seq_rule_set_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "seq_rule_set_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<SequenceRuleSet>(),
+                            self.seq_rule_set_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<SequenceRuleSet>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.seq_rule_set_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1716,21 +1868,27 @@ impl<'a> SomeTable<'a> for SequenceRuleSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("seq_rule_count", self.seq_rule_count())),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "seq_rule_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<SequenceRule>(),
-                        self.seq_rule_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<SequenceRule>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("seq_rule_count", self.seq_rule_count())
+                    .with_bytes(self.data, self.shape.seq_rule_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "seq_rule_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<SequenceRule>(),
+                            self.seq_rule_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<SequenceRule>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.seq_rule_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1824,17 +1982,29 @@ impl<'a> SomeTable<'a> for SequenceRule<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("glyph_count", self.glyph_count())),
-            1usize => Some(Field::new("seq_lookup_count", self.seq_lookup_count())),
-            2usize => Some(Field::new("input_sequence", self.input_sequence())),
-            3usize => Some(Field::new(
-                "seq_lookup_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequenceLookupRecord),
-                    self.seq_lookup_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("seq_lookup_count", self.seq_lookup_count())
+                    .with_bytes(self.data, self.shape.seq_lookup_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("input_sequence", self.input_sequence())
+                    .with_bytes(self.data, self.shape.input_sequence_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "seq_lookup_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequenceLookupRecord),
+                        self.seq_lookup_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.seq_lookup_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -1963,33 +2133,48 @@ impl<'a> SomeTable<'a> for SequenceContextFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new(
-                "class_def_offset",
-                FieldType::offset(self.class_def_offset(), self.class_def()),
-            )),
-            3usize => Some(Field::new(
-                "class_seq_rule_set_count",
-                self.class_seq_rule_set_count(),
-            )),
-            4usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "class_seq_rule_set_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ClassSequenceRuleSet>(),
-                        self.class_seq_rule_set_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ClassSequenceRuleSet>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "class_def_offset",
+                    FieldType::offset(self.class_def_offset(), self.class_def()),
+                )
+                .with_bytes(self.data, self.shape.class_def_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("class_seq_rule_set_count", self.class_seq_rule_set_count())
+                    .with_bytes(self.data, self.shape.class_seq_rule_set_count_byte_range()),
+            ),
+            4usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "class_seq_rule_set_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ClassSequenceRuleSet>(),
+                            self.class_seq_rule_set_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ClassSequenceRuleSet>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.class_seq_rule_set_offsets_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
@@ -2065,24 +2250,27 @@ impl<'a> SomeTable<'a> for ClassSequenceRuleSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "class_seq_rule_count",
-                self.class_seq_rule_count(),
-            )),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "class_seq_rule_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ClassSequenceRule>(),
-                        self.class_seq_rule_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ClassSequenceRule>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("class_seq_rule_count", self.class_seq_rule_count())
+                    .with_bytes(self.data, self.shape.class_seq_rule_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "class_seq_rule_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ClassSequenceRule>(),
+                            self.class_seq_rule_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ClassSequenceRule>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.class_seq_rule_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2177,17 +2365,29 @@ impl<'a> SomeTable<'a> for ClassSequenceRule<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("glyph_count", self.glyph_count())),
-            1usize => Some(Field::new("seq_lookup_count", self.seq_lookup_count())),
-            2usize => Some(Field::new("input_sequence", self.input_sequence())),
-            3usize => Some(Field::new(
-                "seq_lookup_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequenceLookupRecord),
-                    self.seq_lookup_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("seq_lookup_count", self.seq_lookup_count())
+                    .with_bytes(self.data, self.shape.seq_lookup_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("input_sequence", self.input_sequence())
+                    .with_bytes(self.data, self.shape.input_sequence_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "seq_lookup_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequenceLookupRecord),
+                        self.seq_lookup_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.seq_lookup_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2303,31 +2503,46 @@ impl<'a> SomeTable<'a> for SequenceContextFormat3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("glyph_count", self.glyph_count())),
-            2usize => Some(Field::new("seq_lookup_count", self.seq_lookup_count())),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("glyph_count", self.glyph_count())
+                    .with_bytes(self.data, self.shape.glyph_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("seq_lookup_count", self.seq_lookup_count())
+                    .with_bytes(self.data, self.shape.seq_lookup_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "coverage_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CoverageTable>(),
+                            self.coverage_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CoverageTable>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.coverage_offsets_byte_range()),
+            ),
+            4usize => Some(
                 Field::new(
-                    "coverage_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CoverageTable>(),
-                        self.coverage_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CoverageTable>(data);
-                            FieldType::offset(off.get(), target)
-                        },
+                    "seq_lookup_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequenceLookupRecord),
+                        self.seq_lookup_records(),
+                        self.offset_data(),
                     ),
                 )
-            }),
-            4usize => Some(Field::new(
-                "seq_lookup_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequenceLookupRecord),
-                    self.seq_lookup_records(),
-                    self.offset_data(),
-                ),
-            )),
+                .with_bytes(self.data, self.shape.seq_lookup_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2340,6 +2555,7 @@ impl<'a> std::fmt::Debug for SequenceContextFormat3<'a> {
     }
 }
 
+#[derive(Clone)]
 pub enum SequenceContext<'a> {
     Format1(SequenceContextFormat1<'a>),
     Format2(SequenceContextFormat2<'a>),
@@ -2484,29 +2700,47 @@ impl<'a> SomeTable<'a> for ChainedSequenceContextFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new(
-                "chained_seq_rule_set_count",
-                self.chained_seq_rule_set_count(),
-            )),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "chained_seq_rule_set_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ChainedSequenceRuleSet>(),
-                        self.chained_seq_rule_set_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ChainedSequenceRuleSet>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
                 )
-            }),
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "chained_seq_rule_set_count",
+                    self.chained_seq_rule_set_count(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.chained_seq_rule_set_count_byte_range(),
+                ),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "chained_seq_rule_set_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ChainedSequenceRuleSet>(),
+                            self.chained_seq_rule_set_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ChainedSequenceRuleSet>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.chained_seq_rule_set_offsets_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
@@ -2582,24 +2816,27 @@ impl<'a> SomeTable<'a> for ChainedSequenceRuleSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "chained_seq_rule_count",
-                self.chained_seq_rule_count(),
-            )),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "chained_seq_rule_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ChainedSequenceRule>(),
-                        self.chained_seq_rule_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ChainedSequenceRule>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("chained_seq_rule_count", self.chained_seq_rule_count())
+                    .with_bytes(self.data, self.shape.chained_seq_rule_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "chained_seq_rule_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ChainedSequenceRule>(),
+                            self.chained_seq_rule_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ChainedSequenceRule>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.chained_seq_rule_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2743,27 +2980,45 @@ impl<'a> SomeTable<'a> for ChainedSequenceRule<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "backtrack_glyph_count",
-                self.backtrack_glyph_count(),
-            )),
-            1usize => Some(Field::new("backtrack_sequence", self.backtrack_sequence())),
-            2usize => Some(Field::new("input_glyph_count", self.input_glyph_count())),
-            3usize => Some(Field::new("input_sequence", self.input_sequence())),
-            4usize => Some(Field::new(
-                "lookahead_glyph_count",
-                self.lookahead_glyph_count(),
-            )),
-            5usize => Some(Field::new("lookahead_sequence", self.lookahead_sequence())),
-            6usize => Some(Field::new("seq_lookup_count", self.seq_lookup_count())),
-            7usize => Some(Field::new(
-                "seq_lookup_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequenceLookupRecord),
-                    self.seq_lookup_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("backtrack_glyph_count", self.backtrack_glyph_count())
+                    .with_bytes(self.data, self.shape.backtrack_glyph_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("backtrack_sequence", self.backtrack_sequence())
+                    .with_bytes(self.data, self.shape.backtrack_sequence_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("input_glyph_count", self.input_glyph_count())
+                    .with_bytes(self.data, self.shape.input_glyph_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("input_sequence", self.input_sequence())
+                    .with_bytes(self.data, self.shape.input_sequence_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("lookahead_glyph_count", self.lookahead_glyph_count())
+                    .with_bytes(self.data, self.shape.lookahead_glyph_count_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("lookahead_sequence", self.lookahead_sequence())
+                    .with_bytes(self.data, self.shape.lookahead_sequence_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("seq_lookup_count", self.seq_lookup_count())
+                    .with_bytes(self.data, self.shape.seq_lookup_count_byte_range()),
+            ),
+            7usize => Some(
+                Field::new(
+                    "seq_lookup_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequenceLookupRecord),
+                        self.seq_lookup_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.seq_lookup_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -2928,47 +3183,80 @@ impl<'a> SomeTable<'a> for ChainedSequenceContextFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "coverage_offset",
-                FieldType::offset(self.coverage_offset(), self.coverage()),
-            )),
-            2usize => Some(Field::new(
-                "backtrack_class_def_offset",
-                FieldType::offset(
-                    self.backtrack_class_def_offset(),
-                    self.backtrack_class_def(),
-                ),
-            )),
-            3usize => Some(Field::new(
-                "input_class_def_offset",
-                FieldType::offset(self.input_class_def_offset(), self.input_class_def()),
-            )),
-            4usize => Some(Field::new(
-                "lookahead_class_def_offset",
-                FieldType::offset(
-                    self.lookahead_class_def_offset(),
-                    self.lookahead_class_def(),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "coverage_offset",
+                    FieldType::offset(self.coverage_offset(), self.coverage()),
+                )
+                .with_bytes(self.data, self.shape.coverage_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "backtrack_class_def_offset",
+                    FieldType::offset(
+                        self.backtrack_class_def_offset(),
+                        self.backtrack_class_def(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.backtrack_class_def_offset_byte_range(),
                 ),
-            )),
-            5usize => Some(Field::new(
-                "chained_class_seq_rule_set_count",
-                self.chained_class_seq_rule_set_count(),
-            )),
-            6usize => Some({
-                let data = self.data;
+            ),
+            3usize => Some(
+                Field::new(
+                    "input_class_def_offset",
+                    FieldType::offset(self.input_class_def_offset(), self.input_class_def()),
+                )
+                .with_bytes(self.data, self.shape.input_class_def_offset_byte_range()),
+            ),
+            4usize => Some(
                 Field::new(
-                    "chained_class_seq_rule_set_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ChainedClassSequenceRuleSet>(),
-                        self.chained_class_seq_rule_set_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ChainedClassSequenceRuleSet>(data);
-                            FieldType::offset(off.get(), target)
-                        },
+                    "lookahead_class_def_offset",
+                    FieldType::offset(
+                        self.lookahead_class_def_offset(),
+                        self.lookahead_class_def(),
                     ),
                 )
-            }),
+                .with_bytes(
+                    self.data,
+                    self.shape.lookahead_class_def_offset_byte_range(),
+                ),
+            ),
+            5usize => Some(
+                Field::new(
+                    "chained_class_seq_rule_set_count",
+                    self.chained_class_seq_rule_set_count(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.chained_class_seq_rule_set_count_byte_range(),
+                ),
+            ),
+            6usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "chained_class_seq_rule_set_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ChainedClassSequenceRuleSet>(),
+                            self.chained_class_seq_rule_set_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ChainedClassSequenceRuleSet>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.chained_class_seq_rule_set_offsets_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
@@ -3046,24 +3334,36 @@ impl<'a> SomeTable<'a> for ChainedClassSequenceRuleSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "chained_class_seq_rule_count",
-                self.chained_class_seq_rule_count(),
-            )),
-            1usize => Some({
-                let data = self.data;
+            0usize => Some(
                 Field::new(
-                    "chained_class_seq_rule_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ChainedClassSequenceRule>(),
-                        self.chained_class_seq_rule_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ChainedClassSequenceRule>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
+                    "chained_class_seq_rule_count",
+                    self.chained_class_seq_rule_count(),
                 )
-            }),
+                .with_bytes(
+                    self.data,
+                    self.shape.chained_class_seq_rule_count_byte_range(),
+                ),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "chained_class_seq_rule_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ChainedClassSequenceRule>(),
+                            self.chained_class_seq_rule_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ChainedClassSequenceRule>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.chained_class_seq_rule_offsets_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
@@ -3208,27 +3508,45 @@ impl<'a> SomeTable<'a> for ChainedClassSequenceRule<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "backtrack_glyph_count",
-                self.backtrack_glyph_count(),
-            )),
-            1usize => Some(Field::new("backtrack_sequence", self.backtrack_sequence())),
-            2usize => Some(Field::new("input_glyph_count", self.input_glyph_count())),
-            3usize => Some(Field::new("input_sequence", self.input_sequence())),
-            4usize => Some(Field::new(
-                "lookahead_glyph_count",
-                self.lookahead_glyph_count(),
-            )),
-            5usize => Some(Field::new("lookahead_sequence", self.lookahead_sequence())),
-            6usize => Some(Field::new("seq_lookup_count", self.seq_lookup_count())),
-            7usize => Some(Field::new(
-                "seq_lookup_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequenceLookupRecord),
-                    self.seq_lookup_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("backtrack_glyph_count", self.backtrack_glyph_count())
+                    .with_bytes(self.data, self.shape.backtrack_glyph_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("backtrack_sequence", self.backtrack_sequence())
+                    .with_bytes(self.data, self.shape.backtrack_sequence_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("input_glyph_count", self.input_glyph_count())
+                    .with_bytes(self.data, self.shape.input_glyph_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("input_sequence", self.input_sequence())
+                    .with_bytes(self.data, self.shape.input_sequence_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("lookahead_glyph_count", self.lookahead_glyph_count())
+                    .with_bytes(self.data, self.shape.lookahead_glyph_count_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("lookahead_sequence", self.lookahead_sequence())
+                    .with_bytes(self.data, self.shape.lookahead_sequence_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("seq_lookup_count", self.seq_lookup_count())
+                    .with_bytes(self.data, self.shape.seq_lookup_count_byte_range()),
+            ),
+            7usize => Some(
+                Field::new(
+                    "seq_lookup_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequenceLookupRecord),
+                        self.seq_lookup_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.seq_lookup_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3409,67 +3727,94 @@ impl<'a> SomeTable<'a> for ChainedSequenceContextFormat3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "backtrack_glyph_count",
-                self.backtrack_glyph_count(),
-            )),
-            2usize => Some({
-                let data = self.data;
-                Field::new(
-                    "backtrack_coverage_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CoverageTable>(),
-                        self.backtrack_coverage_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CoverageTable>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
-            3usize => Some(Field::new("input_glyph_count", self.input_glyph_count())),
-            4usize => Some({
-                let data = self.data;
-                Field::new(
-                    "input_coverage_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CoverageTable>(),
-                        self.input_coverage_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CoverageTable>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
-            5usize => Some(Field::new(
-                "lookahead_glyph_count",
-                self.lookahead_glyph_count(),
-            )),
-            6usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("backtrack_glyph_count", self.backtrack_glyph_count())
+                    .with_bytes(self.data, self.shape.backtrack_glyph_count_byte_range()),
+            ),
+            2usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "backtrack_coverage_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CoverageTable>(),
+                            self.backtrack_coverage_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CoverageTable>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.backtrack_coverage_offsets_byte_range(),
+                ),
+            ),
+            3usize => Some(
+                Field::new("input_glyph_count", self.input_glyph_count())
+                    .with_bytes(self.data, self.shape.input_glyph_count_byte_range()),
+            ),
+            4usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "input_coverage_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CoverageTable>(),
+                            self.input_coverage_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CoverageTable>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.input_coverage_offsets_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("lookahead_glyph_count", self.lookahead_glyph_count())
+                    .with_bytes(self.data, self.shape.lookahead_glyph_count_byte_range()),
+            ),
+            6usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "lookahead_coverage_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<CoverageTable>(),
+                            self.lookahead_coverage_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<CoverageTable>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.lookahead_coverage_offsets_byte_range(),
+                ),
+            ),
+            7usize => Some(
+                Field::new("seq_lookup_count", self.seq_lookup_count())
+                    .with_bytes(self.data, self.shape.seq_lookup_count_byte_range()),
+            ),
+            8usize => Some(
                 Field::new(
-                    "lookahead_coverage_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<CoverageTable>(),
-                        self.lookahead_coverage_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<CoverageTable>(data);
-                            FieldType::offset(off.get(), target)
-                        },
+                    "seq_lookup_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SequenceLookupRecord),
+                        self.seq_lookup_records(),
+                        self.offset_data(),
                     ),
                 )
-            }),
-            7usize => Some(Field::new("seq_lookup_count", self.seq_lookup_count())),
-            8usize => Some(Field::new(
-                "seq_lookup_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(SequenceLookupRecord),
-                    self.seq_lookup_records(),
-                    self.offset_data(),
-                ),
-            )),
+                .with_bytes(self.data, self.shape.seq_lookup_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3482,6 +3827,7 @@ impl<'a> std::fmt::Debug for ChainedSequenceContextFormat3<'a> {
     }
 }
 
+#[derive(Clone)]
 pub enum ChainedSequenceContext<'a> {
     Format1(ChainedSequenceContextFormat1<'a>),
     Format2(ChainedSequenceContextFormat2<'a>),
@@ -3656,10 +4002,22 @@ impl<'a> SomeTable<'a> for Device<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("start_size", self.start_size())),
-            1usize => Some(Field::new("end_size", self.end_size())),
-            2usize => Some(Field::new("delta_format", self.delta_format())),
-            3usize => Some(Field::new("delta_value", self.delta_value())),
+            0usize => Some(
+                Field::new("start_size", self.start_size())
+                    .with_bytes(self.data, self.shape.start_size_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("end_size", self.end_size())
+                    .with_bytes(self.data, self.shape.end_size_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("delta_format", self.delta_format())
+                    .with_bytes(self.data, self.shape.delta_format_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("delta_value", self.delta_value())
+                    .with_bytes(self.data, self.shape.delta_value_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3734,15 +4092,18 @@ impl<'a> SomeTable<'a> for VariationIndex<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "delta_set_outer_index",
-                self.delta_set_outer_index(),
-            )),
-            1usize => Some(Field::new(
-                "delta_set_inner_index",
-                self.delta_set_inner_index(),
-            )),
-            2usize => Some(Field::new("delta_format", self.delta_format())),
+            0usize => Some(
+                Field::new("delta_set_outer_index", self.delta_set_outer_index())
+                    .with_bytes(self.data, self.shape.delta_set_outer_index_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("delta_set_inner_index", self.delta_set_inner_index())
+                    .with_bytes(self.data, self.shape.delta_set_inner_index_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("delta_format", self.delta_format())
+                    .with_bytes(self.data, self.shape.delta_format_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3756,6 +4117,7 @@ impl<'a> std::fmt::Debug for VariationIndex<'a> {
 }
 
 /// Either a [Device] table (in a non-variable font) or a [VariationIndex] table (in a variable font)
+#[derive(Clone)]
 pub enum DeviceOrVariationIndex<'a> {
     Device(Device<'a>),
     VariationIndex(VariationIndex<'a>),
@@ -3868,19 +4230,31 @@ impl<'a> SomeTable<'a> for FeatureVariations<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "feature_variation_record_count",
-                self.feature_variation_record_count(),
-            )),
-            2usize => Some(Field::new(
-                "feature_variation_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(FeatureVariationRecord),
-                    self.feature_variation_records(),
-                    self.offset_data(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "feature_variation_record_count",
+                    self.feature_variation_record_count(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.feature_variation_record_count_byte_range(),
                 ),
-            )),
+            ),
+            2usize => Some(
+                Field::new(
+                    "feature_variation_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(FeatureVariationRecord),
+                        self.feature_variation_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.feature_variation_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -3953,20 +4327,27 @@ impl<'a> SomeRecord<'a> for FeatureVariationRecord {
         RecordResolver {
             name: "FeatureVariationRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new(
-                    "condition_set_offset",
-                    FieldType::offset(self.condition_set_offset(), self.condition_set(_data)),
-                )),
-                1usize => Some(Field::new(
-                    "feature_table_substitution_offset",
-                    FieldType::offset(
-                        self.feature_table_substitution_offset(),
-                        self.feature_table_substitution(_data),
-                    ),
-                )),
+                0usize => Some(
+                    Field::new(
+                        "condition_set_offset",
+                        FieldType::offset(self.condition_set_offset(), self.condition_set(_data)),
+                    )
+                    .with_record_range(0, Offset32::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "feature_table_substitution_offset",
+                        FieldType::offset(
+                            self.feature_table_substitution_offset(),
+                            self.feature_table_substitution(_data),
+                        ),
+                    )
+                    .with_record_range(Offset32::RAW_BYTE_LEN, Offset32::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -4033,21 +4414,27 @@ impl<'a> SomeTable<'a> for ConditionSet<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("condition_count", self.condition_count())),
-            1usize => Some({
-                let data = self.data;
-                Field::new(
-                    "condition_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ConditionFormat1>(),
-                        self.condition_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ConditionFormat1>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("condition_count", self.condition_count())
+                    .with_bytes(self.data, self.shape.condition_count_byte_range()),
+            ),
+            1usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "condition_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ConditionFormat1>(),
+                            self.condition_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ConditionFormat1>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.condition_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4138,16 +4525,22 @@ impl<'a> SomeTable<'a> for ConditionFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("axis_index", self.axis_index())),
-            2usize => Some(Field::new(
-                "filter_range_min_value",
-                self.filter_range_min_value(),
-            )),
-            3usize => Some(Field::new(
-                "filter_range_max_value",
-                self.filter_range_max_value(),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("axis_index", self.axis_index())
+                    .with_bytes(self.data, self.shape.axis_index_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("filter_range_min_value", self.filter_range_min_value())
+                    .with_bytes(self.data, self.shape.filter_range_min_value_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("filter_range_max_value", self.filter_range_max_value())
+                    .with_bytes(self.data, self.shape.filter_range_max_value_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4226,16 +4619,25 @@ impl<'a> SomeTable<'a> for FeatureTableSubstitution<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("substitution_count", self.substitution_count())),
-            2usize => Some(Field::new(
-                "substitutions",
-                traversal::FieldType::array_of_records(
-                    stringify!(FeatureTableSubstitutionRecord),
-                    self.substitutions(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("substitution_count", self.substitution_count())
+                    .with_bytes(self.data, self.shape.substitution_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "substitutions",
+                    traversal::FieldType::array_of_records(
+                        stringify!(FeatureTableSubstitutionRecord),
+                        self.substitutions(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.substitutions_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4290,17 +4692,24 @@ impl<'a> SomeRecord<'a> for FeatureTableSubstitutionRecord {
         RecordResolver {
             name: "FeatureTableSubstitutionRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("feature_index", self.feature_index())),
-                1usize => Some(Field::new(
-                    "alternate_feature_offset",
-                    FieldType::offset(
-                        self.alternate_feature_offset(),
-                        self.alternate_feature(_data),
-                    ),
-                )),
+                0usize => Some(
+                    Field::new("feature_index", self.feature_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "alternate_feature_offset",
+                        FieldType::offset(
+                            self.alternate_feature_offset(),
+                            self.alternate_feature(_data),
+                        ),
+                    )
+                    .with_record_range(u16::RAW_BYTE_LEN, Offset32::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -4405,11 +4814,26 @@ impl<'a> SomeTable<'a> for SizeParams<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("design_size", self.design_size())),
-            1usize => Some(Field::new("identifier", self.identifier())),
-            2usize => Some(Field::new("name_entry", self.name_entry())),
-            3usize => Some(Field::new("range_start", self.range_start())),
-            4usize => Some(Field::new("range_end", self.range_end())),
+            0usize => Some(
+                Field::new("design_size", self.design_size())
+                    .with_bytes(self.data, self.shape.design_size_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("identifier", self.identifier())
+                    .with_bytes(self.data, self.shape.identifier_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("name_entry", self.name_entry())
+                    .with_bytes(self.data, self.shape.name_entry_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("range_start", self.range_start())
+                    .with_bytes(self.data, self.shape.range_start_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("range_end", self.range_end())
+                    .with_bytes(self.data, self.shape.range_end_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4476,8 +4900,14 @@ impl<'a> SomeTable<'a> for StylisticSetParams<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("ui_name_id", self.ui_name_id())),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("ui_name_id", self.ui_name_id())
+                    .with_bytes(self.data, self.shape.ui_name_id_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -4621,29 +5051,50 @@ impl<'a> SomeTable<'a> for CharacterVariantParams<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "feat_ui_label_name_id",
-                self.feat_ui_label_name_id(),
-            )),
-            2usize => Some(Field::new(
-                "feat_ui_tooltip_text_name_id",
-                self.feat_ui_tooltip_text_name_id(),
-            )),
-            3usize => Some(Field::new(
-                "sample_text_name_id",
-                self.sample_text_name_id(),
-            )),
-            4usize => Some(Field::new(
-                "num_named_parameters",
-                self.num_named_parameters(),
-            )),
-            5usize => Some(Field::new(
-                "first_param_ui_label_name_id",
-                self.first_param_ui_label_name_id(),
-            )),
-            6usize => Some(Field::new("char_count", self.char_count())),
-            7usize => Some(Field::new("character", self.character())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("feat_ui_label_name_id", self.feat_ui_label_name_id())
+                    .with_bytes(self.data, self.shape.feat_ui_label_name_id_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "feat_ui_tooltip_text_name_id",
+                    self.feat_ui_tooltip_text_name_id(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.feat_ui_tooltip_text_name_id_byte_range(),
+                ),
+            ),
+            3usize => Some(
+                Field::new("sample_text_name_id", self.sample_text_name_id())
+                    .with_bytes(self.data, self.shape.sample_text_name_id_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("num_named_parameters", self.num_named_parameters())
+                    .with_bytes(self.data, self.shape.num_named_parameters_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "first_param_ui_label_name_id",
+                    self.first_param_ui_label_name_id(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.first_param_ui_label_name_id_byte_range(),
+                ),
+            ),
+            6usize => Some(
+                Field::new("char_count", self.char_count())
+                    .with_bytes(self.data, self.shape.char_count_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("character", self.character())
+                    .with_bytes(self.data, self.shape.character_byte_range()),
+            ),
             _ => None,
         }
     }
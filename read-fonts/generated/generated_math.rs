@@ -0,0 +1,3293 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [Mathematical Typesetting](https://learn.microsoft.com/en-us/typography/opentype/spec/math) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathMarker {}
+
+impl MathMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + MajorMinor::RAW_BYTE_LEN
+    }
+    fn math_constants_offset_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn math_glyph_info_offset_byte_range(&self) -> Range<usize> {
+        let start = self.math_constants_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn math_variants_offset_byte_range(&self) -> Range<usize> {
+        let start = self.math_glyph_info_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+}
+
+impl TopLevelTable for Math<'_> {
+    /// `MATH`
+    const TAG: Tag = Tag::new(b"MATH");
+}
+
+impl<'a> FontRead<'a> for Math<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<MajorMinor>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.finish(MathMarker {})
+    }
+}
+
+/// The [Mathematical Typesetting](https://learn.microsoft.com/en-us/typography/opentype/spec/math) table.
+pub type Math<'a> = TableRef<'a, MathMarker>;
+
+impl<'a> Math<'a> {
+    /// Major and minor version of the MATH table, = 1.0.
+    pub fn version(&self) -> MajorMinor {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to MathConstants table, from the beginning of MATH table.
+    pub fn math_constants_offset(&self) -> Offset16 {
+        let range = self.shape.math_constants_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`math_constants_offset`][Self::math_constants_offset].
+    pub fn math_constants(&self) -> Result<MathConstants<'a>, ReadError> {
+        let data = self.data;
+        self.math_constants_offset().resolve(data)
+    }
+
+    /// Offset to MathGlyphInfo table, from the beginning of MATH table.
+    pub fn math_glyph_info_offset(&self) -> Offset16 {
+        let range = self.shape.math_glyph_info_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`math_glyph_info_offset`][Self::math_glyph_info_offset].
+    pub fn math_glyph_info(&self) -> Result<MathGlyphInfo<'a>, ReadError> {
+        let data = self.data;
+        self.math_glyph_info_offset().resolve(data)
+    }
+
+    /// Offset to MathVariants table, from the beginning of MATH table.
+    pub fn math_variants_offset(&self) -> Offset16 {
+        let range = self.shape.math_variants_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`math_variants_offset`][Self::math_variants_offset].
+    pub fn math_variants(&self) -> Result<MathVariants<'a>, ReadError> {
+        let data = self.data;
+        self.math_variants_offset().resolve(data)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Math<'a> {
+    fn type_name(&self) -> &str {
+        "Math"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "math_constants_offset",
+                    FieldType::offset(self.math_constants_offset(), self.math_constants()),
+                )
+                .with_bytes(self.data, self.shape.math_constants_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "math_glyph_info_offset",
+                    FieldType::offset(self.math_glyph_info_offset(), self.math_glyph_info()),
+                )
+                .with_bytes(self.data, self.shape.math_glyph_info_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "math_variants_offset",
+                    FieldType::offset(self.math_variants_offset(), self.math_variants()),
+                )
+                .with_bytes(self.data, self.shape.math_variants_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Math<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A value with an optional device table adjustment.
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct MathValueRecord {
+    /// The X or Y value in design units.
+    pub value: BigEndian<FWord>,
+    /// Offset to the device table, from the beginning of parent table.
+    /// May be NULL.
+    pub device_offset: BigEndian<Nullable<Offset16>>,
+}
+
+impl MathValueRecord {
+    /// The X or Y value in design units.
+    pub fn value(&self) -> FWord {
+        self.value.get()
+    }
+
+    /// Offset to the device table, from the beginning of parent table.
+    /// May be NULL.
+    pub fn device_offset(&self) -> Nullable<Offset16> {
+        self.device_offset.get()
+    }
+
+    /// Attempt to resolve [`device_offset`][Self::device_offset].
+    pub fn device<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<DeviceOrVariationIndex<'a>, ReadError>> {
+        self.device_offset().resolve(data)
+    }
+}
+
+impl FixedSize for MathValueRecord {
+    const RAW_BYTE_LEN: usize = FWord::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for MathValueRecord {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for MathValueRecord {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for MathValueRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "MathValueRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("value", self.value()).with_record_range(0, FWord::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "device_offset",
+                        FieldType::offset(self.device_offset(), self.device(_data)),
+                    )
+                    .with_record_range(FWord::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// The [MathConstants](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathconstants-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathConstantsMarker {}
+
+impl MathConstantsMarker {
+    fn script_percent_scale_down_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + i16::RAW_BYTE_LEN
+    }
+    fn script_script_percent_scale_down_byte_range(&self) -> Range<usize> {
+        let start = self.script_percent_scale_down_byte_range().end;
+        start..start + i16::RAW_BYTE_LEN
+    }
+    fn delimited_sub_formula_min_height_byte_range(&self) -> Range<usize> {
+        let start = self.script_script_percent_scale_down_byte_range().end;
+        start..start + UfWord::RAW_BYTE_LEN
+    }
+    fn display_operator_min_height_byte_range(&self) -> Range<usize> {
+        let start = self.delimited_sub_formula_min_height_byte_range().end;
+        start..start + UfWord::RAW_BYTE_LEN
+    }
+    fn math_leading_byte_range(&self) -> Range<usize> {
+        let start = self.display_operator_min_height_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn axis_height_byte_range(&self) -> Range<usize> {
+        let start = self.math_leading_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn accent_base_height_byte_range(&self) -> Range<usize> {
+        let start = self.axis_height_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn flattened_accent_base_height_byte_range(&self) -> Range<usize> {
+        let start = self.accent_base_height_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn subscript_shift_down_byte_range(&self) -> Range<usize> {
+        let start = self.flattened_accent_base_height_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn subscript_top_max_byte_range(&self) -> Range<usize> {
+        let start = self.subscript_shift_down_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn subscript_baseline_drop_min_byte_range(&self) -> Range<usize> {
+        let start = self.subscript_top_max_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn superscript_shift_up_byte_range(&self) -> Range<usize> {
+        let start = self.subscript_baseline_drop_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn superscript_shift_up_cramped_byte_range(&self) -> Range<usize> {
+        let start = self.superscript_shift_up_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn superscript_bottom_min_byte_range(&self) -> Range<usize> {
+        let start = self.superscript_shift_up_cramped_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn superscript_baseline_drop_max_byte_range(&self) -> Range<usize> {
+        let start = self.superscript_bottom_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn sub_superscript_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.superscript_baseline_drop_max_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn superscript_bottom_max_with_subscript_byte_range(&self) -> Range<usize> {
+        let start = self.sub_superscript_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn space_after_script_byte_range(&self) -> Range<usize> {
+        let start = self.superscript_bottom_max_with_subscript_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn upper_limit_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.space_after_script_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn upper_limit_baseline_rise_min_byte_range(&self) -> Range<usize> {
+        let start = self.upper_limit_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn lower_limit_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.upper_limit_baseline_rise_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn lower_limit_baseline_drop_min_byte_range(&self) -> Range<usize> {
+        let start = self.lower_limit_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stack_top_shift_up_byte_range(&self) -> Range<usize> {
+        let start = self.lower_limit_baseline_drop_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stack_top_display_style_shift_up_byte_range(&self) -> Range<usize> {
+        let start = self.stack_top_shift_up_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stack_bottom_shift_down_byte_range(&self) -> Range<usize> {
+        let start = self.stack_top_display_style_shift_up_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stack_bottom_display_style_shift_down_byte_range(&self) -> Range<usize> {
+        let start = self.stack_bottom_shift_down_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stack_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.stack_bottom_display_style_shift_down_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stack_display_style_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.stack_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stretch_stack_top_shift_up_byte_range(&self) -> Range<usize> {
+        let start = self.stack_display_style_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stretch_stack_bottom_shift_down_byte_range(&self) -> Range<usize> {
+        let start = self.stretch_stack_top_shift_up_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stretch_stack_gap_above_min_byte_range(&self) -> Range<usize> {
+        let start = self.stretch_stack_bottom_shift_down_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn stretch_stack_gap_below_min_byte_range(&self) -> Range<usize> {
+        let start = self.stretch_stack_gap_above_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_numerator_shift_up_byte_range(&self) -> Range<usize> {
+        let start = self.stretch_stack_gap_below_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_numerator_display_style_shift_up_byte_range(&self) -> Range<usize> {
+        let start = self.fraction_numerator_shift_up_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_denominator_shift_down_byte_range(&self) -> Range<usize> {
+        let start = self
+            .fraction_numerator_display_style_shift_up_byte_range()
+            .end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_denominator_display_style_shift_down_byte_range(&self) -> Range<usize> {
+        let start = self.fraction_denominator_shift_down_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_numerator_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self
+            .fraction_denominator_display_style_shift_down_byte_range()
+            .end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_num_display_style_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.fraction_numerator_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_rule_thickness_byte_range(&self) -> Range<usize> {
+        let start = self.fraction_num_display_style_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_denominator_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.fraction_rule_thickness_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn fraction_denom_display_style_gap_min_byte_range(&self) -> Range<usize> {
+        let start = self.fraction_denominator_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn skewed_fraction_horizontal_gap_byte_range(&self) -> Range<usize> {
+        let start = self.fraction_denom_display_style_gap_min_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn skewed_fraction_vertical_gap_byte_range(&self) -> Range<usize> {
+        let start = self.skewed_fraction_horizontal_gap_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn overbar_vertical_gap_byte_range(&self) -> Range<usize> {
+        let start = self.skewed_fraction_vertical_gap_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn overbar_rule_thickness_byte_range(&self) -> Range<usize> {
+        let start = self.overbar_vertical_gap_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn overbar_extra_ascender_byte_range(&self) -> Range<usize> {
+        let start = self.overbar_rule_thickness_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn underbar_vertical_gap_byte_range(&self) -> Range<usize> {
+        let start = self.overbar_extra_ascender_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn underbar_rule_thickness_byte_range(&self) -> Range<usize> {
+        let start = self.underbar_vertical_gap_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn underbar_extra_descender_byte_range(&self) -> Range<usize> {
+        let start = self.underbar_rule_thickness_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn radical_vertical_gap_byte_range(&self) -> Range<usize> {
+        let start = self.underbar_extra_descender_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn radical_display_style_vertical_gap_byte_range(&self) -> Range<usize> {
+        let start = self.radical_vertical_gap_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn radical_rule_thickness_byte_range(&self) -> Range<usize> {
+        let start = self.radical_display_style_vertical_gap_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn radical_extra_ascender_byte_range(&self) -> Range<usize> {
+        let start = self.radical_rule_thickness_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn radical_kern_before_degree_byte_range(&self) -> Range<usize> {
+        let start = self.radical_extra_ascender_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn radical_kern_after_degree_byte_range(&self) -> Range<usize> {
+        let start = self.radical_kern_before_degree_byte_range().end;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn radical_degree_bottom_raise_percent_byte_range(&self) -> Range<usize> {
+        let start = self.radical_kern_after_degree_byte_range().end;
+        start..start + i16::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for MathConstants<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<i16>();
+        cursor.advance::<i16>();
+        cursor.advance::<UfWord>();
+        cursor.advance::<UfWord>();
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        cursor.advance::<i16>();
+        cursor.finish(MathConstantsMarker {})
+    }
+}
+
+/// The [MathConstants](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathconstants-table) table.
+pub type MathConstants<'a> = TableRef<'a, MathConstantsMarker>;
+
+impl<'a> MathConstants<'a> {
+    /// Percentage of scaling down for level 1 superscripts and subscripts.
+    pub fn script_percent_scale_down(&self) -> i16 {
+        let range = self.shape.script_percent_scale_down_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Percentage of scaling down for level 2 (scriptScript) superscripts
+    /// and subscripts.
+    pub fn script_script_percent_scale_down(&self) -> i16 {
+        let range = self.shape.script_script_percent_scale_down_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Minimum height required for a delimited expression (contained
+    /// within parentheses, etc.) to be treated as a sub-formula.
+    pub fn delimited_sub_formula_min_height(&self) -> UfWord {
+        let range = self.shape.delimited_sub_formula_min_height_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Minimum height of n-ary operators (such as integral and summation)
+    /// for formulas in display mode (that is, appearing as standalone page
+    /// elements, not embedded inline within text).
+    pub fn display_operator_min_height(&self) -> UfWord {
+        let range = self.shape.display_operator_min_height_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// White space to be left between math formulas to ensure proper line
+    /// spacing.
+    pub fn math_leading(&self) -> &'a MathValueRecord {
+        let range = self.shape.math_leading_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Axis height of the font.
+    pub fn axis_height(&self) -> &'a MathValueRecord {
+        let range = self.shape.axis_height_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Maximum (ink) height of accent base that does not require raising the
+    /// accents.
+    pub fn accent_base_height(&self) -> &'a MathValueRecord {
+        let range = self.shape.accent_base_height_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Maximum (ink) height of accent base that does not require flattening the
+    /// accents.
+    pub fn flattened_accent_base_height(&self) -> &'a MathValueRecord {
+        let range = self.shape.flattened_accent_base_height_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// The standard shift down applied to subscript elements.
+    pub fn subscript_shift_down(&self) -> &'a MathValueRecord {
+        let range = self.shape.subscript_shift_down_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Maximum allowed height of the (ink) top of subscripts that does not
+    /// require moving subscripts further down.
+    pub fn subscript_top_max(&self) -> &'a MathValueRecord {
+        let range = self.shape.subscript_top_max_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum allowed drop of the baseline of subscripts relative to the (ink)
+    /// bottom of the base.
+    pub fn subscript_baseline_drop_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.subscript_baseline_drop_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift up applied to superscript elements.
+    pub fn superscript_shift_up(&self) -> &'a MathValueRecord {
+        let range = self.shape.superscript_shift_up_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift of superscripts relative to the base, in cramped style.
+    pub fn superscript_shift_up_cramped(&self) -> &'a MathValueRecord {
+        let range = self.shape.superscript_shift_up_cramped_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum allowed height of the (ink) bottom of superscripts that does not
+    /// require moving subscripts further up.
+    pub fn superscript_bottom_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.superscript_bottom_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Maximum allowed drop of the baseline of superscripts relative to the
+    /// (ink) top of the base.
+    pub fn superscript_baseline_drop_max(&self) -> &'a MathValueRecord {
+        let range = self.shape.superscript_baseline_drop_max_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum gap between the superscript and subscript ink.
+    pub fn sub_superscript_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.sub_superscript_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// The maximum level to which the (ink) bottom of superscript can be pushed
+    /// to increase the gap between superscript and subscript, before subscript
+    /// starts being moved down.
+    pub fn superscript_bottom_max_with_subscript(&self) -> &'a MathValueRecord {
+        let range = self
+            .shape
+            .superscript_bottom_max_with_subscript_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Extra white space to be added after each subscript and superscript.
+    pub fn space_after_script(&self) -> &'a MathValueRecord {
+        let range = self.shape.space_after_script_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum gap between the (ink) bottom of the upper limit, and the (ink)
+    /// top of the base operator.
+    pub fn upper_limit_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.upper_limit_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum distance between baseline of upper limit and (ink) top of the
+    /// base operator.
+    pub fn upper_limit_baseline_rise_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.upper_limit_baseline_rise_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum gap between (ink) top of the lower limit, and (ink) bottom of
+    /// the base operator.
+    pub fn lower_limit_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.lower_limit_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum distance between baseline of the lower limit and (ink) bottom of
+    /// the base operator.
+    pub fn lower_limit_baseline_drop_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.lower_limit_baseline_drop_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift up applied to the top element of a stack.
+    pub fn stack_top_shift_up(&self) -> &'a MathValueRecord {
+        let range = self.shape.stack_top_shift_up_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift up applied to the top element of a stack in display
+    /// style.
+    pub fn stack_top_display_style_shift_up(&self) -> &'a MathValueRecord {
+        let range = self.shape.stack_top_display_style_shift_up_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift down applied to the bottom element of a stack.
+    pub fn stack_bottom_shift_down(&self) -> &'a MathValueRecord {
+        let range = self.shape.stack_bottom_shift_down_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift down applied to the bottom element of a stack in display
+    /// style.
+    pub fn stack_bottom_display_style_shift_down(&self) -> &'a MathValueRecord {
+        let range = self
+            .shape
+            .stack_bottom_display_style_shift_down_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum gap between (ink) bottom of the top element of a stack, and the
+    /// (ink) top of the bottom element.
+    pub fn stack_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.stack_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum gap between (ink) bottom of the top element of a stack, and the
+    /// (ink) top of the bottom element in display style.
+    pub fn stack_display_style_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.stack_display_style_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift up applied to the top element of the stretch stack.
+    pub fn stretch_stack_top_shift_up(&self) -> &'a MathValueRecord {
+        let range = self.shape.stretch_stack_top_shift_up_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift down applied to the bottom element of the stretch stack.
+    pub fn stretch_stack_bottom_shift_down(&self) -> &'a MathValueRecord {
+        let range = self.shape.stretch_stack_bottom_shift_down_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum gap between the ink of the stretched element, and the (ink)
+    /// bottom of the element above.
+    pub fn stretch_stack_gap_above_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.stretch_stack_gap_above_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum gap between the ink of the stretched element, and the (ink) top
+    /// of the element below.
+    pub fn stretch_stack_gap_below_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.stretch_stack_gap_below_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift up applied to the numerator.
+    pub fn fraction_numerator_shift_up(&self) -> &'a MathValueRecord {
+        let range = self.shape.fraction_numerator_shift_up_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift up applied to the numerator in display style.
+    pub fn fraction_numerator_display_style_shift_up(&self) -> &'a MathValueRecord {
+        let range = self
+            .shape
+            .fraction_numerator_display_style_shift_up_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift down applied to the denominator.
+    pub fn fraction_denominator_shift_down(&self) -> &'a MathValueRecord {
+        let range = self.shape.fraction_denominator_shift_down_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Standard shift down applied to the denominator in display style.
+    pub fn fraction_denominator_display_style_shift_down(&self) -> &'a MathValueRecord {
+        let range = self
+            .shape
+            .fraction_denominator_display_style_shift_down_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum tolerated gap between the (ink) bottom of the numerator and the
+    /// ink of the fraction bar.
+    pub fn fraction_numerator_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.fraction_numerator_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum tolerated gap between the (ink) bottom of the numerator and the
+    /// ink of the fraction bar in display style.
+    pub fn fraction_num_display_style_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.fraction_num_display_style_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Thickness of the fraction bar.
+    pub fn fraction_rule_thickness(&self) -> &'a MathValueRecord {
+        let range = self.shape.fraction_rule_thickness_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum tolerated gap between the (ink) top of the denominator and the
+    /// ink of the fraction bar.
+    pub fn fraction_denominator_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.fraction_denominator_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Minimum tolerated gap between the (ink) top of the denominator and the
+    /// ink of the fraction bar in display style.
+    pub fn fraction_denom_display_style_gap_min(&self) -> &'a MathValueRecord {
+        let range = self.shape.fraction_denom_display_style_gap_min_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Horizontal distance between the top and bottom elements of a skewed
+    /// fraction.
+    pub fn skewed_fraction_horizontal_gap(&self) -> &'a MathValueRecord {
+        let range = self.shape.skewed_fraction_horizontal_gap_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Vertical distance between the ink of the top and bottom elements of a
+    /// skewed fraction.
+    pub fn skewed_fraction_vertical_gap(&self) -> &'a MathValueRecord {
+        let range = self.shape.skewed_fraction_vertical_gap_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Distance between the overbar and the (ink) top of he base.
+    pub fn overbar_vertical_gap(&self) -> &'a MathValueRecord {
+        let range = self.shape.overbar_vertical_gap_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Thickness of overbar.
+    pub fn overbar_rule_thickness(&self) -> &'a MathValueRecord {
+        let range = self.shape.overbar_rule_thickness_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Extra white space reserved above the overbar.
+    pub fn overbar_extra_ascender(&self) -> &'a MathValueRecord {
+        let range = self.shape.overbar_extra_ascender_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Distance between underbar and (ink) bottom of the base.
+    pub fn underbar_vertical_gap(&self) -> &'a MathValueRecord {
+        let range = self.shape.underbar_vertical_gap_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Thickness of underbar.
+    pub fn underbar_rule_thickness(&self) -> &'a MathValueRecord {
+        let range = self.shape.underbar_rule_thickness_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Extra white space reserved below the underbar.
+    pub fn underbar_extra_descender(&self) -> &'a MathValueRecord {
+        let range = self.shape.underbar_extra_descender_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Space between the (ink) top of the expression and the bar over it.
+    pub fn radical_vertical_gap(&self) -> &'a MathValueRecord {
+        let range = self.shape.radical_vertical_gap_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Space between the (ink) top of the expression and the bar over it in
+    /// display style.
+    pub fn radical_display_style_vertical_gap(&self) -> &'a MathValueRecord {
+        let range = self.shape.radical_display_style_vertical_gap_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Thickness of the radical rule.
+    pub fn radical_rule_thickness(&self) -> &'a MathValueRecord {
+        let range = self.shape.radical_rule_thickness_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Extra white space reserved above the radical.
+    pub fn radical_extra_ascender(&self) -> &'a MathValueRecord {
+        let range = self.shape.radical_extra_ascender_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Extra horizontal kern before the degree of a radical, if such is
+    /// present.
+    pub fn radical_kern_before_degree(&self) -> &'a MathValueRecord {
+        let range = self.shape.radical_kern_before_degree_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Negative kern after the degree of a radical, if such is present.
+    pub fn radical_kern_after_degree(&self) -> &'a MathValueRecord {
+        let range = self.shape.radical_kern_after_degree_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Height of the bottom of the radical degree, if such is present, in
+    /// proportion to the height (ascender + descender) of the radical
+    /// sign.
+    pub fn radical_degree_bottom_raise_percent(&self) -> i16 {
+        let range = self.shape.radical_degree_bottom_raise_percent_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathConstants<'a> {
+    fn type_name(&self) -> &str {
+        "MathConstants"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new(
+                    "script_percent_scale_down",
+                    self.script_percent_scale_down(),
+                )
+                .with_bytes(self.data, self.shape.script_percent_scale_down_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "script_script_percent_scale_down",
+                    self.script_script_percent_scale_down(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.script_script_percent_scale_down_byte_range(),
+                ),
+            ),
+            2usize => Some(
+                Field::new(
+                    "delimited_sub_formula_min_height",
+                    self.delimited_sub_formula_min_height(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.delimited_sub_formula_min_height_byte_range(),
+                ),
+            ),
+            3usize => Some(
+                Field::new(
+                    "display_operator_min_height",
+                    self.display_operator_min_height(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.display_operator_min_height_byte_range(),
+                ),
+            ),
+            4usize => Some(
+                Field::new(
+                    "math_leading",
+                    traversal::FieldType::Record(
+                        self.math_leading().clone().traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.math_leading_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "axis_height",
+                    traversal::FieldType::Record(
+                        self.axis_height().clone().traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.axis_height_byte_range()),
+            ),
+            6usize => Some(
+                Field::new(
+                    "accent_base_height",
+                    traversal::FieldType::Record(
+                        self.accent_base_height()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.accent_base_height_byte_range()),
+            ),
+            7usize => Some(
+                Field::new(
+                    "flattened_accent_base_height",
+                    traversal::FieldType::Record(
+                        self.flattened_accent_base_height()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.flattened_accent_base_height_byte_range(),
+                ),
+            ),
+            8usize => Some(
+                Field::new(
+                    "subscript_shift_down",
+                    traversal::FieldType::Record(
+                        self.subscript_shift_down()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.subscript_shift_down_byte_range()),
+            ),
+            9usize => Some(
+                Field::new(
+                    "subscript_top_max",
+                    traversal::FieldType::Record(
+                        self.subscript_top_max()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.subscript_top_max_byte_range()),
+            ),
+            10usize => Some(
+                Field::new(
+                    "subscript_baseline_drop_min",
+                    traversal::FieldType::Record(
+                        self.subscript_baseline_drop_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.subscript_baseline_drop_min_byte_range(),
+                ),
+            ),
+            11usize => Some(
+                Field::new(
+                    "superscript_shift_up",
+                    traversal::FieldType::Record(
+                        self.superscript_shift_up()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.superscript_shift_up_byte_range()),
+            ),
+            12usize => Some(
+                Field::new(
+                    "superscript_shift_up_cramped",
+                    traversal::FieldType::Record(
+                        self.superscript_shift_up_cramped()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.superscript_shift_up_cramped_byte_range(),
+                ),
+            ),
+            13usize => Some(
+                Field::new(
+                    "superscript_bottom_min",
+                    traversal::FieldType::Record(
+                        self.superscript_bottom_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.superscript_bottom_min_byte_range()),
+            ),
+            14usize => Some(
+                Field::new(
+                    "superscript_baseline_drop_max",
+                    traversal::FieldType::Record(
+                        self.superscript_baseline_drop_max()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.superscript_baseline_drop_max_byte_range(),
+                ),
+            ),
+            15usize => Some(
+                Field::new(
+                    "sub_superscript_gap_min",
+                    traversal::FieldType::Record(
+                        self.sub_superscript_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.sub_superscript_gap_min_byte_range()),
+            ),
+            16usize => Some(
+                Field::new(
+                    "superscript_bottom_max_with_subscript",
+                    traversal::FieldType::Record(
+                        self.superscript_bottom_max_with_subscript()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape
+                        .superscript_bottom_max_with_subscript_byte_range(),
+                ),
+            ),
+            17usize => Some(
+                Field::new(
+                    "space_after_script",
+                    traversal::FieldType::Record(
+                        self.space_after_script()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.space_after_script_byte_range()),
+            ),
+            18usize => Some(
+                Field::new(
+                    "upper_limit_gap_min",
+                    traversal::FieldType::Record(
+                        self.upper_limit_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.upper_limit_gap_min_byte_range()),
+            ),
+            19usize => Some(
+                Field::new(
+                    "upper_limit_baseline_rise_min",
+                    traversal::FieldType::Record(
+                        self.upper_limit_baseline_rise_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.upper_limit_baseline_rise_min_byte_range(),
+                ),
+            ),
+            20usize => Some(
+                Field::new(
+                    "lower_limit_gap_min",
+                    traversal::FieldType::Record(
+                        self.lower_limit_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.lower_limit_gap_min_byte_range()),
+            ),
+            21usize => Some(
+                Field::new(
+                    "lower_limit_baseline_drop_min",
+                    traversal::FieldType::Record(
+                        self.lower_limit_baseline_drop_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.lower_limit_baseline_drop_min_byte_range(),
+                ),
+            ),
+            22usize => Some(
+                Field::new(
+                    "stack_top_shift_up",
+                    traversal::FieldType::Record(
+                        self.stack_top_shift_up()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.stack_top_shift_up_byte_range()),
+            ),
+            23usize => Some(
+                Field::new(
+                    "stack_top_display_style_shift_up",
+                    traversal::FieldType::Record(
+                        self.stack_top_display_style_shift_up()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.stack_top_display_style_shift_up_byte_range(),
+                ),
+            ),
+            24usize => Some(
+                Field::new(
+                    "stack_bottom_shift_down",
+                    traversal::FieldType::Record(
+                        self.stack_bottom_shift_down()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.stack_bottom_shift_down_byte_range()),
+            ),
+            25usize => Some(
+                Field::new(
+                    "stack_bottom_display_style_shift_down",
+                    traversal::FieldType::Record(
+                        self.stack_bottom_display_style_shift_down()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape
+                        .stack_bottom_display_style_shift_down_byte_range(),
+                ),
+            ),
+            26usize => Some(
+                Field::new(
+                    "stack_gap_min",
+                    traversal::FieldType::Record(
+                        self.stack_gap_min().clone().traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.stack_gap_min_byte_range()),
+            ),
+            27usize => Some(
+                Field::new(
+                    "stack_display_style_gap_min",
+                    traversal::FieldType::Record(
+                        self.stack_display_style_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.stack_display_style_gap_min_byte_range(),
+                ),
+            ),
+            28usize => Some(
+                Field::new(
+                    "stretch_stack_top_shift_up",
+                    traversal::FieldType::Record(
+                        self.stretch_stack_top_shift_up()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.stretch_stack_top_shift_up_byte_range(),
+                ),
+            ),
+            29usize => Some(
+                Field::new(
+                    "stretch_stack_bottom_shift_down",
+                    traversal::FieldType::Record(
+                        self.stretch_stack_bottom_shift_down()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.stretch_stack_bottom_shift_down_byte_range(),
+                ),
+            ),
+            30usize => Some(
+                Field::new(
+                    "stretch_stack_gap_above_min",
+                    traversal::FieldType::Record(
+                        self.stretch_stack_gap_above_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.stretch_stack_gap_above_min_byte_range(),
+                ),
+            ),
+            31usize => Some(
+                Field::new(
+                    "stretch_stack_gap_below_min",
+                    traversal::FieldType::Record(
+                        self.stretch_stack_gap_below_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.stretch_stack_gap_below_min_byte_range(),
+                ),
+            ),
+            32usize => Some(
+                Field::new(
+                    "fraction_numerator_shift_up",
+                    traversal::FieldType::Record(
+                        self.fraction_numerator_shift_up()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.fraction_numerator_shift_up_byte_range(),
+                ),
+            ),
+            33usize => Some(
+                Field::new(
+                    "fraction_numerator_display_style_shift_up",
+                    traversal::FieldType::Record(
+                        self.fraction_numerator_display_style_shift_up()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape
+                        .fraction_numerator_display_style_shift_up_byte_range(),
+                ),
+            ),
+            34usize => Some(
+                Field::new(
+                    "fraction_denominator_shift_down",
+                    traversal::FieldType::Record(
+                        self.fraction_denominator_shift_down()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.fraction_denominator_shift_down_byte_range(),
+                ),
+            ),
+            35usize => Some(
+                Field::new(
+                    "fraction_denominator_display_style_shift_down",
+                    traversal::FieldType::Record(
+                        self.fraction_denominator_display_style_shift_down()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape
+                        .fraction_denominator_display_style_shift_down_byte_range(),
+                ),
+            ),
+            36usize => Some(
+                Field::new(
+                    "fraction_numerator_gap_min",
+                    traversal::FieldType::Record(
+                        self.fraction_numerator_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.fraction_numerator_gap_min_byte_range(),
+                ),
+            ),
+            37usize => Some(
+                Field::new(
+                    "fraction_num_display_style_gap_min",
+                    traversal::FieldType::Record(
+                        self.fraction_num_display_style_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.fraction_num_display_style_gap_min_byte_range(),
+                ),
+            ),
+            38usize => Some(
+                Field::new(
+                    "fraction_rule_thickness",
+                    traversal::FieldType::Record(
+                        self.fraction_rule_thickness()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.fraction_rule_thickness_byte_range()),
+            ),
+            39usize => Some(
+                Field::new(
+                    "fraction_denominator_gap_min",
+                    traversal::FieldType::Record(
+                        self.fraction_denominator_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.fraction_denominator_gap_min_byte_range(),
+                ),
+            ),
+            40usize => Some(
+                Field::new(
+                    "fraction_denom_display_style_gap_min",
+                    traversal::FieldType::Record(
+                        self.fraction_denom_display_style_gap_min()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.fraction_denom_display_style_gap_min_byte_range(),
+                ),
+            ),
+            41usize => Some(
+                Field::new(
+                    "skewed_fraction_horizontal_gap",
+                    traversal::FieldType::Record(
+                        self.skewed_fraction_horizontal_gap()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.skewed_fraction_horizontal_gap_byte_range(),
+                ),
+            ),
+            42usize => Some(
+                Field::new(
+                    "skewed_fraction_vertical_gap",
+                    traversal::FieldType::Record(
+                        self.skewed_fraction_vertical_gap()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.skewed_fraction_vertical_gap_byte_range(),
+                ),
+            ),
+            43usize => Some(
+                Field::new(
+                    "overbar_vertical_gap",
+                    traversal::FieldType::Record(
+                        self.overbar_vertical_gap()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.overbar_vertical_gap_byte_range()),
+            ),
+            44usize => Some(
+                Field::new(
+                    "overbar_rule_thickness",
+                    traversal::FieldType::Record(
+                        self.overbar_rule_thickness()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.overbar_rule_thickness_byte_range()),
+            ),
+            45usize => Some(
+                Field::new(
+                    "overbar_extra_ascender",
+                    traversal::FieldType::Record(
+                        self.overbar_extra_ascender()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.overbar_extra_ascender_byte_range()),
+            ),
+            46usize => Some(
+                Field::new(
+                    "underbar_vertical_gap",
+                    traversal::FieldType::Record(
+                        self.underbar_vertical_gap()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.underbar_vertical_gap_byte_range()),
+            ),
+            47usize => Some(
+                Field::new(
+                    "underbar_rule_thickness",
+                    traversal::FieldType::Record(
+                        self.underbar_rule_thickness()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.underbar_rule_thickness_byte_range()),
+            ),
+            48usize => Some(
+                Field::new(
+                    "underbar_extra_descender",
+                    traversal::FieldType::Record(
+                        self.underbar_extra_descender()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.underbar_extra_descender_byte_range()),
+            ),
+            49usize => Some(
+                Field::new(
+                    "radical_vertical_gap",
+                    traversal::FieldType::Record(
+                        self.radical_vertical_gap()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.radical_vertical_gap_byte_range()),
+            ),
+            50usize => Some(
+                Field::new(
+                    "radical_display_style_vertical_gap",
+                    traversal::FieldType::Record(
+                        self.radical_display_style_vertical_gap()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.radical_display_style_vertical_gap_byte_range(),
+                ),
+            ),
+            51usize => Some(
+                Field::new(
+                    "radical_rule_thickness",
+                    traversal::FieldType::Record(
+                        self.radical_rule_thickness()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.radical_rule_thickness_byte_range()),
+            ),
+            52usize => Some(
+                Field::new(
+                    "radical_extra_ascender",
+                    traversal::FieldType::Record(
+                        self.radical_extra_ascender()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.radical_extra_ascender_byte_range()),
+            ),
+            53usize => Some(
+                Field::new(
+                    "radical_kern_before_degree",
+                    traversal::FieldType::Record(
+                        self.radical_kern_before_degree()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.radical_kern_before_degree_byte_range(),
+                ),
+            ),
+            54usize => Some(
+                Field::new(
+                    "radical_kern_after_degree",
+                    traversal::FieldType::Record(
+                        self.radical_kern_after_degree()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.radical_kern_after_degree_byte_range()),
+            ),
+            55usize => Some(
+                Field::new(
+                    "radical_degree_bottom_raise_percent",
+                    self.radical_degree_bottom_raise_percent(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.radical_degree_bottom_raise_percent_byte_range(),
+                ),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathConstants<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [MathGlyphInfo](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathglyphinfo-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathGlyphInfoMarker {}
+
+impl MathGlyphInfoMarker {
+    fn math_italics_correction_info_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn math_top_accent_attachment_offset_byte_range(&self) -> Range<usize> {
+        let start = self.math_italics_correction_info_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn extended_shape_coverage_offset_byte_range(&self) -> Range<usize> {
+        let start = self.math_top_accent_attachment_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn math_kern_info_offset_byte_range(&self) -> Range<usize> {
+        let start = self.extended_shape_coverage_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for MathGlyphInfo<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        cursor.finish(MathGlyphInfoMarker {})
+    }
+}
+
+/// The [MathGlyphInfo](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathglyphinfo-table) table.
+pub type MathGlyphInfo<'a> = TableRef<'a, MathGlyphInfoMarker>;
+
+impl<'a> MathGlyphInfo<'a> {
+    /// Offset to MathItalicsCorrectionInfo table, from the beginning of
+    /// the MathGlyphInfo table.
+    pub fn math_italics_correction_info_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.math_italics_correction_info_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`math_italics_correction_info_offset`][Self::math_italics_correction_info_offset].
+    pub fn math_italics_correction_info(
+        &self,
+    ) -> Option<Result<MathItalicsCorrectionInfo<'a>, ReadError>> {
+        let data = self.data;
+        self.math_italics_correction_info_offset().resolve(data)
+    }
+
+    /// Offset to MathTopAccentAttachment table, from the beginning of the
+    /// MathGlyphInfo table.
+    pub fn math_top_accent_attachment_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.math_top_accent_attachment_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`math_top_accent_attachment_offset`][Self::math_top_accent_attachment_offset].
+    pub fn math_top_accent_attachment(
+        &self,
+    ) -> Option<Result<MathTopAccentAttachment<'a>, ReadError>> {
+        let data = self.data;
+        self.math_top_accent_attachment_offset().resolve(data)
+    }
+
+    /// Offset to ExtendedShapes coverage table, from the beginning of the
+    /// MathGlyphInfo table. When the glyph to the left or right of a box
+    /// is an extended shape variant, the (ink) box should be used for
+    /// vertical positioning purposes, not the default position defined by
+    /// values in MathConstants table. May be NULL.
+    pub fn extended_shape_coverage_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.extended_shape_coverage_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`extended_shape_coverage_offset`][Self::extended_shape_coverage_offset].
+    pub fn extended_shape_coverage(&self) -> Option<Result<CoverageTable<'a>, ReadError>> {
+        let data = self.data;
+        self.extended_shape_coverage_offset().resolve(data)
+    }
+
+    /// Offset to MathKernInfo table, from the beginning of the
+    /// MathGlyphInfo table.
+    pub fn math_kern_info_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.math_kern_info_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`math_kern_info_offset`][Self::math_kern_info_offset].
+    pub fn math_kern_info(&self) -> Option<Result<MathKernInfo<'a>, ReadError>> {
+        let data = self.data;
+        self.math_kern_info_offset().resolve(data)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathGlyphInfo<'a> {
+    fn type_name(&self) -> &str {
+        "MathGlyphInfo"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new(
+                    "math_italics_correction_info_offset",
+                    FieldType::offset(
+                        self.math_italics_correction_info_offset(),
+                        self.math_italics_correction_info(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.math_italics_correction_info_offset_byte_range(),
+                ),
+            ),
+            1usize => Some(
+                Field::new(
+                    "math_top_accent_attachment_offset",
+                    FieldType::offset(
+                        self.math_top_accent_attachment_offset(),
+                        self.math_top_accent_attachment(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.math_top_accent_attachment_offset_byte_range(),
+                ),
+            ),
+            2usize => Some(
+                Field::new(
+                    "extended_shape_coverage_offset",
+                    FieldType::offset(
+                        self.extended_shape_coverage_offset(),
+                        self.extended_shape_coverage(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.extended_shape_coverage_offset_byte_range(),
+                ),
+            ),
+            3usize => Some(
+                Field::new(
+                    "math_kern_info_offset",
+                    FieldType::offset(self.math_kern_info_offset(), self.math_kern_info()),
+                )
+                .with_bytes(self.data, self.shape.math_kern_info_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathGlyphInfo<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [MathItalicsCorrectionInfo](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathitalicscorrectioninfo-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathItalicsCorrectionInfoMarker {
+    italics_correction_byte_len: usize,
+}
+
+impl MathItalicsCorrectionInfoMarker {
+    fn italics_correction_coverage_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn italics_correction_count_byte_range(&self) -> Range<usize> {
+        let start = self.italics_correction_coverage_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn italics_correction_byte_range(&self) -> Range<usize> {
+        let start = self.italics_correction_count_byte_range().end;
+        start..start + self.italics_correction_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for MathItalicsCorrectionInfo<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        let italics_correction_count: u16 = cursor.read()?;
+        let italics_correction_byte_len =
+            italics_correction_count as usize * MathValueRecord::RAW_BYTE_LEN;
+        cursor.advance_by(italics_correction_byte_len);
+        cursor.finish(MathItalicsCorrectionInfoMarker {
+            italics_correction_byte_len,
+        })
+    }
+}
+
+/// The [MathItalicsCorrectionInfo](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathitalicscorrectioninfo-table) table.
+pub type MathItalicsCorrectionInfo<'a> = TableRef<'a, MathItalicsCorrectionInfoMarker>;
+
+impl<'a> MathItalicsCorrectionInfo<'a> {
+    /// Offset to Coverage table, from the beginning of
+    /// MathItalicsCorrectionInfo table.
+    pub fn italics_correction_coverage_offset(&self) -> Offset16 {
+        let range = self.shape.italics_correction_coverage_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`italics_correction_coverage_offset`][Self::italics_correction_coverage_offset].
+    pub fn italics_correction_coverage(&self) -> Result<CoverageTable<'a>, ReadError> {
+        let data = self.data;
+        self.italics_correction_coverage_offset().resolve(data)
+    }
+
+    /// Number of italics correction values. Should coincide with the
+    /// number of covered glyphs.
+    pub fn italics_correction_count(&self) -> u16 {
+        let range = self.shape.italics_correction_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of MathValueRecords defining italics correction values for
+    /// each covered glyph.
+    pub fn italics_correction(&self) -> &'a [MathValueRecord] {
+        let range = self.shape.italics_correction_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathItalicsCorrectionInfo<'a> {
+    fn type_name(&self) -> &str {
+        "MathItalicsCorrectionInfo"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new(
+                    "italics_correction_coverage_offset",
+                    FieldType::offset(
+                        self.italics_correction_coverage_offset(),
+                        self.italics_correction_coverage(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.italics_correction_coverage_offset_byte_range(),
+                ),
+            ),
+            1usize => Some(
+                Field::new("italics_correction_count", self.italics_correction_count())
+                    .with_bytes(self.data, self.shape.italics_correction_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "italics_correction",
+                    traversal::FieldType::array_of_records(
+                        stringify!(MathValueRecord),
+                        self.italics_correction(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.italics_correction_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathItalicsCorrectionInfo<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [MathTopAccentAttachment](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathtopaccentattachment-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathTopAccentAttachmentMarker {
+    top_accent_attachment_byte_len: usize,
+}
+
+impl MathTopAccentAttachmentMarker {
+    fn top_accent_coverage_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn top_accent_attachment_count_byte_range(&self) -> Range<usize> {
+        let start = self.top_accent_coverage_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn top_accent_attachment_byte_range(&self) -> Range<usize> {
+        let start = self.top_accent_attachment_count_byte_range().end;
+        start..start + self.top_accent_attachment_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for MathTopAccentAttachment<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        let top_accent_attachment_count: u16 = cursor.read()?;
+        let top_accent_attachment_byte_len =
+            top_accent_attachment_count as usize * MathValueRecord::RAW_BYTE_LEN;
+        cursor.advance_by(top_accent_attachment_byte_len);
+        cursor.finish(MathTopAccentAttachmentMarker {
+            top_accent_attachment_byte_len,
+        })
+    }
+}
+
+/// The [MathTopAccentAttachment](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathtopaccentattachment-table) table.
+pub type MathTopAccentAttachment<'a> = TableRef<'a, MathTopAccentAttachmentMarker>;
+
+impl<'a> MathTopAccentAttachment<'a> {
+    /// Offset to Coverage table, from the beginning of
+    /// MathTopAccentAttachment table.
+    pub fn top_accent_coverage_offset(&self) -> Offset16 {
+        let range = self.shape.top_accent_coverage_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`top_accent_coverage_offset`][Self::top_accent_coverage_offset].
+    pub fn top_accent_coverage(&self) -> Result<CoverageTable<'a>, ReadError> {
+        let data = self.data;
+        self.top_accent_coverage_offset().resolve(data)
+    }
+
+    /// Number of top accent attachment point values. Must be the same as
+    /// the number of glyph IDs referenced in the Coverage table.
+    pub fn top_accent_attachment_count(&self) -> u16 {
+        let range = self.shape.top_accent_attachment_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of MathValueRecords defining top accent attachment points for
+    /// each covered glyph.
+    pub fn top_accent_attachment(&self) -> &'a [MathValueRecord] {
+        let range = self.shape.top_accent_attachment_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathTopAccentAttachment<'a> {
+    fn type_name(&self) -> &str {
+        "MathTopAccentAttachment"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new(
+                    "top_accent_coverage_offset",
+                    FieldType::offset(
+                        self.top_accent_coverage_offset(),
+                        self.top_accent_coverage(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.top_accent_coverage_offset_byte_range(),
+                ),
+            ),
+            1usize => Some(
+                Field::new(
+                    "top_accent_attachment_count",
+                    self.top_accent_attachment_count(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.top_accent_attachment_count_byte_range(),
+                ),
+            ),
+            2usize => Some(
+                Field::new(
+                    "top_accent_attachment",
+                    traversal::FieldType::array_of_records(
+                        stringify!(MathValueRecord),
+                        self.top_accent_attachment(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.top_accent_attachment_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathTopAccentAttachment<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [MathKernInfo](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathkerninfo-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathKernInfoMarker {
+    math_kern_info_records_byte_len: usize,
+}
+
+impl MathKernInfoMarker {
+    fn math_kern_coverage_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn math_kern_count_byte_range(&self) -> Range<usize> {
+        let start = self.math_kern_coverage_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn math_kern_info_records_byte_range(&self) -> Range<usize> {
+        let start = self.math_kern_count_byte_range().end;
+        start..start + self.math_kern_info_records_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for MathKernInfo<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        let math_kern_count: u16 = cursor.read()?;
+        let math_kern_info_records_byte_len =
+            math_kern_count as usize * MathKernInfoRecord::RAW_BYTE_LEN;
+        cursor.advance_by(math_kern_info_records_byte_len);
+        cursor.finish(MathKernInfoMarker {
+            math_kern_info_records_byte_len,
+        })
+    }
+}
+
+/// The [MathKernInfo](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathkerninfo-table) table.
+pub type MathKernInfo<'a> = TableRef<'a, MathKernInfoMarker>;
+
+impl<'a> MathKernInfo<'a> {
+    /// Offset to Coverage table, from the beginning of the MathKernInfo
+    /// table.
+    pub fn math_kern_coverage_offset(&self) -> Offset16 {
+        let range = self.shape.math_kern_coverage_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`math_kern_coverage_offset`][Self::math_kern_coverage_offset].
+    pub fn math_kern_coverage(&self) -> Result<CoverageTable<'a>, ReadError> {
+        let data = self.data;
+        self.math_kern_coverage_offset().resolve(data)
+    }
+
+    /// Number of MathKernInfoRecords. Must be the same as the number of
+    /// glyph IDs referenced in the Coverage table.
+    pub fn math_kern_count(&self) -> u16 {
+        let range = self.shape.math_kern_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of MathKernInfoRecords, one for each covered glyph.
+    pub fn math_kern_info_records(&self) -> &'a [MathKernInfoRecord] {
+        let range = self.shape.math_kern_info_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathKernInfo<'a> {
+    fn type_name(&self) -> &str {
+        "MathKernInfo"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new(
+                    "math_kern_coverage_offset",
+                    FieldType::offset(self.math_kern_coverage_offset(), self.math_kern_coverage()),
+                )
+                .with_bytes(self.data, self.shape.math_kern_coverage_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("math_kern_count", self.math_kern_count())
+                    .with_bytes(self.data, self.shape.math_kern_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "math_kern_info_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(MathKernInfoRecord),
+                        self.math_kern_info_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.math_kern_info_records_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathKernInfo<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The kern tables for each corner of a glyph.
+#[derive(Clone, Debug)]
+#[repr(C)]
+#[repr(packed)]
+pub struct MathKernInfoRecord {
+    /// Offset to MathKern table for top right corner, from the beginning
+    /// of the MathKernInfo table. May be NULL.
+    pub top_right_math_kern_offset: BigEndian<Nullable<Offset16>>,
+    /// Offset to MathKern table for the top left corner, from the
+    /// beginning of the MathKernInfo table. May be NULL.
+    pub top_left_math_kern_offset: BigEndian<Nullable<Offset16>>,
+    /// Offset to MathKern table for bottom right corner, from the
+    /// beginning of the MathKernInfo table. May be NULL.
+    pub bottom_right_math_kern_offset: BigEndian<Nullable<Offset16>>,
+    /// Offset to MathKern table for bottom left corner, from the
+    /// beginning of the MathKernInfo table. May be NULL.
+    pub bottom_left_math_kern_offset: BigEndian<Nullable<Offset16>>,
+}
+
+impl MathKernInfoRecord {
+    /// Offset to MathKern table for top right corner, from the beginning
+    /// of the MathKernInfo table. May be NULL.
+    pub fn top_right_math_kern_offset(&self) -> Nullable<Offset16> {
+        self.top_right_math_kern_offset.get()
+    }
+
+    /// Attempt to resolve [`top_right_math_kern_offset`][Self::top_right_math_kern_offset].
+    pub fn top_right_math_kern<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<MathKern<'a>, ReadError>> {
+        self.top_right_math_kern_offset().resolve(data)
+    }
+
+    /// Offset to MathKern table for the top left corner, from the
+    /// beginning of the MathKernInfo table. May be NULL.
+    pub fn top_left_math_kern_offset(&self) -> Nullable<Offset16> {
+        self.top_left_math_kern_offset.get()
+    }
+
+    /// Attempt to resolve [`top_left_math_kern_offset`][Self::top_left_math_kern_offset].
+    pub fn top_left_math_kern<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<MathKern<'a>, ReadError>> {
+        self.top_left_math_kern_offset().resolve(data)
+    }
+
+    /// Offset to MathKern table for bottom right corner, from the
+    /// beginning of the MathKernInfo table. May be NULL.
+    pub fn bottom_right_math_kern_offset(&self) -> Nullable<Offset16> {
+        self.bottom_right_math_kern_offset.get()
+    }
+
+    /// Attempt to resolve [`bottom_right_math_kern_offset`][Self::bottom_right_math_kern_offset].
+    pub fn bottom_right_math_kern<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<MathKern<'a>, ReadError>> {
+        self.bottom_right_math_kern_offset().resolve(data)
+    }
+
+    /// Offset to MathKern table for bottom left corner, from the
+    /// beginning of the MathKernInfo table. May be NULL.
+    pub fn bottom_left_math_kern_offset(&self) -> Nullable<Offset16> {
+        self.bottom_left_math_kern_offset.get()
+    }
+
+    /// Attempt to resolve [`bottom_left_math_kern_offset`][Self::bottom_left_math_kern_offset].
+    pub fn bottom_left_math_kern<'a>(
+        &self,
+        data: FontData<'a>,
+    ) -> Option<Result<MathKern<'a>, ReadError>> {
+        self.bottom_left_math_kern_offset().resolve(data)
+    }
+}
+
+impl FixedSize for MathKernInfoRecord {
+    const RAW_BYTE_LEN: usize = Offset16::RAW_BYTE_LEN
+        + Offset16::RAW_BYTE_LEN
+        + Offset16::RAW_BYTE_LEN
+        + Offset16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for MathKernInfoRecord {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for MathKernInfoRecord {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for MathKernInfoRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "MathKernInfoRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new(
+                        "top_right_math_kern_offset",
+                        FieldType::offset(
+                            self.top_right_math_kern_offset(),
+                            self.top_right_math_kern(_data),
+                        ),
+                    )
+                    .with_record_range(0, Offset16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "top_left_math_kern_offset",
+                        FieldType::offset(
+                            self.top_left_math_kern_offset(),
+                            self.top_left_math_kern(_data),
+                        ),
+                    )
+                    .with_record_range(Offset16::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new(
+                        "bottom_right_math_kern_offset",
+                        FieldType::offset(
+                            self.bottom_right_math_kern_offset(),
+                            self.bottom_right_math_kern(_data),
+                        ),
+                    )
+                    .with_record_range(
+                        Offset16::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN,
+                        Offset16::RAW_BYTE_LEN,
+                    ),
+                ),
+                3usize => Some(
+                    Field::new(
+                        "bottom_left_math_kern_offset",
+                        FieldType::offset(
+                            self.bottom_left_math_kern_offset(),
+                            self.bottom_left_math_kern(_data),
+                        ),
+                    )
+                    .with_record_range(
+                        Offset16::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN,
+                        Offset16::RAW_BYTE_LEN,
+                    ),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// The [MathKern](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathkern-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathKernMarker {
+    correction_height_byte_len: usize,
+    kern_values_byte_len: usize,
+}
+
+impl MathKernMarker {
+    fn height_count_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn correction_height_byte_range(&self) -> Range<usize> {
+        let start = self.height_count_byte_range().end;
+        start..start + self.correction_height_byte_len
+    }
+    fn kern_values_byte_range(&self) -> Range<usize> {
+        let start = self.correction_height_byte_range().end;
+        start..start + self.kern_values_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for MathKern<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let height_count: u16 = cursor.read()?;
+        let correction_height_byte_len = height_count as usize * MathValueRecord::RAW_BYTE_LEN;
+        cursor.advance_by(correction_height_byte_len);
+        let kern_values_byte_len =
+            transforms::add(height_count, 1_usize) * MathValueRecord::RAW_BYTE_LEN;
+        cursor.advance_by(kern_values_byte_len);
+        cursor.finish(MathKernMarker {
+            correction_height_byte_len,
+            kern_values_byte_len,
+        })
+    }
+}
+
+/// The [MathKern](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathkern-table) table.
+pub type MathKern<'a> = TableRef<'a, MathKernMarker>;
+
+impl<'a> MathKern<'a> {
+    /// Number of heights at which the kern value changes.
+    pub fn height_count(&self) -> u16 {
+        let range = self.shape.height_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of correction heights, in design units, sorted from lowest to
+    /// highest.
+    pub fn correction_height(&self) -> &'a [MathValueRecord] {
+        let range = self.shape.correction_height_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Array of kerning values for different height ranges. Negative
+    /// values are used to move glyphs closer to each other.
+    pub fn kern_values(&self) -> &'a [MathValueRecord] {
+        let range = self.shape.kern_values_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathKern<'a> {
+    fn type_name(&self) -> &str {
+        "MathKern"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("height_count", self.height_count())
+                    .with_bytes(self.data, self.shape.height_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "correction_height",
+                    traversal::FieldType::array_of_records(
+                        stringify!(MathValueRecord),
+                        self.correction_height(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.correction_height_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "kern_values",
+                    traversal::FieldType::array_of_records(
+                        stringify!(MathValueRecord),
+                        self.kern_values(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.kern_values_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathKern<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [MathVariants](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathvariants-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathVariantsMarker {
+    vert_glyph_construction_offsets_byte_len: usize,
+    horiz_glyph_construction_offsets_byte_len: usize,
+}
+
+impl MathVariantsMarker {
+    fn min_connector_overlap_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + UfWord::RAW_BYTE_LEN
+    }
+    fn vert_glyph_coverage_offset_byte_range(&self) -> Range<usize> {
+        let start = self.min_connector_overlap_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn horiz_glyph_coverage_offset_byte_range(&self) -> Range<usize> {
+        let start = self.vert_glyph_coverage_offset_byte_range().end;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn vert_glyph_count_byte_range(&self) -> Range<usize> {
+        let start = self.horiz_glyph_coverage_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn horiz_glyph_count_byte_range(&self) -> Range<usize> {
+        let start = self.vert_glyph_count_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn vert_glyph_construction_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.horiz_glyph_count_byte_range().end;
+        start..start + self.vert_glyph_construction_offsets_byte_len
+    }
+    fn horiz_glyph_construction_offsets_byte_range(&self) -> Range<usize> {
+        let start = self.vert_glyph_construction_offsets_byte_range().end;
+        start..start + self.horiz_glyph_construction_offsets_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for MathVariants<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<UfWord>();
+        cursor.advance::<Offset16>();
+        cursor.advance::<Offset16>();
+        let vert_glyph_count: u16 = cursor.read()?;
+        let horiz_glyph_count: u16 = cursor.read()?;
+        let vert_glyph_construction_offsets_byte_len =
+            vert_glyph_count as usize * Offset16::RAW_BYTE_LEN;
+        cursor.advance_by(vert_glyph_construction_offsets_byte_len);
+        let horiz_glyph_construction_offsets_byte_len =
+            horiz_glyph_count as usize * Offset16::RAW_BYTE_LEN;
+        cursor.advance_by(horiz_glyph_construction_offsets_byte_len);
+        cursor.finish(MathVariantsMarker {
+            vert_glyph_construction_offsets_byte_len,
+            horiz_glyph_construction_offsets_byte_len,
+        })
+    }
+}
+
+/// The [MathVariants](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathvariants-table) table.
+pub type MathVariants<'a> = TableRef<'a, MathVariantsMarker>;
+
+impl<'a> MathVariants<'a> {
+    /// Minimum overlap of connecting glyphs during glyph construction, in
+    /// design units.
+    pub fn min_connector_overlap(&self) -> UfWord {
+        let range = self.shape.min_connector_overlap_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to Coverage table, from the beginning of the MathVariants
+    /// table.
+    pub fn vert_glyph_coverage_offset(&self) -> Offset16 {
+        let range = self.shape.vert_glyph_coverage_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`vert_glyph_coverage_offset`][Self::vert_glyph_coverage_offset].
+    pub fn vert_glyph_coverage(&self) -> Result<CoverageTable<'a>, ReadError> {
+        let data = self.data;
+        self.vert_glyph_coverage_offset().resolve(data)
+    }
+
+    /// Offset to Coverage table, from the beginning of the MathVariants
+    /// table.
+    pub fn horiz_glyph_coverage_offset(&self) -> Offset16 {
+        let range = self.shape.horiz_glyph_coverage_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`horiz_glyph_coverage_offset`][Self::horiz_glyph_coverage_offset].
+    pub fn horiz_glyph_coverage(&self) -> Result<CoverageTable<'a>, ReadError> {
+        let data = self.data;
+        self.horiz_glyph_coverage_offset().resolve(data)
+    }
+
+    /// Number of glyphs for which information is provided for vertically
+    /// growing variants. Must be the same as the number of glyph IDs
+    /// referenced in the vertical Coverage table.
+    pub fn vert_glyph_count(&self) -> u16 {
+        let range = self.shape.vert_glyph_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of glyphs for which information is provided for horizontally
+    /// growing variants. Must be the same as the number of glyph IDs
+    /// referenced in the horizontal Coverage table.
+    pub fn horiz_glyph_count(&self) -> u16 {
+        let range = self.shape.horiz_glyph_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of offsets to MathGlyphConstruction tables, from the
+    /// beginning of the MathVariants table, for shapes growing in the
+    /// vertical direction.
+    pub fn vert_glyph_construction_offsets(&self) -> &'a [BigEndian<Nullable<Offset16>>] {
+        let range = self.shape.vert_glyph_construction_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// A dynamically resolving wrapper for [`vert_glyph_construction_offsets`][Self::vert_glyph_construction_offsets].
+    pub fn vert_glyph_constructions(
+        &self,
+    ) -> ArrayOfNullableOffsets<'a, MathGlyphConstruction<'a>, Offset16> {
+        let data = self.data;
+        let offsets = self.vert_glyph_construction_offsets();
+        ArrayOfNullableOffsets::new(offsets, data, ())
+    }
+
+    /// Array of offsets to MathGlyphConstruction tables, from the
+    /// beginning of the MathVariants table, for shapes growing in the
+    /// horizontal direction.
+    pub fn horiz_glyph_construction_offsets(&self) -> &'a [BigEndian<Nullable<Offset16>>] {
+        let range = self.shape.horiz_glyph_construction_offsets_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// A dynamically resolving wrapper for [`horiz_glyph_construction_offsets`][Self::horiz_glyph_construction_offsets].
+    pub fn horiz_glyph_constructions(
+        &self,
+    ) -> ArrayOfNullableOffsets<'a, MathGlyphConstruction<'a>, Offset16> {
+        let data = self.data;
+        let offsets = self.horiz_glyph_construction_offsets();
+        ArrayOfNullableOffsets::new(offsets, data, ())
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathVariants<'a> {
+    fn type_name(&self) -> &str {
+        "MathVariants"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("min_connector_overlap", self.min_connector_overlap())
+                    .with_bytes(self.data, self.shape.min_connector_overlap_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "vert_glyph_coverage_offset",
+                    FieldType::offset(
+                        self.vert_glyph_coverage_offset(),
+                        self.vert_glyph_coverage(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.vert_glyph_coverage_offset_byte_range(),
+                ),
+            ),
+            2usize => Some(
+                Field::new(
+                    "horiz_glyph_coverage_offset",
+                    FieldType::offset(
+                        self.horiz_glyph_coverage_offset(),
+                        self.horiz_glyph_coverage(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.horiz_glyph_coverage_offset_byte_range(),
+                ),
+            ),
+            3usize => Some(
+                Field::new("vert_glyph_count", self.vert_glyph_count())
+                    .with_bytes(self.data, self.shape.vert_glyph_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("horiz_glyph_count", self.horiz_glyph_count())
+                    .with_bytes(self.data, self.shape.horiz_glyph_count_byte_range()),
+            ),
+            5usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "vert_glyph_construction_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<MathGlyphConstruction>(),
+                            self.vert_glyph_construction_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<MathGlyphConstruction>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.vert_glyph_construction_offsets_byte_range(),
+                ),
+            ),
+            6usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "horiz_glyph_construction_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<MathGlyphConstruction>(),
+                            self.horiz_glyph_construction_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<MathGlyphConstruction>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.horiz_glyph_construction_offsets_byte_range(),
+                ),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathVariants<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// The [MathGlyphConstruction](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathglyphconstruction-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MathGlyphConstructionMarker {
+    math_glyph_variant_records_byte_len: usize,
+}
+
+impl MathGlyphConstructionMarker {
+    fn glyph_assembly_offset_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Offset16::RAW_BYTE_LEN
+    }
+    fn variant_count_byte_range(&self) -> Range<usize> {
+        let start = self.glyph_assembly_offset_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn math_glyph_variant_records_byte_range(&self) -> Range<usize> {
+        let start = self.variant_count_byte_range().end;
+        start..start + self.math_glyph_variant_records_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for MathGlyphConstruction<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Offset16>();
+        let variant_count: u16 = cursor.read()?;
+        let math_glyph_variant_records_byte_len =
+            variant_count as usize * MathGlyphVariantRecord::RAW_BYTE_LEN;
+        cursor.advance_by(math_glyph_variant_records_byte_len);
+        cursor.finish(MathGlyphConstructionMarker {
+            math_glyph_variant_records_byte_len,
+        })
+    }
+}
+
+/// The [MathGlyphConstruction](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathglyphconstruction-table) table.
+pub type MathGlyphConstruction<'a> = TableRef<'a, MathGlyphConstructionMarker>;
+
+impl<'a> MathGlyphConstruction<'a> {
+    /// Offset to the GlyphAssembly table for this shape, from the
+    /// beginning of the MathGlyphConstruction table. May be NULL.
+    pub fn glyph_assembly_offset(&self) -> Nullable<Offset16> {
+        let range = self.shape.glyph_assembly_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`glyph_assembly_offset`][Self::glyph_assembly_offset].
+    pub fn glyph_assembly(&self) -> Option<Result<GlyphAssembly<'a>, ReadError>> {
+        let data = self.data;
+        self.glyph_assembly_offset().resolve(data)
+    }
+
+    /// Count of glyph growing variants for this glyph.
+    pub fn variant_count(&self) -> u16 {
+        let range = self.shape.variant_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// MathGlyphVariantRecords for alternative variants of the glyphs.
+    pub fn math_glyph_variant_records(&self) -> &'a [MathGlyphVariantRecord] {
+        let range = self.shape.math_glyph_variant_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for MathGlyphConstruction<'a> {
+    fn type_name(&self) -> &str {
+        "MathGlyphConstruction"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new(
+                    "glyph_assembly_offset",
+                    FieldType::offset(self.glyph_assembly_offset(), self.glyph_assembly()),
+                )
+                .with_bytes(self.data, self.shape.glyph_assembly_offset_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("variant_count", self.variant_count())
+                    .with_bytes(self.data, self.shape.variant_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "math_glyph_variant_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(MathGlyphVariantRecord),
+                        self.math_glyph_variant_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.math_glyph_variant_records_byte_range(),
+                ),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for MathGlyphConstruction<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A variant of a glyph and its size.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct MathGlyphVariantRecord {
+    /// Glyph ID for the variant.
+    pub variant_glyph: BigEndian<GlyphId>,
+    /// Advance width/height, in design units, of the variant, in the
+    /// direction of requested glyph extension.
+    pub advance_measurement: BigEndian<UfWord>,
+}
+
+impl MathGlyphVariantRecord {
+    /// Glyph ID for the variant.
+    pub fn variant_glyph(&self) -> GlyphId {
+        self.variant_glyph.get()
+    }
+
+    /// Advance width/height, in design units, of the variant, in the
+    /// direction of requested glyph extension.
+    pub fn advance_measurement(&self) -> UfWord {
+        self.advance_measurement.get()
+    }
+}
+
+impl FixedSize for MathGlyphVariantRecord {
+    const RAW_BYTE_LEN: usize = GlyphId::RAW_BYTE_LEN + UfWord::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for MathGlyphVariantRecord {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for MathGlyphVariantRecord {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for MathGlyphVariantRecord {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "MathGlyphVariantRecord",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("variant_glyph", self.variant_glyph())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("advance_measurement", self.advance_measurement())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, UfWord::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// The [GlyphAssembly](https://learn.microsoft.com/en-us/typography/opentype/spec/math#glyphassembly-table) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct GlyphAssemblyMarker {
+    part_records_byte_len: usize,
+}
+
+impl GlyphAssemblyMarker {
+    fn italics_correction_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + MathValueRecord::RAW_BYTE_LEN
+    }
+    fn part_count_byte_range(&self) -> Range<usize> {
+        let start = self.italics_correction_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn part_records_byte_range(&self) -> Range<usize> {
+        let start = self.part_count_byte_range().end;
+        start..start + self.part_records_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for GlyphAssembly<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance_by(MathValueRecord::RAW_BYTE_LEN);
+        let part_count: u16 = cursor.read()?;
+        let part_records_byte_len = part_count as usize * GlyphPart::RAW_BYTE_LEN;
+        cursor.advance_by(part_records_byte_len);
+        cursor.finish(GlyphAssemblyMarker {
+            part_records_byte_len,
+        })
+    }
+}
+
+/// The [GlyphAssembly](https://learn.microsoft.com/en-us/typography/opentype/spec/math#glyphassembly-table) table.
+pub type GlyphAssembly<'a> = TableRef<'a, GlyphAssemblyMarker>;
+
+impl<'a> GlyphAssembly<'a> {
+    /// Italics correction of this GlyphAssembly. Should not depend on the
+    /// assembly size.
+    pub fn italics_correction(&self) -> &'a MathValueRecord {
+        let range = self.shape.italics_correction_byte_range();
+        self.data.read_ref_at(range.start).unwrap()
+    }
+
+    /// Number of parts in this assembly.
+    pub fn part_count(&self) -> u16 {
+        let range = self.shape.part_count_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Array of GlyphPart records, from left to right (for assemblies that
+    /// extend horizontally) or bottom to top (for assemblies that extend
+    /// vertically).
+    pub fn part_records(&self) -> &'a [GlyphPart] {
+        let range = self.shape.part_records_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for GlyphAssembly<'a> {
+    fn type_name(&self) -> &str {
+        "GlyphAssembly"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new(
+                    "italics_correction",
+                    traversal::FieldType::Record(
+                        self.italics_correction()
+                            .clone()
+                            .traverse(self.offset_data()),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.italics_correction_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("part_count", self.part_count())
+                    .with_bytes(self.data, self.shape.part_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "part_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(GlyphPart),
+                        self.part_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.part_records_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for GlyphAssembly<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A part of a glyph assembly.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct GlyphPart {
+    /// Glyph ID for the part.
+    pub glyph_id: BigEndian<GlyphId>,
+    /// Advance width/ height, in design units, of the straight bar
+    /// connector material at the start of the glyph in the direction of
+    /// the extension (the left end for horizontal extension, the bottom
+    /// end for vertical extension).
+    pub start_connector_length: BigEndian<UfWord>,
+    /// Advance width/ height, in design units, of the straight bar
+    /// connector material at the end of the glyph in the direction of the
+    /// extension (the right end for horizontal extension, the top end for
+    /// vertical extension).
+    pub end_connector_length: BigEndian<UfWord>,
+    /// Full advance width/height for this part in the direction of the
+    /// extension, in design units.
+    pub full_advance: BigEndian<UfWord>,
+    /// Part qualifiers.
+    pub part_flags: BigEndian<GlyphPartFlags>,
+}
+
+impl GlyphPart {
+    /// Glyph ID for the part.
+    pub fn glyph_id(&self) -> GlyphId {
+        self.glyph_id.get()
+    }
+
+    /// Advance width/ height, in design units, of the straight bar
+    /// connector material at the start of the glyph in the direction of
+    /// the extension (the left end for horizontal extension, the bottom
+    /// end for vertical extension).
+    pub fn start_connector_length(&self) -> UfWord {
+        self.start_connector_length.get()
+    }
+
+    /// Advance width/ height, in design units, of the straight bar
+    /// connector material at the end of the glyph in the direction of the
+    /// extension (the right end for horizontal extension, the top end for
+    /// vertical extension).
+    pub fn end_connector_length(&self) -> UfWord {
+        self.end_connector_length.get()
+    }
+
+    /// Full advance width/height for this part in the direction of the
+    /// extension, in design units.
+    pub fn full_advance(&self) -> UfWord {
+        self.full_advance.get()
+    }
+
+    /// Part qualifiers.
+    pub fn part_flags(&self) -> GlyphPartFlags {
+        self.part_flags.get()
+    }
+}
+
+impl FixedSize for GlyphPart {
+    const RAW_BYTE_LEN: usize = GlyphId::RAW_BYTE_LEN
+        + UfWord::RAW_BYTE_LEN
+        + UfWord::RAW_BYTE_LEN
+        + UfWord::RAW_BYTE_LEN
+        + GlyphPartFlags::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for GlyphPart {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for GlyphPart {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for GlyphPart {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "GlyphPart",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("glyph_id", self.glyph_id())
+                        .with_record_range(0, GlyphId::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("start_connector_length", self.start_connector_length())
+                        .with_record_range(GlyphId::RAW_BYTE_LEN, UfWord::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("end_connector_length", self.end_connector_length())
+                        .with_record_range(
+                            GlyphId::RAW_BYTE_LEN + UfWord::RAW_BYTE_LEN,
+                            UfWord::RAW_BYTE_LEN,
+                        ),
+                ),
+                3usize => Some(
+                    Field::new("full_advance", self.full_advance()).with_record_range(
+                        GlyphId::RAW_BYTE_LEN + UfWord::RAW_BYTE_LEN + UfWord::RAW_BYTE_LEN,
+                        UfWord::RAW_BYTE_LEN,
+                    ),
+                ),
+                4usize => Some(
+                    Field::new("part_flags", self.part_flags()).with_record_range(
+                        GlyphId::RAW_BYTE_LEN
+                            + UfWord::RAW_BYTE_LEN
+                            + UfWord::RAW_BYTE_LEN
+                            + UfWord::RAW_BYTE_LEN,
+                        GlyphPartFlags::RAW_BYTE_LEN,
+                    ),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// Flags for a [`GlyphPart`].
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GlyphPartFlags {
+    bits: u16,
+}
+
+impl GlyphPartFlags {
+    /// If set, the part can be skipped or repeated.
+    pub const EXTENDER_FLAG: Self = Self { bits: 0x0001 };
+}
+
+impl GlyphPartFlags {
+    ///  Returns an empty set of flags.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Returns the set containing all flags.
+    #[inline]
+    pub const fn all() -> Self {
+        Self {
+            bits: Self::EXTENDER_FLAG.bits,
+        }
+    }
+
+    /// Returns the raw value of the flags currently stored.
+    #[inline]
+    pub const fn bits(&self) -> u16 {
+        self.bits
+    }
+
+    /// Convert from underlying bit representation, unless that
+    /// representation contains bits that do not correspond to a flag.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Option<Self> {
+        if (bits & !Self::all().bits()) == 0 {
+            Some(Self { bits })
+        } else {
+            None
+        }
+    }
+
+    /// Convert from underlying bit representation, dropping any bits
+    /// that do not correspond to flags.
+    #[inline]
+    pub const fn from_bits_truncate(bits: u16) -> Self {
+        Self {
+            bits: bits & Self::all().bits,
+        }
+    }
+
+    /// Returns `true` if no flags are currently stored.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.bits() == Self::empty().bits()
+    }
+
+    /// Returns `true` if there are flags common to both `self` and `other`.
+    #[inline]
+    pub const fn intersects(&self, other: Self) -> bool {
+        !(Self {
+            bits: self.bits & other.bits,
+        })
+        .is_empty()
+    }
+
+    /// Returns `true` if all of the flags in `other` are contained within `self`.
+    #[inline]
+    pub const fn contains(&self, other: Self) -> bool {
+        (self.bits & other.bits) == other.bits
+    }
+
+    /// Inserts the specified flags in-place.
+    #[inline]
+    pub fn insert(&mut self, other: Self) {
+        self.bits |= other.bits;
+    }
+
+    /// Removes the specified flags in-place.
+    #[inline]
+    pub fn remove(&mut self, other: Self) {
+        self.bits &= !other.bits;
+    }
+
+    /// Toggles the specified flags in-place.
+    #[inline]
+    pub fn toggle(&mut self, other: Self) {
+        self.bits ^= other.bits;
+    }
+
+    /// Returns the intersection between the flags in `self` and
+    /// `other`.
+    ///
+    /// Specifically, the returned set contains only the flags which are
+    /// present in *both* `self` *and* `other`.
+    ///
+    /// This is equivalent to using the `&` operator (e.g.
+    /// [`ops::BitAnd`]), as in `flags & other`.
+    ///
+    /// [`ops::BitAnd`]: https://doc.rust-lang.org/std/ops/trait.BitAnd.html
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Returns the union of between the flags in `self` and `other`.
+    ///
+    /// Specifically, the returned set contains all flags which are
+    /// present in *either* `self` *or* `other`, including any which are
+    /// present in both.
+    ///
+    /// This is equivalent to using the `|` operator (e.g.
+    /// [`ops::BitOr`]), as in `flags | other`.
+    ///
+    /// [`ops::BitOr`]: https://doc.rust-lang.org/std/ops/trait.BitOr.html
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Returns the difference between the flags in `self` and `other`.
+    ///
+    /// Specifically, the returned set contains all flags present in
+    /// `self`, except for the ones present in `other`.
+    ///
+    /// It is also conceptually equivalent to the "bit-clear" operation:
+    /// `flags & !other` (and this syntax is also supported).
+    ///
+    /// This is equivalent to using the `-` operator (e.g.
+    /// [`ops::Sub`]), as in `flags - other`.
+    ///
+    /// [`ops::Sub`]: https://doc.rust-lang.org/std/ops/trait.Sub.html
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+}
+
+impl std::ops::BitOr for GlyphPartFlags {
+    type Output = Self;
+
+    /// Returns the union of the two sets of flags.
+    #[inline]
+    fn bitor(self, other: GlyphPartFlags) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+}
+
+impl std::ops::BitOrAssign for GlyphPartFlags {
+    /// Adds the set of flags.
+    #[inline]
+    fn bitor_assign(&mut self, other: Self) {
+        self.bits |= other.bits;
+    }
+}
+
+impl std::ops::BitXor for GlyphPartFlags {
+    type Output = Self;
+
+    /// Returns the left flags, but with all the right flags toggled.
+    #[inline]
+    fn bitxor(self, other: Self) -> Self {
+        Self {
+            bits: self.bits ^ other.bits,
+        }
+    }
+}
+
+impl std::ops::BitXorAssign for GlyphPartFlags {
+    /// Toggles the set of flags.
+    #[inline]
+    fn bitxor_assign(&mut self, other: Self) {
+        self.bits ^= other.bits;
+    }
+}
+
+impl std::ops::BitAnd for GlyphPartFlags {
+    type Output = Self;
+
+    /// Returns the intersection between the two sets of flags.
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+}
+
+impl std::ops::BitAndAssign for GlyphPartFlags {
+    /// Disables all flags disabled in the set.
+    #[inline]
+    fn bitand_assign(&mut self, other: Self) {
+        self.bits &= other.bits;
+    }
+}
+
+impl std::ops::Sub for GlyphPartFlags {
+    type Output = Self;
+
+    /// Returns the set difference of the two sets of flags.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+}
+
+impl std::ops::SubAssign for GlyphPartFlags {
+    /// Disables all flags enabled in the set.
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.bits &= !other.bits;
+    }
+}
+
+impl std::ops::Not for GlyphPartFlags {
+    type Output = Self;
+
+    /// Returns the complement of this set of flags.
+    #[inline]
+    fn not(self) -> Self {
+        Self { bits: !self.bits } & Self::all()
+    }
+}
+
+impl std::fmt::Debug for GlyphPartFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let members: &[(&str, Self)] = &[("EXTENDER_FLAG", Self::EXTENDER_FLAG)];
+        let mut first = true;
+        for (name, value) in members {
+            if self.contains(*value) {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                first = false;
+                f.write_str(name)?;
+            }
+        }
+        if first {
+            f.write_str("(empty)")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Binary for GlyphPartFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Binary::fmt(&self.bits, f)
+    }
+}
+
+impl std::fmt::Octal for GlyphPartFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Octal::fmt(&self.bits, f)
+    }
+}
+
+impl std::fmt::LowerHex for GlyphPartFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::LowerHex::fmt(&self.bits, f)
+    }
+}
+
+impl std::fmt::UpperHex for GlyphPartFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::UpperHex::fmt(&self.bits, f)
+    }
+}
+
+impl font_types::Scalar for GlyphPartFlags {
+    type Raw = <u16 as font_types::Scalar>::Raw;
+    fn to_raw(self) -> Self::Raw {
+        self.bits().to_raw()
+    }
+    fn from_raw(raw: Self::Raw) -> Self {
+        let t = <u16>::from_raw(raw);
+        Self::from_bits_truncate(t)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> From<GlyphPartFlags> for FieldType<'a> {
+    fn from(src: GlyphPartFlags) -> FieldType<'a> {
+        src.bits().into()
+    }
+}
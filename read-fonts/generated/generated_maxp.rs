@@ -287,56 +287,75 @@ impl<'a> SomeTable<'a> for Maxp<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("num_glyphs", self.num_glyphs())),
-            2usize if version.compatible((1, 0)) => {
-                Some(Field::new("max_points", self.max_points().unwrap()))
-            }
-            3usize if version.compatible((1, 0)) => {
-                Some(Field::new("max_contours", self.max_contours().unwrap()))
-            }
-            4usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_composite_points",
-                self.max_composite_points().unwrap(),
-            )),
-            5usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_composite_contours",
-                self.max_composite_contours().unwrap(),
-            )),
-            6usize if version.compatible((1, 0)) => {
-                Some(Field::new("max_zones", self.max_zones().unwrap()))
-            }
-            7usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_twilight_points",
-                self.max_twilight_points().unwrap(),
-            )),
-            8usize if version.compatible((1, 0)) => {
-                Some(Field::new("max_storage", self.max_storage().unwrap()))
-            }
-            9usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_function_defs",
-                self.max_function_defs().unwrap(),
-            )),
-            10usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_instruction_defs",
-                self.max_instruction_defs().unwrap(),
-            )),
-            11usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_stack_elements",
-                self.max_stack_elements().unwrap(),
-            )),
-            12usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_size_of_instructions",
-                self.max_size_of_instructions().unwrap(),
-            )),
-            13usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_component_elements",
-                self.max_component_elements().unwrap(),
-            )),
-            14usize if version.compatible((1, 0)) => Some(Field::new(
-                "max_component_depth",
-                self.max_component_depth().unwrap(),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("num_glyphs", self.num_glyphs())
+                    .with_bytes(self.data, self.shape.num_glyphs_byte_range()),
+            ),
+            2usize if version.compatible((1, 0)) => Some(
+                Field::new("max_points", self.max_points().unwrap())
+                    .with_bytes(self.data, self.shape.max_points_byte_range()),
+            ),
+            3usize if version.compatible((1, 0)) => Some(
+                Field::new("max_contours", self.max_contours().unwrap())
+                    .with_bytes(self.data, self.shape.max_contours_byte_range()),
+            ),
+            4usize if version.compatible((1, 0)) => Some(
+                Field::new("max_composite_points", self.max_composite_points().unwrap())
+                    .with_bytes(self.data, self.shape.max_composite_points_byte_range()),
+            ),
+            5usize if version.compatible((1, 0)) => Some(
+                Field::new(
+                    "max_composite_contours",
+                    self.max_composite_contours().unwrap(),
+                )
+                .with_bytes(self.data, self.shape.max_composite_contours_byte_range()),
+            ),
+            6usize if version.compatible((1, 0)) => Some(
+                Field::new("max_zones", self.max_zones().unwrap())
+                    .with_bytes(self.data, self.shape.max_zones_byte_range()),
+            ),
+            7usize if version.compatible((1, 0)) => Some(
+                Field::new("max_twilight_points", self.max_twilight_points().unwrap())
+                    .with_bytes(self.data, self.shape.max_twilight_points_byte_range()),
+            ),
+            8usize if version.compatible((1, 0)) => Some(
+                Field::new("max_storage", self.max_storage().unwrap())
+                    .with_bytes(self.data, self.shape.max_storage_byte_range()),
+            ),
+            9usize if version.compatible((1, 0)) => Some(
+                Field::new("max_function_defs", self.max_function_defs().unwrap())
+                    .with_bytes(self.data, self.shape.max_function_defs_byte_range()),
+            ),
+            10usize if version.compatible((1, 0)) => Some(
+                Field::new("max_instruction_defs", self.max_instruction_defs().unwrap())
+                    .with_bytes(self.data, self.shape.max_instruction_defs_byte_range()),
+            ),
+            11usize if version.compatible((1, 0)) => Some(
+                Field::new("max_stack_elements", self.max_stack_elements().unwrap())
+                    .with_bytes(self.data, self.shape.max_stack_elements_byte_range()),
+            ),
+            12usize if version.compatible((1, 0)) => Some(
+                Field::new(
+                    "max_size_of_instructions",
+                    self.max_size_of_instructions().unwrap(),
+                )
+                .with_bytes(self.data, self.shape.max_size_of_instructions_byte_range()),
+            ),
+            13usize if version.compatible((1, 0)) => Some(
+                Field::new(
+                    "max_component_elements",
+                    self.max_component_elements().unwrap(),
+                )
+                .with_bytes(self.data, self.shape.max_component_elements_byte_range()),
+            ),
+            14usize if version.compatible((1, 0)) => Some(
+                Field::new("max_component_depth", self.max_component_depth().unwrap())
+                    .with_bytes(self.data, self.shape.max_component_depth_byte_range()),
+            ),
             _ => None,
         }
     }
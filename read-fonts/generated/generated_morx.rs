@@ -0,0 +1,1074 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [morx (Extended Glyph Metamorphosis)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6morx.html) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct MorxMarker {
+    chain_data_byte_len: usize,
+}
+
+impl MorxMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn unused_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn n_chains_byte_range(&self) -> Range<usize> {
+        let start = self.unused_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn chain_data_byte_range(&self) -> Range<usize> {
+        let start = self.n_chains_byte_range().end;
+        start..start + self.chain_data_byte_len
+    }
+}
+
+impl TopLevelTable for Morx<'_> {
+    /// `morx`
+    const TAG: Tag = Tag::new(b"morx");
+}
+
+impl<'a> FontRead<'a> for Morx<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        let chain_data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(chain_data_byte_len);
+        cursor.finish(MorxMarker {
+            chain_data_byte_len,
+        })
+    }
+}
+
+/// The [morx (Extended Glyph Metamorphosis)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6morx.html) table.
+pub type Morx<'a> = TableRef<'a, MorxMarker>;
+
+impl<'a> Morx<'a> {
+    /// Version number of the extended glyph metamorphosis table (either 2
+    /// or 3).
+    pub fn version(&self) -> u16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of metamorphosis chains contained in this table.
+    pub fn n_chains(&self) -> u32 {
+        let range = self.shape.n_chains_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Data for the chains, immediately following the header.
+    pub fn chain_data(&self) -> &'a [u8] {
+        let range = self.shape.chain_data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Morx<'a> {
+    fn type_name(&self) -> &str {
+        "Morx"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_chains", self.n_chains())
+                    .with_bytes(self.data, self.shape.n_chains_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("chain_data", self.traverse_chains())
+                    .with_bytes(self.data, self.shape.chain_data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Morx<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A chain in a `morx` table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct ChainMarker {
+    features_byte_len: usize,
+    subtable_data_byte_len: usize,
+}
+
+impl ChainMarker {
+    fn default_flags_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn chain_length_byte_range(&self) -> Range<usize> {
+        let start = self.default_flags_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn n_feature_entries_byte_range(&self) -> Range<usize> {
+        let start = self.chain_length_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn n_subtables_byte_range(&self) -> Range<usize> {
+        let start = self.n_feature_entries_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn features_byte_range(&self) -> Range<usize> {
+        let start = self.n_subtables_byte_range().end;
+        start..start + self.features_byte_len
+    }
+    fn subtable_data_byte_range(&self) -> Range<usize> {
+        let start = self.features_byte_range().end;
+        start..start + self.subtable_data_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for Chain<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let n_feature_entries: u32 = cursor.read()?;
+        cursor.advance::<u32>();
+        let features_byte_len = n_feature_entries as usize * Feature::RAW_BYTE_LEN;
+        cursor.advance_by(features_byte_len);
+        let subtable_data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(subtable_data_byte_len);
+        cursor.finish(ChainMarker {
+            features_byte_len,
+            subtable_data_byte_len,
+        })
+    }
+}
+
+/// A chain in a `morx` table.
+pub type Chain<'a> = TableRef<'a, ChainMarker>;
+
+impl<'a> Chain<'a> {
+    /// The default specification for subtables.
+    pub fn default_flags(&self) -> u32 {
+        let range = self.shape.default_flags_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Total byte count, including this header; must be a multiple of 4.
+    pub fn chain_length(&self) -> u32 {
+        let range = self.shape.chain_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of feature subtable entries.
+    pub fn n_feature_entries(&self) -> u32 {
+        let range = self.shape.n_feature_entries_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The number of subtables in the chain.
+    pub fn n_subtables(&self) -> u32 {
+        let range = self.shape.n_subtables_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Feature entries for this chain.
+    pub fn features(&self) -> &'a [Feature] {
+        let range = self.shape.features_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Data for the subtables, immediately following the feature entries.
+    pub fn subtable_data(&self) -> &'a [u8] {
+        let range = self.shape.subtable_data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Chain<'a> {
+    fn type_name(&self) -> &str {
+        "Chain"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("default_flags", self.default_flags())
+                    .with_bytes(self.data, self.shape.default_flags_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("chain_length", self.chain_length())
+                    .with_bytes(self.data, self.shape.chain_length_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("n_feature_entries", self.n_feature_entries())
+                    .with_bytes(self.data, self.shape.n_feature_entries_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("n_subtables", self.n_subtables())
+                    .with_bytes(self.data, self.shape.n_subtables_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "features",
+                    traversal::FieldType::array_of_records(
+                        stringify!(Feature),
+                        self.features(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.features_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("subtable_data", self.traverse_subtables())
+                    .with_bytes(self.data, self.shape.subtable_data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Chain<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Used to compute the sub-feature flags for a list of requested features
+/// and settings.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct Feature {
+    /// The type of feature.
+    pub feature_type: BigEndian<u16>,
+    /// The feature's setting (aka selector).
+    pub feature_settings: BigEndian<u16>,
+    /// Flags for the settings that this feature and setting enables.
+    pub enable_flags: BigEndian<u32>,
+    /// Complement of flags for the settings that this feature and setting
+    /// disable.
+    pub disable_flags: BigEndian<u32>,
+}
+
+impl Feature {
+    /// The type of feature.
+    pub fn feature_type(&self) -> u16 {
+        self.feature_type.get()
+    }
+
+    /// The feature's setting (aka selector).
+    pub fn feature_settings(&self) -> u16 {
+        self.feature_settings.get()
+    }
+
+    /// Flags for the settings that this feature and setting enables.
+    pub fn enable_flags(&self) -> u32 {
+        self.enable_flags.get()
+    }
+
+    /// Complement of flags for the settings that this feature and setting
+    /// disable.
+    pub fn disable_flags(&self) -> u32 {
+        self.disable_flags.get()
+    }
+}
+
+impl FixedSize for Feature {
+    const RAW_BYTE_LEN: usize =
+        u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN + u32::RAW_BYTE_LEN + u32::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for Feature {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for Feature {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for Feature {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "Feature",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("feature_type", self.feature_type())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("feature_settings", self.feature_settings())
+                        .with_record_range(u16::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("enable_flags", self.enable_flags()).with_record_range(
+                        u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                        u32::RAW_BYTE_LEN,
+                    ),
+                ),
+                3usize => Some(
+                    Field::new("disable_flags", self.disable_flags()).with_record_range(
+                        u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN + u32::RAW_BYTE_LEN,
+                        u32::RAW_BYTE_LEN,
+                    ),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// A subtable in a `morx` chain.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct SubtableMarker {
+    data_byte_len: usize,
+}
+
+impl SubtableMarker {
+    fn length_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn coverage_byte_range(&self) -> Range<usize> {
+        let start = self.length_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn sub_feature_flags_byte_range(&self) -> Range<usize> {
+        let start = self.coverage_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn data_byte_range(&self) -> Range<usize> {
+        let start = self.sub_feature_flags_byte_range().end;
+        start..start + self.data_byte_len
+    }
+}
+
+impl<'a> FontRead<'a> for Subtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        let data_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(data_byte_len);
+        cursor.finish(SubtableMarker { data_byte_len })
+    }
+}
+
+/// A subtable in a `morx` chain.
+pub type Subtable<'a> = TableRef<'a, SubtableMarker>;
+
+impl<'a> Subtable<'a> {
+    /// Total subtable length, including this header.
+    pub fn length(&self) -> u32 {
+        let range = self.shape.length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Coverage flags and subtable type.
+    pub fn coverage(&self) -> u32 {
+        let range = self.shape.coverage_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The 32-bit mask identifying which subtable this is (the subtable
+    /// being executed if the AND of this value and the processed
+    /// defaultFlags is nonzero).
+    pub fn sub_feature_flags(&self) -> u32 {
+        let range = self.shape.sub_feature_flags_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Data for the specific subtable.
+    pub fn data(&self) -> &'a [u8] {
+        let range = self.shape.data_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable<'a> {
+    fn type_name(&self) -> &str {
+        "Subtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("coverage", self.coverage())
+                    .with_bytes(self.data, self.shape.coverage_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("sub_feature_flags", self.sub_feature_flags())
+                    .with_bytes(self.data, self.shape.sub_feature_flags_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("data", self.traverse_kind())
+                    .with_bytes(self.data, self.shape.data_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Subtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// A contextual glyph substitution subtable.
+///
+/// The first four fields are the header of the extended state table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct ContextualSubtableMarker {}
+
+impl ContextualSubtableMarker {
+    fn n_classes_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.n_classes_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn state_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.class_table_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn entry_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.state_array_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn substitution_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.entry_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for ContextualSubtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.finish(ContextualSubtableMarker {})
+    }
+}
+
+/// A contextual glyph substitution subtable.
+///
+/// The first four fields are the header of the extended state table.
+pub type ContextualSubtable<'a> = TableRef<'a, ContextualSubtableMarker>;
+
+impl<'a> ContextualSubtable<'a> {
+    /// Number of classes in the state table.
+    pub fn n_classes(&self) -> u32 {
+        let range = self.shape.n_classes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of this subtable to the class table.
+    pub fn class_table_offset(&self) -> Offset32 {
+        let range = self.shape.class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`class_table_offset`][Self::class_table_offset].
+    pub fn class_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.class_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the state array.
+    pub fn state_array_offset(&self) -> Offset32 {
+        let range = self.shape.state_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`state_array_offset`][Self::state_array_offset].
+    pub fn state_array(&self) -> Result<RawWords<'a>, ReadError> {
+        let data = self.data;
+        self.state_array_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the entry table.
+    pub fn entry_table_offset(&self) -> Offset32 {
+        let range = self.shape.entry_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`entry_table_offset`][Self::entry_table_offset].
+    pub fn entry_table(&self) -> Result<RawBytes<'a>, ReadError> {
+        let data = self.data;
+        self.entry_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the list of offsets to
+    /// substitution lookup tables.
+    pub fn substitution_table_offset(&self) -> u32 {
+        let range = self.shape.substitution_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for ContextualSubtable<'a> {
+    fn type_name(&self) -> &str {
+        "ContextualSubtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_classes", self.n_classes())
+                    .with_bytes(self.data, self.shape.n_classes_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "class_table_offset",
+                    FieldType::offset(self.class_table_offset(), self.class_table()),
+                )
+                .with_bytes(self.data, self.shape.class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "state_array_offset",
+                    FieldType::offset(self.state_array_offset(), self.state_array()),
+                )
+                .with_bytes(self.data, self.shape.state_array_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "entry_table_offset",
+                    FieldType::offset(self.entry_table_offset(), self.entry_table()),
+                )
+                .with_bytes(self.data, self.shape.entry_table_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "substitution_table_offset",
+                    self.substitution_table_offset(),
+                )
+                .with_bytes(self.data, self.shape.substitution_table_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for ContextualSubtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Entry data for a contextual glyph substitution subtable.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct ContextualEntryData {
+    /// Index of the substitution table for the marked glyph (use 0xFFFF
+    /// for none).
+    pub mark_index: BigEndian<u16>,
+    /// Index of the substitution table for the current glyph (use 0xFFFF
+    /// for none).
+    pub current_index: BigEndian<u16>,
+}
+
+impl ContextualEntryData {
+    /// Index of the substitution table for the marked glyph (use 0xFFFF
+    /// for none).
+    pub fn mark_index(&self) -> u16 {
+        self.mark_index.get()
+    }
+
+    /// Index of the substitution table for the current glyph (use 0xFFFF
+    /// for none).
+    pub fn current_index(&self) -> u16 {
+        self.current_index.get()
+    }
+}
+
+impl FixedSize for ContextualEntryData {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for ContextualEntryData {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for ContextualEntryData {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for ContextualEntryData {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "ContextualEntryData",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("mark_index", self.mark_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("current_index", self.current_index())
+                        .with_record_range(u16::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// A ligature subtable.
+///
+/// The first four fields are the header of the extended state table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct LigatureSubtableMarker {}
+
+impl LigatureSubtableMarker {
+    fn n_classes_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.n_classes_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn state_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.class_table_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn entry_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.state_array_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn lig_action_offset_byte_range(&self) -> Range<usize> {
+        let start = self.entry_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn component_offset_byte_range(&self) -> Range<usize> {
+        let start = self.lig_action_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn ligature_offset_byte_range(&self) -> Range<usize> {
+        let start = self.component_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for LigatureSubtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.finish(LigatureSubtableMarker {})
+    }
+}
+
+/// A ligature subtable.
+///
+/// The first four fields are the header of the extended state table.
+pub type LigatureSubtable<'a> = TableRef<'a, LigatureSubtableMarker>;
+
+impl<'a> LigatureSubtable<'a> {
+    /// Number of classes in the state table.
+    pub fn n_classes(&self) -> u32 {
+        let range = self.shape.n_classes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of this subtable to the class table.
+    pub fn class_table_offset(&self) -> Offset32 {
+        let range = self.shape.class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`class_table_offset`][Self::class_table_offset].
+    pub fn class_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.class_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the state array.
+    pub fn state_array_offset(&self) -> Offset32 {
+        let range = self.shape.state_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`state_array_offset`][Self::state_array_offset].
+    pub fn state_array(&self) -> Result<RawWords<'a>, ReadError> {
+        let data = self.data;
+        self.state_array_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the entry table.
+    pub fn entry_table_offset(&self) -> Offset32 {
+        let range = self.shape.entry_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`entry_table_offset`][Self::entry_table_offset].
+    pub fn entry_table(&self) -> Result<RawBytes<'a>, ReadError> {
+        let data = self.data;
+        self.entry_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the ligature action table.
+    pub fn lig_action_offset(&self) -> u32 {
+        let range = self.shape.lig_action_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of this subtable to the component table.
+    pub fn component_offset(&self) -> u32 {
+        let range = self.shape.component_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of this subtable to the actual ligature list.
+    pub fn ligature_offset(&self) -> u32 {
+        let range = self.shape.ligature_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for LigatureSubtable<'a> {
+    fn type_name(&self) -> &str {
+        "LigatureSubtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_classes", self.n_classes())
+                    .with_bytes(self.data, self.shape.n_classes_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "class_table_offset",
+                    FieldType::offset(self.class_table_offset(), self.class_table()),
+                )
+                .with_bytes(self.data, self.shape.class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "state_array_offset",
+                    FieldType::offset(self.state_array_offset(), self.state_array()),
+                )
+                .with_bytes(self.data, self.shape.state_array_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "entry_table_offset",
+                    FieldType::offset(self.entry_table_offset(), self.entry_table()),
+                )
+                .with_bytes(self.data, self.shape.entry_table_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("lig_action_offset", self.lig_action_offset())
+                    .with_bytes(self.data, self.shape.lig_action_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("component_offset", self.component_offset())
+                    .with_bytes(self.data, self.shape.component_offset_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("ligature_offset", self.ligature_offset())
+                    .with_bytes(self.data, self.shape.ligature_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for LigatureSubtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Entry data for a ligature subtable.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct LigatureEntryData {
+    /// Index to the first ligature action for this entry.
+    pub lig_action_index: BigEndian<u16>,
+}
+
+impl LigatureEntryData {
+    /// Index to the first ligature action for this entry.
+    pub fn lig_action_index(&self) -> u16 {
+        self.lig_action_index.get()
+    }
+}
+
+impl FixedSize for LigatureEntryData {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for LigatureEntryData {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for LigatureEntryData {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for LigatureEntryData {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "LigatureEntryData",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("lig_action_index", self.lig_action_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
+
+/// A glyph insertion subtable.
+///
+/// The first four fields are the header of the extended state table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct InsertionSubtableMarker {}
+
+impl InsertionSubtableMarker {
+    fn n_classes_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn class_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.n_classes_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn state_array_offset_byte_range(&self) -> Range<usize> {
+        let start = self.class_table_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn entry_table_offset_byte_range(&self) -> Range<usize> {
+        let start = self.state_array_offset_byte_range().end;
+        start..start + Offset32::RAW_BYTE_LEN
+    }
+    fn insertion_action_offset_byte_range(&self) -> Range<usize> {
+        let start = self.entry_table_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for InsertionSubtable<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<u32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<Offset32>();
+        cursor.advance::<u32>();
+        cursor.finish(InsertionSubtableMarker {})
+    }
+}
+
+/// A glyph insertion subtable.
+///
+/// The first four fields are the header of the extended state table.
+pub type InsertionSubtable<'a> = TableRef<'a, InsertionSubtableMarker>;
+
+impl<'a> InsertionSubtable<'a> {
+    /// Number of classes in the state table.
+    pub fn n_classes(&self) -> u32 {
+        let range = self.shape.n_classes_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset from the start of this subtable to the class table.
+    pub fn class_table_offset(&self) -> Offset32 {
+        let range = self.shape.class_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`class_table_offset`][Self::class_table_offset].
+    pub fn class_table(&self) -> Result<Lookup<'a>, ReadError> {
+        let data = self.data;
+        self.class_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the state array.
+    pub fn state_array_offset(&self) -> Offset32 {
+        let range = self.shape.state_array_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`state_array_offset`][Self::state_array_offset].
+    pub fn state_array(&self) -> Result<RawWords<'a>, ReadError> {
+        let data = self.data;
+        self.state_array_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the entry table.
+    pub fn entry_table_offset(&self) -> Offset32 {
+        let range = self.shape.entry_table_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Attempt to resolve [`entry_table_offset`][Self::entry_table_offset].
+    pub fn entry_table(&self) -> Result<RawBytes<'a>, ReadError> {
+        let data = self.data;
+        self.entry_table_offset().resolve(data)
+    }
+
+    /// Offset from the start of this subtable to the insertion glyph table.
+    pub fn insertion_action_offset(&self) -> u32 {
+        let range = self.shape.insertion_action_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for InsertionSubtable<'a> {
+    fn type_name(&self) -> &str {
+        "InsertionSubtable"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("n_classes", self.n_classes())
+                    .with_bytes(self.data, self.shape.n_classes_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "class_table_offset",
+                    FieldType::offset(self.class_table_offset(), self.class_table()),
+                )
+                .with_bytes(self.data, self.shape.class_table_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "state_array_offset",
+                    FieldType::offset(self.state_array_offset(), self.state_array()),
+                )
+                .with_bytes(self.data, self.shape.state_array_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "entry_table_offset",
+                    FieldType::offset(self.entry_table_offset(), self.entry_table()),
+                )
+                .with_bytes(self.data, self.shape.entry_table_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("insertion_action_offset", self.insertion_action_offset())
+                    .with_bytes(self.data, self.shape.insertion_action_offset_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for InsertionSubtable<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
+
+/// Entry data for a glyph insertion subtable.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[repr(packed)]
+pub struct InsertionEntryData {
+    /// Zero-based index into the insertion glyph table for the current
+    /// glyph (use 0xFFFF for none).
+    pub current_insert_index: BigEndian<u16>,
+    /// Zero-based index into the insertion glyph table for the marked
+    /// glyph (use 0xFFFF for none).
+    pub marked_insert_index: BigEndian<u16>,
+}
+
+impl InsertionEntryData {
+    /// Zero-based index into the insertion glyph table for the current
+    /// glyph (use 0xFFFF for none).
+    pub fn current_insert_index(&self) -> u16 {
+        self.current_insert_index.get()
+    }
+
+    /// Zero-based index into the insertion glyph table for the marked
+    /// glyph (use 0xFFFF for none).
+    pub fn marked_insert_index(&self) -> u16 {
+        self.marked_insert_index.get()
+    }
+}
+
+impl FixedSize for InsertionEntryData {
+    const RAW_BYTE_LEN: usize = u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN;
+}
+
+impl sealed::Sealed for InsertionEntryData {}
+
+/// SAFETY: see the [`FromBytes`] trait documentation.
+unsafe impl FromBytes for InsertionEntryData {
+    fn this_trait_should_only_be_implemented_in_generated_code() {}
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for InsertionEntryData {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "InsertionEntryData",
+            get_field: Box::new(move |idx, _data| match idx {
+                0usize => Some(
+                    Field::new("current_insert_index", self.current_insert_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("marked_insert_index", self.marked_insert_index())
+                        .with_record_range(u16::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                _ => None,
+            }),
+            data,
+            bytes: None,
+        }
+    }
+}
@@ -109,24 +109,42 @@ impl<'a> SomeTable<'a> for Mvar<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("value_record_size", self.value_record_size())),
-            2usize => Some(Field::new("value_record_count", self.value_record_count())),
-            3usize => Some(Field::new(
-                "item_variation_store_offset",
-                FieldType::offset(
-                    self.item_variation_store_offset(),
-                    self.item_variation_store(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("value_record_size", self.value_record_size())
+                    .with_bytes(self.data, self.shape.value_record_size_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("value_record_count", self.value_record_count())
+                    .with_bytes(self.data, self.shape.value_record_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "item_variation_store_offset",
+                    FieldType::offset(
+                        self.item_variation_store_offset(),
+                        self.item_variation_store(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.item_variation_store_offset_byte_range(),
                 ),
-            )),
-            4usize => Some(Field::new(
-                "value_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(ValueRecord),
-                    self.value_records(),
-                    self.offset_data(),
-                ),
-            )),
+            ),
+            4usize => Some(
+                Field::new(
+                    "value_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(ValueRecord),
+                        self.value_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.value_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -186,18 +204,25 @@ impl<'a> SomeRecord<'a> for ValueRecord {
         RecordResolver {
             name: "ValueRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("value_tag", self.value_tag())),
-                1usize => Some(Field::new(
-                    "delta_set_outer_index",
-                    self.delta_set_outer_index(),
-                )),
-                2usize => Some(Field::new(
-                    "delta_set_inner_index",
-                    self.delta_set_inner_index(),
-                )),
+                0usize => Some(
+                    Field::new("value_tag", self.value_tag())
+                        .with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("delta_set_outer_index", self.delta_set_outer_index())
+                        .with_record_range(Tag::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("delta_set_inner_index", self.delta_set_inner_index())
+                        .with_record_range(
+                            Tag::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                            u16::RAW_BYTE_LEN,
+                        ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
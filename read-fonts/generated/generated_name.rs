@@ -132,28 +132,44 @@ impl<'a> SomeTable<'a> for Name<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("count", self.count())),
-            2usize => Some(Field::new("storage_offset", self.storage_offset())),
-            3usize => Some(Field::new(
-                "name_record",
-                traversal::FieldType::array_of_records(
-                    stringify!(NameRecord),
-                    self.name_record(),
-                    self.string_data(),
-                ),
-            )),
-            4usize if version.compatible(1) => {
-                Some(Field::new("lang_tag_count", self.lang_tag_count().unwrap()))
-            }
-            5usize if version.compatible(1) => Some(Field::new(
-                "lang_tag_record",
-                traversal::FieldType::array_of_records(
-                    stringify!(LangTagRecord),
-                    self.lang_tag_record().unwrap(),
-                    self.string_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("count", self.count())
+                    .with_bytes(self.data, self.shape.count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("storage_offset", self.storage_offset())
+                    .with_bytes(self.data, self.shape.storage_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "name_record",
+                    traversal::FieldType::array_of_records(
+                        stringify!(NameRecord),
+                        self.name_record(),
+                        self.string_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.name_record_byte_range()),
+            ),
+            4usize if version.compatible(1) => Some(
+                Field::new("lang_tag_count", self.lang_tag_count().unwrap())
+                    .with_bytes(self.data, self.shape.lang_tag_count_byte_range()),
+            ),
+            5usize if version.compatible(1) => Some(
+                Field::new(
+                    "lang_tag_record",
+                    traversal::FieldType::array_of_records(
+                        stringify!(LangTagRecord),
+                        self.lang_tag_record().unwrap(),
+                        self.string_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.lang_tag_record_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -208,11 +224,17 @@ impl<'a> SomeRecord<'a> for LangTagRecord {
         RecordResolver {
             name: "LangTagRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("length", self.length())),
-                1usize => Some(Field::new("lang_tag_offset", self.traverse_lang_tag(_data))),
+                0usize => Some(
+                    Field::new("length", self.length()).with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("lang_tag_offset", self.traverse_lang_tag(_data))
+                        .with_record_range(u16::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -290,15 +312,45 @@ impl<'a> SomeRecord<'a> for NameRecord {
         RecordResolver {
             name: "NameRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("platform_id", self.platform_id())),
-                1usize => Some(Field::new("encoding_id", self.encoding_id())),
-                2usize => Some(Field::new("language_id", self.language_id())),
-                3usize => Some(Field::new("name_id", self.name_id())),
-                4usize => Some(Field::new("length", self.length())),
-                5usize => Some(Field::new("string_offset", self.traverse_string(_data))),
+                0usize => Some(
+                    Field::new("platform_id", self.platform_id())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("encoding_id", self.encoding_id())
+                        .with_record_range(u16::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("language_id", self.language_id()).with_record_range(
+                        u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                        u16::RAW_BYTE_LEN,
+                    ),
+                ),
+                3usize => Some(Field::new("name_id", self.name_id()).with_record_range(
+                    u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN + u16::RAW_BYTE_LEN,
+                    NameId::RAW_BYTE_LEN,
+                )),
+                4usize => Some(Field::new("length", self.length()).with_record_range(
+                    u16::RAW_BYTE_LEN
+                        + u16::RAW_BYTE_LEN
+                        + u16::RAW_BYTE_LEN
+                        + NameId::RAW_BYTE_LEN,
+                    u16::RAW_BYTE_LEN,
+                )),
+                5usize => Some(
+                    Field::new("string_offset", self.traverse_string(_data)).with_record_range(
+                        u16::RAW_BYTE_LEN
+                            + u16::RAW_BYTE_LEN
+                            + u16::RAW_BYTE_LEN
+                            + NameId::RAW_BYTE_LEN
+                            + u16::RAW_BYTE_LEN,
+                        Offset16::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
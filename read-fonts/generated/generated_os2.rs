@@ -917,92 +917,174 @@ impl<'a> SomeTable<'a> for Os2<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("x_avg_char_width", self.x_avg_char_width())),
-            2usize => Some(Field::new("us_weight_class", self.us_weight_class())),
-            3usize => Some(Field::new("us_width_class", self.us_width_class())),
-            4usize => Some(Field::new("fs_type", self.fs_type())),
-            5usize => Some(Field::new("y_subscript_x_size", self.y_subscript_x_size())),
-            6usize => Some(Field::new("y_subscript_y_size", self.y_subscript_y_size())),
-            7usize => Some(Field::new(
-                "y_subscript_x_offset",
-                self.y_subscript_x_offset(),
-            )),
-            8usize => Some(Field::new(
-                "y_subscript_y_offset",
-                self.y_subscript_y_offset(),
-            )),
-            9usize => Some(Field::new(
-                "y_superscript_x_size",
-                self.y_superscript_x_size(),
-            )),
-            10usize => Some(Field::new(
-                "y_superscript_y_size",
-                self.y_superscript_y_size(),
-            )),
-            11usize => Some(Field::new(
-                "y_superscript_x_offset",
-                self.y_superscript_x_offset(),
-            )),
-            12usize => Some(Field::new(
-                "y_superscript_y_offset",
-                self.y_superscript_y_offset(),
-            )),
-            13usize => Some(Field::new("y_strikeout_size", self.y_strikeout_size())),
-            14usize => Some(Field::new(
-                "y_strikeout_position",
-                self.y_strikeout_position(),
-            )),
-            15usize => Some(Field::new("s_family_class", self.s_family_class())),
-            16usize => Some(Field::new("panose_10", self.panose_10())),
-            17usize => Some(Field::new("ul_unicode_range_1", self.ul_unicode_range_1())),
-            18usize => Some(Field::new("ul_unicode_range_2", self.ul_unicode_range_2())),
-            19usize => Some(Field::new("ul_unicode_range_3", self.ul_unicode_range_3())),
-            20usize => Some(Field::new("ul_unicode_range_4", self.ul_unicode_range_4())),
-            21usize => Some(Field::new("ach_vend_id", self.ach_vend_id())),
-            22usize => Some(Field::new("fs_selection", self.fs_selection())),
-            23usize => Some(Field::new(
-                "us_first_char_index",
-                self.us_first_char_index(),
-            )),
-            24usize => Some(Field::new("us_last_char_index", self.us_last_char_index())),
-            25usize => Some(Field::new("s_typo_ascender", self.s_typo_ascender())),
-            26usize => Some(Field::new("s_typo_descender", self.s_typo_descender())),
-            27usize => Some(Field::new("s_typo_line_gap", self.s_typo_line_gap())),
-            28usize => Some(Field::new("us_win_ascent", self.us_win_ascent())),
-            29usize => Some(Field::new("us_win_descent", self.us_win_descent())),
-            30usize if version.compatible(1) => Some(Field::new(
-                "ul_code_page_range_1",
-                self.ul_code_page_range_1().unwrap(),
-            )),
-            31usize if version.compatible(1) => Some(Field::new(
-                "ul_code_page_range_2",
-                self.ul_code_page_range_2().unwrap(),
-            )),
-            32usize if version.compatible(2) => {
-                Some(Field::new("sx_height", self.sx_height().unwrap()))
-            }
-            33usize if version.compatible(2) => {
-                Some(Field::new("s_cap_height", self.s_cap_height().unwrap()))
-            }
-            34usize if version.compatible(2) => Some(Field::new(
-                "us_default_char",
-                self.us_default_char().unwrap(),
-            )),
-            35usize if version.compatible(2) => {
-                Some(Field::new("us_break_char", self.us_break_char().unwrap()))
-            }
-            36usize if version.compatible(2) => {
-                Some(Field::new("us_max_context", self.us_max_context().unwrap()))
-            }
-            37usize if version.compatible(5) => Some(Field::new(
-                "us_lower_optical_point_size",
-                self.us_lower_optical_point_size().unwrap(),
-            )),
-            38usize if version.compatible(5) => Some(Field::new(
-                "us_upper_optical_point_size",
-                self.us_upper_optical_point_size().unwrap(),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("x_avg_char_width", self.x_avg_char_width())
+                    .with_bytes(self.data, self.shape.x_avg_char_width_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("us_weight_class", self.us_weight_class())
+                    .with_bytes(self.data, self.shape.us_weight_class_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("us_width_class", self.us_width_class())
+                    .with_bytes(self.data, self.shape.us_width_class_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("fs_type", self.fs_type())
+                    .with_bytes(self.data, self.shape.fs_type_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("y_subscript_x_size", self.y_subscript_x_size())
+                    .with_bytes(self.data, self.shape.y_subscript_x_size_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("y_subscript_y_size", self.y_subscript_y_size())
+                    .with_bytes(self.data, self.shape.y_subscript_y_size_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("y_subscript_x_offset", self.y_subscript_x_offset())
+                    .with_bytes(self.data, self.shape.y_subscript_x_offset_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("y_subscript_y_offset", self.y_subscript_y_offset())
+                    .with_bytes(self.data, self.shape.y_subscript_y_offset_byte_range()),
+            ),
+            9usize => Some(
+                Field::new("y_superscript_x_size", self.y_superscript_x_size())
+                    .with_bytes(self.data, self.shape.y_superscript_x_size_byte_range()),
+            ),
+            10usize => Some(
+                Field::new("y_superscript_y_size", self.y_superscript_y_size())
+                    .with_bytes(self.data, self.shape.y_superscript_y_size_byte_range()),
+            ),
+            11usize => Some(
+                Field::new("y_superscript_x_offset", self.y_superscript_x_offset())
+                    .with_bytes(self.data, self.shape.y_superscript_x_offset_byte_range()),
+            ),
+            12usize => Some(
+                Field::new("y_superscript_y_offset", self.y_superscript_y_offset())
+                    .with_bytes(self.data, self.shape.y_superscript_y_offset_byte_range()),
+            ),
+            13usize => Some(
+                Field::new("y_strikeout_size", self.y_strikeout_size())
+                    .with_bytes(self.data, self.shape.y_strikeout_size_byte_range()),
+            ),
+            14usize => Some(
+                Field::new("y_strikeout_position", self.y_strikeout_position())
+                    .with_bytes(self.data, self.shape.y_strikeout_position_byte_range()),
+            ),
+            15usize => Some(
+                Field::new("s_family_class", self.s_family_class())
+                    .with_bytes(self.data, self.shape.s_family_class_byte_range()),
+            ),
+            16usize => Some(
+                Field::new("panose_10", self.panose_10())
+                    .with_bytes(self.data, self.shape.panose_10_byte_range()),
+            ),
+            17usize => Some(
+                Field::new("ul_unicode_range_1", self.ul_unicode_range_1())
+                    .with_bytes(self.data, self.shape.ul_unicode_range_1_byte_range()),
+            ),
+            18usize => Some(
+                Field::new("ul_unicode_range_2", self.ul_unicode_range_2())
+                    .with_bytes(self.data, self.shape.ul_unicode_range_2_byte_range()),
+            ),
+            19usize => Some(
+                Field::new("ul_unicode_range_3", self.ul_unicode_range_3())
+                    .with_bytes(self.data, self.shape.ul_unicode_range_3_byte_range()),
+            ),
+            20usize => Some(
+                Field::new("ul_unicode_range_4", self.ul_unicode_range_4())
+                    .with_bytes(self.data, self.shape.ul_unicode_range_4_byte_range()),
+            ),
+            21usize => Some(
+                Field::new("ach_vend_id", self.ach_vend_id())
+                    .with_bytes(self.data, self.shape.ach_vend_id_byte_range()),
+            ),
+            22usize => Some(
+                Field::new("fs_selection", self.fs_selection())
+                    .with_bytes(self.data, self.shape.fs_selection_byte_range()),
+            ),
+            23usize => Some(
+                Field::new("us_first_char_index", self.us_first_char_index())
+                    .with_bytes(self.data, self.shape.us_first_char_index_byte_range()),
+            ),
+            24usize => Some(
+                Field::new("us_last_char_index", self.us_last_char_index())
+                    .with_bytes(self.data, self.shape.us_last_char_index_byte_range()),
+            ),
+            25usize => Some(
+                Field::new("s_typo_ascender", self.s_typo_ascender())
+                    .with_bytes(self.data, self.shape.s_typo_ascender_byte_range()),
+            ),
+            26usize => Some(
+                Field::new("s_typo_descender", self.s_typo_descender())
+                    .with_bytes(self.data, self.shape.s_typo_descender_byte_range()),
+            ),
+            27usize => Some(
+                Field::new("s_typo_line_gap", self.s_typo_line_gap())
+                    .with_bytes(self.data, self.shape.s_typo_line_gap_byte_range()),
+            ),
+            28usize => Some(
+                Field::new("us_win_ascent", self.us_win_ascent())
+                    .with_bytes(self.data, self.shape.us_win_ascent_byte_range()),
+            ),
+            29usize => Some(
+                Field::new("us_win_descent", self.us_win_descent())
+                    .with_bytes(self.data, self.shape.us_win_descent_byte_range()),
+            ),
+            30usize if version.compatible(1) => Some(
+                Field::new("ul_code_page_range_1", self.ul_code_page_range_1().unwrap())
+                    .with_bytes(self.data, self.shape.ul_code_page_range_1_byte_range()),
+            ),
+            31usize if version.compatible(1) => Some(
+                Field::new("ul_code_page_range_2", self.ul_code_page_range_2().unwrap())
+                    .with_bytes(self.data, self.shape.ul_code_page_range_2_byte_range()),
+            ),
+            32usize if version.compatible(2) => Some(
+                Field::new("sx_height", self.sx_height().unwrap())
+                    .with_bytes(self.data, self.shape.sx_height_byte_range()),
+            ),
+            33usize if version.compatible(2) => Some(
+                Field::new("s_cap_height", self.s_cap_height().unwrap())
+                    .with_bytes(self.data, self.shape.s_cap_height_byte_range()),
+            ),
+            34usize if version.compatible(2) => Some(
+                Field::new("us_default_char", self.us_default_char().unwrap())
+                    .with_bytes(self.data, self.shape.us_default_char_byte_range()),
+            ),
+            35usize if version.compatible(2) => Some(
+                Field::new("us_break_char", self.us_break_char().unwrap())
+                    .with_bytes(self.data, self.shape.us_break_char_byte_range()),
+            ),
+            36usize if version.compatible(2) => Some(
+                Field::new("us_max_context", self.us_max_context().unwrap())
+                    .with_bytes(self.data, self.shape.us_max_context_byte_range()),
+            ),
+            37usize if version.compatible(5) => Some(
+                Field::new(
+                    "us_lower_optical_point_size",
+                    self.us_lower_optical_point_size().unwrap(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.us_lower_optical_point_size_byte_range(),
+                ),
+            ),
+            38usize if version.compatible(5) => Some(
+                Field::new(
+                    "us_upper_optical_point_size",
+                    self.us_upper_optical_point_size().unwrap(),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.us_upper_optical_point_size_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
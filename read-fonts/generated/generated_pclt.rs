@@ -0,0 +1,290 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [PCLT (PCL 5)](https://learn.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct PcltMarker {
+    typeface_byte_len: usize,
+    character_complement_byte_len: usize,
+    file_name_byte_len: usize,
+}
+
+impl PcltMarker {
+    fn version_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Version16Dot16::RAW_BYTE_LEN
+    }
+    fn font_number_byte_range(&self) -> Range<usize> {
+        let start = self.version_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn pitch_byte_range(&self) -> Range<usize> {
+        let start = self.font_number_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn x_height_byte_range(&self) -> Range<usize> {
+        let start = self.pitch_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn style_byte_range(&self) -> Range<usize> {
+        let start = self.x_height_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn type_family_byte_range(&self) -> Range<usize> {
+        let start = self.style_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn cap_height_byte_range(&self) -> Range<usize> {
+        let start = self.type_family_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn symbol_set_byte_range(&self) -> Range<usize> {
+        let start = self.cap_height_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn typeface_byte_range(&self) -> Range<usize> {
+        let start = self.symbol_set_byte_range().end;
+        start..start + self.typeface_byte_len
+    }
+    fn character_complement_byte_range(&self) -> Range<usize> {
+        let start = self.typeface_byte_range().end;
+        start..start + self.character_complement_byte_len
+    }
+    fn file_name_byte_range(&self) -> Range<usize> {
+        let start = self.character_complement_byte_range().end;
+        start..start + self.file_name_byte_len
+    }
+    fn stroke_weight_byte_range(&self) -> Range<usize> {
+        let start = self.file_name_byte_range().end;
+        start..start + i8::RAW_BYTE_LEN
+    }
+    fn width_type_byte_range(&self) -> Range<usize> {
+        let start = self.stroke_weight_byte_range().end;
+        start..start + i8::RAW_BYTE_LEN
+    }
+    fn serif_style_byte_range(&self) -> Range<usize> {
+        let start = self.width_type_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+    fn reserved_byte_range(&self) -> Range<usize> {
+        let start = self.serif_style_byte_range().end;
+        start..start + u8::RAW_BYTE_LEN
+    }
+}
+
+impl TopLevelTable for Pclt<'_> {
+    /// `PCLT`
+    const TAG: Tag = Tag::new(b"PCLT");
+}
+
+impl<'a> FontRead<'a> for Pclt<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Version16Dot16>();
+        cursor.advance::<u32>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        let typeface_byte_len = 16_usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(typeface_byte_len);
+        let character_complement_byte_len = 8_usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(character_complement_byte_len);
+        let file_name_byte_len = 6_usize * u8::RAW_BYTE_LEN;
+        cursor.advance_by(file_name_byte_len);
+        cursor.advance::<i8>();
+        cursor.advance::<i8>();
+        cursor.advance::<u8>();
+        cursor.advance::<u8>();
+        cursor.finish(PcltMarker {
+            typeface_byte_len,
+            character_complement_byte_len,
+            file_name_byte_len,
+        })
+    }
+}
+
+/// The [PCLT (PCL 5)](https://learn.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+pub type Pclt<'a> = TableRef<'a, PcltMarker>;
+
+impl<'a> Pclt<'a> {
+    /// Table version number 1.0
+    pub fn version(&self) -> Version16Dot16 {
+        let range = self.shape.version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The HP font number.
+    pub fn font_number(&self) -> u32 {
+        let range = self.shape.font_number_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The width of the space in FUnits.
+    pub fn pitch(&self) -> u16 {
+        let range = self.shape.pitch_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The height of the optical line describing the height of the lowercase
+    /// x in FUnits.
+    pub fn x_height(&self) -> u16 {
+        let range = self.shape.x_height_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Contains information on the posture, width and structure of the
+    /// typeface.
+    pub fn style(&self) -> u16 {
+        let range = self.shape.style_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The font vendor code and the family code, which together identify
+    /// the font's typeface family.
+    pub fn type_family(&self) -> u16 {
+        let range = self.shape.type_family_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The height of the optical line describing the top of the uppercase
+    /// H in FUnits.
+    pub fn cap_height(&self) -> u16 {
+        let range = self.shape.cap_height_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The symbol set of the font.
+    pub fn symbol_set(&self) -> u16 {
+        let range = self.shape.symbol_set_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The name of the typeface, padded with spaces.
+    pub fn typeface(&self) -> &'a [u8] {
+        let range = self.shape.typeface_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// Identifies the symbol collections provided by the font.
+    pub fn character_complement(&self) -> &'a [u8] {
+        let range = self.shape.character_complement_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// A suggested six character ASCII file name for the font.
+    pub fn file_name(&self) -> &'a [u8] {
+        let range = self.shape.file_name_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+
+    /// The stroke weight of the font, from -7 to 7.
+    pub fn stroke_weight(&self) -> i8 {
+        let range = self.shape.stroke_weight_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The appearance width of the font, from -5 to 5.
+    pub fn width_type(&self) -> i8 {
+        let range = self.shape.width_type_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The serif style and stroke structure of the font.
+    pub fn serif_style(&self) -> u8 {
+        let range = self.shape.serif_style_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Reserved; set to 0.
+    pub fn reserved(&self) -> u8 {
+        let range = self.shape.reserved_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Pclt<'a> {
+    fn type_name(&self) -> &str {
+        "Pclt"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("font_number", self.font_number())
+                    .with_bytes(self.data, self.shape.font_number_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("pitch", self.pitch())
+                    .with_bytes(self.data, self.shape.pitch_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("x_height", self.x_height())
+                    .with_bytes(self.data, self.shape.x_height_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("style", self.style())
+                    .with_bytes(self.data, self.shape.style_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("type_family", self.type_family())
+                    .with_bytes(self.data, self.shape.type_family_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("cap_height", self.cap_height())
+                    .with_bytes(self.data, self.shape.cap_height_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("symbol_set", self.symbol_set())
+                    .with_bytes(self.data, self.shape.symbol_set_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("typeface", self.typeface())
+                    .with_bytes(self.data, self.shape.typeface_byte_range()),
+            ),
+            9usize => Some(
+                Field::new("character_complement", self.character_complement())
+                    .with_bytes(self.data, self.shape.character_complement_byte_range()),
+            ),
+            10usize => Some(
+                Field::new("file_name", self.file_name())
+                    .with_bytes(self.data, self.shape.file_name_byte_range()),
+            ),
+            11usize => Some(
+                Field::new("stroke_weight", self.stroke_weight())
+                    .with_bytes(self.data, self.shape.stroke_weight_byte_range()),
+            ),
+            12usize => Some(
+                Field::new("width_type", self.width_type())
+                    .with_bytes(self.data, self.shape.width_type_byte_range()),
+            ),
+            13usize => Some(
+                Field::new("serif_style", self.serif_style())
+                    .with_bytes(self.data, self.shape.serif_style_byte_range()),
+            ),
+            14usize => Some(
+                Field::new("reserved", self.reserved())
+                    .with_bytes(self.data, self.shape.reserved_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Pclt<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
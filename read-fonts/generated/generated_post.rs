@@ -225,28 +225,54 @@ impl<'a> SomeTable<'a> for Post<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("italic_angle", self.italic_angle())),
-            2usize => Some(Field::new("underline_position", self.underline_position())),
-            3usize => Some(Field::new(
-                "underline_thickness",
-                self.underline_thickness(),
-            )),
-            4usize => Some(Field::new("is_fixed_pitch", self.is_fixed_pitch())),
-            5usize => Some(Field::new("min_mem_type42", self.min_mem_type42())),
-            6usize => Some(Field::new("max_mem_type42", self.max_mem_type42())),
-            7usize => Some(Field::new("min_mem_type1", self.min_mem_type1())),
-            8usize => Some(Field::new("max_mem_type1", self.max_mem_type1())),
-            9usize if version.compatible((2, 0)) => {
-                Some(Field::new("num_glyphs", self.num_glyphs().unwrap()))
-            }
-            10usize if version.compatible((2, 0)) => Some(Field::new(
-                "glyph_name_index",
-                self.glyph_name_index().unwrap(),
-            )),
-            11usize if version.compatible((2, 0)) => {
-                Some(Field::new("string_data", self.traverse_string_data()))
-            }
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("italic_angle", self.italic_angle())
+                    .with_bytes(self.data, self.shape.italic_angle_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("underline_position", self.underline_position())
+                    .with_bytes(self.data, self.shape.underline_position_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("underline_thickness", self.underline_thickness())
+                    .with_bytes(self.data, self.shape.underline_thickness_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("is_fixed_pitch", self.is_fixed_pitch())
+                    .with_bytes(self.data, self.shape.is_fixed_pitch_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("min_mem_type42", self.min_mem_type42())
+                    .with_bytes(self.data, self.shape.min_mem_type42_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("max_mem_type42", self.max_mem_type42())
+                    .with_bytes(self.data, self.shape.max_mem_type42_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("min_mem_type1", self.min_mem_type1())
+                    .with_bytes(self.data, self.shape.min_mem_type1_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("max_mem_type1", self.max_mem_type1())
+                    .with_bytes(self.data, self.shape.max_mem_type1_byte_range()),
+            ),
+            9usize if version.compatible((2, 0)) => Some(
+                Field::new("num_glyphs", self.num_glyphs().unwrap())
+                    .with_bytes(self.data, self.shape.num_glyphs_byte_range()),
+            ),
+            10usize if version.compatible((2, 0)) => Some(
+                Field::new("glyph_name_index", self.glyph_name_index().unwrap())
+                    .with_bytes(self.data, self.shape.glyph_name_index_byte_range()),
+            ),
+            11usize if version.compatible((2, 0)) => Some(
+                Field::new("string_data", self.traverse_string_data())
+                    .with_bytes(self.data, self.shape.string_data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -85,10 +85,21 @@ impl<'a> SomeTable<'a> for Index1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("count", self.count())),
-            1usize => Some(Field::new("off_size", self.off_size())),
-            2usize => Some(Field::new("offsets", self.offsets())),
-            3usize => Some(Field::new("data", self.data())),
+            0usize => Some(
+                Field::new("count", self.count())
+                    .with_bytes(self.data, self.shape.count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("off_size", self.off_size())
+                    .with_bytes(self.data, self.shape.off_size_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("offsets", self.offsets())
+                    .with_bytes(self.data, self.shape.offsets_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("data", self.data()).with_bytes(self.data, self.shape.data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -181,10 +192,21 @@ impl<'a> SomeTable<'a> for Index2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("count", self.count())),
-            1usize => Some(Field::new("off_size", self.off_size())),
-            2usize => Some(Field::new("offsets", self.offsets())),
-            3usize => Some(Field::new("data", self.data())),
+            0usize => Some(
+                Field::new("count", self.count())
+                    .with_bytes(self.data, self.shape.count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("off_size", self.off_size())
+                    .with_bytes(self.data, self.shape.off_size_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("offsets", self.offsets())
+                    .with_bytes(self.data, self.shape.offsets_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("data", self.data()).with_bytes(self.data, self.shape.data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -198,6 +220,7 @@ impl<'a> std::fmt::Debug for Index2<'a> {
 }
 
 /// Associates a glyph identifier with a Font DICT.
+#[derive(Clone)]
 pub enum FdSelect<'a> {
     Format0(FdSelectFormat0<'a>),
     Format3(FdSelectFormat3<'a>),
@@ -300,8 +323,13 @@ impl<'a> SomeTable<'a> for FdSelectFormat0<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("fds", self.fds())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("fds", self.fds()).with_bytes(self.data, self.shape.fds_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -392,17 +420,29 @@ impl<'a> SomeTable<'a> for FdSelectFormat3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("n_ranges", self.n_ranges())),
-            2usize => Some(Field::new(
-                "ranges",
-                traversal::FieldType::array_of_records(
-                    stringify!(FdSelectRange3),
-                    self.ranges(),
-                    self.offset_data(),
-                ),
-            )),
-            3usize => Some(Field::new("sentinel", self.sentinel())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_ranges", self.n_ranges())
+                    .with_bytes(self.data, self.shape.n_ranges_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "ranges",
+                    traversal::FieldType::array_of_records(
+                        stringify!(FdSelectRange3),
+                        self.ranges(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.ranges_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("sentinel", self.sentinel())
+                    .with_bytes(self.data, self.shape.sentinel_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -455,11 +495,17 @@ impl<'a> SomeRecord<'a> for FdSelectRange3 {
         RecordResolver {
             name: "FdSelectRange3",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("first", self.first())),
-                1usize => Some(Field::new("fd", self.fd())),
+                0usize => {
+                    Some(Field::new("first", self.first()).with_record_range(0, u16::RAW_BYTE_LEN))
+                }
+                1usize => Some(
+                    Field::new("fd", self.fd())
+                        .with_record_range(u16::RAW_BYTE_LEN, u8::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -542,17 +588,29 @@ impl<'a> SomeTable<'a> for FdSelectFormat4<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("n_ranges", self.n_ranges())),
-            2usize => Some(Field::new(
-                "ranges",
-                traversal::FieldType::array_of_records(
-                    stringify!(FdSelectRange4),
-                    self.ranges(),
-                    self.offset_data(),
-                ),
-            )),
-            3usize => Some(Field::new("sentinel", self.sentinel())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("n_ranges", self.n_ranges())
+                    .with_bytes(self.data, self.shape.n_ranges_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "ranges",
+                    traversal::FieldType::array_of_records(
+                        stringify!(FdSelectRange4),
+                        self.ranges(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.ranges_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("sentinel", self.sentinel())
+                    .with_bytes(self.data, self.shape.sentinel_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -605,11 +663,17 @@ impl<'a> SomeRecord<'a> for FdSelectRange4 {
         RecordResolver {
             name: "FdSelectRange4",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("first", self.first())),
-                1usize => Some(Field::new("fd", self.fd())),
+                0usize => {
+                    Some(Field::new("first", self.first()).with_record_range(0, u32::RAW_BYTE_LEN))
+                }
+                1usize => Some(
+                    Field::new("fd", self.fd())
+                        .with_record_range(u32::RAW_BYTE_LEN, u16::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -0,0 +1,71 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [prep (Control Value Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/prep) table.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct PrepMarker {
+    instructions_byte_len: usize,
+}
+
+impl PrepMarker {
+    fn instructions_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + self.instructions_byte_len
+    }
+}
+
+impl TopLevelTable for Prep<'_> {
+    /// `prep`
+    const TAG: Tag = Tag::new(b"prep");
+}
+
+impl<'a> FontRead<'a> for Prep<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let instructions_byte_len = cursor.remaining_bytes();
+        cursor.advance_by(instructions_byte_len);
+        cursor.finish(PrepMarker {
+            instructions_byte_len,
+        })
+    }
+}
+
+/// The [prep (Control Value Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/prep) table.
+pub type Prep<'a> = TableRef<'a, PrepMarker>;
+
+impl<'a> Prep<'a> {
+    /// Instructions that are executed whenever the point size, transform
+    /// or variation instance changes.
+    pub fn instructions(&self) -> &'a [u8] {
+        let range = self.shape.instructions_byte_range();
+        self.data.read_array(range).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Prep<'a> {
+    fn type_name(&self) -> &str {
+        "Prep"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("instructions", self.instructions())
+                    .with_bytes(self.data, self.shape.instructions_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Prep<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
@@ -151,30 +151,54 @@ impl<'a> SomeTable<'a> for Stat<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("design_axis_size", self.design_axis_size())),
-            2usize => Some(Field::new("design_axis_count", self.design_axis_count())),
-            3usize => Some(Field::new(
-                "design_axes_offset",
-                traversal::FieldType::offset_to_array_of_records(
-                    self.design_axes_offset(),
-                    self.design_axes(),
-                    stringify!(AxisRecord),
-                    self.offset_data(),
-                ),
-            )),
-            4usize => Some(Field::new("axis_value_count", self.axis_value_count())),
-            5usize => Some(Field::new(
-                "offset_to_axis_value_offsets",
-                FieldType::offset(
-                    self.offset_to_axis_value_offsets(),
-                    self.offset_to_axis_values(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("design_axis_size", self.design_axis_size())
+                    .with_bytes(self.data, self.shape.design_axis_size_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("design_axis_count", self.design_axis_count())
+                    .with_bytes(self.data, self.shape.design_axis_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "design_axes_offset",
+                    traversal::FieldType::offset_to_array_of_records(
+                        self.design_axes_offset(),
+                        self.design_axes(),
+                        stringify!(AxisRecord),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.design_axes_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("axis_value_count", self.axis_value_count())
+                    .with_bytes(self.data, self.shape.axis_value_count_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "offset_to_axis_value_offsets",
+                    FieldType::offset(
+                        self.offset_to_axis_value_offsets(),
+                        self.offset_to_axis_values(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.offset_to_axis_value_offsets_byte_range(),
                 ),
-            )),
-            6usize if version.compatible((1, 1)) => Some(Field::new(
-                "elided_fallback_name_id",
-                self.elided_fallback_name_id().unwrap(),
-            )),
+            ),
+            6usize if version.compatible((1, 1)) => Some(
+                Field::new(
+                    "elided_fallback_name_id",
+                    self.elided_fallback_name_id().unwrap(),
+                )
+                .with_bytes(self.data, self.shape.elided_fallback_name_id_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -240,12 +264,23 @@ impl<'a> SomeRecord<'a> for AxisRecord {
         RecordResolver {
             name: "AxisRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("axis_tag", self.axis_tag())),
-                1usize => Some(Field::new("axis_name_id", self.axis_name_id())),
-                2usize => Some(Field::new("axis_ordering", self.axis_ordering())),
+                0usize => Some(
+                    Field::new("axis_tag", self.axis_tag()).with_record_range(0, Tag::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("axis_name_id", self.axis_name_id())
+                        .with_record_range(Tag::RAW_BYTE_LEN, NameId::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new("axis_ordering", self.axis_ordering()).with_record_range(
+                        Tag::RAW_BYTE_LEN + NameId::RAW_BYTE_LEN,
+                        u16::RAW_BYTE_LEN,
+                    ),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -317,20 +352,23 @@ impl<'a> SomeTable<'a> for AxisValueArray<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some({
-                let data = self.data;
-                Field::new(
-                    "axis_value_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<AxisValue>(),
-                        self.axis_value_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<AxisValue>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "axis_value_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<AxisValue>(),
+                            self.axis_value_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<AxisValue>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.axis_value_offsets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -344,6 +382,7 @@ impl<'a> std::fmt::Debug for AxisValueArray<'a> {
 }
 
 /// [Axis Value Tables](https://docs.microsoft.com/en-us/typography/opentype/spec/stat#axis-value-tables)
+#[derive(Clone)]
 pub enum AxisValue<'a> {
     Format1(AxisValueFormat1<'a>),
     Format2(AxisValueFormat2<'a>),
@@ -482,11 +521,26 @@ impl<'a> SomeTable<'a> for AxisValueFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("axis_index", self.axis_index())),
-            2usize => Some(Field::new("flags", self.flags())),
-            3usize => Some(Field::new("value_name_id", self.value_name_id())),
-            4usize => Some(Field::new("value", self.value())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("axis_index", self.axis_index())
+                    .with_bytes(self.data, self.shape.axis_index_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("value_name_id", self.value_name_id())
+                    .with_bytes(self.data, self.shape.value_name_id_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("value", self.value())
+                    .with_bytes(self.data, self.shape.value_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -612,13 +666,34 @@ impl<'a> SomeTable<'a> for AxisValueFormat2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("axis_index", self.axis_index())),
-            2usize => Some(Field::new("flags", self.flags())),
-            3usize => Some(Field::new("value_name_id", self.value_name_id())),
-            4usize => Some(Field::new("nominal_value", self.nominal_value())),
-            5usize => Some(Field::new("range_min_value", self.range_min_value())),
-            6usize => Some(Field::new("range_max_value", self.range_max_value())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("axis_index", self.axis_index())
+                    .with_bytes(self.data, self.shape.axis_index_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("value_name_id", self.value_name_id())
+                    .with_bytes(self.data, self.shape.value_name_id_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("nominal_value", self.nominal_value())
+                    .with_bytes(self.data, self.shape.nominal_value_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("range_min_value", self.range_min_value())
+                    .with_bytes(self.data, self.shape.range_min_value_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("range_max_value", self.range_max_value())
+                    .with_bytes(self.data, self.shape.range_max_value_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -731,12 +806,30 @@ impl<'a> SomeTable<'a> for AxisValueFormat3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("axis_index", self.axis_index())),
-            2usize => Some(Field::new("flags", self.flags())),
-            3usize => Some(Field::new("value_name_id", self.value_name_id())),
-            4usize => Some(Field::new("value", self.value())),
-            5usize => Some(Field::new("linked_value", self.linked_value())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("axis_index", self.axis_index())
+                    .with_bytes(self.data, self.shape.axis_index_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("value_name_id", self.value_name_id())
+                    .with_bytes(self.data, self.shape.value_name_id_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("value", self.value())
+                    .with_bytes(self.data, self.shape.value_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("linked_value", self.linked_value())
+                    .with_bytes(self.data, self.shape.linked_value_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -843,18 +936,33 @@ impl<'a> SomeTable<'a> for AxisValueFormat4<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("axis_count", self.axis_count())),
-            2usize => Some(Field::new("flags", self.flags())),
-            3usize => Some(Field::new("value_name_id", self.value_name_id())),
-            4usize => Some(Field::new(
-                "axis_values",
-                traversal::FieldType::array_of_records(
-                    stringify!(AxisValueRecord),
-                    self.axis_values(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("axis_count", self.axis_count())
+                    .with_bytes(self.data, self.shape.axis_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("flags", self.flags())
+                    .with_bytes(self.data, self.shape.flags_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("value_name_id", self.value_name_id())
+                    .with_bytes(self.data, self.shape.value_name_id_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "axis_values",
+                    traversal::FieldType::array_of_records(
+                        stringify!(AxisValueRecord),
+                        self.axis_values(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.axis_values_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -909,11 +1017,18 @@ impl<'a> SomeRecord<'a> for AxisValueRecord {
         RecordResolver {
             name: "AxisValueRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("axis_index", self.axis_index())),
-                1usize => Some(Field::new("value", self.value())),
+                0usize => Some(
+                    Field::new("axis_index", self.axis_index())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("value", self.value())
+                        .with_record_range(u16::RAW_BYTE_LEN, Fixed::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
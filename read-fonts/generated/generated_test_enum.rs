@@ -125,11 +125,18 @@ impl<'a> SomeRecord<'a> for MyRecord {
         RecordResolver {
             name: "MyRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("my_enum1", self.my_enum1())),
-                1usize => Some(Field::new("my_enum2", self.my_enum2())),
+                0usize => Some(
+                    Field::new("my_enum1", self.my_enum1())
+                        .with_record_range(0, MyEnum1::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("my_enum2", self.my_enum2())
+                        .with_record_range(MyEnum1::RAW_BYTE_LEN, MyEnum2::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
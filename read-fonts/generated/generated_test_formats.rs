@@ -64,9 +64,16 @@ impl<'a> SomeTable<'a> for Table1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("heft", self.heft())),
-            2usize => Some(Field::new("flex", self.flex())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("heft", self.heft()).with_bytes(self.data, self.shape.heft_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("flex", self.flex()).with_bytes(self.data, self.shape.flex_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -141,9 +148,18 @@ impl<'a> SomeTable<'a> for Table2<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("value_count", self.value_count())),
-            2usize => Some(Field::new("values", self.values())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("value_count", self.value_count())
+                    .with_bytes(self.data, self.shape.value_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("values", self.values())
+                    .with_bytes(self.data, self.shape.values_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -205,8 +221,14 @@ impl<'a> SomeTable<'a> for Table3<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("something", self.something())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("something", self.something())
+                    .with_bytes(self.data, self.shape.something_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -219,6 +241,7 @@ impl<'a> std::fmt::Debug for Table3<'a> {
     }
 }
 
+#[derive(Clone)]
 pub enum MyTable<'a> {
     Format1(Table1<'a>),
     MyFormat22(Table2<'a>),
@@ -204,52 +204,86 @@ impl<'a> SomeTable<'a> for KindsOfOffsets<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "nonnullable_offset",
-                FieldType::offset(self.nonnullable_offset(), self.nonnullable()),
-            )),
-            2usize => Some(Field::new(
-                "nullable_offset",
-                FieldType::offset(self.nullable_offset(), self.nullable()),
-            )),
-            3usize => Some(Field::new("array_offset_count", self.array_offset_count())),
-            4usize => Some(Field::new(
-                "array_offset",
-                FieldType::offset_to_array_of_scalars(self.array_offset(), self.array()),
-            )),
-            5usize => Some(Field::new(
-                "record_array_offset",
-                traversal::FieldType::offset_to_array_of_records(
-                    self.record_array_offset(),
-                    self.record_array(),
-                    stringify!(Shmecord),
-                    self.offset_data(),
-                ),
-            )),
-            6usize if version.compatible((1, 1)) => Some(Field::new(
-                "versioned_nullable_record_array_offset",
-                traversal::FieldType::offset_to_array_of_records(
-                    self.versioned_nullable_record_array_offset().unwrap(),
-                    self.versioned_nullable_record_array().unwrap(),
-                    stringify!(Shmecord),
-                    self.offset_data(),
-                ),
-            )),
-            7usize if version.compatible((1, 1)) => Some(Field::new(
-                "versioned_nonnullable_offset",
-                FieldType::offset(
-                    self.versioned_nonnullable_offset().unwrap(),
-                    self.versioned_nonnullable().unwrap(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "nonnullable_offset",
+                    FieldType::offset(self.nonnullable_offset(), self.nonnullable()),
+                )
+                .with_bytes(self.data, self.shape.nonnullable_offset_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "nullable_offset",
+                    FieldType::offset(self.nullable_offset(), self.nullable()),
+                )
+                .with_bytes(self.data, self.shape.nullable_offset_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("array_offset_count", self.array_offset_count())
+                    .with_bytes(self.data, self.shape.array_offset_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "array_offset",
+                    FieldType::offset_to_array_of_scalars(self.array_offset(), self.array()),
+                )
+                .with_bytes(self.data, self.shape.array_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "record_array_offset",
+                    traversal::FieldType::offset_to_array_of_records(
+                        self.record_array_offset(),
+                        self.record_array(),
+                        stringify!(Shmecord),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.record_array_offset_byte_range()),
+            ),
+            6usize if version.compatible((1, 1)) => Some(
+                Field::new(
+                    "versioned_nullable_record_array_offset",
+                    traversal::FieldType::offset_to_array_of_records(
+                        self.versioned_nullable_record_array_offset().unwrap(),
+                        self.versioned_nullable_record_array(),
+                        stringify!(Shmecord),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape
+                        .versioned_nullable_record_array_offset_byte_range(),
                 ),
-            )),
-            8usize if version.compatible((1, 1)) => Some(Field::new(
-                "versioned_nullable_offset",
-                FieldType::offset(
-                    self.versioned_nullable_offset().unwrap(),
-                    self.versioned_nullable().unwrap(),
+            ),
+            7usize if version.compatible((1, 1)) => Some(
+                Field::new(
+                    "versioned_nonnullable_offset",
+                    FieldType::offset(
+                        self.versioned_nonnullable_offset().unwrap(),
+                        self.versioned_nonnullable().unwrap(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.versioned_nonnullable_offset_byte_range(),
                 ),
-            )),
+            ),
+            8usize if version.compatible((1, 1)) => Some(
+                Field::new(
+                    "versioned_nullable_offset",
+                    FieldType::offset(
+                        self.versioned_nullable_offset().unwrap(),
+                        self.versioned_nullable(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.versioned_nullable_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -416,64 +450,88 @@ impl<'a> SomeTable<'a> for KindsOfArraysOfOffsets<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("count", self.count())),
-            2usize => Some({
-                let data = self.data;
-                Field::new(
-                    "nonnullable_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<Dummy>(),
-                        self.nonnullable_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<Dummy>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
-            3usize => Some({
-                let data = self.data;
-                Field::new(
-                    "nullable_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<Dummy>(),
-                        self.nullable_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<Dummy>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
-            4usize if version.compatible((1, 1)) => Some({
-                let data = self.data;
-                Field::new(
-                    "versioned_nonnullable_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<Dummy>(),
-                        self.versioned_nonnullable_offsets().unwrap(),
-                        move |off| {
-                            let target = off.get().resolve::<Dummy>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
-            5usize if version.compatible((1, 1)) => Some({
-                let data = self.data;
-                Field::new(
-                    "versioned_nullable_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<Dummy>(),
-                        self.versioned_nullable_offsets().unwrap(),
-                        move |off| {
-                            let target = off.get().resolve::<Dummy>(data);
-                            FieldType::offset(off.get(), target)
-                        },
-                    ),
-                )
-            }),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("count", self.count())
+                    .with_bytes(self.data, self.shape.count_byte_range()),
+            ),
+            2usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "nonnullable_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<Dummy>(),
+                            self.nonnullable_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<Dummy>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.nonnullable_offsets_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "nullable_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<Dummy>(),
+                            self.nullable_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<Dummy>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(self.data, self.shape.nullable_offsets_byte_range()),
+            ),
+            4usize if version.compatible((1, 1)) => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "versioned_nonnullable_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<Dummy>(),
+                            self.versioned_nonnullable_offsets().unwrap(),
+                            move |off| {
+                                let target = off.get().resolve::<Dummy>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.versioned_nonnullable_offsets_byte_range(),
+                ),
+            ),
+            5usize if version.compatible((1, 1)) => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "versioned_nullable_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<Dummy>(),
+                            self.versioned_nullable_offsets().unwrap(),
+                            move |off| {
+                                let target = off.get().resolve::<Dummy>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.versioned_nullable_offsets_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
@@ -611,29 +669,44 @@ impl<'a> SomeTable<'a> for KindsOfArrays<'a> {
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         let version = self.version();
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new("count", self.count())),
-            2usize => Some(Field::new("scalars", self.scalars())),
-            3usize => Some(Field::new(
-                "records",
-                traversal::FieldType::array_of_records(
-                    stringify!(Shmecord),
-                    self.records(),
-                    self.offset_data(),
-                ),
-            )),
-            4usize if version.compatible(1) => Some(Field::new(
-                "versioned_scalars",
-                self.versioned_scalars().unwrap(),
-            )),
-            5usize if version.compatible(1) => Some(Field::new(
-                "versioned_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(Shmecord),
-                    self.versioned_records().unwrap(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("count", self.count())
+                    .with_bytes(self.data, self.shape.count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("scalars", self.scalars())
+                    .with_bytes(self.data, self.shape.scalars_byte_range()),
+            ),
+            3usize => Some(
+                Field::new(
+                    "records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(Shmecord),
+                        self.records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.records_byte_range()),
+            ),
+            4usize if version.compatible(1) => Some(
+                Field::new("versioned_scalars", self.versioned_scalars().unwrap())
+                    .with_bytes(self.data, self.shape.versioned_scalars_byte_range()),
+            ),
+            5usize if version.compatible(1) => Some(
+                Field::new(
+                    "versioned_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(Shmecord),
+                        self.versioned_records().unwrap(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.versioned_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -686,7 +759,10 @@ impl<'a> SomeTable<'a> for Dummy<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("value", self.value())),
+            0usize => Some(
+                Field::new("value", self.value())
+                    .with_bytes(self.data, self.shape.value_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -734,11 +810,17 @@ impl<'a> SomeRecord<'a> for Shmecord {
         RecordResolver {
             name: "Shmecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("length", self.length())),
-                1usize => Some(Field::new("breadth", self.breadth())),
+                0usize => Some(
+                    Field::new("length", self.length()).with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("breadth", self.breadth())
+                        .with_record_range(u16::RAW_BYTE_LEN, u32::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -43,8 +43,11 @@ impl<'a> FontRead<'a> for BasicTable<'a> {
         cursor.advance_by(simple_records_byte_len);
         let arrays_inner_count: u16 = cursor.read()?;
         let array_records_count: u32 = cursor.read()?;
-        let array_records_byte_len = array_records_count as usize
-            * <ContainsArrays as ComputeSize>::compute_size(&arrays_inner_count);
+        let array_records_byte_len = (array_records_count as usize)
+            .checked_mul(<ContainsArrays as ComputeSize>::compute_size(
+                &arrays_inner_count,
+            )?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(array_records_byte_len);
         cursor.finish(BasicTableMarker {
             simple_records_byte_len,
@@ -91,28 +94,40 @@ impl<'a> SomeTable<'a> for BasicTable<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("simple_count", self.simple_count())),
-            1usize => Some(Field::new(
-                "simple_records",
-                traversal::FieldType::array_of_records(
-                    stringify!(SimpleRecord),
-                    self.simple_records(),
-                    self.offset_data(),
-                ),
-            )),
-            2usize => Some(Field::new("arrays_inner_count", self.arrays_inner_count())),
-            3usize => Some(Field::new(
-                "array_records_count",
-                self.array_records_count(),
-            )),
-            4usize => Some(Field::new(
-                "array_records",
-                traversal::FieldType::computed_array(
-                    "ContainsArrays",
-                    self.array_records(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("simple_count", self.simple_count())
+                    .with_bytes(self.data, self.shape.simple_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "simple_records",
+                    traversal::FieldType::array_of_records(
+                        stringify!(SimpleRecord),
+                        self.simple_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.simple_records_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("arrays_inner_count", self.arrays_inner_count())
+                    .with_bytes(self.data, self.shape.arrays_inner_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("array_records_count", self.array_records_count())
+                    .with_bytes(self.data, self.shape.array_records_count_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "array_records",
+                    traversal::FieldType::computed_array(
+                        "ContainsArrays",
+                        self.array_records(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.array_records_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -160,11 +175,17 @@ impl<'a> SomeRecord<'a> for SimpleRecord {
         RecordResolver {
             name: "SimpleRecord",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("val1", self.val1())),
-                1usize => Some(Field::new("va2", self.va2())),
+                0usize => {
+                    Some(Field::new("val1", self.val1()).with_record_range(0, u16::RAW_BYTE_LEN))
+                }
+                1usize => Some(
+                    Field::new("va2", self.va2())
+                        .with_record_range(u16::RAW_BYTE_LEN, u32::RAW_BYTE_LEN),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -190,9 +211,11 @@ impl ReadArgs for ContainsArrays<'_> {
 }
 
 impl ComputeSize for ContainsArrays<'_> {
-    fn compute_size(args: &u16) -> usize {
+    fn compute_size(args: &u16) -> Result<usize, ReadError> {
         let array_len = *args;
-        array_len as usize * u16::RAW_BYTE_LEN + array_len as usize * SimpleRecord::RAW_BYTE_LEN
+        let size = array_len as usize * u16::RAW_BYTE_LEN
+            + array_len as usize * SimpleRecord::RAW_BYTE_LEN;
+        Ok(size)
     }
 }
 
@@ -236,6 +259,7 @@ impl<'a> SomeRecord<'a> for ContainsArrays<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -291,23 +315,36 @@ impl<'a> SomeRecord<'a> for ContainsOffests {
         RecordResolver {
             name: "ContainsOffests",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("off_array_count", self.off_array_count())),
-                1usize => Some(Field::new(
-                    "array_offset",
-                    traversal::FieldType::offset_to_array_of_records(
-                        self.array_offset(),
-                        self.array(_data),
-                        stringify!(SimpleRecord),
-                        _data,
+                0usize => Some(
+                    Field::new("off_array_count", self.off_array_count())
+                        .with_record_range(0, u16::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new(
+                        "array_offset",
+                        traversal::FieldType::offset_to_array_of_records(
+                            self.array_offset(),
+                            self.array(_data),
+                            stringify!(SimpleRecord),
+                            _data,
+                        ),
+                    )
+                    .with_record_range(u16::RAW_BYTE_LEN, Offset16::RAW_BYTE_LEN),
+                ),
+                2usize => Some(
+                    Field::new(
+                        "other_offset",
+                        FieldType::offset(self.other_offset(), self.other(_data)),
+                    )
+                    .with_record_range(
+                        u16::RAW_BYTE_LEN + Offset16::RAW_BYTE_LEN,
+                        Offset32::RAW_BYTE_LEN,
                     ),
-                )),
-                2usize => Some(Field::new(
-                    "other_offset",
-                    FieldType::offset(self.other_offset(), self.other(_data)),
-                )),
+                ),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -101,11 +101,14 @@ impl<'a> SomeTable<'a> for TupleVariationHeader<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "variation_data_size",
-                self.variation_data_size(),
-            )),
-            1usize => Some(Field::new("tuple_index", self.traverse_tuple_index())),
+            0usize => Some(
+                Field::new("variation_data_size", self.variation_data_size())
+                    .with_bytes(self.data, self.shape.variation_data_size_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("tuple_index", self.traverse_tuple_index())
+                    .with_bytes(self.data, self.shape.tuple_index_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -147,9 +150,10 @@ impl ReadArgs for Tuple<'_> {
 }
 
 impl ComputeSize for Tuple<'_> {
-    fn compute_size(args: &u16) -> usize {
+    fn compute_size(args: &u16) -> Result<usize, ReadError> {
         let axis_count = *args;
-        axis_count as usize * F2Dot14::RAW_BYTE_LEN
+        let size = axis_count as usize * F2Dot14::RAW_BYTE_LEN;
+        Ok(size)
     }
 }
 
@@ -184,6 +188,7 @@ impl<'a> SomeRecord<'a> for Tuple<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -267,10 +272,22 @@ impl<'a> SomeTable<'a> for DeltaSetIndexMapFormat0<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("entry_format", self.entry_format())),
-            2usize => Some(Field::new("map_count", self.map_count())),
-            3usize => Some(Field::new("map_data", self.map_data())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("entry_format", self.entry_format())
+                    .with_bytes(self.data, self.shape.entry_format_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("map_count", self.map_count())
+                    .with_bytes(self.data, self.shape.map_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("map_data", self.map_data())
+                    .with_bytes(self.data, self.shape.map_data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -362,10 +379,22 @@ impl<'a> SomeTable<'a> for DeltaSetIndexMapFormat1<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new("entry_format", self.entry_format())),
-            2usize => Some(Field::new("map_count", self.map_count())),
-            3usize => Some(Field::new("map_data", self.map_data())),
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("entry_format", self.entry_format())
+                    .with_bytes(self.data, self.shape.entry_format_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("map_count", self.map_count())
+                    .with_bytes(self.data, self.shape.map_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("map_data", self.map_data())
+                    .with_bytes(self.data, self.shape.map_data_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -379,6 +408,7 @@ impl<'a> std::fmt::Debug for DeltaSetIndexMapFormat1<'a> {
 }
 
 /// The [DeltaSetIndexMap](https://learn.microsoft.com/en-us/typography/opentype/spec/otvarcommonformats#associating-target-items-to-variation-data) table
+#[derive(Clone)]
 pub enum DeltaSetIndexMap<'a> {
     Format0(DeltaSetIndexMapFormat0<'a>),
     Format1(DeltaSetIndexMapFormat1<'a>),
@@ -758,8 +788,9 @@ impl<'a> FontRead<'a> for VariationRegionList<'a> {
         let mut cursor = data.cursor();
         let axis_count: u16 = cursor.read()?;
         let region_count: u16 = cursor.read()?;
-        let variation_regions_byte_len =
-            region_count as usize * <VariationRegion as ComputeSize>::compute_size(&axis_count);
+        let variation_regions_byte_len = (region_count as usize)
+            .checked_mul(<VariationRegion as ComputeSize>::compute_size(&axis_count)?)
+            .ok_or(ReadError::OutOfBounds)?;
         cursor.advance_by(variation_regions_byte_len);
         cursor.finish(VariationRegionListMarker {
             variation_regions_byte_len,
@@ -799,16 +830,25 @@ impl<'a> SomeTable<'a> for VariationRegionList<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("axis_count", self.axis_count())),
-            1usize => Some(Field::new("region_count", self.region_count())),
-            2usize => Some(Field::new(
-                "variation_regions",
-                traversal::FieldType::computed_array(
-                    "VariationRegion",
-                    self.variation_regions(),
-                    self.offset_data(),
-                ),
-            )),
+            0usize => Some(
+                Field::new("axis_count", self.axis_count())
+                    .with_bytes(self.data, self.shape.axis_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("region_count", self.region_count())
+                    .with_bytes(self.data, self.shape.region_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new(
+                    "variation_regions",
+                    traversal::FieldType::computed_array(
+                        "VariationRegion",
+                        self.variation_regions(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.variation_regions_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -842,9 +882,10 @@ impl ReadArgs for VariationRegion<'_> {
 }
 
 impl ComputeSize for VariationRegion<'_> {
-    fn compute_size(args: &u16) -> usize {
+    fn compute_size(args: &u16) -> Result<usize, ReadError> {
         let axis_count = *args;
-        axis_count as usize * RegionAxisCoordinates::RAW_BYTE_LEN
+        let size = axis_count as usize * RegionAxisCoordinates::RAW_BYTE_LEN;
+        Ok(size)
     }
 }
 
@@ -886,6 +927,7 @@ impl<'a> SomeRecord<'a> for VariationRegion<'a> {
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -938,12 +980,22 @@ impl<'a> SomeRecord<'a> for RegionAxisCoordinates {
         RecordResolver {
             name: "RegionAxisCoordinates",
             get_field: Box::new(move |idx, _data| match idx {
-                0usize => Some(Field::new("start_coord", self.start_coord())),
-                1usize => Some(Field::new("peak_coord", self.peak_coord())),
-                2usize => Some(Field::new("end_coord", self.end_coord())),
+                0usize => Some(
+                    Field::new("start_coord", self.start_coord())
+                        .with_record_range(0, F2Dot14::RAW_BYTE_LEN),
+                ),
+                1usize => Some(
+                    Field::new("peak_coord", self.peak_coord())
+                        .with_record_range(F2Dot14::RAW_BYTE_LEN, F2Dot14::RAW_BYTE_LEN),
+                ),
+                2usize => Some(Field::new("end_coord", self.end_coord()).with_record_range(
+                    F2Dot14::RAW_BYTE_LEN + F2Dot14::RAW_BYTE_LEN,
+                    F2Dot14::RAW_BYTE_LEN,
+                )),
                 _ => None,
             }),
             data,
+            bytes: None,
         }
     }
 }
@@ -1042,32 +1094,50 @@ impl<'a> SomeTable<'a> for ItemVariationStore<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("format", self.format())),
-            1usize => Some(Field::new(
-                "variation_region_list_offset",
-                FieldType::offset(
-                    self.variation_region_list_offset(),
-                    self.variation_region_list(),
-                ),
-            )),
-            2usize => Some(Field::new(
-                "item_variation_data_count",
-                self.item_variation_data_count(),
-            )),
-            3usize => Some({
-                let data = self.data;
+            0usize => Some(
+                Field::new("format", self.format())
+                    .with_bytes(self.data, self.shape.format_byte_range()),
+            ),
+            1usize => Some(
                 Field::new(
-                    "item_variation_data_offsets",
-                    FieldType::array_of_offsets(
-                        better_type_name::<ItemVariationData>(),
-                        self.item_variation_data_offsets(),
-                        move |off| {
-                            let target = off.get().resolve::<ItemVariationData>(data);
-                            FieldType::offset(off.get(), target)
-                        },
+                    "variation_region_list_offset",
+                    FieldType::offset(
+                        self.variation_region_list_offset(),
+                        self.variation_region_list(),
                     ),
                 )
-            }),
+                .with_bytes(
+                    self.data,
+                    self.shape.variation_region_list_offset_byte_range(),
+                ),
+            ),
+            2usize => Some(
+                Field::new(
+                    "item_variation_data_count",
+                    self.item_variation_data_count(),
+                )
+                .with_bytes(self.data, self.shape.item_variation_data_count_byte_range()),
+            ),
+            3usize => Some(
+                {
+                    let data = self.data;
+                    Field::new(
+                        "item_variation_data_offsets",
+                        FieldType::array_of_offsets(
+                            better_type_name::<ItemVariationData>(),
+                            self.item_variation_data_offsets(),
+                            move |off| {
+                                let target = off.get().resolve::<ItemVariationData>(data);
+                                FieldType::offset(off.get(), target)
+                            },
+                        ),
+                    )
+                }
+                .with_bytes(
+                    self.data,
+                    self.shape.item_variation_data_offsets_byte_range(),
+                ),
+            ),
             _ => None,
         }
     }
@@ -1171,11 +1241,26 @@ impl<'a> SomeTable<'a> for ItemVariationData<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("item_count", self.item_count())),
-            1usize => Some(Field::new("word_delta_count", self.word_delta_count())),
-            2usize => Some(Field::new("region_index_count", self.region_index_count())),
-            3usize => Some(Field::new("region_indexes", self.region_indexes())),
-            4usize => Some(Field::new("delta_sets", self.delta_sets())),
+            0usize => Some(
+                Field::new("item_count", self.item_count())
+                    .with_bytes(self.data, self.shape.item_count_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("word_delta_count", self.word_delta_count())
+                    .with_bytes(self.data, self.shape.word_delta_count_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("region_index_count", self.region_index_count())
+                    .with_bytes(self.data, self.shape.region_index_count_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("region_indexes", self.region_indexes())
+                    .with_bytes(self.data, self.shape.region_indexes_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("delta_sets", self.delta_sets())
+                    .with_bytes(self.data, self.shape.delta_sets_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -89,15 +89,21 @@ impl<'a> SomeTable<'a> for Vmtx<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new(
-                "v_metrics",
-                traversal::FieldType::array_of_records(
-                    stringify!(LongMetric),
-                    self.v_metrics(),
-                    self.offset_data(),
-                ),
-            )),
-            1usize => Some(Field::new("top_side_bearings", self.top_side_bearings())),
+            0usize => Some(
+                Field::new(
+                    "v_metrics",
+                    traversal::FieldType::array_of_records(
+                        stringify!(LongMetric),
+                        self.v_metrics(),
+                        self.offset_data(),
+                    ),
+                )
+                .with_bytes(self.data, self.shape.v_metrics_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("top_side_bearings", self.top_side_bearings())
+                    .with_bytes(self.data, self.shape.top_side_bearings_byte_range()),
+            ),
             _ => None,
         }
     }
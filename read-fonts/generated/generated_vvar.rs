@@ -134,33 +134,57 @@ impl<'a> SomeTable<'a> for Vvar<'a> {
     }
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
         match idx {
-            0usize => Some(Field::new("version", self.version())),
-            1usize => Some(Field::new(
-                "item_variation_store_offset",
-                FieldType::offset(
-                    self.item_variation_store_offset(),
-                    self.item_variation_store(),
+            0usize => Some(
+                Field::new("version", self.version())
+                    .with_bytes(self.data, self.shape.version_byte_range()),
+            ),
+            1usize => Some(
+                Field::new(
+                    "item_variation_store_offset",
+                    FieldType::offset(
+                        self.item_variation_store_offset(),
+                        self.item_variation_store(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.item_variation_store_offset_byte_range(),
                 ),
-            )),
-            2usize => Some(Field::new(
-                "advance_height_mapping_offset",
-                FieldType::offset(
-                    self.advance_height_mapping_offset(),
-                    self.advance_height_mapping(),
+            ),
+            2usize => Some(
+                Field::new(
+                    "advance_height_mapping_offset",
+                    FieldType::offset(
+                        self.advance_height_mapping_offset(),
+                        self.advance_height_mapping(),
+                    ),
+                )
+                .with_bytes(
+                    self.data,
+                    self.shape.advance_height_mapping_offset_byte_range(),
                 ),
-            )),
-            3usize => Some(Field::new(
-                "tsb_mapping_offset",
-                FieldType::offset(self.tsb_mapping_offset(), self.tsb_mapping()),
-            )),
-            4usize => Some(Field::new(
-                "bsb_mapping_offset",
-                FieldType::offset(self.bsb_mapping_offset(), self.bsb_mapping()),
-            )),
-            5usize => Some(Field::new(
-                "v_org_mapping_offset",
-                FieldType::offset(self.v_org_mapping_offset(), self.v_org_mapping()),
-            )),
+            ),
+            3usize => Some(
+                Field::new(
+                    "tsb_mapping_offset",
+                    FieldType::offset(self.tsb_mapping_offset(), self.tsb_mapping()),
+                )
+                .with_bytes(self.data, self.shape.tsb_mapping_offset_byte_range()),
+            ),
+            4usize => Some(
+                Field::new(
+                    "bsb_mapping_offset",
+                    FieldType::offset(self.bsb_mapping_offset(), self.bsb_mapping()),
+                )
+                .with_bytes(self.data, self.shape.bsb_mapping_offset_byte_range()),
+            ),
+            5usize => Some(
+                Field::new(
+                    "v_org_mapping_offset",
+                    FieldType::offset(self.v_org_mapping_offset(), self.v_org_mapping()),
+                )
+                .with_bytes(self.data, self.shape.v_org_mapping_offset_byte_range()),
+            ),
             _ => None,
         }
     }
@@ -0,0 +1,256 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [WOFF2 Header](https://www.w3.org/TR/WOFF2/#woff20Header)
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Woff2HeaderMarker {}
+
+impl Woff2HeaderMarker {
+    fn signature_byte_range(&self) -> Range<usize> {
+        let start = 0;
+        start..start + Tag::RAW_BYTE_LEN
+    }
+    fn flavor_byte_range(&self) -> Range<usize> {
+        let start = self.signature_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn length_byte_range(&self) -> Range<usize> {
+        let start = self.flavor_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn num_tables_byte_range(&self) -> Range<usize> {
+        let start = self.length_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn reserved_byte_range(&self) -> Range<usize> {
+        let start = self.num_tables_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn total_sfnt_size_byte_range(&self) -> Range<usize> {
+        let start = self.reserved_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn total_compressed_size_byte_range(&self) -> Range<usize> {
+        let start = self.total_sfnt_size_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn major_version_byte_range(&self) -> Range<usize> {
+        let start = self.total_compressed_size_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn minor_version_byte_range(&self) -> Range<usize> {
+        let start = self.major_version_byte_range().end;
+        start..start + u16::RAW_BYTE_LEN
+    }
+    fn meta_offset_byte_range(&self) -> Range<usize> {
+        let start = self.minor_version_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn meta_length_byte_range(&self) -> Range<usize> {
+        let start = self.meta_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn meta_orig_length_byte_range(&self) -> Range<usize> {
+        let start = self.meta_length_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn priv_offset_byte_range(&self) -> Range<usize> {
+        let start = self.meta_orig_length_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+    fn priv_length_byte_range(&self) -> Range<usize> {
+        let start = self.priv_offset_byte_range().end;
+        start..start + u32::RAW_BYTE_LEN
+    }
+}
+
+impl<'a> FontRead<'a> for Woff2Header<'a> {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        cursor.advance::<Tag>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u16>();
+        cursor.advance::<u16>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.advance::<u32>();
+        cursor.finish(Woff2HeaderMarker {})
+    }
+}
+
+/// The [WOFF2 Header](https://www.w3.org/TR/WOFF2/#woff20Header)
+pub type Woff2Header<'a> = TableRef<'a, Woff2HeaderMarker>;
+
+impl<'a> Woff2Header<'a> {
+    /// 0x774F4632 'wOF2'
+    pub fn signature(&self) -> Tag {
+        let range = self.shape.signature_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// The sfnt version of the input font.
+    pub fn flavor(&self) -> u32 {
+        let range = self.shape.flavor_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Total size of the WOFF file.
+    pub fn length(&self) -> u32 {
+        let range = self.shape.length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Number of entries in directory of font tables.
+    pub fn num_tables(&self) -> u16 {
+        let range = self.shape.num_tables_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Reserved; set to zero.
+    pub fn reserved(&self) -> u16 {
+        let range = self.shape.reserved_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Total size needed for the uncompressed font data, including the sfnt
+    /// header, directory, and font tables (including padding).
+    pub fn total_sfnt_size(&self) -> u32 {
+        let range = self.shape.total_sfnt_size_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Total length of the compressed data block.
+    pub fn total_compressed_size(&self) -> u32 {
+        let range = self.shape.total_compressed_size_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Major version of the WOFF file.
+    pub fn major_version(&self) -> u16 {
+        let range = self.shape.major_version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Minor version of the WOFF file.
+    pub fn minor_version(&self) -> u16 {
+        let range = self.shape.minor_version_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to metadata block, from beginning of WOFF file.
+    pub fn meta_offset(&self) -> u32 {
+        let range = self.shape.meta_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Length of compressed metadata block.
+    pub fn meta_length(&self) -> u32 {
+        let range = self.shape.meta_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Uncompressed size of metadata block.
+    pub fn meta_orig_length(&self) -> u32 {
+        let range = self.shape.meta_orig_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Offset to private data block, from beginning of WOFF file.
+    pub fn priv_offset(&self) -> u32 {
+        let range = self.shape.priv_offset_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+
+    /// Length of private data block.
+    pub fn priv_length(&self) -> u32 {
+        let range = self.shape.priv_length_byte_range();
+        self.data.read_at(range.start).unwrap()
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Woff2Header<'a> {
+    fn type_name(&self) -> &str {
+        "Woff2Header"
+    }
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        match idx {
+            0usize => Some(
+                Field::new("signature", self.signature())
+                    .with_bytes(self.data, self.shape.signature_byte_range()),
+            ),
+            1usize => Some(
+                Field::new("flavor", self.flavor())
+                    .with_bytes(self.data, self.shape.flavor_byte_range()),
+            ),
+            2usize => Some(
+                Field::new("length", self.length())
+                    .with_bytes(self.data, self.shape.length_byte_range()),
+            ),
+            3usize => Some(
+                Field::new("num_tables", self.num_tables())
+                    .with_bytes(self.data, self.shape.num_tables_byte_range()),
+            ),
+            4usize => Some(
+                Field::new("reserved", self.reserved())
+                    .with_bytes(self.data, self.shape.reserved_byte_range()),
+            ),
+            5usize => Some(
+                Field::new("total_sfnt_size", self.total_sfnt_size())
+                    .with_bytes(self.data, self.shape.total_sfnt_size_byte_range()),
+            ),
+            6usize => Some(
+                Field::new("total_compressed_size", self.total_compressed_size())
+                    .with_bytes(self.data, self.shape.total_compressed_size_byte_range()),
+            ),
+            7usize => Some(
+                Field::new("major_version", self.major_version())
+                    .with_bytes(self.data, self.shape.major_version_byte_range()),
+            ),
+            8usize => Some(
+                Field::new("minor_version", self.minor_version())
+                    .with_bytes(self.data, self.shape.minor_version_byte_range()),
+            ),
+            9usize => Some(
+                Field::new("meta_offset", self.meta_offset())
+                    .with_bytes(self.data, self.shape.meta_offset_byte_range()),
+            ),
+            10usize => Some(
+                Field::new("meta_length", self.meta_length())
+                    .with_bytes(self.data, self.shape.meta_length_byte_range()),
+            ),
+            11usize => Some(
+                Field::new("meta_orig_length", self.meta_orig_length())
+                    .with_bytes(self.data, self.shape.meta_orig_length_byte_range()),
+            ),
+            12usize => Some(
+                Field::new("priv_offset", self.priv_offset())
+                    .with_bytes(self.data, self.shape.priv_offset_byte_range()),
+            ),
+            13usize => Some(
+                Field::new("priv_length", self.priv_length())
+                    .with_bytes(self.data, self.shape.priv_length_byte_range()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> std::fmt::Debug for Woff2Header<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (self as &dyn SomeTable<'a>).fmt(f)
+    }
+}
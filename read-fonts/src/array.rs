@@ -22,15 +22,16 @@ pub struct ComputedArray<'a, T: ReadArgs> {
 }
 
 impl<'a, T: ComputeSize> ComputedArray<'a, T> {
-    pub fn new(data: FontData<'a>, args: T::Args) -> Self {
-        let item_len = T::compute_size(&args);
-        let len = data.len() / item_len;
-        ComputedArray {
+    pub fn new(data: FontData<'a>, args: T::Args) -> Result<Self, ReadError> {
+        let item_len = T::compute_size(&args)?;
+        // zero sized items occupy no data, so the array appears empty
+        let len = data.len().checked_div(item_len).unwrap_or(0);
+        Ok(ComputedArray {
             item_len,
             len,
             data,
             args,
-        }
+        })
     }
 
     pub fn len(&self) -> usize {
@@ -52,7 +53,7 @@ where
     T::Args: Copy,
 {
     fn read_with_args(data: FontData<'a>, args: &Self::Args) -> Result<Self, ReadError> {
-        Ok(Self::new(data, *args))
+        Self::new(data, *args)
     }
 }
 
@@ -87,6 +88,18 @@ where
             .ok_or(ReadError::OutOfBounds)
             .and_then(|data| T::read_with_args(data, &self.args))
     }
+
+    /// Return the bytes of the item at the provided index.
+    #[cfg(feature = "traversal")]
+    pub(crate) fn item_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        if idx >= self.len {
+            return None;
+        }
+        let item_start = idx * self.item_len;
+        self.data
+            .as_bytes()
+            .get(item_start..item_start + self.item_len)
+    }
 }
 
 impl<T: ReadArgs> std::fmt::Debug for ComputedArray<'_, T> {
@@ -118,6 +131,19 @@ impl<'a, T: FontRead<'a> + VarSize> VarLenArray<'a, T> {
         self.data.split_off(pos).map(T::read)
     }
 
+    /// Return the bytes of the item at the provided index.
+    ///
+    /// This performs a linear search.
+    #[cfg(feature = "traversal")]
+    pub(crate) fn item_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        let mut pos = 0;
+        for _ in 0..idx {
+            pos += T::read_len_at(self.data, pos)?;
+        }
+        let len = T::read_len_at(self.data, pos)?;
+        self.data.as_bytes().get(pos..pos + len)
+    }
+
     /// Return an iterator over this array's items.
     pub fn iter(&self) -> impl Iterator<Item = Result<T, ReadError>> + 'a {
         let mut data = self.data;
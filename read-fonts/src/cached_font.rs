@@ -0,0 +1,210 @@
+//! A font reference that caches table lookups.
+
+use types::Tag;
+
+use crate::{tables, FontData, FontRef, ReadError, TableProvider};
+
+/// Generates the table cache and the memoizing [`TableProvider`] methods.
+macro_rules! cached_tables {
+    ($($name:ident: $typ:ty),* $(,)?) => {
+        /// Names of all cached tables, used to check coverage of [`TableProvider`].
+        #[cfg(test)]
+        const CACHED_TABLES: &[&str] = &[$(stringify!($name),)* "loca"];
+
+        struct TableCache<'a> {
+            $($name: Result<$typ, ReadError>,)*
+            loca: Result<tables::loca::Loca<'a>, ReadError>,
+        }
+
+        impl<'a> TableCache<'a> {
+            fn new(font: &FontRef<'a>) -> Self {
+                Self {
+                    $($name: font.$name(),)*
+                    loca: font.loca(None),
+                }
+            }
+        }
+
+        impl<'a> TableProvider<'a> for CachedFontRef<'a> {
+            fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+                self.table_data(tag)
+            }
+
+            $(
+                fn $name(&self) -> Result<$typ, ReadError> {
+                    self.cache.$name.clone()
+                }
+            )*
+
+            fn loca(&self, is_long: impl Into<Option<bool>>) -> Result<tables::loca::Loca<'a>, ReadError> {
+                match is_long.into() {
+                    // only the format from the head table is cached
+                    Some(is_long) => self.font.loca(is_long),
+                    None => self.cache.loca.clone(),
+                }
+            }
+        }
+    };
+}
+
+/// A reference to an in-memory font that caches table lookups.
+///
+/// The table directory is resolved and every known top-level table is
+/// parsed once, on construction. This makes access through
+/// [`TableProvider`] cheaper than with a plain [`FontRef`], which is useful
+/// when objects that read many tables, such as glyph scalers, are
+/// constructed frequently.
+///
+/// Parsing a table only reads its header, so construction is cheap, but
+/// an instance should be created once and reused for best results.
+///
+/// This type is `Send` and `Sync`, so a single instance can be shared
+/// between threads.
+pub struct CachedFontRef<'a> {
+    font: FontRef<'a>,
+    tables: Vec<(Tag, FontData<'a>)>,
+    cache: TableCache<'a>,
+}
+
+impl<'a> CachedFontRef<'a> {
+    /// Creates a new caching reference to the given font.
+    pub fn new(font: FontRef<'a>) -> Self {
+        let mut tables = font
+            .table_directory
+            .table_records()
+            .iter()
+            .filter_map(|record| Some((record.tag(), font.table_data(record.tag())?)))
+            .collect::<Vec<_>>();
+        // the directory should already be sorted, but don't rely on it
+        tables.sort_by_key(|(tag, _)| *tag);
+        let cache = TableCache::new(&font);
+        Self {
+            font,
+            tables,
+            cache,
+        }
+    }
+
+    /// Returns the underlying font reference.
+    pub fn font_ref(&self) -> &FontRef<'a> {
+        &self.font
+    }
+
+    /// Returns the data for the table with the specified tag, if present.
+    pub fn table_data(&self, tag: Tag) -> Option<FontData<'a>> {
+        self.tables
+            .binary_search_by_key(&tag, |(tag, _)| *tag)
+            .ok()
+            .map(|ix| self.tables[ix].1)
+    }
+}
+
+impl<'a> From<FontRef<'a>> for CachedFontRef<'a> {
+    fn from(font: FontRef<'a>) -> Self {
+        Self::new(font)
+    }
+}
+
+cached_tables! {
+    head: tables::head::Head<'a>,
+    name: tables::name::Name<'a>,
+    hhea: tables::hhea::Hhea<'a>,
+    vhea: tables::vhea::Vhea<'a>,
+    hmtx: tables::hmtx::Hmtx<'a>,
+    vmtx: tables::vmtx::Vmtx<'a>,
+    fvar: tables::fvar::Fvar<'a>,
+    avar: tables::avar::Avar<'a>,
+    hvar: tables::hvar::Hvar<'a>,
+    vvar: tables::vvar::Vvar<'a>,
+    mvar: tables::mvar::Mvar<'a>,
+    math: tables::math::Math<'a>,
+    maxp: tables::maxp::Maxp<'a>,
+    os2: tables::os2::Os2<'a>,
+    post: tables::post::Post<'a>,
+    glyf: tables::glyf::Glyf<'a>,
+    gvar: tables::gvar::Gvar<'a>,
+    cvt: tables::cvt::Cvt<'a>,
+    cvar: tables::cvar::Cvar<'a>,
+    fpgm: tables::fpgm::Fpgm<'a>,
+    prep: tables::prep::Prep<'a>,
+    gasp: tables::gasp::Gasp<'a>,
+    hdmx: tables::hdmx::Hdmx<'a>,
+    vdmx: tables::vdmx::Vdmx<'a>,
+    ltsh: tables::ltsh::Ltsh<'a>,
+    meta: tables::meta::Meta<'a>,
+    ltag: tables::ltag::Ltag<'a>,
+    vorg: tables::vorg::Vorg<'a>,
+    jstf: tables::jstf::Jstf<'a>,
+    pclt: tables::pclt::Pclt<'a>,
+    kern: tables::kern::Kern<'a>,
+    morx: tables::morx::Morx<'a>,
+    kerx: tables::kerx::Kerx<'a>,
+    ankr: tables::ankr::Ankr<'a>,
+    trak: tables::trak::Trak<'a>,
+    feat: tables::feat::Feat<'a>,
+    cff: tables::cff::Cff<'a>,
+    cff2: tables::cff2::Cff2<'a>,
+    cmap: tables::cmap::Cmap<'a>,
+    gdef: tables::gdef::Gdef<'a>,
+    gpos: tables::gpos::Gpos<'a>,
+    gsub: tables::gsub::Gsub<'a>,
+    base: tables::base::Base<'a>,
+    colr: tables::colr::Colr<'a>,
+    cpal: tables::cpal::Cpal<'a>,
+    stat: tables::stat::Stat<'a>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CachedFontRef>();
+    }
+
+    // every table accessor on TableProvider should be cached
+    #[test]
+    fn covers_table_provider() {
+        let source = include_str!("table_provider.rs");
+        let mut expected = source
+            .lines()
+            .filter(|line| line.contains("-> Result<tables::"))
+            .filter_map(|line| line.trim().strip_prefix("fn "))
+            .filter_map(|line| line.split('(').next())
+            .collect::<Vec<_>>();
+        let mut cached = CACHED_TABLES.to_vec();
+        expected.sort_unstable();
+        cached.sort_unstable();
+        assert_eq!(cached, expected);
+    }
+
+    #[test]
+    fn matches_font_ref() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let cached = CachedFontRef::new(font.clone());
+        for record in font.table_directory.table_records() {
+            let tag = record.tag();
+            assert_eq!(
+                cached.data_for_tag(tag).unwrap().as_bytes(),
+                font.data_for_tag(tag).unwrap().as_bytes()
+            );
+        }
+        assert!(cached.data_for_tag(Tag::new(b"CFF ")).is_none());
+        for _ in 0..2 {
+            assert_eq!(
+                cached.gvar().unwrap().offset_data().as_bytes(),
+                font.gvar().unwrap().offset_data().as_bytes()
+            );
+            assert_eq!(
+                cached.loca(None).unwrap().len(),
+                font.loca(None).unwrap().len()
+            );
+            assert!(matches!(
+                cached.cff(),
+                Err(ReadError::TableIsMissing(tag)) if tag == Tag::new(b"CFF ")
+            ));
+        }
+    }
+}
@@ -0,0 +1,879 @@
+//! Fonts loaded from bare CFF data.
+
+use std::collections::BTreeMap;
+
+use types::{Fixed, GlyphId, Tag};
+
+use crate::{
+    checksum::{compute_checksum_adjustment, table_checksum},
+    tables::{
+        cff::Cff,
+        postscript::{
+            charstring::{self, CommandSink},
+            dict, standard_encoding, Charset, Error, FdSelect, Index, Index1, Latin1String,
+            StringId,
+        },
+    },
+    FontData, FontRead, FontRef, OwnedFont, ReadError, TableProvider,
+};
+
+/// A font loaded from a bare CFF font set, without an sfnt wrapper.
+///
+/// Bare CFF data, such as the font streams embedded in PDF files, lacks the
+/// tables that describe global metrics, glyph metrics and the character
+/// mapping. This type selects a single font from the font set and
+/// synthesizes those tables from the CFF data:
+///
+/// * `head`, with the units per em derived from the FontMatrix and the
+///   bounding box of all glyphs
+/// * `hhea` and `hmtx`, with advance widths from the charstrings and left
+///   side bearings from the glyph control boxes
+/// * `maxp`, with the number of charstrings
+/// * `cmap`, mapping Unicode characters to glyphs by their names in the
+///   charset. Only the names of the standard strings and names of the form
+///   `uniXXXX` or `uXXXX[XX]` are recognized; other glyphs, such as those
+///   named `Adieresis.sc` or `f_f`, are not mapped. This is omitted for
+///   CID-keyed fonts, which have no glyph names, and if the charset is
+///   malformed.
+///
+/// The `CFF ` table is a copy of the input in which the selected font has
+/// been moved to the front of the Name and Top DICT INDEXes, since readers
+/// of OpenType fonts only consider the first font in the set.
+///
+/// Tables are accessed through [`TableProvider`], which is implemented for
+/// `&CffFont`, or through a [`FontRef`] obtained with
+/// [`as_font_ref`](Self::as_font_ref).
+///
+/// # Example
+///
+/// ```
+/// # use read_fonts::{FontRef, TableProvider};
+/// # let font = FontRef::new(font_test_data::NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+/// # let cff_data = font.cff().unwrap().offset_data().as_bytes().to_vec();
+/// use read_fonts::CffFont;
+///
+/// let font = CffFont::new(&cff_data, 0).unwrap();
+/// let num_glyphs = font.as_font_ref().maxp().unwrap().num_glyphs();
+/// let advances = font.as_font_ref().hmtx().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct CffFont {
+    font: OwnedFont,
+    index: u32,
+    is_cid_keyed: bool,
+}
+
+impl CffFont {
+    /// Returns the number of fonts in the given CFF font set.
+    pub fn count(data: &[u8]) -> Result<u32, Error> {
+        Ok(Cff::read(FontData::new(data))?.names().count() as u32)
+    }
+
+    /// Creates a new font from the given CFF data and index into the font
+    /// set.
+    pub fn new(data: &[u8], index: u32) -> Result<Self, Error> {
+        let cff_data = select_font(data, index)?;
+        let (tables, is_cid_keyed) = synthesize_tables(&cff_data)?;
+        let font = OwnedFont::new(build_sfnt(tables)?, 0)?;
+        Ok(Self {
+            font,
+            index,
+            is_cid_keyed,
+        })
+    }
+
+    /// Returns the index of the font in the CFF font set.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the PostScript name of the font.
+    pub fn name(&self) -> Option<Latin1String<'_>> {
+        self.as_font_ref().cff().ok()?.name(0)
+    }
+
+    /// Returns true if the font is CID-keyed.
+    pub fn is_cid_keyed(&self) -> bool {
+        self.is_cid_keyed
+    }
+
+    /// Returns the data of the synthesized sfnt font.
+    pub fn data(&self) -> &[u8] {
+        self.font.data()
+    }
+
+    /// Returns a reference to the synthesized font.
+    pub fn as_font_ref(&self) -> FontRef<'_> {
+        self.font.as_font_ref()
+    }
+}
+
+impl<'a> From<&'a CffFont> for FontRef<'a> {
+    fn from(font: &'a CffFont) -> Self {
+        font.as_font_ref()
+    }
+}
+
+impl<'a> TableProvider<'a> for &'a CffFont {
+    fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+        self.as_font_ref().table_data(tag)
+    }
+}
+
+/// Copies the font set, moving the font at `index` to the front.
+fn select_font(data: &[u8], index: u32) -> Result<Vec<u8>, Error> {
+    let cff = Cff::read(FontData::new(data))?;
+    let names = cff.names();
+    let top_dicts = cff.top_dicts();
+    if index >= names.count() as u32 {
+        return Err(ReadError::InvalidCollectionIndex(index).into());
+    }
+    if top_dicts.count() != names.count() {
+        return Err(ReadError::MalformedData("CFF Name and Top DICT INDEX counts differ").into());
+    }
+    let mut selected = data.to_vec();
+    if index != 0 {
+        let names_start = data.len() - cff.header().trailing_data().len();
+        let top_dicts_start = names_start + names.size_in_bytes()?;
+        move_to_front(&mut selected[names_start..], &names, index as usize)?;
+        move_to_front(&mut selected[top_dicts_start..], &top_dicts, index as usize)?;
+    }
+    Ok(selected)
+}
+
+/// Rewrites the INDEX at the start of `data` so that the object at `front`
+/// comes first.
+///
+/// The total size of the objects is unchanged, so the INDEX keeps its size
+/// and offset size, and all data that follows stays in place.
+fn move_to_front(data: &mut [u8], index: &Index1, front: usize) -> Result<(), Error> {
+    let count = index.count() as usize;
+    let off_size = index.off_size() as usize;
+    let order = std::iter::once(front).chain((0..count).filter(|ix| *ix != front));
+    let objects = order
+        .map(|ix| index.get(ix))
+        .collect::<Result<Vec<_>, _>>()?;
+    let offsets_start = 3;
+    let data_start = offsets_start + (count + 1) * off_size;
+    let mut offset = index.get_offset(0)?;
+    for (i, object) in std::iter::once(&[][..])
+        .chain(objects.iter().copied())
+        .enumerate()
+    {
+        offset += object.len();
+        // stored offsets are 1-based
+        let bytes = (offset as u32 + 1).to_be_bytes();
+        let pos = offsets_start + i * off_size;
+        data[pos..pos + off_size].copy_from_slice(&bytes[4 - off_size..]);
+    }
+    let mut pos = data_start + index.get_offset(0)?;
+    for object in objects {
+        data[pos..pos + object.len()].copy_from_slice(object);
+        pos += object.len();
+    }
+    Ok(())
+}
+
+/// Table data paired with tags.
+type Tables = Vec<(Tag, Vec<u8>)>;
+
+/// Local state for the font DICT that applies to a subset of glyphs.
+struct PrivateDict<'a> {
+    subrs: Option<Index<'a>>,
+    default_width: Fixed,
+    nominal_width: Fixed,
+}
+
+impl<'a> PrivateDict<'a> {
+    fn new(cff_data: &'a [u8], font_dict_data: &[u8]) -> Result<Self, Error> {
+        let mut private = Self {
+            subrs: None,
+            default_width: Fixed::ZERO,
+            nominal_width: Fixed::ZERO,
+        };
+        let mut range = None;
+        for entry in dict::entries(font_dict_data, None) {
+            if let dict::Entry::PrivateDictRange(r) = entry? {
+                range = Some(r);
+            }
+        }
+        let range = range.ok_or(Error::MissingPrivateDict)?;
+        let private_dict_data = cff_data.get(range.clone()).ok_or(ReadError::OutOfBounds)?;
+        for entry in dict::entries(private_dict_data, None) {
+            match entry? {
+                // Subrs offset is relative to the private DICT
+                dict::Entry::SubrsOffset(offset) => {
+                    let subrs_data = cff_data.get(range.start + offset..).unwrap_or_default();
+                    private.subrs = Some(Index::new(subrs_data, false)?);
+                }
+                dict::Entry::DefaultWidthX(width) => private.default_width = width,
+                dict::Entry::NominalWidthX(width) => private.nominal_width = width,
+                _ => {}
+            }
+        }
+        Ok(private)
+    }
+}
+
+/// Accumulates the control box of a glyph outline.
+#[derive(Default)]
+struct ControlBox {
+    bounds: Option<[Fixed; 4]>,
+}
+
+impl ControlBox {
+    fn add(&mut self, x: Fixed, y: Fixed) {
+        let [x_min, y_min, x_max, y_max] = self.bounds.get_or_insert([x, y, x, y]);
+        *x_min = (*x_min).min(x);
+        *y_min = (*y_min).min(y);
+        *x_max = (*x_max).max(x);
+        *y_max = (*y_max).max(y);
+    }
+}
+
+impl CommandSink for ControlBox {
+    fn move_to(&mut self, x: Fixed, y: Fixed) {
+        self.add(x, y);
+    }
+
+    fn line_to(&mut self, x: Fixed, y: Fixed) {
+        self.add(x, y);
+    }
+
+    fn curve_to(&mut self, cx0: Fixed, cy0: Fixed, cx1: Fixed, cy1: Fixed, x: Fixed, y: Fixed) {
+        self.add(cx0, cy0);
+        self.add(cx1, cy1);
+        self.add(x, y);
+    }
+
+    fn close(&mut self) {}
+}
+
+/// Synthesizes the sfnt tables, in tag order, for the first font in the
+/// given font set.
+///
+/// Also returns true if the font is CID-keyed.
+fn synthesize_tables(cff_data: &[u8]) -> Result<(Tables, bool), Error> {
+    let cff = Cff::read(FontData::new(cff_data))?;
+    let top_dict_data = cff.top_dicts().get(0)?;
+    let mut charstrings = None;
+    let mut charset_offset = 0;
+    let mut font_dicts = None;
+    let mut fd_select = None;
+    let mut is_cid_keyed = false;
+    let mut font_bbox = None;
+    let mut font_matrix = None;
+    let data_at = |offset: usize| cff_data.get(offset..).unwrap_or_default();
+    for entry in dict::entries(top_dict_data, None) {
+        match entry? {
+            dict::Entry::CharstringsOffset(offset) => {
+                charstrings = Some(Index::new(data_at(offset), false)?);
+            }
+            dict::Entry::Charset(offset) => charset_offset = offset,
+            dict::Entry::FdArrayOffset(offset) => {
+                font_dicts = Some(Index::new(data_at(offset), false)?);
+            }
+            dict::Entry::FdSelectOffset(offset) => {
+                fd_select = Some(FdSelect::read(FontData::new(data_at(offset)))?);
+            }
+            dict::Entry::Ros { .. } => is_cid_keyed = true,
+            dict::Entry::FontBbox(bbox) => font_bbox = Some(bbox),
+            dict::Entry::FontMatrix(matrix) => font_matrix = Some(matrix),
+            _ => {}
+        }
+    }
+    let charstrings = charstrings.ok_or(Error::MissingCharstrings)?;
+    let num_glyphs = u16::try_from(charstrings.count())
+        .map_err(|_| ReadError::MalformedData("CFF font has more than 65535 glyphs"))?;
+    // CID-keyed fonts select a Private DICT for each glyph through the
+    // FDArray and FDSelect, while others have a single Private DICT
+    // referenced by the Top DICT.
+    let private_dicts = match (&font_dicts, is_cid_keyed) {
+        (Some(font_dicts), true) => (0..font_dicts.count() as usize)
+            .map(|ix| PrivateDict::new(cff_data, font_dicts.get(ix)?))
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => vec![PrivateDict::new(cff_data, top_dict_data)?],
+    };
+    // CID-keyed fonts have no glyph names, so the charset maps CIDs. The
+    // charset is only used for the cmap and seac glyphs, so a malformed one
+    // doesn't prevent loading the font.
+    let charset = (!is_cid_keyed)
+        .then(|| Charset::new(FontData::new(cff_data), charset_offset, num_glyphs as u32).ok())
+        .flatten();
+    // accented glyphs may be composed with the deprecated seac operator,
+    // which references the base and accent glyphs by their codes in the
+    // standard encoding
+    let seac_component = |code| {
+        let glyph_id = charset.as_ref()?.glyph_id(standard_encoding(code)).ok()?;
+        charstrings.get(glyph_id.to_u16() as usize).ok()
+    };
+    let mut metrics = Vec::with_capacity(num_glyphs as usize);
+    for gid in 0..num_glyphs {
+        let fd_index = fd_select
+            .as_ref()
+            .filter(|_| is_cid_keyed)
+            .and_then(|select| select.font_index(GlyphId::new(gid)))
+            .unwrap_or(0) as usize;
+        let private = private_dicts
+            .get(fd_index)
+            .ok_or(Error::MissingPrivateDict)?;
+        // a malformed charstring leaves the glyph with zero metrics and an
+        // empty bounding box, rather than failing the entire font
+        let mut control_box = ControlBox::default();
+        let Ok((charstring_data, width)) =
+            charstrings.get(gid as usize).and_then(|charstring_data| {
+                let width = charstring::evaluate_with_width(
+                    charstring_data,
+                    cff.global_subrs().into(),
+                    private.subrs.clone(),
+                    None,
+                    &mut control_box,
+                )?;
+                Ok((charstring_data, width))
+            })
+        else {
+            metrics.push(Default::default());
+            continue;
+        };
+        // only glyphs without an outline of their own can be seac
+        // composites. Those with missing components are left empty, rather
+        // than failing the entire font.
+        if control_box.bounds.is_none() && charset.is_some() {
+            let mut seac_box = ControlBox::default();
+            if charstring::evaluate_with_seac(
+                charstring_data,
+                cff.global_subrs().into(),
+                private.subrs.clone(),
+                None,
+                seac_component,
+                &mut seac_box,
+            )
+            .is_ok()
+            {
+                control_box = seac_box;
+            }
+        }
+        let advance = width.map_or(private.default_width, |w| w + private.nominal_width);
+        metrics.push((advance, control_box.bounds));
+    }
+    let mut tables = vec![(Tag::new(b"CFF "), cff_data.to_vec())];
+    if let Some(charset) = &charset {
+        let mut mapping = BTreeMap::new();
+        for gid in 0..num_glyphs {
+            let Ok(sid) = charset.string_id(GlyphId::new(gid)) else {
+                continue;
+            };
+            if let Some(ch) = glyph_name_to_char(&cff, sid) {
+                mapping.entry(ch).or_insert(gid);
+            }
+        }
+        tables.push((Tag::new(b"cmap"), build_cmap(&mapping)));
+    }
+    let units_per_em = font_matrix.map_or(1000, |matrix| units_per_em(matrix[0]));
+    let font_bbox = font_bbox.map(|bbox| bbox.map(to_fword));
+    let bbox = metrics
+        .iter()
+        .filter_map(|(_, bounds)| *bounds)
+        .map(|bounds| bounds.map(to_fword))
+        .reduce(|a, b| {
+            [
+                a[0].min(b[0]),
+                a[1].min(b[1]),
+                a[2].max(b[2]),
+                a[3].max(b[3]),
+            ]
+        })
+        .or(font_bbox)
+        .unwrap_or_default();
+    let mut head = Vec::with_capacity(54);
+    head.extend_from_slice(&0x00010000u32.to_be_bytes()); // version
+    head.extend_from_slice(&0x00010000u32.to_be_bytes()); // fontRevision
+    head.extend_from_slice(&0u32.to_be_bytes()); // checksumAdjustment
+    head.extend_from_slice(&0x5F0F3CF5u32.to_be_bytes()); // magicNumber
+    head.extend_from_slice(&0u16.to_be_bytes()); // flags
+    head.extend_from_slice(&units_per_em.to_be_bytes());
+    head.extend_from_slice(&[0; 16]); // created and modified
+    for value in bbox {
+        head.extend_from_slice(&value.to_be_bytes());
+    }
+    // macStyle, lowestRecPPEM, fontDirectionHint, indexToLocFormat,
+    // glyphDataFormat
+    for value in [0i16, 3, 2, 0, 0] {
+        head.extend_from_slice(&value.to_be_bytes());
+    }
+    tables.push((Tag::new(b"head"), head));
+    // ascender and descender from the FontBBox, as FreeType does
+    let [_, descender, _, ascender] = font_bbox.filter(|b| b[1] != b[3]).unwrap_or(bbox);
+    let mut hmtx = Vec::with_capacity(num_glyphs as usize * 4);
+    let mut advance_width_max = 0u16;
+    let mut min_lsb = i16::MAX;
+    let mut min_rsb = i16::MAX;
+    let mut x_max_extent = i16::MIN;
+    for (advance, bounds) in &metrics {
+        let advance = advance.to_i32().clamp(0, u16::MAX as i32) as u16;
+        let [x_min, _, x_max, _] = bounds.map(|b| b.map(to_fword)).unwrap_or_default();
+        hmtx.extend_from_slice(&advance.to_be_bytes());
+        hmtx.extend_from_slice(&x_min.to_be_bytes());
+        advance_width_max = advance_width_max.max(advance);
+        if bounds.is_some() {
+            min_lsb = min_lsb.min(x_min);
+            min_rsb = min_rsb.min(saturate_i16(advance as i32 - x_max as i32));
+            x_max_extent = x_max_extent.max(x_max);
+        }
+    }
+    if min_lsb == i16::MAX {
+        (min_lsb, min_rsb, x_max_extent) = (0, 0, 0);
+    }
+    let mut hhea = Vec::with_capacity(36);
+    hhea.extend_from_slice(&0x00010000u32.to_be_bytes()); // version
+    for value in [ascender, descender, 0] {
+        hhea.extend_from_slice(&value.to_be_bytes());
+    }
+    hhea.extend_from_slice(&advance_width_max.to_be_bytes());
+    // minLeftSideBearing, minRightSideBearing, xMaxExtent, caretSlopeRise,
+    // caretSlopeRun, caretOffset, four reserved fields and metricDataFormat
+    for value in [min_lsb, min_rsb, x_max_extent, 1, 0, 0, 0, 0, 0, 0, 0] {
+        hhea.extend_from_slice(&value.to_be_bytes());
+    }
+    hhea.extend_from_slice(&num_glyphs.to_be_bytes()); // numberOfHMetrics
+    tables.push((Tag::new(b"hhea"), hhea));
+    tables.push((Tag::new(b"hmtx"), hmtx));
+    let mut maxp = 0x00005000u32.to_be_bytes().to_vec();
+    maxp.extend_from_slice(&num_glyphs.to_be_bytes());
+    tables.push((Tag::new(b"maxp"), maxp));
+    Ok((tables, is_cid_keyed))
+}
+
+/// Rounds a coordinate to font units, saturating at the limits of the
+/// `FWORD` type.
+fn to_fword(value: Fixed) -> i16 {
+    saturate_i16(value.to_i32())
+}
+
+fn saturate_i16(value: i32) -> i16 {
+    value.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+}
+
+/// Returns the units per em implied by the horizontal scale of the
+/// FontMatrix.
+///
+/// The matrix is parsed into 16.16 values, which cannot exactly represent
+/// the reciprocal of most sizes, so common sizes that round to the same
+/// value are preferred over the computed one.
+fn units_per_em(scale: Fixed) -> u16 {
+    const COMMON_SIZES: [u16; 6] = [1000, 2048, 1024, 2000, 4096, 512];
+    if scale <= Fixed::ZERO {
+        return 1000;
+    }
+    COMMON_SIZES
+        .into_iter()
+        .find(|size| Fixed::from_f64(1.0 / *size as f64) == scale)
+        .unwrap_or_else(|| (1.0 / scale.to_f64()).round().clamp(16.0, 16384.0) as u16)
+}
+
+/// Returns the Unicode character for the glyph with the given name.
+///
+/// This handles the names of the standard strings and the `uniXXXX` and
+/// `uXXXX[XX]` forms.
+fn glyph_name_to_char(cff: &Cff, sid: StringId) -> Option<u32> {
+    if let Some(ch) = STANDARD_STRING_CHARS.get(sid.to_u16() as usize) {
+        return (*ch != 0).then_some(*ch as u32);
+    }
+    let name = cff.string(sid)?;
+    let name = name.chars().collect::<String>();
+    let hex = if let Some(hex) = name.strip_prefix("uni") {
+        (hex.len() == 4).then_some(hex)?
+    } else {
+        let hex = name.strip_prefix('u')?;
+        (4..=6).contains(&hex.len()).then_some(hex)?
+    };
+    if !hex
+        .bytes()
+        .all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b))
+    {
+        return None;
+    }
+    u32::from_str_radix(hex, 16)
+        .ok()
+        .filter(|ch| char::from_u32(*ch).is_some())
+}
+
+/// Builds a `cmap` table with a single format 12 subtable for the Windows
+/// Unicode full repertoire encoding.
+fn build_cmap(mapping: &BTreeMap<u32, u16>) -> Vec<u8> {
+    // sequential runs of characters that map to sequential glyphs
+    let mut groups: Vec<(u32, u32, u16)> = vec![];
+    for (&ch, &gid) in mapping {
+        match groups.last_mut() {
+            Some((start, end, start_gid))
+                if ch == *end + 1 && gid as u32 == *start_gid as u32 + (ch - *start) =>
+            {
+                *end = ch;
+            }
+            _ => groups.push((ch, ch, gid)),
+        }
+    }
+    let subtable_len = 16 + groups.len() as u32 * 12;
+    let mut cmap = Vec::with_capacity(12 + subtable_len as usize);
+    // version, numTables, platformID, encodingID, subtable offset
+    for value in [0u16, 1, 3, 10] {
+        cmap.extend_from_slice(&value.to_be_bytes());
+    }
+    cmap.extend_from_slice(&12u32.to_be_bytes());
+    // format, reserved, length, language, numGroups
+    cmap.extend_from_slice(&12u16.to_be_bytes());
+    cmap.extend_from_slice(&0u16.to_be_bytes());
+    for value in [subtable_len, 0, groups.len() as u32] {
+        cmap.extend_from_slice(&value.to_be_bytes());
+    }
+    for (start, end, gid) in groups {
+        for value in [start, end, gid as u32] {
+            cmap.extend_from_slice(&value.to_be_bytes());
+        }
+    }
+    cmap
+}
+
+/// Writes an sfnt font containing the given tables, which must be sorted
+/// by tag.
+pub(crate) fn build_sfnt(tables: Tables) -> Result<Vec<u8>, Error> {
+    const SFNT_HEADER_LEN: usize = 12;
+    const SFNT_TABLE_RECORD_LEN: usize = 16;
+    let num_tables = tables.len() as u16;
+    let entry_selector = (u16::BITS - 1 - num_tables.leading_zeros()) as u16;
+    let search_range = (1u16 << entry_selector) * 16;
+    let range_shift = num_tables * 16 - search_range;
+    let mut sfnt = b"OTTO".to_vec();
+    for value in [num_tables, search_range, entry_selector, range_shift] {
+        sfnt.extend_from_slice(&value.to_be_bytes());
+    }
+    let mut offset = SFNT_HEADER_LEN + tables.len() * SFNT_TABLE_RECORD_LEN;
+    for (tag, data) in &tables {
+        sfnt.extend_from_slice(&tag.into_bytes());
+        sfnt.extend_from_slice(&table_checksum(*tag, data).to_be_bytes());
+        sfnt.extend_from_slice(&(offset as u32).to_be_bytes());
+        sfnt.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len().next_multiple_of(4);
+    }
+    let mut head_offset = 0;
+    for (tag, data) in &tables {
+        if *tag == Tag::new(b"head") {
+            head_offset = sfnt.len();
+        }
+        sfnt.extend_from_slice(data);
+        sfnt.resize(sfnt.len().next_multiple_of(4), 0);
+    }
+    let adjustment = compute_checksum_adjustment(&FontRef::new(&sfnt)?)
+        .map_err(|_| ReadError::MalformedData("failed to compute font checksum"))?;
+    sfnt[head_offset + 8..head_offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+    Ok(sfnt)
+}
+
+/// Unicode characters for the standard strings that are glyph names in the
+/// ISOAdobe charset, or 0 for `.notdef`.
+///
+/// See "Appendix A - Standard Strings" at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=29>
+#[rustfmt::skip]
+const STANDARD_STRING_CHARS: [u16; 229] = [
+    0x0000, 0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026,
+    0x2019, 0x0028, 0x0029, 0x002A, 0x002B, 0x002C, 0x002D, 0x002E,
+    0x002F, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036,
+    0x0037, 0x0038, 0x0039, 0x003A, 0x003B, 0x003C, 0x003D, 0x003E,
+    0x003F, 0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046,
+    0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E,
+    0x004F, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056,
+    0x0057, 0x0058, 0x0059, 0x005A, 0x005B, 0x005C, 0x005D, 0x005E,
+    0x005F, 0x2018, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076,
+    0x0077, 0x0078, 0x0079, 0x007A, 0x007B, 0x007C, 0x007D, 0x007E,
+    0x00A1, 0x00A2, 0x00A3, 0x2044, 0x00A5, 0x0192, 0x00A7, 0x00A4,
+    0x0027, 0x201C, 0x00AB, 0x2039, 0x203A, 0xFB01, 0xFB02, 0x2013,
+    0x2020, 0x2021, 0x00B7, 0x00B6, 0x2022, 0x201A, 0x201E, 0x201D,
+    0x00BB, 0x2026, 0x2030, 0x00BF, 0x0060, 0x00B4, 0x02C6, 0x02DC,
+    0x00AF, 0x02D8, 0x02D9, 0x00A8, 0x02DA, 0x00B8, 0x02DD, 0x02DB,
+    0x02C7, 0x2014, 0x00C6, 0x00AA, 0x0141, 0x00D8, 0x0152, 0x00BA,
+    0x00E6, 0x0131, 0x0142, 0x00F8, 0x0153, 0x00DF, 0x00B9, 0x00AC,
+    0x00B5, 0x2122, 0x00D0, 0x00BD, 0x00B1, 0x00DE, 0x00BC, 0x00F7,
+    0x00A6, 0x00B0, 0x00FE, 0x00BE, 0x00B2, 0x00AE, 0x2212, 0x00F0,
+    0x00D7, 0x00B3, 0x00A9, 0x00C1, 0x00C2, 0x00C4, 0x00C0, 0x00C5,
+    0x00C3, 0x00C7, 0x00C9, 0x00CA, 0x00CB, 0x00C8, 0x00CD, 0x00CE,
+    0x00CF, 0x00CC, 0x00D1, 0x00D3, 0x00D4, 0x00D6, 0x00D2, 0x00D5,
+    0x0160, 0x00DA, 0x00DB, 0x00DC, 0x00D9, 0x00DD, 0x0178, 0x017D,
+    0x00E1, 0x00E2, 0x00E4, 0x00E0, 0x00E5, 0x00E3, 0x00E7, 0x00E9,
+    0x00EA, 0x00EB, 0x00E8, 0x00ED, 0x00EE, 0x00EF, 0x00EC, 0x00F1,
+    0x00F3, 0x00F4, 0x00F6, 0x00F2, 0x00F5, 0x0161, 0x00FA, 0x00FB,
+    0x00FC, 0x00F9, 0x00FD, 0x00FF, 0x017E,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum::verify_checksum_adjustment;
+
+    fn index(objects: &[Vec<u8>]) -> Vec<u8> {
+        let mut data = (objects.len() as u16).to_be_bytes().to_vec();
+        data.extend([1, 1]);
+        let mut offset = 1;
+        for object in objects {
+            offset += object.len() as u8;
+            data.push(offset);
+        }
+        data.extend(objects.concat());
+        data
+    }
+
+    fn num(value: i32) -> Vec<u8> {
+        [vec![29], value.to_be_bytes().to_vec()].concat()
+    }
+
+    /// Builds a font set containing a name-keyed font with a FontMatrix for
+    /// 2000 units per em, followed by a CID-keyed font with two font DICTs.
+    fn make_font_set() -> Vec<u8> {
+        // [0.0005 0 0 0.0005 0 0]
+        const FONT_MATRIX: [u8; 16] = [
+            0x1E, 0x0A, 0x00, 0x05, 0xFF, 0x8B, 0x8B, 0x1E, 0x0A, 0x00, 0x05, 0xFF, 0x8B, 0x8B, 12,
+            7,
+        ];
+        // defaultWidthX and nominalWidthX
+        let alpha_private = [num(500), vec![20], num(600), vec![21]].concat();
+        let beta_private0 = [num(300), vec![20]].concat();
+        let beta_private1 = [num(400), vec![20], num(1000), vec![21]].concat();
+        // offsets of the data that follows the global subrs, in order
+        let top_dicts = |offsets: &[i32]| {
+            index(&[
+                [
+                    FONT_MATRIX.to_vec(),
+                    num(offsets[0]),
+                    vec![15],
+                    num(offsets[1]),
+                    vec![17],
+                    num(alpha_private.len() as i32),
+                    num(offsets[2]),
+                    vec![18],
+                    num(-10),
+                    num(-250),
+                    num(900),
+                    num(950),
+                    vec![5],
+                ]
+                .concat(),
+                [
+                    num(392),
+                    num(393),
+                    num(0),
+                    vec![12, 30],
+                    num(offsets[3]),
+                    vec![15],
+                    num(offsets[4]),
+                    vec![12, 37],
+                    num(offsets[5]),
+                    vec![17],
+                    num(offsets[6]),
+                    vec![12, 36],
+                ]
+                .concat(),
+            ])
+        };
+        let blocks = |offsets: &[i32]| {
+            vec![
+                // charset: A, quoteright, uni0416, Aacute
+                vec![0, 0, 34, 0, 8, 1, 135, 0, 171],
+                index(&[
+                    // endchar
+                    vec![14],
+                    // 50 10 20 rmoveto 100 hlineto endchar
+                    vec![189, 149, 159, 21, 239, 6, 14],
+                    // -100 5 hmoveto endchar
+                    vec![39, 144, 22, 14],
+                    // 0 0 rmoveto endchar
+                    vec![139, 139, 21, 14],
+                    // 200 30 65 39 endchar (seac A quoteright)
+                    vec![247, 92, 169, 204, 178, 14],
+                ]),
+                alpha_private.clone(),
+                // charset: CIDs 1 and 2
+                vec![0, 0, 1, 0, 2],
+                // FDSelect
+                vec![0, 0, 1, 1],
+                // endchar, 10 endchar, endchar
+                index(&[vec![14], vec![149, 14], vec![14]]),
+                index(&[
+                    [num(beta_private0.len() as i32), num(offsets[7]), vec![18]].concat(),
+                    [num(beta_private1.len() as i32), num(offsets[8]), vec![18]].concat(),
+                ]),
+                beta_private0.clone(),
+                beta_private1.clone(),
+            ]
+        };
+        let header = [
+            vec![1, 0, 4, 4],
+            index(&[b"Alpha".to_vec(), b"Beta".to_vec()]),
+        ]
+        .concat();
+        let strings = [
+            index(&[b"uni0416".to_vec(), b"Adobe".to_vec(), b"Identity".to_vec()]),
+            // empty global subrs
+            vec![0, 0],
+        ]
+        .concat();
+        // sizes don't depend on the offset values
+        let mut offsets = vec![0; 9];
+        let mut pos = header.len() + top_dicts(&offsets).len() + strings.len();
+        for (offset, block) in offsets.iter_mut().zip(blocks(&[0; 9])) {
+            *offset = pos as i32;
+            pos += block.len();
+        }
+        [
+            header,
+            top_dicts(&offsets),
+            strings,
+            blocks(&offsets).concat(),
+        ]
+        .concat()
+    }
+
+    fn advances(font: &CffFont) -> Vec<u16> {
+        let hmtx = font.as_font_ref().hmtx().unwrap();
+        hmtx.h_metrics().iter().map(|m| m.advance()).collect()
+    }
+
+    #[test]
+    fn noto_serif_display() {
+        let font = FontRef::new(font_test_data::NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+        let cff_data = font.cff().unwrap().offset_data().as_bytes();
+        assert_eq!(CffFont::count(cff_data).unwrap(), 1);
+        let cff_font = CffFont::new(cff_data, 0).unwrap();
+        assert_eq!(cff_font.name().unwrap(), "NotoSerifDisplay-Regular");
+        assert!(!cff_font.is_cid_keyed());
+        let synthesized = cff_font.as_font_ref();
+        verify_checksum_adjustment(&synthesized).unwrap();
+        assert_eq!(
+            synthesized.cff().unwrap().offset_data().as_bytes(),
+            cff_data
+        );
+        assert_eq!(
+            synthesized.head().unwrap().units_per_em(),
+            font.head().unwrap().units_per_em()
+        );
+        assert_eq!(
+            synthesized.maxp().unwrap().num_glyphs(),
+            font.maxp().unwrap().num_glyphs()
+        );
+        let expected_advances = font
+            .hmtx()
+            .unwrap()
+            .h_metrics()
+            .iter()
+            .map(|m| m.advance())
+            .collect::<Vec<_>>();
+        assert_eq!(advances(&cff_font), expected_advances);
+        let (cmap, expected_cmap) = (synthesized.cmap().unwrap(), font.cmap().unwrap());
+        for ch in ['i', 'j', 'k', 'l', 'A'] {
+            assert_eq!(
+                cmap.map_codepoint(ch),
+                expected_cmap.map_codepoint(ch),
+                "{ch}"
+            );
+        }
+    }
+
+    #[test]
+    fn font_set() {
+        let data = make_font_set();
+        assert_eq!(CffFont::count(&data).unwrap(), 2);
+        assert!(CffFont::new(&data, 2).is_err());
+        let alpha = CffFont::new(&data, 0).unwrap();
+        assert_eq!(alpha.name().unwrap(), "Alpha");
+        assert!(!alpha.is_cid_keyed());
+        let font = alpha.as_font_ref();
+        let head = font.head().unwrap();
+        assert_eq!(head.units_per_em(), 2000);
+        assert_eq!(
+            [head.x_min(), head.y_min(), head.x_max(), head.y_max()],
+            [0, 0, 205, 30]
+        );
+        let hhea = font.hhea().unwrap();
+        assert_eq!(
+            (hhea.ascender(), hhea.descender()),
+            (950.into(), (-250).into())
+        );
+        assert_eq!(advances(&alpha), [500, 650, 500, 500, 500]);
+        // the seac glyph covers its base and the offset accent
+        let hmtx = font.hmtx().unwrap();
+        assert_eq!(hmtx.h_metrics()[4].side_bearing(), 10);
+        assert_eq!(hhea.x_max_extent(), 205.into());
+        let cmap = font.cmap().unwrap();
+        assert_eq!(cmap.map_codepoint('A'), Some(GlyphId::new(1)));
+        assert_eq!(cmap.map_codepoint('\u{2019}'), Some(GlyphId::new(2)));
+        assert_eq!(cmap.map_codepoint('\u{416}'), Some(GlyphId::new(3)));
+        assert_eq!(cmap.map_codepoint('\''), None);
+    }
+
+    #[test]
+    fn malformed_charset() {
+        let mut data = make_font_set();
+        let charset = [0, 0, 34, 0, 8, 1, 135, 0, 171];
+        let pos = data
+            .windows(charset.len())
+            .position(|window| window == charset)
+            .unwrap();
+        // invalid charset format
+        data[pos] = 5;
+        let alpha = CffFont::new(&data, 0).unwrap();
+        let font = alpha.as_font_ref();
+        assert!(font.cmap().is_err());
+        assert_eq!(advances(&alpha), [500, 650, 500, 500, 500]);
+    }
+
+    #[test]
+    fn malformed_charstring() {
+        let mut data = make_font_set();
+        // -100 5 hmoveto endchar
+        let charstring = [39, 144, 22, 14];
+        let pos = data
+            .windows(charstring.len())
+            .position(|window| window == charstring)
+            .unwrap();
+        // 0 callsubr with no local subrs
+        data[pos..pos + 4].copy_from_slice(&[139, 10, 14, 14]);
+        let alpha = CffFont::new(&data, 0).unwrap();
+        let font = alpha.as_font_ref();
+        assert_eq!(advances(&alpha), [500, 650, 0, 500, 500]);
+        let hmtx = font.hmtx().unwrap();
+        assert_eq!(hmtx.h_metrics()[2].side_bearing(), 0);
+        // the seac glyph using it as an accent is empty, so the font
+        // bounding box only covers the first outline
+        assert_eq!(hmtx.h_metrics()[4].side_bearing(), 0);
+        let head = font.head().unwrap();
+        assert_eq!(
+            [head.x_min(), head.y_min(), head.x_max(), head.y_max()],
+            [0, 0, 110, 20]
+        );
+    }
+
+    #[test]
+    fn cid_keyed_font_in_set() {
+        let data = make_font_set();
+        let beta = CffFont::new(&data, 1).unwrap();
+        assert_eq!(beta.index(), 1);
+        assert_eq!(beta.name().unwrap(), "Beta");
+        assert!(beta.is_cid_keyed());
+        let font = beta.as_font_ref();
+        verify_checksum_adjustment(&font).unwrap();
+        // the other font is moved after the selected one
+        assert_eq!(font.cff().unwrap().name(1).unwrap(), "Alpha");
+        assert_eq!(font.head().unwrap().units_per_em(), 1000);
+        assert!(font.cmap().is_err());
+        // widths come from the Private DICT selected by FDSelect
+        assert_eq!(advances(&beta), [300, 1010, 400]);
+    }
+
+    #[test]
+    fn units_per_em_from_font_matrix() {
+        assert_eq!(units_per_em(Fixed::from_f64(0.001)), 1000);
+        assert_eq!(units_per_em(Fixed::from_f64(1.0 / 2048.0)), 2048);
+        assert_eq!(units_per_em(Fixed::from_f64(0.01)), 100);
+        assert_eq!(units_per_em(Fixed::ZERO), 1000);
+    }
+}
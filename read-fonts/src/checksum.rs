@@ -0,0 +1,272 @@
+//! Computing and verifying sfnt checksums.
+//!
+//! Every record in the [table directory](crate::TableDirectory)
+//! stores a checksum of its table, and the `checksumAdjustment` field of the
+//! `head` table stores a checksum of the whole font. These can be used to
+//! detect corrupted font data.
+//!
+//! # Example
+//!
+//! Checking all the fonts in a file:
+//!
+//! ```no_run
+//! # let bytes: &[u8] = &[];
+//! use read_fonts::{checksum, FileRef};
+//!
+//! let file = FileRef::new(bytes).unwrap();
+//! for font in file.fonts() {
+//!     let font = font.unwrap();
+//!     for error in checksum::verify_table_records(&font) {
+//!         println!("{error}");
+//!     }
+//!     if let Err(error) = checksum::verify_checksum_adjustment(&font) {
+//!         println!("{error}");
+//!     }
+//! }
+//! ```
+
+use types::{Offset32, Tag};
+
+use crate::{offset::Offset, FontRef, TableProvider, TableRecord};
+
+/// The value that the checksum of a complete font is expected to equal.
+///
+/// The `checksumAdjustment` field of the `head` table is set such that the
+/// checksum of the font, including that field, is this value.
+pub const CHECKSUM_MAGIC: u32 = 0xB1B0AFBA;
+
+const HEAD: Tag = Tag::new(b"head");
+const TABLE_DIRECTORY_HEADER_LEN: usize = 12;
+const TABLE_RECORD_LEN: usize = 16;
+
+/// An error found while verifying checksums.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumError {
+    /// The record for the table with this tag points outside of the font data.
+    OutOfBounds(Tag),
+    /// The checksum computed for a table does not match the stored value.
+    ///
+    /// For the whole-font checksum, `tag` is `head` and the values are those
+    /// of the `checksumAdjustment` field.
+    Mismatch {
+        tag: Tag,
+        expected: u32,
+        actual: u32,
+    },
+}
+
+impl std::fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfBounds(tag) => write!(f, "table '{tag}' is out of bounds"),
+            Self::Mismatch {
+                tag,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "checksum mismatch for '{tag}': expected 0x{expected:08X}, found 0x{actual:08X}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumError {}
+
+/// Computes the sfnt checksum of a table.
+///
+/// If `tag` is `head`, the `checksumAdjustment` field is treated as zero, as
+/// required by the specification.
+pub fn table_checksum(tag: Tag, data: &[u8]) -> u32 {
+    let sum = checksum(data);
+    match data.get(8..12) {
+        Some(adjustment) if tag == HEAD => {
+            sum.wrapping_sub(u32::from_be_bytes(adjustment.try_into().unwrap()))
+        }
+        _ => sum,
+    }
+}
+
+/// Computes the sum of the data as big-endian u32 values, padding with
+/// zeros to a multiple of four bytes.
+pub fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut quad = [0u8; 4];
+        quad[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(quad))
+    })
+}
+
+/// Verifies the checksum of every record in the font's table directory.
+///
+/// Returns an iterator over the records that could not be verified. This
+/// works for both standalone fonts and fonts in a collection, since table
+/// offsets are always relative to the start of the file.
+pub fn verify_table_records<'a>(font: &FontRef<'a>) -> impl Iterator<Item = ChecksumError> + 'a {
+    let font = font.clone();
+    let records = font.table_directory.table_records();
+    records.iter().filter_map(move |record| {
+        let tag = record.tag();
+        let Some(data) = record_data(&font, record) else {
+            return Some(ChecksumError::OutOfBounds(tag));
+        };
+        let actual = table_checksum(tag, data);
+        let expected = record.checksum();
+        (actual != expected).then_some(ChecksumError::Mismatch {
+            tag,
+            expected,
+            actual,
+        })
+    })
+}
+
+/// Returns the data referenced by a table record.
+///
+/// Tables are not looked up by tag, so that a malformed directory containing
+/// duplicate tags is checked against the data of each record.
+fn record_data<'a>(font: &FontRef<'a>, record: &TableRecord) -> Option<&'a [u8]> {
+    let start = Offset32::new(record.offset()).non_null()?;
+    let end = start.checked_add(record.length() as usize)?;
+    font.data.as_bytes().get(start..end)
+}
+
+/// Computes the expected value of the `checksumAdjustment` field in the
+/// `head` table.
+///
+/// This is computed from the table directory and the tables it references,
+/// with each table padded to a multiple of four bytes. For a well-formed
+/// standalone font, this is equivalent to a checksum of the whole file.
+///
+/// Returns an error if the table directory or any table is out of bounds.
+pub fn compute_checksum_adjustment(font: &FontRef) -> Result<u32, ChecksumError> {
+    let records = font.table_directory.table_records();
+    let directory_len = TABLE_DIRECTORY_HEADER_LEN + records.len() * TABLE_RECORD_LEN;
+    let directory = font
+        .table_directory
+        .offset_data()
+        .as_bytes()
+        .get(..directory_len)
+        .ok_or(ChecksumError::OutOfBounds(HEAD))?;
+    let mut sum = checksum(directory);
+    for record in records {
+        let tag = record.tag();
+        let data = record_data(font, record).ok_or(ChecksumError::OutOfBounds(tag))?;
+        sum = sum.wrapping_add(table_checksum(tag, data));
+    }
+    Ok(CHECKSUM_MAGIC.wrapping_sub(sum))
+}
+
+/// Verifies the `checksumAdjustment` field of the `head` table.
+///
+/// For fonts in a collection, the specification requires this field to be
+/// ignored, since it is invalidated by the structure of the collection, and
+/// this always succeeds.
+pub fn verify_checksum_adjustment(font: &FontRef) -> Result<(), ChecksumError> {
+    if font.is_collection_member() {
+        return Ok(());
+    }
+    let expected = font
+        .head()
+        .map_err(|_| ChecksumError::OutOfBounds(HEAD))?
+        .checksum_adjustment();
+    let actual = compute_checksum_adjustment(font)?;
+    if actual != expected {
+        return Err(ChecksumError::Mismatch {
+            tag: HEAD,
+            expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table_offset(data: &[u8], tag: Tag) -> usize {
+        let font = FontRef::new(data).unwrap();
+        let records = font.table_directory.table_records();
+        let record = records.iter().find(|record| record.tag() == tag).unwrap();
+        record.offset() as usize
+    }
+
+    #[test]
+    fn head_adjustment_is_ignored() {
+        let font = FontRef::new(font_test_data::SIMPLE_GLYF).unwrap();
+        let head = font.table_data(HEAD).unwrap().as_bytes().to_vec();
+        let mut modified = head.clone();
+        modified[8..12].copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(table_checksum(HEAD, &head), table_checksum(HEAD, &modified));
+        assert_ne!(checksum(&head), checksum(&modified));
+    }
+
+    #[test]
+    fn unpadded_data() {
+        assert_eq!(checksum(&[1, 2, 3, 4, 5]), 0x01020304 + 0x05000000);
+    }
+
+    #[test]
+    fn valid_font() {
+        let font = FontRef::new(font_test_data::SIMPLE_GLYF).unwrap();
+        assert_eq!(verify_table_records(&font).count(), 0);
+        assert_eq!(verify_checksum_adjustment(&font), Ok(()));
+        assert_eq!(
+            checksum(font_test_data::SIMPLE_GLYF),
+            CHECKSUM_MAGIC,
+            "test font should have a valid checksumAdjustment"
+        );
+    }
+
+    #[test]
+    fn corrupted_font() {
+        let mut data = font_test_data::SIMPLE_GLYF.to_vec();
+        let glyf = table_offset(&data, Tag::new(b"glyf"));
+        data[glyf + 2] ^= 0x10;
+        let font = FontRef::new(&data).unwrap();
+        let errors = verify_table_records(&font).collect::<Vec<_>>();
+        assert!(matches!(
+            errors.as_slice(),
+            [ChecksumError::Mismatch { tag, .. }] if *tag == Tag::new(b"glyf")
+        ));
+        assert!(matches!(
+            verify_checksum_adjustment(&font),
+            Err(ChecksumError::Mismatch { tag: HEAD, .. })
+        ));
+    }
+
+    #[test]
+    fn duplicate_tags() {
+        let mut data = font_test_data::SIMPLE_GLYF.to_vec();
+        // give the second table record the same tag as the first
+        let first_tag = data[12..16].to_vec();
+        data[28..32].copy_from_slice(&first_tag);
+        let font = FontRef::new(&data).unwrap();
+        let records = font.table_directory.table_records();
+        assert_eq!(records[0].tag(), records[1].tag());
+        assert_eq!(verify_table_records(&font).count(), 0);
+    }
+
+    #[test]
+    fn collection_ignores_adjustment() {
+        let font = FontRef::new(font_test_data::SIMPLE_GLYF).unwrap();
+        let n_tables = font.table_directory.table_records().len();
+        // build a collection with a single font, shifting all offsets
+        let shift = 16u32;
+        let mut data = Vec::new();
+        data.extend_from_slice(b"ttcf");
+        data.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0, 1]);
+        data.extend_from_slice(&shift.to_be_bytes());
+        data.extend_from_slice(font_test_data::SIMPLE_GLYF);
+        for ix in 0..n_tables {
+            let offset = shift as usize + 12 + ix * 16 + 8;
+            let value = u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap());
+            data[offset..offset + 4].copy_from_slice(&(value + shift).to_be_bytes());
+        }
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert!(font.is_collection_member());
+        assert_eq!(verify_table_records(&font).count(), 0);
+        assert_eq!(verify_checksum_adjustment(&font), Ok(()));
+    }
+}
@@ -0,0 +1,126 @@
+//! Computing the set of glyphs reachable from a set of input glyphs.
+//!
+//! This is the first step of subsetting a font: given the glyphs required
+//! to render some text, find every other glyph that may be produced by
+//! substitution or that is referenced as a component of an outline.
+
+use std::collections::HashSet;
+
+use crate::{
+    tables::postscript,
+    types::{GlyphId, Tag},
+    ReadError, TableProvider,
+};
+
+/// Returns the closure of the given glyphs over the font.
+///
+/// The result contains the input glyphs, `.notdef`, all glyphs reachable
+/// through the `GSUB` lookups of the given features (or all features, if
+/// `features` is `None`) and, recursively, the components of any composite
+/// glyphs in the `glyf` table or accented glyphs composed with the `seac`
+/// operator in the `CFF` table.
+///
+/// See [`Gsub::closure_glyphs`](crate::tables::gsub::Gsub::closure_glyphs)
+/// for details on how substitutions are handled.
+///
+/// Glyph ids that are not less than the number of glyphs in the `maxp`
+/// table are removed from the result.
+pub fn glyph_closure<'a>(
+    font: &impl TableProvider<'a>,
+    glyphs: impl IntoIterator<Item = GlyphId>,
+    features: Option<&[Tag]>,
+) -> Result<HashSet<GlyphId>, ReadError> {
+    let num_glyphs = font.maxp().map(|maxp| maxp.num_glyphs()).ok();
+    let retain_valid = |glyphs: &mut HashSet<GlyphId>| {
+        if let Some(num_glyphs) = num_glyphs {
+            glyphs.retain(|glyph_id| glyph_id.to_u16() < num_glyphs);
+        }
+    };
+    let mut glyphs = glyphs.into_iter().collect::<HashSet<_>>();
+    glyphs.insert(GlyphId::NOTDEF);
+    if let Ok(gsub) = font.gsub() {
+        let lookups = gsub.collect_lookups(features)?;
+        gsub.closure_glyphs(&lookups, &mut glyphs)?;
+    }
+    retain_valid(&mut glyphs);
+    if let (Ok(loca), Ok(glyf)) = (font.loca(None), font.glyf()) {
+        glyf.closure_glyphs(&loca, &mut glyphs);
+    } else if let Ok(cff) = font.cff() {
+        cff.closure_glyphs(0, &mut glyphs).map_err(|e| match e {
+            postscript::Error::Read(e) => e,
+            _ => ReadError::MalformedData("invalid CFF charstring"),
+        })?;
+    }
+    retain_valid(&mut glyphs);
+    Ok(glyphs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::BeBuffer, FontRef};
+
+    #[test]
+    fn gsub_closure() {
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let glyphs = glyph_closure(&font, [GlyphId::new(1)], None).unwrap();
+        let expected = [0, 1, 2].map(GlyphId::new);
+        assert_eq!(glyphs, HashSet::from(expected));
+        let glyphs = glyph_closure(&font, [GlyphId::new(1)], Some(&[Tag::new(b"liga")])).unwrap();
+        let expected = [0, 1].map(GlyphId::new);
+        assert_eq!(glyphs, HashSet::from(expected));
+    }
+
+    #[test]
+    fn glyf_closure() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let glyphs = glyph_closure(&font, [GlyphId::new(2)], None).unwrap();
+        let expected = [0, 1, 2, 3].map(GlyphId::new);
+        assert_eq!(glyphs, HashSet::from(expected));
+    }
+
+    #[test]
+    fn glyph_ids_out_of_range() {
+        // a GSUB table with a liga feature that substitutes glyph 1 with
+        // glyph 60000
+        let gsub = BeBuffer::new()
+            // version, script list, feature list and lookup list offsets
+            .extend([1u16, 0, 10, 12, 26])
+            // empty script list
+            .push(0u16)
+            // feature list with a single feature
+            .push(1u16)
+            .push(Tag::new(b"liga"))
+            .extend([8u16, 0, 1, 0])
+            // lookup list with a single lookup
+            .extend([1u16, 4, 1, 0, 1, 8])
+            // single substitution format 2 mapping 1 -> 60000
+            .extend([2u16, 8, 1, 60000, 1, 1, 1]);
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let mut tables = font
+            .table_directory
+            .table_records()
+            .iter()
+            .filter(|record| record.tag() != Tag::new(b"GSUB"))
+            .map(|record| {
+                let data = font.table_data(record.tag()).unwrap();
+                (record.tag(), data.as_bytes().to_vec())
+            })
+            .chain(std::iter::once((Tag::new(b"GSUB"), gsub.to_vec())))
+            .collect::<Vec<_>>();
+        tables.sort_by_key(|(tag, _)| *tag);
+        let data = crate::cff_font::build_sfnt(tables).unwrap();
+        let font = FontRef::new(&data).unwrap();
+        let glyphs = glyph_closure(&font, [GlyphId::new(1)], None).unwrap();
+        let expected = [0, 1].map(GlyphId::new);
+        assert_eq!(glyphs, HashSet::from(expected));
+    }
+
+    #[test]
+    fn cff_closure() {
+        let font = FontRef::new(font_test_data::NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+        let glyphs = glyph_closure(&font, [GlyphId::new(1)], Some(&[])).unwrap();
+        let expected = [0, 1].map(GlyphId::new);
+        assert_eq!(glyphs, HashSet::from(expected));
+    }
+}
@@ -0,0 +1,5 @@
+//! Data structures useful for working with font data.
+
+pub mod int_set;
+
+pub use int_set::IntSet;
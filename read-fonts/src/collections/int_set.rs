@@ -0,0 +1,663 @@
+//! A compact, page-based set of integers.
+//!
+//! This is modeled on the bit set used by [HarfBuzz](https://github.com/harfbuzz/harfbuzz/blob/main/src/hb-bit-set.hh).
+//! Values are stored as bits in fixed-size pages, which are allocated only
+//! for the parts of the integer space that are populated. This makes the set
+//! compact and fast for the clustered values, such as glyph identifiers and
+//! codepoints, that are typically found in fonts.
+
+use std::{hash::Hash, marker::PhantomData, ops::RangeInclusive};
+
+use types::GlyphId;
+
+/// A type that can be stored in an [`IntSet`].
+///
+/// Values are stored as `u32`s, so the conversion must preserve ordering.
+pub trait Domain: Copy + Ord {
+    /// Converts this value to a `u32`.
+    fn to_u32(self) -> u32;
+
+    /// Converts a `u32` back to a value.
+    ///
+    /// This is only called with values that were produced by
+    /// [`to_u32`](Self::to_u32).
+    fn from_u32(value: u32) -> Self;
+}
+
+impl Domain for u8 {
+    fn to_u32(self) -> u32 {
+        self as u32
+    }
+
+    fn from_u32(value: u32) -> Self {
+        value as u8
+    }
+}
+
+impl Domain for u16 {
+    fn to_u32(self) -> u32 {
+        self as u32
+    }
+
+    fn from_u32(value: u32) -> Self {
+        value as u16
+    }
+}
+
+impl Domain for u32 {
+    fn to_u32(self) -> u32 {
+        self
+    }
+
+    fn from_u32(value: u32) -> Self {
+        value
+    }
+}
+
+impl Domain for GlyphId {
+    fn to_u32(self) -> u32 {
+        self.to_u16() as u32
+    }
+
+    fn from_u32(value: u32) -> Self {
+        GlyphId::new(value as u16)
+    }
+}
+
+const WORD_BITS: u32 = u64::BITS;
+const PAGE_WORDS: usize = 8;
+const PAGE_BITS: u32 = WORD_BITS * PAGE_WORDS as u32;
+const PAGE_SHIFT: u32 = PAGE_BITS.trailing_zeros();
+const PAGE_MASK: u32 = PAGE_BITS - 1;
+
+/// A set of integers.
+///
+/// The set is divided into pages of 512 bits, and only the pages that
+/// contain at least one value are allocated. Membership tests and
+/// insertions are logarithmic in the number of pages, and operations on
+/// contiguous ranges and on other sets work a page at a time.
+///
+/// # Example
+///
+/// ```
+/// use read_fonts::{collections::IntSet, types::GlyphId};
+///
+/// let mut glyphs = IntSet::new();
+/// glyphs.insert_range(GlyphId::new(10)..=GlyphId::new(20));
+/// glyphs.remove(GlyphId::new(15));
+/// assert!(glyphs.contains(GlyphId::new(14)));
+/// assert!(!glyphs.contains(GlyphId::new(15)));
+/// assert_eq!(glyphs.len(), 10);
+/// ```
+#[derive(Clone)]
+pub struct IntSet<T> {
+    pages: Vec<BitPage>,
+    // sorted by major; maps each major to an index into `pages`
+    page_map: Vec<PageInfo>,
+    len: u64,
+    phantom: PhantomData<T>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PageInfo {
+    // the value of the page's first bit, shifted right by PAGE_SHIFT
+    major: u32,
+    index: u32,
+}
+
+impl<T: Domain> IntSet<T> {
+    /// Creates a new, empty set.
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            page_map: Vec::new(),
+            len: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all values from the set.
+    pub fn clear(&mut self) {
+        self.pages.clear();
+        self.page_map.clear();
+        self.len = 0;
+    }
+
+    /// Returns `true` if the set contains `value`.
+    pub fn contains(&self, value: T) -> bool {
+        let value = value.to_u32();
+        self.page(value >> PAGE_SHIFT)
+            .map(|page| page.contains(value & PAGE_MASK))
+            .unwrap_or_default()
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// Returns `true` if the value was not already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let value = value.to_u32();
+        let inserted = self
+            .page_mut_or_insert(value >> PAGE_SHIFT)
+            .insert(value & PAGE_MASK);
+        self.len += inserted as u64;
+        inserted
+    }
+
+    /// Removes a value from the set.
+    ///
+    /// Returns `true` if the value was present.
+    pub fn remove(&mut self, value: T) -> bool {
+        let value = value.to_u32();
+        let removed = self
+            .page_mut(value >> PAGE_SHIFT)
+            .map(|page| page.remove(value & PAGE_MASK))
+            .unwrap_or_default();
+        self.len -= removed as u64;
+        removed
+    }
+
+    /// Adds all values in the given range to the set.
+    pub fn insert_range(&mut self, range: RangeInclusive<T>) {
+        let (first, last) = (range.start().to_u32(), range.end().to_u32());
+        if first > last {
+            return;
+        }
+        let (first_major, last_major) = (first >> PAGE_SHIFT, last >> PAGE_SHIFT);
+        for major in first_major..=last_major {
+            let start = if major == first_major {
+                first & PAGE_MASK
+            } else {
+                0
+            };
+            let end = if major == last_major {
+                last & PAGE_MASK
+            } else {
+                PAGE_MASK
+            };
+            let page = self.page_mut_or_insert(major);
+            let before = page.len();
+            page.insert_range(start, end);
+            self.len += (page.len() - before) as u64;
+        }
+    }
+
+    /// Removes all values in the given range from the set.
+    pub fn remove_range(&mut self, range: RangeInclusive<T>) {
+        let (first, last) = (range.start().to_u32(), range.end().to_u32());
+        if first > last {
+            return;
+        }
+        let (first_major, last_major) = (first >> PAGE_SHIFT, last >> PAGE_SHIFT);
+        let start_ix = self
+            .page_map
+            .partition_point(|info| info.major < first_major);
+        for info in &self.page_map[start_ix..] {
+            if info.major > last_major {
+                break;
+            }
+            let start = if info.major == first_major {
+                first & PAGE_MASK
+            } else {
+                0
+            };
+            let end = if info.major == last_major {
+                last & PAGE_MASK
+            } else {
+                PAGE_MASK
+            };
+            let page = &mut self.pages[info.index as usize];
+            let before = page.len();
+            page.remove_range(start, end);
+            self.len -= (before - page.len()) as u64;
+        }
+    }
+
+    /// Returns the smallest value in the set.
+    pub fn first(&self) -> Option<T> {
+        self.iter_pages()
+            .find_map(|(major, page)| Some(join(major, page.first()?)))
+            .map(T::from_u32)
+    }
+
+    /// Returns the largest value in the set.
+    pub fn last(&self) -> Option<T> {
+        self.iter_pages()
+            .rev()
+            .find_map(|(major, page)| Some(join(major, page.last()?)))
+            .map(T::from_u32)
+    }
+
+    /// Returns an iterator over the values in the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.iter_u32().map(T::from_u32)
+    }
+
+    /// Returns an iterator over the maximal ranges of consecutive values in
+    /// the set, in ascending order.
+    pub fn iter_ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        let mut iter = self.iter_u32().peekable();
+        std::iter::from_fn(move || {
+            let start = iter.next()?;
+            let mut end = start;
+            while let Some(next) = end.checked_add(1).and_then(|next| iter.next_if_eq(&next)) {
+                end = next;
+            }
+            Some(T::from_u32(start)..=T::from_u32(end))
+        })
+    }
+
+    /// Adds all values in `other` to this set.
+    pub fn union(&mut self, other: &IntSet<T>) {
+        self.process(other, |a, b| a | b, true, true);
+    }
+
+    /// Removes all values that are not also in `other` from this set.
+    pub fn intersect(&mut self, other: &IntSet<T>) {
+        self.process(other, |a, b| a & b, false, false);
+    }
+
+    /// Removes all values in `other` from this set.
+    pub fn subtract(&mut self, other: &IntSet<T>) {
+        self.process(other, |a, b| a & !b, true, false);
+    }
+
+    /// Returns `true` if this set and `other` have at least one value in
+    /// common.
+    pub fn intersects(&self, other: &IntSet<T>) -> bool {
+        let (mut a, mut b) = (self.page_map.iter(), other.page_map.iter());
+        let (mut next_a, mut next_b) = (a.next(), b.next());
+        while let (Some(info_a), Some(info_b)) = (next_a, next_b) {
+            match info_a.major.cmp(&info_b.major) {
+                std::cmp::Ordering::Less => next_a = a.next(),
+                std::cmp::Ordering::Greater => next_b = b.next(),
+                std::cmp::Ordering::Equal => {
+                    let page_a = &self.pages[info_a.index as usize];
+                    let page_b = &other.pages[info_b.index as usize];
+                    if page_a.intersects(page_b) {
+                        return true;
+                    }
+                    (next_a, next_b) = (a.next(), b.next());
+                }
+            }
+        }
+        false
+    }
+
+    fn iter_u32(&self) -> impl Iterator<Item = u32> + '_ {
+        self.iter_pages()
+            .flat_map(|(major, page)| page.iter().map(move |bit| join(major, bit)))
+    }
+
+    /// Returns an iterator over the allocated pages, in order.
+    ///
+    /// Pages may be empty if all of their values have been removed.
+    fn iter_pages(&self) -> impl DoubleEndedIterator<Item = (u32, &BitPage)> + '_ {
+        self.page_map
+            .iter()
+            .map(|info| (info.major, &self.pages[info.index as usize]))
+    }
+
+    fn page(&self, major: u32) -> Option<&BitPage> {
+        let ix = self
+            .page_map
+            .binary_search_by_key(&major, |info| info.major)
+            .ok()?;
+        self.pages.get(self.page_map[ix].index as usize)
+    }
+
+    fn page_mut(&mut self, major: u32) -> Option<&mut BitPage> {
+        let ix = self
+            .page_map
+            .binary_search_by_key(&major, |info| info.major)
+            .ok()?;
+        self.pages.get_mut(self.page_map[ix].index as usize)
+    }
+
+    fn page_mut_or_insert(&mut self, major: u32) -> &mut BitPage {
+        let index = match self
+            .page_map
+            .binary_search_by_key(&major, |info| info.major)
+        {
+            Ok(ix) => self.page_map[ix].index as usize,
+            Err(ix) => {
+                // new pages are appended, so existing indices remain valid
+                let index = self.pages.len();
+                self.pages.push(BitPage::default());
+                self.page_map.insert(
+                    ix,
+                    PageInfo {
+                        major,
+                        index: index as u32,
+                    },
+                );
+                index
+            }
+        };
+        &mut self.pages[index]
+    }
+
+    /// Combines the pages of this set with those of `other`.
+    ///
+    /// Pages present in both sets are combined word by word with `op`. The
+    /// `keep_*` arguments determine whether pages present in only one of the
+    /// sets are retained.
+    fn process(
+        &mut self,
+        other: &IntSet<T>,
+        op: impl Fn(u64, u64) -> u64,
+        keep_self: bool,
+        keep_other: bool,
+    ) {
+        let mut result = IntSet::new();
+        let (mut a, mut b) = (self.iter_pages().peekable(), other.iter_pages().peekable());
+        loop {
+            let (major, page) = match (a.peek(), b.peek()) {
+                (Some((major_a, page_a)), Some((major_b, page_b))) if major_a == major_b => {
+                    let combined = page_a.combine(page_b, &op);
+                    let major = *major_a;
+                    a.next();
+                    b.next();
+                    (major, Some(combined))
+                }
+                (Some((major_a, page_a)), next_b)
+                    if next_b.is_none_or(|(major_b, _)| major_a < major_b) =>
+                {
+                    let item = (*major_a, keep_self.then_some(**page_a));
+                    a.next();
+                    item
+                }
+                (_, Some((major_b, page_b))) => {
+                    let item = (*major_b, keep_other.then_some(**page_b));
+                    b.next();
+                    item
+                }
+                (_, None) => break,
+            };
+            if let Some(page) = page.filter(|page| !page.is_empty()) {
+                result.len += page.len() as u64;
+                result.page_map.push(PageInfo {
+                    major,
+                    index: result.pages.len() as u32,
+                });
+                result.pages.push(page);
+            }
+        }
+        drop((a, b));
+        *self = result;
+    }
+}
+
+impl<T: Domain> Default for IntSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Domain> PartialEq for IntSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // empty pages are ignored, since sets may retain them after removal
+        let non_empty = |(_, page): &(u32, &BitPage)| !page.is_empty();
+        self.len == other.len
+            && self
+                .iter_pages()
+                .filter(non_empty)
+                .eq(other.iter_pages().filter(non_empty))
+    }
+}
+
+impl<T: Domain> Eq for IntSet<T> {}
+
+impl<T: Domain> Hash for IntSet<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for (major, page) in self.iter_pages().filter(|(_, page)| !page.is_empty()) {
+            major.hash(state);
+            page.hash(state);
+        }
+    }
+}
+
+impl<T: Domain + std::fmt::Debug> std::fmt::Debug for IntSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Domain> Extend<T> for IntSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Domain> FromIterator<T> for IntSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = IntSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Domain, const N: usize> From<[T; N]> for IntSet<T> {
+    fn from(values: [T; N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+fn join(major: u32, bit: u32) -> u32 {
+    (major << PAGE_SHIFT) | bit
+}
+
+/// A fixed-size page of bits.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct BitPage([u64; PAGE_WORDS]);
+
+impl BitPage {
+    fn word_and_mask(bit: u32) -> (usize, u64) {
+        ((bit / WORD_BITS) as usize, 1 << (bit % WORD_BITS))
+    }
+
+    /// Returns the mask for the bits of word `word` within `start..=end`.
+    fn range_mask(word: u32, start: u32, end: u32) -> u64 {
+        let low = if word == start / WORD_BITS {
+            start % WORD_BITS
+        } else {
+            0
+        };
+        let high = if word == end / WORD_BITS {
+            end % WORD_BITS
+        } else {
+            WORD_BITS - 1
+        };
+        (u64::MAX << low) & (u64::MAX >> (WORD_BITS - 1 - high))
+    }
+
+    fn contains(&self, bit: u32) -> bool {
+        let (word, mask) = Self::word_and_mask(bit);
+        self.0[word] & mask != 0
+    }
+
+    fn insert(&mut self, bit: u32) -> bool {
+        let (word, mask) = Self::word_and_mask(bit);
+        let inserted = self.0[word] & mask == 0;
+        self.0[word] |= mask;
+        inserted
+    }
+
+    fn remove(&mut self, bit: u32) -> bool {
+        let (word, mask) = Self::word_and_mask(bit);
+        let removed = self.0[word] & mask != 0;
+        self.0[word] &= !mask;
+        removed
+    }
+
+    fn insert_range(&mut self, start: u32, end: u32) {
+        for word in start / WORD_BITS..=end / WORD_BITS {
+            self.0[word as usize] |= Self::range_mask(word, start, end);
+        }
+    }
+
+    fn remove_range(&mut self, start: u32, end: u32) {
+        for word in start / WORD_BITS..=end / WORD_BITS {
+            self.0[word as usize] &= !Self::range_mask(word, start, end);
+        }
+    }
+
+    fn len(&self) -> u32 {
+        self.0.iter().map(|word| word.count_ones()).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|word| *word == 0)
+    }
+
+    fn intersects(&self, other: &BitPage) -> bool {
+        self.0.iter().zip(other.0).any(|(a, b)| a & b != 0)
+    }
+
+    fn combine(&self, other: &BitPage, op: impl Fn(u64, u64) -> u64) -> BitPage {
+        let mut result = BitPage::default();
+        for (out, (a, b)) in result.0.iter_mut().zip(self.0.iter().zip(other.0)) {
+            *out = op(*a, b);
+        }
+        result
+    }
+
+    fn first(&self) -> Option<u32> {
+        self.0
+            .iter()
+            .enumerate()
+            .find(|(_, word)| **word != 0)
+            .map(|(i, word)| i as u32 * WORD_BITS + word.trailing_zeros())
+    }
+
+    fn last(&self) -> Option<u32> {
+        self.0
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, word)| **word != 0)
+            .map(|(i, word)| i as u32 * WORD_BITS + (WORD_BITS - 1 - word.leading_zeros()))
+    }
+
+    fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.iter().enumerate().flat_map(|(i, word)| {
+            let mut bits = *word;
+            let base = i as u32 * WORD_BITS;
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+                let bit = bits.trailing_zeros();
+                // clear the lowest set bit
+                bits &= bits - 1;
+                Some(base + bit)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_contains_remove() {
+        let mut set = IntSet::<u32>::new();
+        assert!(set.is_empty());
+        assert!(set.insert(10));
+        assert!(!set.insert(10));
+        assert!(set.insert(2000));
+        assert!(set.insert(5));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(10) && set.contains(2000) && set.contains(5));
+        assert!(!set.contains(11) && !set.contains(100_000));
+        assert!(set.remove(10));
+        assert!(!set.remove(10));
+        assert!(!set.remove(100_000));
+        assert_eq!(set.len(), 2);
+        // pages are allocated out of order but iterated in order
+        assert_eq!(set.iter().collect::<Vec<_>>(), [5, 2000]);
+    }
+
+    #[test]
+    fn extreme_values() {
+        let mut set = IntSet::from([u32::MAX, 0, u32::MAX - 1]);
+        assert_eq!(set.first(), Some(0));
+        assert_eq!(set.last(), Some(u32::MAX));
+        assert_eq!(
+            set.iter_ranges().collect::<Vec<_>>(),
+            [0..=0, u32::MAX - 1..=u32::MAX]
+        );
+        set.remove_range(1..=u32::MAX);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut set = IntSet::<u16>::new();
+        set.insert_range(60..=1100);
+        assert_eq!(set.len(), 1041);
+        set.insert_range(50..=70);
+        assert_eq!(set.len(), 1051);
+        // an empty range is ignored
+        let (start, end) = (10, 5);
+        set.insert_range(start..=end);
+        assert_eq!(set.len(), 1051);
+        set.remove_range(100..=1000);
+        assert_eq!(set.len(), 150);
+        assert!(set.contains(99) && !set.contains(100));
+        assert!(set.contains(1001) && !set.contains(1000));
+        assert_eq!(
+            set.iter_ranges().collect::<Vec<_>>(),
+            [50..=99, 1001..=1100]
+        );
+        assert_eq!(set.first(), Some(50));
+        assert_eq!(set.last(), Some(1100));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = (0u16..1000).step_by(2).collect::<IntSet<_>>();
+        let b = (500u16..3000).collect::<IntSet<_>>();
+
+        let mut union = a.clone();
+        union.union(&b);
+        assert_eq!(union.len(), 250 + 2500);
+        assert!(union.contains(0) && union.contains(2999) && !union.contains(1));
+
+        let mut intersection = a.clone();
+        intersection.intersect(&b);
+        assert_eq!(intersection.len(), 250);
+        assert_eq!(intersection.first(), Some(500));
+        assert_eq!(intersection.last(), Some(998));
+        assert!(a.intersects(&b));
+
+        let mut difference = a.clone();
+        difference.subtract(&b);
+        assert_eq!(difference.len(), 250);
+        assert_eq!(difference.last(), Some(498));
+        assert!(!difference.intersects(&b));
+    }
+
+    #[test]
+    fn equality_ignores_empty_pages() {
+        let mut a = IntSet::from([GlyphId::new(1), GlyphId::new(4000)]);
+        let b = IntSet::from([GlyphId::new(1)]);
+        assert_ne!(a, b);
+        a.remove(GlyphId::new(4000));
+        assert_eq!(a, b);
+        assert_eq!(format!("{a:?}"), "{GlyphId(1)}");
+    }
+}
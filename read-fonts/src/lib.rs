@@ -68,15 +68,32 @@ extern crate std;
 extern crate core as std;
 
 pub mod array;
+#[cfg(feature = "std")]
+mod cached_font;
+#[cfg(feature = "std")]
+mod cff_font;
+pub mod checksum;
+#[cfg(feature = "std")]
+pub mod closure;
+#[cfg(feature = "std")]
+pub mod collections;
 mod font_data;
 mod offset;
 mod offset_array;
+#[cfg(feature = "std")]
+mod owned_font;
 mod read;
+#[cfg(feature = "traversal")]
+pub mod sanitize;
 mod table_provider;
 mod table_ref;
 pub mod tables;
 #[cfg(feature = "traversal")]
 pub mod traversal;
+#[cfg(feature = "woff")]
+pub mod woff;
+#[cfg(feature = "woff2")]
+pub mod woff2;
 
 #[cfg(any(test, feature = "codegen_test"))]
 pub mod codegen_test;
@@ -88,9 +105,15 @@ mod test_helpers;
 #[cfg(any(test, feature = "scaler_test"))]
 pub mod scaler_test;
 
+#[cfg(feature = "std")]
+pub use cached_font::CachedFontRef;
+#[cfg(feature = "std")]
+pub use cff_font::CffFont;
 pub use font_data::FontData;
 pub use offset::{Offset, ResolveNullableOffset, ResolveOffset};
 pub use offset_array::{ArrayOfNullableOffsets, ArrayOfOffsets};
+#[cfg(feature = "std")]
+pub use owned_font::{OwnedFont, SharedFontData};
 pub use read::{ComputeSize, FontRead, FontReadWithArgs, FromBytes, ReadArgs, ReadError, VarSize};
 pub use table_provider::{TableProvider, TopLevelTable};
 pub use table_ref::TableRef;
@@ -153,6 +176,12 @@ pub(crate) mod codegen_prelude {
                 .saturating_mul(c.try_into().unwrap_or_default())
         }
 
+        pub fn multiply<T: TryInto<usize>, U: TryInto<usize>>(a: T, b: U) -> usize {
+            a.try_into()
+                .unwrap_or_default()
+                .saturating_mul(b.try_into().unwrap_or_default())
+        }
+
         pub fn half<T: TryInto<usize>>(val: T) -> usize {
             val.try_into().unwrap_or_default() / 2
         }
@@ -161,18 +190,50 @@ pub(crate) mod codegen_prelude {
 
 include!("../generated/font.rs");
 
+#[cfg(feature = "woff")]
+const ENCODED_FONT_ERROR: ReadError =
+    ReadError::MalformedData("WOFF data must be decoded before use");
+
 #[derive(Clone)]
 /// Reference to the content of a font or font collection file.
+///
+/// Additional variants are available when the `woff` and `woff2` features
+/// are enabled, so this enum is non-exhaustive.
+#[non_exhaustive]
 pub enum FileRef<'a> {
     /// A single font.
     Font(FontRef<'a>),
     /// A collection of fonts.
     Collection(CollectionRef<'a>),
+    /// A WOFF file.
+    ///
+    /// The contained font must be decoded with [`WoffRef::to_sfnt`] before
+    /// use.
+    ///
+    /// [`WoffRef::to_sfnt`]: woff::WoffRef::to_sfnt
+    #[cfg(feature = "woff")]
+    Woff(woff::WoffRef<'a>),
+    /// A WOFF2 file, containing either a single font or a collection.
+    ///
+    /// The contained font data must be decoded with [`Woff2Ref::to_sfnt`]
+    /// before use.
+    ///
+    /// [`Woff2Ref::to_sfnt`]: woff2::Woff2Ref::to_sfnt
+    #[cfg(feature = "woff2")]
+    Woff2(woff2::Woff2Ref<'a>),
 }
 
 impl<'a> FileRef<'a> {
     /// Creates a new reference to a file representing a font or font collection.
     pub fn new(data: &'a [u8]) -> Result<Self, ReadError> {
+        #[cfg(feature = "woff")]
+        if data.starts_with(&woff::WOFF_SIGNATURE.into_bytes()) {
+            return woff::WoffRef::new(data).map(Self::Woff);
+        }
+        #[cfg(feature = "woff2")]
+        if data.starts_with(&woff2::WOFF2_SIGNATURE.into_bytes()) {
+            return woff2::Woff2Ref::new(data).map(Self::Woff2);
+        }
         Ok(if let Ok(collection) = CollectionRef::new(data) {
             Self::Collection(collection)
         } else {
@@ -181,10 +242,39 @@ impl<'a> FileRef<'a> {
     }
 
     /// Returns an iterator over the fonts contained in the file.
+    ///
+    /// For WOFF and WOFF2 files, this yields a single error, since the font data must
+    /// be decoded first. The decoded data, which may itself be a collection in the
+    /// case of WOFF2, can then be loaded with [`FileRef::new`]:
+    ///
+    /// ```
+    /// # use read_fonts::{FileRef, ReadError};
+    /// fn font_count(data: &[u8]) -> Result<usize, ReadError> {
+    ///     let decoded: Vec<u8>;
+    ///     let file = match FileRef::new(data)? {
+    ///         #[cfg(feature = "woff")]
+    ///         FileRef::Woff(woff) => {
+    ///             decoded = woff.to_sfnt()?;
+    ///             FileRef::new(&decoded)?
+    ///         }
+    ///         #[cfg(feature = "woff2")]
+    ///         FileRef::Woff2(woff) => {
+    ///             decoded = woff.to_sfnt()?;
+    ///             FileRef::new(&decoded)?
+    ///         }
+    ///         file => file,
+    ///     };
+    ///     Ok(file.fonts().count())
+    /// }
+    /// ```
     pub fn fonts(&self) -> impl Iterator<Item = Result<FontRef<'a>, ReadError>> + 'a + Clone {
         let (iter_one, iter_two) = match self {
             Self::Font(font) => (Some(Ok(font.clone())), None),
             Self::Collection(collection) => (None, Some(collection.iter())),
+            #[cfg(feature = "woff")]
+            Self::Woff(_) => (Some(Err(ENCODED_FONT_ERROR)), None),
+            #[cfg(feature = "woff2")]
+            Self::Woff2(_) => (Some(Err(ENCODED_FONT_ERROR)), None),
         };
         iter_two.into_iter().flatten().chain(iter_one)
     }
@@ -273,6 +363,9 @@ impl<'a> FontRef<'a> {
     /// In other words, this accepts either font collection (ttc) or single
     /// font (ttf/otf) files. If a single font file is provided, the index
     /// parameter must be 0.
+    ///
+    /// WOFF and WOFF2 files produce an error and must be decoded first, as
+    /// described in [`FileRef::fonts`].
     pub fn from_index(data: &'a [u8], index: u32) -> Result<Self, ReadError> {
         let file = FileRef::new(data)?;
         match file {
@@ -284,9 +377,19 @@ impl<'a> FontRef<'a> {
                 }
             }
             FileRef::Collection(collection) => collection.get(index),
+            #[cfg(feature = "woff")]
+            FileRef::Woff(_) => Err(ENCODED_FONT_ERROR),
+            #[cfg(feature = "woff2")]
+            FileRef::Woff2(_) => Err(ENCODED_FONT_ERROR),
         }
     }
 
+    /// Returns true if this font is a member of a font collection.
+    pub fn is_collection_member(&self) -> bool {
+        // the table directory of a standalone font is at the start of the data
+        self.table_directory.offset_data().len() != self.data.len()
+    }
+
     /// Returns the data for the table with the specified tag, if present.
     pub fn table_data(&self, tag: Tag) -> Option<FontData<'a>> {
         self.table_directory
@@ -0,0 +1,250 @@
+//! An owned font that can be shared between threads.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use types::Tag;
+
+use crate::{FontData, FontRead, FontRef, ReadError, TableDirectory, TableProvider};
+
+/// Shared storage for font data.
+pub type SharedFontData = Arc<dyn AsRef<[u8]> + Send + Sync>;
+
+/// Source of identifiers for fonts.
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A table directory with no tables, used when the table directory can no
+/// longer be read from the font data.
+static EMPTY_TABLE_DIRECTORY: [u8; 12] = [0; 12];
+
+/// An in-memory font that owns its data.
+///
+/// Unlike [`FontRef`], this type has no lifetime parameter, so it can be
+/// stored in long-lived structures such as caches without borrowing. The
+/// data is reference counted, so cloning is cheap, and it may be backed by
+/// anything that implements `AsRef<[u8]>`, such as a `Vec<u8>`, an
+/// `Arc<[u8]>` or a memory map.
+///
+/// Tables are accessed through [`TableProvider`], which is implemented for
+/// `&OwnedFont`, or through a [`FontRef`] obtained with
+/// [`as_font_ref`](Self::as_font_ref). Both are cheap to construct, so they
+/// can be created on demand wherever a borrowed font is needed.
+///
+/// # Example
+///
+/// ```
+/// # let bytes = font_test_data::SIMPLE_GLYF.to_vec();
+/// use read_fonts::{OwnedFont, TableProvider};
+///
+/// let font = OwnedFont::new(bytes, 0).unwrap();
+/// let num_glyphs = font.as_font_ref().maxp().unwrap().num_glyphs();
+/// ```
+#[derive(Clone)]
+pub struct OwnedFont {
+    data: SharedFontData,
+    index: u32,
+    table_directory_offset: usize,
+    id: u64,
+}
+
+impl OwnedFont {
+    /// Creates a new font from the given data and index into a font
+    /// collection.
+    ///
+    /// If the data is a single font rather than a collection, the index
+    /// must be 0.
+    pub fn new(
+        data: impl AsRef<[u8]> + Send + Sync + 'static,
+        index: u32,
+    ) -> Result<Self, ReadError> {
+        Self::from_shared(Arc::new(data), index)
+    }
+
+    /// Creates a new font from shared data and an index into a font
+    /// collection.
+    ///
+    /// This allows multiple fonts in a collection to share the same data.
+    pub fn from_shared(data: SharedFontData, index: u32) -> Result<Self, ReadError> {
+        let bytes = (*data).as_ref();
+        let font = FontRef::from_index(bytes, index)?;
+        let table_directory_offset = bytes.len() - font.table_directory.offset_data().len();
+        Ok(Self {
+            data,
+            index,
+            table_directory_offset,
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        })
+    }
+
+    /// Returns the raw data of the font file.
+    pub fn data(&self) -> &[u8] {
+        (*self.data).as_ref()
+    }
+
+    /// Returns the shared data of the font file.
+    pub fn shared_data(&self) -> &SharedFontData {
+        &self.data
+    }
+
+    /// Returns the index of the font in a font collection.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns an identifier for the font that is unique within the
+    /// process.
+    ///
+    /// Each call to [`new`](Self::new) or [`from_shared`](Self::from_shared)
+    /// produces a new identifier, which is shared by clones of the font.
+    /// Unlike the address of the data, this is never reused after the font
+    /// is dropped.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns a reference to the font.
+    ///
+    /// This only reads the table directory header, so it is cheap enough to
+    /// call whenever a [`FontRef`] is needed.
+    ///
+    /// The table directory is validated on construction. If the storage
+    /// later returns different data in which the table directory can no
+    /// longer be read, the returned font contains no tables.
+    pub fn as_font_ref(&self) -> FontRef<'_> {
+        let data = FontData::new(self.data());
+        let table_directory = data
+            .split_off(self.table_directory_offset)
+            .and_then(|data| TableDirectory::read(data).ok())
+            .unwrap_or_else(|| {
+                TableDirectory::read(FontData::new(&EMPTY_TABLE_DIRECTORY))
+                    .expect("empty table directory is valid")
+            });
+        FontRef {
+            data,
+            table_directory,
+        }
+    }
+}
+
+impl<'a> From<&'a OwnedFont> for FontRef<'a> {
+    fn from(font: &'a OwnedFont) -> Self {
+        font.as_font_ref()
+    }
+}
+
+impl<'a> TableProvider<'a> for &'a OwnedFont {
+    fn data_for_tag(&self, tag: Tag) -> Option<FontData<'a>> {
+        self.as_font_ref().table_data(tag)
+    }
+}
+
+impl std::fmt::Debug for OwnedFont {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OwnedFont")
+            .field("len", &self.data().len())
+            .field("index", &self.index)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn send_sync() {
+        fn assert_send_sync<T: Send + Sync + 'static>() {}
+        assert_send_sync::<OwnedFont>();
+    }
+
+    #[test]
+    fn unique_ids() {
+        let first = OwnedFont::new(font_test_data::SIMPLE_GLYF, 0).unwrap();
+        let second = OwnedFont::new(font_test_data::SIMPLE_GLYF, 0).unwrap();
+        assert_ne!(first.id(), second.id());
+        assert_eq!(first.id(), first.clone().id());
+    }
+
+    #[test]
+    fn changed_data() {
+        use std::sync::atomic::AtomicBool;
+        /// Storage that returns the font data only once.
+        struct Once(AtomicBool);
+        impl AsRef<[u8]> for Once {
+            fn as_ref(&self) -> &[u8] {
+                if self.0.swap(true, Ordering::Relaxed) {
+                    &[]
+                } else {
+                    font_test_data::SIMPLE_GLYF
+                }
+            }
+        }
+        let font = OwnedFont::new(Once(AtomicBool::new(false)), 0).unwrap();
+        let font = font.as_font_ref();
+        assert_eq!(font.table_directory.num_tables(), 0);
+        assert!(font.maxp().is_err());
+    }
+
+    #[test]
+    fn matches_font_ref() {
+        let owned = OwnedFont::new(font_test_data::SIMPLE_GLYF.to_vec(), 0).unwrap();
+        let font = FontRef::new(font_test_data::SIMPLE_GLYF).unwrap();
+        let provider = &owned;
+        for record in font.table_directory.table_records() {
+            let tag = record.tag();
+            assert_eq!(
+                provider.data_for_tag(tag).unwrap().as_bytes(),
+                font.table_data(tag).unwrap().as_bytes()
+            );
+        }
+        assert_eq!(
+            provider.maxp().unwrap().num_glyphs(),
+            font.maxp().unwrap().num_glyphs()
+        );
+    }
+
+    #[test]
+    fn shared_collection() {
+        let font = FontRef::new(font_test_data::SIMPLE_GLYF).unwrap();
+        let n_tables = font.table_directory.table_records().len();
+        // a collection of two fonts with the same tables
+        let dir_len = 12 + n_tables * 16;
+        let mut data = Vec::new();
+        data.extend_from_slice(b"ttcf");
+        data.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0, 2]);
+        data.extend_from_slice(&20u32.to_be_bytes());
+        data.extend_from_slice(&(20 + dir_len as u32).to_be_bytes());
+        let table_base = (20 + dir_len * 2) as u32;
+        for _ in 0..2 {
+            data.extend_from_slice(&font_test_data::SIMPLE_GLYF[..dir_len]);
+        }
+        for ix in 0..n_tables * 2 {
+            let pos = 20 + 12 * (ix / n_tables + 1) + ix * 16 + 8;
+            let offset = u32::from_be_bytes(data[pos..pos + 4].try_into().unwrap());
+            let new_offset = offset - dir_len as u32 + table_base;
+            data[pos..pos + 4].copy_from_slice(&new_offset.to_be_bytes());
+        }
+        data.extend_from_slice(&font_test_data::SIMPLE_GLYF[dir_len..]);
+        let data: SharedFontData = Arc::new(data);
+        let first = OwnedFont::from_shared(data.clone(), 0).unwrap();
+        let second = OwnedFont::from_shared(data.clone(), 1).unwrap();
+        assert!(OwnedFont::from_shared(data, 2).is_err());
+        assert_eq!(second.index(), 1);
+        assert_eq!(first.data().as_ptr(), second.data().as_ptr());
+        for font in [&first, &second] {
+            assert!(font.as_font_ref().is_collection_member());
+            assert_eq!(
+                font.glyf().unwrap().offset_data().as_bytes(),
+                FontRef::new(font_test_data::SIMPLE_GLYF)
+                    .unwrap()
+                    .glyf()
+                    .unwrap()
+                    .offset_data()
+                    .as_bytes()
+            );
+        }
+    }
+}
@@ -0,0 +1,939 @@
+//! Eager validation of font data.
+//!
+//! Font data is normally parsed lazily, and errors are only reported for the
+//! fields that are actually accessed. The [`sanitize`] function instead walks
+//! every table in a font up front, in the spirit of the
+//! [OpenType Sanitizer](https://github.com/khaledhosny/ots), so that
+//! malformed fonts can be rejected before they are used.
+//!
+//! Tables are walked generically using [`SomeTable`], which checks that all
+//! offsets resolve and that all arrays are in bounds. In addition, a number of
+//! invariants that span multiple tables are checked, such as glyph ids being
+//! in range of the glyph count in `maxp`.
+//!
+//! Only checksums are verified for tables that do not support traversal. This
+//! includes `CFF ` and `CFF2`, where only the number of charstrings is
+//! checked, and tables that this crate cannot parse, such as `CBDT`, `CBLC`,
+//! `EBDT`, `EBLC`, `EBSC`, `sbix`, `SVG ` and `DSIG`.
+
+use std::fmt;
+
+use types::{GlyphId, Tag};
+
+use crate::{
+    checksum::{self, ChecksumError},
+    tables::{
+        self,
+        aat::{EntryData, ExtendedStateTable},
+        cmap::{Cmap2, CmapSubtable, MapVariant},
+        glyf::Glyph,
+        kerx, morx,
+        postscript::{self, dict, Index},
+    },
+    traversal::{self, FieldType, SomeArray, SomeTable},
+    FontRef, ReadError, TableProvider, TopLevelTable,
+};
+
+/// The maximum nesting depth of subtables and records.
+const MAX_DEPTH: usize = 64;
+
+/// The maximum number of subtables and records visited in a single table.
+///
+/// Offsets may be shared, so the number of nodes reachable by traversal can
+/// grow exponentially with the size of the data.
+const MAX_VISITED: usize = 1 << 20;
+
+/// A problem found while validating a font.
+#[derive(Clone, Debug)]
+pub struct Issue {
+    /// The tag of the table containing the problem.
+    pub table: Tag,
+    /// The path to the problematic field within the table, such as
+    /// `.lookup_list_offset.lookup_offsets[2]`.
+    ///
+    /// This is empty if the problem concerns the table as a whole.
+    pub path: String,
+    /// What is wrong.
+    pub kind: IssueKind,
+}
+
+/// The kinds of problems found while validating a font.
+#[derive(Clone, Debug)]
+pub enum IssueKind {
+    /// Some data could not be read.
+    Read(ReadError),
+    /// CFF or CFF2 data could not be read.
+    Postscript(postscript::Error),
+    /// The records of the table directory are not sorted by tag.
+    UnsortedTableDirectory,
+    /// The checksum in the table directory does not match the table data, or
+    /// the `checksumAdjustment` in the `head` table does not match the font.
+    ChecksumMismatch { expected: u32, actual: u32 },
+    /// A glyph id is greater than or equal to the number of glyphs.
+    GlyphIdOutOfRange { glyph_id: GlyphId, num_glyphs: u16 },
+    /// A lookup index is greater than or equal to the number of lookups.
+    LookupIndexOutOfRange { index: u16, lookup_count: u16 },
+    /// The number of glyphs in a table does not match the number of glyphs
+    /// in `maxp`.
+    GlyphCountMismatch { expected: u16, actual: u32 },
+    /// The number of metrics in `hhea` or `vhea` is greater than the number
+    /// of glyphs.
+    TooManyMetrics { num_metrics: u16, num_glyphs: u16 },
+    /// The offsets in the `loca` table are not in ascending order.
+    UnsortedLoca,
+    /// The length of an array is not a multiple of the size of its elements.
+    UnalignedLength { len: usize, element_len: usize },
+    /// The table is too deeply nested, or has too many subtables to be
+    /// traversed.
+    TraversalLimitExceeded,
+}
+
+/// The result of validating a font.
+#[derive(Clone, Debug, Default)]
+pub struct SanitizeReport {
+    issues: Vec<Issue>,
+}
+
+impl SanitizeReport {
+    /// Returns true if no problems were found.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the problems that were found.
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Returns the problems that were found in the table with the given tag.
+    pub fn issues_for_table(&self, tag: Tag) -> impl Iterator<Item = &Issue> + '_ {
+        self.issues.iter().filter(move |issue| issue.table == tag)
+    }
+
+    fn push_checksum_error(&mut self, error: ChecksumError, path: &str) {
+        match error {
+            ChecksumError::OutOfBounds(tag) => {
+                self.push(tag, path, IssueKind::Read(ReadError::OutOfBounds))
+            }
+            ChecksumError::Mismatch {
+                tag,
+                expected,
+                actual,
+            } => self.push(tag, path, IssueKind::ChecksumMismatch { expected, actual }),
+        }
+    }
+
+    fn push(&mut self, table: Tag, path: impl Into<String>, kind: IssueKind) {
+        self.issues.push(Issue {
+            table,
+            path: path.into(),
+            kind,
+        });
+    }
+}
+
+/// Validates all tables in the font.
+///
+/// This checks the table directory and [checksums](crate::checksum), walks
+/// every table that supports [traversal] to check that all offsets and
+/// arrays are in bounds, and checks the following invariants:
+///
+/// * the number of glyphs in `loca`, `CFF ` and `CFF2` matches `maxp`, and
+///   all glyphs in `glyf` can be read
+/// * the number of metrics in `hhea` and `vhea` does not exceed the number
+///   of glyphs, and `hmtx` and `vmtx` contain metrics for every glyph
+/// * glyph ids in `cmap`, composite glyphs and traversed tables are less
+///   than the number of glyphs
+/// * lookup indices in `GSUB` and `GPOS` refer to existing lookups
+/// * the `cvt ` table contains a whole number of values
+/// * all states reachable in the state tables of `morx` and `kerx` have
+///   readable entries
+pub fn sanitize(font: &FontRef) -> SanitizeReport {
+    let mut report = SanitizeReport::default();
+    check_table_directory(font, &mut report);
+    let num_glyphs = match font.maxp() {
+        Ok(maxp) => Some(maxp.num_glyphs()),
+        Err(ReadError::TableIsMissing(_)) => None,
+        Err(e) => {
+            report.push(tables::maxp::Maxp::TAG, "", IssueKind::Read(e));
+            None
+        }
+    };
+    for record in font.table_directory.table_records() {
+        let tag = record.tag();
+        let table = match traversal::some_table(font, tag) {
+            Ok(table) => table,
+            // not all tables support traversal
+            Err(ReadError::TableIsMissing(_)) => continue,
+            // reported as a glyph count mismatch by check_metrics
+            Err(ReadError::OutOfBounds)
+                if tag == tables::hmtx::Hmtx::TAG || tag == tables::vmtx::Vmtx::TAG =>
+            {
+                continue
+            }
+            Err(e) => {
+                report.push(tag, "", IssueKind::Read(e));
+                continue;
+            }
+        };
+        let lookup_count = match tag {
+            tables::gsub::Gsub::TAG => font
+                .gsub()
+                .and_then(|gsub| gsub.lookup_list())
+                .map(|list| list.lookup_count())
+                .ok(),
+            tables::gpos::Gpos::TAG => font
+                .gpos()
+                .and_then(|gpos| gpos.lookup_list())
+                .map(|list| list.lookup_count())
+                .ok(),
+            _ => None,
+        };
+        let mut walker = Walker {
+            report: &mut report,
+            table: tag,
+            path: String::new(),
+            num_glyphs,
+            lookup_count,
+            visited: 0,
+        };
+        walker.walk_table(table.as_ref(), 0);
+    }
+    if let Some(num_glyphs) = num_glyphs {
+        check_glyf(font, num_glyphs, &mut report);
+        check_metrics(font, num_glyphs, &mut report);
+        check_cmap(font, num_glyphs, &mut report);
+        check_cff(font, num_glyphs, &mut report);
+    }
+    check_cvt(font, &mut report);
+    check_state_tables(font, &mut report);
+    report
+}
+
+fn check_table_directory(font: &FontRef, report: &mut SanitizeReport) {
+    let records = font.table_directory.table_records();
+    if let Some(pair) = records
+        .windows(2)
+        .find(|pair| pair[0].tag() >= pair[1].tag())
+    {
+        report.push(pair[1].tag(), "", IssueKind::UnsortedTableDirectory);
+    }
+    for error in checksum::verify_table_records(font) {
+        report.push_checksum_error(error, "");
+    }
+    // out of bounds tables have already been reported
+    if let Err(error @ ChecksumError::Mismatch { .. }) = checksum::verify_checksum_adjustment(font)
+    {
+        report.push_checksum_error(error, ".checksum_adjustment");
+    }
+}
+
+fn check_glyf(font: &FontRef, num_glyphs: u16, report: &mut SanitizeReport) {
+    let (Ok(glyf), Ok(loca)) = (font.glyf(), font.loca(None)) else {
+        return;
+    };
+    let loca_tag = tables::loca::Loca::TAG;
+    if loca.len() != num_glyphs as usize {
+        report.push(
+            loca_tag,
+            "",
+            IssueKind::GlyphCountMismatch {
+                expected: num_glyphs,
+                actual: loca.len() as u32,
+            },
+        );
+        return;
+    }
+    let offsets = (0..=loca.len()).filter_map(|ix| loca.get_raw(ix));
+    if offsets.clone().zip(offsets.skip(1)).any(|(a, b)| a > b) {
+        report.push(loca_tag, "", IssueKind::UnsortedLoca);
+        return;
+    }
+    let glyf_tag = tables::glyf::Glyf::TAG;
+    for gid in 0..num_glyphs {
+        let path = || format!("[{gid}]");
+        match loca.get_glyf(GlyphId::new(gid), &glyf) {
+            Ok(Some(Glyph::Composite(composite))) => {
+                for (i, component) in composite.components().enumerate() {
+                    if component.glyph.to_u16() >= num_glyphs {
+                        report.push(
+                            glyf_tag,
+                            format!("{}.components[{i}]", path()),
+                            IssueKind::GlyphIdOutOfRange {
+                                glyph_id: component.glyph,
+                                num_glyphs,
+                            },
+                        );
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(e) => report.push(glyf_tag, path(), IssueKind::Read(e)),
+        }
+    }
+}
+
+fn check_metrics(font: &FontRef, num_glyphs: u16, report: &mut SanitizeReport) {
+    let counts = [
+        (
+            tables::hhea::Hhea::TAG,
+            tables::hmtx::Hmtx::TAG,
+            font.hhea().map(|hhea| hhea.number_of_long_metrics()),
+        ),
+        (
+            tables::vhea::Vhea::TAG,
+            tables::vmtx::Vmtx::TAG,
+            font.vhea().map(|vhea| vhea.number_of_long_ver_metrics()),
+        ),
+    ];
+    for (header_tag, metrics_tag, num_metrics) in counts {
+        let Ok(num_metrics) = num_metrics else {
+            continue;
+        };
+        if num_metrics > num_glyphs {
+            report.push(
+                header_tag,
+                "",
+                IssueKind::TooManyMetrics {
+                    num_metrics,
+                    num_glyphs,
+                },
+            );
+            continue;
+        }
+        let Some(data) = font.table_data(metrics_tag) else {
+            continue;
+        };
+        // each glyph has either a full metric or just a side bearing
+        let long_metrics_len = num_metrics as usize * 4;
+        let covered = if data.len() < long_metrics_len {
+            data.len() / 4
+        } else {
+            num_metrics as usize + (data.len() - long_metrics_len) / 2
+        };
+        if covered < num_glyphs as usize {
+            report.push(
+                metrics_tag,
+                "",
+                IssueKind::GlyphCountMismatch {
+                    expected: num_glyphs,
+                    actual: covered as u32,
+                },
+            );
+        }
+    }
+}
+
+fn check_cmap(font: &FontRef, num_glyphs: u16, report: &mut SanitizeReport) {
+    let Ok(cmap) = font.cmap() else {
+        return;
+    };
+    let cmap_tag = tables::cmap::Cmap::TAG;
+    for (i, record) in cmap.encoding_records().iter().enumerate() {
+        let path = format!(".encoding_records[{i}].subtable_offset");
+        let Ok(subtable) = record.subtable(cmap.offset_data()) else {
+            // reported by traversal
+            continue;
+        };
+        let mut glyph_ids: Box<dyn Iterator<Item = u32>> = match subtable {
+            CmapSubtable::Format0(subtable) => {
+                Box::new(subtable.glyph_id_array().iter().map(|gid| *gid as u32))
+            }
+            CmapSubtable::Format2(subtable) => Box::new(cmap2_glyph_ids(&subtable)),
+            CmapSubtable::Format4(subtable) => {
+                Box::new(subtable.iter().map(|(_, gid)| gid.to_u16() as u32))
+            }
+            CmapSubtable::Format6(subtable) => {
+                Box::new(subtable.glyph_id_array().iter().map(|gid| gid.get() as u32))
+            }
+            CmapSubtable::Format10(subtable) => {
+                Box::new(subtable.glyph_id_array().iter().map(|gid| gid.get() as u32))
+            }
+            CmapSubtable::Format12(subtable) => Box::new(subtable.groups().iter().map(|group| {
+                let len = group
+                    .end_char_code()
+                    .saturating_sub(group.start_char_code());
+                group.start_glyph_id().saturating_add(len)
+            })),
+            CmapSubtable::Format13(subtable) => {
+                Box::new(subtable.groups().iter().map(|group| group.glyph_id()))
+            }
+            CmapSubtable::Format14(subtable) => {
+                Box::new(subtable.iter().filter_map(|(_, _, variant)| match variant {
+                    MapVariant::Variant(gid) => Some(gid.to_u16() as u32),
+                    MapVariant::UseDefault => None,
+                }))
+            }
+            _ => Box::new(std::iter::empty()),
+        };
+        // report only the first glyph id out of range in each subtable
+        if let Some(gid) = glyph_ids.find(|gid| *gid >= num_glyphs as u32) {
+            report.push(
+                cmap_tag,
+                path,
+                IssueKind::GlyphIdOutOfRange {
+                    glyph_id: GlyphId::new(gid.min(u16::MAX as u32) as u16),
+                    num_glyphs,
+                },
+            );
+        }
+    }
+}
+
+/// Returns the glyph ids mapped by a format 2 subtable.
+fn cmap2_glyph_ids<'a>(subtable: &Cmap2<'a>) -> impl Iterator<Item = u32> + 'a {
+    let data = subtable.offset_data();
+    let sub_headers_start = 6 + 256 * 2;
+    let mut keys = subtable
+        .sub_header_keys()
+        .iter()
+        .map(|key| key.get() as usize)
+        .collect::<Vec<_>>();
+    keys.sort_unstable();
+    keys.dedup();
+    keys.into_iter().flat_map(move |key| {
+        let sub_header = sub_headers_start + key;
+        let entry_count = data.read_at::<u16>(sub_header + 2).unwrap_or_default();
+        let id_delta = data.read_at::<i16>(sub_header + 4).unwrap_or_default();
+        // the range offset is relative to its own position
+        let id_range_offset = data.read_at::<u16>(sub_header + 6).unwrap_or_default();
+        let glyph_ids = sub_header + 6 + id_range_offset as usize;
+        (0..entry_count as usize).filter_map(move |i| {
+            let gid = data.read_at::<u16>(glyph_ids + i * 2).ok()?;
+            (gid != 0).then(|| gid.wrapping_add_signed(id_delta) as u32)
+        })
+    })
+}
+
+fn check_cvt(font: &FontRef, report: &mut SanitizeReport) {
+    let tag = tables::cvt::Cvt::TAG;
+    if let Some(data) = font.table_data(tag) {
+        if data.len() % 2 != 0 {
+            report.push(
+                tag,
+                "",
+                IssueKind::UnalignedLength {
+                    len: data.len(),
+                    element_len: 2,
+                },
+            );
+        }
+    }
+}
+
+fn check_state_tables(font: &FontRef, report: &mut SanitizeReport) {
+    if let Ok(morx) = font.morx() {
+        let tag = tables::morx::Morx::TAG;
+        for (i, chain) in morx.chains().enumerate() {
+            // errors are reported by traversal
+            let Ok(chain) = chain else {
+                continue;
+            };
+            for (j, subtable) in chain.subtables().enumerate() {
+                let Ok(kind) = subtable.and_then(|subtable| subtable.kind()) else {
+                    continue;
+                };
+                let result = match kind {
+                    morx::SubtableKind::Rearrangement(header) => {
+                        header.state_table::<()>().and_then(check_states)
+                    }
+                    morx::SubtableKind::Contextual(subtable) => {
+                        subtable.state_table().and_then(check_states)
+                    }
+                    morx::SubtableKind::Ligature(subtable) => {
+                        subtable.state_table().and_then(check_states)
+                    }
+                    morx::SubtableKind::Insertion(subtable) => {
+                        subtable.state_table().and_then(check_states)
+                    }
+                    morx::SubtableKind::NonContextual(_) => Ok(()),
+                };
+                if let Err(e) = result {
+                    report.push(
+                        tag,
+                        format!(".chains[{i}].subtables[{j}]"),
+                        IssueKind::Read(e),
+                    );
+                }
+            }
+        }
+    }
+    if let Ok(kerx) = font.kerx() {
+        let tag = tables::kerx::Kerx::TAG;
+        for (i, subtable) in kerx.subtables().enumerate() {
+            let Ok(kind) = subtable.and_then(|subtable| subtable.kind()) else {
+                continue;
+            };
+            let result = match kind {
+                kerx::SubtableKind::Format1(subtable) => {
+                    subtable.state_table().and_then(check_states)
+                }
+                kerx::SubtableKind::Format4(subtable) => {
+                    subtable.state_table().and_then(check_states)
+                }
+                _ => Ok(()),
+            };
+            if let Err(e) = result {
+                report.push(tag, format!(".subtables[{i}]"), IssueKind::Read(e));
+            }
+        }
+    }
+}
+
+/// Checks that the entries of all states reachable from the start of text
+/// state can be read.
+///
+/// The number of states is not stored in extended state tables, so the state
+/// array runs to the end of the data and only reachable states are checked.
+fn check_states<T: EntryData>(table: ExtendedStateTable<T>) -> Result<(), ReadError> {
+    let n_classes = table.n_classes().min(u16::MAX as usize + 1);
+    let mut visited = vec![false; u16::MAX as usize + 1];
+    let mut pending = vec![0u16];
+    visited[0] = true;
+    while let Some(state) = pending.pop() {
+        for class in 0..n_classes {
+            let new_state = table.entry(state, class as u16)?.new_state;
+            if !std::mem::replace(&mut visited[new_state as usize], true) {
+                pending.push(new_state);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_cff(font: &FontRef, num_glyphs: u16, report: &mut SanitizeReport) {
+    let mut check = |tag: Tag, top_dict: Result<&[u8], ReadError>, table_data: &[u8], is_cff2| {
+        let count = top_dict
+            .map_err(postscript::Error::from)
+            .and_then(|top_dict| {
+                for entry in dict::entries(top_dict, None) {
+                    if let dict::Entry::CharstringsOffset(offset) = entry? {
+                        let data = table_data.get(offset..).unwrap_or_default();
+                        return Ok(Index::new(data, is_cff2)?.count());
+                    }
+                }
+                Err(postscript::Error::MissingCharstrings)
+            });
+        match count {
+            Ok(count) if count != num_glyphs as u32 => report.push(
+                tag,
+                "",
+                IssueKind::GlyphCountMismatch {
+                    expected: num_glyphs,
+                    actual: count,
+                },
+            ),
+            Ok(_) => {}
+            Err(e) => report.push(tag, "", IssueKind::Postscript(e)),
+        }
+    };
+    if let Ok(cff) = font.cff() {
+        let top_dict = cff.top_dicts().get(0).map_err(|e| match e {
+            postscript::Error::Read(e) => e,
+            _ => ReadError::MalformedData("invalid CFF top DICT INDEX"),
+        });
+        check(
+            tables::cff::Cff::TAG,
+            top_dict,
+            cff.offset_data().as_bytes(),
+            false,
+        );
+    }
+    if let Ok(cff2) = font.cff2() {
+        check(
+            tables::cff2::Cff2::TAG,
+            Ok(cff2.top_dict_data()),
+            cff2.offset_data().as_bytes(),
+            true,
+        );
+    }
+}
+
+/// Walks a table using traversal, recording issues.
+struct Walker<'r> {
+    report: &'r mut SanitizeReport,
+    table: Tag,
+    path: String,
+    num_glyphs: Option<u16>,
+    lookup_count: Option<u16>,
+    visited: usize,
+}
+
+impl<'a> Walker<'_> {
+    fn push(&mut self, kind: IssueKind) {
+        self.report.push(self.table, self.path.clone(), kind);
+    }
+
+    fn walk_table(&mut self, table: &(dyn SomeTable<'a> + 'a), depth: usize) {
+        self.visited += 1;
+        if depth > MAX_DEPTH || self.visited > MAX_VISITED {
+            // report only once per table
+            if self.visited <= MAX_VISITED + 1 {
+                self.push(IssueKind::TraversalLimitExceeded);
+            }
+            self.visited = MAX_VISITED + 2;
+            return;
+        }
+        for field in table.iter() {
+            let len = self.path.len();
+            self.path.push('.');
+            self.path.push_str(field.name);
+            self.walk_value(field.name, field.value, depth);
+            self.path.truncate(len);
+        }
+    }
+
+    fn walk_array(&mut self, name: &str, array: &(dyn SomeArray<'a> + 'a), depth: usize) {
+        for idx in 0..array.len() {
+            let len = self.path.len();
+            self.path.push_str(&format!("[{idx}]"));
+            let item = array.get(idx);
+            let is_scalar = matches!(
+                item,
+                Some(
+                    FieldType::I8(_)
+                        | FieldType::U8(_)
+                        | FieldType::I16(_)
+                        | FieldType::U16(_)
+                        | FieldType::I32(_)
+                        | FieldType::U32(_)
+                        | FieldType::U24(_)
+                        | FieldType::Tag(_)
+                        | FieldType::FWord(_)
+                        | FieldType::UfWord(_)
+                        | FieldType::F2Dot14(_)
+                        | FieldType::Fixed(_)
+                        | FieldType::BareOffset(_)
+                )
+            );
+            match item {
+                Some(item) => self.walk_value(name, item, depth),
+                None => self.push(IssueKind::Read(ReadError::OutOfBounds)),
+            }
+            self.path.truncate(len);
+            // arrays of plain values only need checking if they are indices
+            if is_scalar && !is_lookup_index(name) {
+                break;
+            }
+        }
+    }
+
+    fn walk_value(&mut self, name: &str, value: FieldType<'a>, depth: usize) {
+        match value {
+            FieldType::GlyphId(glyph_id) => match self.num_glyphs {
+                Some(num_glyphs) if glyph_id.to_u16() >= num_glyphs => {
+                    self.push(IssueKind::GlyphIdOutOfRange {
+                        glyph_id,
+                        num_glyphs,
+                    })
+                }
+                _ => {}
+            },
+            FieldType::U16(index) if is_lookup_index(name) => match self.lookup_count {
+                Some(lookup_count) if index >= lookup_count => {
+                    self.push(IssueKind::LookupIndexOutOfRange {
+                        index,
+                        lookup_count,
+                    })
+                }
+                _ => {}
+            },
+            FieldType::ResolvedOffset(offset) => match offset.target {
+                Ok(table) => self.walk_table(table.as_ref(), depth + 1),
+                Err(e) => self.push(IssueKind::Read(e)),
+            },
+            FieldType::StringOffset(offset) => {
+                if let Err(e) = offset.target {
+                    self.push(IssueKind::Read(e));
+                }
+            }
+            FieldType::ArrayOffset(offset) => match offset.target {
+                Ok(array) => self.walk_array(name, array.as_ref(), depth + 1),
+                Err(e) => self.push(IssueKind::Read(e)),
+            },
+            FieldType::Record(record) => self.walk_table(&record, depth + 1),
+            FieldType::Array(array) => self.walk_array(name, array.as_ref(), depth),
+            _ => {}
+        }
+    }
+}
+
+fn is_lookup_index(name: &str) -> bool {
+    matches!(name, "lookup_list_index" | "lookup_list_indices")
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(e) => e.fmt(f),
+            Self::Postscript(e) => e.fmt(f),
+            Self::UnsortedTableDirectory => write!(f, "table directory is not sorted by tag"),
+            Self::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected 0x{expected:08X}, found 0x{actual:08X}"
+            ),
+            Self::GlyphIdOutOfRange {
+                glyph_id,
+                num_glyphs,
+            } => write!(
+                f,
+                "glyph id {} out of range for {num_glyphs} glyphs",
+                glyph_id.to_u16()
+            ),
+            Self::LookupIndexOutOfRange {
+                index,
+                lookup_count,
+            } => write!(
+                f,
+                "lookup index {index} out of range for {lookup_count} lookups"
+            ),
+            Self::GlyphCountMismatch { expected, actual } => {
+                write!(f, "expected {expected} glyphs, found {actual}")
+            }
+            Self::TooManyMetrics {
+                num_metrics,
+                num_glyphs,
+            } => write!(f, "{num_metrics} metrics for {num_glyphs} glyphs"),
+            Self::UnsortedLoca => write!(f, "loca offsets are not in ascending order"),
+            Self::UnalignedLength { len, element_len } => write!(
+                f,
+                "length of {len} bytes is not a multiple of {element_len}"
+            ),
+            Self::TraversalLimitExceeded => write!(f, "traversal limit exceeded"),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}: {}", self.table, self.path, self.kind)
+    }
+}
+
+impl fmt::Display for SanitizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tables::layout::Feature, test_helpers::BeBuffer, FontReadWithArgs};
+
+    /// Returns the offset of the table directory record for `tag`.
+    fn record_offset(data: &[u8], tag: Tag) -> usize {
+        let font = FontRef::new(data).unwrap();
+        let ix = font
+            .table_directory
+            .table_records()
+            .iter()
+            .position(|record| record.tag() == tag)
+            .unwrap();
+        12 + ix * 16
+    }
+
+    fn table_offset(data: &[u8], tag: Tag) -> usize {
+        let record = record_offset(data, tag);
+        u32::from_be_bytes(data[record + 8..record + 12].try_into().unwrap()) as usize
+    }
+
+    /// Returns a copy of the font with the given table added or replaced.
+    fn with_table(data: &[u8], tag: Tag, table: &[u8]) -> Vec<u8> {
+        let font = FontRef::new(data).unwrap();
+        let mut tables = font
+            .table_directory
+            .table_records()
+            .iter()
+            .filter(|record| record.tag() != tag)
+            .map(|record| {
+                let data = font.table_data(record.tag()).unwrap();
+                (record.tag(), data.as_bytes().to_vec())
+            })
+            .chain(std::iter::once((tag, table.to_vec())))
+            .collect::<Vec<_>>();
+        tables.sort_by_key(|(tag, _)| *tag);
+        crate::cff_font::build_sfnt(tables).unwrap()
+    }
+
+    #[test]
+    fn valid_fonts() {
+        for data in [
+            font_test_data::SIMPLE_GLYF,
+            font_test_data::VAZIRMATN_VAR,
+            font_test_data::NOTO_SERIF_DISPLAY_TRIMMED,
+            font_test_data::CMAP12_FONT1,
+            font_test_data::CMAP14_FONT1,
+            font_test_data::COLR_GRADIENT_RECT,
+            font_test_data::CHARSTRING_PATH_OPS,
+        ] {
+            let font = FontRef::new(data).unwrap();
+            let report = sanitize(&font);
+            assert!(report.is_ok(), "{report}");
+        }
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let mut data = font_test_data::SIMPLE_GLYF.to_vec();
+        let record = record_offset(&data, Tag::new(b"glyf"));
+        data[record + 4] ^= 0xFF;
+        let font = FontRef::new(&data).unwrap();
+        let report = sanitize(&font);
+        // the table directory is included in the whole-font checksum
+        let issues = report.issues();
+        assert_eq!(issues.len(), 2, "{report}");
+        assert_eq!(issues[0].table, Tag::new(b"glyf"));
+        assert!(matches!(issues[0].kind, IssueKind::ChecksumMismatch { .. }));
+        assert_eq!(issues[1].table, Tag::new(b"head"));
+        assert_eq!(issues[1].path, ".checksum_adjustment");
+    }
+
+    #[test]
+    fn glyph_count_mismatch() {
+        let mut data = font_test_data::VAZIRMATN_VAR.to_vec();
+        let maxp = table_offset(&data, Tag::new(b"maxp"));
+        let num_glyphs = u16::from_be_bytes([data[maxp + 4], data[maxp + 5]]);
+        let fewer_glyphs = (num_glyphs - 1).to_be_bytes();
+        data[maxp + 4..maxp + 6].copy_from_slice(&fewer_glyphs);
+        let font = FontRef::new(&data).unwrap();
+        let report = sanitize(&font);
+        let maxp_issues = report.issues_for_table(Tag::new(b"maxp")).count();
+        assert_eq!(maxp_issues, 1, "{report}");
+        assert!(report
+            .issues_for_table(Tag::new(b"loca"))
+            .any(|issue| matches!(
+                issue.kind,
+                IssueKind::GlyphCountMismatch { actual, .. } if actual == num_glyphs as u32
+            )));
+        assert!(report
+            .issues_for_table(Tag::new(b"cmap"))
+            .any(|issue| matches!(
+                issue.kind,
+                IssueKind::GlyphIdOutOfRange { glyph_id, .. } if glyph_id.to_u16() == num_glyphs - 1
+            )));
+    }
+
+    #[test]
+    fn truncated_metrics() {
+        let mut data = font_test_data::VAZIRMATN_VAR.to_vec();
+        let maxp = table_offset(&data, Tag::new(b"maxp"));
+        let num_glyphs = u16::from_be_bytes([data[maxp + 4], data[maxp + 5]]);
+        let more_glyphs = (num_glyphs + 1).to_be_bytes();
+        data[maxp + 4..maxp + 6].copy_from_slice(&more_glyphs);
+        let font = FontRef::new(&data).unwrap();
+        let report = sanitize(&font);
+        let hmtx_issues = report
+            .issues_for_table(Tag::new(b"hmtx"))
+            .collect::<Vec<_>>();
+        assert_eq!(hmtx_issues.len(), 1, "{report}");
+        assert!(matches!(
+            hmtx_issues[0].kind,
+            IssueKind::GlyphCountMismatch { expected, actual }
+                if expected == num_glyphs + 1 && actual == num_glyphs as u32
+        ));
+    }
+
+    #[test]
+    fn lookup_index_out_of_range() {
+        let data = BeBuffer::new().extend([0u16, 3, 0, 2, 5]);
+        let feature = Feature::read_with_args(data.font_data(), &Tag::new(b"liga")).unwrap();
+        let mut report = SanitizeReport::default();
+        let mut walker = Walker {
+            report: &mut report,
+            table: Tag::new(b"GSUB"),
+            path: String::new(),
+            num_glyphs: None,
+            lookup_count: Some(3),
+            visited: 0,
+        };
+        walker.walk_table(&feature, 0);
+        assert_eq!(report.issues().len(), 1);
+        assert_eq!(
+            report.issues()[0].to_string(),
+            "GSUB.lookup_list_indices[2]: lookup index 5 out of range for 3 lookups"
+        );
+    }
+
+    /// Returns the issues found in a copy of `SIMPLE_GLYF` with the given
+    /// table added or replaced.
+    fn issues_with_table(tag: &[u8; 4], table: &[u8]) -> Vec<Issue> {
+        let data = with_table(font_test_data::SIMPLE_GLYF, Tag::new(tag), table);
+        let font = FontRef::new(&data).unwrap();
+        sanitize(&font).issues().to_vec()
+    }
+
+    #[test]
+    fn zero_size_hdmx_records() {
+        // version, numRecords, sizeDeviceRecord
+        let hdmx = BeBuffer::new().extend([0u16, 1]).push(0u32);
+        let issues = issues_with_table(b"hdmx", &hdmx);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert_eq!(issues[0].table, Tag::new(b"hdmx"));
+        assert!(matches!(issues[0].kind, IssueKind::Read(_)));
+    }
+
+    #[test]
+    fn odd_length_cvt() {
+        let issues = issues_with_table(b"cvt ", &[0, 10, 0, 20, 0]);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert!(matches!(
+            issues[0].kind,
+            IssueKind::UnalignedLength {
+                len: 5,
+                element_len: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn odd_length_state_array() {
+        // nClasses, classTable, stateArray, entryTable offsets
+        let stx = BeBuffer::new()
+            .extend([1u32, 16, 22, 22])
+            // format 0 class lookup with no glyphs
+            .extend([8u16, 0, 0])
+            // a single state that refers to a truncated entry
+            .extend([0u8, 0, 0]);
+        let morx = BeBuffer::new()
+            // version, unused, nChains
+            .extend([2u16, 0])
+            .push(1u32)
+            // defaultFlags, chainLength, nFeatureEntries, nSubtables
+            .extend([1u32, 16 + 12 + stx.len() as u32, 0, 1])
+            // length, coverage, subFeatureFlags of a rearrangement subtable
+            .extend([12 + stx.len() as u32, 0, 1])
+            .extend(stx.iter().copied());
+        let issues = issues_with_table(b"morx", &morx);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert_eq!(issues[0].path, ".chains[0].subtables[0]");
+        assert!(matches!(
+            issues[0].kind,
+            IssueKind::Read(ReadError::OutOfBounds)
+        ));
+    }
+
+    #[test]
+    fn cmap2_glyph_id_out_of_range() {
+        let cmap = BeBuffer::new()
+            // version, numTables, platform, encoding, offset
+            .extend([0u16, 1, 1, 0])
+            .push(12u32)
+            // format, length, language
+            .extend([2u16, 6 + 512 + 8 + 2, 0])
+            // all high bytes use the first subheader
+            .extend([0u16; 256])
+            // firstCode, entryCount, idDelta, idRangeOffset
+            .extend([0u16, 1, 0, 2])
+            .push(500u16);
+        let issues = issues_with_table(b"cmap", &cmap);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert!(matches!(
+            issues[0].kind,
+            IssueKind::GlyphIdOutOfRange { glyph_id, .. } if glyph_id.to_u16() == 500
+        ));
+    }
+}
@@ -76,6 +76,10 @@ pub trait TableProvider<'a> {
         self.expect_table()
     }
 
+    fn math(&self) -> Result<tables::math::Math<'a>, ReadError> {
+        self.expect_table()
+    }
+
     fn maxp(&self) -> Result<tables::maxp::Maxp<'a>, ReadError> {
         self.expect_table()
     }
@@ -106,6 +110,84 @@ pub trait TableProvider<'a> {
         self.expect_table()
     }
 
+    fn cvt(&self) -> Result<tables::cvt::Cvt<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn cvar(&self) -> Result<tables::cvar::Cvar<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn fpgm(&self) -> Result<tables::fpgm::Fpgm<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn prep(&self) -> Result<tables::prep::Prep<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn gasp(&self) -> Result<tables::gasp::Gasp<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn hdmx(&self) -> Result<tables::hdmx::Hdmx<'a>, ReadError> {
+        let num_glyphs = self.maxp().map(|maxp| maxp.num_glyphs())?;
+        let data = self.expect_data_for_tag(tables::hdmx::Hdmx::TAG)?;
+        tables::hdmx::Hdmx::read(data, num_glyphs)
+    }
+
+    fn vdmx(&self) -> Result<tables::vdmx::Vdmx<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn ltsh(&self) -> Result<tables::ltsh::Ltsh<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn meta(&self) -> Result<tables::meta::Meta<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn ltag(&self) -> Result<tables::ltag::Ltag<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn vorg(&self) -> Result<tables::vorg::Vorg<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn jstf(&self) -> Result<tables::jstf::Jstf<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn pclt(&self) -> Result<tables::pclt::Pclt<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn kern(&self) -> Result<tables::kern::Kern<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn morx(&self) -> Result<tables::morx::Morx<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn kerx(&self) -> Result<tables::kerx::Kerx<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn ankr(&self) -> Result<tables::ankr::Ankr<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn trak(&self) -> Result<tables::trak::Trak<'a>, ReadError> {
+        self.expect_table()
+    }
+
+    fn feat(&self) -> Result<tables::feat::Feat<'a>, ReadError> {
+        self.expect_table()
+    }
+
     fn cff(&self) -> Result<tables::cff::Cff<'a>, ReadError> {
         self.expect_table()
     }
@@ -130,6 +212,10 @@ pub trait TableProvider<'a> {
         self.expect_table()
     }
 
+    fn base(&self) -> Result<tables::base::Base<'a>, ReadError> {
+        self.expect_table()
+    }
+
     fn colr(&self) -> Result<tables::colr::Colr<'a>, ReadError> {
         self.expect_table()
     }
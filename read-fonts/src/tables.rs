@@ -1,5 +1,7 @@
 //! The various font tables
 
+pub mod aat;
+pub mod ankr;
 pub mod avar;
 pub mod base;
 pub mod cff;
@@ -7,26 +9,45 @@ pub mod cff2;
 pub mod cmap;
 pub mod colr;
 pub mod cpal;
+pub mod cvar;
+pub mod cvt;
+pub mod feat;
+pub mod fpgm;
 pub mod fvar;
+pub mod gasp;
 pub mod gdef;
 pub mod glyf;
 pub mod gpos;
 pub mod gsub;
 pub mod gvar;
+pub mod hdmx;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
 pub mod hvar;
+pub mod jstf;
+pub mod kern;
+pub mod kerx;
 pub mod layout;
 pub mod loca;
+pub mod ltag;
+pub mod ltsh;
+pub mod math;
 pub mod maxp;
+pub mod meta;
+pub mod morx;
 pub mod mvar;
 pub mod name;
 pub mod os2;
+pub mod pclt;
 pub mod post;
 pub mod postscript;
+pub mod prep;
 pub mod stat;
+pub mod trak;
 pub mod variations;
+pub mod vdmx;
 pub mod vhea;
 pub mod vmtx;
+pub mod vorg;
 pub mod vvar;
@@ -0,0 +1,486 @@
+//! Shared [Apple Advanced Typography](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6Tables.html)
+//! structures: lookup tables and extended state tables.
+
+include!("../../generated/generated_aat.rs");
+
+use std::marker::PhantomData;
+
+/// Predefined classes for glyphs in state tables.
+pub mod class {
+    /// Class for the end of the glyph run.
+    pub const END_OF_TEXT: u16 = 0;
+    /// Class for glyphs that are not covered by the class table.
+    pub const OUT_OF_BOUNDS: u16 = 1;
+    /// Class for glyphs that have been deleted.
+    pub const DELETED_GLYPH: u16 = 2;
+    /// Class for the end of a line.
+    pub const END_OF_LINE: u16 = 3;
+}
+
+/// The glyph id used to mark deleted glyphs.
+pub const DELETED_GLYPH: GlyphId = GlyphId::new(0xFFFF);
+
+/// A value that can be stored in a lookup table.
+pub trait LookupValue: Scalar {
+    /// Converts a value of unspecified size, used by format 10 lookup
+    /// tables, to this type.
+    fn from_u64(value: u64) -> Option<Self>;
+}
+
+impl LookupValue for u16 {
+    fn from_u64(value: u64) -> Option<Self> {
+        value.try_into().ok()
+    }
+}
+
+impl LookupValue for u32 {
+    fn from_u64(value: u64) -> Option<Self> {
+        value.try_into().ok()
+    }
+}
+
+impl LookupValue for GlyphId {
+    fn from_u64(value: u64) -> Option<Self> {
+        u16::from_u64(value).map(GlyphId::new)
+    }
+}
+
+impl Lookup<'_> {
+    /// Returns the value associated with the given glyph.
+    ///
+    /// The value type must match the size of the values stored in the
+    /// lookup table, which is determined by the table that contains it.
+    pub fn value<T: LookupValue>(&self, glyph_id: GlyphId) -> Option<T> {
+        match self {
+            Self::Format0(lookup) => lookup.value(glyph_id),
+            Self::Format2(lookup) => lookup.value(glyph_id),
+            Self::Format4(lookup) => lookup.value(glyph_id),
+            Self::Format6(lookup) => lookup.value(glyph_id),
+            Self::Format8(lookup) => lookup.value(glyph_id),
+            Self::Format10(lookup) => lookup.value(glyph_id),
+        }
+    }
+}
+
+impl Lookup0<'_> {
+    /// Returns the value associated with the given glyph.
+    pub fn value<T: LookupValue>(&self, glyph_id: GlyphId) -> Option<T> {
+        FontData::new(self.values_data())
+            .read_at(glyph_id.to_u16() as usize * T::RAW_BYTE_LEN)
+            .ok()
+    }
+}
+
+impl Lookup2<'_> {
+    /// Returns the value associated with the given glyph.
+    pub fn value<T: LookupValue>(&self, glyph_id: GlyphId) -> Option<T> {
+        let glyph_id = glyph_id.to_u16();
+        let unit_size = self.unit_size() as usize;
+        if unit_size < 4 + T::RAW_BYTE_LEN {
+            return None;
+        }
+        let unit = search_units(self.segments_data(), unit_size, |unit| {
+            let last = unit.read_at::<u16>(0).ok()?;
+            let first = unit.read_at::<u16>(2).ok()?;
+            Some(if glyph_id < first {
+                core::cmp::Ordering::Greater
+            } else if glyph_id > last {
+                core::cmp::Ordering::Less
+            } else {
+                core::cmp::Ordering::Equal
+            })
+        })?;
+        unit.read_at(4).ok()
+    }
+}
+
+impl Lookup4<'_> {
+    /// Returns the value associated with the given glyph.
+    pub fn value<T: LookupValue>(&self, glyph_id: GlyphId) -> Option<T> {
+        let glyph_id = glyph_id.to_u16();
+        let segments = self.segments();
+        let ix = segments
+            .binary_search_by(|segment| {
+                if glyph_id < segment.first_glyph() {
+                    core::cmp::Ordering::Greater
+                } else if glyph_id > segment.last_glyph() {
+                    core::cmp::Ordering::Less
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            })
+            .ok()?;
+        let segment = &segments[ix];
+        let offset = segment.value_offset() as usize
+            + (glyph_id - segment.first_glyph()) as usize * T::RAW_BYTE_LEN;
+        self.offset_data().read_at(offset).ok()
+    }
+}
+
+impl Lookup6<'_> {
+    /// Returns the value associated with the given glyph.
+    pub fn value<T: LookupValue>(&self, glyph_id: GlyphId) -> Option<T> {
+        let glyph_id = glyph_id.to_u16();
+        let unit_size = self.unit_size() as usize;
+        if unit_size < 2 + T::RAW_BYTE_LEN {
+            return None;
+        }
+        let unit = search_units(self.entries_data(), unit_size, |unit| {
+            unit.read_at::<u16>(0)
+                .ok()
+                .map(|glyph| glyph.cmp(&glyph_id))
+        })?;
+        unit.read_at(2).ok()
+    }
+}
+
+impl Lookup8<'_> {
+    /// Returns the value associated with the given glyph.
+    pub fn value<T: LookupValue>(&self, glyph_id: GlyphId) -> Option<T> {
+        let ix = glyph_id.to_u16().checked_sub(self.first_glyph())?;
+        if ix >= self.glyph_count() {
+            return None;
+        }
+        FontData::new(self.values_data())
+            .read_at(ix as usize * T::RAW_BYTE_LEN)
+            .ok()
+    }
+}
+
+impl Lookup10<'_> {
+    /// Returns the value associated with the given glyph.
+    pub fn value<T: LookupValue>(&self, glyph_id: GlyphId) -> Option<T> {
+        let ix = glyph_id.to_u16().checked_sub(self.first_glyph())? as usize;
+        let unit_size = self.unit_size() as usize;
+        let data = FontData::new(self.values_data());
+        let offset = ix * unit_size;
+        let value = match unit_size {
+            1 => data.read_at::<u8>(offset).ok()? as u64,
+            2 => data.read_at::<u16>(offset).ok()? as u64,
+            4 => data.read_at::<u32>(offset).ok()? as u64,
+            8 => {
+                let hi = data.read_at::<u32>(offset).ok()? as u64;
+                let lo = data.read_at::<u32>(offset + 4).ok()? as u64;
+                (hi << 32) | lo
+            }
+            _ => return None,
+        };
+        T::from_u64(value)
+    }
+}
+
+/// Performs a binary search over units of a fixed size.
+///
+/// The comparison function receives the data for a unit and returns the
+/// ordering of that unit relative to the target.
+fn search_units<'a>(
+    data: &'a [u8],
+    unit_size: usize,
+    mut compare: impl FnMut(FontData) -> Option<core::cmp::Ordering>,
+) -> Option<FontData<'a>> {
+    let data = FontData::new(data);
+    let mut lo = 0;
+    let mut hi = data.len() / unit_size;
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let unit = data.slice(mid * unit_size..(mid + 1) * unit_size)?;
+        match compare(unit)? {
+            core::cmp::Ordering::Less => lo = mid + 1,
+            core::cmp::Ordering::Greater => hi = mid,
+            core::cmp::Ordering::Equal => return Some(unit),
+        }
+    }
+    None
+}
+
+impl<'a> StxHeader<'a> {
+    /// Returns the state table described by this header.
+    pub fn state_table<T>(&self) -> Result<ExtendedStateTable<'a, T>, ReadError> {
+        ExtendedStateTable::read(self.offset_data())
+    }
+}
+
+/// Returns an iterator over `count` tables stored back to back, along with
+/// the position of each in the data.
+///
+/// The length of each table, including the length field itself, is a
+/// 32-bit value at `len_pos` from the start of the table. Iteration ends
+/// with an error if a length is less than `header_len`.
+pub(crate) fn sequential<'a, T: FontRead<'a>>(
+    mut data: FontData<'a>,
+    mut count: u32,
+    len_pos: usize,
+    header_len: usize,
+) -> impl Iterator<Item = (usize, Result<T, ReadError>)> + 'a {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        if count == 0 || data.is_empty() {
+            return None;
+        }
+        count -= 1;
+        let this_pos = pos;
+        let len = match data.read_at::<u32>(len_pos) {
+            // a table that is smaller than its header would never advance
+            // the iterator
+            Ok(len) if (len as usize) < header_len => {
+                count = 0;
+                return Some((this_pos, Err(ReadError::MalformedData("table too short"))));
+            }
+            Ok(len) => (len as usize).min(data.len()),
+            Err(e) => {
+                count = 0;
+                return Some((this_pos, Err(e)));
+            }
+        };
+        let item = data.slice(..len)?;
+        data = data.split_off(len)?;
+        pos += len;
+        Some((this_pos, T::read(item)))
+    })
+}
+
+/// Traversal support for tables stored back to back.
+#[cfg(feature = "traversal")]
+pub(crate) struct SequentialArray<'a, T> {
+    pub(crate) type_name: &'static str,
+    pub(crate) items: Vec<(u32, Result<T, ReadError>)>,
+    pub(crate) _marker: PhantomData<&'a ()>,
+}
+
+#[cfg(feature = "traversal")]
+impl<'a, T> SequentialArray<'a, T> {
+    /// Collects items returned by [`sequential`], with positions adjusted by
+    /// the given offset to the start of the data.
+    pub(crate) fn new(
+        type_name: &'static str,
+        offset: usize,
+        items: impl Iterator<Item = (usize, Result<T, ReadError>)>,
+    ) -> Self {
+        Self {
+            type_name,
+            items: items
+                .map(|(pos, item)| ((pos + offset) as u32, item))
+                .collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a, T: SomeTable<'a> + Clone + 'a> traversal::SomeArray<'a> for SequentialArray<'a, T> {
+    fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn get(&self, idx: usize) -> Option<FieldType<'a>> {
+        let (offset, item) = self.items.get(idx)?;
+        Some(FieldType::offset(Offset32::new(*offset), item.clone()))
+    }
+}
+
+/// Table specific data stored in the entries of an extended state table.
+pub trait EntryData: Sized {
+    /// The size of the data in bytes.
+    const DATA_LEN: usize;
+
+    /// Reads the data at the given position.
+    fn read_data(data: FontData, pos: usize) -> Result<Self, ReadError>;
+}
+
+impl EntryData for () {
+    const DATA_LEN: usize = 0;
+
+    fn read_data(_data: FontData, _pos: usize) -> Result<Self, ReadError> {
+        Ok(())
+    }
+}
+
+impl<T: FromBytes + Clone> EntryData for T {
+    const DATA_LEN: usize = <T as FixedSize>::RAW_BYTE_LEN;
+
+    fn read_data(data: FontData, pos: usize) -> Result<Self, ReadError> {
+        data.read_ref_at::<T>(pos).cloned()
+    }
+}
+
+/// Entry in an extended state table.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct StateEntry<T = ()> {
+    /// Index of the next state.
+    pub new_state: u16,
+    /// Table specific flags for the entry.
+    pub flags: u16,
+    /// Table specific data for the entry.
+    pub payload: T,
+}
+
+/// An [extended state table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6Tables.html)
+/// with entries carrying data of type `T`.
+#[derive(Clone)]
+pub struct ExtendedStateTable<'a, T = ()> {
+    n_classes: usize,
+    class_table: Lookup<'a>,
+    state_array: &'a [BigEndian<u16>],
+    entry_table: FontData<'a>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> ExtendedStateTable<'a, T> {
+    /// Reads the state table that begins at the start of the given data.
+    pub fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        let header = StxHeader::read(data)?;
+        Ok(Self {
+            n_classes: header.n_classes() as usize,
+            class_table: header.class_table()?,
+            state_array: header.state_array()?.data(),
+            entry_table: FontData::new(header.entry_table()?.data()),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the number of classes.
+    pub fn n_classes(&self) -> usize {
+        self.n_classes
+    }
+
+    /// Returns the table that maps glyphs to classes.
+    pub fn class_table(&self) -> &Lookup<'a> {
+        &self.class_table
+    }
+
+    /// Returns the class of the given glyph.
+    pub fn class(&self, glyph_id: GlyphId) -> u16 {
+        if glyph_id == DELETED_GLYPH {
+            return class::DELETED_GLYPH;
+        }
+        self.class_table
+            .value(glyph_id)
+            .unwrap_or(class::OUT_OF_BOUNDS)
+    }
+}
+
+impl<T: EntryData> ExtendedStateTable<'_, T> {
+    /// Returns the entry for the given state and class.
+    pub fn entry(&self, state: u16, class: u16) -> Result<StateEntry<T>, ReadError> {
+        let class = class as usize;
+        if class >= self.n_classes {
+            return Err(ReadError::OutOfBounds);
+        }
+        let entry_ix = self
+            .state_array
+            .get(state as usize * self.n_classes + class)
+            .ok_or(ReadError::OutOfBounds)?
+            .get() as usize;
+        let pos = entry_ix * (4 + T::DATA_LEN);
+        Ok(StateEntry {
+            new_state: self.entry_table.read_at(pos)?,
+            flags: self.entry_table.read_at(pos + 2)?,
+            payload: T::read_data(self.entry_table, pos + 4)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn lookup_values(data: BeBuffer, glyphs: impl IntoIterator<Item = u16>) -> Vec<Option<u16>> {
+        let lookup = Lookup::read(data.font_data()).unwrap();
+        glyphs
+            .into_iter()
+            .map(|gid| lookup.value(GlyphId::new(gid)))
+            .collect()
+    }
+
+    #[test]
+    fn lookup_formats() {
+        // format 0: values for glyphs 0..3
+        let data = BeBuffer::new().extend([0u16, 10, 11, 12]);
+        assert_eq!(lookup_values(data, [0, 2, 3]), [Some(10), Some(12), None]);
+        // format 2: segments [3..=5] and [10..=11], with terminator
+        let data = BeBuffer::new()
+            .extend([2u16, 6, 3, 12, 1, 6])
+            .extend([5u16, 3, 1])
+            .extend([11u16, 10, 2])
+            .extend([0xFFFFu16, 0xFFFF, 0]);
+        assert_eq!(
+            lookup_values(data, [2, 3, 5, 6, 11]),
+            [None, Some(1), Some(1), None, Some(2)]
+        );
+        // format 4: one segment [4..=5] with values at offset 18
+        let data = BeBuffer::new()
+            .extend([4u16, 6, 1, 6, 0, 0])
+            .extend([5u16, 4, 18])
+            .extend([40u16, 50]);
+        assert_eq!(lookup_values(data, [3, 4, 5]), [None, Some(40), Some(50)]);
+        // format 6: glyph/value pairs
+        let data = BeBuffer::new()
+            .extend([6u16, 4, 2, 8, 1, 0])
+            .extend([2u16, 20])
+            .extend([7u16, 70]);
+        assert_eq!(lookup_values(data, [2, 5, 7]), [Some(20), None, Some(70)]);
+        // format 8: trimmed array starting at glyph 3
+        let data = BeBuffer::new().extend([8u16, 3, 2, 30, 40]);
+        assert_eq!(
+            lookup_values(data, [2, 3, 4, 5]),
+            [None, Some(30), Some(40), None]
+        );
+        // format 10: single byte values starting at glyph 1
+        let data = BeBuffer::new().extend([10u16, 1, 1, 2]).extend([7u8, 8]);
+        assert_eq!(lookup_values(data, [0, 1, 2]), [None, Some(7), Some(8)]);
+    }
+
+    // a trailing byte in the state array is ignored
+    #[test]
+    fn odd_length_state_array() {
+        let data = BeBuffer::new()
+            // nClasses, classTable, stateArray, entryTable
+            .extend([1u32, 16, 22, 22])
+            // class table: empty format 8
+            .extend([8u16, 0, 0])
+            .extend([0u8, 0, 0]);
+        assert_eq!(data.len(), 25);
+        let table = ExtendedStateTable::<()>::read(data.font_data()).unwrap();
+        assert_eq!(table.state_array.len(), 1);
+        assert!(table.entry(0, 0).is_err());
+    }
+
+    #[test]
+    fn extended_state_table() {
+        let data = BeBuffer::new()
+            // nClasses, classTable, stateArray, entryTable
+            .extend([5u32, 16, 28, 48])
+            // class table: format 8, glyphs 4..=5 are class 4
+            .extend([8u16, 4, 2, 4, 4])
+            .push(0u16)
+            // state array: two states
+            .extend([0u16, 0, 0, 0, 1])
+            .extend([0u16, 0, 0, 0, 2])
+            // entries: new state, flags, payload
+            .extend([0u16, 0, 0xFFFF])
+            .extend([1u16, 0x8000, 3])
+            .extend([0u16, 0x4000, 4]);
+        let table = ExtendedStateTable::<BigEndian<u16>>::read(data.font_data()).unwrap();
+        assert_eq!(table.n_classes(), 5);
+        assert_eq!(table.class(GlyphId::new(5)), 4);
+        assert_eq!(table.class(GlyphId::new(6)), class::OUT_OF_BOUNDS);
+        assert_eq!(table.class(DELETED_GLYPH), class::DELETED_GLYPH);
+        let entry = table.entry(0, 4).unwrap();
+        assert_eq!(
+            (entry.new_state, entry.flags, entry.payload.get()),
+            (1, 0x8000, 3)
+        );
+        let entry = table.entry(1, 4).unwrap();
+        assert_eq!(
+            (entry.new_state, entry.flags, entry.payload.get()),
+            (0, 0x4000, 4)
+        );
+        assert_eq!(table.entry(1, 0).unwrap().payload.get(), 0xFFFF);
+        assert!(table.entry(0, 5).is_err());
+    }
+}
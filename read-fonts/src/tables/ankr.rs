@@ -0,0 +1,57 @@
+//! The [anchor point (ankr)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ankr.html) table
+
+include!("../../generated/generated_ankr.rs");
+
+use super::aat::Lookup;
+
+impl<'a> Ankr<'a> {
+    /// Returns the anchor points for the given glyph.
+    ///
+    /// Glyphs that are not covered by the lookup table have no anchor
+    /// points.
+    pub fn anchor_points(&self, glyph_id: GlyphId) -> Result<&'a [AnchorPoint], ReadError> {
+        let Some(offset) = self.lookup_table()?.value::<u16>(glyph_id) else {
+            return Ok(&[]);
+        };
+        let pos = self.glyph_data_table_offset() as usize + offset as usize;
+        self.offset_data()
+            .split_off(pos)
+            .ok_or(ReadError::OutOfBounds)
+            .and_then(GlyphDataEntry::read)
+            .map(|entry| entry.anchor_points())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn anchor_points() {
+        let data = BeBuffer::new()
+            // version, flags, lookupTable, glyphDataTable
+            .extend([0u16, 0])
+            .extend([12u32, 32])
+            // lookup table: format 6 with glyph 2 at offset 0 and glyph 5
+            // at offset 8
+            .extend([6u16, 4, 2, 8, 1, 0])
+            .extend([2u16, 0, 5, 8])
+            // glyph data: one point, then two points
+            .push(1u32)
+            .extend([10i16, 20])
+            .push(2u32)
+            .extend([-5i16, 0, 7, 8]);
+        let ankr = Ankr::read(data.font_data()).unwrap();
+        let points = |gid: u16| {
+            ankr.anchor_points(GlyphId::new(gid))
+                .unwrap()
+                .iter()
+                .map(|point| (point.x(), point.y()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(points(2), [(10, 20)]);
+        assert_eq!(points(5), [(-5, 0), (7, 8)]);
+        assert!(points(3).is_empty());
+    }
+}
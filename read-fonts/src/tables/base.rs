@@ -4,6 +4,51 @@ use super::{layout::DeviceOrVariationIndex, variations::ItemVariationStore};
 
 include!("../../generated/generated_base.rs");
 
+impl<'a> BaseTagList<'a> {
+    /// Returns the index of the given baseline tag.
+    pub fn index_of(&self, tag: Tag) -> Option<usize> {
+        self.baseline_tags()
+            .binary_search_by(|probe| probe.get().cmp(&tag))
+            .ok()
+    }
+}
+
+impl<'a> BaseScriptList<'a> {
+    /// Returns the baseline data for the given script.
+    pub fn get(&self, script: Tag) -> Option<Result<BaseScript<'a>, ReadError>> {
+        let records = self.base_script_records();
+        let index = records
+            .binary_search_by(|record| record.base_script_tag().cmp(&script))
+            .ok()?;
+        Some(records[index].base_script(self.offset_data()))
+    }
+}
+
+impl<'a> BaseScript<'a> {
+    /// Returns the extent data for the given language system.
+    ///
+    /// This does not fall back to the default extents if the language
+    /// system is not present.
+    pub fn lang_sys_min_max(&self, language: Tag) -> Option<Result<MinMax<'a>, ReadError>> {
+        let records = self.base_lang_sys_records();
+        let index = records
+            .binary_search_by(|record| record.base_lang_sys_tag().cmp(&language))
+            .ok()?;
+        Some(records[index].min_max(self.offset_data()))
+    }
+}
+
+impl<'a> BaseCoord<'a> {
+    /// Returns the X or Y value in design units.
+    pub fn coordinate(&self) -> i16 {
+        match self {
+            Self::Format1(item) => item.coordinate(),
+            Self::Format2(item) => item.coordinate(),
+            Self::Format3(item) => item.coordinate(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use font_types::MajorMinor;
@@ -51,4 +96,51 @@ mod tests {
             Tag::new(b"latn")
         );
     }
+
+    #[test]
+    fn script_and_language_lookup() {
+        let data = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .extend([8u16, 0])
+            // axis: tag list, script list
+            .extend([4u16, 14])
+            // tag list
+            .push(2u16)
+            .extend([Tag::new(b"ideo"), Tag::new(b"romn")])
+            // script list
+            .push(1u16)
+            .push(Tag::new(b"latn"))
+            .push(8u16)
+            // script: values, default min max, lang sys count
+            .extend([12u16, 0, 1])
+            .push(Tag::new(b"TRK "))
+            .push(28u16)
+            // values: default index, count, coord offsets
+            .extend([1u16, 2, 8, 12])
+            // coords, format 1
+            .extend([1u16, (-120i16) as u16, 1, 0])
+            // min max: min, max, feature count, min coord
+            .extend([6u16, 0, 0])
+            .extend([1u16, (-200i16) as u16]);
+        let base = Base::read(data.font_data()).unwrap();
+        let horiz = base.horiz_axis().unwrap().unwrap();
+        let tags = horiz.base_tag_list().unwrap().unwrap();
+        assert_eq!(tags.index_of(Tag::new(b"romn")), Some(1));
+        assert_eq!(tags.index_of(Tag::new(b"hang")), None);
+        let scripts = horiz.base_script_list().unwrap();
+        assert!(scripts.get(Tag::new(b"cyrl")).is_none());
+        let script = scripts.get(Tag::new(b"latn")).unwrap().unwrap();
+        let values = script.base_values().unwrap().unwrap();
+        assert_eq!(values.default_baseline_index(), 1);
+        let coords = values
+            .base_coords()
+            .iter()
+            .map(|coord| coord.unwrap().coordinate())
+            .collect::<Vec<_>>();
+        assert_eq!(coords, [-120, 0]);
+        let min_max = script.lang_sys_min_max(Tag::new(b"TRK ")).unwrap().unwrap();
+        assert_eq!(min_max.min_coord().unwrap().unwrap().coordinate(), -200);
+        assert!(min_max.max_coord().is_none());
+        assert!(script.lang_sys_min_max(Tag::new(b"DEU ")).is_none());
+    }
 }
@@ -5,6 +5,7 @@ include!("../../generated/generated_cff.rs");
 use super::postscript::{Index1, Latin1String, StringId};
 
 /// The [Compact Font Format](https://learn.microsoft.com/en-us/typography/opentype/spec/cff) table.
+#[derive(Clone)]
 pub struct Cff<'a> {
     header: CffHeader<'a>,
     names: Index1<'a>,
@@ -80,6 +81,81 @@ impl<'a> Cff<'a> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<'a> Cff<'a> {
+    /// Extends `glyphs` with the base and accent glyphs of all glyphs in the
+    /// set that are composed using the deprecated `seac` form of the
+    /// `endchar` charstring operator.
+    ///
+    /// The `top_dict_index` parameter selects the font in the font set. This
+    /// is always 0 for CFF tables in OpenType fonts.
+    ///
+    /// Glyphs with charstrings that cannot be evaluated are skipped.
+    pub fn closure_glyphs(
+        &self,
+        top_dict_index: usize,
+        glyphs: &mut std::collections::HashSet<GlyphId>,
+    ) -> Result<(), super::postscript::Error> {
+        use super::postscript::{charstring, dict, standard_encoding, Charset, Error, Index};
+        let table_data = self.offset_data();
+        let mut charstrings = None;
+        let mut charset_offset = 0;
+        let mut private_dict_range = None;
+        for entry in dict::entries(self.top_dicts.get(top_dict_index)?, None) {
+            match entry? {
+                dict::Entry::CharstringsOffset(offset) => {
+                    charstrings = Some(Index::new(
+                        table_data.as_bytes().get(offset..).unwrap_or_default(),
+                        false,
+                    )?);
+                }
+                dict::Entry::Charset(offset) => charset_offset = offset,
+                dict::Entry::PrivateDictRange(range) => private_dict_range = Some(range),
+                // seac is not supported in CID-keyed fonts
+                dict::Entry::Ros { .. } => return Ok(()),
+                _ => {}
+            }
+        }
+        let charstrings = charstrings.ok_or(Error::MissingCharstrings)?;
+        let charset = Charset::new(table_data, charset_offset, charstrings.count())?;
+        let mut subrs = None;
+        if let Some(range) = private_dict_range {
+            let private_dict_data = table_data.read_array(range.clone())?;
+            for entry in dict::entries(private_dict_data, None) {
+                if let dict::Entry::SubrsOffset(offset) = entry? {
+                    // Subrs offset is relative to the private DICT
+                    let subrs_data = table_data
+                        .as_bytes()
+                        .get(range.start + offset..)
+                        .unwrap_or_default();
+                    subrs = Some(Index::new(subrs_data, false)?);
+                }
+            }
+        }
+        let mut components = Vec::new();
+        for glyph_id in glyphs.iter() {
+            let Ok(charstring_data) = charstrings.get(glyph_id.to_u16() as usize) else {
+                continue;
+            };
+            let Ok(Some(seac)) = charstring::seac_components(
+                charstring_data,
+                self.global_subrs().into(),
+                subrs.clone(),
+                None,
+            ) else {
+                continue;
+            };
+            for code in [seac.base_code, seac.accent_code] {
+                if let Ok(component) = charset.glyph_id(standard_encoding(code)) {
+                    components.push(component);
+                }
+            }
+        }
+        glyphs.extend(components);
+        Ok(())
+    }
+}
+
 impl TopLevelTable for Cff<'_> {
     const TAG: Tag = Tag::new(b"CFF ");
 }
@@ -2,6 +2,9 @@
 
 include!("../../generated/generated_cmap.rs");
 
+#[cfg(feature = "std")]
+use crate::collections::IntSet;
+
 /// Result of mapping a codepoint with a variation selector.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum MapVariant {
@@ -71,6 +74,38 @@ impl<'a> Cmap4<'a> {
         Cmap4Iter::new(self.clone())
     }
 
+    /// Returns the set of codepoints that map to a glyph other than
+    /// `.notdef`.
+    ///
+    /// This works directly with the ranges of the subtable, so it is
+    /// more efficient than collecting the codepoints from [`iter`](Self::iter).
+    #[cfg(feature = "std")]
+    pub fn codepoints(&self) -> IntSet<u32> {
+        let mut set = IntSet::new();
+        let deltas = self.id_delta();
+        let range_offsets = self.id_range_offsets();
+        for (index, (delta, range_offset)) in deltas.iter().zip(range_offsets).enumerate() {
+            let Some(range) = self.code_range(index).filter(|range| !range.is_empty()) else {
+                continue;
+            };
+            if range_offset.get() == 0 {
+                set.insert_range(range.start..=range.end - 1);
+                // glyph ids wrap, so at most one codepoint maps to 0
+                let notdef_codepoint = (delta.get() as u16).wrapping_neg() as u32;
+                if range.contains(&notdef_codepoint) {
+                    set.remove(notdef_codepoint);
+                }
+            } else {
+                let start_code = range.start as u16;
+                set.extend(range.filter(|codepoint| {
+                    self.lookup_glyph_id(*codepoint as u16, index, start_code)
+                        .is_some_and(|gid| gid != GlyphId::NOTDEF)
+                }));
+            }
+        }
+        set
+    }
+
     /// Does the final phase of glyph id lookup.
     ///
     /// Shared between Self::map and Cmap4Iter.
@@ -179,6 +214,34 @@ impl<'a> Cmap12<'a> {
         Cmap12Iter::new(self.clone())
     }
 
+    /// Returns the set of codepoints that map to a glyph other than
+    /// `.notdef`.
+    ///
+    /// This works directly with the groups of the subtable, so it is
+    /// more efficient than collecting the codepoints from [`iter`](Self::iter).
+    /// Codepoints beyond the Unicode range are ignored.
+    #[cfg(feature = "std")]
+    pub fn codepoints(&self) -> IntSet<u32> {
+        let mut set = IntSet::new();
+        for group in self.groups() {
+            let start = group.start_char_code();
+            let end = group.end_char_code().min(char::MAX as u32);
+            if start > end {
+                continue;
+            }
+            set.insert_range(start..=end);
+            // glyph ids are truncated to 16 bits, so every 65536th
+            // codepoint after the first that maps to 0 also does
+            let first_notdef = (group.start_glyph_id().wrapping_neg() & 0xFFFF) as u64;
+            let mut notdef_codepoint = start as u64 + first_notdef;
+            while notdef_codepoint <= end as u64 {
+                set.remove(notdef_codepoint as u32);
+                notdef_codepoint += 0x10000;
+            }
+        }
+        set
+    }
+
     /// Does the final phase of glyph id lookup.
     ///
     /// Shared between Self::map and Cmap12Iter.
@@ -528,6 +591,40 @@ mod tests {
         assert_eq!(count, 10);
     }
 
+    #[test]
+    fn codepoint_sets() {
+        for font_data in [font_test_data::VAZIRMATN_VAR, font_test_data::SIMPLE_GLYF] {
+            let font = FontRef::new(font_data).unwrap();
+            let cmap4 = find_cmap4(&font.cmap().unwrap()).unwrap();
+            let expected = cmap4.iter().map(|(cp, _)| cp).collect::<IntSet<_>>();
+            assert_eq!(cmap4.codepoints(), expected);
+        }
+        let font = FontRef::new(font_test_data::CMAP12_FONT1).unwrap();
+        let cmap12 = find_cmap12(&font.cmap().unwrap()).unwrap();
+        let expected = cmap12.iter().map(|(cp, _)| cp).collect::<IntSet<_>>();
+        assert_eq!(cmap12.codepoints(), expected);
+    }
+
+    #[test]
+    fn cmap12_codepoints_skip_notdef() {
+        use crate::test_helpers::BeBuffer;
+        // a single group where the first codepoint maps to glyph 0 and
+        // glyph ids wrap around after 0xFFFF
+        let data = BeBuffer::new()
+            .push(12u16)
+            .push(0u16)
+            .push(28u32)
+            .push(0u32)
+            .push(1u32)
+            .extend([0x100u32, 0x100 + 0x10005, 0]);
+        let cmap12 = Cmap12::read(data.font_data()).unwrap();
+        let expected = cmap12.iter().map(|(cp, _)| cp).collect::<IntSet<_>>();
+        let codepoints = cmap12.codepoints();
+        assert_eq!(codepoints, expected);
+        assert_eq!(codepoints.len(), 0x10006 - 2);
+        assert!(!codepoints.contains(0x100) && !codepoints.contains(0x10100));
+    }
+
     #[test]
     fn cmap14_iter() {
         let font = FontRef::new(font_test_data::CMAP14_FONT1).unwrap();
@@ -0,0 +1,236 @@
+//! The [cvar (CVT Variations)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvar)
+//! table
+
+include!("../../generated/generated_cvar.rs");
+
+use super::variations::{
+    DeltaRunIter, PackedDeltas, PackedPointNumbers, PackedPointNumbersIter, Tuple,
+    TupleVariationCount, TupleVariationHeader, TupleVariationHeaderIter,
+};
+
+impl<'a> Cvar<'a> {
+    fn raw_tuple_header_data(&self) -> FontData<'a> {
+        let range = self.shape.tuple_variation_headers_byte_range();
+        self.data.split_off(range.start).unwrap()
+    }
+
+    /// Returns an iterator over all of the variation tuples for the control
+    /// value table.
+    ///
+    /// The `axis_count` must match the number of axes in the `fvar` table.
+    pub fn tuples(&self, axis_count: u16) -> Result<CvtTupleVariationIter<'a>, ReadError> {
+        let count = self.tuple_variation_count();
+        let data = self.data()?;
+        // if there are shared point numbers, get them now
+        let (shared_point_numbers, serialized_data) = if count.shared_point_numbers() {
+            let (packed, data) = PackedPointNumbers::split_off_front(data);
+            (Some(packed), data)
+        } else {
+            (None, data)
+        };
+        Ok(CvtTupleVariationIter {
+            current: 0,
+            count: count.count() as usize,
+            axis_count,
+            shared_point_numbers,
+            header_iter: TupleVariationHeaderIter::new(
+                self.raw_tuple_header_data(),
+                count.count() as usize,
+                axis_count,
+            ),
+            serialized_data,
+        })
+    }
+
+    /// Computes the accumulated deltas for the control values at the given
+    /// set of normalized coordinates.
+    ///
+    /// The deltas are added to the values in `deltas`, which is indexed by
+    /// control value. Deltas for control values beyond the end of the slice
+    /// are ignored.
+    pub fn deltas(
+        &self,
+        axis_count: u16,
+        coords: &[F2Dot14],
+        deltas: &mut [Fixed],
+    ) -> Result<(), ReadError> {
+        for tuple in self.tuples(axis_count)? {
+            let Some(scalar) = tuple.compute_scalar(coords) else {
+                continue;
+            };
+            for delta in tuple.deltas() {
+                if let Some(value) = deltas.get_mut(delta.position as usize) {
+                    *value += Fixed::from_i32(delta.delta as i32) * scalar;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the [`CvtTupleVariation`]s in a `cvar` table.
+pub struct CvtTupleVariationIter<'a> {
+    current: usize,
+    count: usize,
+    axis_count: u16,
+    shared_point_numbers: Option<PackedPointNumbers<'a>>,
+    header_iter: TupleVariationHeaderIter<'a>,
+    serialized_data: FontData<'a>,
+}
+
+impl<'a> Iterator for CvtTupleVariationIter<'a> {
+    type Item = CvtTupleVariation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.count {
+            return None;
+        }
+        self.current += 1;
+        let header = self.header_iter.next()?.ok()?;
+        let data_len = header.variation_data_size() as usize;
+        let var_data = self.serialized_data.take_up_to(data_len)?;
+        let (point_numbers, packed_deltas) = if header.tuple_index().private_point_numbers() {
+            PackedPointNumbers::split_off_front(var_data)
+        } else {
+            (self.shared_point_numbers.clone()?, var_data)
+        };
+        Some(CvtTupleVariation {
+            axis_count: self.axis_count,
+            header,
+            packed_deltas: PackedDeltas::new(packed_deltas),
+            point_numbers,
+        })
+    }
+}
+
+/// A single set of tuple variation data for the control value table.
+#[derive(Clone)]
+pub struct CvtTupleVariation<'a> {
+    axis_count: u16,
+    header: TupleVariationHeader<'a>,
+    packed_deltas: PackedDeltas<'a>,
+    point_numbers: PackedPointNumbers<'a>,
+}
+
+impl<'a> CvtTupleVariation<'a> {
+    /// Returns true if this tuple provides deltas for all control values.
+    pub fn has_deltas_for_all_values(&self) -> bool {
+        self.point_numbers.count() == 0
+    }
+
+    /// Returns the 'peak' tuple for this variation.
+    ///
+    /// Peak tuples are always embedded in the `cvar` table.
+    pub fn peak(&self) -> Tuple<'a> {
+        self.header.peak_tuple().unwrap_or_default()
+    }
+
+    /// Compute the scalar for this tuple at a given point in design space.
+    ///
+    /// Returns `None` if this tuple is not applicable at the provided
+    /// coordinates (e.g. if the resulting scalar is zero).
+    pub fn compute_scalar(&self, coords: &[F2Dot14]) -> Option<Fixed> {
+        self.header
+            .compute_scalar(&self.peak(), self.axis_count, coords)
+    }
+
+    /// Iterate over the deltas for this tuple.
+    ///
+    /// This does not account for scaling.
+    pub fn deltas(&self) -> CvtDeltaIter<'a> {
+        CvtDeltaIter {
+            cur: 0,
+            points: (!self.has_deltas_for_all_values()).then(|| self.point_numbers.iter()),
+            deltas: self.packed_deltas.iter(),
+        }
+    }
+}
+
+/// An iterator over the deltas for the control value table.
+#[derive(Clone, Debug)]
+pub struct CvtDeltaIter<'a> {
+    cur: u16,
+    // if None all values get deltas, if Some specifies the subset that do
+    points: Option<PackedPointNumbersIter<'a>>,
+    deltas: DeltaRunIter<'a>,
+}
+
+/// Delta for a single control value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CvtDelta {
+    /// The index of the control value.
+    pub position: u16,
+    /// The delta for the control value.
+    pub delta: i16,
+}
+
+impl Iterator for CvtDeltaIter<'_> {
+    type Item = CvtDelta;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = match &mut self.points {
+            Some(points) => points.next()?,
+            None => {
+                let position = self.cur;
+                self.cur = self.cur.checked_add(1)?;
+                position
+            }
+        };
+        Some(CvtDelta {
+            position,
+            delta: self.deltas.next()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn make_cvar() -> BeBuffer {
+        BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            // one tuple, offset to data
+            .extend([1u16, 14])
+            // header: data size, embedded peak with private points
+            .extend([7u16, 0xA000])
+            // peak at 1.0
+            .push(F2Dot14::from_f32(1.0))
+            // points 1 and 3
+            .extend([2u8, 1, 1, 2])
+            // deltas 10 and -5
+            .extend([1u8, 10, (-5i8) as u8])
+    }
+
+    #[test]
+    fn tuples_and_deltas() {
+        let data = make_cvar();
+        let cvar = Cvar::read(data.font_data()).unwrap();
+        let tuples = cvar.tuples(1).unwrap().collect::<Vec<_>>();
+        assert_eq!(tuples.len(), 1);
+        let tuple = &tuples[0];
+        assert!(!tuple.has_deltas_for_all_values());
+        assert_eq!(tuple.peak().get(0), Some(F2Dot14::from_f32(1.0)));
+        let deltas = tuple
+            .deltas()
+            .map(|delta| (delta.position, delta.delta))
+            .collect::<Vec<_>>();
+        assert_eq!(deltas, [(1, 10), (3, -5)]);
+        assert_eq!(
+            tuple.compute_scalar(&[F2Dot14::from_f32(0.5)]),
+            Some(Fixed::from_f64(0.5))
+        );
+        assert_eq!(tuple.compute_scalar(&[F2Dot14::from_f32(-0.5)]), None);
+    }
+
+    #[test]
+    fn accumulated_deltas() {
+        let data = make_cvar();
+        let cvar = Cvar::read(data.font_data()).unwrap();
+        let mut deltas = [Fixed::ZERO; 3];
+        cvar.deltas(1, &[F2Dot14::from_f32(0.5)], &mut deltas)
+            .unwrap();
+        assert_eq!(deltas, [Fixed::ZERO, Fixed::from_i32(5), Fixed::ZERO]);
+    }
+}
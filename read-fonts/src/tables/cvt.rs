@@ -0,0 +1,17 @@
+//! The [cvt (Control Value Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvt) table
+
+include!("../../generated/generated_cvt.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn odd_length() {
+        // the trailing byte is not a complete value
+        let data = [0u8, 10, 0xFF, 0xEC, 7];
+        let cvt = Cvt::read(FontData::new(&data)).unwrap();
+        let values = cvt.values().iter().map(|v| v.get()).collect::<Vec<_>>();
+        assert_eq!(values, [10, -20]);
+    }
+}
@@ -0,0 +1,73 @@
+//! The [feature name (feat)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6feat.html) table
+
+include!("../../generated/generated_feat.rs");
+
+impl<'a> Feat<'a> {
+    /// Returns the name for the given feature type, if it exists.
+    pub fn find(&self, feature: u16) -> Option<&'a FeatureName> {
+        let names = self.names();
+        names
+            .binary_search_by_key(&feature, |name| name.feature())
+            .ok()
+            .map(|ix| &names[ix])
+    }
+}
+
+impl FeatureName {
+    /// Set if the settings of this feature are mutually exclusive.
+    pub const EXCLUSIVE: u16 = 0x8000;
+    /// Set if the low byte of the flags contains the index of the default
+    /// setting.
+    pub const DEFAULT_SETTING: u16 = 0x4000;
+
+    /// True if the settings of this feature are mutually exclusive.
+    pub fn is_exclusive(&self) -> bool {
+        self.feature_flags() & Self::EXCLUSIVE != 0
+    }
+
+    /// Returns the index of the default setting in the setting name array.
+    pub fn default_setting_index(&self) -> u8 {
+        if self.feature_flags() & Self::DEFAULT_SETTING != 0 {
+            self.feature_flags() as u8
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn feature_names() {
+        let data = BeBuffer::new()
+            // version, featureNameCount, reserved
+            .push(MajorMinor::VERSION_1_0)
+            .extend([2u16, 0])
+            .push(0u32)
+            // feature names: feature, nSettings, settingTable, flags, nameIndex
+            .extend([1u16, 1])
+            .push(36u32)
+            .extend([0u16, 260])
+            .extend([6u16, 2])
+            .push(40u32)
+            .extend([0xC001u16, 261])
+            // setting names
+            .extend([0u16, 262])
+            .extend([0u16, 263, 1, 264]);
+        let feat = Feat::read(data.font_data()).unwrap();
+        assert!(feat.find(2).is_none());
+        let name = feat.find(6).unwrap();
+        assert!(name.is_exclusive());
+        assert_eq!(name.default_setting_index(), 1);
+        assert_eq!(name.name_index(), NameId::new(261));
+        let settings = name.setting_table(feat.offset_data()).unwrap();
+        let settings = settings.settings();
+        assert_eq!(settings.len(), 2);
+        assert_eq!(settings[1].setting(), 1);
+        assert_eq!(settings[1].name_index(), NameId::new(264));
+        assert!(!feat.find(1).unwrap().is_exclusive());
+    }
+}
@@ -0,0 +1,41 @@
+//! The [gasp (Grid-fitting and Scan-conversion Procedure)](https://learn.microsoft.com/en-us/typography/opentype/spec/gasp) table
+
+include!("../../generated/generated_gasp.rs");
+
+impl<'a> Gasp<'a> {
+    /// Returns the rendering behavior for the given size in pixels per em.
+    ///
+    /// Returns `None` if the size is larger than the last range, which
+    /// should be 0xFFFF in well formed fonts.
+    pub fn behavior(&self, ppem: u16) -> Option<GaspRangeBehavior> {
+        self.gasp_ranges()
+            .iter()
+            .find(|range| ppem <= range.range_max_ppem())
+            .map(|range| range.range_gasp_behavior())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn behavior_by_size() {
+        let data = BeBuffer::new()
+            .extend([1u16, 3])
+            .extend([8u16, 0x0A])
+            .extend([16u16, 0x05])
+            .extend([0xFFFFu16, 0x0F]);
+        let gasp = Gasp::read(data.font_data()).unwrap();
+        assert_eq!(
+            gasp.behavior(7),
+            Some(GaspRangeBehavior::GASP_DOGRAY | GaspRangeBehavior::GASP_SYMMETRIC_SMOOTHING)
+        );
+        assert_eq!(
+            gasp.behavior(16),
+            Some(GaspRangeBehavior::GASP_GRIDFIT | GaspRangeBehavior::GASP_SYMMETRIC_GRIDFIT)
+        );
+        assert_eq!(gasp.behavior(100), Some(GaspRangeBehavior::all()));
+    }
+}
@@ -487,6 +487,31 @@ impl<'a> CompositeGlyph<'a> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<'a> Glyf<'a> {
+    /// Extends `glyphs` with the components of all composite glyphs in the
+    /// set, recursively.
+    ///
+    /// Glyphs that cannot be read are skipped.
+    pub fn closure_glyphs(
+        &self,
+        loca: &super::loca::Loca<'a>,
+        glyphs: &mut std::collections::HashSet<GlyphId>,
+    ) {
+        let mut stack = glyphs.iter().copied().collect::<Vec<_>>();
+        while let Some(glyph_id) = stack.pop() {
+            if let Ok(Some(Glyph::Composite(composite))) = loca.get_glyf(glyph_id, self) {
+                for component in composite.components() {
+                    // only visit each glyph once to guard against cycles
+                    if glyphs.insert(component.glyph) {
+                        stack.push(component.glyph);
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 struct ComponentIter<'a> {
     cur_flags: CompositeGlyphFlags,
@@ -951,4 +976,17 @@ mod tests {
             CompositeGlyphFlags::WE_HAVE_A_TWO_BY_TWO
         );
     }
+
+    #[test]
+    fn closure_composite() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let loca = font.loca(None).unwrap();
+        let glyf = font.glyf().unwrap();
+        // Agrave is composed of A and grave
+        let mut glyphs = std::collections::HashSet::from([GlyphId::new(2)]);
+        glyf.closure_glyphs(&loca, &mut glyphs);
+        let mut glyphs = glyphs.into_iter().collect::<Vec<_>>();
+        glyphs.sort();
+        assert_eq!(glyphs, [GlyphId::new(1), GlyphId::new(2), GlyphId::new(3)]);
+    }
 }
@@ -7,6 +7,9 @@ pub use super::layout::{
     Lookup, LookupList, ScriptList, SequenceContext,
 };
 
+#[cfg(feature = "std")]
+mod closure;
+
 #[cfg(test)]
 #[path = "../tests/test_gsub.rs"]
 mod tests;
@@ -0,0 +1,724 @@
+//! Computing the closure of a set of glyphs over GSUB lookups.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use types::{GlyphId, Tag};
+
+use super::{
+    AlternateSubstFormat1, ChainedSequenceContext, ClassDef, CoverageTable, ExtensionSubtable,
+    Gsub, LigatureSubstFormat1, MultipleSubstFormat1, ReverseChainSingleSubstFormat1,
+    SequenceContext, SingleSubst, SubstitutionLookup, SubstitutionLookupList,
+};
+use crate::{
+    tables::layout::{Feature, SequenceLookupRecord},
+    ArrayOfOffsets, ReadError,
+};
+
+/// Maximum depth of nested lookups referenced by contextual substitutions.
+const MAX_NESTING_LEVEL: u8 = 64;
+
+/// Maximum number of passes over the lookups.
+///
+/// Each pass may produce new glyphs that enable further substitutions so we
+/// repeat until no new glyphs are found, up to this limit. This is documented
+/// on [`Gsub::closure_glyphs`].
+const MAX_ITERATIONS: usize = 64;
+
+/// Maximum number of lookups visited over all passes.
+///
+/// Contextual lookups can reference each other in ways that make the number
+/// of visits grow exponentially with the nesting level, so the closure is
+/// abandoned once this budget is exhausted, as in HarfBuzz.
+const MAX_LOOKUP_VISITS: usize = 35000;
+
+impl<'a> Gsub<'a> {
+    /// Returns the indices of the lookups referenced by the given features.
+    ///
+    /// If `features` is `None`, the lookups for all features are returned.
+    /// Lookups that may be substituted for a feature through the
+    /// [`FeatureVariations`](super::FeatureVariations) table are included.
+    ///
+    /// Lookups that are only referenced by contextual lookups are not
+    /// included; these are visited as required when computing the closure.
+    pub fn collect_lookups(&self, features: Option<&[Tag]>) -> Result<BTreeSet<u16>, ReadError> {
+        let feature_list = self.feature_list()?;
+        let records = feature_list.feature_records();
+        let is_selected = |tag: Tag| {
+            features
+                .map(|features| features.contains(&tag))
+                .unwrap_or(true)
+        };
+        let mut lookups = BTreeSet::new();
+        let mut add_feature = |feature: Feature| {
+            lookups.extend(feature.lookup_list_indices().iter().map(|ix| ix.get()));
+        };
+        for record in records {
+            if is_selected(record.feature_tag()) {
+                add_feature(record.feature(feature_list.offset_data())?);
+            }
+        }
+        if let Some(feature_variations) = self.feature_variations().transpose()? {
+            let data = feature_variations.offset_data();
+            for variation in feature_variations.feature_variation_records() {
+                let Some(substitution) = variation.feature_table_substitution(data).transpose()?
+                else {
+                    continue;
+                };
+                for record in substitution.substitutions() {
+                    let Some(tag) = records
+                        .get(record.feature_index() as usize)
+                        .map(|record| record.feature_tag())
+                    else {
+                        continue;
+                    };
+                    if is_selected(tag) {
+                        add_feature(record.alternate_feature(substitution.offset_data())?);
+                    }
+                }
+            }
+        }
+        Ok(lookups)
+    }
+
+    /// Extends `glyphs` with the set of all glyphs that are reachable by
+    /// applying the given lookups.
+    ///
+    /// Lookup indices can be obtained from a set of features using
+    /// [`collect_lookups`](Self::collect_lookups).
+    ///
+    /// The result is a conservative approximation: the context of contextual
+    /// and chained contextual lookups is matched against the full set of
+    /// glyphs without regard to ordering in a glyph sequence, so the closure
+    /// may contain glyphs that can never be produced by shaping real text.
+    ///
+    /// The lookups are applied repeatedly until no new glyphs are produced,
+    /// but at most 64 times. A font that requires more passes to reach a
+    /// fixed point (for example, a single substitution chain longer than
+    /// the limit) will yield an incomplete closure. The closure is also
+    /// incomplete if the total number of lookups visited, including those
+    /// referenced by contextual lookups, exceeds 35000.
+    pub fn closure_glyphs(
+        &self,
+        lookups: &BTreeSet<u16>,
+        glyphs: &mut HashSet<GlyphId>,
+    ) -> Result<(), ReadError> {
+        let lookup_list = self.lookup_list()?;
+        let mut visits_left = MAX_LOOKUP_VISITS;
+        for _ in 0..MAX_ITERATIONS {
+            let mut closure = Closure {
+                lookup_list: &lookup_list,
+                glyphs,
+                output: HashSet::new(),
+                done: HashMap::new(),
+                visits_left,
+            };
+            for lookup_index in lookups {
+                closure.lookup(*lookup_index, closure.glyphs, 0)?;
+            }
+            visits_left = closure.visits_left;
+            let output = closure.output;
+            let count = glyphs.len();
+            glyphs.extend(output);
+            if glyphs.len() == count {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// State for a single pass of the closure computation.
+struct Closure<'a, 'b> {
+    lookup_list: &'b SubstitutionLookupList<'a>,
+    /// All glyphs reachable at the start of this pass.
+    glyphs: &'b HashSet<GlyphId>,
+    /// Glyphs produced during this pass.
+    output: HashSet<GlyphId>,
+    /// Input glyphs for which each lookup has already been visited in this
+    /// pass.
+    done: HashMap<u16, HashSet<GlyphId>>,
+    /// Remaining number of lookup visits.
+    visits_left: usize,
+}
+
+impl<'a, 'b> Closure<'a, 'b> {
+    /// Visits the lookup at the given index, where `input` contains the
+    /// glyphs that may appear at the current position.
+    fn lookup(
+        &mut self,
+        lookup_index: u16,
+        input: &HashSet<GlyphId>,
+        nesting_level: u8,
+    ) -> Result<(), ReadError> {
+        if nesting_level > MAX_NESTING_LEVEL || self.visits_left == 0 {
+            return Ok(());
+        }
+        // the reachable glyphs are fixed for the duration of a pass, so
+        // visiting a lookup again with a subset of the glyphs it has already
+        // been visited with cannot produce any new output
+        let done = self.done.entry(lookup_index).or_default();
+        if input.is_subset(done) {
+            return Ok(());
+        }
+        done.extend(input.iter().copied());
+        self.visits_left -= 1;
+        let lookup = self.lookup_list.lookups().get(lookup_index as usize)?;
+        match lookup {
+            SubstitutionLookup::Single(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.single(&subtable?, input)?;
+                }
+            }
+            SubstitutionLookup::Multiple(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.multiple(&subtable?, input)?;
+                }
+            }
+            SubstitutionLookup::Alternate(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.alternate(&subtable?, input)?;
+                }
+            }
+            SubstitutionLookup::Ligature(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.ligature(&subtable?, input)?;
+                }
+            }
+            SubstitutionLookup::Contextual(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.context(&subtable?, input, nesting_level)?;
+                }
+            }
+            SubstitutionLookup::ChainContextual(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.chain_context(&subtable?, input, nesting_level)?;
+                }
+            }
+            SubstitutionLookup::Extension(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.extension(&subtable?, input, nesting_level)?;
+                }
+            }
+            SubstitutionLookup::Reverse(lookup) => {
+                for subtable in lookup.subtables().iter() {
+                    self.reverse(&subtable?, input)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn extension(
+        &mut self,
+        subtable: &ExtensionSubtable,
+        input: &HashSet<GlyphId>,
+        nesting_level: u8,
+    ) -> Result<(), ReadError> {
+        match subtable {
+            ExtensionSubtable::Single(ext) => self.single(&ext.extension()?, input),
+            ExtensionSubtable::Multiple(ext) => self.multiple(&ext.extension()?, input),
+            ExtensionSubtable::Alternate(ext) => self.alternate(&ext.extension()?, input),
+            ExtensionSubtable::Ligature(ext) => self.ligature(&ext.extension()?, input),
+            ExtensionSubtable::Contextual(ext) => {
+                self.context(&ext.extension()?, input, nesting_level)
+            }
+            ExtensionSubtable::ChainContextual(ext) => {
+                self.chain_context(&ext.extension()?, input, nesting_level)
+            }
+            ExtensionSubtable::Reverse(ext) => self.reverse(&ext.extension()?, input),
+        }
+    }
+
+    fn single(
+        &mut self,
+        subtable: &SingleSubst,
+        input: &HashSet<GlyphId>,
+    ) -> Result<(), ReadError> {
+        match subtable {
+            SingleSubst::Format1(table) => {
+                // the addition is performed modulo 65536
+                let delta = table.delta_glyph_id();
+                for glyph_id in table.coverage()?.iter() {
+                    if input.contains(&glyph_id) {
+                        let substitute = glyph_id.to_u16().wrapping_add(delta as u16);
+                        self.output.insert(GlyphId::new(substitute));
+                    }
+                }
+            }
+            SingleSubst::Format2(table) => {
+                let substitutes = table.substitute_glyph_ids();
+                for (glyph_id, substitute) in table.coverage()?.iter().zip(substitutes) {
+                    if input.contains(&glyph_id) {
+                        self.output.insert(substitute.get());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn multiple(
+        &mut self,
+        subtable: &MultipleSubstFormat1,
+        input: &HashSet<GlyphId>,
+    ) -> Result<(), ReadError> {
+        let sequences = subtable.sequences();
+        for (glyph_id, sequence) in subtable.coverage()?.iter().zip(sequences.iter()) {
+            if input.contains(&glyph_id) {
+                let substitutes = sequence?.substitute_glyph_ids();
+                self.output
+                    .extend(substitutes.iter().map(|glyph_id| glyph_id.get()));
+            }
+        }
+        Ok(())
+    }
+
+    fn alternate(
+        &mut self,
+        subtable: &AlternateSubstFormat1,
+        input: &HashSet<GlyphId>,
+    ) -> Result<(), ReadError> {
+        let alternate_sets = subtable.alternate_sets();
+        for (glyph_id, alternate_set) in subtable.coverage()?.iter().zip(alternate_sets.iter()) {
+            if input.contains(&glyph_id) {
+                let alternates = alternate_set?.alternate_glyph_ids();
+                self.output
+                    .extend(alternates.iter().map(|glyph_id| glyph_id.get()));
+            }
+        }
+        Ok(())
+    }
+
+    fn ligature(
+        &mut self,
+        subtable: &LigatureSubstFormat1,
+        input: &HashSet<GlyphId>,
+    ) -> Result<(), ReadError> {
+        let ligature_sets = subtable.ligature_sets();
+        for (glyph_id, ligature_set) in subtable.coverage()?.iter().zip(ligature_sets.iter()) {
+            if !input.contains(&glyph_id) {
+                continue;
+            }
+            for ligature in ligature_set?.ligatures().iter() {
+                let ligature = ligature?;
+                let components = ligature.component_glyph_ids();
+                if components
+                    .iter()
+                    .all(|glyph_id| self.glyphs.contains(&glyph_id.get()))
+                {
+                    self.output.insert(ligature.ligature_glyph());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn reverse(
+        &mut self,
+        subtable: &ReverseChainSingleSubstFormat1,
+        input: &HashSet<GlyphId>,
+    ) -> Result<(), ReadError> {
+        if !self.intersects_all(&subtable.backtrack_coverages())?
+            || !self.intersects_all(&subtable.lookahead_coverages())?
+        {
+            return Ok(());
+        }
+        let substitutes = subtable.substitute_glyph_ids();
+        for (glyph_id, substitute) in subtable.coverage()?.iter().zip(substitutes) {
+            if input.contains(&glyph_id) {
+                self.output.insert(substitute.get());
+            }
+        }
+        Ok(())
+    }
+
+    fn context(
+        &mut self,
+        subtable: &SequenceContext,
+        input: &HashSet<GlyphId>,
+        nesting_level: u8,
+    ) -> Result<(), ReadError> {
+        match subtable {
+            SequenceContext::Format1(table) => {
+                let rule_sets = table.seq_rule_sets();
+                for (glyph_id, rule_set) in table.coverage()?.iter().zip(rule_sets.iter()) {
+                    let Some(rule_set) = rule_set.transpose()? else {
+                        continue;
+                    };
+                    if !input.contains(&glyph_id) {
+                        continue;
+                    }
+                    for rule in rule_set.seq_rules().iter() {
+                        let rule = rule?;
+                        let Some(positions) = self.glyph_positions(glyph_id, rule.input_sequence())
+                        else {
+                            continue;
+                        };
+                        self.lookup_records(rule.seq_lookup_records(), &positions, nesting_level)?;
+                    }
+                }
+            }
+            SequenceContext::Format2(table) => {
+                let class_def = table.class_def()?;
+                let rule_sets = table.class_seq_rule_sets();
+                for (class, first) in first_glyphs_by_class(&table.coverage()?, &class_def, input) {
+                    let Some(rule_set) = rule_sets.get(class as usize).transpose()? else {
+                        continue;
+                    };
+                    for rule in rule_set.class_seq_rules().iter() {
+                        let rule = rule?;
+                        let Some(positions) =
+                            self.class_positions(&first, &class_def, rule.input_sequence())
+                        else {
+                            continue;
+                        };
+                        self.lookup_records(rule.seq_lookup_records(), &positions, nesting_level)?;
+                    }
+                }
+            }
+            SequenceContext::Format3(table) => {
+                let Some(positions) = self.coverage_positions(&table.coverages(), input)? else {
+                    return Ok(());
+                };
+                self.lookup_records(table.seq_lookup_records(), &positions, nesting_level)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn chain_context(
+        &mut self,
+        subtable: &ChainedSequenceContext,
+        input: &HashSet<GlyphId>,
+        nesting_level: u8,
+    ) -> Result<(), ReadError> {
+        match subtable {
+            ChainedSequenceContext::Format1(table) => {
+                let rule_sets = table.chained_seq_rule_sets();
+                for (glyph_id, rule_set) in table.coverage()?.iter().zip(rule_sets.iter()) {
+                    let Some(rule_set) = rule_set.transpose()? else {
+                        continue;
+                    };
+                    if !input.contains(&glyph_id) {
+                        continue;
+                    }
+                    for rule in rule_set.chained_seq_rules().iter() {
+                        let rule = rule?;
+                        let context = rule
+                            .backtrack_sequence()
+                            .iter()
+                            .chain(rule.lookahead_sequence());
+                        if !context
+                            .map(|glyph_id| glyph_id.get())
+                            .all(|glyph_id| self.glyphs.contains(&glyph_id))
+                        {
+                            continue;
+                        }
+                        let Some(positions) = self.glyph_positions(glyph_id, rule.input_sequence())
+                        else {
+                            continue;
+                        };
+                        self.lookup_records(rule.seq_lookup_records(), &positions, nesting_level)?;
+                    }
+                }
+            }
+            ChainedSequenceContext::Format2(table) => {
+                let backtrack_class_def = table.backtrack_class_def()?;
+                let input_class_def = table.input_class_def()?;
+                let lookahead_class_def = table.lookahead_class_def()?;
+                let rule_sets = table.chained_class_seq_rule_sets();
+                for (class, first) in
+                    first_glyphs_by_class(&table.coverage()?, &input_class_def, input)
+                {
+                    let Some(rule_set) = rule_sets.get(class as usize).transpose()? else {
+                        continue;
+                    };
+                    for rule in rule_set.chained_class_seq_rules().iter() {
+                        let rule = rule?;
+                        if !self.intersects_classes(&backtrack_class_def, rule.backtrack_sequence())
+                            || !self
+                                .intersects_classes(&lookahead_class_def, rule.lookahead_sequence())
+                        {
+                            continue;
+                        }
+                        let Some(positions) =
+                            self.class_positions(&first, &input_class_def, rule.input_sequence())
+                        else {
+                            continue;
+                        };
+                        self.lookup_records(rule.seq_lookup_records(), &positions, nesting_level)?;
+                    }
+                }
+            }
+            ChainedSequenceContext::Format3(table) => {
+                if !self.intersects_all(&table.backtrack_coverages())?
+                    || !self.intersects_all(&table.lookahead_coverages())?
+                {
+                    return Ok(());
+                }
+                let Some(positions) = self.coverage_positions(&table.input_coverages(), input)?
+                else {
+                    return Ok(());
+                };
+                self.lookup_records(table.seq_lookup_records(), &positions, nesting_level)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Visits the nested lookups of a matched rule, where `positions`
+    /// contains the set of glyphs that may appear at each position in the
+    /// input sequence.
+    fn lookup_records(
+        &mut self,
+        records: &[SequenceLookupRecord],
+        positions: &[HashSet<GlyphId>],
+        nesting_level: u8,
+    ) -> Result<(), ReadError> {
+        for record in records {
+            if let Some(input) = positions.get(record.sequence_index() as usize) {
+                self.lookup(record.lookup_list_index(), input, nesting_level + 1)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the glyph sets for an input sequence of glyph identifiers,
+    /// or `None` if any of the glyphs are not reachable.
+    fn glyph_positions(
+        &self,
+        first: GlyphId,
+        sequence: &[types::BigEndian<GlyphId>],
+    ) -> Option<Vec<HashSet<GlyphId>>> {
+        let mut positions = vec![HashSet::from([first])];
+        for glyph_id in sequence {
+            let glyph_id = glyph_id.get();
+            if !self.glyphs.contains(&glyph_id) {
+                return None;
+            }
+            positions.push(HashSet::from([glyph_id]));
+        }
+        Some(positions)
+    }
+
+    /// Returns the glyph sets for an input sequence of classes, or `None` if
+    /// no reachable glyph belongs to one of the classes.
+    fn class_positions(
+        &self,
+        first: &HashSet<GlyphId>,
+        class_def: &ClassDef,
+        sequence: &[types::BigEndian<u16>],
+    ) -> Option<Vec<HashSet<GlyphId>>> {
+        let mut positions = vec![first.clone()];
+        for class in sequence {
+            let class = class.get();
+            let glyphs = self
+                .glyphs
+                .iter()
+                .copied()
+                .filter(|glyph_id| class_def.get(*glyph_id) == class)
+                .collect::<HashSet<_>>();
+            if glyphs.is_empty() {
+                return None;
+            }
+            positions.push(glyphs);
+        }
+        Some(positions)
+    }
+
+    /// Returns the glyph sets for an input sequence of coverage tables, or
+    /// `None` if no reachable glyph is covered by one of the tables.
+    ///
+    /// The first position is restricted to the glyphs in `input`.
+    fn coverage_positions<'c>(
+        &self,
+        coverages: &ArrayOfOffsets<'c, CoverageTable<'c>>,
+        input: &HashSet<GlyphId>,
+    ) -> Result<Option<Vec<HashSet<GlyphId>>>, ReadError> {
+        let mut positions = Vec::with_capacity(coverages.len());
+        for (i, coverage) in coverages.iter().enumerate() {
+            let candidates = if i == 0 { input } else { self.glyphs };
+            let glyphs = coverage?
+                .iter()
+                .filter(|glyph_id| candidates.contains(glyph_id))
+                .collect::<HashSet<_>>();
+            if glyphs.is_empty() {
+                return Ok(None);
+            }
+            positions.push(glyphs);
+        }
+        Ok(Some(positions))
+    }
+
+    /// Returns true if every coverage table contains at least one reachable
+    /// glyph.
+    fn intersects_all<'c>(
+        &self,
+        coverages: &ArrayOfOffsets<'c, CoverageTable<'c>>,
+    ) -> Result<bool, ReadError> {
+        for coverage in coverages.iter() {
+            if !coverage?
+                .iter()
+                .any(|glyph_id| self.glyphs.contains(&glyph_id))
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Returns true if every class contains at least one reachable glyph.
+    fn intersects_classes(&self, class_def: &ClassDef, classes: &[types::BigEndian<u16>]) -> bool {
+        classes.iter().all(|class| {
+            let class = class.get();
+            self.glyphs
+                .iter()
+                .any(|glyph_id| class_def.get(*glyph_id) == class)
+        })
+    }
+}
+
+/// Groups the covered glyphs from `input` by class.
+fn first_glyphs_by_class(
+    coverage: &CoverageTable,
+    class_def: &ClassDef,
+    input: &HashSet<GlyphId>,
+) -> HashMap<u16, HashSet<GlyphId>> {
+    let mut classes: HashMap<u16, HashSet<GlyphId>> = HashMap::new();
+    for glyph_id in coverage.iter().filter(|glyph_id| input.contains(glyph_id)) {
+        classes
+            .entry(class_def.get(glyph_id))
+            .or_default()
+            .insert(glyph_id);
+    }
+    classes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::BeBuffer, FontRead, FontRef, TableProvider};
+
+    /// Builds a GSUB table with one lookup for each (type, subtable) pair.
+    ///
+    /// Only the lookup list is populated.
+    fn make_gsub(lookups: &[(u16, &[u16])]) -> BeBuffer {
+        const HEADER_LEN: u16 = 10;
+        const LOOKUP_LEN: u16 = 8;
+        let lookup_list_len = 2 + 2 * lookups.len() as u16;
+        let mut buf = BeBuffer::new()
+            .extend([1u16, 0, 0, 0, HEADER_LEN])
+            .push(lookups.len() as u16);
+        let mut offset = lookup_list_len;
+        for (_, subtable) in lookups {
+            buf = buf.push(offset);
+            offset += LOOKUP_LEN + 2 * subtable.len() as u16;
+        }
+        for (lookup_type, subtable) in lookups {
+            buf = buf
+                .extend([*lookup_type, 0, 1, LOOKUP_LEN])
+                .extend(subtable.iter().copied());
+        }
+        buf
+    }
+
+    fn closure(gsub_data: &BeBuffer, lookups: &[u16], glyphs: &[u16]) -> BTreeSet<u16> {
+        let gsub = Gsub::read(gsub_data.font_data()).unwrap();
+        let lookups = lookups.iter().copied().collect();
+        let mut glyphs = glyphs.iter().copied().map(GlyphId::new).collect();
+        gsub.closure_glyphs(&lookups, &mut glyphs).unwrap();
+        glyphs
+            .into_iter()
+            .map(|glyph_id| glyph_id.to_u16())
+            .collect()
+    }
+
+    /// Single substitution format 2 mapping `from` to `to`.
+    fn single(from: u16, to: u16) -> [u16; 7] {
+        [2, 8, 1, to, 1, 1, from]
+    }
+
+    #[test]
+    fn closure_ligature() {
+        // ligatures 1+2 -> 10 and 1+3 -> 11
+        let ligature = [1, 8, 1, 14, 1, 1, 1, 2, 6, 12, 10, 2, 2, 11, 2, 3];
+        let gsub = make_gsub(&[(4, &ligature)]);
+        assert_eq!(closure(&gsub, &[0], &[1, 2]), BTreeSet::from([1, 2, 10]));
+        assert_eq!(closure(&gsub, &[0], &[1]), BTreeSet::from([1]));
+        assert_eq!(closure(&gsub, &[0], &[1, 3]), BTreeSet::from([1, 3, 11]));
+    }
+
+    #[test]
+    fn closure_contextual() {
+        // input sequence [1] [2] applies lookup 1 to the second glyph
+        let context = [3, 2, 1, 14, 20, 1, 1, 1, 1, 1, 1, 1, 2];
+        let gsub = make_gsub(&[(5, &context), (1, &single(2, 20))]);
+        assert_eq!(closure(&gsub, &[0], &[1, 2]), BTreeSet::from([1, 2, 20]));
+        assert_eq!(closure(&gsub, &[0], &[2]), BTreeSet::from([2]));
+    }
+
+    #[test]
+    fn closure_chained_contextual() {
+        // backtrack [3], input [1] applies lookup 1 to the input glyph
+        let chain_context = [3, 1, 18, 1, 24, 0, 1, 0, 1, 1, 1, 3, 1, 1, 1];
+        let gsub = make_gsub(&[(6, &chain_context), (1, &single(1, 30))]);
+        assert_eq!(closure(&gsub, &[0], &[1, 3]), BTreeSet::from([1, 3, 30]));
+        assert_eq!(closure(&gsub, &[0], &[1]), BTreeSet::from([1]));
+    }
+
+    #[test]
+    fn closure_reverse_chaining() {
+        // 1 -> 40 when followed by 2
+        let reverse = [1, 14, 0, 1, 20, 1, 40, 1, 1, 1, 1, 1, 2];
+        let gsub = make_gsub(&[(8, &reverse)]);
+        assert_eq!(closure(&gsub, &[0], &[1, 2]), BTreeSet::from([1, 2, 40]));
+        assert_eq!(closure(&gsub, &[0], &[1]), BTreeSet::from([1]));
+    }
+
+    #[test]
+    fn closure_multiple_passes() {
+        // the ligature glyph produced by the first lookup is substituted by
+        // the second, which precedes it in lookup order
+        let ligature = [1, 8, 1, 14, 1, 1, 1, 1, 4, 10, 2, 2];
+        let gsub = make_gsub(&[(1, &single(10, 50)), (4, &ligature)]);
+        assert_eq!(
+            closure(&gsub, &[0, 1], &[1, 2]),
+            BTreeSet::from([1, 2, 10, 50])
+        );
+    }
+
+    #[test]
+    fn closure_recursive_context() {
+        // input [1] applies lookup 0 to the first glyph twice, which would
+        // visit 2^64 lookups without caching
+        let context = [3, 1, 2, 16, 0, 0, 0, 0, 1, 1, 1];
+        let multiple = [1, 8, 1, 14, 1, 1, 1, 2, 1, 60];
+        let gsub = make_gsub(&[(5, &context), (2, &multiple)]);
+        assert_eq!(closure(&gsub, &[0], &[1]), BTreeSet::from([1]));
+        // the same with a nested multiple substitution of 1 -> 1 60
+        let context = [3, 1, 3, 20, 0, 1, 0, 0, 0, 0, 1, 1, 1];
+        let gsub = make_gsub(&[(5, &context), (2, &multiple)]);
+        assert_eq!(closure(&gsub, &[0], &[1]), BTreeSet::from([1, 60]));
+    }
+
+    #[test]
+    fn closure_locl() {
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let gsub = font.gsub().unwrap();
+        let lookups = gsub.collect_lookups(None).unwrap();
+        let mut glyphs = HashSet::from([GlyphId::new(1)]);
+        gsub.closure_glyphs(&lookups, &mut glyphs).unwrap();
+        assert_eq!(glyphs, HashSet::from([GlyphId::new(1), GlyphId::new(2)]));
+    }
+
+    #[test]
+    fn closure_unrelated_feature() {
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let gsub = font.gsub().unwrap();
+        let lookups = gsub.collect_lookups(Some(&[Tag::new(b"liga")])).unwrap();
+        assert!(lookups.is_empty());
+        let mut glyphs = HashSet::from([GlyphId::new(1)]);
+        gsub.closure_glyphs(&lookups, &mut glyphs).unwrap();
+        assert_eq!(glyphs, HashSet::from([GlyphId::new(1)]));
+    }
+}
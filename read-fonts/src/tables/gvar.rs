@@ -3,8 +3,9 @@
 
 include!("../../generated/generated_gvar.rs");
 
-use core::iter::Skip;
+use core::{iter::Skip, ops::RangeInclusive};
 
+use super::glyf::{PointFlags, PointMarker};
 use super::variations::{
     DeltaRunIter, PackedDeltas, PackedPointNumbers, PackedPointNumbersIter, Tuple,
     TupleVariationCount, TupleVariationHeader, TupleVariationHeaderIter,
@@ -18,12 +19,12 @@ impl ReadArgs for U16Or32 {
 }
 
 impl ComputeSize for U16Or32 {
-    fn compute_size(args: &GvarFlags) -> usize {
-        if args.contains(GvarFlags::LONG_OFFSETS) {
+    fn compute_size(args: &GvarFlags) -> Result<usize, ReadError> {
+        Ok(if args.contains(GvarFlags::LONG_OFFSETS) {
             4
         } else {
             2
-        }
+        })
     }
 }
 
@@ -82,6 +83,37 @@ impl<'a> Gvar<'a> {
         let data = self.data_for_gid(gid)?;
         GlyphVariationData::new(data, axis_count, shared_tuples)
     }
+
+    /// Computes the variation deltas for all points of a glyph at the given
+    /// normalized coordinates.
+    ///
+    /// This is a convenience wrapper around
+    /// [GlyphVariationData::compute_deltas] that allocates the required
+    /// buffers. A glyph without variation data has zero deltas.
+    #[cfg(feature = "std")]
+    pub fn glyph_deltas(
+        &self,
+        glyph_id: GlyphId,
+        coords: &[F2Dot14],
+        points: &[Point<i32>],
+        contour_ends: &[u16],
+    ) -> Result<Vec<Point<Fixed>>, ReadError> {
+        let mut deltas = vec![Point::default(); points.len()];
+        if self.data_for_gid(glyph_id)?.is_empty() {
+            return Ok(deltas);
+        }
+        let mut flags = vec![PointFlags::default(); points.len()];
+        let mut working_points = vec![Point::default(); points.len()];
+        self.glyph_variation_data(glyph_id)?.compute_deltas(
+            coords,
+            points,
+            contour_ends,
+            &mut flags,
+            &mut working_points,
+            &mut deltas,
+        )?;
+        Ok(deltas)
+    }
 }
 
 impl<'a> GlyphVariationData<'a> {
@@ -142,6 +174,79 @@ impl<'a> GlyphVariationData<'a> {
         })
     }
 
+    /// Computes the accumulated deltas for the points of a glyph at the
+    /// given normalized coordinates.
+    ///
+    /// `points` are the unscaled points of the glyph in the order used by
+    /// the variation data: the outline points (or component offsets for a
+    /// composite glyph) followed by the four phantom points. `contour_ends`
+    /// holds the index of the last point of each contour.
+    ///
+    /// Deltas for points that are not referenced by a tuple are inferred by
+    /// interpolating the referenced points of the same contour, as described
+    /// in [the specification](https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#inferred-deltas-for-un-referenced-point-numbers).
+    /// Points that don't belong to a contour, such as phantom points, only
+    /// receive explicit deltas.
+    ///
+    /// The remaining arguments must be at least as long as `points`. Only
+    /// the [PointMarker::HAS_DELTA] marker is modified in `flags` so the
+    /// glyph's own point flags can be passed. `working_points` is scratch
+    /// space and the result is written to `deltas`.
+    pub fn compute_deltas(
+        &self,
+        coords: &[F2Dot14],
+        points: &[Point<i32>],
+        contour_ends: &[u16],
+        flags: &mut [PointFlags],
+        working_points: &mut [Point<Fixed>],
+        deltas: &mut [Point<Fixed>],
+    ) -> Result<(), ReadError> {
+        let len = points.len();
+        if flags.len() < len || working_points.len() < len || deltas.len() < len {
+            return Err(ReadError::InvalidArrayLen);
+        }
+        let flags = &mut flags[..len];
+        let working_points = &mut working_points[..len];
+        let deltas = &mut deltas[..len];
+        deltas.fill(Point::default());
+        for tuple in self.tuples() {
+            let Some(scalar) = tuple.compute_scalar(coords) else {
+                continue;
+            };
+            // Fast path: tuple contains all points, we can simply accumulate the deltas directly.
+            if tuple.has_deltas_for_all_points() {
+                for (delta, tuple_delta) in deltas.iter_mut().zip(tuple.deltas()) {
+                    *delta += tuple_delta.apply_scalar(scalar);
+                }
+                continue;
+            }
+            // Otherwise, infer missing deltas by interpolation.
+            // Prepare our working buffer by converting the points to 16.16
+            // and clearing the HAS_DELTA flags.
+            for ((flag, point), working_point) in
+                flags.iter_mut().zip(points).zip(working_points.iter_mut())
+            {
+                *working_point = point.map(Fixed::from_i32);
+                flag.clear_marker(PointMarker::HAS_DELTA);
+            }
+            for tuple_delta in tuple.deltas() {
+                let ix = tuple_delta.position as usize;
+                if let (Some(flag), Some(point)) = (flags.get_mut(ix), working_points.get_mut(ix)) {
+                    flag.set_marker(PointMarker::HAS_DELTA);
+                    *point += tuple_delta.apply_scalar(scalar);
+                }
+            }
+            interpolate_deltas(points, flags, contour_ends, working_points)
+                .ok_or(ReadError::OutOfBounds)?;
+            for ((delta, point), working_point) in
+                deltas.iter_mut().zip(points).zip(working_points.iter())
+            {
+                *delta += *working_point - point.map(Fixed::from_i32);
+            }
+        }
+        Ok(())
+    }
+
     fn tuple_count(&self) -> usize {
         self.tuple_count.count() as usize
     }
@@ -229,46 +334,8 @@ impl<'a> TupleVariation<'a> {
     /// Returns `None` if this tuple is not applicable at the provided coordinates
     /// (e.g. if the resulting scalar is zero).
     pub fn compute_scalar(&self, coords: &[F2Dot14]) -> Option<Fixed> {
-        const ZERO: Fixed = Fixed::ZERO;
-        let mut scalar = Fixed::ONE;
-        let peak = self.peak();
-        let inter_start = self.header.intermediate_start_tuple();
-        let inter_end = self.header.intermediate_end_tuple();
-        if peak.len() != self.axis_count as usize {
-            return None;
-        }
-
-        for i in 0..self.axis_count {
-            let i = i as usize;
-            let coord = coords.get(i).copied().unwrap_or_default().to_fixed();
-            let peak = peak.get(i).unwrap_or_default().to_fixed();
-            if peak == ZERO || peak == coord {
-                continue;
-            }
-
-            if coord == ZERO {
-                return None;
-            }
-
-            if let (Some(inter_start), Some(inter_end)) = (&inter_start, &inter_end) {
-                let start = inter_start.get(i).unwrap_or_default().to_fixed();
-                let end = inter_end.get(i).unwrap_or_default().to_fixed();
-                if coord <= start || coord >= end {
-                    return None;
-                }
-                if coord < peak {
-                    scalar = scalar.mul_div(coord - start, peak - start);
-                } else {
-                    scalar = scalar.mul_div(end - coord, end - peak);
-                }
-            } else {
-                if coord < peak.min(ZERO) || coord > peak.max(ZERO) {
-                    return None;
-                }
-                scalar = scalar.mul_div(coord, peak);
-            }
-        }
-        Some(scalar)
+        self.header
+            .compute_scalar(&self.peak(), self.axis_count, coords)
     }
 
     /// Iterate over the deltas for this tuple.
@@ -353,6 +420,157 @@ impl<'a> Iterator for DeltaIter<'a> {
     }
 }
 
+/// Interpolate points without delta values, similar to the IUP hinting
+/// instruction.
+///
+/// Modeled after the FreeType implementation:
+/// <https://github.com/freetype/freetype/blob/bbfcd79eacb4985d4b68783565f4b494aa64516b/src/truetype/ttgxvar.c#L3881>
+fn interpolate_deltas(
+    points: &[Point<i32>],
+    flags: &[PointFlags],
+    contours: &[u16],
+    out_points: &mut [Point<Fixed>],
+) -> Option<()> {
+    debug_assert_eq!(points.len(), flags.len());
+    debug_assert!(out_points.len() >= points.len());
+    let mut jiggler = Jiggler { points, out_points };
+    let mut point_ix = 0usize;
+    for &end_point_ix in contours {
+        let end_point_ix = end_point_ix as usize;
+        let first_point_ix = point_ix;
+        // Search for first point that has a delta.
+        while point_ix <= end_point_ix && !flags.get(point_ix)?.has_marker(PointMarker::HAS_DELTA) {
+            point_ix += 1;
+        }
+        // If we didn't find any deltas, no variations in the current tuple apply,
+        // so skip it.
+        if point_ix > end_point_ix {
+            continue;
+        }
+        let first_delta_ix = point_ix;
+        let mut cur_delta_ix = point_ix;
+        point_ix += 1;
+        // Search for next point that has a delta...
+        while point_ix <= end_point_ix {
+            if flags.get(point_ix)?.has_marker(PointMarker::HAS_DELTA) {
+                // ... and interpolate intermediate points.
+                jiggler.interpolate(
+                    cur_delta_ix + 1..=point_ix - 1,
+                    RefPoints(cur_delta_ix, point_ix),
+                )?;
+                cur_delta_ix = point_ix;
+            }
+            point_ix += 1;
+        }
+        // If we only have a single delta, shift the contour.
+        if cur_delta_ix == first_delta_ix {
+            jiggler.shift(first_point_ix..=end_point_ix, cur_delta_ix)?;
+        } else {
+            // Otherwise, handle remaining points at beginning and end of contour.
+            jiggler.interpolate(
+                cur_delta_ix + 1..=end_point_ix,
+                RefPoints(cur_delta_ix, first_delta_ix),
+            )?;
+            if first_delta_ix > 0 {
+                jiggler.interpolate(
+                    first_point_ix..=first_delta_ix - 1,
+                    RefPoints(cur_delta_ix, first_delta_ix),
+                )?;
+            }
+        }
+    }
+    Some(())
+}
+
+struct RefPoints(usize, usize);
+
+struct Jiggler<'a> {
+    points: &'a [Point<i32>],
+    out_points: &'a mut [Point<Fixed>],
+}
+
+impl<'a> Jiggler<'a> {
+    /// Shift the coordinates of all points in the specified range using the
+    /// difference given by the point at `ref_ix`.
+    ///
+    /// Modeled after the FreeType implementation: <https://github.com/freetype/freetype/blob/bbfcd79eacb4985d4b68783565f4b494aa64516b/src/truetype/ttgxvar.c#L3776>
+    fn shift(&mut self, range: RangeInclusive<usize>, ref_ix: usize) -> Option<()> {
+        let ref_in = self.points.get(ref_ix)?.map(Fixed::from_i32);
+        let ref_out = self.out_points.get(ref_ix)?;
+        let delta = *ref_out - ref_in;
+        if delta.x == Fixed::ZERO && delta.y == Fixed::ZERO {
+            return Some(());
+        }
+        // Apply the reference point delta to the entire range excluding the reference point
+        // itself which would apply the delta twice.
+        for out_point in self.out_points.get_mut(*range.start()..ref_ix)? {
+            *out_point += delta;
+        }
+        for out_point in self.out_points.get_mut(ref_ix + 1..=*range.end())? {
+            *out_point += delta;
+        }
+        Some(())
+    }
+
+    /// Interpolate the coordinates of all points in the specified range using
+    /// `ref1_ix` and `ref2_ix` as the reference point indices.
+    ///
+    /// Modeled after the FreeType implementation: <https://github.com/freetype/freetype/blob/bbfcd79eacb4985d4b68783565f4b494aa64516b/src/truetype/ttgxvar.c#L3813>
+    ///
+    /// For details on the algorithm, see: <https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#inferred-deltas-for-un-referenced-point-numbers>
+    fn interpolate(&mut self, range: RangeInclusive<usize>, ref_points: RefPoints) -> Option<()> {
+        if range.is_empty() {
+            return Some(());
+        }
+        let RefPoints(ref1_ix, ref2_ix) = ref_points;
+        // FreeType uses pointer tricks to handle x and y coords with a single piece of code.
+        // Try a macro instead.
+        macro_rules! interp_coord {
+            ($coord:ident) => {
+                let mut ref1_ix = ref1_ix;
+                let mut ref2_ix = ref2_ix;
+                if self.points.get(ref1_ix)?.$coord > self.points.get(ref2_ix)?.$coord {
+                    core::mem::swap(&mut ref1_ix, &mut ref2_ix);
+                }
+                let in1 = Fixed::from_i32(self.points.get(ref1_ix)?.$coord);
+                let in2 = Fixed::from_i32(self.points.get(ref2_ix)?.$coord);
+                let out1 = self.out_points.get(ref1_ix)?.$coord;
+                let out2 = self.out_points.get(ref2_ix)?.$coord;
+                // If the reference points have the same coordinate but different delta,
+                // inferred delta is zero. Otherwise interpolate.
+                if in1 != in2 || out1 == out2 {
+                    let scale = if in1 != in2 {
+                        (out2 - out1) / (in2 - in1)
+                    } else {
+                        Fixed::ZERO
+                    };
+                    let d1 = out1 - in1;
+                    let d2 = out2 - in2;
+                    for (point, out_point) in self
+                        .points
+                        .get(range.clone())?
+                        .iter()
+                        .zip(self.out_points.get_mut(range.clone())?)
+                    {
+                        let mut out = Fixed::from_i32(point.$coord);
+                        if out <= in1 {
+                            out += d1;
+                        } else if out >= in2 {
+                            out += d2;
+                        } else {
+                            out = out1 + (out - in1) * scale;
+                        }
+                        out_point.$coord = out;
+                    }
+                }
+            };
+        }
+        interp_coord!(x);
+        interp_coord!(y);
+        Some(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -550,4 +768,115 @@ mod tests {
             &[0, -20, -20, 0, 0, 0, 0, 0]
         );
     }
+
+    #[test]
+    fn vazirmatn_glyph_deltas() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let gvar = font.gvar().unwrap();
+        let coords = [F2Dot14::from_f32(-1.0)];
+        // "A" has deltas for all points so these match the first tuple
+        let glyph = match font
+            .loca(None)
+            .unwrap()
+            .get_glyf(GlyphId::new(1), &font.glyf().unwrap())
+            .unwrap()
+        {
+            Some(crate::tables::glyf::Glyph::Simple(glyph)) => glyph,
+            _ => panic!("expected a simple glyph"),
+        };
+        let mut points = glyph
+            .points()
+            .map(|point| Point::new(point.x as i32, point.y as i32))
+            .collect::<Vec<_>>();
+        // phantom points
+        points.extend([Point::default(); 4]);
+        let contour_ends = glyph
+            .end_pts_of_contours()
+            .iter()
+            .map(|end| end.get())
+            .collect::<Vec<_>>();
+        let deltas = gvar
+            .glyph_deltas(GlyphId::new(1), &coords, &points, &contour_ends)
+            .unwrap();
+        let tuple = gvar
+            .glyph_variation_data(GlyphId::new(1))
+            .unwrap()
+            .tuples()
+            .next()
+            .unwrap();
+        let expected = tuple
+            .deltas()
+            .map(|delta| {
+                Point::new(delta.x_delta as i32, delta.y_delta as i32).map(Fixed::from_i32)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(deltas, expected);
+        // "Agrave" is a composite with sparse deltas for two of its six points
+        let points = vec![Point::default(); 6];
+        let deltas = gvar
+            .glyph_deltas(GlyphId::new(2), &coords, &points, &[])
+            .unwrap();
+        let expected = [(0, 0), (-51, 8), (0, 0), (-113, 0), (0, 0), (0, 0)]
+            .map(|(x, y)| Point::new(x, y).map(Fixed::from_i32));
+        assert_eq!(deltas, expected);
+    }
+
+    fn make_points(tuples: &[(i32, i32)]) -> Vec<Point<i32>> {
+        tuples.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    fn make_working_points_and_flags(
+        points: &[Point<i32>],
+        deltas: &[Point<i32>],
+    ) -> (Vec<Point<Fixed>>, Vec<PointFlags>) {
+        let working_points = points
+            .iter()
+            .zip(deltas)
+            .map(|(point, delta)| point.map(Fixed::from_i32) + delta.map(Fixed::from_i32))
+            .collect();
+        let flags = deltas
+            .iter()
+            .map(|delta| {
+                let mut flags = PointFlags::default();
+                if delta.x != 0 || delta.y != 0 {
+                    flags.set_marker(PointMarker::HAS_DELTA);
+                }
+                flags
+            })
+            .collect();
+        (working_points, flags)
+    }
+
+    #[test]
+    fn shift() {
+        let points = make_points(&[(245, 630), (260, 700), (305, 680)]);
+        // Single delta triggers a full contour shift.
+        let deltas = make_points(&[(20, -10), (0, 0), (0, 0)]);
+        let (mut working_points, flags) = make_working_points_and_flags(&points, &deltas);
+        interpolate_deltas(&points, &flags, &[2], &mut working_points).unwrap();
+        let expected = &[
+            Point::new(265, 620).map(Fixed::from_i32),
+            Point::new(280, 690).map(Fixed::from_i32),
+            Point::new(325, 670).map(Fixed::from_i32),
+        ];
+        assert_eq!(&working_points, expected);
+    }
+
+    #[test]
+    fn interpolate() {
+        // Test taken from the spec:
+        // https://learn.microsoft.com/en-us/typography/opentype/spec/gvar#inferred-deltas-for-un-referenced-point-numbers
+        // with a minor adjustment to account for the precision of our fixed point math.
+        let points = make_points(&[(245, 630), (260, 700), (305, 680)]);
+        let deltas = make_points(&[(28, -62), (0, 0), (-42, -57)]);
+        let (mut working_points, flags) = make_working_points_and_flags(&points, &deltas);
+        interpolate_deltas(&points, &flags, &[2], &mut working_points).unwrap();
+        assert_eq!(
+            working_points[1],
+            Point::new(
+                Fixed::from_f64(260.0 + 10.4999237060547),
+                Fixed::from_f64(700.0 - 57.0)
+            )
+        );
+    }
 }
@@ -0,0 +1,121 @@
+//! The [hdmx (Horizontal Device Metrics)](https://learn.microsoft.com/en-us/typography/opentype/spec/hdmx) table
+
+include!("../../generated/generated_hdmx.rs");
+
+impl<'a> Hdmx<'a> {
+    /// Returns the device record for the given size in pixels per em.
+    pub fn record_for_size(&self, ppem: u8) -> Option<DeviceRecord<'a>> {
+        self.records()
+            .iter()
+            .filter_map(|record| record.ok())
+            .find(|record| record.pixel_size == ppem)
+    }
+}
+
+/// The advance widths of all glyphs at a single size.
+#[derive(Clone, Debug)]
+pub struct DeviceRecord<'a> {
+    /// Pixel size for following widths (as ppem).
+    pub pixel_size: u8,
+    /// Maximum width.
+    pub max_width: u8,
+    /// Array of widths, indexed by glyph id.
+    pub widths: &'a [u8],
+}
+
+impl DeviceRecord<'_> {
+    /// Returns the advance width in pixels of the given glyph.
+    pub fn width(&self, glyph_id: GlyphId) -> Option<u8> {
+        self.widths.get(glyph_id.to_u16() as usize).copied()
+    }
+}
+
+impl ReadArgs for DeviceRecord<'_> {
+    type Args = (u16, u32);
+}
+
+impl<'a> FontReadWithArgs<'a> for DeviceRecord<'a> {
+    fn read_with_args(data: FontData<'a>, args: &Self::Args) -> Result<Self, ReadError> {
+        let mut cursor = data.cursor();
+        let pixel_size = cursor.read()?;
+        let max_width = cursor.read()?;
+        let widths = cursor.read_array(args.0 as usize)?;
+        Ok(DeviceRecord {
+            pixel_size,
+            max_width,
+            widths,
+        })
+    }
+}
+
+impl ComputeSize for DeviceRecord<'_> {
+    fn compute_size(args: &(u16, u32)) -> Result<usize, ReadError> {
+        let (num_glyphs, size_device_record) = *args;
+        // records are padded to 32-bit alignment, but must at least hold
+        // the pixel size, maximum width and a width for each glyph
+        if (size_device_record as usize) < 2 + num_glyphs as usize {
+            return Err(ReadError::MalformedData("hdmx device record too small"));
+        }
+        Ok(size_device_record as usize)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeRecord<'a> for DeviceRecord<'a> {
+    fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "DeviceRecord",
+            data,
+            get_field: Box::new(move |idx, _data| match idx {
+                0 => Some(Field::new("pixel_size", self.pixel_size).with_record_range(0, 1)),
+                1 => Some(Field::new("max_width", self.max_width).with_record_range(1, 1)),
+                2 => {
+                    Some(Field::new("widths", self.widths).with_record_range(2, self.widths.len()))
+                }
+                _ => None,
+            }),
+            bytes: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn device_records() {
+        let data = BeBuffer::new()
+            .extend([0u16, 2])
+            .push(8u32)
+            // three glyphs and padding for each record
+            .extend([12u8, 9, 0, 7, 9, 0, 0, 0])
+            .extend([16u8, 12, 0, 9, 12, 0, 0, 0]);
+        let hdmx = Hdmx::read(data.font_data(), 3).unwrap();
+        assert_eq!(hdmx.records().len(), 2);
+        let record = hdmx.record_for_size(16).unwrap();
+        assert_eq!(record.max_width, 12);
+        assert_eq!(record.widths, [0, 9, 12]);
+        assert_eq!(record.width(GlyphId::new(1)), Some(9));
+        assert_eq!(record.width(GlyphId::new(3)), None);
+        assert!(hdmx.record_for_size(14).is_none());
+    }
+
+    #[test]
+    fn reject_small_device_records() {
+        for size_device_record in [0u32, 4] {
+            let data = BeBuffer::new()
+                .extend([0u16, 2])
+                .push(size_device_record)
+                .extend([12u8, 9, 0, 7, 9, 0, 0, 0]);
+            assert!(Hdmx::read(data.font_data(), 3).is_err());
+        }
+    }
+
+    #[test]
+    fn reject_truncated_records() {
+        let data = BeBuffer::new().extend([0u16, u16::MAX]).push(u32::MAX);
+        assert!(Hdmx::read(data.font_data(), 3).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 //! The [HVAR (Horizontal Metrics Variation)](https://docs.microsoft.com/en-us/typography/opentype/spec/hvar) table
 
-use super::variations::{self, DeltaSetIndexMap, ItemVariationStore};
+use super::variations::{self, DeltaSetIndex, DeltaSetIndexMap, ItemVariationStore};
 
 include!("../../generated/generated_hvar.rs");
 
@@ -41,6 +41,33 @@ impl<'a> Hvar<'a> {
             coords,
         )
     }
+
+    /// Returns the delta set index of the advance width delta for the specified
+    /// glyph identifier.
+    ///
+    /// This can be used with an [ItemVariationStoreInstance](variations::ItemVariationStoreInstance)
+    /// to compute deltas for many glyphs at a single location.
+    pub fn advance_width_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::advance_delta_index(self.advance_width_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index of the left side bearing delta for the specified
+    /// glyph identifier.
+    ///
+    /// This can be used with an [ItemVariationStoreInstance](variations::ItemVariationStoreInstance)
+    /// to compute deltas for many glyphs at a single location.
+    pub fn lsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.lsb_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index of the right side bearing delta for the specified
+    /// glyph identifier.
+    ///
+    /// This can be used with an [ItemVariationStoreInstance](variations::ItemVariationStoreInstance)
+    /// to compute deltas for many glyphs at a single location.
+    pub fn rsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.rsb_mapping(), glyph_id)
+    }
 }
 
 #[cfg(test)]
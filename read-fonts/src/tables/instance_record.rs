@@ -64,19 +64,25 @@ impl<'a> FontReadWithArgs<'a> for InstanceRecord<'a> {
 
 impl ComputeSize for InstanceRecord<'_> {
     #[inline]
-    fn compute_size(args: &(u16, u16)) -> usize {
-        args.1 as usize
+    fn compute_size(args: &(u16, u16)) -> Result<usize, ReadError> {
+        Ok(args.1 as usize)
     }
 }
 
 #[cfg(feature = "traversal")]
 impl<'a> InstanceRecord<'a> {
     pub(crate) fn get_field(&self, idx: usize, _data: FontData<'a>) -> Option<Field<'a>> {
+        let coords_len = std::mem::size_of_val(self.coordinates);
         match idx {
-            0 => Some(Field::new("subfamily_name_id", self.subfamily_name_id)),
-            1 => Some(Field::new("flags", self.flags)),
-            2 => Some(Field::new("coordinates", self.coordinates)),
-            3 => Some(Field::new("post_script_name_id", self.post_script_name_id?)),
+            0 => Some(
+                Field::new("subfamily_name_id", self.subfamily_name_id).with_record_range(0, 2),
+            ),
+            1 => Some(Field::new("flags", self.flags).with_record_range(2, 2)),
+            2 => Some(Field::new("coordinates", self.coordinates).with_record_range(4, coords_len)),
+            3 => Some(
+                Field::new("post_script_name_id", self.post_script_name_id?)
+                    .with_record_range(4 + coords_len, 2),
+            ),
             _ => None,
         }
     }
@@ -89,6 +95,7 @@ impl<'a> SomeRecord<'a> for InstanceRecord<'a> {
             name: "InstanceRecord",
             data,
             get_field: Box::new(move |idx, data| self.get_field(idx, data)),
+            bytes: None,
         }
     }
 }
@@ -0,0 +1,73 @@
+//! The [JSTF (Justification)](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) table
+
+include!("../../generated/generated_jstf.rs");
+
+use super::gpos::PositionLookup;
+
+impl<'a> Jstf<'a> {
+    /// Returns the justification script table for the given script tag.
+    pub fn script(&self, tag: Tag) -> Option<Result<JstfScript<'a>, ReadError>> {
+        let records = self.jstf_script_records();
+        let index = records
+            .binary_search_by(|record| record.jstf_script_tag().cmp(&tag))
+            .ok()?;
+        Some(records[index].jstf_script(self.offset_data()))
+    }
+}
+
+impl<'a> JstfScript<'a> {
+    /// Returns the justification language system table for the given
+    /// language tag.
+    ///
+    /// This does not fall back to the default language system.
+    pub fn lang_sys(&self, tag: Tag) -> Option<Result<JstfLangSys<'a>, ReadError>> {
+        let records = self.jstf_lang_sys_records();
+        let index = records
+            .binary_search_by(|record| record.jstf_lang_sys_tag().cmp(&tag))
+            .ok()?;
+        Some(records[index].jstf_lang_sys(self.offset_data()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn script_and_priorities() {
+        let data = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            // one script record
+            .push(1u16)
+            .push(Tag::new(b"arab"))
+            .push(12u16)
+            // JstfScript: extender glyphs, default lang sys, no lang sys records
+            .extend([6u16, 12, 0])
+            // ExtenderGlyph
+            .extend([2u16, 10, 11])
+            // JstfLangSys with one priority
+            .extend([1u16, 4])
+            // JstfPriority: gsub shrinkage enable only
+            .extend([20u16, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+            // JstfGSUBModList
+            .extend([2u16, 3, 5]);
+        let jstf = Jstf::read(data.font_data()).unwrap();
+        assert!(jstf.script(Tag::new(b"latn")).is_none());
+        let script = jstf.script(Tag::new(b"arab")).unwrap().unwrap();
+        let extenders = script.extender_glyph().unwrap().unwrap();
+        assert_eq!(
+            extenders.extender_glyphs(),
+            [GlyphId::new(10), GlyphId::new(11)].map(BigEndian::from)
+        );
+        assert!(script.lang_sys(Tag::new(b"URD ")).is_none());
+        let lang_sys = script.def_jstf_lang_sys().unwrap().unwrap();
+        let priority = lang_sys.jstf_priorities().get(0).unwrap();
+        assert!(priority.gpos_shrinkage_enable().is_none());
+        let mod_list = priority.gsub_shrinkage_enable().unwrap().unwrap();
+        assert_eq!(
+            mod_list.gsub_lookup_indices(),
+            [3u16, 5].map(BigEndian::from)
+        );
+    }
+}
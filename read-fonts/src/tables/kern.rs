@@ -0,0 +1,621 @@
+//! The [kern (Kerning)](https://learn.microsoft.com/en-us/typography/opentype/spec/kern) table
+
+include!("../../generated/generated_kern.rs");
+
+impl TopLevelTable for Kern<'_> {
+    const TAG: Tag = Tag::new(b"kern");
+}
+
+impl<'a> Kern<'a> {
+    /// Returns an iterator over all of the subtables in this `kern` table.
+    pub fn subtables(&self) -> impl Iterator<Item = Result<Subtable<'a>, ReadError>> + 'a {
+        let (is_aat, n_tables, data) = match self {
+            Self::Ot(kern) => (false, kern.n_tables() as u32, kern.subtable_data()),
+            Self::Aat(kern) => (true, kern.n_tables(), kern.subtable_data()),
+        };
+        Subtables {
+            data: FontData::new(data),
+            is_aat,
+            remaining: n_tables,
+        }
+    }
+}
+
+impl<'a> OtKern<'a> {
+    #[cfg(feature = "traversal")]
+    fn traverse_subtables(&self) -> FieldType<'a> {
+        traverse_subtables(Kern::Ot(self.clone()))
+    }
+}
+
+impl<'a> AatKern<'a> {
+    #[cfg(feature = "traversal")]
+    fn traverse_subtables(&self) -> FieldType<'a> {
+        traverse_subtables(Kern::Aat(self.clone()))
+    }
+}
+
+/// Iterator over the subtables of a `kern` table.
+struct Subtables<'a> {
+    data: FontData<'a>,
+    is_aat: bool,
+    remaining: u32,
+}
+
+impl<'a> Iterator for Subtables<'a> {
+    type Item = Result<Subtable<'a>, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 || self.data.is_empty() {
+            return None;
+        }
+        self.remaining -= 1;
+        let len = if self.is_aat {
+            self.data.read_at::<u32>(0).map(|len| len as usize)
+        } else {
+            self.ot_subtable_len()
+        };
+        let header_len = if self.is_aat {
+            AAT_SUBTABLE_HEADER_LEN
+        } else {
+            OT_SUBTABLE_HEADER_LEN
+        };
+        let len = match len {
+            // a subtable that is smaller than its header would never
+            // advance the iterator
+            Ok(len) if len < header_len => {
+                self.remaining = 0;
+                return Some(Err(ReadError::MalformedData("kern subtable too short")));
+            }
+            Ok(len) => len.min(self.data.len()),
+            Err(e) => {
+                self.remaining = 0;
+                return Some(Err(e));
+            }
+        };
+        let data = self.data.slice(..len)?;
+        self.data = self.data.split_off(len)?;
+        Some(if self.is_aat {
+            AatSubtable::read(data).map(Subtable::Aat)
+        } else {
+            OtSubtable::read(data).map(Subtable::Ot)
+        })
+    }
+}
+
+impl Subtables<'_> {
+    /// Returns the length of the next OpenType subtable.
+    ///
+    /// The 16-bit length field overflows for large format 0 subtables in
+    /// some fonts, so the length of those is computed from the number of
+    /// pairs instead.
+    fn ot_subtable_len(&self) -> Result<usize, ReadError> {
+        let len = self.data.read_at::<u16>(2)? as usize;
+        let format = self.data.read_at::<u16>(4)? >> 8;
+        if format == 0 {
+            let n_pairs = self.data.read_at::<u16>(OT_SUBTABLE_HEADER_LEN)? as usize;
+            Ok(OT_SUBTABLE_HEADER_LEN + 8 + n_pairs * Subtable0Pair::RAW_BYTE_LEN)
+        } else {
+            Ok(len)
+        }
+    }
+}
+
+/// Size of the header of a subtable in an OpenType `kern` table.
+const OT_SUBTABLE_HEADER_LEN: usize = 6;
+
+/// Size of the header of a subtable in an Apple `kern` table.
+const AAT_SUBTABLE_HEADER_LEN: usize = 8;
+
+/// A subtable in either an OpenType or Apple `kern` table.
+#[derive(Clone)]
+pub enum Subtable<'a> {
+    Ot(OtSubtable<'a>),
+    Aat(AatSubtable<'a>),
+}
+
+impl<'a> Subtable<'a> {
+    /// Returns the format of the subtable.
+    pub fn format(&self) -> u8 {
+        match self {
+            Self::Ot(subtable) => (subtable.coverage() >> 8) as u8,
+            Self::Aat(subtable) => subtable.coverage() as u8,
+        }
+    }
+
+    /// True if the subtable contains horizontal kerning values.
+    pub fn is_horizontal(&self) -> bool {
+        match self {
+            Self::Ot(subtable) => subtable.coverage() & (1 << 0) != 0,
+            Self::Aat(subtable) => subtable.coverage() & 0x8000 == 0,
+        }
+    }
+
+    /// True if the subtable contains minimum values rather than kerning
+    /// values.
+    ///
+    /// This is only defined for OpenType `kern` tables.
+    pub fn is_minimum(&self) -> bool {
+        match self {
+            Self::Ot(subtable) => subtable.coverage() & (1 << 1) != 0,
+            Self::Aat(_) => false,
+        }
+    }
+
+    /// True if kerning is perpendicular to the flow of the text.
+    pub fn is_cross_stream(&self) -> bool {
+        match self {
+            Self::Ot(subtable) => subtable.coverage() & (1 << 2) != 0,
+            Self::Aat(subtable) => subtable.coverage() & 0x4000 != 0,
+        }
+    }
+
+    /// True if the values in this subtable replace the accumulated value
+    /// rather than being added to it.
+    ///
+    /// This is only defined for OpenType `kern` tables.
+    pub fn is_override(&self) -> bool {
+        match self {
+            Self::Ot(subtable) => subtable.coverage() & (1 << 3) != 0,
+            Self::Aat(_) => false,
+        }
+    }
+
+    /// True if the subtable contains variation kerning values.
+    ///
+    /// This is only defined for Apple `kern` tables.
+    pub fn is_variable(&self) -> bool {
+        match self {
+            Self::Ot(_) => false,
+            Self::Aat(subtable) => subtable.coverage() & 0x2000 != 0,
+        }
+    }
+
+    /// Returns the tuple index for variation subtables.
+    ///
+    /// This is only defined for Apple `kern` tables.
+    pub fn tuple_index(&self) -> Option<u16> {
+        match self {
+            Self::Ot(_) => None,
+            Self::Aat(subtable) => Some(subtable.tuple_index()),
+        }
+    }
+
+    /// Returns the format specific data of the subtable.
+    pub fn kind(&self) -> Result<SubtableKind<'a>, ReadError> {
+        let (data, header_len) = match self {
+            Self::Ot(subtable) => (subtable.offset_data(), OT_SUBTABLE_HEADER_LEN),
+            Self::Aat(subtable) => (subtable.offset_data(), AAT_SUBTABLE_HEADER_LEN),
+        };
+        let body = data.split_off(header_len).ok_or(ReadError::OutOfBounds)?;
+        match self.format() {
+            0 => Subtable0::read(body).map(SubtableKind::Format0),
+            1 => Subtable1::read(body).map(SubtableKind::Format1),
+            2 => Subtable2::read(data, header_len as u16).map(SubtableKind::Format2),
+            3 => Subtable3::read(body).map(SubtableKind::Format3),
+            format => Err(ReadError::InvalidFormat(format as _)),
+        }
+    }
+}
+
+impl<'a> OtSubtable<'a> {
+    #[cfg(feature = "traversal")]
+    fn traverse_kind(&self) -> FieldType<'a> {
+        traverse_kind(Subtable::Ot(self.clone()))
+    }
+}
+
+impl<'a> AatSubtable<'a> {
+    #[cfg(feature = "traversal")]
+    fn traverse_kind(&self) -> FieldType<'a> {
+        traverse_kind(Subtable::Aat(self.clone()))
+    }
+}
+
+/// The format specific data of a `kern` subtable.
+#[derive(Clone)]
+pub enum SubtableKind<'a> {
+    Format0(Subtable0<'a>),
+    Format1(Subtable1<'a>),
+    Format2(Subtable2<'a>),
+    Format3(Subtable3<'a>),
+}
+
+impl SubtableKind<'_> {
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    ///
+    /// Format 1 subtables are contextual and always return `None`.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        match self {
+            Self::Format0(subtable) => subtable.kerning(left, right),
+            Self::Format1(_) => None,
+            Self::Format2(subtable) => subtable.kerning(left, right),
+            Self::Format3(subtable) => subtable.kerning(left, right),
+        }
+    }
+}
+
+impl Subtable0<'_> {
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        let pairs = self.pairs();
+        let key = (left, right);
+        pairs
+            .binary_search_by_key(&key, |pair| (pair.left(), pair.right()))
+            .ok()
+            .map(|ix| pairs[ix].value().to_i16() as i32)
+    }
+}
+
+/// Entry in the state table of a format 1 subtable.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct StateEntry {
+    /// Index of the next state.
+    pub new_state: u16,
+    /// Flags for the entry.
+    pub flags: u16,
+}
+
+impl StateEntry {
+    /// Push the current glyph onto the kerning stack.
+    pub const PUSH: u16 = 0x8000;
+    /// Don't advance to the next glyph before going to the new state.
+    pub const DONT_ADVANCE: u16 = 0x4000;
+    const VALUE_OFFSET_MASK: u16 = 0x3FFF;
+
+    /// Returns the offset from the beginning of the state table to the
+    /// kerning values to apply, if any.
+    pub fn value_offset(&self) -> Option<u16> {
+        let offset = self.flags & Self::VALUE_OFFSET_MASK;
+        (offset != 0).then_some(offset)
+    }
+}
+
+impl<'a> Subtable1<'a> {
+    /// Class for glyphs that are outside of the range of the class table.
+    pub const CLASS_OUT_OF_BOUNDS: u8 = 1;
+
+    /// Returns the class of the given glyph.
+    pub fn class(&self, glyph_id: GlyphId) -> u8 {
+        self.class_table()
+            .ok()
+            .and_then(|table| {
+                let ix = glyph_id
+                    .to_u16()
+                    .checked_sub(table.first_glyph().to_u16())?;
+                table.class_array().get(ix as usize).copied()
+            })
+            .unwrap_or(Self::CLASS_OUT_OF_BOUNDS)
+    }
+
+    /// Returns the entry for the given state and class.
+    pub fn entry(&self, state: u16, class: u8) -> Result<StateEntry, ReadError> {
+        let data = self.offset_data();
+        let n_classes = self.n_classes() as usize;
+        if class as usize >= n_classes {
+            return Err(ReadError::OutOfBounds);
+        }
+        let state_array_offset = self.state_array_offset() as usize;
+        let entry_ix: u8 =
+            data.read_at(state_array_offset + state as usize * n_classes + class as usize)?;
+        let entry_offset = self.entry_table_offset() as usize + entry_ix as usize * 4;
+        // the new state is stored as a byte offset to the row in the state
+        // array
+        let new_state_offset: u16 = data.read_at(entry_offset)?;
+        let new_state = (new_state_offset as usize)
+            .checked_sub(state_array_offset)
+            .ok_or(ReadError::OutOfBounds)?
+            / n_classes.max(1);
+        Ok(StateEntry {
+            new_state: new_state as u16,
+            flags: data.read_at(entry_offset + 2)?,
+        })
+    }
+
+    /// Returns an iterator over the kerning values at the given offset from
+    /// the beginning of the state table.
+    ///
+    /// Each value applies to a glyph popped from the kerning stack. The list
+    /// is terminated by a value with the lowest bit set, which is cleared
+    /// in the returned value.
+    pub fn values(&self, offset: u16) -> impl Iterator<Item = i16> + 'a {
+        let data = self.offset_data();
+        let mut pos = offset as usize;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let value: i16 = data.read_at(pos).ok()?;
+            pos += 2;
+            done = value & 1 != 0;
+            Some(value & !1)
+        })
+    }
+}
+
+impl Subtable2<'_> {
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        let class_offset = |table: Result<Subtable2ClassTable, ReadError>, glyph_id: GlyphId| {
+            let table = table.ok()?;
+            let ix = glyph_id
+                .to_u16()
+                .checked_sub(table.first_glyph().to_u16())?;
+            table.offsets().get(ix as usize).map(|offset| offset.get())
+        };
+        let left_offset = class_offset(self.left_class_table(), left)? as usize;
+        let right_offset = class_offset(self.right_class_table(), right)? as usize;
+        // the left class values include the offset to the kerning array
+        let offset = left_offset + right_offset;
+        if offset < self.array_offset() as usize {
+            return None;
+        }
+        self.offset_data()
+            .read_at::<i16>(offset)
+            .ok()
+            .map(|value| value as i32)
+    }
+}
+
+impl Subtable3<'_> {
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        let left_class = *self.left_class().get(left.to_u16() as usize)? as usize;
+        let right_class = *self.right_class().get(right.to_u16() as usize)? as usize;
+        let right_class_count = self.right_class_count() as usize;
+        if left_class >= self.left_class_count() as usize || right_class >= right_class_count {
+            return None;
+        }
+        let index = *self
+            .kern_index()
+            .get(left_class * right_class_count + right_class)? as usize;
+        self.kern_value()
+            .get(index)
+            .map(|value| value.get().to_i16() as i32)
+    }
+}
+
+#[cfg(feature = "traversal")]
+fn traverse_subtables(kern: Kern) -> FieldType {
+    struct SubtableArray<'a>(Vec<(u32, Result<Subtable<'a>, ReadError>)>);
+
+    impl<'a> traversal::SomeArray<'a> for SubtableArray<'a> {
+        fn type_name(&self) -> &str {
+            "Subtable"
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn get(&self, idx: usize) -> Option<FieldType<'a>> {
+            let (offset, subtable) = self.0.get(idx)?;
+            Some(FieldType::offset(Offset32::new(*offset), subtable.clone()))
+        }
+    }
+
+    let header_len = match &kern {
+        Kern::Ot(_) => 4,
+        Kern::Aat(_) => 8,
+    };
+    // subtables are stored sequentially, so record the position of each
+    // relative to the start of the table
+    let mut offset = header_len;
+    let subtables = kern
+        .subtables()
+        .map(|subtable| {
+            let this_offset = offset;
+            if let Ok(subtable) = &subtable {
+                offset += subtable.offset_data().len() as u32;
+            }
+            (this_offset, subtable)
+        })
+        .collect();
+    SubtableArray(subtables).into()
+}
+
+#[cfg(feature = "traversal")]
+fn traverse_kind(subtable: Subtable) -> FieldType {
+    let offset = match &subtable {
+        Subtable::Ot(_) => OT_SUBTABLE_HEADER_LEN,
+        Subtable::Aat(_) => AAT_SUBTABLE_HEADER_LEN,
+    };
+    // format 2 offsets are relative to the subtable header
+    let offset = match subtable.format() {
+        2 => 0,
+        _ => offset,
+    };
+    FieldType::offset(Offset16::new(offset as u16), subtable.kind())
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> Subtable<'a> {
+    fn offset_data(&self) -> FontData<'a> {
+        match self {
+            Self::Ot(subtable) => subtable.offset_data(),
+            Self::Aat(subtable) => subtable.offset_data(),
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> Subtable<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Ot(table) => table,
+            Self::Aat(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for Subtable<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SubtableKind<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Format0(table) => table,
+            Self::Format1(table) => table,
+            Self::Format2(table) => table,
+            Self::Format3(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for SubtableKind<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn ot_format_0_and_2() {
+        let data = BeBuffer::new()
+            // header: version, nTables
+            .extend([0u16, 2])
+            // format 0 subtable: version, length (intentionally wrong),
+            // coverage
+            .extend([0u16, 10, 0x0001])
+            // nPairs, searchRange, entrySelector, rangeShift
+            .extend([2u16, 12, 1, 0])
+            // pairs
+            .extend([4u16, 5])
+            .push(-40i16)
+            .extend([4u16, 7])
+            .push(30i16)
+            // format 2 subtable: version, length, coverage
+            .extend([0u16, 38, 0x0201])
+            // rowWidth, leftClassTable, rightClassTable, array
+            .extend([4u16, 14, 22, 30])
+            // left class table: first glyph, count, row offsets
+            .extend([10u16, 2, 30, 34])
+            // right class table: first glyph, count, column offsets
+            .extend([20u16, 2, 0, 2])
+            // kerning array
+            .extend([-10i16, -20, -30, -40]);
+        let kern = Kern::read(data.font_data()).unwrap();
+        assert!(matches!(kern, Kern::Ot(_)));
+        let subtables = kern.subtables().map(|s| s.unwrap()).collect::<Vec<_>>();
+        assert_eq!(subtables.len(), 2);
+        assert!(subtables.iter().all(|s| s.is_horizontal()));
+        assert!(!subtables[0].is_cross_stream());
+        assert_eq!(subtables[0].format(), 0);
+        assert_eq!(subtables[1].format(), 2);
+        let kerning = |ix: usize, left: u16, right: u16| {
+            subtables[ix]
+                .kind()
+                .unwrap()
+                .kerning(GlyphId::new(left), GlyphId::new(right))
+        };
+        assert_eq!(kerning(0, 4, 5), Some(-40));
+        assert_eq!(kerning(0, 4, 7), Some(30));
+        assert_eq!(kerning(0, 4, 6), None);
+        assert_eq!(kerning(1, 10, 20), Some(-10));
+        assert_eq!(kerning(1, 10, 21), Some(-20));
+        assert_eq!(kerning(1, 11, 20), Some(-30));
+        assert_eq!(kerning(1, 11, 21), Some(-40));
+        assert_eq!(kerning(1, 12, 21), None);
+    }
+
+    #[test]
+    fn aat_format_1_and_3() {
+        let data = BeBuffer::new()
+            // header: version, nTables
+            .push(MajorMinor::VERSION_1_0)
+            .push(2u32)
+            // format 3 subtable: length, coverage, tupleIndex
+            .push(30u32)
+            .extend([0x0003u16, 0])
+            // glyphCount, kernValueCount, leftClassCount, rightClassCount,
+            // flags
+            .push(4u16)
+            .extend([2u8, 2, 2, 0])
+            // kernValue
+            .extend([0i16, -50])
+            // leftClass, rightClass, kernIndex
+            .extend([0u8, 1, 0, 1])
+            .extend([0u8, 0, 1, 1])
+            .extend([0u8, 0, 0, 1])
+            // format 1 subtable: length, coverage, tupleIndex
+            .push(48u32)
+            .extend([0x0001u16, 0])
+            // nClasses, classTable, stateArray, entryTable, valueTable
+            .extend([5u16, 10, 16, 26, 38])
+            // class table: first glyph, count, classes
+            .extend([4u16, 2])
+            .extend([4u8, 4])
+            // state array: two states
+            .extend([0u8, 0, 0, 0, 1])
+            .extend([0u8, 0, 0, 0, 2])
+            // entries: new state offset, flags
+            .extend([16u16, 0])
+            .extend([21u16, StateEntry::PUSH])
+            .extend([16u16, StateEntry::PUSH | 38])
+            // value table, with the end of list bit set
+            .push(-19i16);
+        let kern = Kern::read(data.font_data()).unwrap();
+        assert!(matches!(kern, Kern::Aat(_)));
+        let subtables = kern.subtables().map(|s| s.unwrap()).collect::<Vec<_>>();
+        assert_eq!(subtables.len(), 2);
+        assert_eq!(subtables[0].tuple_index(), Some(0));
+        let format3 = subtables[0].kind().unwrap();
+        let kerning =
+            |left: u16, right: u16| format3.kerning(GlyphId::new(left), GlyphId::new(right));
+        assert_eq!(kerning(1, 2), Some(-50));
+        assert_eq!(kerning(1, 0), Some(0));
+        assert_eq!(kerning(4, 0), None);
+        let SubtableKind::Format1(format1) = subtables[1].kind().unwrap() else {
+            panic!("expected format 1 subtable");
+        };
+        assert_eq!(format1.class(GlyphId::new(5)), 4);
+        assert_eq!(
+            format1.class(GlyphId::new(9)),
+            Subtable1::CLASS_OUT_OF_BOUNDS
+        );
+        let entry = format1.entry(0, 4).unwrap();
+        assert_eq!(entry.new_state, 1);
+        assert_eq!(entry.flags, StateEntry::PUSH);
+        assert_eq!(entry.value_offset(), None);
+        let entry = format1.entry(1, 4).unwrap();
+        assert_eq!(entry.new_state, 0);
+        let values = format1
+            .values(entry.value_offset().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, [-20]);
+    }
+
+    #[test]
+    fn zero_length_subtable() {
+        let data = BeBuffer::new()
+            // header: version, nTables
+            .push(MajorMinor::VERSION_1_0)
+            .push(u32::MAX)
+            // subtable: length, coverage, tupleIndex
+            .push(0u32)
+            .extend([0x0001u16, 0]);
+        let kern = Kern::read(data.font_data()).unwrap();
+        let subtables = kern.subtables().collect::<Vec<_>>();
+        assert_eq!(subtables.len(), 1);
+        assert!(subtables[0].is_err());
+    }
+}
@@ -0,0 +1,394 @@
+//! The [kerx (Extended Kerning)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kerx.html) table
+
+include!("../../generated/generated_kerx.rs");
+
+use super::aat::{sequential, ExtendedStateTable, Lookup, RawBytes, RawWords};
+
+/// Size of the header of a `kerx` table.
+#[cfg(feature = "traversal")]
+const HEADER_LEN: usize = 8;
+
+/// Size of the header of a subtable in a `kerx` table.
+const SUBTABLE_HEADER_LEN: usize = 12;
+
+impl<'a> Kerx<'a> {
+    /// Returns an iterator over all of the subtables in this `kerx` table.
+    pub fn subtables(&self) -> impl Iterator<Item = Result<Subtable<'a>, ReadError>> + 'a {
+        sequential(
+            FontData::new(self.subtable_data()),
+            self.n_tables(),
+            0,
+            SUBTABLE_HEADER_LEN,
+        )
+        .map(|(_, subtable)| subtable)
+    }
+
+    #[cfg(feature = "traversal")]
+    fn traverse_subtables(&self) -> FieldType<'a> {
+        let subtables = sequential(
+            FontData::new(self.subtable_data()),
+            self.n_tables(),
+            0,
+            SUBTABLE_HEADER_LEN,
+        );
+        super::aat::SequentialArray::<Subtable>::new("Subtable", HEADER_LEN, subtables).into()
+    }
+}
+
+impl<'a> Subtable<'a> {
+    /// Set if the subtable contains vertical kerning values.
+    pub const VERTICAL: u32 = 0x80000000;
+    /// Set if the subtable contains cross-stream kerning values.
+    pub const CROSS_STREAM: u32 = 0x40000000;
+    /// Set if the subtable contains variation kerning values.
+    pub const VARIATION: u32 = 0x20000000;
+
+    /// Returns the format of the subtable.
+    pub fn format(&self) -> u8 {
+        self.coverage() as u8
+    }
+
+    /// True if the subtable contains horizontal kerning values.
+    pub fn is_horizontal(&self) -> bool {
+        self.coverage() & Self::VERTICAL == 0
+    }
+
+    /// True if kerning is perpendicular to the flow of the text.
+    pub fn is_cross_stream(&self) -> bool {
+        self.coverage() & Self::CROSS_STREAM != 0
+    }
+
+    /// True if the subtable contains variation kerning values.
+    pub fn is_variable(&self) -> bool {
+        self.coverage() & Self::VARIATION != 0
+    }
+
+    /// Returns the format specific data of the subtable.
+    pub fn kind(&self) -> Result<SubtableKind<'a>, ReadError> {
+        let data = self.offset_data();
+        let body = FontData::new(self.data());
+        match self.format() {
+            0 => Subtable0::read(body).map(SubtableKind::Format0),
+            1 => Subtable1::read(body).map(SubtableKind::Format1),
+            2 => Subtable2::read(data).map(SubtableKind::Format2),
+            4 => Subtable4::read(body).map(SubtableKind::Format4),
+            6 => Subtable6::read(data).map(SubtableKind::Format6),
+            format => Err(ReadError::InvalidFormat(format as _)),
+        }
+    }
+
+    #[cfg(feature = "traversal")]
+    fn traverse_kind(&self) -> FieldType<'a> {
+        // format 2 and 6 offsets are relative to the subtable header
+        let offset = match self.format() {
+            2 | 6 => 0,
+            _ => SUBTABLE_HEADER_LEN as u32,
+        };
+        FieldType::offset(Offset32::new(offset), self.kind())
+    }
+}
+
+/// The format specific data of a `kerx` subtable.
+#[derive(Clone)]
+pub enum SubtableKind<'a> {
+    Format0(Subtable0<'a>),
+    Format1(Subtable1<'a>),
+    Format2(Subtable2<'a>),
+    Format4(Subtable4<'a>),
+    Format6(Subtable6<'a>),
+}
+
+impl SubtableKind<'_> {
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    ///
+    /// Format 1 and 4 subtables are contextual and always return `None`.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        match self {
+            Self::Format0(subtable) => subtable.kerning(left, right),
+            Self::Format1(_) | Self::Format4(_) => None,
+            Self::Format2(subtable) => subtable.kerning(left, right),
+            Self::Format6(subtable) => subtable.kerning(left, right),
+        }
+    }
+}
+
+impl Subtable0<'_> {
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        let pairs = self.pairs();
+        let key = (left, right);
+        pairs
+            .binary_search_by_key(&key, |pair| (pair.left(), pair.right()))
+            .ok()
+            .map(|ix| pairs[ix].value().to_i16() as i32)
+    }
+}
+
+impl<'a> Subtable1<'a> {
+    /// Push the current glyph onto the kerning stack.
+    pub const PUSH: u16 = 0x8000;
+    /// Don't advance to the next glyph before going to the new state.
+    pub const DONT_ADVANCE: u16 = 0x4000;
+    /// Reset the cross-stream kerning shift.
+    pub const RESET: u16 = 0x2000;
+
+    /// Returns the state table for this subtable.
+    pub fn state_table(&self) -> Result<ExtendedStateTable<'a, Subtable1EntryData>, ReadError> {
+        ExtendedStateTable::read(self.offset_data())
+    }
+
+    /// Returns an iterator over the kerning values starting at the given
+    /// index.
+    ///
+    /// Each value applies to a glyph popped from the kerning stack. The list
+    /// is terminated by a value with the lowest bit set, which is cleared
+    /// in the returned value.
+    pub fn values(&self, index: u16) -> impl Iterator<Item = i16> + 'a {
+        let data = self.offset_data();
+        let mut pos = self.value_table_offset() as usize + index as usize * 2;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let value: i16 = data.read_at(pos).ok()?;
+            pos += 2;
+            done = value & 1 != 0;
+            Some(value & !1)
+        })
+    }
+}
+
+impl Subtable2<'_> {
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        // glyphs that are not covered by a class table use class zero
+        let class = |table: Result<Lookup, ReadError>, glyph_id: GlyphId| {
+            table
+                .ok()
+                .and_then(|table| table.value::<u16>(glyph_id))
+                .unwrap_or_default()
+        };
+        let left_class = class(self.left_class_table(), left) as usize;
+        let right_class = class(self.right_class_table(), right) as usize;
+        let offset = self.array_offset() as usize + (left_class + right_class) * 2;
+        self.offset_data()
+            .read_at::<i16>(offset)
+            .ok()
+            .map(|value| value as i32)
+    }
+}
+
+impl<'a> Subtable4<'a> {
+    /// Mark the current glyph.
+    pub const MARK: u16 = 0x8000;
+    /// Don't advance to the next glyph before going to the new state.
+    pub const DONT_ADVANCE: u16 = 0x4000;
+
+    /// Returns the state table for this subtable.
+    pub fn state_table(&self) -> Result<ExtendedStateTable<'a, Subtable4EntryData>, ReadError> {
+        ExtendedStateTable::read(self.offset_data())
+    }
+
+    /// Returns the type of the actions in this subtable.
+    ///
+    /// This is 0 for control point actions, 1 for anchor point actions and
+    /// 2 for coordinate actions.
+    pub fn action_type(&self) -> u8 {
+        (self.flags() >> 30) as u8
+    }
+
+    /// Returns the action at the given index.
+    pub fn action(&self, index: u16) -> Result<Subtable4Action, ReadError> {
+        let data = self.offset_data();
+        let base = (self.flags() & 0x00FFFFFF) as usize;
+        match self.action_type() {
+            0 | 1 => {
+                let pos = base + index as usize * 4;
+                let marked = data.read_at::<u16>(pos)?;
+                let current = data.read_at::<u16>(pos + 2)?;
+                Ok(if self.action_type() == 0 {
+                    Subtable4Action::ControlPoint { marked, current }
+                } else {
+                    Subtable4Action::AnchorPoint { marked, current }
+                })
+            }
+            2 => {
+                let pos = base + index as usize * 8;
+                Ok(Subtable4Action::Coordinates {
+                    marked: (data.read_at(pos)?, data.read_at(pos + 2)?),
+                    current: (data.read_at(pos + 4)?, data.read_at(pos + 6)?),
+                })
+            }
+            other => Err(ReadError::InvalidFormat(other as _)),
+        }
+    }
+}
+
+/// An action in a format 4 `kerx` subtable, which attaches the current
+/// glyph to the marked glyph.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Subtable4Action {
+    /// Indices of the control points to align.
+    ControlPoint { marked: u16, current: u16 },
+    /// Indices of the anchor points, in the `ankr` table, to align.
+    AnchorPoint { marked: u16, current: u16 },
+    /// Coordinates of the points to align.
+    Coordinates {
+        marked: (i16, i16),
+        current: (i16, i16),
+    },
+}
+
+impl Subtable6<'_> {
+    /// Set if the lookup tables and kerning values are 32-bit.
+    pub const VALUES_ARE_LONG: u32 = 0x00000001;
+
+    /// Returns the kerning adjustment for the given pair of glyphs.
+    pub fn kerning(&self, left: GlyphId, right: GlyphId) -> Option<i32> {
+        let data = self.offset_data();
+        let array_offset = self.kerning_array_offset() as usize;
+        if self.flags() & Self::VALUES_ARE_LONG != 0 {
+            let index = |table: Result<Lookup, ReadError>, glyph_id: GlyphId| {
+                table
+                    .ok()
+                    .and_then(|table| table.value::<u32>(glyph_id))
+                    .unwrap_or_default() as usize
+            };
+            let ix = index(self.row_index_table(), left) + index(self.column_index_table(), right);
+            data.read_at::<i32>(array_offset + ix * 4).ok()
+        } else {
+            let index = |table: Result<Lookup, ReadError>, glyph_id: GlyphId| {
+                table
+                    .ok()
+                    .and_then(|table| table.value::<u16>(glyph_id))
+                    .unwrap_or_default() as usize
+            };
+            let ix = index(self.row_index_table(), left) + index(self.column_index_table(), right);
+            data.read_at::<i16>(array_offset + ix * 2)
+                .ok()
+                .map(|value| value as i32)
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SubtableKind<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Format0(table) => table,
+            Self::Format1(table) => table,
+            Self::Format2(table) => table,
+            Self::Format4(table) => table,
+            Self::Format6(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for SubtableKind<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn format_0_2_and_6() {
+        let data = BeBuffer::new()
+            // header: version, padding, nTables
+            .extend([2u16, 0])
+            .push(3u32)
+            // format 0 subtable: length, coverage, tupleCount
+            .extend([34u32, 0, 0])
+            // nPairs, searchRange, entrySelector, rangeShift
+            .extend([1u32, 6, 0, 0])
+            // pair
+            .extend([4u16, 5])
+            .push(-40i16)
+            // format 2 subtable: length, coverage, tupleCount
+            .extend([60u32, 0x40000002, 0])
+            // rowWidth, leftClassTable, rightClassTable, array
+            .extend([4u32, 28, 40, 52])
+            // left class table: format 8 from glyph 10, index of rows
+            .extend([8u16, 10, 2, 0, 2])
+            .push(0u16)
+            // right class table: format 8 from glyph 20, index of columns
+            .extend([8u16, 20, 2, 0, 1])
+            .push(0u16)
+            // kerning array
+            .extend([-10i16, -20, -30, -40])
+            // format 6 subtable: length, coverage, tupleCount
+            .extend([68u32, 0x80000006, 0])
+            // flags, rowCount, columnCount
+            .push(0u32)
+            .extend([1u16, 2])
+            // rowIndexTable, columnIndexTable, kerningArray, kerningVector
+            .extend([36u32, 52, 64, 0])
+            // row index table: format 6 lookup with a single glyph
+            .extend([6u16, 4, 1, 4, 0, 0])
+            .extend([3u16, 0])
+            // column index table: format 8 from glyph 7
+            .extend([8u16, 7, 2, 0, 1])
+            .push(0u16)
+            // kerning array
+            .extend([15i16, 25]);
+        let kerx = Kerx::read(data.font_data()).unwrap();
+        let subtables = kerx.subtables().map(|s| s.unwrap()).collect::<Vec<_>>();
+        assert_eq!(subtables.len(), 3);
+        assert!(subtables[1].is_cross_stream());
+        assert!(!subtables[2].is_horizontal());
+        let kerning = |ix: usize, left: u16, right: u16| {
+            subtables[ix]
+                .kind()
+                .unwrap()
+                .kerning(GlyphId::new(left), GlyphId::new(right))
+        };
+        assert_eq!(kerning(0, 4, 5), Some(-40));
+        assert_eq!(kerning(0, 4, 6), None);
+        assert_eq!(kerning(1, 10, 20), Some(-10));
+        assert_eq!(kerning(1, 10, 21), Some(-20));
+        assert_eq!(kerning(1, 11, 20), Some(-30));
+        assert_eq!(kerning(1, 11, 21), Some(-40));
+        assert_eq!(kerning(2, 3, 7), Some(15));
+        assert_eq!(kerning(2, 3, 8), Some(25));
+    }
+
+    #[test]
+    fn format_4() {
+        let data = BeBuffer::new()
+            // header: version, padding, nTables
+            .extend([2u16, 0])
+            .push(1u32)
+            // format 4 subtable: length, coverage, tupleCount
+            .extend([44u32, 4, 0])
+            // nClasses, classTable, stateArray, entryTable
+            .extend([4u32, 20, 20, 20])
+            // flags: anchor point actions at offset 28
+            .push(0x4000001Cu32)
+            // padding, then an action
+            .extend([0u16, 0, 0, 0])
+            .extend([1u16, 2]);
+        let kerx = Kerx::read(data.font_data()).unwrap();
+        let subtable = kerx.subtables().next().unwrap().unwrap();
+        let SubtableKind::Format4(format4) = subtable.kind().unwrap() else {
+            panic!("expected format 4 subtable");
+        };
+        assert_eq!(format4.action_type(), 1);
+        assert_eq!(
+            format4.action(0).unwrap(),
+            Subtable4Action::AnchorPoint {
+                marked: 1,
+                current: 2
+            }
+        );
+    }
+}
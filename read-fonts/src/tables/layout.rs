@@ -5,6 +5,9 @@ mod lookupflag;
 
 pub use lookupflag::LookupFlag;
 
+#[cfg(feature = "std")]
+use crate::collections::IntSet;
+
 #[cfg(test)]
 #[path = "../tests/layout.rs"]
 mod tests;
@@ -77,7 +80,129 @@ impl FeatureTableSubstitutionRecord {
     }
 }
 
+impl<'a> FeatureVariations<'a> {
+    /// Returns the feature table substitutions that apply at the given
+    /// location in normalized design space.
+    ///
+    /// This is the substitution table of the first record with a condition
+    /// set that matches the coordinates, or `None` if no record applies.
+    pub fn find_substitutions(
+        &self,
+        coords: &[F2Dot14],
+    ) -> Option<Result<FeatureTableSubstitution<'a>, ReadError>> {
+        let data = self.offset_data();
+        for record in self.feature_variation_records() {
+            let matches = match record.condition_set(data) {
+                Some(Ok(condition_set)) => condition_set.matches(coords),
+                Some(Err(e)) => return Some(Err(e)),
+                // a null offset is a condition set that matches all locations
+                None => true,
+            };
+            if matches {
+                return record.feature_table_substitution(data);
+            }
+        }
+        None
+    }
+}
+
+impl ConditionSet<'_> {
+    /// Returns true if every condition in the set is satisfied at the given
+    /// location in normalized design space.
+    ///
+    /// Coordinates for axes beyond the end of `coords` are assumed to be at
+    /// the default position. An empty condition set always matches.
+    pub fn matches(&self, coords: &[F2Dot14]) -> bool {
+        self.conditions().iter().all(|condition| {
+            condition
+                .map(|condition| condition.matches(coords))
+                .unwrap_or(false)
+        })
+    }
+}
+
+impl ConditionFormat1<'_> {
+    /// Returns true if the coordinate for the referenced axis is within the
+    /// range of this condition.
+    pub fn matches(&self, coords: &[F2Dot14]) -> bool {
+        let coord = coords
+            .get(self.axis_index() as usize)
+            .copied()
+            .unwrap_or_default();
+        coord >= self.filter_range_min_value() && coord <= self.filter_range_max_value()
+    }
+}
+
+impl<'a> FeatureTableSubstitution<'a> {
+    /// Returns the alternate feature table for the feature at the given index
+    /// in the feature list, if one exists.
+    ///
+    /// The `feature_tag` parameter is the tag of the feature being replaced
+    /// and is required to parse any associated feature parameters.
+    pub fn find_alternate(
+        &self,
+        feature_index: u16,
+        feature_tag: Tag,
+    ) -> Option<Result<Feature<'a>, ReadError>> {
+        let substitutions = self.substitutions();
+        let ix = substitutions
+            .binary_search_by_key(&feature_index, |record| record.feature_index())
+            .ok()?;
+        Some(
+            substitutions[ix]
+                .alternate_feature_offset()
+                .resolve_with_args(self.offset_data(), &feature_tag),
+        )
+    }
+}
+
+impl CoverageFormat1<'_> {
+    /// Returns the coverage index of the given glyph, or `None` if it is
+    /// not covered.
+    ///
+    /// This performs a binary search.
+    pub fn get(&self, glyph_id: GlyphId) -> Option<u16> {
+        self.glyph_array()
+            .binary_search_by(|covered| covered.get().cmp(&glyph_id))
+            .ok()
+            .map(|ix| ix as u16)
+    }
+}
+
+impl CoverageFormat2<'_> {
+    /// Returns the coverage index of the given glyph, or `None` if it is
+    /// not covered.
+    ///
+    /// This performs a binary search.
+    pub fn get(&self, glyph_id: GlyphId) -> Option<u16> {
+        let records = self.range_records();
+        let ix = records
+            .binary_search_by(|record| {
+                range_cmp(record.start_glyph_id(), record.end_glyph_id(), glyph_id)
+            })
+            .ok()?;
+        let record = &records[ix];
+        Some(
+            record
+                .start_coverage_index()
+                .wrapping_add(glyph_id.to_u16() - record.start_glyph_id().to_u16()),
+        )
+    }
+}
+
 impl CoverageTable<'_> {
+    /// Returns the coverage index of the given glyph, or `None` if it is
+    /// not covered.
+    ///
+    /// This performs a binary search. For many queries against the same
+    /// table, a [`CoverageAccelerator`] may be faster.
+    pub fn get(&self, glyph_id: GlyphId) -> Option<u16> {
+        match self {
+            CoverageTable::Format1(t) => t.get(glyph_id),
+            CoverageTable::Format2(t) => t.get(glyph_id),
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = GlyphId> + '_ {
         // all one expression so that we have a single return type
         let (iter1, iter2) = match self {
@@ -93,6 +218,213 @@ impl CoverageTable<'_> {
             .flatten()
             .chain(iter2.into_iter().flatten())
     }
+
+    /// Returns the set of glyphs covered by this table.
+    #[cfg(feature = "std")]
+    pub fn glyph_set(&self) -> IntSet<GlyphId> {
+        match self {
+            CoverageTable::Format1(t) => t.glyph_array().iter().map(|g| g.get()).collect(),
+            CoverageTable::Format2(t) => {
+                let mut set = IntSet::new();
+                for record in t.range_records() {
+                    set.insert_range(record.start_glyph_id()..=record.end_glyph_id());
+                }
+                set
+            }
+        }
+    }
+}
+
+impl ClassDefFormat1<'_> {
+    /// Returns the class of the given glyph.
+    ///
+    /// Glyphs that are not listed in the table belong to class 0.
+    pub fn get(&self, glyph_id: GlyphId) -> u16 {
+        glyph_id
+            .to_u16()
+            .checked_sub(self.start_glyph_id().to_u16())
+            .and_then(|ix| self.class_value_array().get(ix as usize))
+            .map(|class| class.get())
+            .unwrap_or(0)
+    }
+}
+
+impl ClassDefFormat2<'_> {
+    /// Returns the class of the given glyph.
+    ///
+    /// Glyphs that are not listed in the table belong to class 0. This
+    /// performs a binary search.
+    pub fn get(&self, glyph_id: GlyphId) -> u16 {
+        let records = self.class_range_records();
+        records
+            .binary_search_by(|record| {
+                range_cmp(record.start_glyph_id(), record.end_glyph_id(), glyph_id)
+            })
+            .map(|ix| records[ix].class())
+            .unwrap_or(0)
+    }
+}
+
+impl ClassDef<'_> {
+    /// Returns the class of the given glyph.
+    ///
+    /// Glyphs that are not listed in the table belong to class 0.
+    pub fn get(&self, glyph_id: GlyphId) -> u16 {
+        match self {
+            ClassDef::Format1(t) => t.get(glyph_id),
+            ClassDef::Format2(t) => t.get(glyph_id),
+        }
+    }
+
+    /// Returns an iterator over all glyphs with an explicitly assigned class,
+    /// paired with that class.
+    ///
+    /// Glyphs that are not listed in the table implicitly belong to class 0
+    /// and are not included.
+    pub fn iter(&self) -> impl Iterator<Item = (GlyphId, u16)> + '_ {
+        // all one expression so that we have a single return type
+        let (iter1, iter2) = match self {
+            ClassDef::Format1(t) => {
+                let start = t.start_glyph_id().to_u16();
+                let iter = t
+                    .class_value_array()
+                    .iter()
+                    .zip(start..=u16::MAX)
+                    .map(|(class, gid)| (GlyphId::new(gid), class.get()));
+                (Some(iter), None)
+            }
+            ClassDef::Format2(t) => {
+                let iter = t.class_range_records().iter().flat_map(|record| {
+                    let class = record.class();
+                    (record.start_glyph_id().to_u16()..=record.end_glyph_id().to_u16())
+                        .map(move |gid| (GlyphId::new(gid), class))
+                });
+                (None, Some(iter))
+            }
+        };
+
+        iter1
+            .into_iter()
+            .flatten()
+            .chain(iter2.into_iter().flatten())
+    }
+
+    /// Returns the set of glyphs explicitly assigned to the given class.
+    ///
+    /// Glyphs that are not listed in the table implicitly belong to class 0
+    /// and are not included.
+    #[cfg(feature = "std")]
+    pub fn class_glyphs(&self, class: u16) -> IntSet<GlyphId> {
+        match self {
+            ClassDef::Format1(_) => self
+                .iter()
+                .filter_map(|(gid, glyph_class)| (glyph_class == class).then_some(gid))
+                .collect(),
+            ClassDef::Format2(t) => {
+                let mut set = IntSet::new();
+                for record in t.class_range_records() {
+                    if record.class() == class {
+                        set.insert_range(record.start_glyph_id()..=record.end_glyph_id());
+                    }
+                }
+                set
+            }
+        }
+    }
+
+    /// Returns the set of classes that are explicitly assigned to at least
+    /// one glyph.
+    #[cfg(feature = "std")]
+    pub fn classes(&self) -> IntSet<u16> {
+        match self {
+            ClassDef::Format1(t) => t.class_value_array().iter().map(|c| c.get()).collect(),
+            ClassDef::Format2(t) => t.class_range_records().iter().map(|r| r.class()).collect(),
+        }
+    }
+}
+
+/// Compares the range `start..=end` to a glyph, for binary searches over
+/// sorted range records.
+fn range_cmp(start: GlyphId, end: GlyphId, glyph_id: GlyphId) -> core::cmp::Ordering {
+    if end < glyph_id {
+        core::cmp::Ordering::Less
+    } else if start > glyph_id {
+        core::cmp::Ordering::Greater
+    } else {
+        core::cmp::Ordering::Equal
+    }
+}
+
+/// A precomputed index for fast coverage queries.
+///
+/// This stores the coverage index of every glyph between the first and
+/// last covered glyphs, so lookups are a single array access rather than a
+/// binary search. It is worth building for large coverage tables that are
+/// queried repeatedly, such as in the inner loop of a shaper. Memory use is
+/// two bytes for each glyph in the covered range.
+///
+/// Since arrays indexed by coverage can have at most `u16::MAX` elements,
+/// a glyph with a coverage index of `u16::MAX` is treated as not covered.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub struct CoverageAccelerator {
+    first: u16,
+    // the coverage index of each glyph starting at `first`, or u16::MAX
+    indices: Vec<u16>,
+}
+
+#[cfg(feature = "std")]
+impl CoverageAccelerator {
+    const NOT_COVERED: u16 = u16::MAX;
+
+    /// Builds an accelerator for the given coverage table.
+    pub fn new(coverage: &CoverageTable) -> Self {
+        let mut result = Self::default();
+        let (Some(first), Some(last)) = (coverage.iter().min(), coverage.iter().max()) else {
+            return result;
+        };
+        result.first = first.to_u16();
+        result.indices = vec![Self::NOT_COVERED; (last.to_u16() - first.to_u16()) as usize + 1];
+        let mut set_index = |glyph_id: GlyphId, index: u16| {
+            let slot = &mut result.indices[(glyph_id.to_u16() - first.to_u16()) as usize];
+            // malformed tables may list a glyph more than once
+            if *slot == Self::NOT_COVERED {
+                *slot = index;
+            }
+        };
+        match coverage {
+            CoverageTable::Format1(t) => {
+                for (index, glyph_id) in t.glyph_array().iter().enumerate() {
+                    set_index(glyph_id.get(), index as u16);
+                }
+            }
+            CoverageTable::Format2(t) => {
+                for record in t.range_records() {
+                    let start = record.start_glyph_id().to_u16();
+                    for glyph_id in record.iter() {
+                        let offset = glyph_id.to_u16() - start;
+                        set_index(glyph_id, record.start_coverage_index().wrapping_add(offset));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the coverage index of the given glyph, or `None` if it is
+    /// not covered.
+    pub fn get(&self, glyph_id: GlyphId) -> Option<u16> {
+        glyph_id
+            .to_u16()
+            .checked_sub(self.first)
+            .and_then(|ix| self.indices.get(ix as usize).copied())
+            .filter(|index| *index != Self::NOT_COVERED)
+    }
+
+    /// Returns `true` if the given glyph is covered.
+    pub fn contains(&self, glyph_id: GlyphId) -> bool {
+        self.get(glyph_id).is_some()
+    }
 }
 
 impl RangeRecord {
@@ -0,0 +1,65 @@
+//! The [ltag](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html) table
+
+include!("../../generated/generated_ltag.rs");
+
+impl<'a> Ltag<'a> {
+    /// Returns the language tag at the given index.
+    ///
+    /// This is the index referenced by the language id of Unicode platform
+    /// name records, and by language ids in the `kerx` and `morx` tables.
+    pub fn tag(&self, index: u32) -> Option<Result<&'a str, ReadError>> {
+        let range = self.tag_ranges().get(index as usize)?;
+        Some(range.tag(self.offset_data()))
+    }
+
+    /// Returns an iterator over all language tags in the table.
+    pub fn tags(&self) -> impl Iterator<Item = Result<&'a str, ReadError>> + 'a {
+        let data = self.offset_data();
+        self.tag_ranges().iter().map(move |range| range.tag(data))
+    }
+}
+
+impl FTStringRange {
+    /// Return the language tag for this range.
+    ///
+    /// The `data` argument should be retrieved from the parent table.
+    pub fn tag<'a>(&self, data: FontData<'a>) -> Result<&'a str, ReadError> {
+        let start = self.tag_offset().to_u32() as usize;
+        let end = start + self.length() as usize;
+        let data = data
+            .as_bytes()
+            .get(start..end)
+            .ok_or(ReadError::OutOfBounds)?;
+        core::str::from_utf8(data)
+            .map_err(|_| ReadError::MalformedData("language tag is not valid UTF-8"))
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl FTStringRange {
+    fn traverse_tag<'a>(&self, data: FontData<'a>) -> FieldType<'a> {
+        FieldType::StringOffset(traversal::StringOffset {
+            offset: self.tag_offset().into(),
+            target: self.tag(data).map(|tag| Box::new(tag) as _),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn language_tags() {
+        let data = BeBuffer::new()
+            .extend([1u32, 0, 3])
+            .extend([24u16, 2, 26, 7, 24, 2])
+            .extend(*b"enzh-Hant");
+        let ltag = Ltag::read(data.font_data()).unwrap();
+        assert_eq!(ltag.tag(1).unwrap().unwrap(), "zh-Hant");
+        assert!(ltag.tag(3).is_none());
+        let tags = ltag.tags().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(tags, ["en", "zh-Hant", "en"]);
+    }
+}
@@ -0,0 +1,13 @@
+//! The [LTSH (Linear Threshold)](https://learn.microsoft.com/en-us/typography/opentype/spec/ltsh) table
+
+include!("../../generated/generated_ltsh.rs");
+
+impl<'a> Ltsh<'a> {
+    /// Returns the size in pixels per em at and above which the given
+    /// glyph scales linearly.
+    ///
+    /// A value of one means the glyph always scales linearly.
+    pub fn y_pel(&self, glyph_id: GlyphId) -> Option<u8> {
+        self.y_pels().get(glyph_id.to_u16() as usize).copied()
+    }
+}
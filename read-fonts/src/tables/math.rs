@@ -0,0 +1,191 @@
+//! The [MATH](https://learn.microsoft.com/en-us/typography/opentype/spec/math) table
+
+include!("../../generated/generated_math.rs");
+
+use super::layout::{CoverageTable, DeviceOrVariationIndex};
+
+impl<'a> MathGlyphInfo<'a> {
+    /// Returns true if the glyph is covered by the extended shape coverage
+    /// table.
+    pub fn is_extended_shape(&self, glyph: GlyphId) -> bool {
+        self.extended_shape_coverage()
+            .and_then(|coverage| coverage.ok())
+            .and_then(|coverage| coverage.get(glyph))
+            .is_some()
+    }
+}
+
+impl<'a> MathItalicsCorrectionInfo<'a> {
+    /// Returns the italics correction value for the given glyph.
+    ///
+    /// Device tables of the returned record are relative to this table.
+    pub fn get(&self, glyph: GlyphId) -> Option<&'a MathValueRecord> {
+        let index = self.italics_correction_coverage().ok()?.get(glyph)? as usize;
+        self.italics_correction().get(index)
+    }
+}
+
+impl<'a> MathTopAccentAttachment<'a> {
+    /// Returns the top accent attachment value for the given glyph.
+    ///
+    /// Device tables of the returned record are relative to this table.
+    pub fn get(&self, glyph: GlyphId) -> Option<&'a MathValueRecord> {
+        let index = self.top_accent_coverage().ok()?.get(glyph)? as usize;
+        self.top_accent_attachment().get(index)
+    }
+}
+
+impl<'a> MathKernInfo<'a> {
+    /// Returns the kerning information for the given glyph.
+    ///
+    /// Offsets in the returned record are relative to this table.
+    pub fn get(&self, glyph: GlyphId) -> Option<&'a MathKernInfoRecord> {
+        let index = self.math_kern_coverage().ok()?.get(glyph)? as usize;
+        self.math_kern_info_records().get(index)
+    }
+}
+
+impl<'a> MathKern<'a> {
+    /// Returns the kern value that applies at the given height, ignoring
+    /// any device tables.
+    ///
+    /// Device tables of the returned record are relative to this table.
+    pub fn kern_value(&self, height: i16) -> Option<&'a MathValueRecord> {
+        let index = self
+            .correction_height()
+            .iter()
+            .position(|correction| height < correction.value().to_i16())
+            .unwrap_or(self.height_count() as usize);
+        self.kern_values().get(index)
+    }
+}
+
+impl<'a> MathVariants<'a> {
+    /// Returns the construction for growing the given glyph in the
+    /// vertical direction.
+    pub fn vert_glyph_construction(
+        &self,
+        glyph: GlyphId,
+    ) -> Option<Result<MathGlyphConstruction<'a>, ReadError>> {
+        let index = self.vert_glyph_coverage().ok()?.get(glyph)? as usize;
+        self.vert_glyph_constructions().get(index)
+    }
+
+    /// Returns the construction for growing the given glyph in the
+    /// horizontal direction.
+    pub fn horiz_glyph_construction(
+        &self,
+        glyph: GlyphId,
+    ) -> Option<Result<MathGlyphConstruction<'a>, ReadError>> {
+        let index = self.horiz_glyph_coverage().ok()?.get(glyph)? as usize;
+        self.horiz_glyph_constructions().get(index)
+    }
+}
+
+impl GlyphPart {
+    /// True if this part can be repeated to grow an assembly.
+    pub fn is_extender(&self) -> bool {
+        self.part_flags().contains(GlyphPartFlags::EXTENDER_FLAG)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn constants() {
+        let mut buf = BeBuffer::new()
+            .extend([80i16, 60])
+            .extend([1500u16, 1300])
+            // math leading with no device table
+            .extend([150i16, 0])
+            // axis height
+            .extend([250i16, 0]);
+        // remaining value records
+        for i in 0..49 {
+            buf = buf.extend([i as i16, 0]);
+        }
+        buf = buf.push(70i16);
+        let constants = MathConstants::read(buf.font_data()).unwrap();
+        assert_eq!(constants.script_percent_scale_down(), 80);
+        assert_eq!(constants.delimited_sub_formula_min_height().to_u16(), 1500);
+        assert_eq!(constants.math_leading().value().to_i16(), 150);
+        assert_eq!(constants.axis_height().value().to_i16(), 250);
+        assert!(constants.axis_height().device_offset().is_null());
+        assert_eq!(constants.radical_kern_after_degree().value().to_i16(), 48);
+        assert_eq!(constants.radical_degree_bottom_raise_percent(), 70);
+    }
+
+    #[test]
+    fn italics_correction() {
+        let data = BeBuffer::new()
+            // coverage offset, count
+            .extend([12u16, 2])
+            // two value records
+            .extend([30i16, 0, 40, 0])
+            // coverage format 1 for glyphs 5 and 9
+            .extend([1u16, 2, 5, 9]);
+        let info = MathItalicsCorrectionInfo::read(data.font_data()).unwrap();
+        assert_eq!(info.get(GlyphId::new(9)).unwrap().value().to_i16(), 40);
+        assert!(info.get(GlyphId::new(6)).is_none());
+    }
+
+    #[test]
+    fn kern_value_by_height() {
+        let data = BeBuffer::new()
+            .push(2u16)
+            // correction heights
+            .extend([100i16, 0, 200, 0])
+            // kern values
+            .extend([-10i16, 0, -20, 0, -30, 0]);
+        let kern = MathKern::read(data.font_data()).unwrap();
+        let kern_at = |height| kern.kern_value(height).unwrap().value().to_i16();
+        assert_eq!(kern_at(50), -10);
+        assert_eq!(kern_at(100), -20);
+        assert_eq!(kern_at(199), -20);
+        assert_eq!(kern_at(500), -30);
+    }
+
+    #[test]
+    fn variants_and_assembly() {
+        let data = BeBuffer::new()
+            // min connector overlap, vert coverage, horiz coverage
+            .extend([20u16, 12, 18])
+            // vert count, horiz count, vert construction offset
+            .extend([1u16, 0, 24])
+            // vert coverage for glyph 3
+            .extend([1u16, 1, 3])
+            // empty horiz coverage
+            .extend([1u16, 0])
+            // padding
+            .push(0u16)
+            // construction: assembly offset, variant count
+            .extend([12u16, 2])
+            // variants
+            .extend([3u16, 500, 4, 1000])
+            // assembly: italics correction, part count
+            .extend([0i16, 0, 2])
+            // bottom part and extender
+            .extend([10u16, 0, 100, 400, 0])
+            .extend([11u16, 100, 100, 300, 1]);
+        let variants = MathVariants::read(data.font_data()).unwrap();
+        assert_eq!(variants.min_connector_overlap().to_u16(), 20);
+        assert!(variants.horiz_glyph_construction(GlyphId::new(3)).is_none());
+        let construction = variants
+            .vert_glyph_construction(GlyphId::new(3))
+            .unwrap()
+            .unwrap();
+        let records = construction.math_glyph_variant_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].variant_glyph(), GlyphId::new(4));
+        assert_eq!(records[1].advance_measurement().to_u16(), 1000);
+        let assembly = construction.glyph_assembly().unwrap().unwrap();
+        let parts = assembly.part_records();
+        assert_eq!(parts.len(), 2);
+        assert!(!parts[0].is_extender());
+        assert!(parts[1].is_extender());
+        assert_eq!(parts[1].full_advance().to_u16(), 300);
+    }
+}
@@ -0,0 +1,152 @@
+//! The [meta (Metadata)](https://learn.microsoft.com/en-us/typography/opentype/spec/meta) table
+
+include!("../../generated/generated_meta.rs");
+
+/// Data tag for the languages the font was primarily designed for.
+pub const DLNG: Tag = Tag::new(b"dlng");
+/// Data tag for the languages the font is capable of supporting.
+pub const SLNG: Tag = Tag::new(b"slng");
+
+impl<'a> Meta<'a> {
+    /// Returns the metadata for the given tag, if present.
+    pub fn data_for_tag(&self, tag: Tag) -> Option<Result<Metadata<'a>, ReadError>> {
+        let data_map = self.data_maps().iter().find(|map| map.tag() == tag)?;
+        Some(data_map.data(self.offset_data()))
+    }
+
+    /// Returns the script and language tags from the `dlng` entry.
+    pub fn design_languages(&self) -> Option<Result<ScriptLangTags<'a>, ReadError>> {
+        self.script_lang_tags(DLNG)
+    }
+
+    /// Returns the script and language tags from the `slng` entry.
+    pub fn supported_languages(&self) -> Option<Result<ScriptLangTags<'a>, ReadError>> {
+        self.script_lang_tags(SLNG)
+    }
+
+    fn script_lang_tags(&self, tag: Tag) -> Option<Result<ScriptLangTags<'a>, ReadError>> {
+        Some(self.data_for_tag(tag)?.and_then(|data| match data {
+            Metadata::ScriptLangTags(tags) => Ok(tags),
+            Metadata::Other(_) => Err(ReadError::MalformedData("unexpected metadata type")),
+        }))
+    }
+}
+
+impl DataMap {
+    /// Return the metadata for this entry.
+    ///
+    /// The `data` argument should be retrieved from the parent table.
+    pub fn data<'a>(&self, data: FontData<'a>) -> Result<Metadata<'a>, ReadError> {
+        let start = self.data_offset().to_u32() as usize;
+        let end = start
+            .checked_add(self.data_length() as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        let data = data
+            .as_bytes()
+            .get(start..end)
+            .ok_or(ReadError::OutOfBounds)?;
+        match self.tag() {
+            DLNG | SLNG => core::str::from_utf8(data)
+                .map(|tags| Metadata::ScriptLangTags(ScriptLangTags(tags)))
+                .map_err(|_| ReadError::MalformedData("ScriptLangTag list is not valid UTF-8")),
+            _ => Ok(Metadata::Other(data)),
+        }
+    }
+}
+
+/// The data referenced by a [`DataMap`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metadata<'a> {
+    /// A list of script and language tags, used for `dlng` and `slng`.
+    ScriptLangTags(ScriptLangTags<'a>),
+    /// Data for any other tag.
+    Other(&'a [u8]),
+}
+
+/// A comma-separated list of [ScriptLangTag]s.
+///
+/// [ScriptLangTag]: https://learn.microsoft.com/en-us/typography/opentype/spec/meta#scriptlangtag-values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScriptLangTags<'a>(&'a str);
+
+impl<'a> ScriptLangTags<'a> {
+    /// Returns the raw, comma-separated list.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns an iterator over the individual tags in the list.
+    ///
+    /// Surrounding whitespace is trimmed and empty entries are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + Clone {
+        self.0
+            .split(',')
+            .map(|tag| tag.trim())
+            .filter(|tag| !tag.is_empty())
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> traversal::SomeString<'a> for ScriptLangTags<'a> {
+    fn iter_chars(&self) -> Box<dyn Iterator<Item = char> + 'a> {
+        Box::new(self.0.chars())
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl DataMap {
+    fn traverse_data<'a>(&self, data: FontData<'a>) -> FieldType<'a> {
+        let offset = self.data_offset().into();
+        match self.data(data) {
+            Ok(Metadata::Other(data)) => FieldType::ArrayOffset(traversal::ArrayOffset {
+                offset,
+                target: Ok(Box::new(data)),
+            }),
+            result => FieldType::StringOffset(traversal::StringOffset {
+                offset,
+                target: result.map(|data| match data {
+                    Metadata::ScriptLangTags(tags) => Box::new(tags) as _,
+                    Metadata::Other(_) => unreachable!(),
+                }),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn script_lang_tags() {
+        let dlng = b"Latn, Cyrl";
+        let slng = b"Latn,Cyrl,Grek,";
+        let data = BeBuffer::new()
+            .extend([1u32, 0, 0, 3])
+            .push(DLNG)
+            .extend([52u32, dlng.len() as u32])
+            .push(Tag::new(b"appl"))
+            .extend([52u32 + 10, 2])
+            .push(SLNG)
+            .extend([64u32, slng.len() as u32])
+            .extend(*dlng)
+            .extend([1u8, 2])
+            .extend(*slng);
+        let meta = Meta::read(data.font_data()).unwrap();
+        assert_eq!(meta.data_maps().len(), 3);
+        let design = meta.design_languages().unwrap().unwrap();
+        assert_eq!(design.as_str(), "Latn, Cyrl");
+        assert_eq!(design.iter().collect::<Vec<_>>(), ["Latn", "Cyrl"]);
+        let supported = meta.supported_languages().unwrap().unwrap();
+        assert_eq!(
+            supported.iter().collect::<Vec<_>>(),
+            ["Latn", "Cyrl", "Grek"]
+        );
+        assert_eq!(
+            meta.data_for_tag(Tag::new(b"appl")).unwrap().unwrap(),
+            Metadata::Other(&[1, 2])
+        );
+        assert!(meta.data_for_tag(Tag::new(b"bild")).is_none());
+    }
+}
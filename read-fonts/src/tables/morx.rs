@@ -0,0 +1,300 @@
+//! The [morx (Extended Glyph Metamorphosis)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6morx.html) table
+
+include!("../../generated/generated_morx.rs");
+
+use super::aat::{sequential, ExtendedStateTable, Lookup, RawBytes, RawWords, StxHeader};
+
+/// Size of the header of a `morx` table.
+#[cfg(feature = "traversal")]
+const HEADER_LEN: usize = 8;
+
+/// Size of the header of a chain in a `morx` table.
+const CHAIN_HEADER_LEN: usize = 16;
+
+/// Size of the header of a subtable in a `morx` chain.
+const SUBTABLE_HEADER_LEN: usize = 12;
+
+impl<'a> Morx<'a> {
+    /// Returns an iterator over all of the chains in this `morx` table.
+    pub fn chains(&self) -> impl Iterator<Item = Result<Chain<'a>, ReadError>> + 'a {
+        sequential(
+            FontData::new(self.chain_data()),
+            self.n_chains(),
+            4,
+            CHAIN_HEADER_LEN,
+        )
+        .map(|(_, chain)| chain)
+    }
+
+    #[cfg(feature = "traversal")]
+    fn traverse_chains(&self) -> FieldType<'a> {
+        let chains = sequential(
+            FontData::new(self.chain_data()),
+            self.n_chains(),
+            4,
+            CHAIN_HEADER_LEN,
+        );
+        super::aat::SequentialArray::<Chain>::new("Chain", HEADER_LEN, chains).into()
+    }
+}
+
+impl<'a> Chain<'a> {
+    /// Returns an iterator over all of the subtables in this chain.
+    pub fn subtables(&self) -> impl Iterator<Item = Result<Subtable<'a>, ReadError>> + 'a {
+        sequential(
+            FontData::new(self.subtable_data()),
+            self.n_subtables(),
+            0,
+            SUBTABLE_HEADER_LEN,
+        )
+        .map(|(_, subtable)| subtable)
+    }
+
+    #[cfg(feature = "traversal")]
+    fn traverse_subtables(&self) -> FieldType<'a> {
+        let offset = CHAIN_HEADER_LEN + self.features().len() * Feature::RAW_BYTE_LEN;
+        let subtables = sequential(
+            FontData::new(self.subtable_data()),
+            self.n_subtables(),
+            0,
+            SUBTABLE_HEADER_LEN,
+        );
+        super::aat::SequentialArray::<Subtable>::new("Subtable", offset, subtables).into()
+    }
+}
+
+impl<'a> Subtable<'a> {
+    /// Set if the subtable only applies to vertical text.
+    pub const VERTICAL: u32 = 0x80000000;
+    /// Set if the glyphs are processed in descending order.
+    pub const DESCENDING: u32 = 0x40000000;
+    /// Set if the subtable applies to text of any orientation.
+    pub const ALL_DIRECTIONS: u32 = 0x20000000;
+    /// Set if the glyphs are processed in logical rather than layout
+    /// order.
+    pub const LOGICAL_ORDER: u32 = 0x10000000;
+
+    /// Returns the type of the subtable.
+    pub fn subtable_type(&self) -> u8 {
+        self.coverage() as u8
+    }
+
+    /// Returns the type specific data of the subtable.
+    pub fn kind(&self) -> Result<SubtableKind<'a>, ReadError> {
+        let data = FontData::new(self.data());
+        match self.subtable_type() {
+            0 => StxHeader::read(data).map(SubtableKind::Rearrangement),
+            1 => ContextualSubtable::read(data).map(SubtableKind::Contextual),
+            2 => LigatureSubtable::read(data).map(SubtableKind::Ligature),
+            4 => Lookup::read(data).map(SubtableKind::NonContextual),
+            5 => InsertionSubtable::read(data).map(SubtableKind::Insertion),
+            other => Err(ReadError::InvalidFormat(other as _)),
+        }
+    }
+
+    #[cfg(feature = "traversal")]
+    fn traverse_kind(&self) -> FieldType<'a> {
+        FieldType::offset(Offset32::new(SUBTABLE_HEADER_LEN as u32), self.kind())
+    }
+}
+
+/// The type specific data of a `morx` subtable.
+#[derive(Clone)]
+pub enum SubtableKind<'a> {
+    /// Reorders glyphs; the state table has no per entry data.
+    Rearrangement(StxHeader<'a>),
+    Contextual(ContextualSubtable<'a>),
+    Ligature(LigatureSubtable<'a>),
+    /// Maps glyphs to glyphs without context.
+    NonContextual(Lookup<'a>),
+    Insertion(InsertionSubtable<'a>),
+}
+
+impl<'a> ContextualSubtable<'a> {
+    /// Returns the state table for this subtable.
+    pub fn state_table(&self) -> Result<ExtendedStateTable<'a, ContextualEntryData>, ReadError> {
+        ExtendedStateTable::read(self.offset_data())
+    }
+
+    /// Returns the substitution lookup table at the given index.
+    ///
+    /// The values in the table are glyph ids.
+    pub fn substitution_table(&self, index: u16) -> Result<Lookup<'a>, ReadError> {
+        let data = self.offset_data();
+        let base = self.substitution_table_offset() as usize;
+        let offset = data.read_at::<u32>(base + index as usize * 4)? as usize;
+        data.split_off(base + offset)
+            .ok_or(ReadError::OutOfBounds)
+            .and_then(Lookup::read)
+    }
+}
+
+impl<'a> LigatureSubtable<'a> {
+    /// Returns the state table for this subtable.
+    pub fn state_table(&self) -> Result<ExtendedStateTable<'a, LigatureEntryData>, ReadError> {
+        ExtendedStateTable::read(self.offset_data())
+    }
+
+    /// Returns the ligature action at the given index.
+    pub fn ligature_action(&self, index: u16) -> Result<LigatureAction, ReadError> {
+        let pos = self.lig_action_offset() as usize + index as usize * 4;
+        self.offset_data().read_at(pos).map(LigatureAction)
+    }
+
+    /// Returns the component value at the given index.
+    pub fn component(&self, index: u32) -> Result<u16, ReadError> {
+        let pos = self.component_offset() as usize + index as usize * 2;
+        self.offset_data().read_at(pos)
+    }
+
+    /// Returns the ligature glyph at the given index.
+    pub fn ligature(&self, index: u16) -> Result<GlyphId, ReadError> {
+        let pos = self.ligature_offset() as usize + index as usize * 2;
+        self.offset_data().read_at(pos)
+    }
+}
+
+/// An action in a ligature subtable.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LigatureAction(pub u32);
+
+impl LigatureAction {
+    /// True if this is the last action in the list.
+    pub fn is_last(self) -> bool {
+        self.0 & 0x80000000 != 0
+    }
+
+    /// True if the ligature glyph should be stored.
+    pub fn is_store(self) -> bool {
+        self.0 & 0x40000000 != 0
+    }
+
+    /// Returns the signed offset that is added to the glyph id to compute
+    /// the index into the component table.
+    pub fn offset(self) -> i32 {
+        // sign extend the 30 bit value
+        ((self.0 << 2) as i32) >> 2
+    }
+}
+
+impl<'a> InsertionSubtable<'a> {
+    /// Returns the state table for this subtable.
+    pub fn state_table(&self) -> Result<ExtendedStateTable<'a, InsertionEntryData>, ReadError> {
+        ExtendedStateTable::read(self.offset_data())
+    }
+
+    /// Returns the glyph at the given index in the insertion glyph table.
+    pub fn insertion_glyph(&self, index: u16) -> Result<GlyphId, ReadError> {
+        let pos = self.insertion_action_offset() as usize + index as usize * 2;
+        self.offset_data().read_at(pos)
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SubtableKind<'a> {
+    fn dyn_inner<'b>(&'b self) -> &'b dyn SomeTable<'a> {
+        match self {
+            Self::Rearrangement(table) => table,
+            Self::Contextual(table) => table,
+            Self::Ligature(table) => table,
+            Self::NonContextual(table) => table,
+            Self::Insertion(table) => table,
+        }
+    }
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeTable<'a> for SubtableKind<'a> {
+    fn type_name(&self) -> &str {
+        self.dyn_inner().type_name()
+    }
+
+    fn get_field(&self, idx: usize) -> Option<Field<'a>> {
+        self.dyn_inner().get_field(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn chains_and_subtables() {
+        let data = BeBuffer::new()
+            // header: version, unused, nChains
+            .extend([2u16, 0])
+            .push(1u32)
+            // chain: defaultFlags, chainLength, nFeatureEntries, nSubtables
+            .extend([1u32, 88, 1, 2])
+            // feature: type, setting, enable flags, disable flags
+            .extend([1u16, 0])
+            .extend([1u32, 0xFFFFFFFF])
+            // noncontextual subtable: length, coverage, subFeatureFlags
+            .extend([24u32, 0x20000004, 1])
+            // format 8 lookup mapping glyphs 3..=4 to 7..=8
+            .extend([8u16, 3, 2, 7, 8, 0])
+            // insertion subtable: length, coverage, subFeatureFlags
+            .extend([36u32, 0x00000005, 1])
+            // nClasses, classTable, stateArray, entryTable, insertionAction
+            .extend([4u32, 20, 20, 20, 20])
+            // insertion glyphs
+            .extend([9u16, 10]);
+        let morx = Morx::read(data.font_data()).unwrap();
+        let chains = morx.chains().map(|c| c.unwrap()).collect::<Vec<_>>();
+        assert_eq!(chains.len(), 1);
+        let chain = &chains[0];
+        assert_eq!(chain.default_flags(), 1);
+        assert_eq!(chain.features()[0].disable_flags(), 0xFFFFFFFF);
+        let subtables = chain.subtables().map(|s| s.unwrap()).collect::<Vec<_>>();
+        assert_eq!(subtables.len(), 2);
+        assert_eq!(subtables[0].subtable_type(), 4);
+        assert!(subtables[0].coverage() & Subtable::ALL_DIRECTIONS != 0);
+        let SubtableKind::NonContextual(lookup) = subtables[0].kind().unwrap() else {
+            panic!("expected noncontextual subtable");
+        };
+        assert_eq!(lookup.value(GlyphId::new(4)), Some(GlyphId::new(8)));
+        assert_eq!(lookup.value::<GlyphId>(GlyphId::new(5)), None);
+        let SubtableKind::Insertion(insertion) = subtables[1].kind().unwrap() else {
+            panic!("expected insertion subtable");
+        };
+        assert_eq!(insertion.n_classes(), 4);
+        assert_eq!(insertion.insertion_glyph(1).unwrap(), GlyphId::new(10));
+    }
+
+    #[test]
+    fn zero_length_chain_and_subtable() {
+        let data = BeBuffer::new()
+            // header: version, unused, nChains
+            .extend([2u16, 0])
+            .push(u32::MAX)
+            // chain: defaultFlags, chainLength, nFeatureEntries, nSubtables
+            .extend([1u32, 0, 0, 0]);
+        let morx = Morx::read(data.font_data()).unwrap();
+        let chains = morx.chains().collect::<Vec<_>>();
+        assert_eq!(chains.len(), 1);
+        assert!(chains[0].is_err());
+        let data = BeBuffer::new()
+            // header: version, unused, nChains
+            .extend([2u16, 0])
+            .push(1u32)
+            // chain: defaultFlags, chainLength, nFeatureEntries, nSubtables
+            .extend([1u32, 28, 0, u32::MAX])
+            // subtable: length, coverage, subFeatureFlags
+            .extend([0u32, 0x20000004, 1]);
+        let morx = Morx::read(data.font_data()).unwrap();
+        let chain = morx.chains().next().unwrap().unwrap();
+        let subtables = chain.subtables().collect::<Vec<_>>();
+        assert_eq!(subtables.len(), 1);
+        assert!(subtables[0].is_err());
+    }
+
+    #[test]
+    fn ligature_action() {
+        let action = LigatureAction(0x80000000 | 0x3FFFFFFE);
+        assert!(action.is_last());
+        assert!(!action.is_store());
+        assert_eq!(action.offset(), -2);
+        assert_eq!(LigatureAction(0x40000003).offset(), 3);
+    }
+}
@@ -0,0 +1,3 @@
+//! The [PCLT (PCL 5)](https://learn.microsoft.com/en-us/typography/opentype/spec/pclt) table
+
+include!("../../generated/generated_pclt.rs");
@@ -3,6 +3,8 @@
 use std::fmt;
 
 mod blend;
+mod charset;
+mod encoding;
 mod fd_select;
 mod index;
 mod stack;
@@ -14,6 +16,11 @@ pub mod dict;
 include!("../../generated/generated_postscript.rs");
 
 pub use blend::BlendState;
+pub use charset::{
+    Charset, CharsetFormat0, CharsetFormat1, CharsetFormat2, CharsetKind, CharsetRange1,
+    CharsetRange2, CustomCharset,
+};
+pub use encoding::standard_encoding;
 pub use index::Index;
 pub use stack::{Number, Stack};
 pub use string::{Latin1String, StringId, STANDARD_STRINGS};
@@ -36,6 +43,7 @@ pub enum Error {
     MissingBlendState,
     MissingPrivateDict,
     MissingCharstrings,
+    InvalidSeacCode(i32),
     Read(ReadError),
 }
 
@@ -106,6 +114,12 @@ impl fmt::Display for Error {
             Self::MissingCharstrings => {
                 write!(f, "CFF table does not contain a charstrings index")
             }
+            Self::InvalidSeacCode(code) => {
+                write!(
+                    f,
+                    "seac character code {code} does not map to a glyph in the standard encoding"
+                )
+            }
             Self::Read(err) => write!(f, "{err}"),
         }
     }
@@ -0,0 +1,315 @@
+//! CFF charset support.
+
+include!("../../../generated/generated_charset.rs");
+
+use super::StringId;
+use crate::types::GlyphId;
+
+/// Character set for mapping from glyph to string identifiers.
+///
+/// See <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=21>
+pub struct Charset<'a> {
+    kind: CharsetKind<'a>,
+    num_glyphs: u32,
+}
+
+impl<'a> Charset<'a> {
+    /// Creates a new charset from the given CFF table data, offset of the
+    /// charset (as specified by the Top DICT) and number of glyphs.
+    ///
+    /// The offsets 0, 1 and 2 select the predefined ISOAdobe, Expert and
+    /// ExpertSubset charsets, respectively.
+    pub fn new(
+        cff_data: FontData<'a>,
+        charset_offset: usize,
+        num_glyphs: u32,
+    ) -> Result<Self, ReadError> {
+        let kind = match charset_offset {
+            0 => CharsetKind::IsoAdobe,
+            1 => CharsetKind::Expert,
+            2 => CharsetKind::ExpertSubset,
+            _ => {
+                let data = cff_data
+                    .split_off(charset_offset)
+                    .ok_or(ReadError::OutOfBounds)?;
+                // The charset is followed by other CFF data so trim it to
+                // the size implied by the glyph count.
+                let len = custom_charset_len(data, num_glyphs)?;
+                let data = data.slice(..len).ok_or(ReadError::OutOfBounds)?;
+                CharsetKind::Custom(CustomCharset::read(data)?)
+            }
+        };
+        Ok(Self { kind, num_glyphs })
+    }
+
+    /// Returns the underlying type of the charset.
+    pub fn kind(&self) -> &CharsetKind<'a> {
+        &self.kind
+    }
+
+    /// Returns the number of glyphs covered by the charset.
+    pub fn num_glyphs(&self) -> u32 {
+        self.num_glyphs
+    }
+
+    /// Returns the string identifier for the given glyph identifier.
+    pub fn string_id(&self, glyph_id: GlyphId) -> Result<StringId, ReadError> {
+        let gid = glyph_id.to_u16() as usize;
+        if gid as u32 >= self.num_glyphs {
+            return Err(ReadError::OutOfBounds);
+        }
+        match &self.kind {
+            CharsetKind::IsoAdobe => {
+                // The ISOAdobe charset is an identity mapping of glyph
+                // identifiers to string identifiers in the range 0..=228.
+                if gid <= 228 {
+                    Ok(StringId::new(gid as u16))
+                } else {
+                    Err(ReadError::OutOfBounds)
+                }
+            }
+            CharsetKind::Expert => EXPERT_CHARSET
+                .get(gid)
+                .copied()
+                .ok_or(ReadError::OutOfBounds)
+                .map(StringId::new),
+            CharsetKind::ExpertSubset => EXPERT_SUBSET_CHARSET
+                .get(gid)
+                .copied()
+                .ok_or(ReadError::OutOfBounds)
+                .map(StringId::new),
+            CharsetKind::Custom(custom) => {
+                // .notdef is always the first glyph and is not encoded
+                if gid == 0 {
+                    return Ok(StringId::new(0));
+                }
+                let mut start = 1;
+                for (first, count) in custom.glyph_ranges() {
+                    if gid < start + count {
+                        return Ok(StringId::new((first as usize + gid - start) as u16));
+                    }
+                    start += count;
+                }
+                Err(ReadError::OutOfBounds)
+            }
+        }
+    }
+
+    /// Returns the glyph identifier for the given string identifier.
+    pub fn glyph_id(&self, string_id: StringId) -> Result<GlyphId, ReadError> {
+        let sid = string_id.to_u16();
+        let glyph_id = match &self.kind {
+            CharsetKind::IsoAdobe => (sid <= 228).then_some(sid),
+            CharsetKind::Expert => EXPERT_CHARSET
+                .iter()
+                .position(|id| *id == sid)
+                .map(|gid| gid as u16),
+            CharsetKind::ExpertSubset => EXPERT_SUBSET_CHARSET
+                .iter()
+                .position(|id| *id == sid)
+                .map(|gid| gid as u16),
+            CharsetKind::Custom(custom) => {
+                if sid == 0 {
+                    return Ok(GlyphId::NOTDEF);
+                }
+                let mut start = 1;
+                let mut glyph_id = None;
+                for (first, count) in custom.glyph_ranges() {
+                    if start >= self.num_glyphs as usize {
+                        break;
+                    }
+                    if sid >= first && ((sid - first) as usize) < count {
+                        glyph_id = Some((start + (sid - first) as usize) as u16);
+                        break;
+                    }
+                    start += count;
+                }
+                glyph_id
+            }
+        };
+        glyph_id
+            .filter(|gid| (*gid as u32) < self.num_glyphs)
+            .map(GlyphId::new)
+            .ok_or(ReadError::OutOfBounds)
+    }
+}
+
+/// Predefined and custom character sets.
+pub enum CharsetKind<'a> {
+    IsoAdobe,
+    Expert,
+    ExpertSubset,
+    Custom(CustomCharset<'a>),
+}
+
+impl<'a> CustomCharset<'a> {
+    /// Returns an iterator over the ranges of string identifiers assigned to
+    /// consecutive glyphs, beginning at glyph 1.
+    ///
+    /// Each item is the first string identifier and the number of glyphs in
+    /// the range.
+    fn glyph_ranges(&self) -> impl Iterator<Item = (u16, usize)> + 'a {
+        let (format0, format1, format2) = match self {
+            Self::Format0(table) => (Some(table.glyph().iter()), None, None),
+            Self::Format1(table) => (None, Some(table.ranges().iter()), None),
+            Self::Format2(table) => (None, None, Some(table.ranges().iter())),
+        };
+        format0
+            .into_iter()
+            .flatten()
+            .map(|sid| (sid.get(), 1))
+            .chain(
+                format1
+                    .into_iter()
+                    .flatten()
+                    .map(|range| (range.first(), range.n_left() as usize + 1)),
+            )
+            .chain(
+                format2
+                    .into_iter()
+                    .flatten()
+                    .map(|range| (range.first(), range.n_left() as usize + 1)),
+            )
+    }
+}
+
+/// Returns the size in bytes of the custom charset at the start of `data`
+/// that covers `num_glyphs` glyphs.
+fn custom_charset_len(data: FontData, num_glyphs: u32) -> Result<usize, ReadError> {
+    // .notdef is not encoded
+    let count = num_glyphs.saturating_sub(1) as usize;
+    let format: u8 = data.read_at(0)?;
+    let range_len = match format {
+        0 => return Ok(1 + count * 2),
+        1 => 3,
+        2 => 4,
+        _ => return Err(ReadError::InvalidFormat(format as _)),
+    };
+    let mut len = 1;
+    let mut covered = 0;
+    while covered < count {
+        let n_left = if format == 1 {
+            data.read_at::<u8>(len + 2)? as usize
+        } else {
+            data.read_at::<u16>(len + 2)? as usize
+        };
+        covered += n_left + 1;
+        len += range_len;
+    }
+    Ok(len)
+}
+
+/// See "Expert" charset at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=47>
+#[rustfmt::skip]
+const EXPERT_CHARSET: &[u16] = &[
+    0, 1, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238,
+    13, 14, 15, 99, 239, 240, 241, 242, 243, 244, 245, 246,
+    247, 248, 27, 28, 249, 250, 251, 252, 253, 254, 255, 256,
+    257, 258, 259, 260, 261, 262, 263, 264, 265, 266, 109, 110,
+    267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277, 278,
+    279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290,
+    291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 301, 302,
+    303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313, 314,
+    315, 316, 317, 318, 158, 155, 163, 319, 320, 321, 322, 323,
+    324, 325, 326, 150, 164, 169, 327, 328, 329, 330, 331, 332,
+    333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344,
+    345, 346, 347, 348, 349, 350, 351, 352, 353, 354, 355, 356,
+    357, 358, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368,
+    369, 370, 371, 372, 373, 374, 375, 376, 377, 378,
+];
+
+/// See "Expert Subset" charset at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=49>
+#[rustfmt::skip]
+const EXPERT_SUBSET_CHARSET: &[u16] = &[
+    0, 1, 231, 232, 235, 236, 237, 238, 13, 14, 15, 99,
+    239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 27, 28,
+    249, 250, 251, 253, 254, 255, 256, 257, 258, 259, 260, 261,
+    262, 263, 264, 265, 266, 109, 110, 267, 268, 269, 270, 272,
+    300, 301, 302, 305, 314, 315, 158, 155, 163, 320, 321, 322,
+    323, 324, 325, 326, 150, 164, 169, 327, 328, 329, 330, 331,
+    332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343,
+    344, 345, 346,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    fn check_custom(data: BeBuffer) {
+        // Pad with three bytes since offsets 0..=2 select predefined charsets
+        let data = BeBuffer::new()
+            .extend([0u8; 3])
+            .extend(data.iter().copied());
+        let charset = Charset::new(data.font_data(), 3, 4).unwrap();
+        assert!(matches!(charset.kind(), CharsetKind::Custom(_)));
+        let expected = [(0, 0), (1, 5), (2, 6), (3, 7)];
+        for (gid, sid) in expected {
+            assert_eq!(
+                charset.string_id(GlyphId::new(gid)).unwrap(),
+                StringId::new(sid)
+            );
+            assert_eq!(
+                charset.glyph_id(StringId::new(sid)).unwrap(),
+                GlyphId::new(gid)
+            );
+        }
+        assert!(charset.string_id(GlyphId::new(4)).is_err());
+        assert!(charset.glyph_id(StringId::new(8)).is_err());
+    }
+
+    #[test]
+    fn custom_format0() {
+        check_custom(BeBuffer::new().push(0u8).extend([5u16, 6, 7]));
+    }
+
+    #[test]
+    fn custom_format1() {
+        check_custom(BeBuffer::new().push(1u8).push(5u16).push(2u8));
+    }
+
+    #[test]
+    fn custom_format2() {
+        check_custom(BeBuffer::new().push(2u8).push(5u16).push(2u16));
+    }
+
+    #[test]
+    fn custom_with_trailing_data() {
+        // Charsets are not length prefixed and are usually followed by other
+        // data in the CFF table
+        check_custom(BeBuffer::new().push(0u8).extend([5u16, 6, 7]).push(0xFFu8));
+        check_custom(
+            BeBuffer::new()
+                .push(1u8)
+                .push(5u16)
+                .push(2u8)
+                .extend([0xFFu8; 2]),
+        );
+        check_custom(
+            BeBuffer::new()
+                .push(2u8)
+                .push(5u16)
+                .push(2u16)
+                .extend([0xFFu8; 3]),
+        );
+    }
+
+    #[test]
+    fn predefined() {
+        let iso_adobe = Charset::new(FontData::new(&[]), 0, 300).unwrap();
+        assert!(matches!(iso_adobe.kind(), CharsetKind::IsoAdobe));
+        assert_eq!(
+            iso_adobe.glyph_id(StringId::new(34)).unwrap(),
+            GlyphId::new(34)
+        );
+        assert!(iso_adobe.string_id(GlyphId::new(229)).is_err());
+        let expert = Charset::new(FontData::new(&[]), 1, 166).unwrap();
+        // glyph 1 in the expert charset is "space"
+        assert_eq!(expert.string_id(GlyphId::new(1)).unwrap(), StringId::new(1));
+        let expert_subset = Charset::new(FontData::new(&[]), 2, 87).unwrap();
+        assert_eq!(
+            expert_subset.glyph_id(StringId::new(1)).unwrap(),
+            GlyphId::new(1)
+        );
+    }
+}
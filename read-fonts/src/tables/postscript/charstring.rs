@@ -1,5 +1,7 @@
 //! Parsing for PostScript charstrings.
 
+#[cfg(feature = "std")]
+use super::Number;
 use super::{BlendState, Error, Index, Stack};
 use crate::{
     types::{Fixed, Pen, Point},
@@ -102,6 +104,168 @@ pub fn evaluate(
     Ok(())
 }
 
+/// Evaluates the given charstring, emits the resulting commands to the
+/// specified sink and returns the width operand, if present.
+///
+/// In CFF charstrings, the advance width is an optional first operand that
+/// is added to `nominalWidthX` from the associated Private DICT. If it is
+/// absent, the width is `defaultWidthX`. CFF2 charstrings never contain
+/// a width.
+///
+/// See "3.1 Charstring Organization" at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=11>
+/// and [`evaluate`] for a description of the parameters.
+pub fn evaluate_with_width(
+    charstring_data: &[u8],
+    global_subrs: Index,
+    subrs: Option<Index>,
+    blend_state: Option<BlendState>,
+    sink: &mut impl CommandSink,
+) -> Result<Option<Fixed>, Error> {
+    let mut evaluator = Evaluator::new(global_subrs, subrs, blend_state, sink);
+    evaluator.evaluate(charstring_data, 0)?;
+    Ok(evaluator.width)
+}
+
+/// Components of a glyph that is composed from a base and an accent
+/// character using the deprecated `seac` form of the `endchar` operator.
+///
+/// The base and accent are specified as character codes in the CFF
+/// Standard Encoding. See [`standard_encoding`](super::standard_encoding).
+///
+/// See "Appendix C Compatibility and Deprecated Operators" at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=35>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Seac {
+    /// Horizontal offset of the accent relative to the base.
+    pub adx: Fixed,
+    /// Vertical offset of the accent relative to the base.
+    pub ady: Fixed,
+    /// Standard encoding code of the base character.
+    pub base_code: u8,
+    /// Standard encoding code of the accent character.
+    pub accent_code: u8,
+}
+
+/// Evaluates the given charstring and returns the base and accent
+/// components if the glyph is defined with the `seac` form of the `endchar`
+/// operator.
+///
+/// Returns `Error::InvalidSeacCode` if a character code is outside the
+/// standard encoding.
+///
+/// See [`evaluate`] for a description of the parameters.
+pub fn seac_components(
+    charstring_data: &[u8],
+    global_subrs: Index,
+    subrs: Option<Index>,
+    blend_state: Option<BlendState>,
+) -> Result<Option<Seac>, Error> {
+    struct NullSink;
+    impl CommandSink for NullSink {
+        fn move_to(&mut self, _: Fixed, _: Fixed) {}
+        fn line_to(&mut self, _: Fixed, _: Fixed) {}
+        fn curve_to(&mut self, _: Fixed, _: Fixed, _: Fixed, _: Fixed, _: Fixed, _: Fixed) {}
+        fn close(&mut self) {}
+    }
+    let mut sink = NullSink;
+    let mut evaluator = Evaluator::new(global_subrs, subrs, blend_state, &mut sink);
+    evaluator.evaluate(charstring_data, 0)?;
+    evaluator.seac_components()
+}
+
+/// Evaluates the given charstring and emits the resulting commands to the
+/// specified sink, composing the outlines of glyphs that are defined with
+/// the `seac` form of the `endchar` operator.
+///
+/// For such glyphs, the charstring of the base character is evaluated
+/// followed by the charstring of the accent character, offset by the
+/// accent position. The `seac_component` function receives a character
+/// code in the CFF Standard Encoding and returns the charstring of the
+/// corresponding glyph. Components are evaluated with the same subroutines
+/// as the composed glyph and may not themselves use `seac`.
+///
+/// Returns `Error::InvalidSeacCode` if a component could not be found or
+/// a character code is outside the standard encoding.
+///
+/// See [`evaluate`] for a description of the other parameters and
+/// [`Seac`] for more detail.
+pub fn evaluate_with_seac<'a>(
+    charstring_data: &[u8],
+    global_subrs: Index<'a>,
+    subrs: Option<Index<'a>>,
+    blend_state: Option<BlendState<'a>>,
+    seac_component: impl Fn(u8) -> Option<&'a [u8]>,
+    sink: &mut impl CommandSink,
+) -> Result<(), Error> {
+    let seac = {
+        let mut evaluator = Evaluator::new(global_subrs.clone(), subrs.clone(), blend_state, sink);
+        evaluator.evaluate(charstring_data, 0)?;
+        evaluator.seac_components()?
+    };
+    let Some(seac) = seac else {
+        return Ok(());
+    };
+    let base =
+        seac_component(seac.base_code).ok_or(Error::InvalidSeacCode(seac.base_code as i32))?;
+    let accent =
+        seac_component(seac.accent_code).ok_or(Error::InvalidSeacCode(seac.accent_code as i32))?;
+    evaluate(base, global_subrs.clone(), subrs.clone(), None, sink)?;
+    let mut accent_sink = TranslatingSink {
+        inner: sink,
+        dx: seac.adx,
+        dy: seac.ady,
+    };
+    evaluate(accent, global_subrs, subrs, None, &mut accent_sink)
+}
+
+/// Command sink adapter that offsets all points by a fixed amount.
+struct TranslatingSink<'a, S> {
+    inner: &'a mut S,
+    dx: Fixed,
+    dy: Fixed,
+}
+
+impl<S: CommandSink> CommandSink for TranslatingSink<'_, S> {
+    fn move_to(&mut self, x: Fixed, y: Fixed) {
+        self.inner.move_to(x + self.dx, y + self.dy);
+    }
+
+    fn line_to(&mut self, x: Fixed, y: Fixed) {
+        self.inner.line_to(x + self.dx, y + self.dy);
+    }
+
+    fn curve_to(&mut self, cx0: Fixed, cy0: Fixed, cx1: Fixed, cy1: Fixed, x: Fixed, y: Fixed) {
+        self.inner.curve_to(
+            cx0 + self.dx,
+            cy0 + self.dy,
+            cx1 + self.dx,
+            cy1 + self.dy,
+            x + self.dx,
+            y + self.dy,
+        );
+    }
+
+    fn close(&mut self) {
+        self.inner.close();
+    }
+
+    // The evaluator emits stems as a pair of edge coordinates
+    fn hstem(&mut self, y0: Fixed, y1: Fixed) {
+        self.inner.hstem(y0 + self.dy, y1 + self.dy);
+    }
+
+    fn vstem(&mut self, x0: Fixed, x1: Fixed) {
+        self.inner.vstem(x0 + self.dx, x1 + self.dx);
+    }
+
+    fn hint_mask(&mut self, mask: &[u8]) {
+        self.inner.hint_mask(mask);
+    }
+
+    fn counter_mask(&mut self, mask: &[u8]) {
+        self.inner.counter_mask(mask);
+    }
+}
+
 /// Transient state for evaluating a charstring and handling recursive
 /// subroutine calls.
 struct Evaluator<'a, S> {
@@ -111,11 +275,14 @@ struct Evaluator<'a, S> {
     sink: &'a mut S,
     is_open: bool,
     have_read_width: bool,
+    width: Option<Fixed>,
     stem_count: usize,
     x: Fixed,
     y: Fixed,
     stack: Stack,
     stack_ix: usize,
+    seac: Option<Seac>,
+    invalid_seac_code: Option<i32>,
 }
 
 impl<'a, S> Evaluator<'a, S>
@@ -135,11 +302,23 @@ where
             sink,
             is_open: false,
             have_read_width: false,
+            width: None,
             stem_count: 0,
             stack: Stack::new(),
             x: Fixed::ZERO,
             y: Fixed::ZERO,
             stack_ix: 0,
+            seac: None,
+            invalid_seac_code: None,
+        }
+    }
+
+    /// Returns the `seac` components found during evaluation or an error
+    /// if the character codes were outside the standard encoding.
+    fn seac_components(&self) -> Result<Option<Seac>, Error> {
+        match self.invalid_seac_code {
+            Some(value) => Err(Error::InvalidSeacCode(value)),
+            None => Ok(self.seac),
         }
     }
 
@@ -232,12 +411,46 @@ where
                 return Ok(false);
             }
             // End the current charstring
-            // TODO: handle implied 'seac' operator
+            // The outlines for the implied 'seac' operator are composed by
+            // `evaluate_with_seac`
             // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=21>
             // FT: <https://gitlab.freedesktop.org/freetype/freetype/-/blob/80a507a6b8e3d2906ad2c8ba69329bd2fb2a85ef/src/psaux/psintrp.c#L2463>
             EndChar => {
+                // Four arguments (five with a width) indicate the deprecated
+                // 'seac' operator. The accent offset and the base/accent
+                // character codes are always the final four values.
+                // Spec: <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=35>
+                let len = self.stack.len();
+                if len == 4 || (len == 5 && !self.have_read_width) {
+                    let [adx, ady, base_code, accent_code] =
+                        self.stack.fixed_array::<4>(len - 4)?;
+                    // codes index the 256 entry standard encoding; invalid
+                    // codes are only reported when the components are
+                    // requested
+                    let code = |value: Fixed| {
+                        let value = value.to_i32();
+                        u8::try_from(value).map_err(|_| value)
+                    };
+                    match (code(base_code), code(accent_code)) {
+                        (Ok(base_code), Ok(accent_code)) => {
+                            self.seac = Some(Seac {
+                                adx,
+                                ady,
+                                base_code,
+                                accent_code,
+                            });
+                        }
+                        (Err(value), _) | (_, Err(value)) => {
+                            self.invalid_seac_code = Some(value);
+                        }
+                    }
+                }
                 if !self.stack.is_empty() && !self.have_read_width {
                     self.have_read_width = true;
+                    // The seac arguments are not preceded by a width
+                    if len == 1 || len == 5 {
+                        self.width = Some(self.stack.get_fixed(0)?);
+                    }
                     self.stack.clear();
                 }
                 if self.is_open {
@@ -253,6 +466,7 @@ where
                 let mut i = 0;
                 let len = if self.stack.len_is_odd() && !self.have_read_width {
                     self.have_read_width = true;
+                    self.width = Some(self.stack.get_fixed(0)?);
                     i = 1;
                     self.stack.len() - 1
                 } else {
@@ -287,6 +501,7 @@ where
                 let mut i = 0;
                 let len = if self.stack.len_is_odd() && !self.have_read_width {
                     self.have_read_width = true;
+                    self.width = Some(self.stack.get_fixed(0)?);
                     i = 1;
                     self.stack.len() - 1
                 } else {
@@ -319,6 +534,7 @@ where
                 let mut i = 0;
                 if self.stack.len() == 3 && !self.have_read_width {
                     self.have_read_width = true;
+                    self.width = Some(self.stack.get_fixed(0)?);
                     i = 1;
                 }
                 if !self.is_open {
@@ -340,6 +556,7 @@ where
                 let mut i = 0;
                 if self.stack.len() == 2 && !self.have_read_width {
                     self.have_read_width = true;
+                    self.width = Some(self.stack.get_fixed(0)?);
                     i = 1;
                 }
                 if !self.is_open {
@@ -591,8 +808,8 @@ enum PointMode {
 /// See <https://learn.microsoft.com/en-us/typography/opentype/spec/cff2charstr#appendix-a-cff2-charstring-command-codes>
 // TODO: This is currently missing legacy math and logical operators.
 // fonttools doesn't even implement these: <https://github.com/fonttools/fonttools/blob/65598197c8afd415781f6667a7fb647c2c987fff/Lib/fontTools/misc/psCharStrings.py#L409>
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-enum Operator {
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Operator {
     HStem,
     VStem,
     VMoveTo,
@@ -683,6 +900,418 @@ impl Operator {
             _ => return None,
         })
     }
+
+    /// Returns the name of the operator as used in the specification.
+    pub fn name(self) -> &'static str {
+        use Operator::*;
+        match self {
+            HStem => "hstem",
+            VStem => "vstem",
+            VMoveTo => "vmoveto",
+            RLineTo => "rlineto",
+            HLineTo => "hlineto",
+            VLineTo => "vlineto",
+            RrCurveTo => "rrcurveto",
+            CallSubr => "callsubr",
+            Return => "return",
+            EndChar => "endchar",
+            VariationStoreIndex => "vsindex",
+            Blend => "blend",
+            HStemHm => "hstemhm",
+            HintMask => "hintmask",
+            CntrMask => "cntrmask",
+            RMoveTo => "rmoveto",
+            HMoveTo => "hmoveto",
+            VStemHm => "vstemhm",
+            RCurveLine => "rcurveline",
+            RLineCurve => "rlinecurve",
+            VvCurveTo => "vvcurveto",
+            HhCurveTo => "hhcurveto",
+            CallGsubr => "callgsubr",
+            VhCurveTo => "vhcurveto",
+            HvCurveTo => "hvcurveto",
+            HFlex => "hflex",
+            Flex => "flex",
+            HFlex1 => "hflex1",
+            Flex1 => "flex1",
+        }
+    }
+}
+
+impl std::fmt::Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A single instruction decoded from a charstring.
+///
+/// Each instruction holds an operator along with the operands that were
+/// pushed since the previous instruction, in the order they appear in the
+/// charstring. Operators that read additional data from the charstring or
+/// take special operands have dedicated variants.
+#[cfg(feature = "std")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Instruction<'a> {
+    /// An operator with its preceding operands.
+    Operator {
+        operator: Operator,
+        operands: Vec<Number>,
+    },
+    /// A `hintmask` or `cntrmask` operator along with the mask bytes that
+    /// follow it.
+    ///
+    /// Any operands define implicit vertical stem hints.
+    Mask {
+        operator: Operator,
+        operands: Vec<Number>,
+        mask: &'a [u8],
+    },
+    /// A `callsubr` or `callgsubr` operator.
+    ///
+    /// The subroutine number is not included in the operands. The `index`
+    /// field contains the number with the subroutine bias applied.
+    Call {
+        operator: Operator,
+        operands: Vec<Number>,
+        number: i32,
+        index: usize,
+    },
+    /// A `blend` operator.
+    ///
+    /// The final operand that specifies the number of blended values is
+    /// not included in the operands. See [`blend_operands`](Self::blend_operands)
+    /// to access the default values and deltas.
+    Blend {
+        operands: Vec<Number>,
+        value_count: usize,
+        region_count: usize,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<'a> Instruction<'a> {
+    /// Returns the operator for this instruction.
+    pub fn operator(&self) -> Operator {
+        match self {
+            Self::Operator { operator, .. }
+            | Self::Mask { operator, .. }
+            | Self::Call { operator, .. } => *operator,
+            Self::Blend { .. } => Operator::Blend,
+        }
+    }
+
+    /// Returns the operands that precede this instruction.
+    pub fn operands(&self) -> &[Number] {
+        match self {
+            Self::Operator { operands, .. }
+            | Self::Mask { operands, .. }
+            | Self::Call { operands, .. }
+            | Self::Blend { operands, .. } => operands,
+        }
+    }
+
+    /// For a `blend` operator, returns the default values and the deltas
+    /// for each value, grouped by value.
+    ///
+    /// Returns `None` for other operators or if the blend operands are not
+    /// all present in this instruction (for example, when some were pushed
+    /// before a subroutine call).
+    pub fn blend_operands(&self) -> Option<(&[Number], &[Number])> {
+        let Self::Blend {
+            operands,
+            value_count,
+            region_count,
+        } = self
+        else {
+            return None;
+        };
+        let len = value_count.checked_mul(region_count + 1)?;
+        let values = operands.get(operands.len().checked_sub(len)?..)?;
+        Some(values.split_at(*value_count))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for Instruction<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for operand in self.operands() {
+            write!(f, "{operand} ")?;
+        }
+        match self {
+            Self::Call { number, .. } => write!(f, "{number} ")?,
+            Self::Blend { value_count, .. } => write!(f, "{value_count} ")?,
+            _ => {}
+        }
+        f.write_str(self.operator().name())?;
+        if let Self::Mask { mask, .. } = self {
+            f.write_str(" ")?;
+            for byte in mask.iter() {
+                write!(f, "{byte:08b}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns an iterator that decodes the given charstring into a sequence
+/// of [`Instruction`]s.
+///
+/// Unlike [`evaluate`], this does not interpret the path construction
+/// operators and can be used to inspect the charstring exactly as it is
+/// encoded. Subroutines are followed to track the hint count (which
+/// determines the size of hint masks) but, by default, are not expanded.
+/// Use [`Disassembler::inline_subroutines`] to replace subroutine calls with
+/// the instructions of the called subroutine.
+///
+/// See [`evaluate`] for a description of the parameters.
+#[cfg(feature = "std")]
+pub fn disassemble<'a>(
+    charstring_data: &'a [u8],
+    global_subrs: Index<'a>,
+    subrs: Option<Index<'a>>,
+    blend_state: Option<BlendState<'a>>,
+) -> Disassembler<'a> {
+    Disassembler {
+        global_subrs,
+        subrs,
+        blend_state,
+        inline_subrs: false,
+        frames: vec![Frame {
+            cursor: crate::FontData::new(charstring_data).cursor(),
+            emit: true,
+        }],
+        operands: Vec::new(),
+        stack: Vec::new(),
+        stem_count: 0,
+    }
+}
+
+/// Iterator over the instructions in a charstring.
+///
+/// See [`disassemble`].
+#[cfg(feature = "std")]
+pub struct Disassembler<'a> {
+    global_subrs: Index<'a>,
+    subrs: Option<Index<'a>>,
+    blend_state: Option<BlendState<'a>>,
+    inline_subrs: bool,
+    frames: Vec<Frame<'a>>,
+    operands: Vec<Number>,
+    /// Operand stack of the charstring program. Values that are the result
+    /// of a blend are unknown.
+    stack: Vec<Option<Number>>,
+    stem_count: usize,
+}
+
+/// A charstring or subroutine that is being decoded.
+#[cfg(feature = "std")]
+struct Frame<'a> {
+    cursor: Cursor<'a>,
+    /// True if instructions in this frame are yielded by the iterator.
+    emit: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'a> Disassembler<'a> {
+    /// Expands subroutine calls, yielding the instructions of the called
+    /// subroutines in place of `callsubr`, `callgsubr` and `return`
+    /// operators.
+    pub fn inline_subroutines(mut self) -> Self {
+        self.inline_subrs = true;
+        self
+    }
+
+    fn push(&mut self, number: Number, emit: bool) -> Result<(), Error> {
+        if self.stack.len() >= super::stack::MAX_STACK {
+            return Err(Error::StackOverflow);
+        }
+        self.stack.push(Some(number));
+        if emit {
+            self.operands.push(number);
+        }
+        Ok(())
+    }
+
+    /// Removes the integer on the top of the stack which is consumed by the
+    /// current operator.
+    fn pop_i32(&mut self, emit: bool) -> Result<i32, Error> {
+        let number = self.stack.pop().ok_or(Error::StackUnderflow)?;
+        if emit {
+            self.operands.pop();
+        }
+        match number {
+            Some(Number::I32(value)) => Ok(value),
+            _ => Err(Error::ExpectedI32StackEntry(self.stack.len())),
+        }
+    }
+
+    fn decode_next(&mut self) -> Result<Option<Instruction<'a>>, Error> {
+        loop {
+            let Some(frame) = self.frames.last_mut() else {
+                return Ok(None);
+            };
+            if frame.cursor.remaining_bytes() == 0 {
+                self.frames.pop();
+                continue;
+            }
+            let emit = frame.emit;
+            let cursor = &mut frame.cursor;
+            let b0 = cursor.read::<u8>()?;
+            let operator = match b0 {
+                // See "3.2 Charstring Number Encoding" <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf#page=12>
+                28 | 32..=254 => {
+                    let number = super::dict::parse_int(cursor, b0)?;
+                    self.push(number.into(), emit)?;
+                    continue;
+                }
+                255 => {
+                    let number = Fixed::from_bits(cursor.read::<i32>()?);
+                    self.push(number.into(), emit)?;
+                    continue;
+                }
+                _ => Operator::read(cursor, b0)?,
+            };
+            if let Some(instruction) = self.decode_operator(operator, emit)? {
+                return Ok(Some(instruction));
+            }
+        }
+    }
+
+    /// Updates the decoder state for the given operator and returns the
+    /// corresponding instruction if it should be yielded.
+    fn decode_operator(
+        &mut self,
+        operator: Operator,
+        emit: bool,
+    ) -> Result<Option<Instruction<'a>>, Error> {
+        use Operator::*;
+        let instruction = match operator {
+            HStem | VStem | HStemHm | VStemHm => {
+                // An odd operand count implies a leading width value
+                self.stem_count += self.stack.len() / 2;
+                self.stack.clear();
+                Instruction::Operator {
+                    operator,
+                    operands: core::mem::take(&mut self.operands),
+                }
+            }
+            HintMask | CntrMask => {
+                self.stem_count += self.stack.len() / 2;
+                self.stack.clear();
+                let count = self.stem_count.div_ceil(8);
+                let frame = self.frames.last_mut().ok_or(Error::StackUnderflow)?;
+                let mask = frame.cursor.read_array::<u8>(count)?;
+                Instruction::Mask {
+                    operator,
+                    operands: core::mem::take(&mut self.operands),
+                    mask,
+                }
+            }
+            CallSubr | CallGsubr => {
+                let number = self.pop_i32(emit)?;
+                let subrs_index = if operator == CallSubr {
+                    self.subrs.as_ref().ok_or(Error::MissingSubroutines)?
+                } else {
+                    &self.global_subrs
+                };
+                let index = usize::try_from(number + subrs_index.subr_bias())
+                    .map_err(|_| crate::ReadError::OutOfBounds)?;
+                let subr_data = subrs_index.get(index)?;
+                if self.frames.len() > NESTING_DEPTH_LIMIT as usize {
+                    return Err(Error::CharstringNestingDepthLimitExceeded);
+                }
+                self.frames.push(Frame {
+                    cursor: crate::FontData::new(subr_data).cursor(),
+                    emit: emit && self.inline_subrs,
+                });
+                if self.inline_subrs {
+                    // Remaining operands are consumed by the subroutine
+                    return Ok(None);
+                }
+                Instruction::Call {
+                    operator,
+                    operands: core::mem::take(&mut self.operands),
+                    number,
+                    index,
+                }
+            }
+            Return => {
+                self.frames.pop();
+                if self.inline_subrs {
+                    return Ok(None);
+                }
+                Instruction::Operator {
+                    operator,
+                    operands: core::mem::take(&mut self.operands),
+                }
+            }
+            EndChar => {
+                self.stack.clear();
+                // In a subroutine that is not being expanded, only the
+                // subroutine itself ends here
+                if emit {
+                    self.frames.clear();
+                } else {
+                    self.frames.pop();
+                }
+                Instruction::Operator {
+                    operator,
+                    operands: core::mem::take(&mut self.operands),
+                }
+            }
+            VariationStoreIndex => {
+                let store_index = self.pop_i32(false)?;
+                let blend_state = self.blend_state.as_mut().ok_or(Error::MissingBlendState)?;
+                blend_state.set_store_index(store_index as u16)?;
+                Instruction::Operator {
+                    operator,
+                    operands: core::mem::take(&mut self.operands),
+                }
+            }
+            Blend => {
+                let value_count = self.pop_i32(emit)?.max(0) as usize;
+                let blend_state = self.blend_state.as_ref().ok_or(Error::MissingBlendState)?;
+                let region_count = blend_state.region_count()?;
+                // The blended values replace the defaults and deltas
+                let start = value_count
+                    .checked_mul(region_count + 1)
+                    .and_then(|len| self.stack.len().checked_sub(len))
+                    .ok_or(Error::StackUnderflow)?;
+                self.stack.truncate(start);
+                self.stack.resize(start + value_count, None);
+                Instruction::Blend {
+                    operands: core::mem::take(&mut self.operands),
+                    value_count,
+                    region_count,
+                }
+            }
+            _ => {
+                self.stack.clear();
+                Instruction::Operator {
+                    operator,
+                    operands: core::mem::take(&mut self.operands),
+                }
+            }
+        };
+        Ok(emit.then_some(instruction))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Iterator for Disassembler<'a> {
+    type Item = Result<Instruction<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.decode_next() {
+            Ok(instruction) => instruction.map(Ok),
+            Err(error) => {
+                // Stop after the first error
+                self.frames.clear();
+                Some(Err(error))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1048,4 +1677,169 @@ mod tests {
         ];
         assert_eq!(&commands.0, expected);
     }
+
+    #[test]
+    fn seac() {
+        let empty_index_bytes = [0u8; 8];
+        let empty_index = Index::new(&empty_index_bytes, false).unwrap();
+        // 10 20 65 193 endchar
+        let charstring = &[149, 159, 204, 247, 85, 14];
+        let seac = seac_components(charstring, empty_index.clone(), None, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            seac,
+            Seac {
+                adx: Fixed::from_i32(10),
+                ady: Fixed::from_i32(20),
+                base_code: 65,
+                accent_code: 193,
+            }
+        );
+        // Same with a leading width argument
+        let charstring = &[239, 149, 159, 204, 247, 85, 14];
+        let seac_with_width = seac_components(charstring, empty_index.clone(), None, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(seac_with_width, seac);
+        // Plain endchar is not seac
+        let seac = seac_components(&[14], empty_index.clone(), None, None).unwrap();
+        assert!(seac.is_none());
+        // Codes outside the standard encoding are an error
+        // 10 20 300 65 endchar
+        let charstring = &[149, 159, 247, 192, 204, 14];
+        let result = seac_components(charstring, empty_index.clone(), None, None);
+        assert!(matches!(result, Err(Error::InvalidSeacCode(300))));
+        // 10 20 65 -5 endchar
+        let charstring = &[149, 159, 204, 134, 14];
+        let result = seac_components(charstring, empty_index.clone(), None, None);
+        assert!(matches!(result, Err(Error::InvalidSeacCode(-5))));
+        // Plain evaluation ignores the invalid codes
+        let mut commands = CaptureCommandSink::default();
+        evaluate(charstring, empty_index, None, None, &mut commands).unwrap();
+    }
+
+    #[test]
+    fn evaluate_seac() {
+        let empty_index_bytes = [0u8; 8];
+        let empty_index = Index::new(&empty_index_bytes, false).unwrap();
+        // 10 20 65 193 endchar
+        let charstring = &[149, 159, 204, 247, 85, 14];
+        // base: 0 0 rmoveto 100 hlineto endchar
+        let base: &[u8] = &[139, 139, 21, 239, 6, 14];
+        // accent: 5 0 rmoveto 0 50 rlineto endchar
+        let accent: &[u8] = &[144, 139, 21, 139, 189, 5, 14];
+        let components = |code| match code {
+            65 => Some(base),
+            193 => Some(accent),
+            _ => None,
+        };
+        let mut commands = CaptureCommandSink::default();
+        evaluate_with_seac(
+            charstring,
+            empty_index.clone(),
+            None,
+            None,
+            components,
+            &mut commands,
+        )
+        .unwrap();
+        use Command::*;
+        let expected = &[
+            MoveTo(Fixed::ZERO, Fixed::ZERO),
+            LineTo(Fixed::from_i32(100), Fixed::ZERO),
+            LineTo(Fixed::ZERO, Fixed::ZERO),
+            // accent is offset by (10, 20)
+            MoveTo(Fixed::from_i32(15), Fixed::from_i32(20)),
+            LineTo(Fixed::from_i32(15), Fixed::from_i32(70)),
+            LineTo(Fixed::from_i32(15), Fixed::from_i32(20)),
+        ];
+        assert_eq!(&commands.0, expected);
+        // Missing components are an error
+        let result = evaluate_with_seac(
+            charstring,
+            empty_index,
+            None,
+            None,
+            |code| (code == 65).then_some(base),
+            &mut CaptureCommandSink::default(),
+        );
+        assert!(matches!(result, Err(Error::InvalidSeacCode(193))));
+    }
+
+    #[test]
+    fn disassemble_cff2_example() {
+        let charstring = &font_test_data::cff2::EXAMPLE[0xc8..=0xe1];
+        let empty_index_bytes = [0u8; 8];
+        let store =
+            ItemVariationStore::read(FontData::new(&font_test_data::cff2::EXAMPLE[18..])).unwrap();
+        let global_subrs = Index::new(&empty_index_bytes, true).unwrap();
+        let blend_state = BlendState::new(store, &[], 0).unwrap();
+        let instructions = disassemble(charstring, global_subrs, None, Some(blend_state))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let text = instructions
+            .iter()
+            .map(|instruction| instruction.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(
+            text,
+            "50 50 100 1 blend 0 rmoveto 500 -100 -200 1 blend hlineto 500 vlineto \
+             -500 100 200 1 blend hlineto"
+        );
+        let (defaults, deltas) = instructions[0].blend_operands().unwrap();
+        assert_eq!(defaults, &[Number::I32(50)]);
+        assert_eq!(deltas, &[Number::I32(50), Number::I32(100)]);
+    }
+
+    #[test]
+    fn disassemble_subr_and_hintmask() {
+        // A single global subroutine: 30 40 hstem return
+        let global_subrs_bytes = [0, 1, 1, 1, 5, 169, 179, 1, 11];
+        let global_subrs = Index::new(&global_subrs_bytes, false).unwrap();
+        // -107 callgsubr hintmask 0x80 10 20 rmoveto endchar
+        let charstring = &[32, 29, 19, 0x80, 149, 159, 21, 14];
+        let instructions = disassemble(charstring, global_subrs.clone(), None, None)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            instructions,
+            [
+                Instruction::Call {
+                    operator: Operator::CallGsubr,
+                    operands: vec![],
+                    number: -107,
+                    index: 0,
+                },
+                // The hint mask size depends on the stems in the subroutine
+                Instruction::Mask {
+                    operator: Operator::HintMask,
+                    operands: vec![],
+                    mask: &[0x80],
+                },
+                Instruction::Operator {
+                    operator: Operator::RMoveTo,
+                    operands: vec![Number::I32(10), Number::I32(20)],
+                },
+                Instruction::Operator {
+                    operator: Operator::EndChar,
+                    operands: vec![],
+                },
+            ]
+        );
+        let inlined = disassemble(charstring, global_subrs, None, None)
+            .inline_subroutines()
+            .map(|instruction| instruction.unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            inlined,
+            [
+                "30 40 hstem",
+                "hintmask 10000000",
+                "10 20 rmoveto",
+                "endchar"
+            ]
+        );
+    }
 }
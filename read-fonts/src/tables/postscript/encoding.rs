@@ -0,0 +1,55 @@
+//! CFF predefined encodings.
+
+use super::StringId;
+
+/// Returns the string identifier for the given character code in the
+/// CFF Standard Encoding.
+///
+/// This is used to resolve the base and accent characters of the deprecated
+/// `seac` form of the `endchar` charstring operator.
+///
+/// See "Standard" encoding at <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf#page=37>
+pub fn standard_encoding(code: u8) -> StringId {
+    StringId::new(STANDARD_ENCODING[code as usize] as u16)
+}
+
+/// Mapping of character codes to string identifiers in the Standard
+/// Encoding.
+#[rustfmt::skip]
+const STANDARD_ENCODING: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+    65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110,
+    0, 111, 112, 113, 114, 0, 115, 116, 117, 118, 119, 120, 121, 122, 0, 123,
+    0, 124, 125, 126, 127, 128, 129, 130, 131, 0, 132, 133, 0, 134, 135, 136,
+    137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 138, 0, 139, 0, 0, 0, 0, 140, 141, 142, 143, 0, 0, 0, 0,
+    0, 144, 0, 0, 0, 145, 0, 0, 146, 147, 148, 149, 0, 0, 0, 0,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::standard_encoding;
+
+    #[test]
+    fn standard_encoding_names() {
+        let name = |code: u8| standard_encoding(code).standard_string().unwrap();
+        assert_eq!(name(0), ".notdef");
+        assert_eq!(name(b'A'), "A");
+        assert_eq!(name(b'~'), "asciitilde");
+        assert_eq!(name(0xA1), "exclamdown");
+        assert_eq!(name(0xC1), "grave");
+        assert_eq!(name(0xCF), "caron");
+        assert_eq!(name(0xE1), "AE");
+        assert_eq!(name(0xFB), "germandbls");
+        assert_eq!(name(0xFF), ".notdef");
+    }
+}
@@ -10,7 +10,7 @@ use super::{BlendState, Error};
 /// preceded by up to a maximum of 513 operands."
 ///
 /// <https://learn.microsoft.com/en-us/typography/opentype/spec/cff2#table-9-top-dict-operator-entries>
-const MAX_STACK: usize = 513;
+pub(super) const MAX_STACK: usize = 513;
 
 /// Operand stack for DICTs and charstrings.
 ///
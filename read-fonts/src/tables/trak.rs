@@ -0,0 +1,63 @@
+//! The [tracking (trak)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6trak.html) table
+
+include!("../../generated/generated_trak.rs");
+
+impl<'a> Trak<'a> {
+    /// Returns the point sizes for the given tracking data.
+    pub fn sizes(&self, track_data: &TrackData) -> Result<&'a [BigEndian<Fixed>], ReadError> {
+        let start = track_data.size_table_offset() as usize;
+        let len = track_data.n_sizes() as usize * Fixed::RAW_BYTE_LEN;
+        self.offset_data().read_array(start..start + len)
+    }
+
+    /// Returns the tracking values for each point size of the given track.
+    pub fn values(
+        &self,
+        track_data: &TrackData,
+        track: &TrackTableEntry,
+    ) -> Result<&'a [BigEndian<i16>], ReadError> {
+        let start = track.offset() as usize;
+        let len = track_data.n_sizes() as usize * i16::RAW_BYTE_LEN;
+        self.offset_data().read_array(start..start + len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn horizontal_tracks() {
+        let data = BeBuffer::new()
+            // version, format, horizOffset, vertOffset, reserved
+            .push(MajorMinor::VERSION_1_0)
+            .extend([0u16, 12, 0, 0])
+            // track data: nTracks, nSizes, sizeTableOffset
+            .extend([2u16, 2])
+            .push(36u32)
+            // tracks: track, nameIndex, offset
+            .push(Fixed::from_i32(-1))
+            .extend([256u16, 44])
+            .push(Fixed::from_i32(0))
+            .extend([257u16, 48])
+            // sizes
+            .push(Fixed::from_i32(12))
+            .push(Fixed::from_i32(24))
+            // values
+            .extend([-15i16, -7, 0, 0]);
+        let trak = Trak::read(data.font_data()).unwrap();
+        assert!(trak.vert().is_none());
+        let horiz = trak.horiz().unwrap().unwrap();
+        let sizes = trak.sizes(&horiz).unwrap();
+        assert_eq!(sizes[1].get(), Fixed::from_i32(24));
+        let tracks = horiz.track_table();
+        assert_eq!(tracks[0].track(), Fixed::from_i32(-1));
+        assert_eq!(tracks[1].name_index(), NameId::new(257));
+        let values = trak.values(&horiz, &tracks[0]).unwrap();
+        assert_eq!(
+            values.iter().map(|v| v.get()).collect::<Vec<_>>(),
+            [-15, -7]
+        );
+    }
+}
@@ -7,7 +7,10 @@ use super::ValueFormat;
 use crate::{tables::layout::DeviceOrVariationIndex, ResolveNullableOffset};
 
 #[cfg(feature = "traversal")]
-use crate::traversal::{Field, FieldType, RecordResolver, SomeRecord};
+use crate::{
+    array::ComputedArray,
+    traversal::{Field, FieldType, RecordResolver, SomeArray, SomeRecord},
+};
 use crate::{ComputeSize, FontData, FontReadWithArgs, ReadArgs, ReadError};
 
 impl ValueFormat {
@@ -152,30 +155,121 @@ impl std::fmt::Debug for ValueRecord {
 
 impl ComputeSize for ValueRecord {
     #[inline]
-    fn compute_size(args: &ValueFormat) -> usize {
-        args.record_byte_len()
+    fn compute_size(args: &ValueFormat) -> Result<usize, ReadError> {
+        Ok(args.record_byte_len())
     }
 }
 
 #[cfg(feature = "traversal")]
 impl<'a> ValueRecord {
-    pub(crate) fn traversal_type(&self, data: FontData<'a>) -> FieldType<'a> {
-        FieldType::Record(self.clone().traverse(data))
+    /// Returns the traversal type for a record that was read with the given
+    /// format.
+    pub(crate) fn traversal_type(&self, data: FontData<'a>, format: ValueFormat) -> FieldType<'a> {
+        FieldType::Record(self.clone().traverse_with_format(data, format))
     }
 
-    pub(crate) fn get_field(&self, idx: usize, data: FontData<'a>) -> Option<Field<'a>> {
+    /// Returns the traversal type for an array of records.
+    pub(crate) fn traverse_array(
+        array: ComputedArray<'a, ValueRecord>,
+        data: FontData<'a>,
+        format: ValueFormat,
+    ) -> FieldType<'a> {
+        FieldType::Array(Box::new(ValueRecordArray {
+            array,
+            data,
+            format,
+        }))
+    }
+
+    fn traverse_with_format(self, data: FontData<'a>, format: ValueFormat) -> RecordResolver<'a> {
+        RecordResolver {
+            name: "ValueRecord",
+            data,
+            get_field: Box::new(move |idx, data| self.get_field(idx, data, format)),
+            bytes: None,
+        }
+    }
+
+    /// Returns the format implied by the fields that are present.
+    ///
+    /// This omits device offsets that are present in the original format but
+    /// null.
+    fn present_format(&self) -> ValueFormat {
+        [
+            (ValueFormat::X_PLACEMENT, self.x_placement.is_some()),
+            (ValueFormat::Y_PLACEMENT, self.y_placement.is_some()),
+            (ValueFormat::X_ADVANCE, self.x_advance.is_some()),
+            (ValueFormat::Y_ADVANCE, self.y_advance.is_some()),
+            (
+                ValueFormat::X_PLACEMENT_DEVICE,
+                !self.x_placement_device.get().is_null(),
+            ),
+            (
+                ValueFormat::Y_PLACEMENT_DEVICE,
+                !self.y_placement_device.get().is_null(),
+            ),
+            (
+                ValueFormat::X_ADVANCE_DEVICE,
+                !self.x_advance_device.get().is_null(),
+            ),
+            (
+                ValueFormat::Y_ADVANCE_DEVICE,
+                !self.y_advance_device.get().is_null(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, present)| *present)
+        .fold(ValueFormat::empty(), |format, (flag, _)| format | flag)
+    }
+
+    fn get_field(&self, idx: usize, data: FontData<'a>, format: ValueFormat) -> Option<Field<'a>> {
         let fields = [
-            self.x_placement.is_some().then_some("x_placement"),
-            self.y_placement.is_some().then_some("y_placement"),
-            self.x_advance.is_some().then_some("x_advance"),
-            self.y_advance.is_some().then_some("y_advance"),
-            (!self.x_placement_device.get().is_null()).then_some("x_placement_device"),
-            (!self.y_placement_device.get().is_null()).then_some("y_placement_device"),
-            (!self.x_advance_device.get().is_null()).then_some("x_advance_device"),
-            (!self.y_advance_device.get().is_null()).then_some("y_advance_device"),
+            (
+                ValueFormat::X_PLACEMENT,
+                self.x_placement.is_some(),
+                "x_placement",
+            ),
+            (
+                ValueFormat::Y_PLACEMENT,
+                self.y_placement.is_some(),
+                "y_placement",
+            ),
+            (
+                ValueFormat::X_ADVANCE,
+                self.x_advance.is_some(),
+                "x_advance",
+            ),
+            (
+                ValueFormat::Y_ADVANCE,
+                self.y_advance.is_some(),
+                "y_advance",
+            ),
+            (
+                ValueFormat::X_PLACEMENT_DEVICE,
+                !self.x_placement_device.get().is_null(),
+                "x_placement_device",
+            ),
+            (
+                ValueFormat::Y_PLACEMENT_DEVICE,
+                !self.y_placement_device.get().is_null(),
+                "y_placement_device",
+            ),
+            (
+                ValueFormat::X_ADVANCE_DEVICE,
+                !self.x_advance_device.get().is_null(),
+                "x_advance_device",
+            ),
+            (
+                ValueFormat::Y_ADVANCE_DEVICE,
+                !self.y_advance_device.get().is_null(),
+                "y_advance_device",
+            ),
         ];
 
-        let name = fields.iter().filter_map(|x| *x).nth(idx)?;
+        let (flag, _, name) = fields
+            .into_iter()
+            .filter(|(_, present, _)| *present)
+            .nth(idx)?;
         let typ: FieldType = match name {
             "x_placement" => self.x_placement().unwrap().into(),
             "y_placement" => self.y_placement().unwrap().into(),
@@ -196,17 +290,51 @@ impl<'a> ValueRecord {
             _ => panic!("hmm"),
         };
 
-        Some(Field::new(name, typ))
+        // null device offsets are skipped above but still occupy two bytes,
+        // so the position depends on every field in the format before this one
+        let preceding = format.bits() & (flag.bits() - 1);
+        let start = preceding.count_ones() as usize * u16::RAW_BYTE_LEN;
+        Some(Field::new(name, typ).with_record_range(start, u16::RAW_BYTE_LEN))
     }
 }
 
+// records that contain value records do not provide their formats, so the
+// format is inferred from the fields that are present
 #[cfg(feature = "traversal")]
 impl<'a> SomeRecord<'a> for ValueRecord {
     fn traverse(self, data: FontData<'a>) -> RecordResolver<'a> {
-        RecordResolver {
-            name: "ValueRecord",
-            data,
-            get_field: Box::new(move |idx, data| self.get_field(idx, data)),
-        }
+        let format = self.present_format();
+        self.traverse_with_format(data, format)
+    }
+}
+
+#[cfg(feature = "traversal")]
+struct ValueRecordArray<'a> {
+    array: ComputedArray<'a, ValueRecord>,
+    data: FontData<'a>,
+    format: ValueFormat,
+}
+
+#[cfg(feature = "traversal")]
+impl<'a> SomeArray<'a> for ValueRecordArray<'a> {
+    fn type_name(&self) -> &str {
+        "ValueRecord"
+    }
+
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    fn get(&self, idx: usize) -> Option<FieldType<'a>> {
+        self.array.get(idx).ok().map(|record| {
+            record
+                .traverse_with_format(self.data, self.format)
+                .with_bytes(self.get_bytes(idx))
+                .into()
+        })
+    }
+
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        self.array.item_bytes(idx)
     }
 }
@@ -179,6 +179,58 @@ impl<'a> TupleVariationHeader<'a> {
         })
     }
 
+    /// Compute the scalar for a tuple with the given peak at a point in
+    /// design space.
+    ///
+    /// Returns `None` if the tuple is not applicable at the provided
+    /// coordinates (e.g. if the resulting scalar is zero).
+    pub(crate) fn compute_scalar(
+        &self,
+        peak: &Tuple,
+        axis_count: u16,
+        coords: &[F2Dot14],
+    ) -> Option<Fixed> {
+        const ZERO: Fixed = Fixed::ZERO;
+        let mut scalar = Fixed::ONE;
+        let inter_start = self.intermediate_start_tuple();
+        let inter_end = self.intermediate_end_tuple();
+        if peak.len() != axis_count as usize {
+            return None;
+        }
+
+        for i in 0..axis_count {
+            let i = i as usize;
+            let coord = coords.get(i).copied().unwrap_or_default().to_fixed();
+            let peak = peak.get(i).unwrap_or_default().to_fixed();
+            if peak == ZERO || peak == coord {
+                continue;
+            }
+
+            if coord == ZERO {
+                return None;
+            }
+
+            if let (Some(inter_start), Some(inter_end)) = (&inter_start, &inter_end) {
+                let start = inter_start.get(i).unwrap_or_default().to_fixed();
+                let end = inter_end.get(i).unwrap_or_default().to_fixed();
+                if coord <= start || coord >= end {
+                    return None;
+                }
+                if coord < peak {
+                    scalar = scalar.mul_div(coord - start, peak - start);
+                } else {
+                    scalar = scalar.mul_div(end - coord, end - peak);
+                }
+            } else {
+                if coord < peak.min(ZERO) || coord > peak.max(ZERO) {
+                    return None;
+                }
+                scalar = scalar.mul_div(coord, peak);
+            }
+        }
+        Some(scalar)
+    }
+
     /// Compute the actual length of this table in bytes
     fn byte_len(&self, axis_count: u16) -> usize {
         const FIXED_LEN: usize = u16::RAW_BYTE_LEN + TupleIndex::RAW_BYTE_LEN;
@@ -545,12 +597,24 @@ impl<'a> ItemVariationStore<'a> {
         &self,
         index: DeltaSetIndex,
         coords: &[F2Dot14],
+    ) -> Result<i32, ReadError> {
+        let regions = self.variation_region_list()?.variation_regions();
+        self.compute_delta_with(index, |region_index| {
+            Ok(regions.get(region_index)?.compute_scalar(coords))
+        })
+    }
+
+    /// Computes the delta value for the specified index, using the given
+    /// function to produce the scalar for each referenced region.
+    fn compute_delta_with(
+        &self,
+        index: DeltaSetIndex,
+        mut region_scalar: impl FnMut(usize) -> Result<Fixed, ReadError>,
     ) -> Result<i32, ReadError> {
         let data = match self.item_variation_data().get(index.outer as usize) {
             Some(data) => data?,
             None => return Ok(0),
         };
-        let regions = self.variation_region_list()?.variation_regions();
         let region_indices = data.region_indexes();
         // Compute deltas with 64-bit precision.
         // See <https://gitlab.freedesktop.org/freetype/freetype/-/blob/7ab541a2/src/truetype/ttgxvar.c#L1094>
@@ -562,14 +626,67 @@ impl<'a> ItemVariationStore<'a> {
                     "invalid delta sets in ItemVariationStore",
                 ))?
                 .get() as usize;
-            let region = regions.get(region_index)?;
-            let scalar = region.compute_scalar(coords);
+            let scalar = region_scalar(region_index)?;
             accum += region_delta as i64 * scalar.to_bits() as i64;
         }
         Ok(((accum + 0x8000) >> 16) as i32)
     }
 }
 
+/// An [ItemVariationStore] with region scalars precomputed for a single
+/// location in variation space.
+///
+/// Computing a delta with [ItemVariationStore::compute_delta] evaluates the
+/// scalar of every referenced region on each call. When many deltas are
+/// needed at the same location (for example, while applying GPOS lookups or
+/// computing metrics for a run of glyphs) this type evaluates each region
+/// once, up front.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct ItemVariationStoreInstance<'a> {
+    store: ItemVariationStore<'a>,
+    scalars: Vec<Fixed>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> ItemVariationStoreInstance<'a> {
+    /// Creates a new instance of the given store at the specified normalized
+    /// variation coordinates.
+    pub fn new(store: ItemVariationStore<'a>, coords: &[F2Dot14]) -> Result<Self, ReadError> {
+        let scalars = store
+            .variation_region_list()?
+            .variation_regions()
+            .iter()
+            .map(|region| region.map(|region| region.compute_scalar(coords)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { store, scalars })
+    }
+
+    /// Returns the underlying item variation store.
+    pub fn store(&self) -> &ItemVariationStore<'a> {
+        &self.store
+    }
+
+    /// Returns the precomputed scalars, indexed by region.
+    pub fn region_scalars(&self) -> &[Fixed] {
+        &self.scalars
+    }
+
+    /// Computes the delta value for the specified index.
+    ///
+    /// This produces the same result as [ItemVariationStore::compute_delta]
+    /// with the coordinates used to construct this instance, including the
+    /// error for an invalid index.
+    pub fn compute_delta(&self, index: DeltaSetIndex) -> Result<i32, ReadError> {
+        self.store.compute_delta_with(index, |region_index| {
+            self.scalars
+                .get(region_index)
+                .copied()
+                .ok_or(ReadError::OutOfBounds)
+        })
+    }
+}
+
 impl<'a> VariationRegion<'a> {
     /// Computes a scalar value for this region and the specified
     /// normalized variation coordinates.
@@ -655,14 +772,7 @@ pub(crate) fn advance_delta(
     glyph_id: GlyphId,
     coords: &[F2Dot14],
 ) -> Result<Fixed, ReadError> {
-    let gid = glyph_id.to_u16();
-    let ix = match dsim {
-        Some(Ok(dsim)) => dsim.get(gid as u32)?,
-        _ => DeltaSetIndex {
-            outer: 0,
-            inner: gid,
-        },
-    };
+    let ix = advance_delta_index(dsim, glyph_id)?;
     Ok(Fixed::from_i32(ivs?.compute_delta(ix, coords)?))
 }
 
@@ -672,14 +782,39 @@ pub(crate) fn item_delta(
     glyph_id: GlyphId,
     coords: &[F2Dot14],
 ) -> Result<Fixed, ReadError> {
-    let gid = glyph_id.to_u16();
-    let ix = match dsim {
-        Some(Ok(dsim)) => dsim.get(gid as u32)?,
-        _ => return Err(ReadError::NullOffset),
-    };
+    let ix = item_delta_index(dsim, glyph_id)?;
     Ok(Fixed::from_i32(ivs?.compute_delta(ix, coords)?))
 }
 
+/// Maps a glyph to a delta set index for an advance, where a missing
+/// mapping implies an implicit identity mapping into the first
+/// item variation data subtable.
+pub(crate) fn advance_delta_index(
+    dsim: Option<Result<DeltaSetIndexMap, ReadError>>,
+    glyph_id: GlyphId,
+) -> Result<DeltaSetIndex, ReadError> {
+    let gid = glyph_id.to_u16();
+    match dsim {
+        Some(Ok(dsim)) => dsim.get(gid as u32),
+        _ => Ok(DeltaSetIndex {
+            outer: 0,
+            inner: gid,
+        }),
+    }
+}
+
+/// Maps a glyph to a delta set index for a metric other than an advance,
+/// which requires an explicit mapping.
+pub(crate) fn item_delta_index(
+    dsim: Option<Result<DeltaSetIndexMap, ReadError>>,
+    glyph_id: GlyphId,
+) -> Result<DeltaSetIndex, ReadError> {
+    match dsim {
+        Some(Ok(dsim)) => dsim.get(glyph_id.to_u16() as u32),
+        _ => Err(ReadError::NullOffset),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -823,4 +958,45 @@ mod tests {
         assert_eq!(points.total_len(), 4);
         assert_eq!(data.len(), INPUT.len() - 4);
     }
+
+    #[test]
+    fn ivs_instance_matches_compute_delta() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let hvar = font.hvar().unwrap();
+        let ivs = hvar.item_variation_store().unwrap();
+        let glyph_count = font.maxp().unwrap().num_glyphs();
+        for coord in [-1.0, -0.75, -0.3, 0.0, 0.5, 1.0] {
+            let coords = [F2Dot14::from_f32(coord)];
+            let instance = ItemVariationStoreInstance::new(ivs.clone(), &coords).unwrap();
+            for gid in 0..glyph_count {
+                let index = hvar.advance_width_delta_index(GlyphId::new(gid)).unwrap();
+                assert_eq!(
+                    instance.compute_delta(index).unwrap(),
+                    ivs.compute_delta(index, &coords).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ivs_instance_invalid_index_at_default() {
+        use crate::test_helpers::BeBuffer;
+        // one region and a single item variation data that references
+        // the nonexistent region 5
+        let data = BeBuffer::new()
+            .push(1u16)
+            .extend([12u32])
+            .push(1u16)
+            .extend([22u32])
+            .extend([1u16, 1])
+            .extend([0u16, 0x4000, 0x4000])
+            .extend([1u16, 0, 1, 5])
+            .push(10u8);
+        let ivs = ItemVariationStore::read(data.font_data()).unwrap();
+        let coords = [F2Dot14::ZERO];
+        let instance = ItemVariationStoreInstance::new(ivs.clone(), &coords).unwrap();
+        let index = DeltaSetIndex { outer: 0, inner: 0 };
+        assert!(ivs.compute_delta(index, &coords).is_err());
+        assert!(instance.compute_delta(index).is_err());
+    }
 }
@@ -0,0 +1,81 @@
+//! The [VDMX (Vertical Device Metrics)](https://learn.microsoft.com/en-us/typography/opentype/spec/vdmx) table
+
+include!("../../generated/generated_vdmx.rs");
+
+impl<'a> Vdmx<'a> {
+    /// Returns the group of vertical metrics that applies to the given
+    /// horizontal and vertical resolution.
+    ///
+    /// This selects the first ratio range that matches the aspect ratio,
+    /// as described by the specification.
+    pub fn group_for_ratio(
+        &self,
+        x_res: u16,
+        y_res: u16,
+    ) -> Option<Result<VdmxGroup<'a>, ReadError>> {
+        let index = self
+            .rat_range()
+            .iter()
+            .position(|range| range.matches(x_res, y_res))?;
+        Some(self.vdmx_groups().get(index))
+    }
+}
+
+impl RatioRange {
+    /// Returns true if the given aspect ratio falls within this range.
+    ///
+    /// A range with all values set to zero matches any ratio.
+    pub fn matches(&self, x_res: u16, y_res: u16) -> bool {
+        let x_ratio = self.x_ratio() as u32;
+        if x_ratio == 0 && self.y_start_ratio() == 0 && self.y_end_ratio() == 0 {
+            return true;
+        }
+        let (x_res, y_res) = (x_res as u32, y_res as u32);
+        // compare y_res / x_res against y_ratio / x_ratio without division
+        x_res * self.y_start_ratio() as u32 <= y_res * x_ratio
+            && y_res * x_ratio <= x_res * self.y_end_ratio() as u32
+    }
+}
+
+impl<'a> VdmxGroup<'a> {
+    /// Returns the vertical metrics for the given height in pixels.
+    pub fn entry_for_height(&self, y_pel_height: u16) -> Option<&'a VTable> {
+        let entries = self.entries();
+        entries
+            .binary_search_by_key(&y_pel_height, |entry| entry.y_pel_height())
+            .ok()
+            .map(|index| &entries[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn group_and_entry() {
+        let data = BeBuffer::new()
+            // version, numRecs, numRatios
+            .extend([1u16, 1, 2])
+            // 1:1 ratio and a catch-all ratio
+            .extend([1u8, 1, 1, 1])
+            .extend([0u8, 0, 0, 0])
+            // both ratios share the group at offset 18
+            .extend([18u16, 18])
+            // group: recs, startsz, endsz
+            .push(2u16)
+            .extend([8u8, 9])
+            .extend([8u16, 7, (-2i16) as u16])
+            .extend([9u16, 8, (-3i16) as u16]);
+        let vdmx = Vdmx::read(data.font_data()).unwrap();
+        assert!(vdmx.rat_range()[0].matches(96, 96));
+        assert!(!vdmx.rat_range()[0].matches(72, 96));
+        assert!(vdmx.rat_range()[1].matches(72, 96));
+        let group = vdmx.group_for_ratio(72, 96).unwrap().unwrap();
+        assert_eq!(group.startsz(), 8);
+        let entry = group.entry_for_height(9).unwrap();
+        assert_eq!((entry.y_max(), entry.y_min()), (8, -3));
+        assert!(group.entry_for_height(10).is_none());
+    }
+}
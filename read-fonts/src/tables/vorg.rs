@@ -0,0 +1,35 @@
+//! The [VORG (Vertical Origin)](https://learn.microsoft.com/en-us/typography/opentype/spec/vorg) table
+
+include!("../../generated/generated_vorg.rs");
+
+impl<'a> Vorg<'a> {
+    /// Returns the y coordinate of the vertical origin for the given glyph.
+    ///
+    /// Falls back to the default vertical origin if the glyph has no
+    /// explicit entry.
+    pub fn vertical_origin_y(&self, glyph_id: GlyphId) -> i16 {
+        let metrics = self.vert_origin_y_metrics();
+        match metrics.binary_search_by(|metric| metric.glyph_id().cmp(&glyph_id)) {
+            Ok(index) => metrics[index].vert_origin_y(),
+            Err(_) => self.default_vert_origin_y(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+
+    #[test]
+    fn vertical_origins() {
+        let data = BeBuffer::new()
+            .push(MajorMinor::VERSION_1_0)
+            .extend([880i16, 2])
+            .extend([3u16, 900, 7, 850]);
+        let vorg = Vorg::read(data.font_data()).unwrap();
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(3)), 900);
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(7)), 850);
+        assert_eq!(vorg.vertical_origin_y(GlyphId::new(5)), 880);
+    }
+}
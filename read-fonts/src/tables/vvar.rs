@@ -1,6 +1,6 @@
 //! The [VVAR (Vertical Metrics Variation)](https://docs.microsoft.com/en-us/typography/opentype/spec/vvar) table
 
-use super::variations::{self, DeltaSetIndexMap, ItemVariationStore};
+use super::variations::{self, DeltaSetIndex, DeltaSetIndexMap, ItemVariationStore};
 
 include!("../../generated/generated_vvar.rs");
 
@@ -52,4 +52,43 @@ impl<'a> Vvar<'a> {
             coords,
         )
     }
+
+    /// Returns the delta set index of the advance height delta for the specified
+    /// glyph identifier.
+    ///
+    /// This can be used with an [ItemVariationStoreInstance](variations::ItemVariationStoreInstance)
+    /// to compute deltas for many glyphs at a single location.
+    pub fn advance_height_delta_index(
+        &self,
+        glyph_id: GlyphId,
+    ) -> Result<DeltaSetIndex, ReadError> {
+        variations::advance_delta_index(self.advance_height_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index of the top side bearing delta for the specified
+    /// glyph identifier.
+    ///
+    /// This can be used with an [ItemVariationStoreInstance](variations::ItemVariationStoreInstance)
+    /// to compute deltas for many glyphs at a single location.
+    pub fn tsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.tsb_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index of the bottom side bearing delta for the specified
+    /// glyph identifier.
+    ///
+    /// This can be used with an [ItemVariationStoreInstance](variations::ItemVariationStoreInstance)
+    /// to compute deltas for many glyphs at a single location.
+    pub fn bsb_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.bsb_mapping(), glyph_id)
+    }
+
+    /// Returns the delta set index of the vertical origin delta for the specified
+    /// glyph identifier.
+    ///
+    /// This can be used with an [ItemVariationStoreInstance](variations::ItemVariationStoreInstance)
+    /// to compute deltas for many glyphs at a single location.
+    pub fn v_org_delta_index(&self, glyph_id: GlyphId) -> Result<DeltaSetIndex, ReadError> {
+        variations::item_delta_index(self.v_org_mapping(), glyph_id)
+    }
 }
@@ -34,6 +34,47 @@ fn singleposformat2() {
     assert!(table.value_records().get(3).is_err());
 }
 
+// null device offsets are not traversed, but still occupy their slot
+#[test]
+#[cfg(feature = "traversal")]
+fn value_record_field_byte_ranges() {
+    use crate::test_helpers::BeBuffer;
+    use crate::traversal::{FieldType, SomeTable};
+
+    let format =
+        ValueFormat::X_ADVANCE | ValueFormat::X_PLACEMENT_DEVICE | ValueFormat::Y_PLACEMENT_DEVICE;
+    let data = BeBuffer::new()
+        // posFormat, coverageOffset, valueFormat
+        .extend([1u16, 12, format.bits()])
+        // valueRecord: xAdvance, xPlaDeviceOffset (null), yPlaDeviceOffset
+        .extend([20u16, 0, 18])
+        // coverage
+        .extend([1u16, 1, 5])
+        // variation index
+        .extend([0u16, 1, 0x8000]);
+    let table = SinglePosFormat1::read(data.font_data()).unwrap();
+    let table = &table as &dyn SomeTable;
+    let Some(FieldType::Record(record)) = table
+        .iter()
+        .find(|field| field.name == "value_record")
+        .map(|field| field.value)
+    else {
+        panic!("expected a record");
+    };
+    let record = &record as &dyn SomeTable;
+    let ranges = record
+        .iter()
+        .map(|field| (field.name, field.byte_range(&data)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ranges,
+        [
+            ("x_advance", Some(6..8)),
+            ("y_placement_device", Some(10..12))
+        ]
+    );
+}
+
 #[test]
 fn pairposformat1() {
     // https://docs.microsoft.com/en-us/typography/opentype/spec/gpos#example-4-pairposformat1-subtable
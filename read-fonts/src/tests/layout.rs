@@ -39,3 +39,147 @@ fn example_3_featurelist_and_feature() {
     assert!(feature.feature_params_offset().is_null());
     assert_eq!(feature.lookup_list_indices().len(), 1);
 }
+
+#[test]
+fn feature_variations_substitutions() {
+    use crate::test_helpers::BeBuffer;
+    let buf = BeBuffer::new()
+        // FeatureVariations: version, record count
+        .extend([1u16, 0])
+        .push(2u32)
+        // record 0: condition set, substitutions
+        .extend([24u32, 38])
+        // record 1: no condition set, substitutions
+        .extend([0u32, 56])
+        // ConditionSet: count, offset to condition
+        .push(1u16)
+        .push(6u32)
+        // ConditionFormat1: format, axis, min, max
+        .extend([1u16, 0])
+        .extend([F2Dot14::from_f32(0.5), F2Dot14::from_f32(1.0)])
+        // FeatureTableSubstitution: version, count, record (index 2)
+        .extend([1u16, 0, 1, 2])
+        .push(12u32)
+        // Feature: params, lookup count, lookup indices
+        .extend([0u16, 1, 7])
+        // FeatureTableSubstitution for record 1
+        .extend([1u16, 0, 1, 2])
+        .push(12u32)
+        .extend([0u16, 1, 9]);
+    let table = FeatureVariations::read(buf.font_data()).unwrap();
+    let lookups_at = |coord: f32, feature_index| {
+        let coords = [F2Dot14::from_f32(coord)];
+        table
+            .find_substitutions(&coords)
+            .unwrap()
+            .unwrap()
+            .find_alternate(feature_index, Tag::new(b"rvrn"))
+            .map(|feature| feature.unwrap().lookup_list_indices()[0].get())
+    };
+    assert_eq!(lookups_at(0.75, 2), Some(7));
+    assert_eq!(lookups_at(1.0, 2), Some(7));
+    // falls through to the unconditional record
+    assert_eq!(lookups_at(0.25, 2), Some(9));
+    assert_eq!(lookups_at(0.75, 1), None);
+}
+
+#[test]
+fn class_def_iter() {
+    use crate::test_helpers::BeBuffer;
+    // format 1: start glyph 4, classes [1, 0, 2]
+    let buf = BeBuffer::new().extend([1u16, 4, 3, 1, 0, 2]);
+    let class_def = ClassDef::read(buf.font_data()).unwrap();
+    let classes = class_def
+        .iter()
+        .map(|(gid, class)| (gid.to_u16(), class))
+        .collect::<Vec<_>>();
+    assert_eq!(classes, [(4, 1), (5, 0), (6, 2)]);
+    // format 2: ranges 2..=3 -> 1, 7..=7 -> 3
+    let buf = BeBuffer::new().extend([2u16, 2, 2, 3, 1, 7, 7, 3]);
+    let class_def = ClassDef::read(buf.font_data()).unwrap();
+    let classes = class_def
+        .iter()
+        .map(|(gid, class)| (gid.to_u16(), class))
+        .collect::<Vec<_>>();
+    assert_eq!(classes, [(2, 1), (3, 1), (7, 3)]);
+}
+
+#[test]
+fn class_def_sets() {
+    use crate::test_helpers::BeBuffer;
+    // format 1: start glyph 4, classes [1, 0, 2]
+    let buf = BeBuffer::new().extend([1u16, 4, 3, 1, 0, 2]);
+    let class_def = ClassDef::read(buf.font_data()).unwrap();
+    assert_eq!(class_def.classes(), IntSet::from([0, 1, 2]));
+    assert_eq!(class_def.class_glyphs(2), IntSet::from([GlyphId::new(6)]));
+    // format 2: ranges 2..=3 -> 1, 7..=7 -> 3, 9..=10 -> 1
+    let buf = BeBuffer::new().extend([2u16, 3, 2, 3, 1, 7, 7, 3, 9, 10, 1]);
+    let class_def = ClassDef::read(buf.font_data()).unwrap();
+    assert_eq!(class_def.classes(), IntSet::from([1, 3]));
+    let glyphs = class_def.class_glyphs(1);
+    assert_eq!(
+        glyphs.iter().map(|gid| gid.to_u16()).collect::<Vec<_>>(),
+        [2, 3, 9, 10]
+    );
+    assert!(class_def.class_glyphs(0).is_empty());
+}
+
+#[test]
+fn coverage_glyph_set() {
+    use crate::test_helpers::BeBuffer;
+    // format 1: glyphs [1, 5, 9]
+    let buf = BeBuffer::new().extend([1u16, 3, 1, 5, 9]);
+    let coverage = CoverageTable::read(buf.font_data()).unwrap();
+    assert_eq!(coverage.glyph_set(), coverage.iter().collect());
+    // format 2: ranges 4..=600 and 1000..=1001
+    let buf = BeBuffer::new().extend([2u16, 2, 4, 600, 0, 1000, 1001, 597]);
+    let coverage = CoverageTable::read(buf.font_data()).unwrap();
+    let glyphs = coverage.glyph_set();
+    assert_eq!(glyphs.len(), 599);
+    assert_eq!(glyphs, coverage.iter().collect());
+}
+
+#[test]
+fn coverage_get() {
+    use crate::test_helpers::BeBuffer;
+    // format 1: glyphs [1, 5, 9]
+    let format1 = BeBuffer::new().extend([1u16, 3, 1, 5, 9]);
+    // format 2: ranges 4..=6 and 10..=11
+    let format2 = BeBuffer::new().extend([2u16, 2, 4, 6, 0, 10, 11, 3]);
+    for buf in [format1, format2] {
+        let coverage = CoverageTable::read(buf.font_data()).unwrap();
+        let accelerator = CoverageAccelerator::new(&coverage);
+        for gid in 0..16 {
+            let gid = GlyphId::new(gid);
+            let expected = coverage
+                .iter()
+                .position(|covered| covered == gid)
+                .map(|ix| ix as u16);
+            assert_eq!(coverage.get(gid), expected);
+            assert_eq!(accelerator.get(gid), expected);
+        }
+    }
+    let empty = BeBuffer::new().extend([1u16, 0]);
+    let coverage = CoverageTable::read(empty.font_data()).unwrap();
+    assert!(!CoverageAccelerator::new(&coverage).contains(GlyphId::new(0)));
+}
+
+#[test]
+fn class_def_get() {
+    use crate::test_helpers::BeBuffer;
+    // format 1: start glyph 4, classes [1, 0, 2]
+    let format1 = BeBuffer::new().extend([1u16, 4, 3, 1, 0, 2]);
+    // format 2: ranges 2..=3 -> 1, 7..=7 -> 3, 9..=10 -> 1
+    let format2 = BeBuffer::new().extend([2u16, 3, 2, 3, 1, 7, 7, 3, 9, 10, 1]);
+    for buf in [format1, format2] {
+        let class_def = ClassDef::read(buf.font_data()).unwrap();
+        for gid in 0..16 {
+            let gid = GlyphId::new(gid);
+            let expected = class_def
+                .iter()
+                .find_map(|(listed, class)| (listed == gid).then_some(class))
+                .unwrap_or(0);
+            assert_eq!(class_def.get(gid), expected);
+        }
+    }
+}
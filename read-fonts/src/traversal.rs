@@ -7,8 +7,15 @@
 //! all font tables. This trait provides the table's name, as well as ordered access
 //! to the table's fields. Using this, it is possible to iterate through a table
 //! and its subtables, records, and values.
+//!
+//! Where possible, fields and array items also report the bytes they were
+//! read from, which can be used to locate them within the font file. See
+//! [`Field::byte_range`] and [`SomeArray::get_bytes`].
 
-use std::{fmt::Debug, ops::Deref};
+use std::{
+    fmt::Debug,
+    ops::{Deref, Range},
+};
 
 use types::{
     BigEndian, F2Dot14, FWord, Fixed, GlyphId, LongDateTime, MajorMinor, NameId, Nullable,
@@ -17,8 +24,8 @@ use types::{
 
 use crate::{
     array::{ComputedArray, VarLenArray},
-    read::{ComputeSize, ReadArgs},
-    FontData, FontRead, FontReadWithArgs, ReadError, VarSize,
+    read::{ComputeSize, FromBytes, ReadArgs},
+    tables, FontData, FontRead, FontReadWithArgs, ReadError, TableProvider, TopLevelTable, VarSize,
 };
 
 /// Types of fields in font tables.
@@ -100,7 +107,7 @@ pub(crate) struct ArrayOfOffsets<'a, O> {
     resolver: Box<dyn Fn(&O) -> FieldType<'a> + 'a>,
 }
 
-impl<'a, O> SomeArray<'a> for ArrayOfOffsets<'a, O> {
+impl<'a, O: FromBytes> SomeArray<'a> for ArrayOfOffsets<'a, O> {
     fn type_name(&self) -> &str {
         self.type_name
     }
@@ -114,6 +121,10 @@ impl<'a, O> SomeArray<'a> for ArrayOfOffsets<'a, O> {
         let target = (self.resolver)(off);
         Some(target)
     }
+
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        self.offsets.get(idx..idx + 1).map(slice_bytes)
+    }
 }
 
 impl<'a> FieldType<'a> {
@@ -125,7 +136,7 @@ impl<'a> FieldType<'a> {
         data: FontData<'a>,
     ) -> FieldType<'a>
     where
-        T: Clone + SomeRecord<'a> + 'a,
+        T: Clone + FromBytes + SomeRecord<'a> + 'a,
     {
         ArrayOfRecords {
             type_name,
@@ -174,12 +185,11 @@ impl<'a> FieldType<'a> {
     ///
     /// The `resolver` argument is a function that takes an offset and resolves
     /// it.
-    pub fn array_of_offsets<O>(
+    pub fn array_of_offsets<O: FromBytes>(
         type_name: &'static str,
         offsets: &'a [O],
         resolver: impl Fn(&O) -> FieldType<'a> + 'a,
-    ) -> Self
-where {
+    ) -> Self {
         FieldType::Array(Box::new(ArrayOfOffsets {
             type_name,
             offsets,
@@ -203,7 +213,7 @@ where {
     }
 
     /// Convenience method for creating a `FieldType` from an offset to an array.
-    pub fn offset_to_array_of_records<T: Clone + SomeRecord<'a> + 'a>(
+    pub fn offset_to_array_of_records<T: Clone + FromBytes + SomeRecord<'a> + 'a>(
         offset: impl Into<OffsetType>,
         result: impl Into<Option<Result<&'a [T], ReadError>>>,
         type_name: &'static str,
@@ -250,12 +260,78 @@ where {
     }
 }
 
+/// Returns the table with the given tag as a [`SomeTable`] trait object.
+///
+/// Returns [`ReadError::TableIsMissing`] if the table is not present in the
+/// font, or if it does not support traversal.
+pub fn some_table<'a>(
+    font: &impl TableProvider<'a>,
+    tag: Tag,
+) -> Result<Box<dyn SomeTable<'a> + 'a>, ReadError> {
+    match tag {
+        tables::gpos::Gpos::TAG => font.gpos().map(|x| Box::new(x) as _),
+        tables::gsub::Gsub::TAG => font.gsub().map(|x| Box::new(x) as _),
+        tables::cmap::Cmap::TAG => font.cmap().map(|x| Box::new(x) as _),
+        tables::fvar::Fvar::TAG => font.fvar().map(|x| Box::new(x) as _),
+        tables::avar::Avar::TAG => font.avar().map(|x| Box::new(x) as _),
+        tables::base::Base::TAG => font.base().map(|x| Box::new(x) as _),
+        tables::gdef::Gdef::TAG => font.gdef().map(|x| Box::new(x) as _),
+        tables::glyf::Glyf::TAG => font.glyf().map(|x| Box::new(x) as _),
+        tables::cvt::Cvt::TAG => font.cvt().map(|x| Box::new(x) as _),
+        tables::cvar::Cvar::TAG => font.cvar().map(|x| Box::new(x) as _),
+        tables::fpgm::Fpgm::TAG => font.fpgm().map(|x| Box::new(x) as _),
+        tables::prep::Prep::TAG => font.prep().map(|x| Box::new(x) as _),
+        tables::gasp::Gasp::TAG => font.gasp().map(|x| Box::new(x) as _),
+        tables::hdmx::Hdmx::TAG => font.hdmx().map(|x| Box::new(x) as _),
+        tables::vdmx::Vdmx::TAG => font.vdmx().map(|x| Box::new(x) as _),
+        tables::ltsh::Ltsh::TAG => font.ltsh().map(|x| Box::new(x) as _),
+        tables::meta::Meta::TAG => font.meta().map(|x| Box::new(x) as _),
+        tables::ltag::Ltag::TAG => font.ltag().map(|x| Box::new(x) as _),
+        tables::vorg::Vorg::TAG => font.vorg().map(|x| Box::new(x) as _),
+        tables::jstf::Jstf::TAG => font.jstf().map(|x| Box::new(x) as _),
+        tables::pclt::Pclt::TAG => font.pclt().map(|x| Box::new(x) as _),
+        tables::head::Head::TAG => font.head().map(|x| Box::new(x) as _),
+        tables::hhea::Hhea::TAG => font.hhea().map(|x| Box::new(x) as _),
+        tables::hmtx::Hmtx::TAG => font.hmtx().map(|x| Box::new(x) as _),
+        tables::kern::Kern::TAG => font.kern().map(|x| Box::new(x) as _),
+        tables::kerx::Kerx::TAG => font.kerx().map(|x| Box::new(x) as _),
+        tables::morx::Morx::TAG => font.morx().map(|x| Box::new(x) as _),
+        tables::ankr::Ankr::TAG => font.ankr().map(|x| Box::new(x) as _),
+        tables::trak::Trak::TAG => font.trak().map(|x| Box::new(x) as _),
+        tables::feat::Feat::TAG => font.feat().map(|x| Box::new(x) as _),
+        tables::loca::Loca::TAG => font.loca(None).map(|x| Box::new(x) as _),
+        tables::math::Math::TAG => font.math().map(|x| Box::new(x) as _),
+        tables::maxp::Maxp::TAG => font.maxp().map(|x| Box::new(x) as _),
+        tables::name::Name::TAG => font.name().map(|x| Box::new(x) as _),
+        tables::post::Post::TAG => font.post().map(|x| Box::new(x) as _),
+        tables::colr::Colr::TAG => font.colr().map(|x| Box::new(x) as _),
+        tables::stat::Stat::TAG => font.stat().map(|x| Box::new(x) as _),
+        tables::vhea::Vhea::TAG => font.vhea().map(|x| Box::new(x) as _),
+        tables::vmtx::Vmtx::TAG => font.vmtx().map(|x| Box::new(x) as _),
+        tables::os2::Os2::TAG => font.os2().map(|x| Box::new(x) as _),
+        tables::hvar::Hvar::TAG => font.hvar().map(|x| Box::new(x) as _),
+        tables::vvar::Vvar::TAG => font.vvar().map(|x| Box::new(x) as _),
+        tables::mvar::Mvar::TAG => font.mvar().map(|x| Box::new(x) as _),
+        tables::gvar::Gvar::TAG => font.gvar().map(|x| Box::new(x) as _),
+        tables::cpal::Cpal::TAG => font.cpal().map(|x| Box::new(x) as _),
+        _ => Err(ReadError::TableIsMissing(tag)),
+    }
+}
+
 /// A generic field in a font table.
+///
+/// In addition to its name and value, a field tracks the position of the
+/// bytes it was read from. This is private, so fields must be created
+/// with [`Field::new`] rather than with a struct literal.
 pub struct Field<'a> {
     /// The field's name.
     pub name: &'static str,
     /// The field's value.
     pub value: FieldType<'a>,
+    bytes: Option<&'a [u8]>,
+    // for fields of records, the range relative to the start of the record,
+    // which is resolved to bytes by the `RecordResolver`
+    record_range: Option<Range<usize>>,
 }
 
 /// A generic table type.
@@ -316,6 +392,20 @@ pub struct RecordResolver<'a> {
     pub(crate) name: &'static str,
     pub(crate) get_field: Box<dyn Fn(usize, FontData<'a>) -> Option<Field<'a>> + 'a>,
     pub(crate) data: FontData<'a>,
+    /// The bytes of the record itself, if known.
+    pub(crate) bytes: Option<&'a [u8]>,
+}
+
+impl<'a> RecordResolver<'a> {
+    /// The bytes this record was read from, if known.
+    pub fn bytes(&self) -> Option<&'a [u8]> {
+        self.bytes
+    }
+
+    pub(crate) fn with_bytes(mut self, bytes: Option<&'a [u8]>) -> Self {
+        self.bytes = bytes;
+        self
+    }
 }
 
 /// A generic trait for arrays.
@@ -333,6 +423,14 @@ pub trait SomeArray<'a> {
 
     /// Return the item at `idx`, or `None` if `idx` is out of bounds.
     fn get(&self, idx: usize) -> Option<FieldType<'a>>;
+
+    /// Return the bytes of the item at `idx`, if they are known.
+    ///
+    /// This returns `None` if `idx` is out of bounds or if the array does
+    /// not know where its items are stored.
+    fn get_bytes(&self, _idx: usize) -> Option<&'a [u8]> {
+        None
+    }
 }
 
 impl<'a> dyn SomeArray<'a> + 'a {
@@ -372,6 +470,10 @@ where
         (*self).get(idx).map(|val| val.get().into())
     }
 
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        (*self).get(idx).map(BigEndian::be_bytes)
+    }
+
     fn type_name(&self) -> &str {
         let full_name = std::any::type_name::<T>();
         full_name.split("::").last().unwrap_or(full_name)
@@ -390,6 +492,10 @@ impl<'a> SomeArray<'a> for &'a [u8] {
     fn get(&self, idx: usize) -> Option<FieldType<'a>> {
         (*self).get(idx).copied().map(Into::into)
     }
+
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        (*self).get(idx..idx + 1)
+    }
 }
 
 impl<'a> SomeArray<'a> for Box<dyn SomeArray<'a> + 'a> {
@@ -404,6 +510,10 @@ impl<'a> SomeArray<'a> for Box<dyn SomeArray<'a> + 'a> {
     fn get(&self, idx: usize) -> Option<FieldType<'a>> {
         self.deref().get(idx)
     }
+
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        self.deref().get_bytes(idx)
+    }
 }
 
 pub trait SomeString<'a> {
@@ -416,6 +526,12 @@ impl<'a> SomeString<'a> for Box<dyn SomeString<'a> + 'a> {
     }
 }
 
+impl<'a> SomeString<'a> for &'a str {
+    fn iter_chars(&self) -> Box<dyn Iterator<Item = char> + 'a> {
+        Box::new(self.chars())
+    }
+}
+
 // only used as Box<dyn SomeArray<'a>>
 struct ArrayOfRecords<'a, T> {
     pub(crate) type_name: &'static str,
@@ -447,10 +563,16 @@ where
     }
 
     fn get(&self, idx: usize) -> Option<FieldType<'a>> {
-        self.array
-            .get(idx)
-            .ok()
-            .map(|record| record.traverse(self.data).into())
+        self.array.get(idx).ok().map(|record| {
+            record
+                .traverse(self.data)
+                .with_bytes(self.get_bytes(idx))
+                .into()
+        })
+    }
+
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        self.array.item_bytes(idx)
     }
 
     fn type_name(&self) -> &str {
@@ -458,7 +580,7 @@ where
     }
 }
 
-impl<'a, T: SomeRecord<'a> + Clone> SomeArray<'a> for ArrayOfRecords<'a, T> {
+impl<'a, T: SomeRecord<'a> + Clone + FromBytes> SomeArray<'a> for ArrayOfRecords<'a, T> {
     fn type_name(&self) -> &str {
         self.type_name
     }
@@ -468,9 +590,17 @@ impl<'a, T: SomeRecord<'a> + Clone> SomeArray<'a> for ArrayOfRecords<'a, T> {
     }
 
     fn get(&self, idx: usize) -> Option<FieldType<'a>> {
-        self.records
-            .get(idx)
-            .map(|record| record.clone().traverse(self.data).into())
+        self.records.get(idx).map(|record| {
+            record
+                .clone()
+                .traverse(self.data)
+                .with_bytes(self.get_bytes(idx))
+                .into()
+        })
+    }
+
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        self.records.get(idx..idx + 1).map(slice_bytes)
     }
 }
 
@@ -484,10 +614,16 @@ where
     }
 
     fn get(&self, idx: usize) -> Option<FieldType<'a>> {
-        self.array
-            .get(idx)?
-            .ok()
-            .map(|record| record.traverse(self.data).into())
+        self.array.get(idx)?.ok().map(|record| {
+            record
+                .traverse(self.data)
+                .with_bytes(self.get_bytes(idx))
+                .into()
+        })
+    }
+
+    fn get_bytes(&self, idx: usize) -> Option<&'a [u8]> {
+        self.array.item_bytes(idx)
     }
 
     fn type_name(&self) -> &str {
@@ -501,10 +637,78 @@ impl<'a> Field<'a> {
         Field {
             name,
             value: value.into(),
+            bytes: None,
+            record_range: None,
+        }
+    }
+
+    /// The bytes this field was read from, if known.
+    ///
+    /// For offsets, these are the bytes of the offset itself, not of the
+    /// data it points to.
+    pub fn bytes(&self) -> Option<&'a [u8]> {
+        self.bytes
+    }
+
+    /// The range of this field's bytes within `data`, if known.
+    ///
+    /// `data` should be the data that the containing table was read from,
+    /// typically the entire font file. Returns `None` if the position of
+    /// this field is not known, or if it does not lie within `data`.
+    pub fn byte_range(&self, data: &[u8]) -> Option<Range<usize>> {
+        subslice_range(data, self.bytes?)
+    }
+
+    /// Attach the bytes at `range` in `data` to this field.
+    ///
+    /// The range is optional to support fields that may not be present
+    /// in all versions of a table.
+    pub(crate) fn with_bytes(
+        self,
+        data: FontData<'a>,
+        range: impl Into<Option<Range<usize>>>,
+    ) -> Self {
+        let bytes = range.into().and_then(|range| data.as_bytes().get(range));
+        self.with_resolved_bytes(bytes)
+    }
+
+    /// Set the position of this field relative to the start of its record.
+    pub(crate) fn with_record_range(mut self, start: usize, len: usize) -> Self {
+        self.record_range = Some(start..start + len);
+        self
+    }
+
+    fn with_resolved_bytes(mut self, bytes: Option<&'a [u8]>) -> Self {
+        self.bytes = bytes;
+        // an inline record occupies the same bytes as the field
+        if let FieldType::Record(record) = &mut self.value {
+            if record.bytes.is_none() {
+                record.bytes = bytes;
+            }
         }
+        self
     }
 }
 
+/// Returns the range of `inner` within `outer`, if `inner` is a subslice of
+/// `outer`.
+///
+/// This can be used with [`Field::bytes`], [`RecordResolver::bytes`] and
+/// [`SomeArray::get_bytes`] to find the position of a value in the font file.
+pub fn subslice_range(outer: &[u8], inner: &[u8]) -> Option<Range<usize>> {
+    let start = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+    let end = start.checked_add(inner.len())?;
+    (end <= outer.len()).then_some(start..end)
+}
+
+/// Reinterprets a slice of records as its underlying bytes.
+fn slice_bytes<T: FromBytes>(items: &[T]) -> &[u8] {
+    let len = std::mem::size_of_val(items);
+    // SAFETY: `FromBytes` types have no padding and an alignment of 1, so
+    // the memory of the slice is exactly `len` initialized bytes.
+    unsafe { std::slice::from_raw_parts(items.as_ptr() as *const u8, len) }
+}
+
 /// A wrapper type that implements `Debug` for any table.
 struct DebugPrintTable<'a, 'b>(pub &'b (dyn SomeTable<'a> + 'a));
 
@@ -611,7 +815,12 @@ impl<'a> SomeTable<'a> for RecordResolver<'a> {
     }
 
     fn get_field(&self, idx: usize) -> Option<Field<'a>> {
-        (self.get_field)(idx, self.data)
+        let mut field = (self.get_field)(idx, self.data)?;
+        if let Some(range) = field.record_range.take() {
+            let bytes = self.bytes.and_then(|bytes| bytes.get(range));
+            field = field.with_resolved_bytes(bytes);
+        }
+        Some(field)
     }
 }
 
@@ -770,3 +979,64 @@ impl<T: Into<OffsetType> + Clone> From<Nullable<T>> for OffsetType {
         src.offset().clone().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FontRef;
+
+    fn find_field<'a>(table: &(dyn SomeTable<'a> + 'a), name: &str) -> Field<'a> {
+        table.iter().find(|field| field.name == name).unwrap()
+    }
+
+    fn table_offset(font: &FontRef, tag: Tag) -> usize {
+        let records = font.table_directory.table_records();
+        let record = records.iter().find(|record| record.tag() == tag).unwrap();
+        record.offset() as usize
+    }
+
+    #[test]
+    fn table_field_byte_range() {
+        let data = font_test_data::SIMPLE_GLYF;
+        let font = FontRef::new(data).unwrap();
+        let head = some_table(&font, Tag::new(b"head")).unwrap();
+        let field = find_field(&head, "units_per_em");
+        let start = table_offset(&font, Tag::new(b"head")) + 18;
+        assert_eq!(field.byte_range(data), Some(start..start + 2));
+        // not within the given data
+        assert_eq!(field.byte_range(&data[..start]), None);
+    }
+
+    #[test]
+    fn record_and_array_byte_ranges() {
+        let data = font_test_data::SIMPLE_GLYF;
+        let font = FontRef::new(data).unwrap();
+        let cmap_start = table_offset(&font, Tag::new(b"cmap"));
+        let cmap = some_table(&font, Tag::new(b"cmap")).unwrap();
+        let FieldType::Array(records) = find_field(&cmap, "encoding_records").value else {
+            panic!("expected an array");
+        };
+        // encoding records are eight bytes each, after a four byte header
+        let record_start = cmap_start + 4 + 8;
+        assert_eq!(
+            records
+                .get_bytes(1)
+                .and_then(|bytes| subslice_range(data, bytes)),
+            Some(record_start..record_start + 8)
+        );
+        let Some(FieldType::Record(record)) = records.get(1) else {
+            panic!("expected a record");
+        };
+        let record = &record as &dyn SomeTable;
+        let field = find_field(record, "encoding_id");
+        assert_eq!(
+            field.byte_range(data),
+            Some(record_start + 2..record_start + 4)
+        );
+        let field = find_field(record, "subtable_offset");
+        assert_eq!(
+            field.byte_range(data),
+            Some(record_start + 4..record_start + 8)
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! Decoding of [WOFF 1.0](https://www.w3.org/TR/WOFF/) files.
+//!
+//! A WOFF file wraps an sfnt font, with each table optionally compressed
+//! with zlib. [`WoffRef`] validates the WOFF directory and provides access to
+//! the decompressed table data, the extended metadata and private data
+//! blocks, and the reconstructed sfnt.
+
+include!("../generated/generated_woff.rs");
+
+use std::borrow::Cow;
+
+use crate::checksum::table_checksum;
+
+/// The signature at the start of every WOFF file: 'wOFF'
+pub const WOFF_SIGNATURE: Tag = Tag::new(b"wOFF");
+
+const SFNT_HEADER_LEN: usize = 12;
+const SFNT_TABLE_RECORD_LEN: usize = 16;
+
+/// Maximum number of bytes reserved for a decoded font before its tables
+/// are decompressed.
+const MAX_SFNT_RESERVE_LEN: usize = 30 * 1024 * 1024;
+
+/// Reference to the content of a WOFF file.
+#[derive(Clone)]
+pub struct WoffRef<'a> {
+    data: FontData<'a>,
+    header: WoffHeader<'a>,
+}
+
+impl<'a> WoffRef<'a> {
+    /// Creates a new reference to a WOFF file.
+    ///
+    /// This validates the header and table directory: the directory must be
+    /// sorted by tag, and all data blocks must be in bounds, aligned and
+    /// must not overlap.
+    pub fn new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let data = FontData::new(data);
+        let header = WoffHeader::read(data)?;
+        if header.signature() != WOFF_SIGNATURE {
+            return Err(ReadError::InvalidSfnt(u32::from_be_bytes(
+                header.signature().into_bytes(),
+            )));
+        }
+        let woff = Self { data, header };
+        woff.validate()?;
+        Ok(woff)
+    }
+
+    fn validate(&self) -> Result<(), ReadError> {
+        let header = &self.header;
+        if header.length() as usize != self.data.len() {
+            return Err(ReadError::MalformedData(
+                "WOFF length does not match file size",
+            ));
+        }
+        if header.reserved() != 0 {
+            return Err(ReadError::MalformedData("WOFF reserved field is not zero"));
+        }
+        let directory = header.table_directory();
+        if directory
+            .windows(2)
+            .any(|pair| pair[0].tag() >= pair[1].tag())
+        {
+            return Err(ReadError::MalformedData(
+                "WOFF table directory is not sorted",
+            ));
+        }
+        let mut sfnt_size = SFNT_HEADER_LEN + directory.len() * SFNT_TABLE_RECORD_LEN;
+        // collect all data blocks to check for overlap
+        let mut blocks = Vec::with_capacity(directory.len() + 2);
+        for entry in directory {
+            if entry.comp_length() > entry.orig_length() {
+                return Err(ReadError::MalformedData(
+                    "WOFF compressed table is larger than original",
+                ));
+            }
+            blocks.push((entry.offset(), entry.comp_length()));
+            sfnt_size += round4(entry.orig_length() as usize);
+        }
+        if sfnt_size != header.total_sfnt_size() as usize {
+            return Err(ReadError::MalformedData("WOFF totalSfntSize is incorrect"));
+        }
+        if header.meta_offset() != 0 {
+            blocks.push((header.meta_offset(), header.meta_length()));
+        }
+        if header.priv_offset() != 0 {
+            blocks.push((header.priv_offset(), header.priv_length()));
+        }
+        blocks.sort_unstable();
+        let mut min_start = header.shape.table_directory_byte_range().end;
+        for (offset, length) in blocks {
+            let start = offset as usize;
+            let end = start
+                .checked_add(length as usize)
+                .ok_or(ReadError::OutOfBounds)?;
+            if !start.is_multiple_of(4) {
+                return Err(ReadError::MalformedData("WOFF data block is not aligned"));
+            }
+            if start < min_start {
+                return Err(ReadError::MalformedData("WOFF data blocks overlap"));
+            }
+            if end > self.data.len() {
+                return Err(ReadError::OutOfBounds);
+            }
+            min_start = end;
+        }
+        Ok(())
+    }
+
+    /// Returns the WOFF header, which contains the table directory.
+    pub fn header(&self) -> &WoffHeader<'a> {
+        &self.header
+    }
+
+    /// Returns the sfnt version of the wrapped font.
+    pub fn flavor(&self) -> u32 {
+        self.header.flavor()
+    }
+
+    /// Returns the decompressed data for the table with the specified tag,
+    /// if present.
+    ///
+    /// Uncompressed tables are returned without copying. Returns an error if
+    /// decompression fails or if the checksum of the decompressed data does
+    /// not match the directory entry.
+    pub fn table_data(&self, tag: Tag) -> Option<Result<Cow<'a, [u8]>, ReadError>> {
+        let directory = self.header.table_directory();
+        let index = directory
+            .binary_search_by(|entry| entry.tag().cmp(&tag))
+            .ok()?;
+        Some(self.decode_table(&directory[index]))
+    }
+
+    fn decode_table(&self, entry: &WoffTableDirectoryEntry) -> Result<Cow<'a, [u8]>, ReadError> {
+        let start = entry.offset() as usize;
+        let data = self
+            .data
+            .as_bytes()
+            .get(start..start + entry.comp_length() as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        let data = decompress(data, entry.orig_length() as usize)?;
+        if table_checksum(entry.tag(), &data) != entry.orig_checksum() {
+            return Err(ReadError::MalformedData("WOFF table checksum mismatch"));
+        }
+        Ok(data)
+    }
+
+    /// Returns the decompressed extended metadata block, if present.
+    ///
+    /// This is UTF-8 encoded XML.
+    pub fn metadata(&self) -> Option<Result<Vec<u8>, ReadError>> {
+        let start = self.header.meta_offset() as usize;
+        if start == 0 {
+            return None;
+        }
+        let data = self
+            .data
+            .as_bytes()
+            .get(start..start + self.header.meta_length() as usize)?;
+        let orig_length = self.header.meta_orig_length() as usize;
+        Some(
+            miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, orig_length)
+                .ok()
+                .filter(|data| data.len() == orig_length)
+                .ok_or(ReadError::MalformedData(
+                    "failed to decompress WOFF metadata",
+                )),
+        )
+    }
+
+    /// Returns the private data block, if present.
+    pub fn private_data(&self) -> Option<&'a [u8]> {
+        let start = self.header.priv_offset() as usize;
+        if start == 0 {
+            return None;
+        }
+        self.data
+            .as_bytes()
+            .get(start..start + self.header.priv_length() as usize)
+    }
+
+    /// Decodes the WOFF file into an sfnt font.
+    ///
+    /// Table data is written in the order it appears in the WOFF file, which
+    /// preserves the physical table order of the original font.
+    pub fn to_sfnt(&self) -> Result<Vec<u8>, ReadError> {
+        let directory = self.header.table_directory();
+        let mut physical_order = (0..directory.len()).collect::<Vec<_>>();
+        physical_order.sort_by_key(|&index| directory[index].offset());
+        let mut offsets = vec![0u32; directory.len()];
+        let mut position = SFNT_HEADER_LEN + directory.len() * SFNT_TABLE_RECORD_LEN;
+        for &index in &physical_order {
+            offsets[index] = position as u32;
+            position += round4(directory[index].orig_length() as usize);
+        }
+        // The totalSfntSize field is not trusted. Size the buffer from the
+        // table lengths instead, and limit what is allocated up front since
+        // those are not verified until the tables are decoded.
+        let mut sfnt = Vec::with_capacity(position.min(MAX_SFNT_RESERVE_LEN));
+        write_sfnt_header(&mut sfnt, self.flavor(), directory.len() as u16);
+        for (entry, offset) in directory.iter().zip(&offsets) {
+            sfnt.extend_from_slice(&entry.tag().into_bytes());
+            sfnt.extend_from_slice(&entry.orig_checksum().to_be_bytes());
+            sfnt.extend_from_slice(&offset.to_be_bytes());
+            sfnt.extend_from_slice(&entry.orig_length().to_be_bytes());
+        }
+        for &index in &physical_order {
+            let table = self.decode_table(&directory[index])?;
+            sfnt.extend_from_slice(&table);
+            sfnt.resize(round4(sfnt.len()), 0);
+        }
+        Ok(sfnt)
+    }
+}
+
+fn decompress(data: &[u8], orig_length: usize) -> Result<Cow<'_, [u8]>, ReadError> {
+    if data.len() == orig_length {
+        return Ok(Cow::Borrowed(data));
+    }
+    miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, orig_length)
+        .ok()
+        .filter(|data| data.len() == orig_length)
+        .map(Cow::Owned)
+        .ok_or(ReadError::MalformedData("failed to decompress WOFF table"))
+}
+
+/// Writes the sfnt header that precedes the table records.
+pub(crate) fn write_sfnt_header(sfnt: &mut Vec<u8>, flavor: u32, num_tables: u16) {
+    let entry_selector = (u16::BITS - 1).saturating_sub(num_tables.leading_zeros()) as u16;
+    let search_range = (1u16 << entry_selector).wrapping_mul(16);
+    let range_shift = num_tables.wrapping_mul(16).saturating_sub(search_range);
+    sfnt.extend_from_slice(&flavor.to_be_bytes());
+    for value in [num_tables, search_range, entry_selector, range_shift] {
+        sfnt.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+pub(crate) fn round4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+    use crate::{FileRef, FontRef, TableProvider};
+
+    const HEADER_LEN: u32 = 44;
+    const ENTRY_LEN: u32 = 20;
+
+    // 'maxp' is stored compressed and 'head' uncompressed, in reverse
+    // tag order.
+    fn make_woff() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let mut head = vec![0u8; 54];
+        head[..4].copy_from_slice(&[0, 1, 0, 0]);
+        // checksumAdjustment, which is ignored in the checksum
+        head[8..12].copy_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        head[18] = 0x08;
+        let mut maxp = vec![0u8; 32];
+        maxp[..6].copy_from_slice(&[0, 1, 0, 0, 0, 42]);
+        let maxp_compressed = miniz_oxide::deflate::compress_to_vec_zlib(&maxp, 6);
+        assert!(maxp_compressed.len() < maxp.len());
+        let maxp_offset = HEADER_LEN + 2 * ENTRY_LEN;
+        let head_offset = maxp_offset + round4(maxp_compressed.len()) as u32;
+        let total_sfnt_size = 12 + 2 * 16 + 56 + 32;
+        let mut buf = BeBuffer::new()
+            .push(WOFF_SIGNATURE)
+            .extend([0x00010000u32, 0])
+            .extend([2u16, 0])
+            .push(total_sfnt_size as u32)
+            .extend([1u16, 0])
+            .extend([0u32; 5])
+            .push(Tag::new(b"head"))
+            .extend([head_offset, 54, 54])
+            .push(table_checksum(Tag::new(b"head"), &head))
+            .push(Tag::new(b"maxp"))
+            .extend([maxp_offset, maxp_compressed.len() as u32, 32])
+            .push(table_checksum(Tag::new(b"maxp"), &maxp))
+            .extend(maxp_compressed.iter().copied());
+        while !buf.len().is_multiple_of(4) {
+            buf = buf.push(0u8);
+        }
+        buf = buf.extend(head.iter().copied());
+        let mut data = buf.to_vec();
+        let len = data.len() as u32;
+        data[8..12].copy_from_slice(&len.to_be_bytes());
+        (data, head, maxp)
+    }
+
+    #[test]
+    fn decode_tables() {
+        let (data, head, maxp) = make_woff();
+        let woff = WoffRef::new(&data).unwrap();
+        assert_eq!(woff.flavor(), 0x00010000);
+        assert_eq!(
+            woff.table_data(Tag::new(b"head")).unwrap().unwrap(),
+            &head[..]
+        );
+        assert!(matches!(
+            woff.table_data(Tag::new(b"head")).unwrap().unwrap(),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            woff.table_data(Tag::new(b"maxp")).unwrap().unwrap(),
+            &maxp[..]
+        );
+        assert!(woff.table_data(Tag::new(b"glyf")).is_none());
+        assert!(woff.metadata().is_none());
+        assert!(woff.private_data().is_none());
+    }
+
+    #[test]
+    fn decode_to_sfnt() {
+        let (data, _, _) = make_woff();
+        let woff = match FileRef::new(&data).unwrap() {
+            FileRef::Woff(woff) => woff,
+            _ => panic!("expected WOFF"),
+        };
+        let sfnt = woff.to_sfnt().unwrap();
+        assert_eq!(sfnt.len(), woff.header().total_sfnt_size() as usize);
+        let font = FontRef::new(&sfnt).unwrap();
+        assert_eq!(font.maxp().unwrap().num_glyphs(), 42);
+        assert_eq!(font.head().unwrap().units_per_em(), 0x0800);
+        // maxp precedes head in the WOFF data, and so in the sfnt
+        let records = font.table_directory.table_records();
+        assert_eq!(records[0].tag(), Tag::new(b"head"));
+        assert!(records[1].offset() < records[0].offset());
+    }
+
+    #[test]
+    fn reject_bad_checksum() {
+        let (mut data, _, _) = make_woff();
+        // corrupt the uncompressed head table, outside of checksumAdjustment
+        let last = data.len() - 1;
+        data[last] ^= 1;
+        let woff = WoffRef::new(&data).unwrap();
+        assert!(woff.table_data(Tag::new(b"head")).unwrap().is_err());
+        assert!(woff.to_sfnt().is_err());
+    }
+
+    #[test]
+    fn reject_bad_directory() {
+        let (data, _, _) = make_woff();
+        let mut unsorted = data.clone();
+        let first = HEADER_LEN as usize;
+        let second = first + ENTRY_LEN as usize;
+        unsorted[first..first + 4].copy_from_slice(b"name");
+        assert!(WoffRef::new(&unsorted).is_err());
+        let mut bad_length = data.clone();
+        bad_length.push(0);
+        assert!(WoffRef::new(&bad_length).is_err());
+        let mut overlapping = data;
+        let maxp_offset = overlapping[second + 4..second + 8].to_vec();
+        overlapping[first + 4..first + 8].copy_from_slice(&maxp_offset);
+        assert!(WoffRef::new(&overlapping).is_err());
+    }
+}
@@ -0,0 +1,1371 @@
+//! Decoding of [WOFF 2.0](https://www.w3.org/TR/WOFF2/) files.
+//!
+//! A WOFF2 file stores the tables of an sfnt font or font collection in a
+//! single brotli-compressed stream. The `glyf`, `loca` and `hmtx` tables may
+//! additionally be stored in a transformed representation. [`Woff2Ref`]
+//! validates the WOFF2 directories and reconstructs the original sfnt.
+
+include!("../generated/generated_woff2.rs");
+
+use std::{borrow::Cow, io::Read};
+
+use crate::checksum::{checksum, table_checksum, CHECKSUM_MAGIC};
+use crate::tables::{glyf::Glyf, head::Head, hhea::Hhea, loca::Loca, maxp::Maxp};
+use crate::woff::{round4, write_sfnt_header};
+
+/// The signature at the start of every WOFF2 file: 'wOF2'
+pub const WOFF2_SIGNATURE: Tag = Tag::new(b"wOF2");
+
+const SFNT_HEADER_LEN: usize = 12;
+const SFNT_TABLE_RECORD_LEN: usize = 16;
+
+/// Maximum size of decompressed data, as in the OpenType Sanitizer.
+const MAX_DECOMPRESSED_LEN: usize = 30 * 1024 * 1024;
+
+const GLYF: Tag = Tag::new(b"glyf");
+const LOCA: Tag = Tag::new(b"loca");
+const HMTX: Tag = Tag::new(b"hmtx");
+const HEAD: Tag = Tag::new(b"head");
+const HHEA: Tag = Tag::new(b"hhea");
+const MAXP: Tag = Tag::new(b"maxp");
+
+/// Tags that can be encoded in the flags of a table directory entry.
+const KNOWN_TAGS: [Tag; 63] = [
+    Tag::new(b"cmap"),
+    Tag::new(b"head"),
+    Tag::new(b"hhea"),
+    Tag::new(b"hmtx"),
+    Tag::new(b"maxp"),
+    Tag::new(b"name"),
+    Tag::new(b"OS/2"),
+    Tag::new(b"post"),
+    Tag::new(b"cvt "),
+    Tag::new(b"fpgm"),
+    Tag::new(b"glyf"),
+    Tag::new(b"loca"),
+    Tag::new(b"prep"),
+    Tag::new(b"CFF "),
+    Tag::new(b"VORG"),
+    Tag::new(b"EBDT"),
+    Tag::new(b"EBLC"),
+    Tag::new(b"gasp"),
+    Tag::new(b"hdmx"),
+    Tag::new(b"kern"),
+    Tag::new(b"LTSH"),
+    Tag::new(b"PCLT"),
+    Tag::new(b"VDMX"),
+    Tag::new(b"vhea"),
+    Tag::new(b"vmtx"),
+    Tag::new(b"BASE"),
+    Tag::new(b"GDEF"),
+    Tag::new(b"GPOS"),
+    Tag::new(b"GSUB"),
+    Tag::new(b"EBSC"),
+    Tag::new(b"JSTF"),
+    Tag::new(b"MATH"),
+    Tag::new(b"CBDT"),
+    Tag::new(b"CBLC"),
+    Tag::new(b"COLR"),
+    Tag::new(b"CPAL"),
+    Tag::new(b"SVG "),
+    Tag::new(b"sbix"),
+    Tag::new(b"acnt"),
+    Tag::new(b"avar"),
+    Tag::new(b"bdat"),
+    Tag::new(b"bloc"),
+    Tag::new(b"bsln"),
+    Tag::new(b"cvar"),
+    Tag::new(b"fdsc"),
+    Tag::new(b"feat"),
+    Tag::new(b"fmtx"),
+    Tag::new(b"fvar"),
+    Tag::new(b"gvar"),
+    Tag::new(b"hsty"),
+    Tag::new(b"just"),
+    Tag::new(b"lcar"),
+    Tag::new(b"mort"),
+    Tag::new(b"morx"),
+    Tag::new(b"opbd"),
+    Tag::new(b"prop"),
+    Tag::new(b"trak"),
+    Tag::new(b"Zapf"),
+    Tag::new(b"Silf"),
+    Tag::new(b"Glat"),
+    Tag::new(b"Gloc"),
+    Tag::new(b"Feat"),
+    Tag::new(b"Sill"),
+];
+
+/// An entry in the [WOFF2 table directory](https://www.w3.org/TR/WOFF2/#table_dir_format).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Woff2TableDirectoryEntry {
+    tag: Tag,
+    transform_version: u8,
+    orig_length: u32,
+    transform_length: Option<u32>,
+}
+
+impl Woff2TableDirectoryEntry {
+    /// The table tag.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// The preprocessing transformation version number.
+    ///
+    /// For `glyf` and `loca`, version 0 is the glyph transform and version 3
+    /// is the null transform. For all other tables, version 0 is the null
+    /// transform.
+    pub fn transform_version(&self) -> u8 {
+        self.transform_version
+    }
+
+    /// The length of the original table, excluding padding.
+    pub fn orig_length(&self) -> u32 {
+        self.orig_length
+    }
+
+    /// The length of the transformed table data, if a transform was applied.
+    pub fn transform_length(&self) -> Option<u32> {
+        self.transform_length
+    }
+
+    /// Returns true if the table is stored in a transformed representation.
+    pub fn is_transformed(&self) -> bool {
+        self.transform_length.is_some()
+    }
+
+    /// The number of bytes of the decompressed stream used by this table.
+    fn stored_length(&self) -> u32 {
+        self.transform_length.unwrap_or(self.orig_length)
+    }
+}
+
+/// An entry in the [WOFF2 collection directory](https://www.w3.org/TR/WOFF2/#collection_dir_format).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Woff2CollectionFontEntry {
+    flavor: u32,
+    table_indices: Vec<u16>,
+}
+
+impl Woff2CollectionFontEntry {
+    /// The sfnt version of the font.
+    pub fn flavor(&self) -> u32 {
+        self.flavor
+    }
+
+    /// Indices into the table directory of the tables used by this font.
+    pub fn table_indices(&self) -> &[u16] {
+        &self.table_indices
+    }
+}
+
+#[derive(Clone)]
+struct CollectionDirectory {
+    version: u32,
+    fonts: Vec<Woff2CollectionFontEntry>,
+}
+
+/// Reference to the content of a WOFF2 file.
+#[derive(Clone)]
+pub struct Woff2Ref<'a> {
+    data: FontData<'a>,
+    header: Woff2Header<'a>,
+    table_directory: Vec<Woff2TableDirectoryEntry>,
+    collection_directory: Option<CollectionDirectory>,
+    compressed_data: &'a [u8],
+}
+
+impl<'a> Woff2Ref<'a> {
+    /// Creates a new reference to a WOFF2 file.
+    ///
+    /// This parses and validates the header, the table directory and, for
+    /// font collections, the collection directory. The compressed data is
+    /// not decoded until requested.
+    pub fn new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let data = FontData::new(data);
+        let header = Woff2Header::read(data)?;
+        if header.signature() != WOFF2_SIGNATURE {
+            return Err(ReadError::InvalidSfnt(u32::from_be_bytes(
+                header.signature().into_bytes(),
+            )));
+        }
+        if header.length() as usize != data.len() {
+            return Err(ReadError::MalformedData(
+                "WOFF2 length does not match file size",
+            ));
+        }
+        if header.reserved() != 0 {
+            return Err(ReadError::MalformedData("WOFF2 reserved field is not zero"));
+        }
+        let mut cursor = data.cursor();
+        cursor.advance_by(header.shape.priv_length_byte_range().end);
+        let table_directory = (0..header.num_tables())
+            .map(|_| read_table_directory_entry(&mut cursor))
+            .collect::<Result<Vec<_>, _>>()?;
+        let collection_directory =
+            if header.flavor() == u32::from_be_bytes(TTC_HEADER_TAG.into_bytes()) {
+                Some(read_collection_directory(
+                    &mut cursor,
+                    table_directory.len(),
+                )?)
+            } else {
+                None
+            };
+        let start = cursor.position()?;
+        let end = start
+            .checked_add(header.total_compressed_size() as usize)
+            .ok_or(ReadError::OutOfBounds)?;
+        let compressed_data = data
+            .as_bytes()
+            .get(start..end)
+            .ok_or(ReadError::OutOfBounds)?;
+        let woff = Self {
+            data,
+            header,
+            table_directory,
+            collection_directory,
+            compressed_data,
+        };
+        woff.validate_blocks(end)?;
+        Ok(woff)
+    }
+
+    fn validate_blocks(&self, compressed_end: usize) -> Result<(), ReadError> {
+        let mut min_start = compressed_end;
+        for (offset, length) in [
+            (self.header.meta_offset(), self.header.meta_length()),
+            (self.header.priv_offset(), self.header.priv_length()),
+        ] {
+            if offset == 0 {
+                continue;
+            }
+            let start = offset as usize;
+            let end = start
+                .checked_add(length as usize)
+                .ok_or(ReadError::OutOfBounds)?;
+            if !start.is_multiple_of(4) {
+                return Err(ReadError::MalformedData("WOFF2 data block is not aligned"));
+            }
+            if start < min_start {
+                return Err(ReadError::MalformedData("WOFF2 data blocks overlap"));
+            }
+            if end > self.data.len() {
+                return Err(ReadError::OutOfBounds);
+            }
+            min_start = end;
+        }
+        Ok(())
+    }
+
+    /// Returns the WOFF2 header.
+    pub fn header(&self) -> &Woff2Header<'a> {
+        &self.header
+    }
+
+    /// Returns the sfnt version of the wrapped font.
+    ///
+    /// This is 'ttcf' for font collections.
+    pub fn flavor(&self) -> u32 {
+        self.header.flavor()
+    }
+
+    /// Returns the entries of the table directory.
+    pub fn table_directory(&self) -> &[Woff2TableDirectoryEntry] {
+        &self.table_directory
+    }
+
+    /// Returns the fonts of the collection directory, if this file contains
+    /// a font collection.
+    pub fn collection_fonts(&self) -> Option<&[Woff2CollectionFontEntry]> {
+        self.collection_directory
+            .as_ref()
+            .map(|collection| collection.fonts.as_slice())
+    }
+
+    /// Returns the decompressed extended metadata block, if present.
+    ///
+    /// This is UTF-8 encoded XML.
+    pub fn metadata(&self) -> Option<Result<Vec<u8>, ReadError>> {
+        let start = self.header.meta_offset() as usize;
+        if start == 0 {
+            return None;
+        }
+        let data = self
+            .data
+            .as_bytes()
+            .get(start..start + self.header.meta_length() as usize)?;
+        Some(
+            decompress(data, self.header.meta_orig_length() as usize).ok_or(
+                ReadError::MalformedData("failed to decompress WOFF2 metadata"),
+            ),
+        )
+    }
+
+    /// Returns the private data block, if present.
+    pub fn private_data(&self) -> Option<&'a [u8]> {
+        let start = self.header.priv_offset() as usize;
+        if start == 0 {
+            return None;
+        }
+        self.data
+            .as_bytes()
+            .get(start..start + self.header.priv_length() as usize)
+    }
+
+    /// Decodes the WOFF2 file into an sfnt font or font collection.
+    ///
+    /// Transformed tables are reconstructed and table data is written in the
+    /// order of the table directory. Table checksums are recomputed and, for
+    /// single fonts, so is the checksumAdjustment field of the `head` table.
+    ///
+    /// The result can be loaded with [`FileRef::new`](crate::FileRef::new).
+    pub fn to_sfnt(&self) -> Result<Vec<u8>, ReadError> {
+        let stream = self.decompress_tables()?;
+        let mut stored = Vec::with_capacity(self.table_directory.len());
+        let mut position = 0;
+        for entry in &self.table_directory {
+            let end = position + entry.stored_length() as usize;
+            stored.push(&stream[position..end]);
+            position = end;
+        }
+        let fonts: Vec<(u32, Vec<usize>)> = match &self.collection_directory {
+            Some(collection) => collection
+                .fonts
+                .iter()
+                .map(|font| {
+                    let indices = font.table_indices.iter().map(|ix| *ix as usize);
+                    (font.flavor, indices.collect())
+                })
+                .collect(),
+            None => vec![(self.flavor(), (0..self.table_directory.len()).collect())],
+        };
+        let mut tables = vec![None; self.table_directory.len()];
+        for (_, indices) in &fonts {
+            self.reconstruct_tables(indices, &stored, &mut tables)?;
+        }
+        Ok(self.write_sfnt(&fonts, &tables))
+    }
+
+    fn decompress_tables(&self) -> Result<Vec<u8>, ReadError> {
+        let expected_len = self
+            .table_directory
+            .iter()
+            .try_fold(0usize, |len, entry| {
+                len.checked_add(entry.stored_length() as usize)
+            })
+            .ok_or(ReadError::OutOfBounds)?;
+        decompress(self.compressed_data, expected_len)
+            .ok_or(ReadError::MalformedData("failed to decompress WOFF2 data"))
+    }
+
+    /// Fills in the data of the tables with the given directory indices,
+    /// reconstructing transformed tables.
+    ///
+    /// Tables shared by multiple fonts of a collection are only processed
+    /// once.
+    fn reconstruct_tables<'b>(
+        &self,
+        indices: &[usize],
+        stored: &[&'b [u8]],
+        tables: &mut [Option<Cow<'b, [u8]>>],
+    ) -> Result<(), ReadError> {
+        let directory = &self.table_directory;
+        let find = |tag| indices.iter().copied().find(|ix| directory[*ix].tag == tag);
+        for &ix in indices {
+            if !directory[ix].is_transformed() {
+                tables[ix] = Some(Cow::Borrowed(stored[ix]));
+            }
+        }
+        match (find(GLYF), find(LOCA)) {
+            (Some(glyf), Some(loca))
+                if directory[glyf].is_transformed() && directory[loca].is_transformed() =>
+            {
+                if directory[loca].transform_length != Some(0) {
+                    return Err(ReadError::MalformedData(
+                        "WOFF2 transformed loca table must be empty",
+                    ));
+                }
+                if tables[glyf].is_none() {
+                    let (glyf_data, loca_data) = reconstruct_glyf(stored[glyf])?;
+                    if loca_data.len() != directory[loca].orig_length as usize {
+                        return Err(ReadError::MalformedData(
+                            "WOFF2 reconstructed loca has unexpected length",
+                        ));
+                    }
+                    tables[glyf] = Some(Cow::Owned(glyf_data));
+                    tables[loca] = Some(Cow::Owned(loca_data));
+                }
+            }
+            (glyf, loca) => {
+                if glyf
+                    .or(loca)
+                    .is_some_and(|ix| directory[ix].is_transformed())
+                {
+                    return Err(ReadError::MalformedData(
+                        "WOFF2 glyf and loca must be transformed together",
+                    ));
+                }
+            }
+        }
+        if let Some(hmtx) = find(HMTX).filter(|ix| directory[*ix].is_transformed()) {
+            if tables[hmtx].is_none() {
+                let table = |tag| {
+                    find(tag)
+                        .and_then(|ix| tables[ix].as_deref())
+                        .map(FontData::new)
+                        .ok_or(ReadError::TableIsMissing(tag))
+                };
+                let hmtx_data = reconstruct_hmtx(
+                    stored[hmtx],
+                    Head::read(table(HEAD)?)?,
+                    Hhea::read(table(HHEA)?)?,
+                    Maxp::read(table(MAXP)?)?,
+                    table(GLYF)?,
+                    table(LOCA)?,
+                )?;
+                if hmtx_data.len() != directory[hmtx].orig_length as usize {
+                    return Err(ReadError::MalformedData(
+                        "WOFF2 reconstructed hmtx has unexpected length",
+                    ));
+                }
+                tables[hmtx] = Some(Cow::Owned(hmtx_data));
+            }
+        }
+        if let Some(&ix) = indices.iter().find(|ix| tables[**ix].is_none()) {
+            return Err(ReadError::MalformedData(if directory[ix].tag == HMTX {
+                "unsupported WOFF2 hmtx transform"
+            } else {
+                "unsupported WOFF2 table transform"
+            }));
+        }
+        Ok(())
+    }
+
+    fn write_sfnt(&self, fonts: &[(u32, Vec<usize>)], tables: &[Option<Cow<[u8]>>]) -> Vec<u8> {
+        let collection = self.collection_directory.as_ref();
+        // The TTC header, with DSIG fields for version 2
+        let mut position = collection.map_or(0, |collection| {
+            let dsig_len = if collection.version >= 0x00020000 {
+                12
+            } else {
+                0
+            };
+            12 + fonts.len() * 4 + dsig_len
+        });
+        let mut font_offsets = Vec::with_capacity(fonts.len());
+        for (_, indices) in fonts {
+            font_offsets.push(position as u32);
+            position += SFNT_HEADER_LEN + indices.len() * SFNT_TABLE_RECORD_LEN;
+        }
+        let mut table_offsets = vec![0u32; tables.len()];
+        for (offset, table) in table_offsets.iter_mut().zip(tables) {
+            if let Some(table) = table {
+                *offset = position as u32;
+                position += round4(table.len());
+            }
+        }
+
+        let mut sfnt = Vec::with_capacity(position);
+        if let Some(collection) = collection {
+            sfnt.extend_from_slice(&TTC_HEADER_TAG.into_bytes());
+            sfnt.extend_from_slice(&collection.version.to_be_bytes());
+            sfnt.extend_from_slice(&(fonts.len() as u32).to_be_bytes());
+            for offset in &font_offsets {
+                sfnt.extend_from_slice(&offset.to_be_bytes());
+            }
+            if collection.version >= 0x00020000 {
+                sfnt.extend_from_slice(&[0; 12]);
+            }
+        }
+        for (flavor, indices) in fonts {
+            write_sfnt_header(&mut sfnt, *flavor, indices.len() as u16);
+            let mut records = indices.clone();
+            records.sort_by_key(|ix| self.table_directory[*ix].tag);
+            for ix in records {
+                let tag = self.table_directory[ix].tag;
+                let data = tables[ix].as_deref().unwrap_or_default();
+                sfnt.extend_from_slice(&tag.into_bytes());
+                sfnt.extend_from_slice(&table_checksum(tag, data).to_be_bytes());
+                sfnt.extend_from_slice(&table_offsets[ix].to_be_bytes());
+                sfnt.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            }
+        }
+        let mut head_offset = None;
+        for (entry, (table, offset)) in self
+            .table_directory
+            .iter()
+            .zip(tables.iter().zip(&table_offsets))
+        {
+            if let Some(table) = table {
+                if entry.tag == HEAD && table.len() >= 12 {
+                    head_offset = Some(*offset as usize);
+                }
+                sfnt.extend_from_slice(table);
+                sfnt.resize(round4(sfnt.len()), 0);
+            }
+        }
+        if let (None, Some(offset)) = (collection, head_offset) {
+            let adjustment = &mut sfnt[offset + 8..offset + 12];
+            adjustment.copy_from_slice(&[0; 4]);
+            let adjustment = CHECKSUM_MAGIC.wrapping_sub(checksum(&sfnt));
+            sfnt[offset + 8..offset + 12].copy_from_slice(&adjustment.to_be_bytes());
+        }
+        sfnt
+    }
+}
+
+fn decompress(data: &[u8], expected_len: usize) -> Option<Vec<u8>> {
+    // the expected length comes from the file so reject implausible sizes
+    // before allocating
+    if expected_len > MAX_DECOMPRESSED_LEN {
+        return None;
+    }
+    let mut decompressed = Vec::new();
+    brotli_decompressor::Decompressor::new(data, 4096)
+        .take(expected_len as u64 + 1)
+        .read_to_end(&mut decompressed)
+        .ok()?;
+    (decompressed.len() == expected_len).then_some(decompressed)
+}
+
+fn read_table_directory_entry(cursor: &mut Cursor) -> Result<Woff2TableDirectoryEntry, ReadError> {
+    let flags = cursor.read::<u8>()?;
+    let tag = match flags & 0x3F {
+        0x3F => cursor.read::<Tag>()?,
+        index => KNOWN_TAGS[index as usize],
+    };
+    let transform_version = flags >> 6;
+    let orig_length = read_uint_base128(cursor)?;
+    let is_transformed = if tag == GLYF || tag == LOCA {
+        transform_version != 3
+    } else {
+        transform_version != 0
+    };
+    let transform_length = if is_transformed {
+        Some(read_uint_base128(cursor)?)
+    } else {
+        None
+    };
+    Ok(Woff2TableDirectoryEntry {
+        tag,
+        transform_version,
+        orig_length,
+        transform_length,
+    })
+}
+
+fn read_collection_directory(
+    cursor: &mut Cursor,
+    num_tables: usize,
+) -> Result<CollectionDirectory, ReadError> {
+    let version = cursor.read::<u32>()?;
+    let num_fonts = read_255_u16(cursor)?;
+    let fonts = (0..num_fonts)
+        .map(|_| {
+            let num_tables_in_font = read_255_u16(cursor)?;
+            let flavor = cursor.read::<u32>()?;
+            let table_indices = (0..num_tables_in_font)
+                .map(|_| {
+                    read_255_u16(cursor).and_then(|ix| {
+                        if (ix as usize) < num_tables {
+                            Ok(ix)
+                        } else {
+                            Err(ReadError::MalformedData(
+                                "WOFF2 collection table index out of range",
+                            ))
+                        }
+                    })
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Woff2CollectionFontEntry {
+                flavor,
+                table_indices,
+            })
+        })
+        .collect::<Result<_, ReadError>>()?;
+    Ok(CollectionDirectory { version, fonts })
+}
+
+/// Reads a [UIntBase128](https://www.w3.org/TR/WOFF2/#DataTypes) value.
+fn read_uint_base128(cursor: &mut Cursor) -> Result<u32, ReadError> {
+    let mut value = 0u32;
+    for i in 0..5 {
+        let byte = cursor.read::<u8>()?;
+        // no leading zeros
+        if i == 0 && byte == 0x80 {
+            return Err(ReadError::MalformedData("UIntBase128 has leading zeros"));
+        }
+        if value & 0xFE000000 != 0 {
+            return Err(ReadError::MalformedData("UIntBase128 overflow"));
+        }
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(ReadError::MalformedData(
+        "UIntBase128 is longer than 5 bytes",
+    ))
+}
+
+/// Reads a [255UInt16](https://www.w3.org/TR/WOFF2/#DataTypes) value.
+fn read_255_u16(cursor: &mut Cursor) -> Result<u16, ReadError> {
+    const WORD_CODE: u8 = 253;
+    const ONE_MORE_BYTE_CODE2: u8 = 254;
+    const ONE_MORE_BYTE_CODE1: u8 = 255;
+    const LOWEST_U_CODE: u16 = 253;
+    match cursor.read::<u8>()? {
+        WORD_CODE => cursor.read::<u16>(),
+        ONE_MORE_BYTE_CODE2 => Ok(cursor.read::<u8>()? as u16 + LOWEST_U_CODE * 2),
+        ONE_MORE_BYTE_CODE1 => Ok(cursor.read::<u8>()? as u16 + LOWEST_U_CODE),
+        code => Ok(code as u16),
+    }
+}
+
+// simple glyph flags
+const ON_CURVE_POINT: u8 = 0x01;
+const X_SHORT_VECTOR: u8 = 0x02;
+const Y_SHORT_VECTOR: u8 = 0x04;
+const REPEAT_FLAG: u8 = 0x08;
+const X_IS_SAME_OR_POSITIVE: u8 = 0x10;
+const Y_IS_SAME_OR_POSITIVE: u8 = 0x20;
+const OVERLAP_SIMPLE: u8 = 0x40;
+
+// composite glyph flags
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+const WE_HAVE_INSTRUCTIONS: u16 = 0x0100;
+
+/// The substreams of the [transformed glyf table](https://www.w3.org/TR/WOFF2/#glyf_table_format).
+struct GlyfStreams<'a> {
+    n_contours: Cursor<'a>,
+    n_points: Cursor<'a>,
+    flags: Cursor<'a>,
+    glyphs: Cursor<'a>,
+    composites: Cursor<'a>,
+    bbox_bitmap: &'a [u8],
+    bboxes: Cursor<'a>,
+    instructions: Cursor<'a>,
+    overlap_bitmap: Option<&'a [u8]>,
+}
+
+/// Reconstructs the `glyf` and `loca` tables from the transformed `glyf`
+/// data.
+fn reconstruct_glyf(data: &[u8]) -> Result<(Vec<u8>, Vec<u8>), ReadError> {
+    let data = FontData::new(data);
+    let mut cursor = data.cursor();
+    let _reserved = cursor.read::<u16>()?;
+    let option_flags = cursor.read::<u16>()?;
+    let num_glyphs = cursor.read::<u16>()? as usize;
+    let index_format = cursor.read::<u16>()?;
+    let stream_lens = [
+        cursor.read::<u32>()?,
+        cursor.read::<u32>()?,
+        cursor.read::<u32>()?,
+        cursor.read::<u32>()?,
+        cursor.read::<u32>()?,
+        cursor.read::<u32>()?,
+        cursor.read::<u32>()?,
+    ];
+    // the substreams follow the header, in order
+    let mut remaining = data
+        .split_off(cursor.position()?)
+        .ok_or(ReadError::OutOfBounds)?;
+    let mut stream = |len: usize| remaining.take_up_to(len).ok_or(ReadError::OutOfBounds);
+    let n_contours = stream(stream_lens[0] as usize)?;
+    let n_points = stream(stream_lens[1] as usize)?;
+    let flags = stream(stream_lens[2] as usize)?;
+    let glyphs = stream(stream_lens[3] as usize)?;
+    let composites = stream(stream_lens[4] as usize)?;
+    let bboxes = stream(stream_lens[5] as usize)?;
+    let instructions = stream(stream_lens[6] as usize)?;
+    let overlap_bitmap = if option_flags & 1 != 0 {
+        Some(stream(num_glyphs.div_ceil(8))?.as_bytes())
+    } else {
+        None
+    };
+    let bbox_bitmap_len = num_glyphs.div_ceil(32) * 4;
+    let bbox_bitmap = bboxes
+        .slice(..bbox_bitmap_len)
+        .ok_or(ReadError::OutOfBounds)?
+        .as_bytes();
+    let mut bboxes = bboxes.cursor();
+    bboxes.advance_by(bbox_bitmap_len);
+    let mut streams = GlyfStreams {
+        n_contours: n_contours.cursor(),
+        n_points: n_points.cursor(),
+        flags: flags.cursor(),
+        glyphs: glyphs.cursor(),
+        composites: composites.cursor(),
+        bbox_bitmap,
+        bboxes,
+        instructions: instructions.cursor(),
+        overlap_bitmap,
+    };
+
+    let mut glyf = Vec::new();
+    let mut offsets = Vec::with_capacity(num_glyphs + 1);
+    offsets.push(0);
+    for gid in 0..num_glyphs {
+        streams.write_glyph(gid, &mut glyf)?;
+        glyf.resize(round4(glyf.len()), 0);
+        offsets.push(glyf.len());
+    }
+
+    let mut loca = Vec::new();
+    for offset in offsets {
+        if index_format == 0 {
+            let offset = u16::try_from(offset / 2)
+                .map_err(|_| ReadError::MalformedData("WOFF2 glyf too large for short loca"))?;
+            loca.extend_from_slice(&offset.to_be_bytes());
+        } else {
+            loca.extend_from_slice(&(offset as u32).to_be_bytes());
+        }
+    }
+    Ok((glyf, loca))
+}
+
+impl GlyfStreams<'_> {
+    fn write_glyph(&mut self, gid: usize, glyf: &mut Vec<u8>) -> Result<(), ReadError> {
+        let has_bbox = bitmap_contains(self.bbox_bitmap, gid);
+        let bbox = if has_bbox {
+            Some([
+                self.bboxes.read::<i16>()?,
+                self.bboxes.read::<i16>()?,
+                self.bboxes.read::<i16>()?,
+                self.bboxes.read::<i16>()?,
+            ])
+        } else {
+            None
+        };
+        match self.n_contours.read::<i16>()? {
+            0 if has_bbox => Err(ReadError::MalformedData(
+                "WOFF2 empty glyph has a bounding box",
+            )),
+            0 => Ok(()),
+            -1 => {
+                let bbox = bbox.ok_or(ReadError::MalformedData(
+                    "WOFF2 composite glyph has no bounding box",
+                ))?;
+                self.write_composite_glyph(bbox, glyf)
+            }
+            n_contours if n_contours > 0 => {
+                let overlap = self
+                    .overlap_bitmap
+                    .is_some_and(|bitmap| bitmap_contains(bitmap, gid));
+                self.write_simple_glyph(n_contours as u16, bbox, overlap, glyf)
+            }
+            _ => Err(ReadError::MalformedData(
+                "WOFF2 glyph has invalid number of contours",
+            )),
+        }
+    }
+
+    fn write_composite_glyph(
+        &mut self,
+        bbox: [i16; 4],
+        glyf: &mut Vec<u8>,
+    ) -> Result<(), ReadError> {
+        glyf.extend_from_slice(&(-1i16).to_be_bytes());
+        for value in bbox {
+            glyf.extend_from_slice(&value.to_be_bytes());
+        }
+        let mut have_instructions = false;
+        loop {
+            let flags = self.composites.read::<u16>()?;
+            let mut len = 2; // glyph index
+            len += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+                4
+            } else {
+                2
+            };
+            if flags & WE_HAVE_A_SCALE != 0 {
+                len += 2;
+            } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                len += 4;
+            } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+                len += 8;
+            }
+            glyf.extend_from_slice(&flags.to_be_bytes());
+            glyf.extend_from_slice(self.composites.read_array::<u8>(len)?);
+            have_instructions |= flags & WE_HAVE_INSTRUCTIONS != 0;
+            if flags & MORE_COMPONENTS == 0 {
+                break;
+            }
+        }
+        if have_instructions {
+            self.write_instructions(glyf)?;
+        }
+        Ok(())
+    }
+
+    fn write_simple_glyph(
+        &mut self,
+        n_contours: u16,
+        bbox: Option<[i16; 4]>,
+        overlap: bool,
+        glyf: &mut Vec<u8>,
+    ) -> Result<(), ReadError> {
+        let mut end_points = Vec::with_capacity(n_contours as usize);
+        let mut n_points = 0usize;
+        for _ in 0..n_contours {
+            match read_255_u16(&mut self.n_points)? {
+                0 => return Err(ReadError::MalformedData("WOFF2 glyph has an empty contour")),
+                contour_points => n_points += contour_points as usize,
+            }
+            let end_point = u16::try_from(n_points - 1)
+                .map_err(|_| ReadError::MalformedData("WOFF2 glyph has too many points"))?;
+            end_points.push(end_point);
+        }
+        let flags = self.flags.read_array::<u8>(n_points)?;
+        let mut points = Vec::with_capacity(n_points);
+        let (mut x, mut y) = (0i32, 0i32);
+        let out_of_range = || ReadError::MalformedData("WOFF2 glyph coordinate out of range");
+        for &flag in flags {
+            let (dx, dy) = self.read_triplet(flag & 0x7F)?;
+            x = x.checked_add(dx).ok_or_else(out_of_range)?;
+            y = y.checked_add(dy).ok_or_else(out_of_range)?;
+            // coordinates must be representable in the glyf table
+            if i16::try_from(x).is_err() || i16::try_from(y).is_err() {
+                return Err(out_of_range());
+            }
+            points.push((x, y, flag & 0x80 == 0));
+        }
+        let bbox = bbox.unwrap_or_else(|| {
+            let mut bbox = [i16::MAX, i16::MAX, i16::MIN, i16::MIN];
+            // coordinates were checked above so these casts are lossless
+            for &(x, y, _) in &points {
+                bbox[0] = bbox[0].min(x as i16);
+                bbox[1] = bbox[1].min(y as i16);
+                bbox[2] = bbox[2].max(x as i16);
+                bbox[3] = bbox[3].max(y as i16);
+            }
+            if points.is_empty() {
+                [0; 4]
+            } else {
+                bbox
+            }
+        });
+
+        glyf.extend_from_slice(&(n_contours as i16).to_be_bytes());
+        for value in bbox {
+            glyf.extend_from_slice(&value.to_be_bytes());
+        }
+        for end_point in end_points {
+            glyf.extend_from_slice(&end_point.to_be_bytes());
+        }
+        self.write_instructions(glyf)?;
+
+        let mut encoded_flags = Vec::with_capacity(n_points);
+        let mut x_coords = Vec::new();
+        let mut y_coords = Vec::new();
+        let (mut prev_x, mut prev_y) = (0, 0);
+        for (i, &(x, y, on_curve)) in points.iter().enumerate() {
+            let mut flag = if on_curve { ON_CURVE_POINT } else { 0 };
+            if overlap && i == 0 {
+                flag |= OVERLAP_SIMPLE;
+            }
+            flag |= encode_coordinate(
+                x - prev_x,
+                X_SHORT_VECTOR,
+                X_IS_SAME_OR_POSITIVE,
+                &mut x_coords,
+            );
+            flag |= encode_coordinate(
+                y - prev_y,
+                Y_SHORT_VECTOR,
+                Y_IS_SAME_OR_POSITIVE,
+                &mut y_coords,
+            );
+            (prev_x, prev_y) = (x, y);
+            encoded_flags.push(flag);
+        }
+        write_flags(&encoded_flags, glyf);
+        glyf.extend_from_slice(&x_coords);
+        glyf.extend_from_slice(&y_coords);
+        Ok(())
+    }
+
+    /// Copies the instruction length from the glyph stream and the
+    /// instructions from the instruction stream.
+    fn write_instructions(&mut self, glyf: &mut Vec<u8>) -> Result<(), ReadError> {
+        let len = read_255_u16(&mut self.glyphs)?;
+        glyf.extend_from_slice(&len.to_be_bytes());
+        glyf.extend_from_slice(self.instructions.read_array::<u8>(len as usize)?);
+        Ok(())
+    }
+
+    /// Decodes a point delta using the [triplet encoding](https://www.w3.org/TR/WOFF2/#triplet_decoding).
+    fn read_triplet(&mut self, flag: u8) -> Result<(i32, i32), ReadError> {
+        fn with_sign(flag: u8, value: i32) -> i32 {
+            if flag & 1 != 0 {
+                value
+            } else {
+                -value
+            }
+        }
+        let flag_i32 = flag as i32;
+        let mut next = || self.glyphs.read::<u8>().map(|byte| byte as i32);
+        Ok(match flag {
+            0..=9 => (0, with_sign(flag, ((flag_i32 & 14) << 7) + next()?)),
+            10..=19 => (with_sign(flag, (((flag_i32 - 10) & 14) << 7) + next()?), 0),
+            20..=83 => {
+                let b0 = flag_i32 - 20;
+                let b1 = next()?;
+                (
+                    with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+                    with_sign(flag >> 1, 1 + ((b0 & 0x0C) << 2) + (b1 & 0x0F)),
+                )
+            }
+            84..=119 => {
+                let b0 = flag_i32 - 84;
+                let (b1, b2) = (next()?, next()?);
+                (
+                    with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+                    with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+                )
+            }
+            120..=123 => {
+                let (b1, b2, b3) = (next()?, next()?, next()?);
+                (
+                    with_sign(flag, (b1 << 4) + (b2 >> 4)),
+                    with_sign(flag >> 1, ((b2 & 0x0F) << 8) + b3),
+                )
+            }
+            _ => {
+                let (b1, b2, b3, b4) = (next()?, next()?, next()?, next()?);
+                (
+                    with_sign(flag, (b1 << 8) + b2),
+                    with_sign(flag >> 1, (b3 << 8) + b4),
+                )
+            }
+        })
+    }
+}
+
+fn bitmap_contains(bitmap: &[u8], index: usize) -> bool {
+    bitmap
+        .get(index >> 3)
+        .is_some_and(|byte| byte & (0x80 >> (index & 7)) != 0)
+}
+
+/// Appends the coordinate delta to `coords` in its most compact form and
+/// returns the corresponding flag bits.
+fn encode_coordinate(delta: i32, short_flag: u8, same_flag: u8, coords: &mut Vec<u8>) -> u8 {
+    if delta == 0 {
+        same_flag
+    } else if delta.abs() < 256 {
+        coords.push(delta.unsigned_abs() as u8);
+        short_flag | if delta > 0 { same_flag } else { 0 }
+    } else {
+        coords.extend_from_slice(&(delta as i16).to_be_bytes());
+        0
+    }
+}
+
+/// Writes the flags of a simple glyph, using the repeat flag for runs.
+fn write_flags(flags: &[u8], glyf: &mut Vec<u8>) {
+    let mut i = 0;
+    while i < flags.len() {
+        let flag = flags[i];
+        let repeat = flags[i + 1..]
+            .iter()
+            .take(255)
+            .take_while(|next| **next == flag)
+            .count();
+        if repeat > 0 {
+            glyf.extend_from_slice(&[flag | REPEAT_FLAG, repeat as u8]);
+        } else {
+            glyf.push(flag);
+        }
+        i += repeat + 1;
+    }
+}
+
+/// Reconstructs the `hmtx` table from the [transformed hmtx](https://www.w3.org/TR/WOFF2/#hmtx_table_format)
+/// data.
+///
+/// Omitted left side bearings are taken from the glyph bounding boxes.
+fn reconstruct_hmtx(
+    data: &[u8],
+    head: Head,
+    hhea: Hhea,
+    maxp: Maxp,
+    glyf: FontData,
+    loca: FontData,
+) -> Result<Vec<u8>, ReadError> {
+    let num_glyphs = maxp.num_glyphs() as usize;
+    let num_h_metrics = hhea.number_of_long_metrics() as usize;
+    if num_h_metrics == 0 || num_h_metrics > num_glyphs {
+        return Err(ReadError::MalformedData(
+            "WOFF2 hmtx has invalid number of metrics",
+        ));
+    }
+    let glyf = Glyf::read(glyf)?;
+    let loca = Loca::read(loca, head.index_to_loc_format() == 1)?;
+    let x_min = |gid: usize| -> Result<i16, ReadError> {
+        Ok(loca
+            .get_glyf(GlyphId::new(gid as u16), &glyf)?
+            .map(|glyph| glyph.x_min())
+            .unwrap_or_default())
+    };
+
+    let mut cursor = FontData::new(data).cursor();
+    let flags = cursor.read::<u8>()?;
+    if flags & 0xFC != 0 {
+        return Err(ReadError::MalformedData(
+            "WOFF2 hmtx has reserved flags set",
+        ));
+    }
+    let advances = cursor.read_array::<BigEndian<u16>>(num_h_metrics)?;
+    let lsbs = if flags & 1 == 0 {
+        Some(cursor.read_array::<BigEndian<i16>>(num_h_metrics)?)
+    } else {
+        None
+    };
+    let trailing_lsbs = if flags & 2 == 0 {
+        Some(cursor.read_array::<BigEndian<i16>>(num_glyphs - num_h_metrics)?)
+    } else {
+        None
+    };
+
+    let mut hmtx = Vec::with_capacity(num_h_metrics * 4 + (num_glyphs - num_h_metrics) * 2);
+    for (gid, advance) in advances.iter().enumerate() {
+        let lsb = match lsbs {
+            Some(lsbs) => lsbs[gid].get(),
+            None => x_min(gid)?,
+        };
+        hmtx.extend_from_slice(&advance.get().to_be_bytes());
+        hmtx.extend_from_slice(&lsb.to_be_bytes());
+    }
+    for gid in num_h_metrics..num_glyphs {
+        let lsb = match trailing_lsbs {
+            Some(lsbs) => lsbs[gid - num_h_metrics].get(),
+            None => x_min(gid)?,
+        };
+        hmtx.extend_from_slice(&lsb.to_be_bytes());
+    }
+    Ok(hmtx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::BeBuffer;
+    use crate::{tables::glyf::Glyph, FileRef, FontRef, TableProvider};
+
+    /// Wraps the data in a brotli stream of uncompressed meta-blocks.
+    fn brotli_uncompressed(data: &[u8]) -> Vec<u8> {
+        let mut stream = vec![];
+        for (i, chunk) in data.chunks(1 << 16).enumerate() {
+            // WBITS = 16, ISLAST = 0, MNIBBLES = 4, MLEN - 1, ISUNCOMPRESSED = 1
+            let header = ((chunk.len() as u32 - 1) << 4) | (1 << 20);
+            // WBITS is only present at the start of the stream
+            let header = if i == 0 { header } else { header >> 1 };
+            stream.extend_from_slice(&header.to_le_bytes()[..3]);
+            stream.extend_from_slice(chunk);
+        }
+        // ISLAST = 1, ISLASTEMPTY = 1
+        stream.push(if data.is_empty() { 0b110 } else { 0b11 });
+        stream
+    }
+
+    fn make_woff2(flavor: u32, directory: &[u8], collection: &[u8], tables: &[u8]) -> Vec<u8> {
+        let compressed = brotli_uncompressed(tables);
+        let num_tables = directory_len(directory);
+        let mut data = BeBuffer::new()
+            .push(WOFF2_SIGNATURE)
+            .push(flavor)
+            .push(0u32)
+            .extend([num_tables, 0])
+            .extend([0u32, compressed.len() as u32])
+            .extend([1u16, 0])
+            .extend([0u32; 5])
+            .to_vec();
+        data.extend_from_slice(directory);
+        data.extend_from_slice(collection);
+        data.extend_from_slice(&compressed);
+        let len = data.len() as u32;
+        data[8..12].copy_from_slice(&len.to_be_bytes());
+        data
+    }
+
+    // all test directories use single byte lengths, and known tags
+    fn directory_len(directory: &[u8]) -> u16 {
+        let mut cursor = FontData::new(directory).cursor();
+        let mut len = 0;
+        while cursor.remaining_bytes() > 0 {
+            read_table_directory_entry(&mut cursor).unwrap();
+            len += 1;
+        }
+        len
+    }
+
+    #[test]
+    fn uint_base128() {
+        let read = |bytes: &[u8]| read_uint_base128(&mut FontData::new(bytes).cursor());
+        assert_eq!(read(&[0x3F]).unwrap(), 63);
+        assert_eq!(read(&[0x81, 0x00]).unwrap(), 128);
+        assert_eq!(read(&[0x8F, 0xFF, 0xFF, 0xFF, 0x7F]).unwrap(), u32::MAX);
+        // leading zeros
+        assert!(read(&[0x80, 0x01]).is_err());
+        // overflow
+        assert!(read(&[0x90, 0x80, 0x80, 0x80, 0x00]).is_err());
+        // too long
+        assert!(read(&[0x81, 0x80, 0x80, 0x80, 0x80, 0x00]).is_err());
+    }
+
+    #[test]
+    fn u16_255() {
+        let read = |bytes: &[u8]| read_255_u16(&mut FontData::new(bytes).cursor()).unwrap();
+        assert_eq!(read(&[252]), 252);
+        assert_eq!(read(&[255, 0]), 253);
+        assert_eq!(read(&[255, 252]), 505);
+        assert_eq!(read(&[254, 0]), 506);
+        assert_eq!(read(&[253, 0x01, 0x02]), 258);
+    }
+
+    #[test]
+    fn triplets() {
+        let read = |flag: u8, bytes: &[u8]| {
+            let mut streams = empty_streams();
+            streams.glyphs = FontData::new(bytes).cursor();
+            streams.read_triplet(flag).unwrap()
+        };
+        assert_eq!(read(0, &[50]), (0, -50));
+        assert_eq!(read(3, &[50]), (0, 256 + 50));
+        assert_eq!(read(11, &[10]), (10, 0));
+        assert_eq!(read(20, &[0x12]), (-2, -3));
+        assert_eq!(read(84 + 12 + 3, &[1, 2]), (1 + 256 + 1, 1 + 2));
+        assert_eq!(read(123, &[0x12, 0x34, 0x56]), (0x123, 0x456));
+        assert_eq!(read(126, &[0x01, 0x00, 0x00, 0xC8]), (-256, 200));
+    }
+
+    #[test]
+    fn reject_coordinate_overflow() {
+        let glyph = |n_points: usize| {
+            let mut streams = empty_streams();
+            let n_points_data = [n_points as u8];
+            streams.n_points = FontData::new(&n_points_data).cursor();
+            streams.flags = FontData::new(&[127, 127]).cursor();
+            // each point moves 32767 units right, followed by the
+            // instruction length
+            let mut glyphs = [0x7F, 0xFF, 0, 0].repeat(n_points);
+            glyphs.push(0);
+            streams.glyphs = FontData::new(&glyphs).cursor();
+            streams.write_simple_glyph(1, None, false, &mut vec![])
+        };
+        assert!(glyph(1).is_ok());
+        assert!(glyph(2).is_err());
+    }
+
+    #[test]
+    fn reject_oversized_decompression() {
+        assert!(decompress(&brotli_uncompressed(&[]), 0).is_some());
+        assert!(decompress(&brotli_uncompressed(&[]), MAX_DECOMPRESSED_LEN + 1).is_none());
+    }
+
+    fn empty_streams() -> GlyfStreams<'static> {
+        let empty = FontData::new(&[]).cursor();
+        GlyfStreams {
+            n_contours: empty,
+            n_points: empty,
+            flags: empty,
+            glyphs: empty,
+            composites: empty,
+            bbox_bitmap: &[],
+            bboxes: empty,
+            instructions: empty,
+            overlap_bitmap: None,
+        }
+    }
+
+    // A simple glyph, an empty glyph and a composite glyph, with the
+    // overlap flag set for the simple glyph.
+    fn transformed_glyf() -> Vec<u8> {
+        let n_contours = BeBuffer::new().extend([1i16, 0, -1]);
+        let n_points = [3u8];
+        let flags = [11u8, 11, 126 | 0x80];
+        let glyphs = [10u8, 100, 0, 50, 0, 200, 2];
+        let composites = BeBuffer::new().extend([0x0003u16, 0]).extend([5i16, -5]);
+        let bboxes = BeBuffer::new()
+            .extend([0x20u8, 0, 0, 0])
+            .extend([15i16, -5, 115, 195]);
+        let instructions = [0xB0u8, 0x05];
+        let mut data = BeBuffer::new().extend([0u16, 1, 3, 0]);
+        for len in [
+            n_contours.len(),
+            n_points.len(),
+            flags.len(),
+            glyphs.len(),
+            composites.len(),
+            bboxes.len(),
+            instructions.len(),
+        ] {
+            data = data.push(len as u32);
+        }
+        data.extend(n_contours.iter().copied())
+            .extend(n_points)
+            .extend(flags)
+            .extend(glyphs)
+            .extend(composites.iter().copied())
+            .extend(bboxes.iter().copied())
+            .extend(instructions)
+            .push(0x80u8)
+            .to_vec()
+    }
+
+    #[test]
+    fn decode_transformed_glyf_and_hmtx() {
+        let mut head = vec![0u8; 54];
+        head[..4].copy_from_slice(&[0, 1, 0, 0]);
+        head[18] = 0x04;
+        let mut hhea = vec![0u8; 36];
+        hhea[..4].copy_from_slice(&[0, 1, 0, 0]);
+        hhea[35] = 2;
+        let maxp = [0u8, 0, 0x50, 0, 0, 3];
+        let glyf = transformed_glyf();
+        // both sets of side bearings omitted
+        let hmtx = BeBuffer::new().push(3u8).extend([500u16, 0]);
+
+        let directory = [
+            vec![1, 54],
+            vec![2, 36],
+            vec![3 | 0x40, 10, hmtx.len() as u8],
+            vec![4, 6],
+            vec![10, 44, glyf.len() as u8],
+            vec![11, 8, 0],
+        ]
+        .concat();
+        let tables = [&head, &hhea, &hmtx[..], &maxp, &glyf].concat();
+        let data = make_woff2(0x00010000, &directory, &[], &tables);
+        let woff = match FileRef::new(&data).unwrap() {
+            FileRef::Woff2(woff) => woff,
+            _ => panic!("expected WOFF2"),
+        };
+        assert_eq!(woff.table_directory().len(), 6);
+        assert!(woff.table_directory()[4].is_transformed());
+        assert!(woff.collection_fonts().is_none());
+
+        let sfnt = woff.to_sfnt().unwrap();
+        assert_eq!(checksum(&sfnt), CHECKSUM_MAGIC);
+        let font = FontRef::new(&sfnt).unwrap();
+        assert_eq!(font.head().unwrap().units_per_em(), 0x0400);
+        let glyf = font.glyf().unwrap();
+        let loca = font.loca(None).unwrap();
+        assert_eq!(loca.len(), 3);
+
+        let Some(Glyph::Simple(simple)) = loca.get_glyf(GlyphId::new(0), &glyf).unwrap() else {
+            panic!("expected simple glyph");
+        };
+        let points = simple
+            .points()
+            .map(|point| (point.x, point.y, point.on_curve))
+            .collect::<Vec<_>>();
+        assert_eq!(points, [(10, 0, true), (110, 0, true), (60, 200, false)]);
+        assert_eq!(
+            [
+                simple.x_min(),
+                simple.y_min(),
+                simple.x_max(),
+                simple.y_max()
+            ],
+            [10, 0, 110, 200]
+        );
+        assert_eq!(simple.instructions(), &[0xB0, 0x05]);
+        assert_eq!(simple.glyph_data()[0] & OVERLAP_SIMPLE, OVERLAP_SIMPLE);
+
+        assert!(loca.get_glyf(GlyphId::new(1), &glyf).unwrap().is_none());
+        let Some(Glyph::Composite(composite)) = loca.get_glyf(GlyphId::new(2), &glyf).unwrap()
+        else {
+            panic!("expected composite glyph");
+        };
+        assert_eq!(composite.x_min(), 15);
+        let component = composite.components().next().unwrap();
+        assert_eq!(component.glyph, GlyphId::new(0));
+
+        let hmtx = font.hmtx().unwrap();
+        let metrics = hmtx.h_metrics();
+        assert_eq!(metrics[0].advance(), 500);
+        assert_eq!(metrics[0].side_bearing(), 10);
+        assert_eq!(metrics[1].side_bearing(), 0);
+        assert_eq!(hmtx.left_side_bearings()[0].get(), 15);
+    }
+
+    #[test]
+    fn decode_collection() {
+        let maxp = [0u8, 0, 0x50, 0, 0, 7];
+        let directory = [4u8, 6, 5, 4, 5, 4];
+        let collection = BeBuffer::new()
+            .push(0x00010000u32)
+            .push(2u8)
+            .push(2u8)
+            .push(0x00010000u32)
+            .extend([0u8, 1])
+            .push(2u8)
+            .push(0x00010000u32)
+            .extend([0u8, 2]);
+        let tables = [&maxp[..], b"AAAA", b"BBBB"].concat();
+        let data = make_woff2(
+            u32::from_be_bytes(TTC_HEADER_TAG.into_bytes()),
+            &directory,
+            &collection,
+            &tables,
+        );
+        // the fonts are only available after decoding
+        let file = FileRef::new(&data).unwrap();
+        assert!(file.fonts().all(|font| font.is_err()));
+        assert!(FontRef::from_index(&data, 1).is_err());
+        let FileRef::Woff2(woff) = file else {
+            panic!("expected WOFF2");
+        };
+        let fonts = woff.collection_fonts().unwrap();
+        assert_eq!(fonts.len(), 2);
+        assert_eq!(fonts[1].table_indices(), &[0, 2]);
+
+        let sfnt = woff.to_sfnt().unwrap();
+        assert_eq!(FileRef::new(&sfnt).unwrap().fonts().count(), 2);
+        assert!(FontRef::from_index(&sfnt, 1).is_ok());
+        let FileRef::Collection(collection) = FileRef::new(&sfnt).unwrap() else {
+            panic!("expected collection");
+        };
+        assert_eq!(collection.len(), 2);
+        let name = Tag::new(b"name");
+        let fonts = collection.iter().collect::<Result<Vec<_>, _>>().unwrap();
+        for (font, expected) in fonts.iter().zip([b"AAAA", b"BBBB"]) {
+            assert_eq!(font.maxp().unwrap().num_glyphs(), 7);
+            assert_eq!(font.table_data(name).unwrap().as_bytes(), expected);
+        }
+        // the maxp table is shared
+        let maxp_offset = |font: &FontRef| font.table_directory.table_records()[0].offset();
+        assert_eq!(maxp_offset(&fonts[0]), maxp_offset(&fonts[1]));
+    }
+
+    #[test]
+    fn reject_bad_data() {
+        let data = make_woff2(0x00010000, &[4, 6], &[], &[0, 0, 0x50, 0, 0, 7]);
+        assert!(Woff2Ref::new(&data).unwrap().to_sfnt().is_ok());
+        let mut bad_length = data.clone();
+        bad_length.push(0);
+        assert!(Woff2Ref::new(&bad_length).is_err());
+        // the directory claims more data than the stream contains
+        let short_stream = make_woff2(0x00010000, &[4, 7], &[], &[0, 0, 0x50, 0, 0, 7]);
+        assert!(Woff2Ref::new(&short_stream).unwrap().to_sfnt().is_err());
+        // transformed glyf without a transformed loca
+        let glyf = transformed_glyf();
+        let untransformed_loca = make_woff2(
+            0x00010000,
+            &[10, 44, glyf.len() as u8, 11 | 0xC0, 0],
+            &[],
+            &glyf,
+        );
+        assert!(Woff2Ref::new(&untransformed_loca)
+            .unwrap()
+            .to_sfnt()
+            .is_err());
+    }
+}
@@ -0,0 +1,177 @@
+#![parse_module(read_fonts::tables::aat)]
+
+/// An [AAT lookup table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6Tables.html),
+/// which maps glyph ids to values.
+///
+/// The size of the values depends on the table that contains the lookup.
+format u16 Lookup {
+    Format0(Lookup0),
+    Format2(Lookup2),
+    Format4(Lookup4),
+    Format6(Lookup6),
+    Format8(Lookup8),
+    Format10(Lookup10),
+}
+
+/// Simple array format. The lookup data is an array of lookup values,
+/// indexed by glyph index.
+table Lookup0 {
+    /// Format number is set to 0.
+    #[format = 0]
+    format: u16,
+    /// Values, indexed by glyph index.
+    #[count(..)]
+    values_data: [u8],
+}
+
+/// Segment single format. Each non-overlapping segment has a single lookup
+/// value that applies to all glyphs in the segment.
+table Lookup2 {
+    /// Format number is set to 2.
+    #[format = 2]
+    format: u16,
+    /// Size of a lookup unit for this search in bytes.
+    unit_size: u16,
+    /// Number of units of the preceding size to be searched.
+    n_units: u16,
+    /// The value of unitSize times the largest power of 2 that is less
+    /// than or equal to the value of nUnits.
+    search_range: u16,
+    /// The log base 2 of the largest power of 2 less than or equal to the
+    /// value of nUnits.
+    entry_selector: u16,
+    /// The value of unitSize times the difference of the value of nUnits
+    /// minus the largest power of 2 less than or equal to the value of
+    /// nUnits.
+    range_shift: u16,
+    /// Segments, each consisting of the last glyph, first glyph and value.
+    #[count(multiply($unit_size, $n_units))]
+    segments_data: [u8],
+}
+
+/// Segment array format. Each segment has an array of lookup values, one
+/// for each glyph in the segment.
+table Lookup4 {
+    /// Format number is set to 4.
+    #[format = 4]
+    format: u16,
+    /// Size of a lookup unit for this search in bytes.
+    unit_size: u16,
+    /// Number of units of the preceding size to be searched.
+    n_units: u16,
+    /// The value of unitSize times the largest power of 2 that is less
+    /// than or equal to the value of nUnits.
+    search_range: u16,
+    /// The log base 2 of the largest power of 2 less than or equal to the
+    /// value of nUnits.
+    entry_selector: u16,
+    /// The value of unitSize times the difference of the value of nUnits
+    /// minus the largest power of 2 less than or equal to the value of
+    /// nUnits.
+    range_shift: u16,
+    /// Segments, each consisting of the last glyph, first glyph and the
+    /// offset from the beginning of the lookup table to the values of the
+    /// segment.
+    #[count($n_units)]
+    segments: [LookupSegment4],
+}
+
+/// A segment in a format 4 lookup table.
+record LookupSegment4 {
+    /// Last glyph index in this segment.
+    last_glyph: u16,
+    /// First glyph index in this segment.
+    first_glyph: u16,
+    /// Offset from the beginning of the lookup table to the values for
+    /// this segment.
+    value_offset: u16,
+}
+
+/// Single table format. The lookup data is a sorted list of glyph index
+/// and value pairs.
+table Lookup6 {
+    /// Format number is set to 6.
+    #[format = 6]
+    format: u16,
+    /// Size of a lookup unit for this search in bytes.
+    unit_size: u16,
+    /// Number of units of the preceding size to be searched.
+    n_units: u16,
+    /// The value of unitSize times the largest power of 2 that is less
+    /// than or equal to the value of nUnits.
+    search_range: u16,
+    /// The log base 2 of the largest power of 2 less than or equal to the
+    /// value of nUnits.
+    entry_selector: u16,
+    /// The value of unitSize times the difference of the value of nUnits
+    /// minus the largest power of 2 less than or equal to the value of
+    /// nUnits.
+    range_shift: u16,
+    /// Entries, each consisting of a glyph index and value.
+    #[count(multiply($unit_size, $n_units))]
+    entries_data: [u8],
+}
+
+/// Trimmed array format. The lookup data is a simple trimmed array indexed
+/// by glyph index.
+table Lookup8 {
+    /// Format number is set to 8.
+    #[format = 8]
+    format: u16,
+    /// First glyph index included in the trimmed array.
+    first_glyph: u16,
+    /// Total number of glyphs (equivalent to the last glyph minus the value
+    /// of firstGlyph plus 1).
+    glyph_count: u16,
+    /// The lookup values, indexed by the glyph index minus the value of
+    /// firstGlyph.
+    #[count(..)]
+    values_data: [u8],
+}
+
+/// Trimmed array format with explicit value size. The lookup data is a
+/// simple trimmed array indexed by glyph index.
+table Lookup10 {
+    /// Format number is set to 10.
+    #[format = 10]
+    format: u16,
+    /// Size of a lookup unit for this lookup table in bytes. Allowed values
+    /// are 1, 2, 4, and 8.
+    unit_size: u16,
+    /// First glyph index included in the trimmed array.
+    first_glyph: u16,
+    /// Total number of glyphs (equivalent to the last glyph minus the value
+    /// of firstGlyph plus 1).
+    glyph_count: u16,
+    /// The lookup values, indexed by the glyph index minus the value of
+    /// firstGlyph.
+    #[count(multiply($unit_size, $glyph_count))]
+    values_data: [u8],
+}
+
+/// Header for an [extended state table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6Tables.html).
+table StxHeader {
+    /// Number of classes, which is the number of 16-bit entry indices in a
+    /// single line in the state array.
+    n_classes: u32,
+    /// Offset from the start of the state table header to the class table.
+    class_table_offset: Offset32<Lookup>,
+    /// Offset from the start of the state table header to the state array.
+    state_array_offset: Offset32<RawWords>,
+    /// Offset from the start of the state table header to the entry table.
+    entry_table_offset: Offset32<RawBytes>,
+}
+
+/// An array of 16-bit values extending to the end of the data, used for
+/// state arrays.
+table RawWords {
+    #[count(..)]
+    data: [u16],
+}
+
+/// An array of bytes extending to the end of the data, used for entry
+/// tables.
+table RawBytes {
+    #[count(..)]
+    data: [u8],
+}
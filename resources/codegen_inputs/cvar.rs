@@ -0,0 +1,23 @@
+#![parse_module(read_fonts::tables::cvar)]
+
+extern scalar TupleVariationCount;
+extern record TupleVariationHeader;
+
+/// The [cvar (CVT Variations)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvar) table.
+#[tag = "cvar"]
+table Cvar {
+    /// Major/minor version number of the CVT variations table — set to (1,0).
+    version: MajorMinor,
+    /// Flags and the number of tuple variation tables. The high 4 bits
+    /// are flags, and the low 12 bits are the number of tuple variation
+    /// tables.
+    #[traverse_with(skip)]
+    tuple_variation_count: TupleVariationCount,
+    /// Offset from the start of the 'cvar' table to the serialized data.
+    #[traverse_with(skip)]
+    data_offset: Offset16<FontData>,
+    /// Array of tuple variation headers.
+    #[count(..)]
+    #[traverse_with(skip)]
+    tuple_variation_headers: VarLenArray<TupleVariationHeader>,
+}
@@ -0,0 +1,9 @@
+#![parse_module(read_fonts::tables::cvt)]
+
+/// The [cvt (Control Value Table)](https://learn.microsoft.com/en-us/typography/opentype/spec/cvt) table.
+#[tag = "cvt "]
+table Cvt {
+    /// List of values referenceable by instructions, in font units.
+    #[count(..)]
+    values: [i16],
+}
@@ -0,0 +1,9 @@
+#![parse_module(read_fonts::tables::fpgm)]
+
+/// The [fpgm (Font Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/fpgm) table.
+#[tag = "fpgm"]
+table Fpgm {
+    /// Instructions that are executed once, when the font is first used.
+    #[count(..)]
+    instructions: [u8],
+}
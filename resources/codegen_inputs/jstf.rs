@@ -0,0 +1,147 @@
+#![parse_module(read_fonts::tables::jstf)]
+
+/// The [JSTF (Justification)](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) table.
+#[tag = "JSTF"]
+table Jstf {
+    /// Major/minor version of the JSTF table, = 1.0
+    #[compile(MajorMinor::VERSION_1_0)]
+    version: MajorMinor,
+    /// Number of JstfScriptRecords in this table
+    #[compile(array_len($jstf_script_records))]
+    jstf_script_count: u16,
+    /// Array of JstfScriptRecords, in alphabetical order by jstfScriptTag
+    #[count($jstf_script_count)]
+    jstf_script_records: [JstfScriptRecord],
+}
+
+/// Part of [Jstf]
+record JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    jstf_script_tag: Tag,
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    jstf_script_offset: Offset16<JstfScript>,
+}
+
+/// [JstfScript](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-script-table) table
+table JstfScript {
+    /// Offset to ExtenderGlyph table, from beginning of JstfScript table
+    /// (may be NULL)
+    #[nullable]
+    extender_glyph_offset: Offset16<ExtenderGlyph>,
+    /// Offset to default JstfLangSys table, from beginning of JstfScript
+    /// table (may be NULL)
+    #[nullable]
+    def_jstf_lang_sys_offset: Offset16<JstfLangSys>,
+    /// Number of JstfLangSysRecords in this table- may be zero (0)
+    #[compile(array_len($jstf_lang_sys_records))]
+    jstf_lang_sys_count: u16,
+    /// Array of JstfLangSysRecords, in alphabetical order by JstfLangSysTag
+    #[count($jstf_lang_sys_count)]
+    jstf_lang_sys_records: [JstfLangSysRecord],
+}
+
+/// Part of [JstfScript]
+record JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    jstf_lang_sys_tag: Tag,
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    jstf_lang_sys_offset: Offset16<JstfLangSys>,
+}
+
+/// [ExtenderGlyph](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table) table
+table ExtenderGlyph {
+    /// Number of extender glyphs in this script
+    #[compile(array_len($extender_glyphs))]
+    glyph_count: u16,
+    /// Extender glyph IDs — in increasing numerical order
+    #[count($glyph_count)]
+    extender_glyphs: [GlyphId],
+}
+
+/// [JstfLangSys](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-language-system-table) table
+table JstfLangSys {
+    /// Number of JstfPriority tables
+    #[compile(array_len($jstf_priority_offsets))]
+    jstf_priority_count: u16,
+    /// Array of offsets to JstfPriority tables, from beginning of
+    /// JstfLangSys table, in priority order
+    #[count($jstf_priority_count)]
+    jstf_priority_offsets: [Offset16<JstfPriority>],
+}
+
+/// [JstfPriority](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-priority-table) table
+table JstfPriority {
+    /// Offset to shrinkage-enable JstfGSUBModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_shrinkage_enable_offset: Offset16<JstfGsubModList>,
+    /// Offset to shrinkage-disable JstfGSUBModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_shrinkage_disable_offset: Offset16<JstfGsubModList>,
+    /// Offset to shrinkage-enable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_shrinkage_enable_offset: Offset16<JstfGposModList>,
+    /// Offset to shrinkage-disable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_shrinkage_disable_offset: Offset16<JstfGposModList>,
+    /// Offset to shrinkage JstfMax table, from beginning of JstfPriority
+    /// table (may be NULL)
+    #[nullable]
+    shrinkage_jstf_max_offset: Offset16<JstfMax>,
+    /// Offset to extension-enable JstfGSUBModList table, from beginnning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_extension_enable_offset: Offset16<JstfGsubModList>,
+    /// Offset to extension-disable JstfGSUBModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gsub_extension_disable_offset: Offset16<JstfGsubModList>,
+    /// Offset to extension-enable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_extension_enable_offset: Offset16<JstfGposModList>,
+    /// Offset to extension-disable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    #[nullable]
+    gpos_extension_disable_offset: Offset16<JstfGposModList>,
+    /// Offset to extension JstfMax table, from beginning of JstfPriority
+    /// table (may be NULL)
+    #[nullable]
+    extension_jstf_max_offset: Offset16<JstfMax>,
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables) table
+table JstfGsubModList {
+    /// Number of lookups for this modification
+    #[compile(array_len($gsub_lookup_indices))]
+    lookup_count: u16,
+    /// Array of Lookup indices into the GSUB LookupList, in increasing
+    /// numerical order
+    #[count($lookup_count)]
+    gsub_lookup_indices: [u16],
+}
+
+/// [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables) table
+table JstfGposModList {
+    /// Number of lookups for this modification
+    #[compile(array_len($gpos_lookup_indices))]
+    lookup_count: u16,
+    /// Array of Lookup indices into the GPOS LookupList, in increasing
+    /// numerical order
+    #[count($lookup_count)]
+    gpos_lookup_indices: [u16],
+}
+
+/// [JstfMax](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-maximum-table) table
+table JstfMax {
+    /// Number of lookup Indices for this modification
+    #[compile(array_len($lookup_offsets))]
+    lookup_count: u16,
+    /// Array of offsets to GPOS-type lookup tables, from beginning of
+    /// JstfMax table, in design order
+    #[count($lookup_count)]
+    lookup_offsets: [Offset16<PositionLookup>],
+}
@@ -0,0 +1,182 @@
+#![parse_module(read_fonts::tables::kern)]
+
+/// The [kern (Kerning)](https://learn.microsoft.com/en-us/typography/opentype/spec/kern) table.
+///
+/// This is either the OpenType version of the table or the version defined
+/// by [Apple](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html).
+format u16 Kern {
+    #[match_if($format == 0)]
+    Ot(OtKern),
+    #[match_if($format == 1)]
+    Aat(AatKern),
+}
+
+/// The OpenType [kern](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#kerning-table-header) table header.
+table OtKern {
+    /// Table version number—set to 0.
+    version: u16,
+    /// Number of subtables in the kerning table.
+    n_tables: u16,
+    /// Data for subtables, immediately following the header.
+    #[count(..)]
+    #[traverse_with(traverse_subtables)]
+    subtable_data: [u8],
+}
+
+/// The Apple [kern](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) table header.
+table AatKern {
+    /// The version number of the kerning table (0x00010000 for the current version).
+    version: MajorMinor,
+    /// The number of subtables included in the kerning table.
+    n_tables: u32,
+    /// Data for subtables, immediately following the header.
+    #[count(..)]
+    #[traverse_with(traverse_subtables)]
+    subtable_data: [u8],
+}
+
+/// A [subtable](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#kerning-subtables) in an OpenType `kern` table.
+table OtSubtable {
+    /// Kern subtable version number—set to 0.
+    version: u16,
+    /// The length of this subtable in bytes, including this header.
+    length: u16,
+    /// What type of information is contained in this table. The format
+    /// of the subtable is in the high byte.
+    coverage: u16,
+    /// Subtable specific data.
+    #[count(..)]
+    #[traverse_with(traverse_kind)]
+    data: [u8],
+}
+
+/// A [subtable](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) in an Apple `kern` table.
+table AatSubtable {
+    /// The length of this subtable in bytes, including this header.
+    length: u32,
+    /// Circumstances under which this table is used. The format of the
+    /// subtable is in the low byte.
+    coverage: u16,
+    /// The tuple index (used for variations fonts). This value specifies
+    /// which tuple this subtable covers.
+    tuple_index: u16,
+    /// Subtable specific data.
+    #[count(..)]
+    #[traverse_with(traverse_kind)]
+    data: [u8],
+}
+
+/// The [format 0](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#format-0) kern subtable: an ordered list of kerning pairs.
+table Subtable0 {
+    /// The number of kerning pairs in this subtable.
+    n_pairs: u16,
+    /// The largest power of two less than or equal to the value of nPairs,
+    /// multiplied by the size in bytes of an entry in the subtable.
+    search_range: u16,
+    /// This is calculated as log2 of the largest power of two less than or
+    /// equal to the value of nPairs.
+    entry_selector: u16,
+    /// The value of nPairs minus the largest power of two less than or
+    /// equal to nPairs.
+    range_shift: u16,
+    /// Kerning records, sorted by the left and right glyph identifiers.
+    #[count($n_pairs)]
+    pairs: [Subtable0Pair],
+}
+
+/// A kerning pair in a format 0 subtable.
+record Subtable0Pair {
+    /// The glyph index for the lefthand glyph in the kerning pair.
+    left: GlyphId,
+    /// The glyph index for the righthand glyph in the kerning pair.
+    right: GlyphId,
+    /// The kerning value for the above pair, in design units.
+    value: FWord,
+}
+
+/// The [format 1](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) kern subtable: a state table for contextual kerning.
+table Subtable1 {
+    /// Number of classes, which is the number of 1-byte entries in a
+    /// state array row.
+    n_classes: u16,
+    /// Offset from the beginning of the state table to the class subtable.
+    class_table_offset: Offset16<ClassSubtable>,
+    /// Offset from the beginning of the state table to the state array.
+    state_array_offset: u16,
+    /// Offset from the beginning of the state table to the entry subtable.
+    entry_table_offset: u16,
+    /// Offset from the beginning of the state table to the kerning value
+    /// table.
+    value_table_offset: u16,
+}
+
+/// Maps glyphs to classes in a format 1 subtable.
+table ClassSubtable {
+    /// Glyph index of the first glyph in the class array.
+    first_glyph: GlyphId,
+    /// Number of glyphs in the class array.
+    n_glyphs: u16,
+    /// The class of each glyph, beginning with the first glyph.
+    #[count($n_glyphs)]
+    class_array: [u8],
+}
+
+/// The [format 2](https://learn.microsoft.com/en-us/typography/opentype/spec/kern#format-2) kern subtable: a two-dimensional array of kerning values.
+///
+/// Offsets in this subtable are relative to the start of the enclosing
+/// subtable header, so this is read from the data of the full subtable
+/// with the length of the header as an argument.
+#[read_args(header_len: u16)]
+table Subtable2 {
+    /// The subtable header.
+    #[count($header_len)]
+    #[skip_getter]
+    header: [u8],
+    /// The width, in bytes, of a row in the table.
+    row_width: u16,
+    /// Offset from beginning of this subtable to the left-hand class table.
+    left_class_table_offset: Offset16<Subtable2ClassTable>,
+    /// Offset from beginning of this subtable to the right-hand class table.
+    right_class_table_offset: Offset16<Subtable2ClassTable>,
+    /// Offset from beginning of this subtable to the start of the kerning
+    /// array.
+    array_offset: u16,
+}
+
+/// Class table for a format 2 kern subtable.
+table Subtable2ClassTable {
+    /// First glyph in class range.
+    first_glyph: GlyphId,
+    /// Number of glyph in class range.
+    n_glyphs: u16,
+    /// The class values, which are byte offsets from the beginning of the
+    /// subtable to a row or column of the kerning array.
+    #[count($n_glyphs)]
+    offsets: [u16],
+}
+
+/// The [format 3](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html) kern subtable: a compact two-dimensional array of kerning values indexed by glyph class.
+table Subtable3 {
+    /// The number of glyphs in this font.
+    glyph_count: u16,
+    /// The number of kerning values.
+    kern_value_count: u8,
+    /// The number of left-hand classes.
+    left_class_count: u8,
+    /// The number of right-hand classes.
+    right_class_count: u8,
+    /// Set to zero (reserved for future use).
+    flags: u8,
+    /// The kerning values.
+    #[count($kern_value_count)]
+    kern_value: [FWord],
+    /// The left-hand classes.
+    #[count($glyph_count)]
+    left_class: [u8],
+    /// The right-hand classes.
+    #[count($glyph_count)]
+    right_class: [u8],
+    /// The indices into the kernValue array.
+    #[count(multiply($left_class_count, $right_class_count))]
+    kern_index: [u8],
+}
@@ -0,0 +1,161 @@
+#![parse_module(read_fonts::tables::kerx)]
+
+/// The [kerx (Extended Kerning)](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kerx.html) table.
+#[tag = "kerx"]
+table Kerx {
+    /// The version number of the extended kerning table (currently 2, 3,
+    /// or 4).
+    version: u16,
+    /// Unused; set to zero.
+    #[skip_getter]
+    #[compile(0)]
+    padding: u16,
+    /// The number of subtables included in the extended kerning table.
+    n_tables: u32,
+    /// Data for subtables, immediately following the header.
+    #[count(..)]
+    #[traverse_with(traverse_subtables)]
+    subtable_data: [u8],
+}
+
+/// A subtable in a `kerx` table.
+table Subtable {
+    /// The length of this subtable in bytes, including this header.
+    length: u32,
+    /// Circumstances under which this table is used. The format of the
+    /// subtable is in the low byte.
+    coverage: u32,
+    /// The tuple count. This value is only used with variation fonts and
+    /// should be 0 for all other fonts.
+    tuple_count: u32,
+    /// Subtable specific data.
+    #[count(..)]
+    #[traverse_with(traverse_kind)]
+    data: [u8],
+}
+
+/// The format 0 `kerx` subtable: an ordered list of kerning pairs.
+table Subtable0 {
+    /// The number of kerning pairs in this subtable.
+    n_pairs: u32,
+    /// The largest power of two less than or equal to the value of nPairs,
+    /// multiplied by the size in bytes of an entry in the subtable.
+    search_range: u32,
+    /// This is calculated as log2 of the largest power of two less than or
+    /// equal to the value of nPairs.
+    entry_selector: u32,
+    /// The value of nPairs minus the largest power of two less than or
+    /// equal to nPairs.
+    range_shift: u32,
+    /// Kerning records, sorted by the left and right glyph identifiers.
+    #[count($n_pairs)]
+    pairs: [Subtable0Pair],
+}
+
+/// A kerning pair in a format 0 subtable.
+record Subtable0Pair {
+    /// The glyph index for the lefthand glyph in the kerning pair.
+    left: GlyphId,
+    /// The glyph index for the righthand glyph in the kerning pair.
+    right: GlyphId,
+    /// The kerning value for the above pair, in design units.
+    value: FWord,
+}
+
+/// The format 1 `kerx` subtable: a state table for contextual kerning.
+///
+/// The first four fields are the header of the extended state table.
+table Subtable1 {
+    /// Number of classes in the state table.
+    n_classes: u32,
+    /// Offset from the start of the state table to the class table.
+    class_table_offset: Offset32<Lookup>,
+    /// Offset from the start of the state table to the state array.
+    state_array_offset: Offset32<RawWords>,
+    /// Offset from the start of the state table to the entry table.
+    entry_table_offset: Offset32<RawBytes>,
+    /// Offset from the start of the state table to the kerning values.
+    value_table_offset: u32,
+}
+
+/// Entry data for a format 1 subtable.
+record Subtable1EntryData {
+    /// Index into the kerning value array for this entry (use 0xFFFF for
+    /// none).
+    value_index: u16,
+}
+
+/// The format 2 `kerx` subtable: a two-dimensional array of kerning values.
+///
+/// Offsets in this subtable are relative to the start of the subtable
+/// header, so this is read from the data of the full subtable.
+table Subtable2 {
+    /// The subtable header.
+    #[count(12)]
+    #[skip_getter]
+    header: [u8],
+    /// The number of bytes in each row of the kerning value array.
+    row_width: u32,
+    /// Offset from beginning of this subtable to the left-hand class table.
+    left_class_table_offset: Offset32<Lookup>,
+    /// Offset from beginning of this subtable to the right-hand class table.
+    right_class_table_offset: Offset32<Lookup>,
+    /// Offset from beginning of this subtable to the start of the kerning
+    /// array.
+    array_offset: u32,
+}
+
+/// The format 4 `kerx` subtable: a state table for attaching glyphs using
+/// control or anchor points.
+///
+/// The first four fields are the header of the extended state table.
+table Subtable4 {
+    /// Number of classes in the state table.
+    n_classes: u32,
+    /// Offset from the start of the state table to the class table.
+    class_table_offset: Offset32<Lookup>,
+    /// Offset from the start of the state table to the state array.
+    state_array_offset: Offset32<RawWords>,
+    /// Offset from the start of the state table to the entry table.
+    entry_table_offset: Offset32<RawBytes>,
+    /// The action type in the high two bits and the offset from the start
+    /// of the state table to the control point actions in the low 24 bits.
+    flags: u32,
+}
+
+/// Entry data for a format 4 subtable.
+record Subtable4EntryData {
+    /// Index into the control point action table for this entry (use
+    /// 0xFFFF for none).
+    ankr_action_index: u16,
+}
+
+/// The format 6 `kerx` subtable: a compact two-dimensional array of kerning
+/// values indexed by glyph.
+///
+/// Offsets in this subtable are relative to the start of the subtable
+/// header, so this is read from the data of the full subtable.
+table Subtable6 {
+    /// The subtable header.
+    #[count(12)]
+    #[skip_getter]
+    header: [u8],
+    /// Flags for this subtable. The low bit is set if the lookup and
+    /// kerning values are 32-bit.
+    flags: u32,
+    /// The number of rows in the kerning value array.
+    row_count: u16,
+    /// The number of columns in the kerning value array.
+    column_count: u16,
+    /// Offset from beginning of this subtable to the row index lookup
+    /// table.
+    row_index_table_offset: Offset32<Lookup>,
+    /// Offset from beginning of this subtable to the column index lookup
+    /// table.
+    column_index_table_offset: Offset32<Lookup>,
+    /// Offset from beginning of this subtable to the kerning array.
+    kerning_array_offset: u32,
+    /// Offset from beginning of this subtable to the kerning vectors, for
+    /// variation fonts.
+    kerning_vector_offset: u32,
+}
@@ -0,0 +1,32 @@
+#![parse_module(read_fonts::tables::ltag)]
+
+/// The [ltag](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html) table
+#[tag = "ltag"]
+table Ltag {
+    /// Table version; currently 1.
+    #[compile(1)]
+    version: u32,
+    /// Table flags; currently none defined.
+    #[compile(0)]
+    flags: u32,
+    /// Number of language tags which follow.
+    #[compile(array_len($tag_ranges))]
+    num_tags: u32,
+    /// Range of each tag's string.
+    #[count($num_tags)]
+    tag_ranges: [FTStringRange],
+}
+
+/// Offset and length of a language tag string within the ltag table.
+record FTStringRange {
+    /// Offset from the start of the table to the beginning of the string.
+    #[offset_getter(tag)]
+    #[traverse_with(traverse_tag)]
+    #[compile_type(OffsetMarker<String>)]
+    #[compile_with(compile_tag)]
+    #[validate(skip)]
+    tag_offset: Offset16<FontData>,
+    /// String length (in bytes).
+    #[compile(skip)]
+    length: u16,
+}
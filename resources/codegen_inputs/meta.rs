@@ -0,0 +1,40 @@
+#![parse_module(read_fonts::tables::meta)]
+
+/// The [meta (Metadata)](https://learn.microsoft.com/en-us/typography/opentype/spec/meta) table
+#[tag = "meta"]
+table Meta {
+    /// Version number of the metadata table — set to 1.
+    #[compile(1)]
+    version: u32,
+    /// Flags — currently unused; set to 0.
+    #[compile(0)]
+    flags: u32,
+    /// Not used; should be set to 0.
+    #[skip_getter]
+    #[compile(0)]
+    reserved: u32,
+    /// The number of data maps in the table.
+    #[compile(array_len($data_maps))]
+    data_maps_count: u32,
+    /// Array of data map records.
+    #[count($data_maps_count)]
+    data_maps: [DataMap],
+}
+
+/// [DataMap](https://learn.microsoft.com/en-us/typography/opentype/spec/meta#table-formats) record
+record DataMap {
+    /// A tag indicating the type of metadata.
+    tag: Tag,
+    /// Offset in bytes from the beginning of the metadata table to the data
+    /// for this tag.
+    #[offset_getter(data)]
+    #[traverse_with(traverse_data)]
+    #[compile_type(OffsetMarker<Metadata, WIDTH_32>)]
+    #[compile_with(compile_map_value)]
+    #[validate(skip)]
+    data_offset: Offset32<Metadata>,
+    /// Length of the data, in bytes. The data is not required to be padded
+    /// to any byte boundary.
+    #[compile(skip)]
+    data_length: u32,
+}
@@ -0,0 +1,52 @@
+#![parse_module(read_fonts::tables::pclt)]
+
+/// The [PCLT (PCL 5)](https://learn.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+#[tag = "PCLT"]
+#[skip_constructor]
+table Pclt {
+    /// Table version number 1.0
+    #[compile(Version16Dot16::VERSION_1_0)]
+    version: Version16Dot16,
+    /// The HP font number.
+    font_number: u32,
+    /// The width of the space in FUnits.
+    pitch: u16,
+    /// The height of the optical line describing the height of the lowercase
+    /// x in FUnits.
+    x_height: u16,
+    /// Contains information on the posture, width and structure of the
+    /// typeface.
+    style: u16,
+    /// The font vendor code and the family code, which together identify
+    /// the font's typeface family.
+    type_family: u16,
+    /// The height of the optical line describing the top of the uppercase
+    /// H in FUnits.
+    cap_height: u16,
+    /// The symbol set of the font.
+    symbol_set: u16,
+    /// The name of the typeface, padded with spaces.
+    #[count(16)]
+    #[compile_type([u8; 16])]
+    #[to_owned(convert_typeface(obj.typeface()))]
+    typeface: [u8],
+    /// Identifies the symbol collections provided by the font.
+    #[count(8)]
+    #[compile_type([u8; 8])]
+    #[to_owned(convert_character_complement(obj.character_complement()))]
+    character_complement: [u8],
+    /// A suggested six character ASCII file name for the font.
+    #[count(6)]
+    #[compile_type([u8; 6])]
+    #[to_owned(convert_file_name(obj.file_name()))]
+    file_name: [u8],
+    /// The stroke weight of the font, from -7 to 7.
+    stroke_weight: i8,
+    /// The appearance width of the font, from -5 to 5.
+    width_type: i8,
+    /// The serif style and stroke structure of the font.
+    serif_style: u8,
+    /// Reserved; set to 0.
+    #[compile(0)]
+    reserved: u8,
+}
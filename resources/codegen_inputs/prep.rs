@@ -0,0 +1,10 @@
+#![parse_module(read_fonts::tables::prep)]
+
+/// The [prep (Control Value Program)](https://learn.microsoft.com/en-us/typography/opentype/spec/prep) table.
+#[tag = "prep"]
+table Prep {
+    /// Instructions that are executed whenever the point size, transform
+    /// or variation instance changes.
+    #[count(..)]
+    instructions: [u8],
+}
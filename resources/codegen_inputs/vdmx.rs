@@ -0,0 +1,54 @@
+#![parse_module(read_fonts::tables::vdmx)]
+
+/// The [VDMX (Vertical Device Metrics)](https://learn.microsoft.com/en-us/typography/opentype/spec/vdmx) table.
+#[tag = "VDMX"]
+table Vdmx {
+    /// Version number (0 or 1).
+    version: u16,
+    /// Number of VDMX groups present.
+    num_recs: u16,
+    /// Number of aspect ratio groupings.
+    num_ratios: u16,
+    /// Ratio record array.
+    #[count($num_ratios)]
+    rat_range: [RatioRange],
+    /// Offsets from start of this table to the VDMX group for each ratio
+    /// range.
+    #[count($num_ratios)]
+    vdmx_group_offsets: [Offset16<VdmxGroup>],
+}
+
+/// A range of aspect ratios.
+record RatioRange {
+    /// Character set (see below).
+    b_char_set: u8,
+    /// Value to use for x-Ratio.
+    x_ratio: u8,
+    /// Starting y-Ratio value.
+    y_start_ratio: u8,
+    /// Ending y-Ratio value.
+    y_end_ratio: u8,
+}
+
+/// The maximum and minimum heights for a range of sizes.
+table VdmxGroup {
+    /// Number of height records in this group.
+    recs: u16,
+    /// Starting yPelHeight.
+    startsz: u8,
+    /// Ending yPelHeight.
+    endsz: u8,
+    /// The VDMX records.
+    #[count($recs)]
+    entries: [VTable],
+}
+
+/// The maximum and minimum heights for a single size.
+record VTable {
+    /// yPelHeight to which values apply.
+    y_pel_height: u16,
+    /// Maximum value (in pels) for this yPelHeight.
+    y_max: i16,
+    /// Minimum value (in pels) for this yPelHeight.
+    y_min: i16,
+}
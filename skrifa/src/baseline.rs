@@ -0,0 +1,491 @@
+//! Baseline coordinates for scripts and languages.
+//!
+//! Baselines are read from the
+//! [BASE](https://learn.microsoft.com/en-us/typography/opentype/spec/base)
+//! table when available. Ideographic baselines that are not defined by the
+//! font are synthesized from the font metrics in the same way that web
+//! browsers do.
+//!
+//! # Example
+//! The following function will return the offset of the ideographic em box
+//! bottom from the alphabetic baseline for Han text, in pixels at a 16px
+//! font size:
+//! ```
+//! use skrifa::{
+//!     baseline::{Baseline, BaselineAxis},
+//!     instance::{LocationRef, Size},
+//!     MetadataProvider, Tag,
+//! };
+//!
+//! fn ideographic_bottom<'a>(font: &impl MetadataProvider<'a>) -> Option<f32> {
+//!     let baselines = font.baselines(Size::new(16.0), LocationRef::default());
+//!     baselines
+//!         .baseline_set(BaselineAxis::Horizontal, Tag::new(b"hani"), None)
+//!         .get(Baseline::IdeographicBottom)
+//! }
+//! ```
+
+use read_fonts::{
+    tables::{
+        base::{Axis, Base, BaseCoord, BaseScript},
+        layout::DeviceOrVariationIndex,
+        mvar::tags::{HASC, HDSC},
+        variations::{DeltaSetIndex, ItemVariationStore},
+    },
+    types::Tag,
+    TableProvider,
+};
+
+use crate::instance::{LocationRef, NormalizedCoord, Size};
+
+/// Script tag used when the requested script is not present.
+const DEFAULT_SCRIPT: Tag = Tag::new(b"DFLT");
+
+/// Inset of the ideographic character face from the em box, as a fraction
+/// of the em, used when the font does not define the face baselines.
+const SYNTHESIZED_FACE_INSET: f32 = 0.05;
+
+/// Direction of the text for which baselines are requested.
+///
+/// For horizontal text, baseline coordinates are vertical offsets from the
+/// alphabetic baseline. For vertical text, they are horizontal offsets from
+/// the vertical origin.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BaselineAxis {
+    Horizontal,
+    Vertical,
+}
+
+/// Baseline identified by a tag in the
+/// [baseline tag registry](https://learn.microsoft.com/en-us/typography/opentype/spec/baselinetags).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Baseline {
+    /// The alphabetic baseline (`romn`).
+    Roman,
+    /// The hanging baseline used by Tibetan and Indic scripts (`hang`).
+    Hanging,
+    /// The baseline about which mathematical characters are centered
+    /// (`math`).
+    Math,
+    /// The bottom or left edge of the ideographic em box (`ideo`).
+    IdeographicBottom,
+    /// The top or right edge of the ideographic em box (`idtp`).
+    IdeographicTop,
+    /// The bottom or left edge of the ideographic character face (`icfb`).
+    IdeographicFaceBottom,
+    /// The top or right edge of the ideographic character face (`icft`).
+    IdeographicFaceTop,
+}
+
+impl Baseline {
+    /// All supported baselines.
+    pub const ALL: [Self; 7] = [
+        Self::Roman,
+        Self::Hanging,
+        Self::Math,
+        Self::IdeographicBottom,
+        Self::IdeographicTop,
+        Self::IdeographicFaceBottom,
+        Self::IdeographicFaceTop,
+    ];
+
+    /// Returns the registered tag for the baseline.
+    pub fn tag(self) -> Tag {
+        match self {
+            Self::Roman => Tag::new(b"romn"),
+            Self::Hanging => Tag::new(b"hang"),
+            Self::Math => Tag::new(b"math"),
+            Self::IdeographicBottom => Tag::new(b"ideo"),
+            Self::IdeographicTop => Tag::new(b"idtp"),
+            Self::IdeographicFaceBottom => Tag::new(b"icfb"),
+            Self::IdeographicFaceTop => Tag::new(b"icft"),
+        }
+    }
+
+    /// Returns the baseline for the given registered tag.
+    pub fn from_tag(tag: Tag) -> Option<Self> {
+        Self::ALL.into_iter().find(|baseline| baseline.tag() == tag)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Baseline coordinates and extents for a script and language.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct BaselineSet {
+    default_baseline: Option<Baseline>,
+    values: [Option<f32>; 7],
+    min_extent: Option<f32>,
+    max_extent: Option<f32>,
+}
+
+impl BaselineSet {
+    /// Returns the baseline that the script is aligned to by default.
+    pub fn default_baseline(&self) -> Option<Baseline> {
+        self.default_baseline
+    }
+
+    /// Returns the scaled coordinate of the given baseline.
+    pub fn get(&self, baseline: Baseline) -> Option<f32> {
+        self.values[baseline.index()]
+    }
+
+    /// Returns an iterator over the available baselines and their scaled
+    /// coordinates.
+    pub fn iter(&self) -> impl Iterator<Item = (Baseline, f32)> + '_ {
+        Baseline::ALL
+            .into_iter()
+            .filter_map(|baseline| Some((baseline, self.get(baseline)?)))
+    }
+
+    /// Returns the scaled minimum extent of glyphs in the script and
+    /// language, if defined by the font.
+    pub fn min_extent(&self) -> Option<f32> {
+        self.min_extent
+    }
+
+    /// Returns the scaled maximum extent of glyphs in the script and
+    /// language, if defined by the font.
+    pub fn max_extent(&self) -> Option<f32> {
+        self.max_extent
+    }
+}
+
+/// Baseline information for a font at a specific size and location in
+/// variation space.
+///
+/// Coordinates from the `BASE` table include variation deltas from its
+/// item variation store. Device tables for hinting and the contour points
+/// of format 2 coordinates are not applied.
+#[derive(Clone, Default)]
+pub struct Baselines<'a> {
+    base: Option<Base<'a>>,
+    var_store: Option<ItemVariationStore<'a>>,
+    coords: &'a [NormalizedCoord],
+    scale: f32,
+    em: f32,
+    /// Top and bottom of the horizontal em box derived from the font
+    /// metrics.
+    em_box: (f32, f32),
+}
+
+impl<'a> Baselines<'a> {
+    /// Creates new baseline information for the given font, size and
+    /// location in normalized variation space.
+    pub fn new(
+        font: &impl TableProvider<'a>,
+        size: Size,
+        location: impl Into<LocationRef<'a>>,
+    ) -> Self {
+        let upem = font
+            .head()
+            .map(|head| head.units_per_em())
+            .unwrap_or_default();
+        let scale = size.linear_scale(upem);
+        let coords = location.into().coords();
+        let base = font.base().ok();
+        let var_store = base
+            .as_ref()
+            .and_then(|base| base.item_var_store())
+            .and_then(|store| store.ok());
+        // browsers prefer the typographic metrics, falling back to hhea
+        let (mut ascent, mut descent) = if let Ok(os2) = font.os2() {
+            (os2.s_typo_ascender() as f32, os2.s_typo_descender() as f32)
+        } else if let Ok(hhea) = font.hhea() {
+            (
+                hhea.ascender().to_i16() as f32,
+                hhea.descender().to_i16() as f32,
+            )
+        } else {
+            (upem as f32 * 0.8, upem as f32 * -0.2)
+        };
+        if let (Ok(mvar), true) = (font.mvar(), !coords.is_empty()) {
+            let metric_delta = |tag| mvar.metric_delta(tag, coords).unwrap_or_default().to_f64();
+            ascent += metric_delta(HASC) as f32;
+            descent += metric_delta(HDSC) as f32;
+        }
+        // center the em box within the ascent and descent
+        let half_gap = (upem as f32 - (ascent - descent)) / 2.0;
+        Self {
+            base,
+            var_store,
+            coords,
+            scale,
+            em: upem as f32 * scale,
+            em_box: ((ascent + half_gap) * scale, (descent - half_gap) * scale),
+        }
+    }
+
+    /// Returns true if the font contains a `BASE` table.
+    pub fn is_present(&self) -> bool {
+        self.base.is_some()
+    }
+
+    /// Returns the baselines for the given axis, script and optional
+    /// language system.
+    ///
+    /// If the script is not present in the `BASE` table, the values for
+    /// the `DFLT` script are used. Ideographic baselines that are missing
+    /// are synthesized from the em box and, for horizontal text, the
+    /// alphabetic baseline defaults to zero.
+    pub fn baseline_set(
+        &self,
+        axis: BaselineAxis,
+        script: Tag,
+        language: Option<Tag>,
+    ) -> BaselineSet {
+        let mut set = self.read_set(axis, script, language).unwrap_or_default();
+        self.synthesize(axis, &mut set);
+        set
+    }
+
+    fn read_set(
+        &self,
+        axis: BaselineAxis,
+        script: Tag,
+        language: Option<Tag>,
+    ) -> Option<BaselineSet> {
+        let axis = self.axis(axis)?;
+        let scripts = axis.base_script_list().ok()?;
+        let base_script = scripts
+            .get(script)
+            .or_else(|| scripts.get(DEFAULT_SCRIPT))?
+            .ok()?;
+        let mut set = BaselineSet::default();
+        if let (Some(Ok(tags)), Some(Ok(values))) =
+            (axis.base_tag_list(), base_script.base_values())
+        {
+            let tags = tags.baseline_tags();
+            set.default_baseline = tags
+                .get(values.default_baseline_index() as usize)
+                .and_then(|tag| Baseline::from_tag(tag.get()));
+            for (tag, coord) in tags.iter().zip(values.base_coords().iter()) {
+                if let (Some(baseline), Ok(coord)) = (Baseline::from_tag(tag.get()), coord) {
+                    set.values[baseline.index()] = Some(self.coordinate(&coord));
+                }
+            }
+        }
+        self.read_extents(&base_script, language, &mut set);
+        Some(set)
+    }
+
+    fn read_extents(&self, script: &BaseScript<'a>, language: Option<Tag>, set: &mut BaselineSet) {
+        let min_max = language
+            .and_then(|language| script.lang_sys_min_max(language))
+            .or_else(|| script.default_min_max());
+        if let Some(Ok(min_max)) = min_max {
+            let coordinate = |coord: Option<Result<BaseCoord, _>>| {
+                coord
+                    .and_then(|coord| coord.ok())
+                    .map(|coord| self.coordinate(&coord))
+            };
+            set.min_extent = coordinate(min_max.min_coord());
+            set.max_extent = coordinate(min_max.max_coord());
+        }
+    }
+
+    fn synthesize(&self, axis: BaselineAxis, set: &mut BaselineSet) {
+        use Baseline::*;
+        let em = self.em;
+        let (top, bottom) = match (set.get(IdeographicTop), set.get(IdeographicBottom)) {
+            (Some(top), Some(bottom)) => (top, bottom),
+            (Some(top), None) => (top, top - em),
+            (None, Some(bottom)) => (bottom + em, bottom),
+            (None, None) => match axis {
+                BaselineAxis::Horizontal => self.em_box,
+                // the vertical em box is centered on the vertical origin
+                BaselineAxis::Vertical => (em / 2.0, -em / 2.0),
+            },
+        };
+        let inset = set
+            .get(IdeographicFaceBottom)
+            .map(|face_bottom| face_bottom - bottom)
+            .or_else(|| set.get(IdeographicFaceTop).map(|face_top| top - face_top))
+            .unwrap_or(em * SYNTHESIZED_FACE_INSET);
+        let values = &mut set.values;
+        values[IdeographicTop.index()] = Some(top);
+        values[IdeographicBottom.index()] = Some(bottom);
+        values[IdeographicFaceTop.index()].get_or_insert(top - inset);
+        values[IdeographicFaceBottom.index()].get_or_insert(bottom + inset);
+        if axis == BaselineAxis::Horizontal {
+            values[Roman.index()].get_or_insert(0.0);
+        }
+    }
+
+    fn axis(&self, axis: BaselineAxis) -> Option<Axis<'a>> {
+        let base = self.base.as_ref()?;
+        match axis {
+            BaselineAxis::Horizontal => base.horiz_axis(),
+            BaselineAxis::Vertical => base.vert_axis(),
+        }?
+        .ok()
+    }
+
+    /// Returns the scaled value of a coordinate, including variation
+    /// deltas at the location of the baseline information.
+    fn coordinate(&self, coord: &BaseCoord) -> f32 {
+        let mut value = coord.coordinate() as f32;
+        if let (BaseCoord::Format3(coord), Some(store), false) =
+            (coord, self.var_store.as_ref(), self.coords.is_empty())
+        {
+            if let Some(Ok(DeviceOrVariationIndex::VariationIndex(index))) = coord.device() {
+                let delta = store
+                    .compute_delta(
+                        DeltaSetIndex {
+                            outer: index.delta_set_outer_index(),
+                            inner: index.delta_set_inner_index(),
+                        },
+                        self.coords,
+                    )
+                    .unwrap_or_default();
+                value += delta as f32;
+            }
+        }
+        value * self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MetadataProvider as _;
+    use read_fonts::FontRef;
+    use write_fonts::{
+        tables::{
+            base::{
+                Axis, Base, BaseCoord, BaseLangSysRecord, BaseScript, BaseScriptList,
+                BaseScriptRecord, BaseTagList, BaseValues, MinMax,
+            },
+            head::Head,
+            layout::VariationIndex,
+            os2::Os2,
+            variations::{
+                ItemVariationData, ItemVariationStore, RegionAxisCoordinates, VariationRegion,
+                VariationRegionList,
+            },
+        },
+        types::F2Dot14,
+        FontBuilder,
+    };
+
+    #[test]
+    fn base_table_baselines() {
+        let font_data = make_font(true);
+        let font = FontRef::new(&font_data).unwrap();
+        let baselines = font.baselines(Size::unscaled(), LocationRef::default());
+        assert!(baselines.is_present());
+        let set = baselines.baseline_set(BaselineAxis::Horizontal, Tag::new(b"latn"), None);
+        assert_eq!(set.default_baseline(), Some(Baseline::Roman));
+        assert_eq!(set.get(Baseline::Roman), Some(0.0));
+        assert_eq!(set.get(Baseline::Hanging), Some(700.0));
+        assert_eq!(set.get(Baseline::IdeographicBottom), Some(-150.0));
+        // idtp is derived from ideo
+        assert_eq!(set.get(Baseline::IdeographicTop), Some(850.0));
+        assert_eq!(set.get(Baseline::Math), None);
+        assert_eq!(set.min_extent(), Some(-300.0));
+        // language specific extents
+        let set = baselines.baseline_set(
+            BaselineAxis::Horizontal,
+            Tag::new(b"latn"),
+            Some(Tag::new(b"TRK ")),
+        );
+        assert_eq!(set.min_extent(), Some(-400.0));
+        // unknown scripts use DFLT
+        let set = baselines.baseline_set(BaselineAxis::Horizontal, Tag::new(b"cyrl"), None);
+        assert_eq!(set.get(Baseline::Hanging), Some(700.0));
+    }
+
+    #[test]
+    fn base_table_variations() {
+        let font_data = make_font(true);
+        let font = FontRef::new(&font_data).unwrap();
+        let coords = &[NormalizedCoord::from_f32(0.5)];
+        let baselines = font.baselines(Size::unscaled(), LocationRef::new(coords));
+        let set = baselines.baseline_set(BaselineAxis::Horizontal, Tag::new(b"latn"), None);
+        assert_eq!(set.get(Baseline::Hanging), Some(720.0));
+        assert_eq!(set.get(Baseline::Roman), Some(0.0));
+    }
+
+    #[test]
+    fn synthesized_baselines() {
+        let font_data = make_font(false);
+        let font = FontRef::new(&font_data).unwrap();
+        let baselines = font.baselines(Size::unscaled(), LocationRef::default());
+        assert!(!baselines.is_present());
+        let set = baselines.baseline_set(BaselineAxis::Horizontal, Tag::new(b"hani"), None);
+        assert_eq!(set.default_baseline(), None);
+        // typo metrics of 800/-100 leave 100 units centered in the em box
+        assert_eq!(set.get(Baseline::IdeographicTop), Some(850.0));
+        assert_eq!(set.get(Baseline::IdeographicBottom), Some(-150.0));
+        assert_eq!(set.get(Baseline::IdeographicFaceTop), Some(800.0));
+        assert_eq!(set.get(Baseline::IdeographicFaceBottom), Some(-100.0));
+        assert_eq!(set.get(Baseline::Roman), Some(0.0));
+        assert_eq!(set.get(Baseline::Hanging), None);
+        let set = baselines.baseline_set(BaselineAxis::Vertical, Tag::new(b"hani"), None);
+        assert_eq!(set.get(Baseline::IdeographicTop), Some(500.0));
+        assert_eq!(set.get(Baseline::IdeographicBottom), Some(-500.0));
+        assert_eq!(set.get(Baseline::Roman), None);
+        assert_eq!(set.iter().count(), 4);
+    }
+
+    #[test]
+    fn baseline_tags() {
+        for baseline in Baseline::ALL {
+            assert_eq!(Baseline::from_tag(baseline.tag()), Some(baseline));
+        }
+        assert_eq!(Baseline::from_tag(Tag::new(b"abcd")), None);
+    }
+
+    fn make_font(with_base: bool) -> Vec<u8> {
+        let head = Head {
+            units_per_em: 1000,
+            ..Default::default()
+        };
+        let os2 = Os2 {
+            s_typo_ascender: 800,
+            s_typo_descender: -100,
+            ..Default::default()
+        };
+        let mut builder = FontBuilder::new();
+        builder.add_table(&head).unwrap().add_table(&os2).unwrap();
+        if with_base {
+            let coord = |value| BaseCoord::format_1(value);
+            let values = BaseValues::new(
+                2,
+                vec![
+                    BaseCoord::format_3(700, Some(VariationIndex::new(0, 0).into())),
+                    coord(-150),
+                    coord(0),
+                ],
+            );
+            let min_max = |value| MinMax::new(Some(coord(value)), None, vec![]);
+            let script = BaseScript::new(
+                Some(values),
+                Some(min_max(-300)),
+                vec![BaseLangSysRecord::new(Tag::new(b"TRK "), min_max(-400))],
+            );
+            let scripts = BaseScriptList::new(vec![
+                BaseScriptRecord::new(Tag::new(b"DFLT"), script.clone()),
+                BaseScriptRecord::new(Tag::new(b"latn"), script),
+            ]);
+            let tags = BaseTagList::new(vec![
+                Tag::new(b"hang"),
+                Tag::new(b"ideo"),
+                Tag::new(b"romn"),
+            ]);
+            let one = F2Dot14::from_f32(1.0);
+            let var_store = ItemVariationStore::new(
+                VariationRegionList::new(vec![VariationRegion::new(vec![
+                    RegionAxisCoordinates::new(F2Dot14::ZERO, one, one),
+                ])]),
+                vec![Some(ItemVariationData::new(1, 0, vec![0], vec![40]))],
+            );
+            let mut base = Base::new(Some(Axis::new(Some(tags), scripts)), None);
+            base.item_var_store = Some(var_store).into();
+            builder.add_table(&base).unwrap();
+        }
+        builder.build()
+    }
+}
@@ -1,6 +1,12 @@
 //! Basic representation of an in-memory font resource.
+//!
+//! Fonts are usually represented by [`FontRef`], which borrows its data. For
+//! fonts that need to be stored in long-lived structures, [`OwnedFont`] owns
+//! its data and can cheaply produce a `FontRef` whenever one is needed.
+//! [`CachedFontRef`] parses each table once, which speeds up code that
+//! frequently creates scalers or other objects from the same font.
 
-pub use read_fonts::FontRef;
+pub use read_fonts::{CachedFontRef, FontRef, OwnedFont};
 
 /// Identifier used as a key for internal caches.
 ///
@@ -45,3 +51,100 @@ impl UniqueId {
         Self { data_id, index }
     }
 }
+
+/// Creates an identifier from the [identifier](OwnedFont::id) and the
+/// collection index of the font.
+///
+/// This is unique within the process and shared by clones of the font.
+impl From<&OwnedFont> for UniqueId {
+    fn from(font: &OwnedFont) -> Self {
+        Self::new(font.id(), font.index())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        instance::{LocationRef, Size},
+        scale::Context,
+        MetadataProvider,
+    };
+    use read_fonts::scaler_test::Path;
+
+    /// A long-lived structure that holds a font without borrowing.
+    struct CachedFont {
+        font: OwnedFont,
+        context: Context,
+    }
+
+    impl CachedFont {
+        fn glyph(&mut self, ch: char) -> Option<(f32, Path)> {
+            let font = self.font.as_font_ref();
+            let gid = font.charmap().map(ch)?;
+            let advance = font
+                .glyph_metrics(Size::new(16.0), LocationRef::default())
+                .advance_width(gid)?;
+            let mut path = Path::default();
+            self.context
+                .new_scaler()
+                .size(Size::new(16.0))
+                .cache_key(Some(UniqueId::from(&self.font)))
+                .build(&font)
+                .outline(gid, &mut path)
+                .ok()?;
+            Some((advance, path))
+        }
+    }
+
+    #[test]
+    fn owned_font() {
+        let data = font_test_data::VAZIRMATN_VAR.to_vec();
+        let mut cached = CachedFont {
+            font: OwnedFont::new(data, 0).unwrap(),
+            context: Context::new(),
+        };
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let (codepoint, gid) = font.charmap().mappings().last().unwrap();
+        let ch = char::from_u32(codepoint).unwrap();
+        let expected_advance = font
+            .glyph_metrics(Size::new(16.0), LocationRef::default())
+            .advance_width(gid);
+        let mut expected_path = Path::default();
+        Context::new()
+            .new_scaler()
+            .size(Size::new(16.0))
+            .build(&font)
+            .outline(gid, &mut expected_path)
+            .unwrap();
+        let (advance, path) = std::thread::spawn(move || cached.glyph(ch).unwrap())
+            .join()
+            .unwrap();
+        assert_eq!(Some(advance), expected_advance);
+        assert_eq!(path.elements, expected_path.elements);
+        assert!(!path.elements.is_empty());
+    }
+
+    #[test]
+    fn cached_font_ref() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let cached = CachedFontRef::new(font.clone());
+        let mut context = Context::new();
+        // a single context can be reused for many scalers
+        for (_, gid) in cached.charmap().mappings() {
+            let mut path = Path::default();
+            let mut expected_path = Path::default();
+            context
+                .new_scaler()
+                .build(&cached)
+                .outline(gid, &mut path)
+                .unwrap();
+            context
+                .new_scaler()
+                .build(&font)
+                .outline(gid, &mut expected_path)
+                .unwrap();
+            assert_eq!(path.elements, expected_path.elements);
+        }
+    }
+}
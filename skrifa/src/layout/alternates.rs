@@ -0,0 +1,206 @@
+//! Enumeration of alternate glyphs provided by substitution features.
+
+use read_fonts::{
+    tables::{
+        gsub::{
+            AlternateSubstFormat1, ExtensionSubtable, SingleSubst, SubstitutionLookup,
+            SubstitutionLookupList,
+        },
+        layout::CoverageTable,
+    },
+    types::{GlyphId, Tag},
+};
+
+use super::{Feature, LayoutTable};
+
+/// Alternate glyph that is reachable from a source glyph by applying a
+/// substitution feature.
+#[derive(Clone)]
+pub struct GlyphAlternate<'a> {
+    glyph_id: GlyphId,
+    feature: Feature<'a>,
+}
+
+impl<'a> GlyphAlternate<'a> {
+    /// Returns the identifier of the alternate glyph.
+    pub fn glyph_id(&self) -> GlyphId {
+        self.glyph_id
+    }
+
+    /// Returns the tag of the feature that provides the alternate.
+    pub fn tag(&self) -> Tag {
+        self.feature.tag()
+    }
+
+    /// Returns the feature that provides the alternate.
+    ///
+    /// This can be used to retrieve the user interface name for
+    /// stylistic sets and character variants.
+    pub fn feature(&self) -> &Feature<'a> {
+        &self.feature
+    }
+}
+
+/// Collects the alternates for `glyph_id` from single and alternate
+/// substitution lookups referenced by the features in `table`.
+pub(super) fn collect<'a>(
+    table: &LayoutTable<'a>,
+    lookups: &SubstitutionLookupList<'a>,
+    glyph_id: GlyphId,
+    feature_tag: Option<Tag>,
+) -> Vec<GlyphAlternate<'a>> {
+    let mut alternates: Vec<GlyphAlternate<'a>> = vec![];
+    for feature in table.features() {
+        if feature_tag.map(|tag| tag != feature.tag()).unwrap_or(false) {
+            continue;
+        }
+        for lookup_index in feature.lookup_indices() {
+            let Ok(lookup) = lookups.lookups().get(lookup_index as usize) else {
+                continue;
+            };
+            let mut push = |alternate: GlyphId| {
+                // fonts often reference the same glyphs from multiple
+                // lookups, so only report unique pairs
+                if alternate != glyph_id
+                    && !alternates
+                        .iter()
+                        .any(|alt| alt.glyph_id == alternate && alt.tag() == feature.tag())
+                {
+                    alternates.push(GlyphAlternate {
+                        glyph_id: alternate,
+                        feature: feature.clone(),
+                    });
+                }
+            };
+            visit_lookup(&lookup, glyph_id, &mut push);
+        }
+    }
+    alternates
+}
+
+fn visit_lookup(lookup: &SubstitutionLookup, glyph_id: GlyphId, f: &mut impl FnMut(GlyphId)) {
+    match lookup {
+        SubstitutionLookup::Single(lookup) => {
+            for subtable in lookup.subtables().iter().flatten() {
+                visit_single(&subtable, glyph_id, f);
+            }
+        }
+        SubstitutionLookup::Alternate(lookup) => {
+            for subtable in lookup.subtables().iter().flatten() {
+                visit_alternate(&subtable, glyph_id, f);
+            }
+        }
+        SubstitutionLookup::Extension(lookup) => {
+            for subtable in lookup.subtables().iter().flatten() {
+                match subtable {
+                    ExtensionSubtable::Single(ext) => {
+                        if let Ok(subtable) = ext.extension() {
+                            visit_single(&subtable, glyph_id, f);
+                        }
+                    }
+                    ExtensionSubtable::Alternate(ext) => {
+                        if let Ok(subtable) = ext.extension() {
+                            visit_alternate(&subtable, glyph_id, f);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn visit_single(subtable: &SingleSubst, glyph_id: GlyphId, f: &mut impl FnMut(GlyphId)) {
+    match subtable {
+        SingleSubst::Format1(subtable) => {
+            if coverage_index(subtable.coverage(), glyph_id).is_some() {
+                let delta = subtable.delta_glyph_id() as i32;
+                // the addition is performed modulo 65536
+                let alternate = (glyph_id.to_u16() as i32 + delta) as u16;
+                f(GlyphId::new(alternate));
+            }
+        }
+        SingleSubst::Format2(subtable) => {
+            if let Some(alternate) = coverage_index(subtable.coverage(), glyph_id)
+                .and_then(|ix| subtable.substitute_glyph_ids().get(ix))
+            {
+                f(alternate.get());
+            }
+        }
+    }
+}
+
+fn visit_alternate(
+    subtable: &AlternateSubstFormat1,
+    glyph_id: GlyphId,
+    f: &mut impl FnMut(GlyphId),
+) {
+    let Some(ix) = coverage_index(subtable.coverage(), glyph_id) else {
+        return;
+    };
+    if let Ok(set) = subtable.alternate_sets().get(ix) {
+        for alternate in set.alternate_glyph_ids() {
+            f(alternate.get());
+        }
+    }
+}
+
+fn coverage_index(
+    coverage: Result<CoverageTable, read_fonts::ReadError>,
+    glyph_id: GlyphId,
+) -> Option<usize> {
+    coverage.ok()?.get(glyph_id).map(usize::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::make_font;
+    use crate::{instance::LocationRef, MetadataProvider as _};
+    use read_fonts::{
+        types::{GlyphId, Tag},
+        FontRef,
+    };
+
+    #[test]
+    fn all_alternates() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let layout = font.layout_info(LocationRef::default());
+        let alternates = layout
+            .glyph_alternates(GlyphId::new(1), None)
+            .iter()
+            .map(|alt| (alt.tag(), alt.glyph_id().to_u16()))
+            .collect::<Vec<_>>();
+        let cv01 = Tag::new(b"cv01");
+        let locl = Tag::new(b"locl");
+        let ss03 = Tag::new(b"ss03");
+        assert_eq!(
+            alternates,
+            [(cv01, 3), (cv01, 4), (locl, 2), (ss03, 5), (ss03, 6)]
+        );
+    }
+
+    #[test]
+    fn alternates_for_feature() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let layout = font.layout_info(LocationRef::default());
+        let alternates = layout.glyph_alternates(GlyphId::new(1), Some(Tag::new(b"ss03")));
+        assert_eq!(
+            alternates
+                .iter()
+                .map(|alt| alt.glyph_id().to_u16())
+                .collect::<Vec<_>>(),
+            [5, 6]
+        );
+        let name = alternates[0]
+            .feature()
+            .ui_name()
+            .and_then(|names| names.english_or_first())
+            .map(|name| name.to_string());
+        assert_eq!(name.as_deref(), Some("Single-storey a"));
+        // no alternates for uncovered glyphs
+        assert!(layout.glyph_alternates(GlyphId::new(2), None).is_empty());
+    }
+}
@@ -0,0 +1,433 @@
+//! Flattened kerning pairs from pair adjustment lookups.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use read_fonts::{
+    tables::{
+        gpos::{
+            DeviceOrVariationIndex, ExtensionSubtable, PairPos, PairPosFormat1, PairPosFormat2,
+            PositionLookup, PositionLookupList, ValueRecord,
+        },
+        variations::DeltaSetIndex,
+    },
+    types::{GlyphId, Tag},
+    FontData,
+};
+
+use super::LayoutInfo;
+
+/// Horizontal adjustment applied between a pair of glyphs.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct KerningPair {
+    left: GlyphId,
+    right: GlyphId,
+    adjustment: f32,
+}
+
+impl KerningPair {
+    /// Returns the identifier of the first glyph in the pair.
+    pub fn left(&self) -> GlyphId {
+        self.left
+    }
+
+    /// Returns the identifier of the second glyph in the pair.
+    pub fn right(&self) -> GlyphId {
+        self.right
+    }
+
+    /// Returns the adjustment to the advance of the first glyph in font
+    /// units, including any variation deltas.
+    pub fn adjustment(&self) -> f32 {
+        self.adjustment
+    }
+}
+
+/// Collects the effective kerning pairs from the pair adjustment lookups
+/// referenced by the features in the `GPOS` table.
+pub(super) fn collect(
+    info: &LayoutInfo,
+    lookups: &PositionLookupList,
+    feature_tag: Option<Tag>,
+) -> Vec<KerningPair> {
+    let Some(gpos) = info.gpos.as_ref() else {
+        return vec![];
+    };
+    // lookups are applied in lookup list order, regardless of the order in
+    // which they are referenced by features
+    let lookup_indices = gpos
+        .features()
+        .filter(|feature| feature_tag.map(|tag| tag == feature.tag()).unwrap_or(true))
+        .flat_map(|feature| feature.lookup_indices())
+        .collect::<BTreeSet<_>>();
+    let mut pairs: BTreeMap<(GlyphId, GlyphId), f32> = BTreeMap::new();
+    for lookup_index in lookup_indices {
+        let Ok(lookup) = lookups.lookups().get(lookup_index as usize) else {
+            continue;
+        };
+        let mut collector = LookupPairs {
+            info,
+            pairs: HashMap::new(),
+            claimed: HashSet::new(),
+        };
+        match lookup {
+            PositionLookup::Pair(lookup) => {
+                for subtable in lookup.subtables().iter().flatten() {
+                    collector.subtable(&subtable);
+                }
+            }
+            PositionLookup::Extension(lookup) => {
+                for subtable in lookup.subtables().iter().flatten() {
+                    if let ExtensionSubtable::Pair(ext) = subtable {
+                        if let Ok(subtable) = ext.extension() {
+                            collector.subtable(&subtable);
+                        }
+                    }
+                }
+            }
+            _ => continue,
+        }
+        // adjustments from separate lookups accumulate
+        for (pair, adjustment) in collector.pairs {
+            *pairs.entry(pair).or_default() += adjustment;
+        }
+    }
+    pairs
+        .into_iter()
+        .filter(|(_, adjustment)| *adjustment != 0.0)
+        .map(|((left, right), adjustment)| KerningPair {
+            left,
+            right,
+            adjustment,
+        })
+        .collect()
+}
+
+/// Pairs collected from the subtables of a single lookup.
+///
+/// Only the first subtable that applies to a pair of glyphs is used, so
+/// pairs from earlier subtables take precedence.
+struct LookupPairs<'a, 'b> {
+    info: &'b LayoutInfo<'a>,
+    pairs: HashMap<(GlyphId, GlyphId), f32>,
+    /// First glyphs covered by a class based subtable. These match any
+    /// second glyph so later subtables can never apply.
+    claimed: HashSet<GlyphId>,
+}
+
+impl LookupPairs<'_, '_> {
+    fn subtable(&mut self, subtable: &PairPos) {
+        match subtable {
+            PairPos::Format1(subtable) => self.format1(subtable),
+            PairPos::Format2(subtable) => self.format2(subtable),
+        }
+    }
+
+    fn format1(&mut self, subtable: &PairPosFormat1) {
+        let Ok(coverage) = subtable.coverage() else {
+            return;
+        };
+        let pair_sets = subtable.pair_sets();
+        for (ix, left) in coverage.iter().enumerate() {
+            if self.claimed.contains(&left) {
+                continue;
+            }
+            let Ok(pair_set) = pair_sets.get(ix) else {
+                continue;
+            };
+            for record in pair_set.pair_value_records().iter().flatten() {
+                // device offsets are relative to the pair set
+                let adjustment = self.x_advance(pair_set.offset_data(), record.value_record1());
+                self.pairs
+                    .entry((left, record.second_glyph()))
+                    .or_insert(adjustment);
+            }
+        }
+    }
+
+    fn format2(&mut self, subtable: &PairPosFormat2) {
+        let (Ok(coverage), Ok(class_def1), Ok(class_def2)) = (
+            subtable.coverage(),
+            subtable.class_def1(),
+            subtable.class_def2(),
+        ) else {
+            return;
+        };
+        let class1_count = subtable.class1_count();
+        let class2_count = subtable.class2_count();
+        if class2_count == 0 {
+            return;
+        }
+        let mut classes2: Vec<Vec<GlyphId>> = vec![vec![]; class2_count as usize];
+        let mut assigned = HashSet::new();
+        for (glyph_id, class) in class_def2.iter() {
+            if class == 0 {
+                continue;
+            }
+            // glyphs with a class beyond the count are never kerned
+            if class < class2_count {
+                classes2[class as usize].push(glyph_id);
+            }
+            assigned.insert(glyph_id);
+        }
+        // class 0 of the second class definition contains all glyphs not
+        // otherwise assigned a class
+        classes2[0] = (0..self.info.glyph_count)
+            .map(GlyphId::new)
+            .filter(|glyph_id| !assigned.contains(glyph_id))
+            .collect();
+        let class1_records = subtable.class1_records();
+        for left in coverage.iter() {
+            if self.claimed.contains(&left) {
+                continue;
+            }
+            let class1 = class_def1.get(left);
+            if class1 >= class1_count {
+                continue;
+            }
+            let Ok(class1_record) = class1_records.get(class1 as usize) else {
+                continue;
+            };
+            let class2_records = class1_record.class2_records();
+            for (class2, rights) in classes2.iter().enumerate() {
+                let Ok(class2_record) = class2_records.get(class2) else {
+                    continue;
+                };
+                let adjustment =
+                    self.x_advance(subtable.offset_data(), class2_record.value_record1());
+                // the first glyph is claimed below so zero adjustments need
+                // not be recorded
+                if adjustment == 0.0 {
+                    continue;
+                }
+                for right in rights {
+                    self.pairs.entry((left, *right)).or_insert(adjustment);
+                }
+            }
+            self.claimed.insert(left);
+        }
+    }
+
+    /// Returns the advance adjustment from a value record, including
+    /// variation deltas at the location of the layout information.
+    fn x_advance(&self, offset_data: FontData, record: &ValueRecord) -> f32 {
+        let value = record.x_advance().unwrap_or(0) as f32;
+        let info = self.info;
+        if info.coords.is_empty() {
+            return value;
+        }
+        match (
+            record.x_advance_device(offset_data),
+            info.var_store.as_ref(),
+        ) {
+            (Some(Ok(DeviceOrVariationIndex::VariationIndex(index))), Some(store)) => {
+                let delta = store
+                    .compute_delta(
+                        DeltaSetIndex {
+                            outer: index.delta_set_outer_index(),
+                            inner: index.delta_set_inner_index(),
+                        },
+                        info.coords,
+                    )
+                    .unwrap_or_default();
+                value + delta as f32
+            }
+            _ => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        instance::{LocationRef, NormalizedCoord},
+        MetadataProvider as _,
+    };
+    use read_fonts::{
+        types::{GlyphId, Tag},
+        FontRef,
+    };
+    use write_fonts::{
+        tables::{
+            gdef::Gdef,
+            gpos::{
+                Class1Record, Class2Record, Gpos, PairPos, PairSet, PairValueRecord,
+                PositionLookup, ValueRecord,
+            },
+            layout::{
+                ClassDef, CoverageTable, Feature, FeatureList, FeatureRecord, LangSys, Lookup,
+                LookupFlag, LookupList, Script, ScriptList, ScriptRecord, VariationIndex,
+            },
+            maxp::Maxp,
+            variations::{
+                ItemVariationData, ItemVariationStore, RegionAxisCoordinates, VariationRegion,
+                VariationRegionList,
+            },
+        },
+        types::F2Dot14,
+        FontBuilder,
+    };
+
+    #[test]
+    fn kern_feature_pairs() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let layout = font.layout_info(LocationRef::default());
+        let pairs = pairs(&layout, Some(Tag::new(b"kern")));
+        assert_eq!(
+            pairs,
+            [
+                (1, 0, -7.0),
+                (1, 1, -7.0),
+                (1, 2, -7.0),
+                (1, 3, -55.0),
+                (1, 5, -10.0),
+                (2, 0, -7.0),
+                (2, 1, -7.0),
+                (2, 2, -7.0),
+                (2, 3, -30.0),
+                (2, 4, -30.0),
+                (2, 5, -10.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn all_feature_pairs() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let layout = font.layout_info(LocationRef::default());
+        let pairs = pairs(&layout, None);
+        assert_eq!(pairs.len(), 12);
+        assert_eq!(pairs.last(), Some(&(5, 1, -15.0)));
+    }
+
+    #[test]
+    fn variable_pairs() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let coords = [NormalizedCoord::from_f32(1.0)];
+        let layout = font.layout_info(LocationRef::new(&coords));
+        let pairs = pairs(&layout, Some(Tag::new(b"kern")));
+        assert_eq!(pairs[3], (1, 3, -75.0));
+    }
+
+    fn pairs(layout: &super::LayoutInfo, feature_tag: Option<Tag>) -> Vec<(u16, u16, f32)> {
+        layout
+            .kerning_pairs(feature_tag)
+            .iter()
+            .map(|pair| {
+                (
+                    pair.left().to_u16(),
+                    pair.right().to_u16(),
+                    pair.adjustment(),
+                )
+            })
+            .collect()
+    }
+
+    /// Builds a font with a GPOS table containing the following features:
+    ///
+    /// * `kern`: two lookups. The first contains a glyph pair subtable that
+    ///   kerns `1 3` by -50 units (with an additional -20 units at the
+    ///   maximum of the first axis) and `1 4` by zero, followed by a class
+    ///   subtable covering glyphs 1 and 2, then a glyph pair subtable for
+    ///   `2 6` that is never reached. The class subtable kerns glyphs 1 and 2 by
+    ///   -7 units against the unclassified glyphs 0, 1 and 2, but not against
+    ///   glyph 6, which has a class beyond the class count. The second lookup
+    ///   kerns `1 3` by -5.
+    /// * `dist`: kerns `5 1` by -15 units
+    fn make_font() -> Vec<u8> {
+        let glyph = GlyphId::new;
+        let coverage =
+            |glyphs: &[u16]| CoverageTable::format_1(glyphs.iter().copied().map(glyph).collect());
+        let x_advance = |value| ValueRecord::new().with_x_advance(value);
+        let pair_lookup =
+            |subtables| PositionLookup::Pair(Lookup::new(LookupFlag::empty(), subtables, 0));
+        let glyph_pairs = |left: u16, pairs: Vec<(u16, ValueRecord)>| {
+            PairPos::format_1(
+                coverage(&[left]),
+                vec![PairSet::new(
+                    pairs
+                        .into_iter()
+                        .map(|(right, record)| {
+                            PairValueRecord::new(glyph(right), record, ValueRecord::new())
+                        })
+                        .collect(),
+                )],
+            )
+        };
+        let class_pairs = PairPos::format_2(
+            coverage(&[1, 2]),
+            ClassDef::format_1(glyph(1), vec![1, 1]),
+            ClassDef::format_1(glyph(3), vec![1, 1, 2, 3]),
+            vec![
+                Class1Record::new(vec![Class2Record::new(x_advance(0), ValueRecord::new()); 3]),
+                Class1Record::new(vec![
+                    Class2Record::new(x_advance(-7), ValueRecord::new()),
+                    Class2Record::new(x_advance(-30), ValueRecord::new()),
+                    Class2Record::new(x_advance(-10), ValueRecord::new()),
+                ]),
+            ],
+        );
+        let gpos = Gpos::new(
+            ScriptList::new(vec![ScriptRecord::new(
+                Tag::new(b"DFLT"),
+                Script::new(Some(LangSys::new(vec![0, 1])), vec![]),
+            )]),
+            FeatureList::new(vec![
+                FeatureRecord::new(Tag::new(b"dist"), Feature::new(None, vec![2])),
+                FeatureRecord::new(Tag::new(b"kern"), Feature::new(None, vec![1, 0])),
+            ]),
+            LookupList::new(vec![
+                pair_lookup(vec![
+                    glyph_pairs(
+                        1,
+                        vec![
+                            (
+                                3,
+                                x_advance(-50).with_x_advance_device(VariationIndex::new(0, 0)),
+                            ),
+                            (
+                                4,
+                                x_advance(0).with_x_advance_device(VariationIndex::new(0, 0)),
+                            ),
+                        ],
+                    ),
+                    class_pairs,
+                    glyph_pairs(2, vec![(6, x_advance(-99))]),
+                ]),
+                pair_lookup(vec![glyph_pairs(1, vec![(3, x_advance(-5))])]),
+                pair_lookup(vec![glyph_pairs(5, vec![(1, x_advance(-15))])]),
+            ]),
+        );
+        let mut gdef = Gdef::new(None, None, None, None);
+        let one = F2Dot14::from_f32(1.0);
+        gdef.item_var_store = Some(ItemVariationStore::new(
+            VariationRegionList::new(vec![VariationRegion::new(vec![
+                RegionAxisCoordinates::new(F2Dot14::ZERO, one, one),
+            ])]),
+            vec![Some(ItemVariationData::new(
+                1,
+                0,
+                vec![0],
+                vec![-20i8 as u8],
+            ))],
+        ))
+        .into();
+        // the class count is derived from the class definition when
+        // compiling, so reduce it to exclude class 3
+        let mut gpos_data = write_fonts::dump_table(&gpos).unwrap();
+        let pos = gpos_data
+            .windows(16)
+            .position(|w| w[..2] == [0, 2] && w[4..8] == [0, 4, 0, 0] && w[12..] == [0, 2, 0, 4])
+            .unwrap();
+        gpos_data[pos + 15] = 3;
+        FontBuilder::new()
+            .add_table(&Maxp::new(7))
+            .unwrap()
+            .add_table(&gdef)
+            .unwrap()
+            .add_raw(Tag::new(b"GPOS"), gpos_data)
+            .build()
+    }
+}
@@ -0,0 +1,764 @@
+//! Scripts, languages and features defined by OpenType layout tables.
+//!
+//! This provides higher level interfaces for discovering the layout
+//! capabilities of a font as described by the
+//! [script](https://learn.microsoft.com/en-us/typography/opentype/spec/chapter2#script-list-table-and-script-record),
+//! [language system](https://learn.microsoft.com/en-us/typography/opentype/spec/chapter2#language-system-table)
+//! and [feature](https://learn.microsoft.com/en-us/typography/opentype/spec/chapter2#feature-list-table)
+//! lists of the `GSUB` and `GPOS` tables.
+//!
+//! # Example
+//! The following function will print the user interface names of all
+//! stylistic sets and character variants in a font:
+//! ```
+//! use skrifa::{instance::LocationRef, MetadataProvider};
+//!
+//! fn print_feature_names<'a>(font: &impl MetadataProvider<'a>) {
+//!     let layout = font.layout_info(LocationRef::default());
+//!     for feature in layout.gsub().iter().flat_map(|gsub| gsub.features()) {
+//!         if let Some(name) = feature.ui_name().and_then(|names| names.english_or_first()) {
+//!             println!("{}: {}", feature.tag(), name);
+//!         }
+//!     }
+//! }
+//! ```
+
+use read_fonts::{
+    tables::{
+        gpos::{Gpos, PositionLookupList},
+        gsub::{Gsub, SubstitutionLookupList},
+        layout::{self, FeatureList, FeatureParams, FeatureTableSubstitution, ScriptList},
+        name::Name,
+        variations::ItemVariationStore,
+    },
+    types::{GlyphId, Tag, Uint24},
+    ReadError, TableProvider, TopLevelTable,
+};
+
+mod alternates;
+mod kerning;
+
+pub use alternates::GlyphAlternate;
+pub use kerning::KerningPair;
+
+use crate::{
+    instance::{LocationRef, NormalizedCoord},
+    string::{LocalizedStrings, StringId},
+};
+
+/// Value of a feature index that signals the absence of a required feature.
+const NO_REQUIRED_FEATURE: u16 = 0xFFFF;
+
+/// Script, language and feature information for a font.
+///
+/// This provides access to the [`LayoutTable`]s for glyph substitution
+/// (`GSUB`) and positioning (`GPOS`).
+///
+/// Features are resolved for a specific location in variation space. For
+/// variable fonts, feature tables replaced by the
+/// [FeatureVariations](https://learn.microsoft.com/en-us/typography/opentype/spec/chapter2#featurevariations-table)
+/// table at that location are substituted transparently.
+#[derive(Clone, Default)]
+pub struct LayoutInfo<'a> {
+    gsub: Option<LayoutTable<'a>>,
+    gpos: Option<LayoutTable<'a>>,
+    gsub_lookups: Option<SubstitutionLookupList<'a>>,
+    gpos_lookups: Option<PositionLookupList<'a>>,
+    var_store: Option<ItemVariationStore<'a>>,
+    coords: &'a [NormalizedCoord],
+    glyph_count: u16,
+}
+
+impl<'a> LayoutInfo<'a> {
+    /// Creates new layout information for the given font and location in
+    /// normalized variation space.
+    pub fn new(font: &impl TableProvider<'a>, location: impl Into<LocationRef<'a>>) -> Self {
+        let coords = location.into().coords();
+        let name = font.name().ok();
+        let gsub_table = font.gsub().ok();
+        let gsub_lookups = gsub_table.as_ref().and_then(|gsub| gsub.lookup_list().ok());
+        let gsub = gsub_table.map(|gsub| {
+            LayoutTable::new(
+                Gsub::TAG,
+                gsub.script_list().ok(),
+                gsub.feature_list().ok(),
+                gsub.feature_variations().and_then(|fv| fv.ok()),
+                coords,
+                name.clone(),
+            )
+        });
+        let gpos_table = font.gpos().ok();
+        let gpos_lookups = gpos_table.as_ref().and_then(|gpos| gpos.lookup_list().ok());
+        let gpos = gpos_table.map(|gpos| {
+            LayoutTable::new(
+                Gpos::TAG,
+                gpos.script_list().ok(),
+                gpos.feature_list().ok(),
+                gpos.feature_variations().and_then(|fv| fv.ok()),
+                coords,
+                name.clone(),
+            )
+        });
+        let var_store = font
+            .gdef()
+            .ok()
+            .and_then(|gdef| gdef.item_var_store())
+            .and_then(|store| store.ok());
+        let glyph_count = font.maxp().map(|maxp| maxp.num_glyphs()).unwrap_or(0);
+        Self {
+            gsub,
+            gpos,
+            gsub_lookups,
+            gpos_lookups,
+            var_store,
+            coords,
+            glyph_count,
+        }
+    }
+
+    /// Returns the layout information for the glyph substitution table.
+    pub fn gsub(&self) -> Option<&LayoutTable<'a>> {
+        self.gsub.as_ref()
+    }
+
+    /// Returns the layout information for the glyph positioning table.
+    pub fn gpos(&self) -> Option<&LayoutTable<'a>> {
+        self.gpos.as_ref()
+    }
+
+    /// Returns the alternates for the given glyph that are provided by
+    /// single and alternate substitution lookups.
+    ///
+    /// If `feature_tag` is `Some`, only features with that tag are
+    /// considered. Otherwise, alternates from all features are returned,
+    /// ordered by feature and then by lookup.
+    ///
+    /// This is intended for populating glyph pickers in user interfaces and
+    /// only considers direct substitutions of the source glyph; alternates
+    /// that depend on context or on the application of other features are
+    /// not reported.
+    pub fn glyph_alternates(
+        &self,
+        glyph_id: GlyphId,
+        feature_tag: Option<Tag>,
+    ) -> Vec<GlyphAlternate<'a>> {
+        match (&self.gsub, &self.gsub_lookups) {
+            (Some(gsub), Some(lookups)) => {
+                alternates::collect(gsub, lookups, glyph_id, feature_tag)
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Returns the effective kerning pairs defined by pair adjustment
+    /// lookups, sorted by the first and then the second glyph.
+    ///
+    /// If `feature_tag` is `Some`, only lookups referenced by features with
+    /// that tag (typically `kern`) are considered. Otherwise, lookups from
+    /// all features are used.
+    ///
+    /// Class based subtables are expanded to individual glyph pairs. Within
+    /// a lookup, only the first subtable that applies to a pair contributes
+    /// and adjustments from separate lookups are summed. Adjustments include
+    /// variation deltas at the location of the layout information and pairs
+    /// with a resulting adjustment of zero are omitted.
+    ///
+    /// This is intended for exporting kerning to legacy formats and only
+    /// reports adjustments to the advance of the first glyph. Lookup flags
+    /// are ignored. Class 0 of the second class definition in a class based
+    /// subtable is expanded to every glyph in the font that is not assigned
+    /// another class, so a nonzero adjustment for that class can produce a
+    /// large number of pairs.
+    pub fn kerning_pairs(&self, feature_tag: Option<Tag>) -> Vec<KerningPair> {
+        match &self.gpos_lookups {
+            Some(lookups) => kerning::collect(self, lookups, feature_tag),
+            _ => vec![],
+        }
+    }
+}
+
+/// Scripts, languages and features defined by a single layout table.
+#[derive(Clone)]
+pub struct LayoutTable<'a> {
+    tag: Tag,
+    script_list: Option<ScriptList<'a>>,
+    feature_list: Option<FeatureList<'a>>,
+    substitutions: Option<FeatureTableSubstitution<'a>>,
+    name: Option<Name<'a>>,
+}
+
+impl<'a> LayoutTable<'a> {
+    fn new(
+        tag: Tag,
+        script_list: Option<ScriptList<'a>>,
+        feature_list: Option<FeatureList<'a>>,
+        feature_variations: Option<layout::FeatureVariations<'a>>,
+        coords: &[crate::instance::NormalizedCoord],
+        name: Option<Name<'a>>,
+    ) -> Self {
+        // Conditions may match the default location, so this applies even
+        // when no coordinates are given.
+        let substitutions = feature_variations.and_then(|fv| fv.find_substitutions(coords)?.ok());
+        Self {
+            tag,
+            script_list,
+            feature_list,
+            substitutions,
+            name,
+        }
+    }
+
+    /// Returns the tag of the underlying table, either `GSUB` or `GPOS`.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Returns the number of scripts defined by the table.
+    pub fn script_count(&self) -> usize {
+        self.script_list
+            .as_ref()
+            .map(|list| list.script_records().len())
+            .unwrap_or(0)
+    }
+
+    /// Returns an iterator over the scripts defined by the table, in the
+    /// order they appear in the script list.
+    pub fn scripts(&self) -> impl Iterator<Item = Script<'a>> + 'a + Clone {
+        let copy = self.clone();
+        (0..self.script_count()).filter_map(move |ix| copy.script_at(ix))
+    }
+
+    /// Returns the script with the given tag.
+    pub fn script(&self, tag: Tag) -> Option<Script<'a>> {
+        let records = self.script_list.as_ref()?.script_records();
+        let ix = records.iter().position(|rec| rec.script_tag() == tag)?;
+        self.script_at(ix)
+    }
+
+    /// Returns the number of features defined by the table.
+    pub fn feature_count(&self) -> usize {
+        self.feature_list
+            .as_ref()
+            .map(|list| list.feature_records().len())
+            .unwrap_or(0)
+    }
+
+    /// Returns an iterator over all features defined by the table, in the
+    /// order they appear in the feature list.
+    pub fn features(&self) -> impl Iterator<Item = Feature<'a>> + 'a + Clone {
+        let copy = self.clone();
+        (0..self.feature_count()).filter_map(move |ix| copy.feature(ix as u16))
+    }
+
+    /// Returns the feature at the given index in the feature list.
+    ///
+    /// If the feature table was replaced by a feature variation at the
+    /// location used to construct the layout information, the alternate
+    /// table is returned.
+    pub fn feature(&self, index: u16) -> Option<Feature<'a>> {
+        let list = self.feature_list.as_ref()?;
+        let record = list.feature_records().get(index as usize)?;
+        let tag = record.feature_tag();
+        let (feature, is_substituted) = match self
+            .substitutions
+            .as_ref()
+            .and_then(|subs| subs.find_alternate(index, tag))
+        {
+            Some(alternate) => (alternate.ok()?, true),
+            None => (record.feature(list.offset_data()).ok()?, false),
+        };
+        Some(Feature {
+            index,
+            tag,
+            feature,
+            is_substituted,
+            name: self.name.clone(),
+        })
+    }
+
+    fn script_at(&self, index: usize) -> Option<Script<'a>> {
+        let list = self.script_list.as_ref()?;
+        let record = list.script_records().get(index)?;
+        let script = record.script(list.offset_data()).ok()?;
+        Some(Script {
+            tag: record.script_tag(),
+            script,
+            table: self.clone(),
+        })
+    }
+}
+
+/// Script defined in a layout table.
+#[derive(Clone)]
+pub struct Script<'a> {
+    tag: Tag,
+    script: layout::Script<'a>,
+    table: LayoutTable<'a>,
+}
+
+impl<'a> Script<'a> {
+    /// Returns the tag that identifies the script.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Returns the default language system for the script.
+    pub fn default_language(&self) -> Option<Language<'a>> {
+        let lang_sys = self.script.default_lang_sys()?.ok()?;
+        Some(Language {
+            tag: None,
+            lang_sys,
+            table: self.table.clone(),
+        })
+    }
+
+    /// Returns the number of language systems defined for the script,
+    /// excluding the default.
+    pub fn language_count(&self) -> usize {
+        self.script.lang_sys_records().len()
+    }
+
+    /// Returns an iterator over the language systems defined for the script,
+    /// excluding the default.
+    pub fn languages(&self) -> impl Iterator<Item = Language<'a>> + 'a + Clone {
+        let copy = self.clone();
+        (0..self.language_count()).filter_map(move |ix| copy.language_at(ix))
+    }
+
+    /// Returns the language system with the given tag.
+    pub fn language(&self, tag: Tag) -> Option<Language<'a>> {
+        let ix = self
+            .script
+            .lang_sys_records()
+            .iter()
+            .position(|rec| rec.lang_sys_tag() == tag)?;
+        self.language_at(ix)
+    }
+
+    fn language_at(&self, index: usize) -> Option<Language<'a>> {
+        let record = self.script.lang_sys_records().get(index)?;
+        let lang_sys = record.lang_sys(self.script.offset_data()).ok()?;
+        Some(Language {
+            tag: Some(record.lang_sys_tag()),
+            lang_sys,
+            table: self.table.clone(),
+        })
+    }
+}
+
+/// Language system defined for a script.
+#[derive(Clone)]
+pub struct Language<'a> {
+    tag: Option<Tag>,
+    lang_sys: layout::LangSys<'a>,
+    table: LayoutTable<'a>,
+}
+
+impl<'a> Language<'a> {
+    /// Returns the tag that identifies the language system or `None` if this
+    /// is the default language system for a script.
+    pub fn tag(&self) -> Option<Tag> {
+        self.tag
+    }
+
+    /// Returns true if this is the default language system for a script.
+    pub fn is_default(&self) -> bool {
+        self.tag.is_none()
+    }
+
+    /// Returns the feature that is required for this language system.
+    pub fn required_feature(&self) -> Option<Feature<'a>> {
+        match self.lang_sys.required_feature_index() {
+            NO_REQUIRED_FEATURE => None,
+            index => self.table.feature(index),
+        }
+    }
+
+    /// Returns an iterator over the features enabled for this language
+    /// system, excluding the required feature.
+    pub fn features(&self) -> impl Iterator<Item = Feature<'a>> + 'a + Clone {
+        let table = self.table.clone();
+        self.lang_sys
+            .feature_indices()
+            .iter()
+            .filter_map(move |index| table.feature(index.get()))
+    }
+}
+
+/// Feature defined in a layout table.
+///
+/// In addition to the set of lookups that implement the feature, this
+/// exposes the user interface strings that may be provided by
+/// [stylistic set](https://learn.microsoft.com/en-us/typography/opentype/spec/features_pt#ss01---ss20)
+/// and [character variant](https://learn.microsoft.com/en-us/typography/opentype/spec/features_ae#cv01-cv99)
+/// features.
+#[derive(Clone)]
+pub struct Feature<'a> {
+    index: u16,
+    tag: Tag,
+    feature: layout::Feature<'a>,
+    is_substituted: bool,
+    name: Option<Name<'a>>,
+}
+
+impl<'a> Feature<'a> {
+    /// Returns the index of the feature in the feature list.
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the tag that identifies the feature.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Returns true if the feature table was replaced by a feature variation
+    /// at the current location.
+    pub fn is_substituted(&self) -> bool {
+        self.is_substituted
+    }
+
+    /// Returns an iterator over the indices of the lookups that implement the
+    /// feature, in the order they appear in the feature table.
+    pub fn lookup_indices(&self) -> impl Iterator<Item = u16> + 'a + Clone {
+        self.feature
+            .lookup_list_indices()
+            .iter()
+            .map(|index| index.get())
+    }
+
+    /// Returns the raw feature parameters table, if present.
+    pub fn params(&self) -> Option<Result<FeatureParams<'a>, ReadError>> {
+        self.feature.feature_params()
+    }
+
+    /// Returns the string identifier of the user interface name for a
+    /// stylistic set or character variant feature.
+    pub fn ui_name_id(&self) -> Option<StringId> {
+        let id = match self.params()?.ok()? {
+            FeatureParams::StylisticSet(params) => params.ui_name_id(),
+            FeatureParams::CharacterVariant(params) => params.feat_ui_label_name_id(),
+            FeatureParams::Size(_) => return None,
+        };
+        non_null(id)
+    }
+
+    /// Returns the localized user interface names for a stylistic set or
+    /// character variant feature.
+    ///
+    /// For example, "Single-storey a" for a stylistic set that provides
+    /// alternate forms of the letter 'a'.
+    pub fn ui_name(&self) -> Option<LocalizedStrings<'a>> {
+        self.ui_name_id().map(|id| self.strings(id))
+    }
+
+    /// Returns the localized tooltip text for a character variant feature.
+    pub fn tooltip(&self) -> Option<LocalizedStrings<'a>> {
+        let id = self
+            .character_variant_params()?
+            .feat_ui_tooltip_text_name_id();
+        non_null(id).map(|id| self.strings(id))
+    }
+
+    /// Returns the localized sample text that illustrates the effect of a
+    /// character variant feature.
+    pub fn sample_text(&self) -> Option<LocalizedStrings<'a>> {
+        let id = self.character_variant_params()?.sample_text_name_id();
+        non_null(id).map(|id| self.strings(id))
+    }
+
+    /// Returns an iterator over the localized user interface names for each
+    /// named parameter of a character variant feature.
+    pub fn param_names(&self) -> impl Iterator<Item = LocalizedStrings<'a>> + 'a + Clone {
+        let (first, count) = self
+            .character_variant_params()
+            .and_then(|params| {
+                let first = non_null(params.first_param_ui_label_name_id())?;
+                Some((first.to_u16(), params.num_named_parameters()))
+            })
+            .unwrap_or_default();
+        let name = self.name.clone();
+        (0..count).filter_map(move |i| {
+            let id = StringId::new(first.checked_add(i)?);
+            Some(LocalizedStrings::from_name(name.clone(), id))
+        })
+    }
+
+    /// Returns an iterator over the characters for which a character variant
+    /// feature provides glyph variants.
+    pub fn characters(&self) -> impl Iterator<Item = char> + 'a + Clone {
+        let chars: &'a [read_fonts::types::BigEndian<Uint24>] = self
+            .character_variant_params()
+            .map(|params| params.character())
+            .unwrap_or_default();
+        chars
+            .iter()
+            .filter_map(|c| char::from_u32(c.get().to_u32()))
+    }
+
+    fn character_variant_params(&self) -> Option<layout::CharacterVariantParams<'a>> {
+        match self.params()?.ok()? {
+            FeatureParams::CharacterVariant(params) => Some(params),
+            _ => None,
+        }
+    }
+
+    fn strings(&self, id: StringId) -> LocalizedStrings<'a> {
+        LocalizedStrings::from_name(self.name.clone(), id)
+    }
+}
+
+fn non_null(id: StringId) -> Option<StringId> {
+    (id.to_u16() != 0).then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{instance::NormalizedCoord, MetadataProvider as _};
+    use read_fonts::{types::NameId, FontRef};
+    use write_fonts::{
+        tables::{
+            gsub::{
+                AlternateSet, AlternateSubstFormat1, ExtensionSubstFormat1, ExtensionSubtable,
+                Gsub, SingleSubst, SubstitutionLookup,
+            },
+            layout::{
+                CharacterVariantParams, ConditionFormat1, ConditionSet, CoverageTable,
+                Feature as FeatureTable, FeatureList, FeatureParams as WriteFeatureParams,
+                FeatureRecord, FeatureTableSubstitution, FeatureTableSubstitutionRecord,
+                FeatureVariationRecord, FeatureVariations, LangSys, LangSysRecord, Lookup,
+                LookupFlag, LookupList, Script as ScriptTable, ScriptList, ScriptRecord,
+                StylisticSetParams,
+            },
+            name::{Name, NameRecord},
+        },
+        types::GlyphId,
+        FontBuilder,
+    };
+
+    #[test]
+    fn scripts_and_languages() {
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let layout = font.layout_info(LocationRef::default());
+        assert!(layout.gpos().is_some());
+        let gsub = layout.gsub().unwrap();
+        assert_eq!(gsub.tag(), Tag::new(b"GSUB"));
+        let scripts = gsub.scripts().map(|s| s.tag()).collect::<Vec<_>>();
+        assert_eq!(scripts, [Tag::new(b"DFLT"), Tag::new(b"latn")]);
+        let dflt = gsub.script(Tag::new(b"DFLT")).unwrap();
+        let default_lang = dflt.default_language().unwrap();
+        assert!(default_lang.is_default());
+        assert_eq!(default_lang.features().count(), 0);
+        let latn = gsub.script(Tag::new(b"latn")).unwrap();
+        assert!(latn.default_language().is_none());
+        let languages = latn.languages().map(|l| l.tag()).collect::<Vec<_>>();
+        assert_eq!(languages, [Some(Tag::new(b"TRK "))]);
+        let trk = latn.language(Tag::new(b"TRK ")).unwrap();
+        assert!(trk.required_feature().is_none());
+        let features = trk.features().collect::<Vec<_>>();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].tag(), Tag::new(b"locl"));
+        assert_eq!(features[0].lookup_indices().collect::<Vec<_>>(), [0]);
+        assert!(features[0].ui_name().is_none());
+    }
+
+    #[test]
+    fn feature_ui_names() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let layout = font.layout_info(LocationRef::default());
+        let gsub = layout.gsub().unwrap();
+        let tags = gsub.features().map(|f| f.tag()).collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            [Tag::new(b"cv01"), Tag::new(b"locl"), Tag::new(b"ss03")]
+        );
+        let ss03 = gsub.feature(2).unwrap();
+        assert_eq!(ss03.ui_name_id(), Some(NameId::new(256)));
+        assert_eq!(
+            english_or_first(ss03.ui_name()).as_deref(),
+            Some("Single-storey a")
+        );
+        assert!(ss03.tooltip().is_none());
+        let cv01 = gsub.feature(0).unwrap();
+        assert_eq!(
+            english_or_first(cv01.ui_name()).as_deref(),
+            Some("Alternate a")
+        );
+        assert!(cv01.tooltip().is_none());
+        assert_eq!(english_or_first(cv01.sample_text()).as_deref(), Some("abc"));
+        let params = cv01
+            .param_names()
+            .map(|names| english_or_first(Some(names)).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(params, ["Round", "Flat"]);
+        assert_eq!(cv01.characters().collect::<Vec<_>>(), ['a', 'ɑ']);
+        let dflt = gsub.script(Tag::new(b"DFLT")).unwrap();
+        let lang = dflt.default_language().unwrap();
+        assert_eq!(lang.required_feature().unwrap().tag(), Tag::new(b"locl"));
+        let tags = lang.features().map(|f| f.tag()).collect::<Vec<_>>();
+        assert_eq!(tags, [Tag::new(b"ss03"), Tag::new(b"cv01")]);
+    }
+
+    #[test]
+    fn feature_variations() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let lookups_at = |coord: f32| {
+            let coords = [NormalizedCoord::from_f32(coord)];
+            let layout = font.layout_info(LocationRef::new(&coords));
+            let locl = layout.gsub().unwrap().feature(1).unwrap();
+            (
+                locl.is_substituted(),
+                locl.lookup_indices().collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(lookups_at(0.0), (false, vec![0]));
+        assert_eq!(lookups_at(0.25), (false, vec![0]));
+        assert_eq!(lookups_at(0.5), (true, vec![0, 1]));
+        assert_eq!(lookups_at(1.0), (true, vec![0, 1]));
+    }
+
+    #[test]
+    fn feature_variations_at_default_location() {
+        let font_data = make_font_with_condition(-0.5, 0.5);
+        let font = FontRef::new(&font_data).unwrap();
+        for layout in [
+            font.layout_info(LocationRef::default()),
+            font.layout_info(LocationRef::new(&[NormalizedCoord::from_f32(0.0)])),
+        ] {
+            let locl = layout.gsub().unwrap().feature(1).unwrap();
+            assert!(locl.is_substituted());
+            assert_eq!(locl.lookup_indices().collect::<Vec<_>>(), [0, 1]);
+        }
+    }
+
+    fn english_or_first(strings: Option<LocalizedStrings>) -> Option<String> {
+        Some(strings?.english_or_first()?.to_string())
+    }
+
+    /// Builds a font with a GSUB table containing the following features:
+    ///
+    /// * `cv01`: alternate substitution of glyph 1 to glyphs 3 and 4
+    /// * `locl`: single substitution of glyph 1 to glyph 2, with an additional
+    ///   substitution to glyph 3 when the first axis is in the range 0.5..=1.0
+    /// * `ss03`: single substitution of glyph 1 to glyph 5 (in an extension
+    ///   subtable) and to glyph 6
+    pub(super) fn make_font() -> Vec<u8> {
+        make_font_with_condition(0.5, 1.0)
+    }
+
+    /// Builds the font described in [`make_font`] with the given range for
+    /// the condition on the `locl` substitution.
+    fn make_font_with_condition(min: f32, max: f32) -> Vec<u8> {
+        let cv01_params = CharacterVariantParams::new(
+            NameId::new(257),
+            NameId::new(0),
+            NameId::new(258),
+            2,
+            NameId::new(259),
+            vec![Uint24::new('a' as u32), Uint24::new('ɑ' as u32)],
+        );
+        let features = vec![
+            FeatureRecord::new(
+                Tag::new(b"cv01"),
+                FeatureTable::new(
+                    Some(WriteFeatureParams::CharacterVariant(cv01_params)),
+                    vec![2],
+                ),
+            ),
+            FeatureRecord::new(Tag::new(b"locl"), FeatureTable::new(None, vec![0])),
+            FeatureRecord::new(
+                Tag::new(b"ss03"),
+                FeatureTable::new(
+                    Some(WriteFeatureParams::StylisticSet(StylisticSetParams::new(
+                        NameId::new(256),
+                    ))),
+                    vec![3, 4],
+                ),
+            ),
+        ];
+        let mut lang_sys = LangSys::new(vec![2, 0]);
+        lang_sys.required_feature_index = 1;
+        let scripts = vec![
+            ScriptRecord::new(Tag::new(b"DFLT"), ScriptTable::new(Some(lang_sys), vec![])),
+            ScriptRecord::new(
+                Tag::new(b"latn"),
+                ScriptTable::new(
+                    None,
+                    vec![LangSysRecord::new(Tag::new(b"TRK "), LangSys::new(vec![1]))],
+                ),
+            ),
+        ];
+        let coverage = || CoverageTable::format_1(vec![GlyphId::new(1)]);
+        let single = |delta| SingleSubst::format_1(coverage(), delta);
+        let lookup = |subtable| {
+            SubstitutionLookup::Single(Lookup::new(LookupFlag::empty(), vec![subtable], 0))
+        };
+        let alternate = SubstitutionLookup::Alternate(Lookup::new(
+            LookupFlag::empty(),
+            vec![AlternateSubstFormat1::new(
+                coverage(),
+                vec![AlternateSet::new(vec![GlyphId::new(3), GlyphId::new(4)])],
+            )],
+            0,
+        ));
+        let extension = SubstitutionLookup::Extension(Lookup::new(
+            LookupFlag::empty(),
+            vec![ExtensionSubtable::Single(ExtensionSubstFormat1::new(
+                1,
+                single(4),
+            ))],
+            0,
+        ));
+        let mut gsub = Gsub::new(
+            ScriptList::new(scripts),
+            FeatureList::new(features),
+            LookupList::new(vec![
+                lookup(single(1)),
+                lookup(single(2)),
+                alternate,
+                extension,
+                lookup(SingleSubst::format_2(coverage(), vec![GlyphId::new(6)])),
+            ]),
+        );
+        let substitutions =
+            FeatureTableSubstitution::new(vec![FeatureTableSubstitutionRecord::new(
+                1,
+                FeatureTable::new(None, vec![0, 1]),
+            )]);
+        let conditions = ConditionSet::new(vec![ConditionFormat1::new(
+            0,
+            NormalizedCoord::from_f32(min),
+            NormalizedCoord::from_f32(max),
+        )]);
+        gsub.feature_variations = Some(FeatureVariations::new(vec![FeatureVariationRecord::new(
+            Some(conditions),
+            Some(substitutions),
+        )]))
+        .into();
+        let mut name = Name::default();
+        for (id, string) in [
+            (256, "Single-storey a"),
+            (257, "Alternate a"),
+            (258, "abc"),
+            (259, "Round"),
+            (260, "Flat"),
+        ] {
+            name.name_record.insert(NameRecord::new(
+                3,
+                1,
+                0x409,
+                NameId::new(id),
+                String::from(string).into(),
+            ));
+        }
+        FontBuilder::new()
+            .add_table(&gsub)
+            .unwrap()
+            .add_table(&name)
+            .unwrap()
+            .build()
+    }
+}
@@ -17,13 +17,17 @@
 pub extern crate read_fonts as raw;
 
 pub mod attribute;
+pub mod baseline;
 pub mod charmap;
 pub mod font;
 pub mod instance;
+pub mod layout;
+pub mod math;
 pub mod metrics;
 #[cfg(feature = "scale")]
 pub mod scale;
 pub mod setting;
+pub mod shape;
 pub mod string;
 
 mod provider;
@@ -36,7 +40,7 @@ pub use variation::{Axis, AxisCollection, NamedInstance, NamedInstanceCollection
 pub mod prelude {
     #[doc(no_inline)]
     pub use super::{
-        font::{FontRef, UniqueId},
+        font::{FontRef, OwnedFont, UniqueId},
         instance::{LocationRef, NormalizedCoord, Size},
         GlyphId, MetadataProvider, Tag,
     };
@@ -0,0 +1,698 @@
+//! Mathematical layout information.
+//!
+//! This provides scaled access to the constants, per glyph positioning
+//! information and glyph size variants described by the
+//! [MATH](https://learn.microsoft.com/en-us/typography/opentype/spec/math)
+//! table.
+//!
+//! # Example
+//! The following function will return the glyph to use for a vertical
+//! delimiter that covers at least the given height, in pixels at a
+//! 16px font size:
+//! ```
+//! use skrifa::{
+//!     instance::{LocationRef, Size},
+//!     math::{MathDirection, MathStretch},
+//!     GlyphId, MetadataProvider,
+//! };
+//!
+//! fn delimiter<'a>(
+//!     font: &impl MetadataProvider<'a>,
+//!     glyph_id: GlyphId,
+//!     height: f32,
+//! ) -> Option<GlyphId> {
+//!     let math = font.math_info(Size::new(16.0), LocationRef::default());
+//!     match math.stretch(glyph_id, MathDirection::Vertical, height)? {
+//!         MathStretch::Variant(variant) => Some(variant.glyph_id()),
+//!         // a real layout engine would arrange the parts of the assembly
+//!         MathStretch::Assembly(_) => None,
+//!     }
+//! }
+//! ```
+
+use read_fonts::{
+    tables::{
+        layout::DeviceOrVariationIndex,
+        math::{
+            GlyphAssembly, GlyphPart, MathConstants, MathGlyphConstruction, MathGlyphInfo,
+            MathValueRecord, MathVariants,
+        },
+        variations::{DeltaSetIndex, ItemVariationStore},
+    },
+    types::GlyphId,
+    FontData, TableProvider,
+};
+
+use crate::instance::{LocationRef, NormalizedCoord, Size};
+
+/// Direction in which a glyph is stretched.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MathDirection {
+    Horizontal,
+    Vertical,
+}
+
+/// Corner of a glyph for which a math kern is requested.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MathKernCorner {
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+/// Global constant defined by the `MATH` table.
+///
+/// See the
+/// [MathConstants](https://learn.microsoft.com/en-us/typography/opentype/spec/math#mathconstants-table)
+/// table for a description of each value.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MathConstant {
+    ScriptPercentScaleDown,
+    ScriptScriptPercentScaleDown,
+    DelimitedSubFormulaMinHeight,
+    DisplayOperatorMinHeight,
+    MathLeading,
+    AxisHeight,
+    AccentBaseHeight,
+    FlattenedAccentBaseHeight,
+    SubscriptShiftDown,
+    SubscriptTopMax,
+    SubscriptBaselineDropMin,
+    SuperscriptShiftUp,
+    SuperscriptShiftUpCramped,
+    SuperscriptBottomMin,
+    SuperscriptBaselineDropMax,
+    SubSuperscriptGapMin,
+    SuperscriptBottomMaxWithSubscript,
+    SpaceAfterScript,
+    UpperLimitGapMin,
+    UpperLimitBaselineRiseMin,
+    LowerLimitGapMin,
+    LowerLimitBaselineDropMin,
+    StackTopShiftUp,
+    StackTopDisplayStyleShiftUp,
+    StackBottomShiftDown,
+    StackBottomDisplayStyleShiftDown,
+    StackGapMin,
+    StackDisplayStyleGapMin,
+    StretchStackTopShiftUp,
+    StretchStackBottomShiftDown,
+    StretchStackGapAboveMin,
+    StretchStackGapBelowMin,
+    FractionNumeratorShiftUp,
+    FractionNumeratorDisplayStyleShiftUp,
+    FractionDenominatorShiftDown,
+    FractionDenominatorDisplayStyleShiftDown,
+    FractionNumeratorGapMin,
+    FractionNumDisplayStyleGapMin,
+    FractionRuleThickness,
+    FractionDenominatorGapMin,
+    FractionDenomDisplayStyleGapMin,
+    SkewedFractionHorizontalGap,
+    SkewedFractionVerticalGap,
+    OverbarVerticalGap,
+    OverbarRuleThickness,
+    OverbarExtraAscender,
+    UnderbarVerticalGap,
+    UnderbarRuleThickness,
+    UnderbarExtraDescender,
+    RadicalVerticalGap,
+    RadicalDisplayStyleVerticalGap,
+    RadicalRuleThickness,
+    RadicalExtraAscender,
+    RadicalKernBeforeDegree,
+    RadicalKernAfterDegree,
+    RadicalDegreeBottomRaisePercent,
+}
+
+/// Size variant of a glyph with its advance in the direction of growth.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MathGlyphVariant {
+    glyph_id: GlyphId,
+    advance: f32,
+}
+
+impl MathGlyphVariant {
+    /// Returns the identifier of the variant glyph.
+    pub fn glyph_id(&self) -> GlyphId {
+        self.glyph_id
+    }
+
+    /// Returns the scaled advance of the variant in the direction of
+    /// growth.
+    pub fn advance(&self) -> f32 {
+        self.advance
+    }
+}
+
+/// Part of a glyph assembly.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MathGlyphPart {
+    glyph_id: GlyphId,
+    start_connector_length: f32,
+    end_connector_length: f32,
+    full_advance: f32,
+    is_extender: bool,
+}
+
+impl MathGlyphPart {
+    /// Returns the identifier of the glyph for this part.
+    pub fn glyph_id(&self) -> GlyphId {
+        self.glyph_id
+    }
+
+    /// Returns the scaled length of the connector at the start of the part.
+    pub fn start_connector_length(&self) -> f32 {
+        self.start_connector_length
+    }
+
+    /// Returns the scaled length of the connector at the end of the part.
+    pub fn end_connector_length(&self) -> f32 {
+        self.end_connector_length
+    }
+
+    /// Returns the scaled advance of the part in the direction of growth.
+    pub fn full_advance(&self) -> f32 {
+        self.full_advance
+    }
+
+    /// Returns true if the part can be repeated to grow the assembly.
+    pub fn is_extender(&self) -> bool {
+        self.is_extender
+    }
+}
+
+/// Set of parts that can be assembled to construct a glyph of arbitrary
+/// size.
+#[derive(Clone)]
+pub struct MathAssembly<'a> {
+    italics_correction: f32,
+    parts: &'a [GlyphPart],
+    scale: f32,
+}
+
+impl<'a> MathAssembly<'a> {
+    /// Returns the scaled italics correction of the assembled glyph.
+    pub fn italics_correction(&self) -> f32 {
+        self.italics_correction
+    }
+
+    /// Returns an iterator over the parts of the assembly, ordered from
+    /// bottom to top or left to right.
+    pub fn parts(&self) -> impl Iterator<Item = MathGlyphPart> + 'a + Clone {
+        let scale = self.scale;
+        self.parts.iter().map(move |part| MathGlyphPart {
+            glyph_id: part.glyph_id(),
+            start_connector_length: part.start_connector_length().to_u16() as f32 * scale,
+            end_connector_length: part.end_connector_length().to_u16() as f32 * scale,
+            full_advance: part.full_advance().to_u16() as f32 * scale,
+            is_extender: part.is_extender(),
+        })
+    }
+}
+
+/// Result of stretching a glyph to a requested size.
+#[derive(Clone)]
+pub enum MathStretch<'a> {
+    /// A single glyph that covers the requested size.
+    Variant(MathGlyphVariant),
+    /// Parts that must be assembled to cover the requested size.
+    Assembly(MathAssembly<'a>),
+}
+
+/// Mathematical layout information for a font at a specific size and
+/// location in variation space.
+///
+/// All values, other than percentages, are scaled to the requested size
+/// and include variation deltas from the item variation store in the
+/// `GDEF` table. Device tables for hinting are not applied.
+#[derive(Clone, Default)]
+pub struct MathInfo<'a> {
+    constants: Option<MathConstants<'a>>,
+    glyph_info: Option<MathGlyphInfo<'a>>,
+    variants: Option<MathVariants<'a>>,
+    var_store: Option<ItemVariationStore<'a>>,
+    coords: &'a [NormalizedCoord],
+    scale: f32,
+}
+
+impl<'a> MathInfo<'a> {
+    /// Creates new mathematical layout information for the given font,
+    /// size and location in normalized variation space.
+    pub fn new(
+        font: &impl TableProvider<'a>,
+        size: Size,
+        location: impl Into<LocationRef<'a>>,
+    ) -> Self {
+        let upem = font
+            .head()
+            .map(|head| head.units_per_em())
+            .unwrap_or_default();
+        let math = font.math().ok();
+        let var_store = font
+            .gdef()
+            .ok()
+            .and_then(|gdef| gdef.item_var_store())
+            .and_then(|store| store.ok());
+        Self {
+            constants: math.as_ref().and_then(|math| math.math_constants().ok()),
+            glyph_info: math.as_ref().and_then(|math| math.math_glyph_info().ok()),
+            variants: math.as_ref().and_then(|math| math.math_variants().ok()),
+            var_store,
+            coords: location.into().coords(),
+            scale: size.linear_scale(upem),
+        }
+    }
+
+    /// Returns true if the font contains a `MATH` table.
+    pub fn is_present(&self) -> bool {
+        self.constants.is_some() || self.glyph_info.is_some() || self.variants.is_some()
+    }
+
+    /// Returns the value of the given constant.
+    ///
+    /// Percentages are returned unscaled and all other values are scaled
+    /// to the requested size. Returns zero if the constant is not
+    /// available.
+    pub fn constant(&self, constant: MathConstant) -> f32 {
+        use MathConstant::*;
+        let Some(constants) = self.constants.as_ref() else {
+            return 0.0;
+        };
+        let record = match constant {
+            ScriptPercentScaleDown => return constants.script_percent_scale_down() as f32,
+            ScriptScriptPercentScaleDown => {
+                return constants.script_script_percent_scale_down() as f32
+            }
+            RadicalDegreeBottomRaisePercent => {
+                return constants.radical_degree_bottom_raise_percent() as f32
+            }
+            DelimitedSubFormulaMinHeight => {
+                return constants.delimited_sub_formula_min_height().to_u16() as f32 * self.scale
+            }
+            DisplayOperatorMinHeight => {
+                return constants.display_operator_min_height().to_u16() as f32 * self.scale
+            }
+            MathLeading => constants.math_leading(),
+            AxisHeight => constants.axis_height(),
+            AccentBaseHeight => constants.accent_base_height(),
+            FlattenedAccentBaseHeight => constants.flattened_accent_base_height(),
+            SubscriptShiftDown => constants.subscript_shift_down(),
+            SubscriptTopMax => constants.subscript_top_max(),
+            SubscriptBaselineDropMin => constants.subscript_baseline_drop_min(),
+            SuperscriptShiftUp => constants.superscript_shift_up(),
+            SuperscriptShiftUpCramped => constants.superscript_shift_up_cramped(),
+            SuperscriptBottomMin => constants.superscript_bottom_min(),
+            SuperscriptBaselineDropMax => constants.superscript_baseline_drop_max(),
+            SubSuperscriptGapMin => constants.sub_superscript_gap_min(),
+            SuperscriptBottomMaxWithSubscript => constants.superscript_bottom_max_with_subscript(),
+            SpaceAfterScript => constants.space_after_script(),
+            UpperLimitGapMin => constants.upper_limit_gap_min(),
+            UpperLimitBaselineRiseMin => constants.upper_limit_baseline_rise_min(),
+            LowerLimitGapMin => constants.lower_limit_gap_min(),
+            LowerLimitBaselineDropMin => constants.lower_limit_baseline_drop_min(),
+            StackTopShiftUp => constants.stack_top_shift_up(),
+            StackTopDisplayStyleShiftUp => constants.stack_top_display_style_shift_up(),
+            StackBottomShiftDown => constants.stack_bottom_shift_down(),
+            StackBottomDisplayStyleShiftDown => constants.stack_bottom_display_style_shift_down(),
+            StackGapMin => constants.stack_gap_min(),
+            StackDisplayStyleGapMin => constants.stack_display_style_gap_min(),
+            StretchStackTopShiftUp => constants.stretch_stack_top_shift_up(),
+            StretchStackBottomShiftDown => constants.stretch_stack_bottom_shift_down(),
+            StretchStackGapAboveMin => constants.stretch_stack_gap_above_min(),
+            StretchStackGapBelowMin => constants.stretch_stack_gap_below_min(),
+            FractionNumeratorShiftUp => constants.fraction_numerator_shift_up(),
+            FractionNumeratorDisplayStyleShiftUp => {
+                constants.fraction_numerator_display_style_shift_up()
+            }
+            FractionDenominatorShiftDown => constants.fraction_denominator_shift_down(),
+            FractionDenominatorDisplayStyleShiftDown => {
+                constants.fraction_denominator_display_style_shift_down()
+            }
+            FractionNumeratorGapMin => constants.fraction_numerator_gap_min(),
+            FractionNumDisplayStyleGapMin => constants.fraction_num_display_style_gap_min(),
+            FractionRuleThickness => constants.fraction_rule_thickness(),
+            FractionDenominatorGapMin => constants.fraction_denominator_gap_min(),
+            FractionDenomDisplayStyleGapMin => constants.fraction_denom_display_style_gap_min(),
+            SkewedFractionHorizontalGap => constants.skewed_fraction_horizontal_gap(),
+            SkewedFractionVerticalGap => constants.skewed_fraction_vertical_gap(),
+            OverbarVerticalGap => constants.overbar_vertical_gap(),
+            OverbarRuleThickness => constants.overbar_rule_thickness(),
+            OverbarExtraAscender => constants.overbar_extra_ascender(),
+            UnderbarVerticalGap => constants.underbar_vertical_gap(),
+            UnderbarRuleThickness => constants.underbar_rule_thickness(),
+            UnderbarExtraDescender => constants.underbar_extra_descender(),
+            RadicalVerticalGap => constants.radical_vertical_gap(),
+            RadicalDisplayStyleVerticalGap => constants.radical_display_style_vertical_gap(),
+            RadicalRuleThickness => constants.radical_rule_thickness(),
+            RadicalExtraAscender => constants.radical_extra_ascender(),
+            RadicalKernBeforeDegree => constants.radical_kern_before_degree(),
+            RadicalKernAfterDegree => constants.radical_kern_after_degree(),
+        };
+        self.value(record, constants.offset_data())
+    }
+
+    /// Returns the italics correction for the given glyph.
+    pub fn italics_correction(&self, glyph_id: GlyphId) -> Option<f32> {
+        let info = self
+            .glyph_info
+            .as_ref()?
+            .math_italics_correction_info()?
+            .ok()?;
+        let record = info.get(glyph_id)?;
+        Some(self.value(record, info.offset_data()))
+    }
+
+    /// Returns the horizontal position for attaching accents above the
+    /// given glyph.
+    pub fn top_accent_attachment(&self, glyph_id: GlyphId) -> Option<f32> {
+        let info = self
+            .glyph_info
+            .as_ref()?
+            .math_top_accent_attachment()?
+            .ok()?;
+        let record = info.get(glyph_id)?;
+        Some(self.value(record, info.offset_data()))
+    }
+
+    /// Returns true if the given glyph is an extended shape.
+    ///
+    /// Extended shapes are glyphs that should be positioned relative to
+    /// their full height when attaching scripts.
+    pub fn is_extended_shape(&self, glyph_id: GlyphId) -> bool {
+        self.glyph_info
+            .as_ref()
+            .map(|info| info.is_extended_shape(glyph_id))
+            .unwrap_or_default()
+    }
+
+    /// Returns the kerning adjustment for the given corner of a glyph at
+    /// the given scaled height.
+    ///
+    /// Returns zero if the glyph has no kerning information for the
+    /// corner.
+    pub fn kern(&self, glyph_id: GlyphId, corner: MathKernCorner, height: f32) -> f32 {
+        self.kern_impl(glyph_id, corner, height).unwrap_or_default()
+    }
+
+    fn kern_impl(&self, glyph_id: GlyphId, corner: MathKernCorner, height: f32) -> Option<f32> {
+        let info = self.glyph_info.as_ref()?.math_kern_info()?.ok()?;
+        let record = info.get(glyph_id)?;
+        let data = info.offset_data();
+        let kern = match corner {
+            MathKernCorner::TopRight => record.top_right_math_kern(data),
+            MathKernCorner::TopLeft => record.top_left_math_kern(data),
+            MathKernCorner::BottomRight => record.bottom_right_math_kern(data),
+            MathKernCorner::BottomLeft => record.bottom_left_math_kern(data),
+        }?
+        .ok()?;
+        let data = kern.offset_data();
+        // heights may vary so compare against the adjusted values
+        let index = kern
+            .correction_height()
+            .iter()
+            .position(|correction| height < self.value(correction, data))
+            .unwrap_or(kern.height_count() as usize);
+        Some(self.value(kern.kern_values().get(index)?, data))
+    }
+
+    /// Returns the minimum overlap of connecting glyphs in a glyph
+    /// assembly.
+    pub fn min_connector_overlap(&self) -> f32 {
+        self.variants
+            .as_ref()
+            .map(|variants| variants.min_connector_overlap().to_u16() as f32 * self.scale)
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over the size variants of the given glyph for
+    /// the given direction, in order of increasing size.
+    pub fn variants(
+        &self,
+        glyph_id: GlyphId,
+        direction: MathDirection,
+    ) -> impl Iterator<Item = MathGlyphVariant> + 'a + Clone {
+        let scale = self.scale;
+        self.construction(glyph_id, direction)
+            .map(|construction| construction.math_glyph_variant_records())
+            .unwrap_or_default()
+            .iter()
+            .map(move |record| MathGlyphVariant {
+                glyph_id: record.variant_glyph(),
+                advance: record.advance_measurement().to_u16() as f32 * scale,
+            })
+    }
+
+    /// Returns the parts for assembling the given glyph in the given
+    /// direction.
+    pub fn assembly(
+        &self,
+        glyph_id: GlyphId,
+        direction: MathDirection,
+    ) -> Option<MathAssembly<'a>> {
+        let assembly = self
+            .construction(glyph_id, direction)?
+            .glyph_assembly()?
+            .ok()?;
+        Some(self.make_assembly(&assembly))
+    }
+
+    /// Returns the smallest variant of the given glyph that is at least
+    /// `min_size` long in the given direction or, if no variant is large
+    /// enough, the parts for assembling the glyph.
+    ///
+    /// `min_size` is in scaled units. Returns `None` if there is neither a
+    /// suitable variant nor an assembly.
+    pub fn stretch(
+        &self,
+        glyph_id: GlyphId,
+        direction: MathDirection,
+        min_size: f32,
+    ) -> Option<MathStretch<'a>> {
+        if let Some(variant) = self
+            .variants(glyph_id, direction)
+            .find(|variant| variant.advance >= min_size)
+        {
+            return Some(MathStretch::Variant(variant));
+        }
+        self.assembly(glyph_id, direction)
+            .map(MathStretch::Assembly)
+    }
+
+    fn construction(
+        &self,
+        glyph_id: GlyphId,
+        direction: MathDirection,
+    ) -> Option<MathGlyphConstruction<'a>> {
+        let variants = self.variants.as_ref()?;
+        match direction {
+            MathDirection::Vertical => variants.vert_glyph_construction(glyph_id),
+            MathDirection::Horizontal => variants.horiz_glyph_construction(glyph_id),
+        }?
+        .ok()
+    }
+
+    fn make_assembly(&self, assembly: &GlyphAssembly<'a>) -> MathAssembly<'a> {
+        MathAssembly {
+            italics_correction: self.value(assembly.italics_correction(), assembly.offset_data()),
+            parts: assembly.part_records(),
+            scale: self.scale,
+        }
+    }
+
+    /// Returns the scaled value of a record, including variation deltas at
+    /// the location of the math information.
+    ///
+    /// Offsets to device tables are relative to `offset_data`.
+    fn value(&self, record: &MathValueRecord, offset_data: FontData) -> f32 {
+        let mut value = record.value().to_i16() as f32;
+        if !self.coords.is_empty() {
+            if let (Some(Ok(DeviceOrVariationIndex::VariationIndex(index))), Some(store)) =
+                (record.device(offset_data), self.var_store.as_ref())
+            {
+                let delta = store
+                    .compute_delta(
+                        DeltaSetIndex {
+                            outer: index.delta_set_outer_index(),
+                            inner: index.delta_set_inner_index(),
+                        },
+                        self.coords,
+                    )
+                    .unwrap_or_default();
+                value += delta as f32;
+            }
+        }
+        value * self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MetadataProvider as _;
+    use read_fonts::{types::Tag, FontRef};
+    use write_fonts::{
+        tables::{
+            gdef::Gdef,
+            variations::{
+                ItemVariationData, ItemVariationStore, RegionAxisCoordinates, VariationRegion,
+                VariationRegionList,
+            },
+        },
+        types::F2Dot14,
+        FontBuilder,
+    };
+
+    #[test]
+    fn constants() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let math = font.math_info(Size::unscaled(), LocationRef::default());
+        assert!(math.is_present());
+        assert_eq!(math.constant(MathConstant::ScriptPercentScaleDown), 80.0);
+        assert_eq!(
+            math.constant(MathConstant::DisplayOperatorMinHeight),
+            1300.0
+        );
+        assert_eq!(math.constant(MathConstant::MathLeading), 150.0);
+        assert_eq!(math.constant(MathConstant::AxisHeight), 250.0);
+        assert_eq!(math.constant(MathConstant::RadicalKernAfterDegree), 48.0);
+        assert_eq!(
+            math.constant(MathConstant::RadicalDegreeBottomRaisePercent),
+            70.0
+        );
+        // axis height has a delta of -20 at the max of the first axis
+        let coords = &[NormalizedCoord::from_f32(1.0)];
+        let math = font.math_info(Size::unscaled(), LocationRef::new(coords));
+        assert_eq!(math.constant(MathConstant::AxisHeight), 230.0);
+        assert_eq!(math.constant(MathConstant::MathLeading), 150.0);
+    }
+
+    #[test]
+    fn glyph_info() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let math = font.math_info(Size::unscaled(), LocationRef::default());
+        assert_eq!(math.italics_correction(GlyphId::new(5)), Some(35.0));
+        assert_eq!(math.italics_correction(GlyphId::new(6)), None);
+        assert_eq!(math.top_accent_attachment(GlyphId::new(5)), None);
+        assert!(math.is_extended_shape(GlyphId::new(5)));
+        assert!(!math.is_extended_shape(GlyphId::new(6)));
+        assert_eq!(
+            math.kern(GlyphId::new(5), MathKernCorner::TopRight, 100.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn stretch_vertical() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let math = font.math_info(Size::unscaled(), LocationRef::default());
+        let glyph_id = GlyphId::new(5);
+        assert_eq!(math.min_connector_overlap(), 10.0);
+        let variants = math
+            .variants(glyph_id, MathDirection::Vertical)
+            .map(|variant| (variant.glyph_id().to_u16(), variant.advance()))
+            .collect::<Vec<_>>();
+        assert_eq!(variants, [(5, 500.0), (6, 1000.0)]);
+        assert_eq!(
+            math.variants(glyph_id, MathDirection::Horizontal).count(),
+            0
+        );
+        let Some(MathStretch::Variant(variant)) =
+            math.stretch(glyph_id, MathDirection::Vertical, 600.0)
+        else {
+            panic!("expected a variant");
+        };
+        assert_eq!(variant.glyph_id(), GlyphId::new(6));
+        let Some(MathStretch::Assembly(assembly)) =
+            math.stretch(glyph_id, MathDirection::Vertical, 1200.0)
+        else {
+            panic!("expected an assembly");
+        };
+        let parts = assembly
+            .parts()
+            .map(|part| {
+                (
+                    part.glyph_id().to_u16(),
+                    part.full_advance(),
+                    part.is_extender(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(parts, [(7, 300.0, false), (8, 200.0, true)]);
+        assert!(math
+            .stretch(glyph_id, MathDirection::Horizontal, 10.0)
+            .is_none());
+    }
+
+    #[test]
+    fn missing_table() {
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let math = font.math_info(Size::new(16.0), LocationRef::default());
+        assert!(!math.is_present());
+        assert_eq!(math.constant(MathConstant::AxisHeight), 0.0);
+        assert!(math
+            .stretch(GlyphId::new(1), MathDirection::Vertical, 10.0)
+            .is_none());
+    }
+
+    fn make_font() -> Vec<u8> {
+        let mut math = Vec::new();
+        let mut push = |values: &[u16]| {
+            for value in values {
+                math.extend_from_slice(&value.to_be_bytes());
+            }
+        };
+        // header: version, constants, glyph info and variants offsets
+        push(&[1, 0, 10, 230, 258]);
+        // constants at 10: percents and min heights
+        push(&[80, 60, 1500, 1300]);
+        // math leading, axis height with a device table at 214
+        push(&[150, 0, 250, 214]);
+        for i in 0..49 {
+            push(&[i, 0]);
+        }
+        push(&[70]);
+        // variation index at 224
+        push(&[0, 0, 0x8000]);
+        // glyph info at 230: italics, top accent, extended shapes, kern
+        push(&[8, 0, 22, 0]);
+        // italics correction info at 238 with coverage for glyph 5
+        push(&[8, 1, 35, 0]);
+        push(&[1, 1, 5]);
+        // extended shape coverage at 252
+        push(&[1, 1, 5]);
+        // variants at 258: overlap, coverages, counts, construction offset
+        push(&[10, 12, 18, 1, 0, 22]);
+        push(&[1, 1, 5]);
+        push(&[1, 0]);
+        // construction: assembly offset, variants
+        push(&[12, 2, 5, 500, 6, 1000]);
+        // assembly: italics correction, parts
+        push(&[0, 0, 2]);
+        push(&[7, 0, 50, 300, 0]);
+        push(&[8, 50, 50, 200, 1]);
+        let mut gdef = Gdef::new(None, None, None, None);
+        let one = F2Dot14::from_f32(1.0);
+        gdef.item_var_store = Some(ItemVariationStore::new(
+            VariationRegionList::new(vec![VariationRegion::new(vec![
+                RegionAxisCoordinates::new(F2Dot14::ZERO, one, one),
+            ])]),
+            vec![Some(ItemVariationData::new(
+                1,
+                0,
+                vec![0],
+                vec![-20i8 as u8],
+            ))],
+        ))
+        .into();
+        FontBuilder::new()
+            .add_table(&gdef)
+            .unwrap()
+            .add_raw(Tag::new(b"MATH"), math)
+            .build()
+    }
+}
@@ -30,6 +30,7 @@ use read_fonts::{
         hvar::Hvar,
         loca::Loca,
         os2::SelectionFlags,
+        variations::ItemVariationStoreInstance,
     },
     types::{BigEndian, Fixed, GlyphId},
     TableProvider,
@@ -227,7 +228,7 @@ pub struct GlyphMetrics<'a> {
     h_metrics: &'a [LongMetric],
     default_advance_width: u16,
     lsbs: &'a [BigEndian<i16>],
-    hvar: Option<Hvar<'a>>,
+    hvar: Option<(Hvar<'a>, ItemVariationStoreInstance<'a>)>,
     gvar: Option<Gvar<'a>>,
     loca_glyf: Option<(Loca<'a>, Glyf<'a>)>,
     coords: &'a [NormalizedCoord],
@@ -260,7 +261,13 @@ impl<'a> GlyphMetrics<'a> {
                 (h_metrics, default_advance_width, lsbs)
             })
             .unwrap_or_default();
-        let hvar = font.hvar().ok();
+        // Region scalars are computed once here since metrics are typically
+        // requested for many glyphs at the same location
+        let hvar = font.hvar().ok().and_then(|hvar| {
+            let store = hvar.item_variation_store().ok()?;
+            let instance = ItemVariationStoreInstance::new(store, coords).ok()?;
+            Some((hvar, instance))
+        });
         let gvar = font.gvar().ok();
         let loca_glyf = if let (Ok(loca), Ok(glyf)) = (font.loca(None), font.glyf()) {
             Some((loca, glyf))
@@ -298,12 +305,10 @@ impl<'a> GlyphMetrics<'a> {
             .get(glyph_id.to_u16() as usize)
             .map(|metric| metric.advance())
             .unwrap_or(self.default_advance_width) as i32;
-        if let Some(hvar) = &self.hvar {
+        if let Some((hvar, store)) = &self.hvar {
             advance += hvar
-                .advance_width_delta(glyph_id, self.coords)
-                // FreeType truncates metric deltas...
-                // https://github.com/freetype/freetype/blob/7838c78f53f206ac5b8e9cefde548aa81cb00cf4/src/truetype/ttgxvar.c#L1027
-                .map(|delta| delta.to_f64() as i32)
+                .advance_width_delta_index(glyph_id)
+                .and_then(|index| store.compute_delta(index))
                 .unwrap_or(0);
         } else if self.gvar.is_some() {
             advance += self.metric_deltas_from_gvar(glyph_id)[1];
@@ -330,12 +335,10 @@ impl<'a> GlyphMetrics<'a> {
                     .map(|lsb| lsb.get())
                     .unwrap_or_default()
             }) as i32;
-        if let Some(hvar) = &self.hvar {
+        if let Some((hvar, store)) = &self.hvar {
             lsb += hvar
-                .lsb_delta(glyph_id, self.coords)
-                // FreeType truncates metric deltas...
-                // https://github.com/freetype/freetype/blob/7838c78f53f206ac5b8e9cefde548aa81cb00cf4/src/truetype/ttgxvar.c#L1027
-                .map(|delta| delta.to_f64() as i32)
+                .lsb_delta_index(glyph_id)
+                .and_then(|index| store.compute_delta(index))
                 .unwrap_or(0);
         } else if self.gvar.is_some() {
             lsb += self.metric_deltas_from_gvar(glyph_id)[0];
@@ -461,8 +464,8 @@ impl<'a> GvarMetricDeltas<'a> {
 mod tests {
     use super::*;
     use crate::MetadataProvider as _;
-    use font_test_data::{SIMPLE_GLYF, VAZIRMATN_VAR};
-    use read_fonts::FontRef;
+    use font_test_data::{NOTO_SERIF_DISPLAY_TRIMMED, SIMPLE_GLYF, VAZIRMATN_VAR};
+    use read_fonts::{CffFont, FontRef, TableProvider};
 
     #[test]
     fn metrics() {
@@ -545,6 +548,23 @@ mod tests {
         assert_eq!(expected, &result[..]);
     }
 
+    #[test]
+    fn glyph_metrics_bare_cff() {
+        let font = FontRef::new(NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+        let cff_font = CffFont::new(font.cff().unwrap().offset_data().as_bytes(), 0).unwrap();
+        let size = Size::new(16.0);
+        let expected = font.glyph_metrics(size, LocationRef::default());
+        let glyph_metrics = (&cff_font).glyph_metrics(size, LocationRef::default());
+        assert_eq!(glyph_metrics.glyph_count(), expected.glyph_count());
+        for i in 0..expected.glyph_count() {
+            let gid = GlyphId::new(i);
+            assert_eq!(
+                glyph_metrics.advance_width(gid),
+                expected.advance_width(gid)
+            );
+        }
+    }
+
     #[test]
     fn glyph_metrics_var() {
         let font = FontRef::new(VAZIRMATN_VAR).unwrap();
@@ -1,7 +1,10 @@
 use super::{
     attribute::Attributes,
+    baseline::Baselines,
     charmap::Charmap,
     instance::{LocationRef, Size},
+    layout::LayoutInfo,
+    math::MathInfo,
     metrics::{GlyphMetrics, Metrics},
     string::{LocalizedStrings, StringId},
     variation::{AxisCollection, NamedInstanceCollection},
@@ -43,6 +46,24 @@ pub trait MetadataProvider<'a>: raw::TableProvider<'a> + Sized {
         GlyphMetrics::new(self, size, location)
     }
 
+    /// Returns the scripts, languages and features defined by the layout
+    /// tables for the specified location in normalized variation space.
+    fn layout_info(&self, location: impl Into<LocationRef<'a>>) -> LayoutInfo<'a> {
+        LayoutInfo::new(self, location)
+    }
+
+    /// Returns the baseline information for the specified size and location
+    /// in normalized variation space.
+    fn baselines(&self, size: Size, location: impl Into<LocationRef<'a>>) -> Baselines<'a> {
+        Baselines::new(self, size, location)
+    }
+
+    /// Returns the mathematical layout information for the specified size
+    /// and location in normalized variation space.
+    fn math_info(&self, size: Size, location: impl Into<LocationRef<'a>>) -> MathInfo<'a> {
+        MathInfo::new(self, size, location)
+    }
+
     /// Returns the character to nominal glyph identifier mapping.
     fn charmap(&self) -> Charmap<'a> {
         Charmap::new(self)
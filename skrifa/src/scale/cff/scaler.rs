@@ -8,7 +8,7 @@ use read_fonts::{
         cff2::Cff2,
         postscript::{
             charstring::{self, CommandSink},
-            dict, BlendState, Error, FdSelect, Index,
+            dict, standard_encoding, BlendState, Charset, Error, FdSelect, Index,
         },
         variations::ItemVariationStore,
     },
@@ -197,13 +197,32 @@ impl<'a> Scaler<'a> {
         let mut pen_sink = charstring::PenSink::new(pen);
         let mut simplifying_adapter = NopFilteringSink::new(&mut pen_sink);
         let mut scaling_adapter = ScalingSink26Dot6::new(&mut simplifying_adapter, subfont.scale);
-        charstring::evaluate(
-            charstring_data,
-            self.global_subrs(),
-            subrs,
-            blend_state,
-            &mut scaling_adapter,
-        )?;
+        if let Some(charset) = &self.top_dict.charset {
+            // Accented glyphs may be composed with the deprecated 'seac'
+            // operator, which references the base and accent glyphs by
+            // their codes in the standard encoding.
+            let charstrings = self.top_dict.charstrings.as_ref();
+            let seac_component = |code| {
+                let glyph_id = charset.glyph_id(standard_encoding(code)).ok()?;
+                charstrings?.get(glyph_id.to_u16() as usize).ok()
+            };
+            charstring::evaluate_with_seac(
+                charstring_data,
+                self.global_subrs(),
+                subrs,
+                blend_state,
+                seac_component,
+                &mut scaling_adapter,
+            )?;
+        } else {
+            charstring::evaluate(
+                charstring_data,
+                self.global_subrs(),
+                subrs,
+                blend_state,
+                &mut scaling_adapter,
+            )?;
+        }
         simplifying_adapter.finish();
         Ok(())
     }
@@ -305,6 +324,9 @@ impl Subfont {
 #[derive(Default)]
 struct TopDict<'a> {
     charstrings: Option<Index<'a>>,
+    /// Only present for CFF fonts that are not CID-keyed, since it is used
+    /// to resolve the components of 'seac' glyphs.
+    charset: Option<Charset<'a>>,
     font_dicts: Option<Index<'a>>,
     fd_select: Option<FdSelect<'a>>,
     private_dict_range: Option<Range<usize>>,
@@ -314,8 +336,12 @@ struct TopDict<'a> {
 impl<'a> TopDict<'a> {
     fn new(table_data: &'a [u8], top_dict_data: &'a [u8], is_cff2: bool) -> Result<Self, Error> {
         let mut items = TopDict::default();
+        let mut charset_offset = 0;
+        let mut is_cid_keyed = false;
         for entry in dict::entries(top_dict_data, None) {
             match entry? {
+                dict::Entry::Charset(offset) => charset_offset = offset,
+                dict::Entry::Ros { .. } => is_cid_keyed = true,
                 dict::Entry::CharstringsOffset(offset) => {
                     items.charstrings = Some(Index::new(
                         table_data.get(offset..).unwrap_or_default(),
@@ -345,6 +371,18 @@ impl<'a> TopDict<'a> {
                 _ => {}
             }
         }
+        if !is_cff2 && !is_cid_keyed {
+            if let Some(charstrings) = &items.charstrings {
+                // the charset is only needed for seac glyphs, so a malformed
+                // one shouldn't prevent loading the remaining outlines
+                items.charset = Charset::new(
+                    FontData::new(table_data),
+                    charset_offset,
+                    charstrings.count(),
+                )
+                .ok();
+            }
+        }
         Ok(items)
     }
 }
@@ -538,7 +576,10 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use read_fonts::FontRef;
+    use read_fonts::{
+        scaler_test::{Path, PathElement},
+        CffFont, FontRef,
+    };
 
     #[test]
     fn read_cff_static() {
@@ -648,4 +689,87 @@ mod tests {
             }
         }
     }
+
+    /// Builds a bare CFF font with the given charset offset, in which
+    /// 'Aacute' is composed from 'A' and 'acute' with seac.
+    ///
+    /// The offset 0 selects the ISOAdobe charset, where glyph ids are equal
+    /// to string ids.
+    fn make_seac_font(charset_offset: i32) -> Vec<u8> {
+        const NUM_GLYPHS: usize = 172;
+        let mut charstrings = vec![vec![14u8]; NUM_GLYPHS];
+        // A: 0 0 rmoveto 100 hlineto endchar
+        charstrings[34] = vec![139, 139, 21, 239, 6, 14];
+        // acute: 5 0 rmoveto 0 50 rlineto endchar
+        charstrings[125] = vec![144, 139, 21, 139, 189, 5, 14];
+        // Aacute: 10 20 65 194 endchar
+        charstrings[171] = vec![149, 159, 204, 247, 86, 14];
+        let mut charstrings_index = (NUM_GLYPHS as u16).to_be_bytes().to_vec();
+        charstrings_index.push(2);
+        let mut offset = 1u16;
+        charstrings_index.extend(offset.to_be_bytes());
+        for charstring in &charstrings {
+            offset += charstring.len() as u16;
+            charstrings_index.extend(offset.to_be_bytes());
+        }
+        charstrings_index.extend(charstrings.concat());
+        let num = |value: i32| [vec![29], value.to_be_bytes().to_vec()].concat();
+        // header, Name INDEX and the header of the Top DICT INDEX
+        let mut data = vec![1, 0, 4, 4, 0, 1, 1, 1, 5];
+        data.extend(b"Seac");
+        data.extend([0, 1, 1, 1, 24]);
+        // Top DICT followed by empty String and Global Subr INDEXes
+        let charstrings_offset = data.len() + 23 + 4;
+        let private_offset = charstrings_offset + charstrings_index.len();
+        data.extend([num(charset_offset), vec![15]].concat());
+        data.extend([num(charstrings_offset as i32), vec![17]].concat());
+        data.extend([num(0), num(private_offset as i32), vec![18]].concat());
+        data.extend([0, 0, 0, 0]);
+        data.extend(charstrings_index);
+        data
+    }
+
+    #[test]
+    fn seac_composition() {
+        let cff_font = CffFont::new(&make_seac_font(0), 0).unwrap();
+        let font = cff_font.as_font_ref();
+        let scaler = Scaler::new(&font).unwrap();
+        assert!(scaler.top_dict.charset.is_some());
+        let subfont = scaler.subfont(0, 0.0, &[]).unwrap();
+        let mut path = Path::default();
+        scaler
+            .outline(&subfont, GlyphId::new(171), &[], false, &mut path)
+            .unwrap();
+        // outline of 'A' followed by 'acute' offset by (10, 20)
+        let expected = [
+            PathElement::MoveTo([0.0, 0.0]),
+            PathElement::LineTo([100.0, 0.0]),
+            PathElement::LineTo([0.0, 0.0]),
+            PathElement::MoveTo([15.0, 20.0]),
+            PathElement::LineTo([15.0, 70.0]),
+            PathElement::LineTo([15.0, 20.0]),
+        ];
+        assert_eq!(path.elements, expected);
+    }
+
+    #[test]
+    fn malformed_charset() {
+        // the charset is out of bounds
+        let cff_font = CffFont::new(&make_seac_font(100_000), 0).unwrap();
+        let font = cff_font.as_font_ref();
+        let scaler = Scaler::new(&font).unwrap();
+        assert!(scaler.top_dict.charset.is_none());
+        let subfont = scaler.subfont(0, 0.0, &[]).unwrap();
+        let mut path = Path::default();
+        scaler
+            .outline(&subfont, GlyphId::new(34), &[], false, &mut path)
+            .unwrap();
+        assert_eq!(path.elements.len(), 3);
+        // seac glyphs are empty without a charset
+        let mut path = Path::default();
+        scaler
+            .outline(&subfont, GlyphId::new(171), &[], false, &mut path)
+            .unwrap();
+        assert!(path.elements.is_empty());
+    }
 }
@@ -17,7 +17,7 @@ use read_fonts::{
         hvar::Hvar,
         loca::Loca,
     },
-    types::{BigEndian, F26Dot6, F2Dot14, Fixed, GlyphId, Tag},
+    types::{BigEndian, F26Dot6, F2Dot14, Fixed, GlyphId},
     TableProvider,
 };
 
@@ -596,17 +596,14 @@ impl<'a> ScalerFont<'a> {
             (false, F26Dot6::from_bits(0x10000))
         };
         let fpgm = font
-            .data_for_tag(Tag::new(b"fpgm"))
-            .map(|data| data.read_array(0..data.len()).unwrap())
+            .fpgm()
+            .map(|fpgm| fpgm.instructions())
             .unwrap_or_default();
         let prep = font
-            .data_for_tag(Tag::new(b"prep"))
-            .map(|data| data.read_array(0..data.len()).unwrap())
-            .unwrap_or_default();
-        let cvt = font
-            .data_for_tag(Tag::new(b"cvt "))
-            .and_then(|data| data.read_array(0..data.len()).ok())
+            .prep()
+            .map(|prep| prep.instructions())
             .unwrap_or_default();
+        let cvt = font.cvt().map(|cvt| cvt.values()).unwrap_or_default();
         let maxp = font.maxp()?;
         let glyph_count = maxp.num_glyphs();
         let axis_count = font.fvar().map(|fvar| fvar.axis_count()).unwrap_or(0);
@@ -220,7 +220,7 @@ impl Context {
 #[cfg(test)]
 mod tests {
     use super::{Context, Size};
-    use read_fonts::{scaler_test, FontRef};
+    use read_fonts::{scaler_test, CffFont, FontRef, TableProvider};
 
     #[test]
     fn vazirmatin_var() {
@@ -249,8 +249,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn noto_serif_display_bare_cff() {
+        let font = FontRef::new(font_test_data::NOTO_SERIF_DISPLAY_TRIMMED).unwrap();
+        let cff_font = CffFont::new(font.cff().unwrap().offset_data().as_bytes(), 0).unwrap();
+        compare_glyphs_for_font(
+            cff_font.as_font_ref(),
+            font_test_data::NOTO_SERIF_DISPLAY_TRIMMED_GLYPHS,
+            true,
+        );
+    }
+
+    // trailing bytes in the cvt table that don't make up a value are ignored
+    #[test]
+    fn odd_length_cvt() {
+        use read_fonts::types::Tag;
+        let font = FontRef::new(font_test_data::VAZIRMATN_VAR).unwrap();
+        let font_data = write_fonts::FontBuilder::new()
+            .add_raw(Tag::new(b"cvt "), vec![0u8, 10, 0, 20, 0])
+            .copy_missing_tables(font)
+            .build();
+        let font = FontRef::new(&font_data).unwrap();
+        assert_eq!(font.cvt().unwrap().values().len(), 2);
+        compare_glyphs_for_font(font, font_test_data::VAZIRMATN_VAR_GLYPHS, false);
+    }
+
     fn compare_glyphs(font_data: &[u8], expected_outlines: &str, is_cff: bool) {
         let font = FontRef::new(font_data).unwrap();
+        compare_glyphs_for_font(font, expected_outlines, is_cff);
+    }
+
+    fn compare_glyphs_for_font(font: FontRef, expected_outlines: &str, is_cff: bool) {
         let outlines = scaler_test::parse_glyph_outlines(expected_outlines);
         let mut cx = Context::new();
         let mut path = scaler_test::Path {
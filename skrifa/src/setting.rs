@@ -15,9 +15,10 @@ use core::str::FromStr;
 /// CSS property [font-variation-settings](https://developer.mozilla.org/en-US/docs/Web/CSS/font-variation-settings)).
 /// See [`VariationSetting`].
 ///
-/// In the future, this will likely also be used for specifying feature settings
-/// (analogous to the CSS property [font-feature-settings](https://developer.mozilla.org/en-US/docs/Web/CSS/font-feature-settings))
+/// It is also used for specifying feature settings (analogous to the CSS
+/// property [font-feature-settings](https://developer.mozilla.org/en-US/docs/Web/CSS/font-feature-settings))
 /// for selecting OpenType [features](https://learn.microsoft.com/en-us/typography/opentype/spec/featuretags).
+/// See [`FeatureSetting`].
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct Setting<T> {
     /// Tag that specifies the target setting.
@@ -95,3 +96,22 @@ impl<T: Copy> From<&(&str, T)> for Setting<T> {
 /// assert_eq!(slightly_bolder, VariationSetting::new(Tag::new(b"wght"), 720.0));
 /// ```
 pub type VariationSetting = Setting<f32>;
+
+/// Type for specifying an OpenType feature setting.
+///
+/// The `selector` field should contain a feature tag while the `value`
+/// field specifies the desired state of the feature. A value of 0 disables
+/// the feature and 1 enables it. For features that select from a set of
+/// alternates (such as `aalt` or `salt`), values greater than 1 select the
+/// alternate at index `value - 1`.
+///
+/// # Example
+/// ```
+/// use skrifa::{Tag, setting::FeatureSetting};
+///
+/// // For convenience, a conversion from (&str, u16) is provided.
+/// let no_ligatures: FeatureSetting = ("liga", 0).into();
+///
+/// assert_eq!(no_ligatures, FeatureSetting::new(Tag::new(b"liga"), 0));
+/// ```
+pub type FeatureSetting = Setting<u16>;
@@ -0,0 +1,479 @@
+//! State and common operations for lookup application.
+
+use read_fonts::{
+    tables::{
+        gdef::Gdef,
+        gpos::{DeviceOrVariationIndex, PositionLookupList},
+        gsub::SubstitutionLookupList,
+        layout::{Lookup, LookupFlag},
+        variations::{DeltaSetIndex, ItemVariationStoreInstance},
+    },
+    types::{F2Dot14, GlyphId},
+    ReadError, TableProvider,
+};
+
+use super::{
+    buffer::{GlyphBuffer, GlyphInfo, CLASS_LIGATURE, CLASS_MARK},
+    gpos, gsub,
+    plan::LookupEntry,
+};
+
+/// Maximum depth of nested lookups invoked by contextual lookups and of
+/// glyph attachment chains.
+pub(super) const MAX_NESTING_LEVEL: usize = 64;
+
+/// Maximum number of glyphs matched by a single contextual rule.
+pub(super) const MAX_CONTEXT_LENGTH: usize = 64;
+
+/// Tables from the font that are required for applying lookups.
+#[derive(Clone, Default)]
+pub(super) struct LayoutData<'a> {
+    pub gdef: Option<Gdef<'a>>,
+    pub var_store: Option<ItemVariationStoreInstance<'a>>,
+    pub coords: &'a [F2Dot14],
+    pub gsub: Option<SubstitutionLookupList<'a>>,
+    pub gpos: Option<PositionLookupList<'a>>,
+}
+
+impl<'a> LayoutData<'a> {
+    pub fn new(font: &impl TableProvider<'a>, coords: &'a [F2Dot14]) -> Self {
+        let gdef = font.gdef().ok();
+        let var_store = gdef
+            .as_ref()
+            .and_then(|gdef| gdef.item_var_store())
+            .and_then(|store| store.ok())
+            .filter(|_| !coords.is_empty())
+            .and_then(|store| ItemVariationStoreInstance::new(store, coords).ok());
+        Self {
+            gdef,
+            var_store,
+            coords,
+            gsub: font.gsub().and_then(|gsub| gsub.lookup_list()).ok(),
+            gpos: font.gpos().and_then(|gpos| gpos.lookup_list()).ok(),
+        }
+    }
+
+    /// Updates the glyph and mark attachment classes for the given glyph.
+    ///
+    /// If the font does not provide a glyph class definition table,
+    /// `fallback_class` is used.
+    pub fn set_glyph_props(&self, info: &mut GlyphInfo, fallback_class: u8) {
+        let glyph_id = info.glyph_id;
+        let gdef = self.gdef.as_ref();
+        info.class = match gdef.and_then(|gdef| gdef.glyph_class_def()) {
+            Some(Ok(class_def)) => class_def.get(glyph_id) as u8,
+            _ => fallback_class,
+        };
+        info.mark_class = match gdef.and_then(|gdef| gdef.mark_attach_class_def()) {
+            Some(Ok(class_def)) if info.class == CLASS_MARK => class_def.get(glyph_id) as u8,
+            _ => 0,
+        };
+    }
+
+    /// Returns true if the glyph is in the mark glyph set at the given index.
+    pub fn is_in_mark_set(&self, set_index: u16, glyph_id: GlyphId) -> bool {
+        self.gdef
+            .as_ref()
+            .and_then(|gdef| gdef.mark_glyph_sets_def())
+            .and_then(|sets| sets.ok()?.coverages().get(set_index as usize).ok())
+            .map(|coverage| coverage.get(glyph_id).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Computes the variation delta for an optional device table.
+    ///
+    /// Device tables provide adjustments for hinting at specific sizes and
+    /// are ignored.
+    pub fn delta(&self, device: Option<Result<DeviceOrVariationIndex, ReadError>>) -> f32 {
+        if self.coords.is_empty() {
+            return 0.0;
+        }
+        match (device, self.var_store.as_ref()) {
+            (Some(Ok(DeviceOrVariationIndex::VariationIndex(index))), Some(store)) => store
+                .compute_delta(DeltaSetIndex {
+                    outer: index.delta_set_outer_index(),
+                    inner: index.delta_set_inner_index(),
+                })
+                .unwrap_or_default()
+                as f32,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Selects which table is being applied.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(super) enum TableKind {
+    Gsub,
+    Gpos,
+}
+
+/// Mutable state for applying a sequence of lookups to a buffer.
+pub(super) struct ApplyContext<'a, 'b> {
+    pub data: &'b LayoutData<'a>,
+    pub buffer: &'b mut GlyphBuffer,
+    /// Index of the current glyph.
+    pub idx: usize,
+    kind: TableKind,
+    /// Mask of the feature(s) that selected the current lookup.
+    pub lookup_mask: u32,
+    /// Value of the feature that selected the current lookup.
+    pub feature_value: u16,
+    pub lookup_flag: LookupFlag,
+    /// Mark filtering set for the current lookup.
+    pub mark_set: Option<u16>,
+    nesting_level: usize,
+}
+
+impl<'a, 'b> ApplyContext<'a, 'b> {
+    pub fn new(data: &'b LayoutData<'a>, buffer: &'b mut GlyphBuffer, kind: TableKind) -> Self {
+        Self {
+            data,
+            buffer,
+            idx: 0,
+            kind,
+            lookup_mask: 0,
+            feature_value: 0,
+            lookup_flag: LookupFlag::empty(),
+            mark_set: None,
+            nesting_level: 0,
+        }
+    }
+
+    /// Applies the lookup described by `entry` to the entire buffer.
+    pub fn apply_lookup(&mut self, entry: &LookupEntry) {
+        self.lookup_mask = entry.mask;
+        self.feature_value = entry.value;
+        self.nesting_level = 0;
+        match self.kind {
+            TableKind::Gsub => {
+                let Some(Ok(lookup)) = self
+                    .data
+                    .gsub
+                    .as_ref()
+                    .map(|list| list.lookups().get(entry.index as usize))
+                else {
+                    return;
+                };
+                let (flag, mark_set) = gsub::lookup_props(&lookup);
+                self.set_lookup_props(flag, mark_set);
+                if gsub::is_reverse(&lookup) {
+                    // reverse chaining lookups are applied from the end of
+                    // the buffer and never change its length
+                    for idx in (0..self.len()).rev() {
+                        if !self.buffer.take_op() {
+                            break;
+                        }
+                        self.idx = idx;
+                        if self.may_apply(idx) {
+                            gsub::apply(self, &lookup);
+                        }
+                    }
+                } else {
+                    self.idx = 0;
+                    while self.idx < self.len() && self.buffer.take_op() {
+                        if !(self.may_apply(self.idx) && gsub::apply(self, &lookup)) {
+                            self.idx += 1;
+                        }
+                    }
+                }
+            }
+            TableKind::Gpos => {
+                let Some(Ok(lookup)) = self
+                    .data
+                    .gpos
+                    .as_ref()
+                    .map(|list| list.lookups().get(entry.index as usize))
+                else {
+                    return;
+                };
+                let (flag, mark_set) = gpos::lookup_props(&lookup);
+                self.set_lookup_props(flag, mark_set);
+                self.idx = 0;
+                while self.idx < self.len() && self.buffer.take_op() {
+                    if !(self.may_apply(self.idx) && gpos::apply(self, &lookup)) {
+                        self.idx += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Applies the lookup at the given index to the current glyph.
+    ///
+    /// This is invoked by contextual lookups.
+    pub fn recurse(&mut self, lookup_index: u16) -> bool {
+        if self.nesting_level >= MAX_NESTING_LEVEL
+            || self.idx >= self.len()
+            || !self.buffer.take_op()
+        {
+            return false;
+        }
+        let saved_props = (self.lookup_flag, self.mark_set);
+        self.nesting_level += 1;
+        let applied = match self.kind {
+            TableKind::Gsub => match self
+                .data
+                .gsub
+                .as_ref()
+                .map(|list| list.lookups().get(lookup_index as usize))
+            {
+                // reverse chaining lookups cannot be nested
+                Some(Ok(lookup)) if !gsub::is_reverse(&lookup) => {
+                    let (flag, mark_set) = gsub::lookup_props(&lookup);
+                    self.set_lookup_props(flag, mark_set);
+                    !self.should_ignore(&self.buffer.infos[self.idx]) && gsub::apply(self, &lookup)
+                }
+                _ => false,
+            },
+            TableKind::Gpos => match self
+                .data
+                .gpos
+                .as_ref()
+                .map(|list| list.lookups().get(lookup_index as usize))
+            {
+                Some(Ok(lookup)) => {
+                    let (flag, mark_set) = gpos::lookup_props(&lookup);
+                    self.set_lookup_props(flag, mark_set);
+                    !self.should_ignore(&self.buffer.infos[self.idx]) && gpos::apply(self, &lookup)
+                }
+                _ => false,
+            },
+        };
+        self.nesting_level -= 1;
+        (self.lookup_flag, self.mark_set) = saved_props;
+        applied
+    }
+
+    fn set_lookup_props(&mut self, flag: LookupFlag, mark_set: Option<u16>) {
+        self.lookup_flag = flag;
+        self.mark_set = mark_set;
+    }
+
+    /// Returns the number of glyphs in the buffer.
+    pub fn len(&self) -> usize {
+        self.buffer.infos.len()
+    }
+
+    /// Returns the glyph identifier at the given index.
+    pub fn glyph(&self, idx: usize) -> GlyphId {
+        self.buffer.infos[idx].glyph_id
+    }
+
+    /// Returns true if the current lookup can be applied to the glyph at
+    /// the given index.
+    fn may_apply(&self, idx: usize) -> bool {
+        let info = &self.buffer.infos[idx];
+        info.mask & self.lookup_mask != 0 && !self.should_ignore(info)
+    }
+
+    /// Returns true if the glyph at the given index was selected by the
+    /// features associated with the current lookup.
+    pub fn matches_mask(&self, idx: usize) -> bool {
+        self.buffer.infos[idx].mask & self.lookup_mask != 0
+    }
+
+    /// Returns true if the glyph should be skipped according to the flags
+    /// of the current lookup.
+    pub fn should_ignore(&self, info: &GlyphInfo) -> bool {
+        self.should_ignore_with_flag(info, self.lookup_flag)
+    }
+
+    fn should_ignore_with_flag(&self, info: &GlyphInfo, flag: LookupFlag) -> bool {
+        match info.class {
+            CLASS_MARK => {
+                if flag.ignore_marks() {
+                    return true;
+                }
+                if let Some(set) = self.mark_set {
+                    return !self.data.is_in_mark_set(set, info.glyph_id);
+                }
+                if let Some(mark_class) = flag.mark_attachment_type_mask() {
+                    return mark_class != info.mark_class as u16;
+                }
+                false
+            }
+            CLASS_LIGATURE => flag.ignore_ligatures(),
+            _ => flag.ignore_base_glyphs() && info.is_base(),
+        }
+    }
+
+    /// Returns the index of the next glyph after `idx` that is not skipped
+    /// by the current lookup.
+    pub fn next_glyph(&self, idx: usize) -> Option<usize> {
+        (idx + 1..self.len()).find(|&i| !self.should_ignore(&self.buffer.infos[i]))
+    }
+
+    /// Returns the index of the previous glyph before `idx` that is not
+    /// skipped by the current lookup.
+    pub fn prev_glyph(&self, idx: usize) -> Option<usize> {
+        (0..idx)
+            .rev()
+            .find(|&i| !self.should_ignore(&self.buffer.infos[i]))
+    }
+
+    /// Returns the index of the previous glyph before `idx` that is not
+    /// skipped when the ignore flags of the current lookup are cleared.
+    ///
+    /// This only skips marks that are excluded by a mark filtering set
+    /// or mark attachment type and is used for mark to mark attachment.
+    pub fn prev_glyph_unfiltered(&self, idx: usize) -> Option<usize> {
+        let mut flag = self.lookup_flag;
+        flag.set_ignore_base_glyphs(false);
+        flag.set_ignore_ligatures(false);
+        flag.set_ignore_marks(false);
+        (0..idx)
+            .rev()
+            .find(|&i| !self.should_ignore_with_flag(&self.buffer.infos[i], flag))
+    }
+
+    /// Matches a sequence of `count` glyphs following the current glyph
+    /// using the given predicate.
+    ///
+    /// On success, returns the positions of the current glyph and each
+    /// matched glyph along with the index one past the last match.
+    pub fn match_input(
+        &self,
+        count: usize,
+        mut f: impl FnMut(usize, GlyphId) -> bool,
+    ) -> Option<(Vec<usize>, usize)> {
+        if count + 1 > MAX_CONTEXT_LENGTH {
+            return None;
+        }
+        let mut positions = Vec::with_capacity(count + 1);
+        positions.push(self.idx);
+        let mut idx = self.idx;
+        for i in 0..count {
+            idx = self.next_glyph(idx)?;
+            if !self.matches_mask(idx) || !f(i, self.glyph(idx)) {
+                return None;
+            }
+            positions.push(idx);
+        }
+        Some((positions, idx + 1))
+    }
+
+    /// Matches `count` glyphs preceding the current glyph using the given
+    /// predicate. The predicate receives glyphs in reverse order, starting
+    /// with the one closest to the current glyph.
+    pub fn match_backtrack(&self, count: usize, mut f: impl FnMut(usize, GlyphId) -> bool) -> bool {
+        let mut idx = self.idx;
+        for i in 0..count {
+            match self.prev_glyph(idx) {
+                Some(prev) if f(i, self.glyph(prev)) => idx = prev,
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Matches `count` glyphs starting at `end` using the given predicate.
+    pub fn match_lookahead(
+        &self,
+        end: usize,
+        count: usize,
+        mut f: impl FnMut(usize, GlyphId) -> bool,
+    ) -> bool {
+        // next_glyph() searches after the given index and end is always
+        // past the current glyph
+        let mut idx = end - 1;
+        for i in 0..count {
+            match self.next_glyph(idx) {
+                Some(next) if f(i, self.glyph(next)) => idx = next,
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Replaces the current glyph and advances to the next.
+    pub fn replace_glyph(&mut self, glyph_id: GlyphId) {
+        let info = &mut self.buffer.infos[self.idx];
+        let fallback_class = info.class;
+        info.glyph_id = glyph_id;
+        self.data.set_glyph_props(info, fallback_class);
+        self.idx += 1;
+    }
+
+    /// Replaces the current glyph with a sequence of glyphs and advances
+    /// past the sequence.
+    ///
+    /// An empty sequence deletes the current glyph. Returns false and leaves
+    /// the buffer unchanged if the sequence would grow the buffer beyond its
+    /// maximum length.
+    pub fn replace_glyphs(&mut self, glyph_ids: impl ExactSizeIterator<Item = GlyphId>) -> bool {
+        if self.len() - 1 + glyph_ids.len() > self.buffer.max_len {
+            return false;
+        }
+        let template = self.buffer.infos.remove(self.idx);
+        let start = self.idx;
+        let len = self.len();
+        let data = self.data;
+        self.buffer.infos.splice(
+            start..start,
+            glyph_ids.map(|glyph_id| {
+                let mut info = GlyphInfo {
+                    glyph_id,
+                    ..template
+                };
+                data.set_glyph_props(&mut info, template.class);
+                info
+            }),
+        );
+        // advance past the inserted glyphs
+        self.idx = start + (self.len() - len);
+        true
+    }
+
+    /// Replaces the glyphs at the given positions with a ligature glyph.
+    ///
+    /// Marks between the matched components are retained and associated
+    /// with their respective components for later mark to ligature
+    /// positioning.
+    pub fn ligate(&mut self, positions: &[usize], end: usize, glyph_id: GlyphId) {
+        let first = positions[0];
+        let infos = &mut self.buffer.infos;
+        let is_mark_ligature = positions.iter().all(|&i| infos[i].class == CLASS_MARK);
+        // all glyphs in the matched range become part of the same cluster
+        let cluster = infos[first..end]
+            .iter()
+            .map(|info| info.cluster)
+            .min()
+            .unwrap_or_default();
+        for info in &mut infos[first..end] {
+            info.cluster = cluster;
+        }
+        let lig_id = if is_mark_ligature {
+            0
+        } else {
+            self.buffer.next_lig_id()
+        };
+        let infos = &mut self.buffer.infos;
+        for (component, range) in positions.windows(2).enumerate() {
+            for info in &mut infos[range[0] + 1..range[1]] {
+                if lig_id != 0 && info.class == CLASS_MARK {
+                    info.lig_id = lig_id;
+                    info.lig_comp = (component + 1).min(u8::MAX as usize) as u8;
+                }
+            }
+        }
+        let info = &mut infos[first];
+        info.glyph_id = glyph_id;
+        info.lig_id = lig_id;
+        info.lig_comp = 0;
+        self.data.set_glyph_props(info, CLASS_LIGATURE);
+        for &idx in positions[1..].iter().rev() {
+            infos.remove(idx);
+        }
+        self.idx = end - (positions.len() - 1);
+    }
+}
+
+/// Returns the lookup flag and optional mark filtering set for a lookup.
+pub(super) fn lookup_props<T>(lookup: &Lookup<T>) -> (LookupFlag, Option<u16>) {
+    let flag = lookup.lookup_flag();
+    let mark_set = flag
+        .use_mark_filtering_set()
+        .then(|| lookup.mark_filtering_set());
+    (flag, mark_set)
+}
@@ -0,0 +1,200 @@
+//! Glyph buffer used as input and output for shaping.
+
+use read_fonts::types::GlyphId;
+
+/// Glyph class assigned to glyphs without GDEF class information.
+pub(super) const CLASS_UNCLASSIFIED: u8 = 0;
+/// Base glyph (single character, spacing glyph).
+pub(super) const CLASS_BASE: u8 = 1;
+/// Ligature glyph (multiple character, spacing glyph).
+pub(super) const CLASS_LIGATURE: u8 = 2;
+/// Mark glyph (non-spacing combining glyph).
+pub(super) const CLASS_MARK: u8 = 3;
+
+/// Attachment of a glyph to a cursive parent.
+pub(super) const ATTACH_CURSIVE: u8 = 1;
+/// Attachment of a mark glyph to a base, ligature or mark.
+pub(super) const ATTACH_MARK: u8 = 2;
+
+/// Factor applied to the initial length of the buffer to limit its growth
+/// during substitution.
+const MAX_LEN_FACTOR: usize = 64;
+/// Minimum limit on the length of the buffer.
+const MAX_LEN_MIN: usize = 16384;
+/// Factor applied to the initial length of the buffer to limit the number
+/// of lookup applications.
+const MAX_OPS_FACTOR: usize = 1024;
+/// Minimum limit on the number of lookup applications.
+const MAX_OPS_MIN: usize = 16384;
+
+/// Sequence of glyphs with associated positioning information.
+///
+/// This serves as both the input and output for the
+/// [`Shaper`](super::Shaper). On input, each glyph carries a cluster value
+/// that is used to select the range of glyphs to which a feature applies
+/// and to track the mapping between glyphs and the source text. On output,
+/// glyphs have been substituted and positioned according to the layout
+/// tables of the font.
+#[derive(Clone, Default, Debug)]
+pub struct GlyphBuffer {
+    pub(super) infos: Vec<GlyphInfo>,
+    pub(super) positions: Vec<GlyphPosition>,
+    next_lig_id: u8,
+    /// Maximum number of glyphs allowed during substitution.
+    pub(super) max_len: usize,
+    /// Remaining number of lookup applications.
+    pub(super) max_ops: usize,
+}
+
+impl GlyphBuffer {
+    /// Creates a new empty glyph buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a glyph with the given cluster value to the buffer.
+    pub fn push(&mut self, glyph_id: GlyphId, cluster: u32) {
+        self.infos.push(GlyphInfo {
+            glyph_id,
+            cluster,
+            ..Default::default()
+        });
+    }
+
+    /// Returns the number of glyphs in the buffer.
+    pub fn len(&self) -> usize {
+        self.infos.len()
+    }
+
+    /// Returns true if the buffer contains no glyphs.
+    pub fn is_empty(&self) -> bool {
+        self.infos.is_empty()
+    }
+
+    /// Removes all glyphs from the buffer.
+    pub fn clear(&mut self) {
+        self.infos.clear();
+        self.positions.clear();
+        self.next_lig_id = 0;
+    }
+
+    /// Returns the glyphs in the buffer.
+    pub fn glyphs(&self) -> &[GlyphInfo] {
+        &self.infos
+    }
+
+    /// Returns the positions of the glyphs in the buffer.
+    ///
+    /// This is empty until the buffer has been shaped.
+    pub fn positions(&self) -> &[GlyphPosition] {
+        &self.positions
+    }
+
+    /// Returns an iterator over pairs of glyphs and their associated
+    /// positions.
+    pub fn iter(&self) -> impl Iterator<Item = (&GlyphInfo, &GlyphPosition)> + '_ + Clone {
+        self.infos.iter().zip(&self.positions)
+    }
+
+    /// Resets the limits that protect against fonts with lookups that grow
+    /// the buffer or recurse without bound.
+    ///
+    /// The limits are proportional to the current length of the buffer.
+    pub(super) fn reset_limits(&mut self) {
+        let len = self.infos.len();
+        self.max_len = len.saturating_mul(MAX_LEN_FACTOR).max(MAX_LEN_MIN);
+        self.max_ops = len.saturating_mul(MAX_OPS_FACTOR).max(MAX_OPS_MIN);
+    }
+
+    /// Consumes one lookup application and returns false if the limit has
+    /// been reached.
+    pub(super) fn take_op(&mut self) -> bool {
+        if self.max_ops == 0 {
+            return false;
+        }
+        self.max_ops -= 1;
+        true
+    }
+
+    /// Allocates a new identifier for a ligature.
+    ///
+    /// The identifier is used to associate marks with ligature components
+    /// and is never zero.
+    pub(super) fn next_lig_id(&mut self) -> u8 {
+        self.next_lig_id = self.next_lig_id.wrapping_add(1);
+        if self.next_lig_id == 0 {
+            self.next_lig_id = 1;
+        }
+        self.next_lig_id
+    }
+}
+
+/// Glyph identifier and associated cluster value.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct GlyphInfo {
+    pub(super) glyph_id: GlyphId,
+    pub(super) cluster: u32,
+    /// Set of features that apply to this glyph.
+    pub(super) mask: u32,
+    /// Class from the GDEF glyph class definition table.
+    pub(super) class: u8,
+    /// Class from the GDEF mark attachment class definition table.
+    pub(super) mark_class: u8,
+    /// Identifier of the ligature that this glyph belongs to or, for marks,
+    /// the ligature that this mark is attached to.
+    pub(super) lig_id: u8,
+    /// For marks, the 1-based index of the ligature component that this
+    /// mark belongs to.
+    pub(super) lig_comp: u8,
+}
+
+impl GlyphInfo {
+    /// Returns the glyph identifier.
+    pub fn glyph_id(&self) -> GlyphId {
+        self.glyph_id
+    }
+
+    /// Returns the cluster value.
+    ///
+    /// When shaping text, this is the byte offset in the source string of
+    /// the first character that produced this glyph.
+    pub fn cluster(&self) -> u32 {
+        self.cluster
+    }
+
+    /// Returns true if this glyph is classified as a mark in the GDEF
+    /// table.
+    pub fn is_mark(&self) -> bool {
+        self.class == CLASS_MARK
+    }
+
+    /// Returns true if this glyph was produced by a ligature substitution.
+    pub fn is_ligature(&self) -> bool {
+        self.class == CLASS_LIGATURE
+    }
+
+    pub(super) fn is_base(&self) -> bool {
+        self.class == CLASS_BASE || self.class == CLASS_UNCLASSIFIED
+    }
+}
+
+/// Advance and offset for a positioned glyph.
+///
+/// All values are scaled according to the size provided when constructing
+/// the [`Shaper`](super::Shaper), and are expressed in a coordinate system
+/// where the y-axis points up.
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub struct GlyphPosition {
+    /// Horizontal distance to advance after rendering the glyph.
+    pub x_advance: f32,
+    /// Vertical distance to advance after rendering the glyph.
+    pub y_advance: f32,
+    /// Horizontal offset applied when rendering the glyph.
+    pub x_offset: f32,
+    /// Vertical offset applied when rendering the glyph.
+    pub y_offset: f32,
+    /// Relative index of the glyph that this glyph is attached to.
+    pub(super) attach_chain: i16,
+    /// Type of attachment: either [`ATTACH_CURSIVE`] or [`ATTACH_MARK`].
+    pub(super) attach_kind: u8,
+}
@@ -0,0 +1,252 @@
+//! Contextual and chained contextual lookups shared by GSUB and GPOS.
+
+use read_fonts::{
+    tables::layout::{
+        ChainedSequenceContext, ClassDef, CoverageTable, SequenceContext, SequenceLookupRecord,
+    },
+    types::{BigEndian, GlyphId},
+    ArrayOfOffsets, ReadError,
+};
+
+use super::apply::{ApplyContext, MAX_CONTEXT_LENGTH};
+
+/// Applies a (non-chained) sequence context subtable at the current glyph.
+pub(super) fn apply_context(ctx: &mut ApplyContext, context: &SequenceContext) -> bool {
+    let glyph_id = ctx.glyph(ctx.idx);
+    match context {
+        SequenceContext::Format1(table) => {
+            let Some(Some(Ok(rule_set))) = covered(table.coverage(), glyph_id)
+                .map(|idx| table.seq_rule_sets().get(idx as usize))
+            else {
+                return false;
+            };
+            for rule in rule_set.seq_rules().iter().flatten() {
+                let input = rule.input_sequence();
+                if let Some(matched) = ctx.match_input(input.len(), |i, g| input[i].get() == g) {
+                    apply_lookup_records(ctx, rule.seq_lookup_records(), matched);
+                    return true;
+                }
+            }
+            false
+        }
+        SequenceContext::Format2(table) => {
+            let Ok(class_def) = table.class_def() else {
+                return false;
+            };
+            if covered(table.coverage(), glyph_id).is_none() {
+                return false;
+            }
+            let class = class_def.get(glyph_id);
+            let Some(Ok(rule_set)) = table.class_seq_rule_sets().get(class as usize) else {
+                return false;
+            };
+            for rule in rule_set.class_seq_rules().iter().flatten() {
+                let input = rule.input_sequence();
+                if let Some(matched) =
+                    ctx.match_input(input.len(), |i, g| input[i].get() == class_def.get(g))
+                {
+                    apply_lookup_records(ctx, rule.seq_lookup_records(), matched);
+                    return true;
+                }
+            }
+            false
+        }
+        SequenceContext::Format3(table) => {
+            let coverages = table.coverages();
+            if covered(coverages.get(0), glyph_id).is_none() {
+                return false;
+            }
+            let Some(matched) = ctx.match_input(coverages.len().saturating_sub(1), |i, g| {
+                covered(coverages.get(i + 1), g).is_some()
+            }) else {
+                return false;
+            };
+            apply_lookup_records(ctx, table.seq_lookup_records(), matched);
+            true
+        }
+    }
+}
+
+/// Applies a chained sequence context subtable at the current glyph.
+pub(super) fn apply_chain_context(
+    ctx: &mut ApplyContext,
+    context: &ChainedSequenceContext,
+) -> bool {
+    let glyph_id = ctx.glyph(ctx.idx);
+    match context {
+        ChainedSequenceContext::Format1(table) => {
+            let Some(Some(Ok(rule_set))) = covered(table.coverage(), glyph_id)
+                .map(|idx| table.chained_seq_rule_sets().get(idx as usize))
+            else {
+                return false;
+            };
+            for rule in rule_set.chained_seq_rules().iter().flatten() {
+                let backtrack = rule.backtrack_sequence();
+                let input = rule.input_sequence();
+                let lookahead = rule.lookahead_sequence();
+                if let Some(matched) = match_chain(
+                    ctx,
+                    (backtrack.len(), |i, g| backtrack[i].get() == g),
+                    (input.len(), |i, g| input[i].get() == g),
+                    (lookahead.len(), |i, g| lookahead[i].get() == g),
+                ) {
+                    apply_lookup_records(ctx, rule.seq_lookup_records(), matched);
+                    return true;
+                }
+            }
+            false
+        }
+        ChainedSequenceContext::Format2(table) => {
+            let (Ok(backtrack_classes), Ok(input_classes), Ok(lookahead_classes)) = (
+                table.backtrack_class_def(),
+                table.input_class_def(),
+                table.lookahead_class_def(),
+            ) else {
+                return false;
+            };
+            if covered(table.coverage(), glyph_id).is_none() {
+                return false;
+            }
+            let class = input_classes.get(glyph_id);
+            let Some(Ok(rule_set)) = table.chained_class_seq_rule_sets().get(class as usize) else {
+                return false;
+            };
+            for rule in rule_set.chained_class_seq_rules().iter().flatten() {
+                let backtrack = rule.backtrack_sequence();
+                let input = rule.input_sequence();
+                let lookahead = rule.lookahead_sequence();
+                if let Some(matched) = match_chain(
+                    ctx,
+                    (
+                        backtrack.len(),
+                        class_matcher(backtrack, &backtrack_classes),
+                    ),
+                    (input.len(), class_matcher(input, &input_classes)),
+                    (
+                        lookahead.len(),
+                        class_matcher(lookahead, &lookahead_classes),
+                    ),
+                ) {
+                    apply_lookup_records(ctx, rule.seq_lookup_records(), matched);
+                    return true;
+                }
+            }
+            false
+        }
+        ChainedSequenceContext::Format3(table) => {
+            let input = table.input_coverages();
+            if covered(input.get(0), glyph_id).is_none() {
+                return false;
+            }
+            let backtrack = table.backtrack_coverages();
+            let lookahead = table.lookahead_coverages();
+            let Some(matched) = match_chain(
+                ctx,
+                (backtrack.len(), coverage_matcher(&backtrack, 0)),
+                (input.len().saturating_sub(1), coverage_matcher(&input, 1)),
+                (lookahead.len(), coverage_matcher(&lookahead, 0)),
+            ) else {
+                return false;
+            };
+            apply_lookup_records(ctx, table.seq_lookup_records(), matched);
+            true
+        }
+    }
+}
+
+/// Returns the coverage index of the glyph if the coverage table was
+/// successfully read and contains the glyph.
+pub(super) fn covered(
+    coverage: Result<CoverageTable, ReadError>,
+    glyph_id: GlyphId,
+) -> Option<u16> {
+    coverage.ok()?.get(glyph_id)
+}
+
+/// Returns a predicate that matches a sequence of coverage tables,
+/// beginning at `start`.
+pub(super) fn coverage_matcher<'a>(
+    coverages: &'a ArrayOfOffsets<'a, CoverageTable<'a>>,
+    start: usize,
+) -> impl FnMut(usize, GlyphId) -> bool + 'a {
+    move |i, g| covered(coverages.get(i + start), g).is_some()
+}
+
+fn class_matcher<'a>(
+    classes: &'a [BigEndian<u16>],
+    class_def: &'a ClassDef<'a>,
+) -> impl FnMut(usize, GlyphId) -> bool + 'a {
+    move |i, g| classes[i].get() == class_def.get(g)
+}
+
+/// Matches the backtrack, input and lookahead sequences of a chained rule.
+///
+/// Each sequence is described by a glyph count and a matching predicate.
+/// Note that the input count excludes the current glyph.
+fn match_chain(
+    ctx: &ApplyContext,
+    backtrack: (usize, impl FnMut(usize, GlyphId) -> bool),
+    input: (usize, impl FnMut(usize, GlyphId) -> bool),
+    lookahead: (usize, impl FnMut(usize, GlyphId) -> bool),
+) -> Option<(Vec<usize>, usize)> {
+    let (positions, end) = ctx.match_input(input.0, input.1)?;
+    (ctx.match_backtrack(backtrack.0, backtrack.1)
+        && ctx.match_lookahead(end, lookahead.0, lookahead.1))
+    .then_some((positions, end))
+}
+
+/// Applies the nested lookups of a matched rule and moves the current
+/// position past the matched sequence.
+fn apply_lookup_records(
+    ctx: &mut ApplyContext,
+    records: &[SequenceLookupRecord],
+    (mut positions, end): (Vec<usize>, usize),
+) {
+    let mut end = end as isize;
+    for record in records {
+        if ctx.buffer.max_ops == 0 {
+            break;
+        }
+        let idx = record.sequence_index() as usize;
+        if idx >= positions.len() {
+            continue;
+        }
+        let orig_len = ctx.len() as isize;
+        ctx.idx = positions[idx];
+        if !ctx.recurse(record.lookup_list_index()) {
+            continue;
+        }
+        let mut delta = ctx.len() as isize - orig_len;
+        if delta == 0 {
+            continue;
+        }
+        // the nested lookup changed the length of the buffer (by a multiple
+        // or ligature substitution) so adjust the remaining positions
+        end += delta;
+        let pos = positions[idx] as isize;
+        if end < pos {
+            // a ligature consumed glyphs beyond the end of the match
+            delta += pos - end;
+            end = pos;
+        }
+        let next = idx + 1;
+        if delta > 0 {
+            let count = delta as usize;
+            if positions.len() + count > MAX_CONTEXT_LENGTH {
+                break;
+            }
+            let start = positions[idx];
+            positions.splice(next..next, (1..=count).map(|i| start + i));
+            for pos in &mut positions[next + count..] {
+                *pos = (*pos as isize + delta) as usize;
+            }
+        } else {
+            let delta = delta.max(next as isize - positions.len() as isize);
+            positions.drain(next..next + (-delta) as usize);
+            for pos in &mut positions[next..] {
+                *pos = (*pos as isize + delta) as usize;
+            }
+        }
+    }
+    ctx.idx = (end as usize).min(ctx.len());
+}
@@ -0,0 +1,518 @@
+//! Application of glyph positioning lookups.
+
+use read_fonts::{
+    tables::{
+        gpos::{
+            AnchorTable, CursivePosFormat1, ExtensionSubtable, MarkArray, MarkBasePosFormat1,
+            MarkLigPosFormat1, MarkMarkPosFormat1, PairPos, PositionLookup, SinglePos, ValueRecord,
+        },
+        layout::LookupFlag,
+    },
+    types::GlyphId,
+    FontData, ReadError,
+};
+
+use super::{
+    apply::{self, ApplyContext, LayoutData},
+    buffer::{ATTACH_CURSIVE, ATTACH_MARK},
+    context::{apply_chain_context, apply_context, covered},
+};
+
+/// Returns the lookup flag and optional mark filtering set for a lookup.
+pub(super) fn lookup_props(lookup: &PositionLookup) -> (LookupFlag, Option<u16>) {
+    match lookup {
+        PositionLookup::Single(lookup) => apply::lookup_props(lookup),
+        PositionLookup::Pair(lookup) => apply::lookup_props(lookup),
+        PositionLookup::Cursive(lookup) => apply::lookup_props(lookup),
+        PositionLookup::MarkToBase(lookup) => apply::lookup_props(lookup),
+        PositionLookup::MarkToLig(lookup) => apply::lookup_props(lookup),
+        PositionLookup::MarkToMark(lookup) => apply::lookup_props(lookup),
+        PositionLookup::Contextual(lookup) => apply::lookup_props(lookup),
+        PositionLookup::ChainContextual(lookup) => apply::lookup_props(lookup),
+        PositionLookup::Extension(lookup) => apply::lookup_props(lookup),
+    }
+}
+
+/// Applies the first matching subtable of the lookup at the current glyph.
+///
+/// Returns true if the glyph was positioned, in which case the current
+/// position has been advanced appropriately.
+pub(super) fn apply(ctx: &mut ApplyContext, lookup: &PositionLookup) -> bool {
+    match lookup {
+        PositionLookup::Single(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_single(ctx, &subtable)),
+        PositionLookup::Pair(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_pair(ctx, &subtable)),
+        PositionLookup::Cursive(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_cursive(ctx, &subtable)),
+        PositionLookup::MarkToBase(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_mark_to_base(ctx, &subtable)),
+        PositionLookup::MarkToLig(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_mark_to_lig(ctx, &subtable)),
+        PositionLookup::MarkToMark(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_mark_to_mark(ctx, &subtable)),
+        PositionLookup::Contextual(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_context(ctx, &subtable)),
+        PositionLookup::ChainContextual(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_chain_context(ctx, &subtable)),
+        PositionLookup::Extension(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_extension(ctx, &subtable)),
+    }
+}
+
+fn apply_extension(ctx: &mut ApplyContext, subtable: &ExtensionSubtable) -> bool {
+    match subtable {
+        ExtensionSubtable::Single(ext) => ext
+            .extension()
+            .map(|subtable| apply_single(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Pair(ext) => ext
+            .extension()
+            .map(|subtable| apply_pair(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Cursive(ext) => ext
+            .extension()
+            .map(|subtable| apply_cursive(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::MarkToBase(ext) => ext
+            .extension()
+            .map(|subtable| apply_mark_to_base(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::MarkToLig(ext) => ext
+            .extension()
+            .map(|subtable| apply_mark_to_lig(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::MarkToMark(ext) => ext
+            .extension()
+            .map(|subtable| apply_mark_to_mark(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Contextual(ext) => ext
+            .extension()
+            .map(|subtable| apply_context(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::ChainContextual(ext) => ext
+            .extension()
+            .map(|subtable| apply_chain_context(ctx, &subtable))
+            .unwrap_or(false),
+    }
+}
+
+fn apply_single(ctx: &mut ApplyContext, subtable: &SinglePos) -> bool {
+    let glyph_id = ctx.glyph(ctx.idx);
+    match subtable {
+        SinglePos::Format1(table) => {
+            if covered(table.coverage(), glyph_id).is_none() {
+                return false;
+            }
+            apply_value(ctx, table.offset_data(), &table.value_record(), ctx.idx);
+        }
+        SinglePos::Format2(table) => {
+            let Some(Ok(record)) = covered(table.coverage(), glyph_id)
+                .map(|idx| table.value_records().get(idx as usize))
+            else {
+                return false;
+            };
+            apply_value(ctx, table.offset_data(), &record, ctx.idx);
+        }
+    }
+    ctx.idx += 1;
+    true
+}
+
+fn apply_pair(ctx: &mut ApplyContext, subtable: &PairPos) -> bool {
+    let first = ctx.glyph(ctx.idx);
+    let Some(second_idx) = ctx.next_glyph(ctx.idx) else {
+        return false;
+    };
+    if !ctx.matches_mask(second_idx) {
+        return false;
+    }
+    let second = ctx.glyph(second_idx);
+    let (data, format2, record1, record2) = match subtable {
+        PairPos::Format1(table) => {
+            let Some(Ok(pair_set)) =
+                covered(table.coverage(), first).map(|idx| table.pair_sets().get(idx as usize))
+            else {
+                return false;
+            };
+            let records = pair_set.pair_value_records();
+            let Some(record) = find_pair(records.len(), second, |idx| {
+                records
+                    .get(idx)
+                    .map(|record| (record.second_glyph(), record))
+            }) else {
+                return false;
+            };
+            // device offsets are relative to the pair set
+            (
+                pair_set.offset_data(),
+                table.value_format2(),
+                record.value_record1().clone(),
+                record.value_record2().clone(),
+            )
+        }
+        PairPos::Format2(table) => {
+            if covered(table.coverage(), first).is_none() {
+                return false;
+            }
+            let (Ok(class_def1), Ok(class_def2)) = (table.class_def1(), table.class_def2()) else {
+                return false;
+            };
+            let class1 = class_def1.get(first);
+            let class2 = class_def2.get(second);
+            if class1 >= table.class1_count() || class2 >= table.class2_count() {
+                return false;
+            }
+            let Ok(record) = table
+                .class1_records()
+                .get(class1 as usize)
+                .and_then(|record| record.class2_records().get(class2 as usize))
+            else {
+                return false;
+            };
+            (
+                table.offset_data(),
+                table.value_format2(),
+                record.value_record1().clone(),
+                record.value_record2().clone(),
+            )
+        }
+    };
+    apply_value(ctx, data, &record1, ctx.idx);
+    apply_value(ctx, data, &record2, second_idx);
+    // if the second glyph was adjusted, it is not considered as the first
+    // glyph of a subsequent pair
+    ctx.idx = if format2.is_empty() {
+        second_idx
+    } else {
+        second_idx + 1
+    };
+    true
+}
+
+/// Binary search for the record associated with the given second glyph.
+fn find_pair<T>(
+    len: usize,
+    glyph_id: GlyphId,
+    get: impl Fn(usize) -> Result<(GlyphId, T), ReadError>,
+) -> Option<T> {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let (mid_glyph, record) = get(mid).ok()?;
+        match mid_glyph.cmp(&glyph_id) {
+            core::cmp::Ordering::Less => lo = mid + 1,
+            core::cmp::Ordering::Greater => hi = mid,
+            core::cmp::Ordering::Equal => return Some(record),
+        }
+    }
+    None
+}
+
+fn apply_cursive(ctx: &mut ApplyContext, subtable: &CursivePosFormat1) -> bool {
+    let data = ctx.data;
+    let records = subtable.entry_exit_record();
+    let Some(entry) = covered(subtable.coverage(), ctx.glyph(ctx.idx))
+        .and_then(|idx| records.get(idx as usize))
+        .and_then(|record| record.entry_anchor(subtable.offset_data()))
+        .and_then(|anchor| anchor.ok())
+    else {
+        return false;
+    };
+    let Some(prev_idx) = ctx.prev_glyph(ctx.idx) else {
+        return false;
+    };
+    if !ctx.matches_mask(prev_idx) {
+        return false;
+    }
+    let Some(exit) = covered(subtable.coverage(), ctx.glyph(prev_idx))
+        .and_then(|idx| records.get(idx as usize))
+        .and_then(|record| record.exit_anchor(subtable.offset_data()))
+        .and_then(|anchor| anchor.ok())
+    else {
+        return false;
+    };
+    let (entry_x, entry_y) = anchor_point(data, &entry);
+    let (exit_x, exit_y) = anchor_point(data, &exit);
+    let i = prev_idx;
+    let j = ctx.idx;
+    let positions = &mut ctx.buffer.positions;
+    // adjust advances so that the exit point of the previous glyph meets
+    // the entry point of the current glyph
+    positions[i].x_advance = exit_x + positions[i].x_offset;
+    let d = entry_x + positions[j].x_offset;
+    positions[j].x_advance -= d;
+    positions[j].x_offset -= d;
+    // the vertical offset is applied to the child glyph which, by default,
+    // is the current glyph
+    let (mut child, mut parent) = (i, j);
+    let mut y_offset = entry_y - exit_y;
+    if !ctx.lookup_flag.right_to_left() {
+        core::mem::swap(&mut child, &mut parent);
+        y_offset = -y_offset;
+    }
+    reverse_cursive_minor_offset(positions, child, parent);
+    positions[child].attach_kind = ATTACH_CURSIVE;
+    positions[child].attach_chain = (parent as isize - child as isize) as i16;
+    positions[child].y_offset = y_offset;
+    // if the parent was attached to the child, break the cycle
+    if positions[parent].attach_chain == -positions[child].attach_chain {
+        positions[parent].attach_chain = 0;
+        positions[parent].attach_kind = 0;
+    }
+    ctx.idx += 1;
+    true
+}
+
+/// Reverses an existing cursive attachment chain starting at `idx` so
+/// that `idx` can be attached to a new parent.
+fn reverse_cursive_minor_offset(
+    positions: &mut [super::GlyphPosition],
+    idx: usize,
+    new_parent: usize,
+) {
+    let chain = positions[idx].attach_chain;
+    if chain == 0 || positions[idx].attach_kind != ATTACH_CURSIVE {
+        return;
+    }
+    positions[idx].attach_chain = 0;
+    let Some(j) = idx
+        .checked_add_signed(chain as isize)
+        .filter(|&j| j < positions.len())
+    else {
+        return;
+    };
+    // stop if we see the new parent in the chain
+    if j == new_parent {
+        return;
+    }
+    reverse_cursive_minor_offset(positions, j, new_parent);
+    positions[j].y_offset = -positions[idx].y_offset;
+    positions[j].attach_chain = -chain;
+    positions[j].attach_kind = ATTACH_CURSIVE;
+}
+
+fn apply_mark_to_base(ctx: &mut ApplyContext, subtable: &MarkBasePosFormat1) -> bool {
+    let Some(mark_idx) = covered(subtable.mark_coverage(), ctx.glyph(ctx.idx)) else {
+        return false;
+    };
+    // the base is the closest preceding glyph that is not a mark
+    let Some(base_idx) = (0..ctx.idx)
+        .rev()
+        .find(|&idx| !ctx.buffer.infos[idx].is_mark())
+    else {
+        return false;
+    };
+    let (Some(base_cov_idx), Ok(marks), Ok(bases)) = (
+        covered(subtable.base_coverage(), ctx.glyph(base_idx)),
+        subtable.mark_array(),
+        subtable.base_array(),
+    ) else {
+        return false;
+    };
+    let Some((class, mark_anchor)) = mark_anchor(&marks, mark_idx) else {
+        return false;
+    };
+    let Some(Ok(base_anchor)) = bases
+        .base_records()
+        .get(base_cov_idx as usize)
+        .ok()
+        .and_then(|record| record.base_anchors(bases.offset_data()).get(class as usize))
+    else {
+        return false;
+    };
+    attach_mark(ctx, &mark_anchor, &base_anchor, base_idx);
+    true
+}
+
+fn apply_mark_to_lig(ctx: &mut ApplyContext, subtable: &MarkLigPosFormat1) -> bool {
+    let Some(mark_idx) = covered(subtable.mark_coverage(), ctx.glyph(ctx.idx)) else {
+        return false;
+    };
+    // the ligature is the closest preceding glyph that is not a mark
+    let Some(lig_idx) = (0..ctx.idx)
+        .rev()
+        .find(|&idx| !ctx.buffer.infos[idx].is_mark())
+    else {
+        return false;
+    };
+    let (Some(lig_cov_idx), Ok(marks), Ok(ligatures)) = (
+        covered(subtable.ligature_coverage(), ctx.glyph(lig_idx)),
+        subtable.mark_array(),
+        subtable.ligature_array(),
+    ) else {
+        return false;
+    };
+    let Some((class, mark_anchor)) = mark_anchor(&marks, mark_idx) else {
+        return false;
+    };
+    let Ok(ligature) = ligatures.ligature_attaches().get(lig_cov_idx as usize) else {
+        return false;
+    };
+    let component_count = ligature.component_count() as usize;
+    if component_count == 0 {
+        return false;
+    }
+    // attach to the component that the mark was associated with during
+    // ligature substitution or the last component otherwise
+    let lig_info = &ctx.buffer.infos[lig_idx];
+    let mark_info = &ctx.buffer.infos[ctx.idx];
+    let component =
+        if lig_info.lig_id != 0 && lig_info.lig_id == mark_info.lig_id && mark_info.lig_comp > 0 {
+            (mark_info.lig_comp as usize).min(component_count) - 1
+        } else {
+            component_count - 1
+        };
+    let Some(Ok(lig_anchor)) =
+        ligature
+            .component_records()
+            .get(component)
+            .ok()
+            .and_then(|record| {
+                record
+                    .ligature_anchors(ligature.offset_data())
+                    .get(class as usize)
+            })
+    else {
+        return false;
+    };
+    attach_mark(ctx, &mark_anchor, &lig_anchor, lig_idx);
+    true
+}
+
+fn apply_mark_to_mark(ctx: &mut ApplyContext, subtable: &MarkMarkPosFormat1) -> bool {
+    let Some(mark1_idx) = covered(subtable.mark1_coverage(), ctx.glyph(ctx.idx)) else {
+        return false;
+    };
+    let Some(prev_idx) = ctx.prev_glyph_unfiltered(ctx.idx) else {
+        return false;
+    };
+    let mark1_info = &ctx.buffer.infos[ctx.idx];
+    let mark2_info = &ctx.buffer.infos[prev_idx];
+    if !mark2_info.is_mark() {
+        return false;
+    }
+    // both marks must belong to the same base, ligature or component
+    let (id1, id2) = (mark1_info.lig_id, mark2_info.lig_id);
+    let (comp1, comp2) = (mark1_info.lig_comp, mark2_info.lig_comp);
+    let same_base = if id1 == id2 {
+        id1 == 0 || comp1 == comp2
+    } else {
+        // one of the marks may itself be a ligature
+        (id1 > 0 && comp1 == 0) || (id2 > 0 && comp2 == 0)
+    };
+    if !same_base {
+        return false;
+    }
+    let (Some(mark2_cov_idx), Ok(marks1), Ok(marks2)) = (
+        covered(subtable.mark2_coverage(), ctx.glyph(prev_idx)),
+        subtable.mark1_array(),
+        subtable.mark2_array(),
+    ) else {
+        return false;
+    };
+    let Some((class, mark_anchor)) = mark_anchor(&marks1, mark1_idx) else {
+        return false;
+    };
+    let Some(Ok(mark2_anchor)) = marks2
+        .mark2_records()
+        .get(mark2_cov_idx as usize)
+        .ok()
+        .and_then(|record| {
+            record
+                .mark2_anchors(marks2.offset_data())
+                .get(class as usize)
+        })
+    else {
+        return false;
+    };
+    attach_mark(ctx, &mark_anchor, &mark2_anchor, prev_idx);
+    true
+}
+
+/// Returns the class and anchor for the mark at the given coverage index.
+fn mark_anchor<'a>(marks: &MarkArray<'a>, idx: u16) -> Option<(u16, AnchorTable<'a>)> {
+    let record = marks.mark_records().get(idx as usize)?;
+    let anchor = record.mark_anchor(marks.offset_data()).ok()?;
+    Some((record.mark_class(), anchor))
+}
+
+/// Positions the current mark glyph relative to the glyph at `base_idx`
+/// so that their anchors coincide.
+fn attach_mark(
+    ctx: &mut ApplyContext,
+    mark_anchor: &AnchorTable,
+    base_anchor: &AnchorTable,
+    base_idx: usize,
+) {
+    let (mark_x, mark_y) = anchor_point(ctx.data, mark_anchor);
+    let (base_x, base_y) = anchor_point(ctx.data, base_anchor);
+    let pos = &mut ctx.buffer.positions[ctx.idx];
+    pos.x_offset = base_x - mark_x;
+    pos.y_offset = base_y - mark_y;
+    pos.attach_kind = ATTACH_MARK;
+    pos.attach_chain = (base_idx as isize - ctx.idx as isize) as i16;
+    ctx.idx += 1;
+}
+
+/// Returns the coordinates of an anchor, including any variation deltas.
+fn anchor_point(data: &LayoutData, anchor: &AnchorTable) -> (f32, f32) {
+    match anchor {
+        AnchorTable::Format1(anchor) => {
+            (anchor.x_coordinate() as f32, anchor.y_coordinate() as f32)
+        }
+        // contour point anchors are only meaningful with hinting so we use
+        // the design coordinates
+        AnchorTable::Format2(anchor) => {
+            (anchor.x_coordinate() as f32, anchor.y_coordinate() as f32)
+        }
+        AnchorTable::Format3(anchor) => (
+            anchor.x_coordinate() as f32 + data.delta(anchor.x_device()),
+            anchor.y_coordinate() as f32 + data.delta(anchor.y_device()),
+        ),
+    }
+}
+
+/// Adds the adjustments from a value record to the glyph at `idx`.
+///
+/// `offset_data` is the data of the subtable containing the record, from
+/// which device table offsets are resolved.
+fn apply_value(ctx: &mut ApplyContext, offset_data: FontData, record: &ValueRecord, idx: usize) {
+    let data = ctx.data;
+    let value = |value: Option<i16>, device| match value {
+        Some(value) => value as f32 + data.delta(device),
+        None => data.delta(device),
+    };
+    let pos = &mut ctx.buffer.positions[idx];
+    pos.x_offset += value(record.x_placement(), record.x_placement_device(offset_data));
+    pos.y_offset += value(record.y_placement(), record.y_placement_device(offset_data));
+    pos.x_advance += value(record.x_advance(), record.x_advance_device(offset_data));
+    pos.y_advance += value(record.y_advance(), record.y_advance_device(offset_data));
+}
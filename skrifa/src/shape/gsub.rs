@@ -0,0 +1,223 @@
+//! Application of glyph substitution lookups.
+
+use read_fonts::{
+    tables::{
+        gsub::{
+            AlternateSubstFormat1, ExtensionSubtable, LigatureSubstFormat1, MultipleSubstFormat1,
+            ReverseChainSingleSubstFormat1, SingleSubst, SubstitutionLookup,
+        },
+        layout::LookupFlag,
+    },
+    types::GlyphId,
+};
+
+use super::{
+    apply::{self, ApplyContext},
+    context::{apply_chain_context, apply_context, coverage_matcher, covered},
+};
+
+/// Returns the lookup flag and optional mark filtering set for a lookup.
+pub(super) fn lookup_props(lookup: &SubstitutionLookup) -> (LookupFlag, Option<u16>) {
+    match lookup {
+        SubstitutionLookup::Single(lookup) => apply::lookup_props(lookup),
+        SubstitutionLookup::Multiple(lookup) => apply::lookup_props(lookup),
+        SubstitutionLookup::Alternate(lookup) => apply::lookup_props(lookup),
+        SubstitutionLookup::Ligature(lookup) => apply::lookup_props(lookup),
+        SubstitutionLookup::Contextual(lookup) => apply::lookup_props(lookup),
+        SubstitutionLookup::ChainContextual(lookup) => apply::lookup_props(lookup),
+        SubstitutionLookup::Extension(lookup) => apply::lookup_props(lookup),
+        SubstitutionLookup::Reverse(lookup) => apply::lookup_props(lookup),
+    }
+}
+
+/// Returns true if the lookup is a reverse chaining single substitution,
+/// either directly or through an extension.
+pub(super) fn is_reverse(lookup: &SubstitutionLookup) -> bool {
+    match lookup {
+        SubstitutionLookup::Reverse(_) => true,
+        SubstitutionLookup::Extension(lookup) => {
+            matches!(lookup.subtables().get(0), Ok(ExtensionSubtable::Reverse(_)))
+        }
+        _ => false,
+    }
+}
+
+/// Applies the first matching subtable of the lookup at the current glyph.
+///
+/// Returns true if a substitution was performed, in which case the current
+/// position has been advanced appropriately.
+pub(super) fn apply(ctx: &mut ApplyContext, lookup: &SubstitutionLookup) -> bool {
+    match lookup {
+        SubstitutionLookup::Single(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_single(ctx, &subtable)),
+        SubstitutionLookup::Multiple(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_multiple(ctx, &subtable)),
+        SubstitutionLookup::Alternate(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_alternate(ctx, &subtable)),
+        SubstitutionLookup::Ligature(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_ligature(ctx, &subtable)),
+        SubstitutionLookup::Contextual(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_context(ctx, &subtable)),
+        SubstitutionLookup::ChainContextual(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_chain_context(ctx, &subtable)),
+        SubstitutionLookup::Extension(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_extension(ctx, &subtable)),
+        SubstitutionLookup::Reverse(lookup) => lookup
+            .subtables()
+            .iter()
+            .flatten()
+            .any(|subtable| apply_reverse(ctx, &subtable)),
+    }
+}
+
+fn apply_extension(ctx: &mut ApplyContext, subtable: &ExtensionSubtable) -> bool {
+    match subtable {
+        ExtensionSubtable::Single(ext) => ext
+            .extension()
+            .map(|subtable| apply_single(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Multiple(ext) => ext
+            .extension()
+            .map(|subtable| apply_multiple(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Alternate(ext) => ext
+            .extension()
+            .map(|subtable| apply_alternate(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Ligature(ext) => ext
+            .extension()
+            .map(|subtable| apply_ligature(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Contextual(ext) => ext
+            .extension()
+            .map(|subtable| apply_context(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::ChainContextual(ext) => ext
+            .extension()
+            .map(|subtable| apply_chain_context(ctx, &subtable))
+            .unwrap_or(false),
+        ExtensionSubtable::Reverse(ext) => ext
+            .extension()
+            .map(|subtable| apply_reverse(ctx, &subtable))
+            .unwrap_or(false),
+    }
+}
+
+fn apply_single(ctx: &mut ApplyContext, subtable: &SingleSubst) -> bool {
+    let glyph_id = ctx.glyph(ctx.idx);
+    let substitute = match subtable {
+        SingleSubst::Format1(table) => {
+            if covered(table.coverage(), glyph_id).is_none() {
+                return false;
+            }
+            // the addition is performed modulo 65536
+            let delta = table.delta_glyph_id() as i32;
+            (glyph_id.to_u16() as i32 + delta) as u16
+        }
+        SingleSubst::Format2(table) => {
+            let Some(substitute) = covered(table.coverage(), glyph_id)
+                .and_then(|idx| table.substitute_glyph_ids().get(idx as usize))
+            else {
+                return false;
+            };
+            substitute.get().to_u16()
+        }
+    };
+    ctx.replace_glyph(GlyphId::new(substitute));
+    true
+}
+
+fn apply_multiple(ctx: &mut ApplyContext, subtable: &MultipleSubstFormat1) -> bool {
+    let Some(Ok(sequence)) = covered(subtable.coverage(), ctx.glyph(ctx.idx))
+        .map(|idx| subtable.sequences().get(idx as usize))
+    else {
+        return false;
+    };
+    let glyph_ids = sequence.substitute_glyph_ids();
+    if let [glyph_id] = glyph_ids {
+        ctx.replace_glyph(glyph_id.get());
+        true
+    } else {
+        ctx.replace_glyphs(glyph_ids.iter().map(|glyph_id| glyph_id.get()))
+    }
+}
+
+fn apply_alternate(ctx: &mut ApplyContext, subtable: &AlternateSubstFormat1) -> bool {
+    let Some(Ok(set)) = covered(subtable.coverage(), ctx.glyph(ctx.idx))
+        .map(|idx| subtable.alternate_sets().get(idx as usize))
+    else {
+        return false;
+    };
+    // a feature value of 1 selects the first alternate
+    let Some(glyph_id) = (ctx.feature_value as usize)
+        .checked_sub(1)
+        .and_then(|idx| set.alternate_glyph_ids().get(idx))
+    else {
+        return false;
+    };
+    ctx.replace_glyph(glyph_id.get());
+    true
+}
+
+fn apply_ligature(ctx: &mut ApplyContext, subtable: &LigatureSubstFormat1) -> bool {
+    let Some(Ok(set)) = covered(subtable.coverage(), ctx.glyph(ctx.idx))
+        .map(|idx| subtable.ligature_sets().get(idx as usize))
+    else {
+        return false;
+    };
+    for ligature in set.ligatures().iter().flatten() {
+        let components = ligature.component_glyph_ids();
+        if let Some((positions, end)) =
+            ctx.match_input(components.len(), |i, g| components[i].get() == g)
+        {
+            ctx.ligate(&positions, end, ligature.ligature_glyph());
+            return true;
+        }
+    }
+    false
+}
+
+fn apply_reverse(ctx: &mut ApplyContext, subtable: &ReverseChainSingleSubstFormat1) -> bool {
+    let Some(substitute) = covered(subtable.coverage(), ctx.glyph(ctx.idx))
+        .and_then(|idx| subtable.substitute_glyph_ids().get(idx as usize))
+    else {
+        return false;
+    };
+    let backtrack = subtable.backtrack_coverages();
+    let lookahead = subtable.lookahead_coverages();
+    if ctx.match_backtrack(backtrack.len(), coverage_matcher(&backtrack, 0))
+        && ctx.match_lookahead(
+            ctx.idx + 1,
+            lookahead.len(),
+            coverage_matcher(&lookahead, 0),
+        )
+    {
+        // the position is managed by the caller
+        let idx = ctx.idx;
+        ctx.replace_glyph(substitute.get());
+        ctx.idx = idx;
+        return true;
+    }
+    false
+}
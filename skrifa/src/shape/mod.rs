@@ -0,0 +1,745 @@
+//! Basic text shaping.
+//!
+//! This module provides a [`Shaper`] that maps text to glyphs and applies
+//! the substitution ([GSUB](https://learn.microsoft.com/en-us/typography/opentype/spec/gsub))
+//! and positioning ([GPOS](https://learn.microsoft.com/en-us/typography/opentype/spec/gpos))
+//! lookups of a font.
+//!
+//! The shaper implements the generic OpenType layout model which is
+//! sufficient for simple scripts written left to right such as Latin, Greek
+//! and Cyrillic. It does not perform Unicode normalization, script specific
+//! reordering, or bidirectional processing so complex scripts will not be
+//! rendered correctly.
+//!
+//! # Example
+//!
+//! ```
+//! # use skrifa::{prelude::*, shape::Shaper};
+//! fn shape_latin(font: &FontRef, text: &str) {
+//!     let shaper = Shaper::new(font, Size::unscaled(), LocationRef::default());
+//!     // enable discretionary ligatures in addition to the defaults
+//!     let features = [("dlig", 1).into()];
+//!     let buffer = shaper.shape(text, Tag::new(b"latn"), None, &features);
+//!     for (glyph, pos) in buffer.iter() {
+//!         println!(
+//!             "{} @ {} advance: {} offset: ({}, {})",
+//!             glyph.glyph_id(),
+//!             glyph.cluster(),
+//!             pos.x_advance,
+//!             pos.x_offset,
+//!             pos.y_offset
+//!         );
+//!     }
+//! }
+//! ```
+
+mod apply;
+mod buffer;
+mod context;
+mod gpos;
+mod gsub;
+mod plan;
+
+pub use buffer::{GlyphBuffer, GlyphInfo, GlyphPosition};
+
+use core::ops::{Bound, RangeBounds};
+
+use read_fonts::{types::Tag, TableProvider};
+
+use self::{
+    apply::{ApplyContext, LayoutData, TableKind, MAX_NESTING_LEVEL},
+    buffer::{ATTACH_CURSIVE, CLASS_UNCLASSIFIED},
+    plan::ShapePlan,
+};
+use crate::{
+    charmap::Charmap,
+    instance::{LocationRef, Size},
+    layout::LayoutInfo,
+    metrics::GlyphMetrics,
+    setting::FeatureSetting,
+};
+
+/// Feature setting that applies to a range of the input.
+///
+/// The range is specified in terms of cluster values which, when shaping
+/// text with [`Shaper::shape`], are byte offsets into the source string.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FeatureRange {
+    /// Tag and value of the feature.
+    pub setting: FeatureSetting,
+    /// Start of the range (inclusive).
+    pub start: u32,
+    /// End of the range (exclusive).
+    pub end: u32,
+}
+
+impl FeatureRange {
+    /// Creates a new feature setting that applies to the entire input.
+    pub fn new(setting: impl Into<FeatureSetting>) -> Self {
+        Self {
+            setting: setting.into(),
+            start: 0,
+            end: u32::MAX,
+        }
+    }
+
+    /// Creates a new feature setting that applies to the given range of
+    /// cluster values.
+    pub fn with_range(setting: impl Into<FeatureSetting>, range: impl RangeBounds<u32>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => u32::MAX,
+        };
+        Self {
+            setting: setting.into(),
+            start,
+            end,
+        }
+    }
+
+    /// Returns true if the setting applies to the entire input.
+    pub fn is_global(&self) -> bool {
+        self.start == 0 && self.end == u32::MAX
+    }
+}
+
+impl<T: Into<FeatureSetting>> From<T> for FeatureRange {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Shaper for simple scripts.
+///
+/// See the [module level documentation](self) for more detail.
+#[derive(Clone)]
+pub struct Shaper<'a> {
+    layout: LayoutInfo<'a>,
+    data: LayoutData<'a>,
+    charmap: Charmap<'a>,
+    metrics: GlyphMetrics<'a>,
+    scale: f32,
+}
+
+impl<'a> Shaper<'a> {
+    /// Creates a new shaper for the given font, size and location in
+    /// normalized variation space.
+    pub fn new(
+        font: &impl TableProvider<'a>,
+        size: Size,
+        location: impl Into<LocationRef<'a>>,
+    ) -> Self {
+        let location = location.into();
+        let upem = font
+            .head()
+            .map(|head| head.units_per_em())
+            .unwrap_or_default();
+        Self {
+            layout: LayoutInfo::new(font, location),
+            data: LayoutData::new(font, location.coords()),
+            charmap: Charmap::new(font),
+            metrics: GlyphMetrics::new(font, Size::unscaled(), location),
+            scale: size.linear_scale(upem),
+        }
+    }
+
+    /// Maps the characters of `text` to glyphs and shapes the result.
+    ///
+    /// The `script` and `language` parameters are OpenType
+    /// [script](https://learn.microsoft.com/en-us/typography/opentype/spec/scripttags)
+    /// and [language system](https://learn.microsoft.com/en-us/typography/opentype/spec/languagetags)
+    /// tags that select the set of features to apply. The `features`
+    /// parameter modifies the default set of features.
+    ///
+    /// The cluster value of each glyph is the byte offset of the character
+    /// in `text` that produced it.
+    pub fn shape(
+        &self,
+        text: &str,
+        script: Tag,
+        language: Option<Tag>,
+        features: &[FeatureRange],
+    ) -> GlyphBuffer {
+        let mut buffer = GlyphBuffer::new();
+        let mut prev_ch = None;
+        for (offset, ch) in text.char_indices() {
+            if let (true, Some(prev_ch)) = (is_variation_selector(ch), prev_ch) {
+                // variation selectors modify the previous glyph
+                if let Some(read_fonts::tables::cmap::MapVariant::Variant(glyph_id)) =
+                    self.charmap.map_variant(prev_ch, ch)
+                {
+                    if let Some(last) = buffer.infos.last_mut() {
+                        last.glyph_id = glyph_id;
+                    }
+                }
+                continue;
+            }
+            let glyph_id = self.charmap.map(ch).unwrap_or_default();
+            buffer.push(glyph_id, offset as u32);
+            prev_ch = Some(ch);
+        }
+        self.shape_buffer(&mut buffer, script, language, features);
+        buffer
+    }
+
+    /// Shapes a buffer of glyphs.
+    ///
+    /// This can be used when the mapping from characters to glyphs has
+    /// already been performed. See [`shape`](Self::shape) for a
+    /// description of the parameters.
+    pub fn shape_buffer(
+        &self,
+        buffer: &mut GlyphBuffer,
+        script: Tag,
+        language: Option<Tag>,
+        features: &[FeatureRange],
+    ) {
+        let plan = ShapePlan::new(
+            self.layout.gsub(),
+            self.layout.gpos(),
+            script,
+            language,
+            features,
+        );
+        plan.setup_masks(&mut buffer.infos);
+        for info in &mut buffer.infos {
+            self.data.set_glyph_props(info, CLASS_UNCLASSIFIED);
+        }
+        buffer.reset_limits();
+        let mut ctx = ApplyContext::new(&self.data, buffer, TableKind::Gsub);
+        for lookup in &plan.gsub {
+            ctx.apply_lookup(lookup);
+        }
+        buffer.positions.clear();
+        buffer.positions.extend(buffer.infos.iter().map(|info| {
+            GlyphPosition {
+                x_advance: self
+                    .metrics
+                    .advance_width(info.glyph_id)
+                    .unwrap_or_default(),
+                ..Default::default()
+            }
+        }));
+        let mut ctx = ApplyContext::new(&self.data, buffer, TableKind::Gpos);
+        for lookup in &plan.gpos {
+            ctx.apply_lookup(lookup);
+        }
+        // marks never advance; when no positioning was applied, shift them
+        // back so that they hang over the preceding glyph
+        let adjust_offsets = plan.gpos.is_empty();
+        for (info, pos) in buffer.infos.iter().zip(buffer.positions.iter_mut()) {
+            if info.is_mark() {
+                if adjust_offsets {
+                    pos.x_offset -= pos.x_advance;
+                    pos.y_offset -= pos.y_advance;
+                }
+                pos.x_advance = 0.0;
+                pos.y_advance = 0.0;
+            }
+        }
+        for idx in 0..buffer.positions.len() {
+            propagate_attachment_offsets(&mut buffer.positions, idx, MAX_NESTING_LEVEL);
+        }
+        if self.scale != 1.0 {
+            for pos in &mut buffer.positions {
+                pos.x_advance *= self.scale;
+                pos.y_advance *= self.scale;
+                pos.x_offset *= self.scale;
+                pos.y_offset *= self.scale;
+            }
+        }
+    }
+}
+
+/// Accumulates the offsets of attached glyphs so that they are positioned
+/// relative to their parent.
+///
+/// Chains deeper than `nesting_level` are not followed further, as in
+/// HarfBuzz.
+fn propagate_attachment_offsets(positions: &mut [GlyphPosition], idx: usize, nesting_level: usize) {
+    let chain = positions[idx].attach_chain;
+    let kind = positions[idx].attach_kind;
+    if chain == 0 || nesting_level == 0 {
+        return;
+    }
+    positions[idx].attach_chain = 0;
+    let Some(parent) = idx
+        .checked_add_signed(chain as isize)
+        .filter(|&parent| parent < positions.len())
+    else {
+        return;
+    };
+    propagate_attachment_offsets(positions, parent, nesting_level - 1);
+    let parent_pos = positions[parent];
+    if kind == ATTACH_CURSIVE {
+        positions[idx].y_offset += parent_pos.y_offset;
+    } else {
+        positions[idx].x_offset += parent_pos.x_offset;
+        positions[idx].y_offset += parent_pos.y_offset;
+        if parent < idx {
+            // the attached glyph is rendered after the advances of all
+            // glyphs following the parent
+            let (x, y) = positions[parent..idx]
+                .iter()
+                .fold((0.0, 0.0), |(x, y), pos| {
+                    (x + pos.x_advance, y + pos.y_advance)
+                });
+            positions[idx].x_offset -= x;
+            positions[idx].y_offset -= y;
+        }
+    }
+}
+
+fn is_variation_selector(ch: char) -> bool {
+    matches!(ch as u32, 0x180B..=0x180D | 0xFE00..=0xFE0F | 0xE0100..=0xE01EF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instance::NormalizedCoord;
+    use read_fonts::{types::GlyphId, FontRef};
+    use write_fonts::{
+        tables::{
+            gdef::{Gdef, GlyphClassDef},
+            gpos::{
+                AnchorTable, BaseArray, BaseRecord, ComponentRecord, Gpos, LigatureArray,
+                LigatureAttach, MarkArray, MarkBasePosFormat1, MarkLigPosFormat1, MarkRecord,
+                PairPos, PairSet, PairValueRecord, PositionLookup, ValueRecord,
+            },
+            gsub::{
+                AlternateSet, AlternateSubstFormat1, Gsub, Ligature, LigatureSet,
+                LigatureSubstFormat1, MultipleSubstFormat1, Sequence, SingleSubst,
+                SubstitutionLookup,
+            },
+            head::Head,
+            hhea::Hhea,
+            hmtx::{Hmtx, LongMetric},
+            layout::{
+                ChainedSequenceContext, ClassDef, CoverageTable, Feature, FeatureList,
+                FeatureRecord, LangSys, Lookup, LookupFlag, LookupList, Script, ScriptList,
+                ScriptRecord, SequenceLookupRecord, VariationIndex,
+            },
+            maxp::Maxp,
+            variations::{
+                ItemVariationData, ItemVariationStore, RegionAxisCoordinates, VariationRegion,
+                VariationRegionList,
+            },
+        },
+        types::F2Dot14,
+        FontBuilder,
+    };
+
+    const F: u16 = 1;
+    const I: u16 = 2;
+    const F_I: u16 = 3;
+    const A: u16 = 4;
+    const ACUTE: u16 = 5;
+    const V: u16 = 6;
+    const A_ALT: u16 = 7;
+    const CAP_A: u16 = 8;
+
+    #[test]
+    fn cantarell_locl() {
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let shaper = Shaper::new(&font, Size::unscaled(), LocationRef::default());
+        let latn = Tag::new(b"latn");
+        let trk = Tag::new(b"TRK ");
+        let buffer = shaper.shape("ijkl", latn, Some(trk), &[]);
+        assert_eq!(glyph_ids(&buffer), [2, 3, 4, 5]);
+        assert_eq!(advances(&buffer), [246.0, 246.0, 498.0, 278.0]);
+        assert_eq!(
+            buffer
+                .glyphs()
+                .iter()
+                .map(|g| g.cluster())
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 3]
+        );
+        // locl is only present for Turkish
+        let buffer = shaper.shape("ijkl", latn, None, &[]);
+        assert_eq!(glyph_ids(&buffer), [1, 3, 4, 5]);
+        let buffer = shaper.shape("ijkl", Tag::new(b"DFLT"), Some(trk), &[]);
+        assert_eq!(glyph_ids(&buffer), [1, 3, 4, 5]);
+        // explicitly disabled
+        let buffer = shaper.shape("ijkl", latn, Some(trk), &[("locl", 0).into()]);
+        assert_eq!(glyph_ids(&buffer), [1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn long_attachment_chain() {
+        // each glyph is cursively attached to the next
+        let count = 100_000;
+        let mut positions = vec![
+            GlyphPosition {
+                y_offset: 1.0,
+                attach_chain: 1,
+                attach_kind: ATTACH_CURSIVE,
+                ..Default::default()
+            };
+            count
+        ];
+        positions[count - 1].attach_chain = 0;
+        for idx in 0..count {
+            propagate_attachment_offsets(&mut positions, idx, MAX_NESTING_LEVEL);
+        }
+        // offsets accumulate along at most MAX_NESTING_LEVEL links
+        let max_offset = (MAX_NESTING_LEVEL + 1) as f32;
+        assert!(positions.iter().all(|pos| pos.y_offset <= max_offset));
+        assert_eq!(positions[count - 2].y_offset, 2.0);
+    }
+
+    #[test]
+    fn cantarell_feature_range() {
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let shaper = Shaper::new(&font, Size::unscaled(), LocationRef::default());
+        let features = [FeatureRange::with_range(("locl", 0), 1..)];
+        let buffer = shaper.shape("ii", Tag::new(b"latn"), Some(Tag::new(b"TRK ")), &features);
+        assert_eq!(glyph_ids(&buffer), [2, 1]);
+    }
+
+    #[test]
+    fn scaled_advances() {
+        let font = FontRef::new(font_test_data::CANTARELL_VF_TRIMMED).unwrap();
+        let shaper = Shaper::new(&font, Size::new(500.0), LocationRef::default());
+        let buffer = shaper.shape("kl", Tag::new(b"latn"), None, &[]);
+        assert_eq!(advances(&buffer), [249.0, 139.0]);
+    }
+
+    #[test]
+    fn ligature_skips_marks() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let buffer = shape_glyphs(&font, &[F, ACUTE, I], &[], &[]);
+        assert_eq!(glyph_ids(&buffer), [F_I, ACUTE]);
+        assert!(buffer.glyphs()[0].is_ligature());
+        assert!(buffer.glyphs()[1].is_mark());
+        // the mark is attached to the first component of the ligature
+        let pos = buffer.positions()[1];
+        assert_eq!(advances(&buffer), [520.0, 0.0]);
+        assert_eq!((pos.x_offset, pos.y_offset), (50.0 - 520.0, 200.0));
+        // disable the ligature
+        let buffer = shape_glyphs(&font, &[F, I], &[("liga", 0).into()], &[]);
+        assert_eq!(glyph_ids(&buffer), [F, I]);
+    }
+
+    #[test]
+    fn mark_to_base() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let buffer = shape_glyphs(&font, &[A, ACUTE], &[], &[]);
+        assert_eq!(advances(&buffer), [500.0, 0.0]);
+        let pos = buffer.positions()[1];
+        assert_eq!((pos.x_offset, pos.y_offset), (150.0 - 500.0, 100.0));
+    }
+
+    #[test]
+    fn chained_context() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let buffer = shape_glyphs(&font, &[A, V, A, A], &[], &[]);
+        assert_eq!(glyph_ids(&buffer), [A_ALT, V, A, A]);
+        let buffer = shape_glyphs(&font, &[A, V], &[("calt", 0).into()], &[]);
+        assert_eq!(glyph_ids(&buffer), [A, V]);
+    }
+
+    #[test]
+    fn alternates() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let buffer = shape_glyphs(&font, &[V], &[], &[]);
+        assert_eq!(glyph_ids(&buffer), [V]);
+        let buffer = shape_glyphs(&font, &[V], &[("salt", 1).into()], &[]);
+        assert_eq!(glyph_ids(&buffer), [CAP_A]);
+        let buffer = shape_glyphs(&font, &[V], &[("salt", 2).into()], &[]);
+        assert_eq!(glyph_ids(&buffer), [A_ALT]);
+        // out of range
+        let buffer = shape_glyphs(&font, &[V], &[("salt", 3).into()], &[]);
+        assert_eq!(glyph_ids(&buffer), [V]);
+    }
+
+    #[test]
+    fn variable_kerning() {
+        let font_data = make_font();
+        let font = FontRef::new(&font_data).unwrap();
+        let buffer = shape_glyphs(&font, &[CAP_A, V, CAP_A], &[], &[]);
+        assert_eq!(advances(&buffer), [560.0, 480.0, 600.0]);
+        let coords = [NormalizedCoord::from_f32(1.0)];
+        let buffer = shape_glyphs(&font, &[CAP_A, V], &[], &coords);
+        assert_eq!(advances(&buffer), [540.0, 480.0]);
+        let buffer = shape_glyphs(&font, &[CAP_A, V], &[("kern", 0).into()], &coords);
+        assert_eq!(advances(&buffer), [600.0, 480.0]);
+    }
+
+    #[test]
+    fn runaway_substitution() {
+        // a contextual lookup that doubles `a` and then applies itself to
+        // both copies
+        let coverage = CoverageTable::format_1(vec![GlyphId::new(A)]);
+        let gsub = Gsub::new(
+            ScriptList::new(vec![ScriptRecord::new(
+                Tag::new(b"DFLT"),
+                Script::new(Some(LangSys::new(vec![0])), vec![]),
+            )]),
+            FeatureList::new(vec![FeatureRecord::new(
+                Tag::new(b"calt"),
+                Feature::new(None, vec![0]),
+            )]),
+            LookupList::new(vec![
+                SubstitutionLookup::ChainContextual(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![ChainedSequenceContext::format_3(
+                        vec![],
+                        vec![coverage.clone()],
+                        vec![],
+                        vec![
+                            SequenceLookupRecord::new(0, 1),
+                            SequenceLookupRecord::new(0, 0),
+                            SequenceLookupRecord::new(1, 0),
+                        ],
+                    )
+                    .into()],
+                    0,
+                )),
+                SubstitutionLookup::Multiple(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![MultipleSubstFormat1::new(
+                        coverage,
+                        vec![Sequence::new(vec![GlyphId::new(A), GlyphId::new(A)])],
+                    )],
+                    0,
+                )),
+            ]),
+        );
+        let base_font_data = make_font();
+        let base_font = FontRef::new(&base_font_data).unwrap();
+        let font_data = FontBuilder::new()
+            .add_table(&gsub)
+            .unwrap()
+            .copy_missing_tables(base_font)
+            .build();
+        let font = FontRef::new(&font_data).unwrap();
+        let buffer = shape_glyphs(&font, &[A], &[], &[]);
+        assert!(buffer.len() > 1);
+        assert!(buffer.len() <= 16384);
+        assert!(buffer
+            .glyphs()
+            .iter()
+            .all(|glyph| glyph.glyph_id() == GlyphId::new(A)));
+    }
+
+    fn shape_glyphs(
+        font: &FontRef,
+        glyph_ids: &[u16],
+        features: &[FeatureRange],
+        coords: &[NormalizedCoord],
+    ) -> GlyphBuffer {
+        let shaper = Shaper::new(font, Size::unscaled(), LocationRef::new(coords));
+        let mut buffer = GlyphBuffer::new();
+        for (cluster, glyph_id) in glyph_ids.iter().enumerate() {
+            buffer.push(GlyphId::new(*glyph_id), cluster as u32);
+        }
+        shaper.shape_buffer(&mut buffer, Tag::new(b"latn"), None, features);
+        buffer
+    }
+
+    fn glyph_ids(buffer: &GlyphBuffer) -> Vec<u16> {
+        buffer
+            .glyphs()
+            .iter()
+            .map(|glyph| glyph.glyph_id().to_u16())
+            .collect()
+    }
+
+    fn advances(buffer: &GlyphBuffer) -> Vec<f32> {
+        buffer.positions().iter().map(|pos| pos.x_advance).collect()
+    }
+
+    /// Builds a font with the following features in the default language
+    /// system of the `DFLT` script:
+    ///
+    /// * `calt`: replaces `a` with `a.alt` when followed by `v`
+    /// * `liga`: forms the `f_i` ligature, ignoring marks
+    /// * `salt`: alternates `A` and `a.alt` for `v`
+    /// * `kern`: kerns the pair `A v` by -40 units, with an additional -20
+    ///   units at the maximum of the first axis
+    /// * `mark`: attaches `acute` to `a` and to both components of `f_i`
+    fn make_font() -> Vec<u8> {
+        let glyph = GlyphId::new;
+        let coverage = |glyphs: &[u16]| {
+            CoverageTable::format_1(glyphs.iter().copied().map(GlyphId::new).collect())
+        };
+        let features = |features: &[(&[u8; 4], Vec<u16>)]| {
+            FeatureList::new(
+                features
+                    .iter()
+                    .map(|(tag, lookups)| {
+                        FeatureRecord::new(Tag::new(tag), Feature::new(None, lookups.clone()))
+                    })
+                    .collect(),
+            )
+        };
+        let scripts = |feature_count: u16| {
+            ScriptList::new(vec![ScriptRecord::new(
+                Tag::new(b"DFLT"),
+                Script::new(Some(LangSys::new((0..feature_count).collect())), vec![]),
+            )])
+        };
+        let mut ignore_marks = LookupFlag::empty();
+        ignore_marks.set_ignore_marks(true);
+        let gsub = Gsub::new(
+            scripts(3),
+            features(&[(b"calt", vec![1]), (b"liga", vec![2]), (b"salt", vec![3])]),
+            LookupList::new(vec![
+                SubstitutionLookup::Single(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![SingleSubst::format_2(coverage(&[A]), vec![glyph(A_ALT)])],
+                    0,
+                )),
+                SubstitutionLookup::ChainContextual(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![ChainedSequenceContext::format_3(
+                        vec![],
+                        vec![coverage(&[A])],
+                        vec![coverage(&[V])],
+                        vec![SequenceLookupRecord::new(0, 0)],
+                    )
+                    .into()],
+                    0,
+                )),
+                SubstitutionLookup::Ligature(Lookup::new(
+                    ignore_marks,
+                    vec![LigatureSubstFormat1::new(
+                        coverage(&[F]),
+                        vec![LigatureSet::new(vec![Ligature::new(
+                            glyph(F_I),
+                            vec![glyph(I)],
+                        )])],
+                    )],
+                    0,
+                )),
+                SubstitutionLookup::Alternate(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![AlternateSubstFormat1::new(
+                        coverage(&[V]),
+                        vec![AlternateSet::new(vec![glyph(CAP_A), glyph(A_ALT)])],
+                    )],
+                    0,
+                )),
+            ]),
+        );
+        let mark_array =
+            || MarkArray::new(vec![MarkRecord::new(0, AnchorTable::format_1(100, 500))]);
+        let gpos = Gpos::new(
+            scripts(2),
+            features(&[(b"kern", vec![0]), (b"mark", vec![1, 2])]),
+            LookupList::new(vec![
+                PositionLookup::Pair(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![PairPos::format_1(
+                        coverage(&[CAP_A]),
+                        vec![PairSet::new(vec![PairValueRecord::new(
+                            glyph(V),
+                            ValueRecord::new()
+                                .with_x_advance(-40)
+                                .with_x_advance_device(VariationIndex::new(0, 0)),
+                            ValueRecord::new(),
+                        )])],
+                    )],
+                    0,
+                )),
+                PositionLookup::MarkToBase(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![MarkBasePosFormat1::new(
+                        coverage(&[ACUTE]),
+                        coverage(&[A]),
+                        mark_array(),
+                        BaseArray::new(vec![BaseRecord::new(vec![Some(AnchorTable::format_1(
+                            250, 600,
+                        ))])]),
+                    )],
+                    0,
+                )),
+                PositionLookup::MarkToLig(Lookup::new(
+                    LookupFlag::empty(),
+                    vec![MarkLigPosFormat1::new(
+                        coverage(&[ACUTE]),
+                        coverage(&[F_I]),
+                        mark_array(),
+                        LigatureArray::new(vec![LigatureAttach::new(vec![
+                            ComponentRecord::new(vec![Some(AnchorTable::format_1(150, 700))]),
+                            ComponentRecord::new(vec![Some(AnchorTable::format_1(400, 700))]),
+                        ])]),
+                    )],
+                    0,
+                )),
+            ]),
+        );
+        let mut gdef = Gdef::new(
+            Some(ClassDef::format_1(
+                glyph(F),
+                vec![
+                    GlyphClassDef::Base as u16,
+                    GlyphClassDef::Base as u16,
+                    GlyphClassDef::Ligature as u16,
+                    GlyphClassDef::Base as u16,
+                    GlyphClassDef::Mark as u16,
+                ],
+            )),
+            None,
+            None,
+            None,
+        );
+        let one = F2Dot14::from_f32(1.0);
+        gdef.item_var_store = Some(ItemVariationStore::new(
+            VariationRegionList::new(vec![VariationRegion::new(vec![
+                RegionAxisCoordinates::new(F2Dot14::ZERO, one, one),
+            ])]),
+            vec![Some(ItemVariationData::new(
+                1,
+                0,
+                vec![0],
+                vec![-20i8 as u8],
+            ))],
+        ))
+        .into();
+        let advances = [500, 300, 250, 520, 500, 200, 480, 510, 600];
+        let hmtx = Hmtx::new(
+            advances
+                .iter()
+                .map(|advance| LongMetric::new(*advance, 0))
+                .collect(),
+            vec![],
+        );
+        let hhea = Hhea {
+            number_of_long_metrics: advances.len() as u16,
+            ..Default::default()
+        };
+        let head = Head {
+            units_per_em: 1000,
+            ..Default::default()
+        };
+        FontBuilder::new()
+            .add_table(&head)
+            .unwrap()
+            .add_table(&hhea)
+            .unwrap()
+            .add_table(&hmtx)
+            .unwrap()
+            .add_table(&Maxp::new(advances.len() as u16))
+            .unwrap()
+            .add_table(&gdef)
+            .unwrap()
+            .add_table(&gsub)
+            .unwrap()
+            .add_table(&gpos)
+            .unwrap()
+            .build()
+    }
+}
@@ -0,0 +1,207 @@
+//! Mapping of requested features to lookups and glyph masks.
+
+use read_fonts::types::Tag;
+
+use super::{buffer::GlyphInfo, FeatureRange};
+use crate::layout::{Language, LayoutTable};
+
+/// Mask bit shared by all features that are enabled for the entire buffer.
+pub(super) const GLOBAL_MASK: u32 = 1;
+
+/// Features that are enabled by default for simple scripts.
+const DEFAULT_FEATURES: &[Tag] = &[
+    Tag::new(b"rvrn"),
+    Tag::new(b"ccmp"),
+    Tag::new(b"locl"),
+    Tag::new(b"rlig"),
+    Tag::new(b"calt"),
+    Tag::new(b"clig"),
+    Tag::new(b"liga"),
+    Tag::new(b"rclt"),
+    Tag::new(b"curs"),
+    Tag::new(b"dist"),
+    Tag::new(b"kern"),
+    Tag::new(b"mark"),
+    Tag::new(b"mkmk"),
+];
+
+/// Fallback scripts to use when the requested script is not present.
+const FALLBACK_SCRIPTS: &[Tag] = &[Tag::new(b"DFLT"), Tag::new(b"dflt"), Tag::new(b"latn")];
+
+/// A lookup that should be applied along with the mask of glyphs that it
+/// applies to.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(super) struct LookupEntry {
+    pub index: u16,
+    pub mask: u32,
+    /// Value of the feature that selected the lookup. Used to choose an
+    /// alternate glyph in alternate substitution lookups.
+    pub value: u16,
+}
+
+/// A feature request with an assigned mask bit.
+#[derive(Copy, Clone, Debug)]
+struct MaskedFeature {
+    tag: Tag,
+    value: u16,
+    mask: u32,
+    start: u32,
+    end: u32,
+}
+
+impl MaskedFeature {
+    fn is_global(&self) -> bool {
+        self.start == 0 && self.end == u32::MAX
+    }
+}
+
+/// Compiled set of lookups and masks for a particular script, language
+/// and set of features.
+#[derive(Clone, Default, Debug)]
+pub(super) struct ShapePlan {
+    global_mask: u32,
+    features: Vec<MaskedFeature>,
+    pub gsub: Vec<LookupEntry>,
+    pub gpos: Vec<LookupEntry>,
+}
+
+impl ShapePlan {
+    pub fn new(
+        gsub: Option<&LayoutTable>,
+        gpos: Option<&LayoutTable>,
+        script: Tag,
+        language: Option<Tag>,
+        user_features: &[FeatureRange],
+    ) -> Self {
+        let mut requests: Vec<FeatureRange> = vec![];
+        let defaults = DEFAULT_FEATURES
+            .iter()
+            .map(|tag| FeatureRange::new((*tag, 1)));
+        for request in defaults.chain(user_features.iter().cloned()) {
+            // a feature that applies to the entire buffer overrides all
+            // earlier requests for the same tag
+            if request.is_global() {
+                requests.retain(|prev| prev.setting.selector != request.setting.selector);
+            }
+            requests.push(request);
+        }
+        let mut features = vec![];
+        let mut global_mask = GLOBAL_MASK;
+        let mut next_bit = 1;
+        for request in &requests {
+            let tag = request.setting.selector;
+            let value = request.setting.value;
+            let is_ranged = |r: &FeatureRange| r.setting.selector == tag && !r.is_global();
+            let mask = if value == 0 {
+                0
+            } else if request.is_global() && value == 1 && !requests.iter().any(is_ranged) {
+                GLOBAL_MASK
+            } else if next_bit < u32::BITS {
+                // features with ranges or non-default values need their
+                // own bit
+                let mask = 1 << next_bit;
+                next_bit += 1;
+                if request.is_global() {
+                    global_mask |= mask;
+                }
+                mask
+            } else {
+                // out of bits; drop the request
+                continue;
+            };
+            features.push(MaskedFeature {
+                tag,
+                value,
+                mask,
+                start: request.start,
+                end: request.end,
+            });
+        }
+        let mut plan = Self {
+            global_mask,
+            features,
+            ..Default::default()
+        };
+        plan.gsub = plan.collect_lookups(gsub, script, language);
+        plan.gpos = plan.collect_lookups(gpos, script, language);
+        plan
+    }
+
+    /// Computes the feature mask for a glyph with the given cluster value.
+    pub fn mask_for(&self, cluster: u32) -> u32 {
+        let mut mask = self.global_mask;
+        for feature in self.features.iter().filter(|f| !f.is_global()) {
+            if cluster >= feature.start && cluster < feature.end {
+                // clear any previous state for this feature in the range
+                for other in self.features.iter().filter(|f| f.tag == feature.tag) {
+                    if other.mask != GLOBAL_MASK {
+                        mask &= !other.mask;
+                    }
+                }
+                mask |= feature.mask;
+            }
+        }
+        mask
+    }
+
+    /// Assigns feature masks to each glyph in the buffer.
+    pub fn setup_masks(&self, infos: &mut [GlyphInfo]) {
+        for info in infos {
+            info.mask = self.mask_for(info.cluster);
+        }
+    }
+
+    fn collect_lookups(
+        &self,
+        table: Option<&LayoutTable>,
+        script: Tag,
+        language: Option<Tag>,
+    ) -> Vec<LookupEntry> {
+        let Some(language) = table.and_then(|table| select_language(table, script, language))
+        else {
+            return vec![];
+        };
+        let mut lookups = vec![];
+        if let Some(feature) = language.required_feature() {
+            lookups.extend(feature.lookup_indices().map(|index| LookupEntry {
+                index,
+                mask: GLOBAL_MASK,
+                value: 1,
+            }));
+        }
+        for request in self.features.iter().filter(|f| f.mask != 0) {
+            if let Some(feature) = language.features().find(|f| f.tag() == request.tag) {
+                lookups.extend(feature.lookup_indices().map(|index| LookupEntry {
+                    index,
+                    mask: request.mask,
+                    value: request.value,
+                }));
+            }
+        }
+        // lookups are applied in lookup list order
+        lookups.sort_by_key(|lookup| lookup.index);
+        // merge duplicate lookups that differ only by mask
+        lookups.dedup_by(|next, prev| {
+            if next.index == prev.index && next.value == prev.value {
+                prev.mask |= next.mask;
+                true
+            } else {
+                false
+            }
+        });
+        lookups
+    }
+}
+
+fn select_language<'a>(
+    table: &LayoutTable<'a>,
+    script: Tag,
+    language: Option<Tag>,
+) -> Option<Language<'a>> {
+    let script = core::iter::once(&script)
+        .chain(FALLBACK_SCRIPTS)
+        .find_map(|tag| table.script(*tag))?;
+    language
+        .and_then(|tag| script.language(tag))
+        .or_else(|| script.default_language())
+}
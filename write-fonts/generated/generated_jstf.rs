@@ -0,0 +1,681 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [JSTF (Justification)](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Jstf {
+    /// Array of JstfScriptRecords, in alphabetical order by jstfScriptTag
+    pub jstf_script_records: Vec<JstfScriptRecord>,
+}
+
+impl Jstf {
+    /// Construct a new `Jstf`
+    pub fn new(jstf_script_records: Vec<JstfScriptRecord>) -> Self {
+        Self {
+            jstf_script_records: jstf_script_records.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for Jstf {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (MajorMinor::VERSION_1_0 as MajorMinor).write_into(writer);
+        (array_len(&self.jstf_script_records).unwrap() as u16).write_into(writer);
+        self.jstf_script_records.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::TopLevel(Jstf::TAG)
+    }
+}
+
+impl Validate for Jstf {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Jstf", |ctx| {
+            ctx.in_field("jstf_script_records", |ctx| {
+                if self.jstf_script_records.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+                self.jstf_script_records.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl TopLevelTable for Jstf {
+    const TAG: Tag = Tag::new(b"JSTF");
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::Jstf<'a>> for Jstf {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::Jstf<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        Jstf {
+            jstf_script_records: obj.jstf_script_records().to_owned_obj(offset_data),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::Jstf<'a>> for Jstf {}
+
+impl<'a> FontRead<'a> for Jstf {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::Jstf as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// Part of [Jstf]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfScriptRecord {
+    /// 4-byte JstfScript identification
+    pub jstf_script_tag: Tag,
+    /// Offset to JstfScript table, from beginning of JSTF Header
+    pub jstf_script: OffsetMarker<JstfScript>,
+}
+
+impl JstfScriptRecord {
+    /// Construct a new `JstfScriptRecord`
+    pub fn new(jstf_script_tag: Tag, jstf_script: JstfScript) -> Self {
+        Self {
+            jstf_script_tag,
+            jstf_script: jstf_script.into(),
+        }
+    }
+}
+
+impl FontWrite for JstfScriptRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.jstf_script_tag.write_into(writer);
+        self.jstf_script.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfScriptRecord")
+    }
+}
+
+impl Validate for JstfScriptRecord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfScriptRecord", |ctx| {
+            ctx.in_field("jstf_script", |ctx| {
+                self.jstf_script.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl FromObjRef<read_fonts::tables::jstf::JstfScriptRecord> for JstfScriptRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::tables::jstf::JstfScriptRecord,
+        offset_data: FontData,
+    ) -> Self {
+        JstfScriptRecord {
+            jstf_script_tag: obj.jstf_script_tag(),
+            jstf_script: obj.jstf_script(offset_data).to_owned_table(),
+        }
+    }
+}
+
+/// [JstfScript](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-script-table) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfScript {
+    /// Offset to ExtenderGlyph table, from beginning of JstfScript table
+    /// (may be NULL)
+    pub extender_glyph: NullableOffsetMarker<ExtenderGlyph>,
+    /// Offset to default JstfLangSys table, from beginning of JstfScript
+    /// table (may be NULL)
+    pub def_jstf_lang_sys: NullableOffsetMarker<JstfLangSys>,
+    /// Array of JstfLangSysRecords, in alphabetical order by JstfLangSysTag
+    pub jstf_lang_sys_records: Vec<JstfLangSysRecord>,
+}
+
+impl JstfScript {
+    /// Construct a new `JstfScript`
+    pub fn new(
+        extender_glyph: Option<ExtenderGlyph>,
+        def_jstf_lang_sys: Option<JstfLangSys>,
+        jstf_lang_sys_records: Vec<JstfLangSysRecord>,
+    ) -> Self {
+        Self {
+            extender_glyph: extender_glyph.into(),
+            def_jstf_lang_sys: def_jstf_lang_sys.into(),
+            jstf_lang_sys_records: jstf_lang_sys_records.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for JstfScript {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.extender_glyph.write_into(writer);
+        self.def_jstf_lang_sys.write_into(writer);
+        (array_len(&self.jstf_lang_sys_records).unwrap() as u16).write_into(writer);
+        self.jstf_lang_sys_records.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfScript")
+    }
+}
+
+impl Validate for JstfScript {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfScript", |ctx| {
+            ctx.in_field("extender_glyph", |ctx| {
+                self.extender_glyph.validate_impl(ctx);
+            });
+            ctx.in_field("def_jstf_lang_sys", |ctx| {
+                self.def_jstf_lang_sys.validate_impl(ctx);
+            });
+            ctx.in_field("jstf_lang_sys_records", |ctx| {
+                if self.jstf_lang_sys_records.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+                self.jstf_lang_sys_records.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::JstfScript<'a>> for JstfScript {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::JstfScript<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        JstfScript {
+            extender_glyph: obj.extender_glyph().to_owned_table(),
+            def_jstf_lang_sys: obj.def_jstf_lang_sys().to_owned_table(),
+            jstf_lang_sys_records: obj.jstf_lang_sys_records().to_owned_obj(offset_data),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::JstfScript<'a>> for JstfScript {}
+
+impl<'a> FontRead<'a> for JstfScript {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::JstfScript as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// Part of [JstfScript]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfLangSysRecord {
+    /// 4-byte JstfLangSys identifier
+    pub jstf_lang_sys_tag: Tag,
+    /// Offset to JstfLangSys table, from beginning of JstfScript table
+    pub jstf_lang_sys: OffsetMarker<JstfLangSys>,
+}
+
+impl JstfLangSysRecord {
+    /// Construct a new `JstfLangSysRecord`
+    pub fn new(jstf_lang_sys_tag: Tag, jstf_lang_sys: JstfLangSys) -> Self {
+        Self {
+            jstf_lang_sys_tag,
+            jstf_lang_sys: jstf_lang_sys.into(),
+        }
+    }
+}
+
+impl FontWrite for JstfLangSysRecord {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.jstf_lang_sys_tag.write_into(writer);
+        self.jstf_lang_sys.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfLangSysRecord")
+    }
+}
+
+impl Validate for JstfLangSysRecord {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfLangSysRecord", |ctx| {
+            ctx.in_field("jstf_lang_sys", |ctx| {
+                self.jstf_lang_sys.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl FromObjRef<read_fonts::tables::jstf::JstfLangSysRecord> for JstfLangSysRecord {
+    fn from_obj_ref(
+        obj: &read_fonts::tables::jstf::JstfLangSysRecord,
+        offset_data: FontData,
+    ) -> Self {
+        JstfLangSysRecord {
+            jstf_lang_sys_tag: obj.jstf_lang_sys_tag(),
+            jstf_lang_sys: obj.jstf_lang_sys(offset_data).to_owned_table(),
+        }
+    }
+}
+
+/// [ExtenderGlyph](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#extender-glyph-table) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExtenderGlyph {
+    /// Extender glyph IDs — in increasing numerical order
+    pub extender_glyphs: Vec<GlyphId>,
+}
+
+impl ExtenderGlyph {
+    /// Construct a new `ExtenderGlyph`
+    pub fn new(extender_glyphs: Vec<GlyphId>) -> Self {
+        Self {
+            extender_glyphs: extender_glyphs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for ExtenderGlyph {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.extender_glyphs).unwrap() as u16).write_into(writer);
+        self.extender_glyphs.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("ExtenderGlyph")
+    }
+}
+
+impl Validate for ExtenderGlyph {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("ExtenderGlyph", |ctx| {
+            ctx.in_field("extender_glyphs", |ctx| {
+                if self.extender_glyphs.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+            });
+        })
+    }
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::ExtenderGlyph<'a>> for ExtenderGlyph {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::ExtenderGlyph<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        ExtenderGlyph {
+            extender_glyphs: obj.extender_glyphs().to_owned_obj(offset_data),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::ExtenderGlyph<'a>> for ExtenderGlyph {}
+
+impl<'a> FontRead<'a> for ExtenderGlyph {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::ExtenderGlyph as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfLangSys](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-language-system-table) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfLangSys {
+    /// Array of offsets to JstfPriority tables, from beginning of
+    /// JstfLangSys table, in priority order
+    pub jstf_priorities: Vec<OffsetMarker<JstfPriority>>,
+}
+
+impl JstfLangSys {
+    /// Construct a new `JstfLangSys`
+    pub fn new(jstf_priorities: Vec<JstfPriority>) -> Self {
+        Self {
+            jstf_priorities: jstf_priorities.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for JstfLangSys {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.jstf_priorities).unwrap() as u16).write_into(writer);
+        self.jstf_priorities.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfLangSys")
+    }
+}
+
+impl Validate for JstfLangSys {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfLangSys", |ctx| {
+            ctx.in_field("jstf_priorities", |ctx| {
+                if self.jstf_priorities.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+                self.jstf_priorities.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::JstfLangSys<'a>> for JstfLangSys {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::JstfLangSys<'a>, _: FontData) -> Self {
+        JstfLangSys {
+            jstf_priorities: obj.jstf_priorities().to_owned_table(),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::JstfLangSys<'a>> for JstfLangSys {}
+
+impl<'a> FontRead<'a> for JstfLangSys {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::JstfLangSys as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfPriority](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-priority-table) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfPriority {
+    /// Offset to shrinkage-enable JstfGSUBModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub gsub_shrinkage_enable: NullableOffsetMarker<JstfGsubModList>,
+    /// Offset to shrinkage-disable JstfGSUBModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub gsub_shrinkage_disable: NullableOffsetMarker<JstfGsubModList>,
+    /// Offset to shrinkage-enable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub gpos_shrinkage_enable: NullableOffsetMarker<JstfGposModList>,
+    /// Offset to shrinkage-disable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub gpos_shrinkage_disable: NullableOffsetMarker<JstfGposModList>,
+    /// Offset to shrinkage JstfMax table, from beginning of JstfPriority
+    /// table (may be NULL)
+    pub shrinkage_jstf_max: NullableOffsetMarker<JstfMax>,
+    /// Offset to extension-enable JstfGSUBModList table, from beginnning of
+    /// JstfPriority table (may be NULL)
+    pub gsub_extension_enable: NullableOffsetMarker<JstfGsubModList>,
+    /// Offset to extension-disable JstfGSUBModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub gsub_extension_disable: NullableOffsetMarker<JstfGsubModList>,
+    /// Offset to extension-enable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub gpos_extension_enable: NullableOffsetMarker<JstfGposModList>,
+    /// Offset to extension-disable JstfGPOSModList table, from beginning of
+    /// JstfPriority table (may be NULL)
+    pub gpos_extension_disable: NullableOffsetMarker<JstfGposModList>,
+    /// Offset to extension JstfMax table, from beginning of JstfPriority
+    /// table (may be NULL)
+    pub extension_jstf_max: NullableOffsetMarker<JstfMax>,
+}
+
+impl JstfPriority {
+    /// Construct a new `JstfPriority`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        gsub_shrinkage_enable: Option<JstfGsubModList>,
+        gsub_shrinkage_disable: Option<JstfGsubModList>,
+        gpos_shrinkage_enable: Option<JstfGposModList>,
+        gpos_shrinkage_disable: Option<JstfGposModList>,
+        shrinkage_jstf_max: Option<JstfMax>,
+        gsub_extension_enable: Option<JstfGsubModList>,
+        gsub_extension_disable: Option<JstfGsubModList>,
+        gpos_extension_enable: Option<JstfGposModList>,
+        gpos_extension_disable: Option<JstfGposModList>,
+        extension_jstf_max: Option<JstfMax>,
+    ) -> Self {
+        Self {
+            gsub_shrinkage_enable: gsub_shrinkage_enable.into(),
+            gsub_shrinkage_disable: gsub_shrinkage_disable.into(),
+            gpos_shrinkage_enable: gpos_shrinkage_enable.into(),
+            gpos_shrinkage_disable: gpos_shrinkage_disable.into(),
+            shrinkage_jstf_max: shrinkage_jstf_max.into(),
+            gsub_extension_enable: gsub_extension_enable.into(),
+            gsub_extension_disable: gsub_extension_disable.into(),
+            gpos_extension_enable: gpos_extension_enable.into(),
+            gpos_extension_disable: gpos_extension_disable.into(),
+            extension_jstf_max: extension_jstf_max.into(),
+        }
+    }
+}
+
+impl FontWrite for JstfPriority {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.gsub_shrinkage_enable.write_into(writer);
+        self.gsub_shrinkage_disable.write_into(writer);
+        self.gpos_shrinkage_enable.write_into(writer);
+        self.gpos_shrinkage_disable.write_into(writer);
+        self.shrinkage_jstf_max.write_into(writer);
+        self.gsub_extension_enable.write_into(writer);
+        self.gsub_extension_disable.write_into(writer);
+        self.gpos_extension_enable.write_into(writer);
+        self.gpos_extension_disable.write_into(writer);
+        self.extension_jstf_max.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfPriority")
+    }
+}
+
+impl Validate for JstfPriority {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfPriority", |ctx| {
+            ctx.in_field("gsub_shrinkage_enable", |ctx| {
+                self.gsub_shrinkage_enable.validate_impl(ctx);
+            });
+            ctx.in_field("gsub_shrinkage_disable", |ctx| {
+                self.gsub_shrinkage_disable.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_shrinkage_enable", |ctx| {
+                self.gpos_shrinkage_enable.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_shrinkage_disable", |ctx| {
+                self.gpos_shrinkage_disable.validate_impl(ctx);
+            });
+            ctx.in_field("shrinkage_jstf_max", |ctx| {
+                self.shrinkage_jstf_max.validate_impl(ctx);
+            });
+            ctx.in_field("gsub_extension_enable", |ctx| {
+                self.gsub_extension_enable.validate_impl(ctx);
+            });
+            ctx.in_field("gsub_extension_disable", |ctx| {
+                self.gsub_extension_disable.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_extension_enable", |ctx| {
+                self.gpos_extension_enable.validate_impl(ctx);
+            });
+            ctx.in_field("gpos_extension_disable", |ctx| {
+                self.gpos_extension_disable.validate_impl(ctx);
+            });
+            ctx.in_field("extension_jstf_max", |ctx| {
+                self.extension_jstf_max.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::JstfPriority<'a>> for JstfPriority {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::JstfPriority<'a>, _: FontData) -> Self {
+        JstfPriority {
+            gsub_shrinkage_enable: obj.gsub_shrinkage_enable().to_owned_table(),
+            gsub_shrinkage_disable: obj.gsub_shrinkage_disable().to_owned_table(),
+            gpos_shrinkage_enable: obj.gpos_shrinkage_enable().to_owned_table(),
+            gpos_shrinkage_disable: obj.gpos_shrinkage_disable().to_owned_table(),
+            shrinkage_jstf_max: obj.shrinkage_jstf_max().to_owned_table(),
+            gsub_extension_enable: obj.gsub_extension_enable().to_owned_table(),
+            gsub_extension_disable: obj.gsub_extension_disable().to_owned_table(),
+            gpos_extension_enable: obj.gpos_extension_enable().to_owned_table(),
+            gpos_extension_disable: obj.gpos_extension_disable().to_owned_table(),
+            extension_jstf_max: obj.extension_jstf_max().to_owned_table(),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::JstfPriority<'a>> for JstfPriority {}
+
+impl<'a> FontRead<'a> for JstfPriority {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::JstfPriority as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfGSUBModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfGsubModList {
+    /// Array of Lookup indices into the GSUB LookupList, in increasing
+    /// numerical order
+    pub gsub_lookup_indices: Vec<u16>,
+}
+
+impl JstfGsubModList {
+    /// Construct a new `JstfGsubModList`
+    pub fn new(gsub_lookup_indices: Vec<u16>) -> Self {
+        Self {
+            gsub_lookup_indices: gsub_lookup_indices.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for JstfGsubModList {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.gsub_lookup_indices).unwrap() as u16).write_into(writer);
+        self.gsub_lookup_indices.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfGsubModList")
+    }
+}
+
+impl Validate for JstfGsubModList {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfGsubModList", |ctx| {
+            ctx.in_field("gsub_lookup_indices", |ctx| {
+                if self.gsub_lookup_indices.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+            });
+        })
+    }
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::JstfGsubModList<'a>> for JstfGsubModList {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::JstfGsubModList<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        JstfGsubModList {
+            gsub_lookup_indices: obj.gsub_lookup_indices().to_owned_obj(offset_data),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::JstfGsubModList<'a>> for JstfGsubModList {}
+
+impl<'a> FontRead<'a> for JstfGsubModList {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::JstfGsubModList as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfGPOSModList](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-modification-list-tables) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfGposModList {
+    /// Array of Lookup indices into the GPOS LookupList, in increasing
+    /// numerical order
+    pub gpos_lookup_indices: Vec<u16>,
+}
+
+impl JstfGposModList {
+    /// Construct a new `JstfGposModList`
+    pub fn new(gpos_lookup_indices: Vec<u16>) -> Self {
+        Self {
+            gpos_lookup_indices: gpos_lookup_indices.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for JstfGposModList {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.gpos_lookup_indices).unwrap() as u16).write_into(writer);
+        self.gpos_lookup_indices.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfGposModList")
+    }
+}
+
+impl Validate for JstfGposModList {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfGposModList", |ctx| {
+            ctx.in_field("gpos_lookup_indices", |ctx| {
+                if self.gpos_lookup_indices.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+            });
+        })
+    }
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::JstfGposModList<'a>> for JstfGposModList {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::JstfGposModList<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        JstfGposModList {
+            gpos_lookup_indices: obj.gpos_lookup_indices().to_owned_obj(offset_data),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::JstfGposModList<'a>> for JstfGposModList {}
+
+impl<'a> FontRead<'a> for JstfGposModList {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::JstfGposModList as FontRead>::read(data)
+            .map(|x| x.to_owned_table())
+    }
+}
+
+/// [JstfMax](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf#justification-maximum-table) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JstfMax {
+    /// Array of offsets to GPOS-type lookup tables, from beginning of
+    /// JstfMax table, in design order
+    pub lookups: Vec<OffsetMarker<PositionLookup>>,
+}
+
+impl JstfMax {
+    /// Construct a new `JstfMax`
+    pub fn new(lookups: Vec<PositionLookup>) -> Self {
+        Self {
+            lookups: lookups.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for JstfMax {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (array_len(&self.lookups).unwrap() as u16).write_into(writer);
+        self.lookups.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("JstfMax")
+    }
+}
+
+impl Validate for JstfMax {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("JstfMax", |ctx| {
+            ctx.in_field("lookups", |ctx| {
+                if self.lookups.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+                self.lookups.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl<'a> FromObjRef<read_fonts::tables::jstf::JstfMax<'a>> for JstfMax {
+    fn from_obj_ref(obj: &read_fonts::tables::jstf::JstfMax<'a>, _: FontData) -> Self {
+        JstfMax {
+            lookups: obj.lookups().to_owned_table(),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::jstf::JstfMax<'a>> for JstfMax {}
+
+impl<'a> FontRead<'a> for JstfMax {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::jstf::JstfMax as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
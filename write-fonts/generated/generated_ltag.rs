@@ -0,0 +1,106 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [ltag](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ltag {
+    /// Range of each tag's string.
+    pub tag_ranges: Vec<FTStringRange>,
+}
+
+impl Ltag {
+    /// Construct a new `Ltag`
+    pub fn new(tag_ranges: Vec<FTStringRange>) -> Self {
+        Self {
+            tag_ranges: tag_ranges.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for Ltag {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (1 as u32).write_into(writer);
+        (0 as u32).write_into(writer);
+        (array_len(&self.tag_ranges).unwrap() as u32).write_into(writer);
+        self.tag_ranges.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::TopLevel(Ltag::TAG)
+    }
+}
+
+impl Validate for Ltag {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Ltag", |ctx| {
+            ctx.in_field("tag_ranges", |ctx| {
+                if self.tag_ranges.len() > (u32::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+                self.tag_ranges.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl TopLevelTable for Ltag {
+    const TAG: Tag = Tag::new(b"ltag");
+}
+
+impl<'a> FromObjRef<read_fonts::tables::ltag::Ltag<'a>> for Ltag {
+    fn from_obj_ref(obj: &read_fonts::tables::ltag::Ltag<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        Ltag {
+            tag_ranges: obj.tag_ranges().to_owned_obj(offset_data),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::ltag::Ltag<'a>> for Ltag {}
+
+impl<'a> FontRead<'a> for Ltag {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::ltag::Ltag as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// Offset and length of a language tag string within the ltag table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FTStringRange {
+    /// Offset from the start of the table to the beginning of the string.
+    pub tag: OffsetMarker<String>,
+}
+
+impl FTStringRange {
+    /// Construct a new `FTStringRange`
+    #[allow(clippy::useless_conversion)]
+    pub fn new(tag: OffsetMarker<String>) -> Self {
+        Self { tag: tag.into() }
+    }
+}
+
+impl FontWrite for FTStringRange {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (self.compile_tag()).write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("FTStringRange")
+    }
+}
+
+impl Validate for FTStringRange {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+impl FromObjRef<read_fonts::tables::ltag::FTStringRange> for FTStringRange {
+    fn from_obj_ref(obj: &read_fonts::tables::ltag::FTStringRange, offset_data: FontData) -> Self {
+        FTStringRange {
+            tag: obj.tag(offset_data).to_owned_table(),
+        }
+    }
+}
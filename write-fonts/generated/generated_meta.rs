@@ -0,0 +1,115 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [meta (Metadata)](https://learn.microsoft.com/en-us/typography/opentype/spec/meta) table
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Meta {
+    /// Array of data map records.
+    pub data_maps: Vec<DataMap>,
+}
+
+impl Meta {
+    /// Construct a new `Meta`
+    pub fn new(data_maps: Vec<DataMap>) -> Self {
+        Self {
+            data_maps: data_maps.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl FontWrite for Meta {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (1 as u32).write_into(writer);
+        (0 as u32).write_into(writer);
+        (0 as u32).write_into(writer);
+        (array_len(&self.data_maps).unwrap() as u32).write_into(writer);
+        self.data_maps.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::TopLevel(Meta::TAG)
+    }
+}
+
+impl Validate for Meta {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("Meta", |ctx| {
+            ctx.in_field("data_maps", |ctx| {
+                if self.data_maps.len() > (u32::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+                self.data_maps.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+impl TopLevelTable for Meta {
+    const TAG: Tag = Tag::new(b"meta");
+}
+
+impl<'a> FromObjRef<read_fonts::tables::meta::Meta<'a>> for Meta {
+    fn from_obj_ref(obj: &read_fonts::tables::meta::Meta<'a>, _: FontData) -> Self {
+        let offset_data = obj.offset_data();
+        Meta {
+            data_maps: obj.data_maps().to_owned_obj(offset_data),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::meta::Meta<'a>> for Meta {}
+
+impl<'a> FontRead<'a> for Meta {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::meta::Meta as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
+
+/// [DataMap](https://learn.microsoft.com/en-us/typography/opentype/spec/meta#table-formats) record
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DataMap {
+    /// A tag indicating the type of metadata.
+    pub tag: Tag,
+    /// Offset in bytes from the beginning of the metadata table to the data
+    /// for this tag.
+    pub data: OffsetMarker<Metadata, WIDTH_32>,
+}
+
+impl DataMap {
+    /// Construct a new `DataMap`
+    #[allow(clippy::useless_conversion)]
+    pub fn new(tag: Tag, data: OffsetMarker<Metadata, WIDTH_32>) -> Self {
+        Self {
+            tag,
+            data: data.into(),
+        }
+    }
+}
+
+impl FontWrite for DataMap {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.tag.write_into(writer);
+        (self.compile_map_value()).write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("DataMap")
+    }
+}
+
+impl Validate for DataMap {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+impl FromObjRef<read_fonts::tables::meta::DataMap> for DataMap {
+    fn from_obj_ref(obj: &read_fonts::tables::meta::DataMap, offset_data: FontData) -> Self {
+        DataMap {
+            tag: obj.tag(),
+            data: obj.data(offset_data).to_owned_table(),
+        }
+    }
+}
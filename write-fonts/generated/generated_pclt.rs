@@ -0,0 +1,101 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [PCLT (PCL 5)](https://learn.microsoft.com/en-us/typography/opentype/spec/pclt) table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pclt {
+    /// The HP font number.
+    pub font_number: u32,
+    /// The width of the space in FUnits.
+    pub pitch: u16,
+    /// The height of the optical line describing the height of the lowercase
+    /// x in FUnits.
+    pub x_height: u16,
+    /// Contains information on the posture, width and structure of the
+    /// typeface.
+    pub style: u16,
+    /// The font vendor code and the family code, which together identify
+    /// the font's typeface family.
+    pub type_family: u16,
+    /// The height of the optical line describing the top of the uppercase
+    /// H in FUnits.
+    pub cap_height: u16,
+    /// The symbol set of the font.
+    pub symbol_set: u16,
+    /// The name of the typeface, padded with spaces.
+    pub typeface: [u8; 16],
+    /// Identifies the symbol collections provided by the font.
+    pub character_complement: [u8; 8],
+    /// A suggested six character ASCII file name for the font.
+    pub file_name: [u8; 6],
+    /// The stroke weight of the font, from -7 to 7.
+    pub stroke_weight: i8,
+    /// The appearance width of the font, from -5 to 5.
+    pub width_type: i8,
+    /// The serif style and stroke structure of the font.
+    pub serif_style: u8,
+}
+
+impl FontWrite for Pclt {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        (Version16Dot16::VERSION_1_0 as Version16Dot16).write_into(writer);
+        self.font_number.write_into(writer);
+        self.pitch.write_into(writer);
+        self.x_height.write_into(writer);
+        self.style.write_into(writer);
+        self.type_family.write_into(writer);
+        self.cap_height.write_into(writer);
+        self.symbol_set.write_into(writer);
+        self.typeface.write_into(writer);
+        self.character_complement.write_into(writer);
+        self.file_name.write_into(writer);
+        self.stroke_weight.write_into(writer);
+        self.width_type.write_into(writer);
+        self.serif_style.write_into(writer);
+        (0 as u8).write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::TopLevel(Pclt::TAG)
+    }
+}
+
+impl Validate for Pclt {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
+
+impl TopLevelTable for Pclt {
+    const TAG: Tag = Tag::new(b"PCLT");
+}
+
+impl<'a> FromObjRef<read_fonts::tables::pclt::Pclt<'a>> for Pclt {
+    fn from_obj_ref(obj: &read_fonts::tables::pclt::Pclt<'a>, _: FontData) -> Self {
+        Pclt {
+            font_number: obj.font_number(),
+            pitch: obj.pitch(),
+            x_height: obj.x_height(),
+            style: obj.style(),
+            type_family: obj.type_family(),
+            cap_height: obj.cap_height(),
+            symbol_set: obj.symbol_set(),
+            typeface: convert_typeface(obj.typeface()),
+            character_complement: convert_character_complement(obj.character_complement()),
+            file_name: convert_file_name(obj.file_name()),
+            stroke_weight: obj.stroke_weight(),
+            width_type: obj.width_type(),
+            serif_style: obj.serif_style(),
+        }
+    }
+}
+
+impl<'a> FromTableRef<read_fonts::tables::pclt::Pclt<'a>> for Pclt {}
+
+impl<'a> FontRead<'a> for Pclt {
+    fn read(data: FontData<'a>) -> Result<Self, ReadError> {
+        <read_fonts::tables::pclt::Pclt as FontRead>::read(data).map(|x| x.to_owned_table())
+    }
+}
@@ -0,0 +1,123 @@
+// THIS FILE IS AUTOGENERATED.
+// Any changes to this file will be overwritten.
+// For more information about how codegen works, see font-codegen/README.md
+
+#[allow(unused_imports)]
+use crate::codegen_prelude::*;
+
+/// The [WOFF Header](https://www.w3.org/TR/WOFF/#WOFFHeader)
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WoffHeader {
+    /// 0x774F4646 'wOFF'
+    pub signature: Tag,
+    /// The sfnt version of the input font.
+    pub flavor: u32,
+    /// Total size of the WOFF file.
+    pub length: u32,
+    /// Total size needed for the uncompressed font data, including the sfnt
+    /// header, directory, and font tables (including padding).
+    pub total_sfnt_size: u32,
+    /// Major version of the WOFF file.
+    pub major_version: u16,
+    /// Minor version of the WOFF file.
+    pub minor_version: u16,
+    /// Offset to metadata block, from beginning of WOFF file.
+    pub meta_offset: u32,
+    /// Length of compressed metadata block.
+    pub meta_length: u32,
+    /// Uncompressed size of metadata block.
+    pub meta_orig_length: u32,
+    /// Offset to private data block, from beginning of WOFF file.
+    pub priv_offset: u32,
+    /// Length of private data block.
+    pub priv_length: u32,
+    /// Directory of font tables, in ascending order by tag.
+    pub table_directory: Vec<WoffTableDirectoryEntry>,
+}
+
+impl FontWrite for WoffHeader {
+    #[allow(clippy::unnecessary_cast)]
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.signature.write_into(writer);
+        self.flavor.write_into(writer);
+        self.length.write_into(writer);
+        (array_len(&self.table_directory).unwrap() as u16).write_into(writer);
+        (0 as u16).write_into(writer);
+        self.total_sfnt_size.write_into(writer);
+        self.major_version.write_into(writer);
+        self.minor_version.write_into(writer);
+        self.meta_offset.write_into(writer);
+        self.meta_length.write_into(writer);
+        self.meta_orig_length.write_into(writer);
+        self.priv_offset.write_into(writer);
+        self.priv_length.write_into(writer);
+        self.table_directory.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("WoffHeader")
+    }
+}
+
+impl Validate for WoffHeader {
+    fn validate_impl(&self, ctx: &mut ValidationCtx) {
+        ctx.in_table("WoffHeader", |ctx| {
+            ctx.in_field("table_directory", |ctx| {
+                if self.table_directory.len() > (u16::MAX as usize) {
+                    ctx.report("array exceeds max length");
+                }
+                self.table_directory.validate_impl(ctx);
+            });
+        })
+    }
+}
+
+/// [WOFF Table Directory](https://www.w3.org/TR/WOFF/#TableDirectory) entry
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WoffTableDirectoryEntry {
+    /// 4-byte sfnt table identifier.
+    pub tag: Tag,
+    /// Offset to the data, from beginning of WOFF file.
+    pub offset: u32,
+    /// Length of the compressed data, excluding padding.
+    pub comp_length: u32,
+    /// Length of the uncompressed table, excluding padding.
+    pub orig_length: u32,
+    /// Checksum of the uncompressed table.
+    pub orig_checksum: u32,
+}
+
+impl WoffTableDirectoryEntry {
+    /// Construct a new `WoffTableDirectoryEntry`
+    pub fn new(
+        tag: Tag,
+        offset: u32,
+        comp_length: u32,
+        orig_length: u32,
+        orig_checksum: u32,
+    ) -> Self {
+        Self {
+            tag,
+            offset,
+            comp_length,
+            orig_length,
+            orig_checksum,
+        }
+    }
+}
+
+impl FontWrite for WoffTableDirectoryEntry {
+    fn write_into(&self, writer: &mut TableWriter) {
+        self.tag.write_into(writer);
+        self.offset.write_into(writer);
+        self.comp_length.write_into(writer);
+        self.orig_length.write_into(writer);
+        self.orig_checksum.write_into(writer);
+    }
+    fn table_type(&self) -> TableType {
+        TableType::Named("WoffTableDirectoryEntry")
+    }
+}
+
+impl Validate for WoffTableDirectoryEntry {
+    fn validate_impl(&self, _ctx: &mut ValidationCtx) {}
+}
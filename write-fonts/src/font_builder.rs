@@ -7,8 +7,14 @@ use read_fonts::{FontRef, TableProvider};
 use types::{Tag, TT_SFNT_VERSION};
 
 include!("../generated/generated_font.rs");
+#[cfg(feature = "woff")]
+include!("../generated/generated_woff.rs");
 
 const TABLE_RECORD_LEN: usize = 16;
+#[cfg(feature = "woff")]
+const WOFF_HEADER_LEN: usize = 44;
+#[cfg(feature = "woff")]
+const WOFF_TABLE_ENTRY_LEN: usize = 20;
 
 /// Build a font from some set of tables.
 #[derive(Debug, Clone, Default)]
@@ -128,6 +134,101 @@ impl<'a> FontBuilder<'a> {
         }
         data
     }
+
+    /// Assemble all the tables into a [WOFF] file.
+    ///
+    /// Each table is compressed with zlib, unless that would not reduce its
+    /// size. The optional extended metadata (UTF-8 encoded XML) is always
+    /// compressed, and the optional private data is stored as is.
+    ///
+    /// [WOFF]: https://www.w3.org/TR/WOFF/
+    #[cfg(feature = "woff")]
+    pub fn build_woff(&mut self, metadata: Option<&[u8]>, private_data: Option<&[u8]>) -> Vec<u8> {
+        let packed_tables: Vec<_> = self
+            .tables
+            .values()
+            .map(|data| {
+                let packed = miniz_oxide::deflate::compress_to_vec_zlib(data, 9);
+                if packed.len() < data.len() {
+                    Cow::Owned(packed)
+                } else {
+                    Cow::Borrowed(data.as_ref())
+                }
+            })
+            .collect();
+
+        let mut position = WOFF_HEADER_LEN + self.tables.len() * WOFF_TABLE_ENTRY_LEN;
+        let mut total_sfnt_size = std::mem::size_of::<u32>() // sfnt
+            + std::mem::size_of::<u16>() * 4 // num_tables to range_shift
+            + self.tables.len() * TABLE_RECORD_LEN;
+        let table_directory = self
+            .tables
+            .iter()
+            .zip(&packed_tables)
+            .map(|((tag, data), packed)| {
+                let offset = position as u32;
+                position += round4(packed.len());
+                total_sfnt_size += round4(data.len());
+                let (mut checksum, _) = checksum_and_padding(data);
+                if *tag == Tag::new(b"head") && data.len() >= 12 {
+                    // the checksum ignores the checksumAdjustment field
+                    let adjustment = u32::from_be_bytes(data[8..12].try_into().unwrap());
+                    checksum = checksum.wrapping_sub(adjustment);
+                }
+                WoffTableDirectoryEntry::new(
+                    *tag,
+                    offset,
+                    packed.len() as u32,
+                    data.len() as u32,
+                    checksum,
+                )
+            })
+            .collect();
+
+        let packed_metadata =
+            metadata.map(|metadata| miniz_oxide::deflate::compress_to_vec_zlib(metadata, 9));
+        let mut header = WoffHeader {
+            signature: Tag::new(b"wOFF"),
+            flavor: TT_SFNT_VERSION,
+            total_sfnt_size: total_sfnt_size as u32,
+            major_version: 1,
+            table_directory,
+            ..Default::default()
+        };
+        let mut length = position;
+        if let (Some(metadata), Some(packed)) = (metadata, &packed_metadata) {
+            header.meta_offset = position as u32;
+            header.meta_length = packed.len() as u32;
+            header.meta_orig_length = metadata.len() as u32;
+            length = position + packed.len();
+            position += round4(packed.len());
+        }
+        if let Some(private_data) = private_data {
+            header.priv_offset = position as u32;
+            header.priv_length = private_data.len() as u32;
+            length = position + private_data.len();
+        }
+        header.length = length as u32;
+
+        let mut writer = TableWriter::default();
+        header.write_into(&mut writer);
+        let mut data = writer.into_data();
+        for packed in &packed_tables {
+            data.extend_from_slice(packed);
+            data.resize(round4(data.len()), 0);
+        }
+        if let Some(packed) = &packed_metadata {
+            data.extend_from_slice(packed);
+            if private_data.is_some() {
+                data.resize(round4(data.len()), 0);
+            }
+        }
+        if let Some(private_data) = private_data {
+            data.extend_from_slice(private_data);
+        }
+        data.truncate(length);
+        data
+    }
 }
 
 /// <https://github.com/google/woff2/blob/a0d0ed7da27b708c0a4e96ad7a998bddc933c06e/src/round.h#L19>
@@ -198,6 +299,27 @@ mod tests {
         FontBuilder::default().build();
     }
 
+    #[test]
+    #[cfg(feature = "woff")]
+    fn woff_roundtrip() {
+        use read_fonts::woff::WoffRef;
+
+        let compressible = vec![7u8; 1000];
+        let mut builder = FontBuilder::default();
+        builder
+            .add_raw(Tag::new(b"abcd"), &compressible)
+            .add_raw(Tag::new(b"efgh"), b"odd".as_slice())
+            .add_raw(Tag::new(b"ijkl"), b"four".as_slice());
+        let metadata = b"<?xml version=\"1.0\"?><metadata version=\"1.0\"/>";
+        let woff_data = builder.build_woff(Some(metadata), Some(b"private"));
+        let woff = WoffRef::new(&woff_data).unwrap();
+        let entry = &woff.header().table_directory()[0];
+        assert!(entry.comp_length() < entry.orig_length());
+        assert_eq!(woff.metadata().unwrap().unwrap(), metadata);
+        assert_eq!(woff.private_data(), Some(b"private".as_slice()));
+        assert_eq!(woff.to_sfnt().unwrap(), builder.build());
+    }
+
     #[test]
     fn pad4() {
         for i in 0..10 {
@@ -13,13 +13,18 @@ pub mod head;
 pub mod hhea;
 pub mod hmtx;
 pub mod hvar;
+pub mod jstf;
 pub mod layout;
 pub mod loca;
+pub mod ltag;
 pub mod maxp;
+pub mod meta;
 pub mod name;
 pub mod os2;
+pub mod pclt;
 pub mod post;
 pub mod stat;
 pub mod variations;
 pub mod vhea;
 pub mod vmtx;
+pub mod vorg;
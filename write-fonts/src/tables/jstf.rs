@@ -0,0 +1,5 @@
+//! The [JSTF (Justification)](https://learn.microsoft.com/en-us/typography/opentype/spec/jstf) table
+
+include!("../../generated/generated_jstf.rs");
+
+use super::gpos::PositionLookup;
@@ -1,12 +1,9 @@
 //! OpenType layout.
 
-use std::{
-    collections::{BTreeMap, HashSet},
-    hash::Hash,
-};
+use std::{collections::BTreeMap, hash::Hash};
 
 pub use read_fonts::tables::layout::LookupFlag;
-use read_fonts::FontRead;
+use read_fonts::{collections::IntSet, FontRead};
 
 #[cfg(test)]
 #[path = "../tests/layout.rs"]
@@ -310,11 +307,10 @@ impl ClassDef {
     }
 
     pub fn class_count(&self) -> u16 {
-        //TODO: implement a good integer set!!
         self.iter()
             .map(|(_gid, cls)| cls)
             .chain(std::iter::once(0))
-            .collect::<HashSet<_>>()
+            .collect::<IntSet<_>>()
             .len()
             .try_into()
             .unwrap()
@@ -0,0 +1,69 @@
+//! The [ltag](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html) table
+
+include!("../../generated/generated_ltag.rs");
+
+impl Ltag {
+    /// Create a new table from a list of language tags.
+    pub fn from_tags<I, S>(tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Ltag::new(
+            tags.into_iter()
+                .map(|tag| FTStringRange::new(tag.into().into()))
+                .collect(),
+        )
+    }
+}
+
+impl FTStringRange {
+    fn compile_tag(&self) -> LangTagAndLenWriter<'_> {
+        LangTagAndLenWriter(self.tag.as_str())
+    }
+}
+
+/// A helper that compiles both the offset and the length of the tag string
+struct LangTagAndLenWriter<'a>(&'a str);
+
+struct LangTagWriter<'a>(&'a str);
+
+impl FontWrite for LangTagAndLenWriter<'_> {
+    fn write_into(&self, writer: &mut TableWriter) {
+        writer.write_offset(&LangTagWriter(self.0), 2);
+        (self.0.len() as u16).write_into(writer);
+    }
+}
+
+impl FontWrite for LangTagWriter<'_> {
+    fn write_into(&self, writer: &mut TableWriter) {
+        writer.write_slice(self.0.as_bytes());
+    }
+}
+
+impl FromObjRef<&str> for String {
+    fn from_obj_ref(obj: &&str, _: FontData) -> Self {
+        obj.to_string()
+    }
+}
+
+impl FromTableRef<&str> for String {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use read_fonts::tables::ltag as read_ltag;
+
+    #[test]
+    fn roundtrip() {
+        let table = Ltag::from_tags(["en", "zh-Hant", "en"]);
+        let bytes = crate::dump_table(&table).unwrap();
+        let read = read_ltag::Ltag::read(FontData::new(&bytes)).unwrap();
+        let tags = read.tags().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(tags, ["en", "zh-Hant", "en"]);
+        // identical strings are shared
+        assert_eq!(bytes.len(), 12 + 3 * 4 + 2 + 7);
+        let owned: Ltag = read.to_owned_table();
+        assert_eq!(owned, table);
+    }
+}
@@ -0,0 +1,107 @@
+//! The [meta (Metadata)](https://learn.microsoft.com/en-us/typography/opentype/spec/meta) table
+
+include!("../../generated/generated_meta.rs");
+
+pub use read_fonts::tables::meta::{DLNG, SLNG};
+
+/// The data referenced by a [`DataMap`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Metadata {
+    /// A list of script and language tags, used for `dlng` and `slng`.
+    ///
+    /// The tags are joined with commas when compiled.
+    ScriptLangTags(Vec<String>),
+    /// Data for any other tag.
+    Other(Vec<u8>),
+}
+
+impl Default for Metadata {
+    fn default() -> Self {
+        Metadata::Other(Vec::new())
+    }
+}
+
+impl Metadata {
+    fn compute_length(&self) -> u32 {
+        match self {
+            Metadata::ScriptLangTags(tags) => {
+                let commas = tags.len().saturating_sub(1);
+                (tags.iter().map(String::len).sum::<usize>() + commas) as u32
+            }
+            Metadata::Other(data) => data.len() as u32,
+        }
+    }
+}
+
+impl DataMap {
+    fn compile_map_value(&self) -> MetadataAndLenWriter<'_> {
+        MetadataAndLenWriter(&self.data)
+    }
+}
+
+/// A helper that compiles both the offset and the length of the data
+struct MetadataAndLenWriter<'a>(&'a Metadata);
+
+impl FontWrite for MetadataAndLenWriter<'_> {
+    fn write_into(&self, writer: &mut TableWriter) {
+        writer.write_offset(self.0, 4);
+        self.0.compute_length().write_into(writer);
+    }
+}
+
+impl FontWrite for Metadata {
+    fn write_into(&self, writer: &mut TableWriter) {
+        match self {
+            Metadata::ScriptLangTags(tags) => {
+                for (i, tag) in tags.iter().enumerate() {
+                    if i > 0 {
+                        writer.write_slice(b",");
+                    }
+                    writer.write_slice(tag.as_bytes());
+                }
+            }
+            Metadata::Other(data) => writer.write_slice(data),
+        }
+    }
+}
+
+impl FromObjRef<read_fonts::tables::meta::Metadata<'_>> for Metadata {
+    fn from_obj_ref(obj: &read_fonts::tables::meta::Metadata<'_>, _: FontData) -> Self {
+        match obj {
+            read_fonts::tables::meta::Metadata::ScriptLangTags(tags) => {
+                Metadata::ScriptLangTags(tags.iter().map(Into::into).collect())
+            }
+            read_fonts::tables::meta::Metadata::Other(data) => Metadata::Other(data.to_vec()),
+        }
+    }
+}
+
+impl FromTableRef<read_fonts::tables::meta::Metadata<'_>> for Metadata {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use read_fonts::tables::meta as read_meta;
+
+    #[test]
+    fn roundtrip() {
+        let table = Meta::new(vec![
+            DataMap::new(
+                DLNG,
+                Metadata::ScriptLangTags(vec!["Latn".into(), "Cyrl".into()]).into(),
+            ),
+            DataMap::new(Tag::new(b"appl"), Metadata::Other(vec![1, 2, 3]).into()),
+        ]);
+        let bytes = crate::dump_table(&table).unwrap();
+        let read = read_meta::Meta::read(FontData::new(&bytes)).unwrap();
+        let design = read.design_languages().unwrap().unwrap();
+        assert_eq!(design.as_str(), "Latn,Cyrl");
+        assert!(read.supported_languages().is_none());
+        assert_eq!(
+            read.data_for_tag(Tag::new(b"appl")).unwrap().unwrap(),
+            read_meta::Metadata::Other(&[1, 2, 3])
+        );
+        let owned: Meta = read.to_owned_table();
+        assert_eq!(owned, table);
+    }
+}
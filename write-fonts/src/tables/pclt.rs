@@ -0,0 +1,40 @@
+//! The [PCLT (PCL 5)](https://learn.microsoft.com/en-us/typography/opentype/spec/pclt) table
+
+include!("../../generated/generated_pclt.rs");
+
+fn convert_typeface(raw: &[u8]) -> [u8; 16] {
+    raw.try_into().unwrap_or_default()
+}
+
+fn convert_character_complement(raw: &[u8]) -> [u8; 8] {
+    raw.try_into().unwrap_or_default()
+}
+
+fn convert_file_name(raw: &[u8]) -> [u8; 6] {
+    raw.try_into().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use read_fonts::tables::pclt as read_pclt;
+
+    #[test]
+    fn roundtrip() {
+        let table = Pclt {
+            pitch: 500,
+            typeface: *b"Test Sans       ",
+            file_name: *b"TSTSAN",
+            stroke_weight: -2,
+            ..Default::default()
+        };
+        let bytes = crate::dump_table(&table).unwrap();
+        assert_eq!(bytes.len(), 54);
+        let read = read_pclt::Pclt::read(FontData::new(&bytes)).unwrap();
+        assert_eq!(read.version(), Version16Dot16::VERSION_1_0);
+        assert_eq!(read.typeface(), b"Test Sans       ");
+        assert_eq!(read.stroke_weight(), -2);
+        let owned: Pclt = read.to_owned_table();
+        assert_eq!(owned, table);
+    }
+}
@@ -0,0 +1,3 @@
+//! The [VORG (Vertical Origin)](https://learn.microsoft.com/en-us/typography/opentype/spec/vorg) table
+
+include!("../../generated/generated_vorg.rs");